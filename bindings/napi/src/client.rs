@@ -0,0 +1,59 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::iota::IotaDID;
+use identity_iota::iota::IotaDocument;
+use identity_iota::iota::IotaIdentityClientExt;
+use identity_iota::iota::NetworkName;
+use iota_sdk::client::Client;
+use napi::Env;
+use napi::JsObject;
+use napi_derive::napi;
+
+use crate::document::NapiIotaDocument;
+use crate::error::NapiResult;
+use crate::error::Result;
+
+/// A client connected to an IOTA node, used to resolve IOTA DID Documents.
+#[napi(js_name = "IotaClient")]
+pub struct NapiIotaClient {
+  pub(crate) inner: Client,
+}
+
+#[napi(js_class = "IotaClient")]
+impl NapiIotaClient {
+  /// Connects to the node at `url`.
+  #[napi(ts_return_type = "Promise<IotaClient>")]
+  pub fn connect(env: Env, url: String) -> Result<JsObject> {
+    env.spawn_future(async move {
+      let client: Client = Client::builder()
+        .with_primary_node(&url, None)
+        .napi_result()?
+        .finish()
+        .await
+        .napi_result()?;
+      Ok(Self { inner: client })
+    })
+  }
+
+  /// Returns the name of the network the node is part of.
+  #[napi(ts_return_type = "Promise<string>")]
+  pub fn network_name(&self, env: Env) -> Result<JsObject> {
+    let client: Client = self.inner.clone();
+    env.spawn_future(async move {
+      let network_name: NetworkName = client.network_name().await.napi_result()?;
+      Ok(network_name.to_string())
+    })
+  }
+
+  /// Resolves the DID Document of `did` from the node.
+  #[napi(ts_return_type = "Promise<IotaDocument>")]
+  pub fn resolve_did(&self, env: Env, did: String) -> Result<JsObject> {
+    let client: Client = self.inner.clone();
+    let did: IotaDID = IotaDID::parse(did).napi_result()?;
+    env.spawn_future(async move {
+      let document: IotaDocument = client.resolve_did(&did).await.napi_result()?;
+      Ok(NapiIotaDocument::from(document))
+    })
+  }
+}
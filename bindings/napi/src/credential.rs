@@ -0,0 +1,190 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_iota::core::FromJson;
+use identity_iota::core::Object;
+use identity_iota::core::ToJson;
+use identity_iota::credential::Credential;
+use identity_iota::credential::DecodedJwtCredential;
+use identity_iota::credential::DecodedJwtPresentation;
+use identity_iota::credential::FailFast;
+use identity_iota::credential::Jwt;
+use identity_iota::credential::JwtCredentialValidationOptions;
+use identity_iota::credential::JwtCredentialValidator;
+use identity_iota::credential::JwtPresentationValidationOptions;
+use identity_iota::credential::JwtPresentationValidator;
+use identity_iota::credential::Presentation;
+use identity_iota::iota::IotaDocument;
+use napi_derive::napi;
+use serde_json::Value;
+use tokio::sync::RwLockReadGuard;
+
+use crate::document::NapiIotaDocument;
+use crate::error::NapiResult;
+use crate::error::Result;
+
+/// A Verifiable Credential.
+///
+/// See: <https://www.w3.org/TR/vc-data-model/#credentials>
+#[napi(js_name = "Credential")]
+pub struct NapiCredential {
+  pub(crate) inner: Credential,
+}
+
+#[napi(js_class = "Credential")]
+impl NapiCredential {
+  /// Creates a {@link Credential} from its JSON representation, checking its structure.
+  #[napi(constructor)]
+  pub fn new(json: Value) -> Result<Self> {
+    let credential: Credential = Credential::from_json_value(json).napi_result()?;
+    credential.check_structure().napi_result()?;
+    Ok(Self { inner: credential })
+  }
+
+  /// Returns the unique `URI` identifying the credential, if any.
+  #[napi]
+  pub fn id(&self) -> Option<String> {
+    self.inner.id.as_ref().map(ToString::to_string)
+  }
+
+  /// Returns the URI of the issuer of the credential.
+  #[napi]
+  pub fn issuer(&self) -> String {
+    self.inner.issuer.url().to_string()
+  }
+
+  /// Returns the JSON representation of the credential.
+  #[napi(js_name = "toJSON")]
+  pub fn to_json(&self) -> Result<Value> {
+    self.inner.to_json_value().napi_result()
+  }
+}
+
+/// A Verifiable Presentation of JWT credentials.
+///
+/// See: <https://www.w3.org/TR/vc-data-model/#presentations>
+#[napi(js_name = "Presentation")]
+pub struct NapiPresentation {
+  pub(crate) inner: Presentation<Jwt>,
+}
+
+#[napi(js_class = "Presentation")]
+impl NapiPresentation {
+  /// Creates a {@link Presentation} from its JSON representation, checking its structure.
+  #[napi(constructor)]
+  pub fn new(json: Value) -> Result<Self> {
+    let presentation: Presentation<Jwt> = Presentation::from_json_value(json).napi_result()?;
+    presentation.check_structure().napi_result()?;
+    Ok(Self { inner: presentation })
+  }
+
+  /// Returns the URI of the holder of the presentation.
+  #[napi]
+  pub fn holder(&self) -> String {
+    self.inner.holder.to_string()
+  }
+
+  /// Returns the JWT credentials contained in the presentation.
+  #[napi(js_name = "verifiableCredential")]
+  pub fn verifiable_credential(&self) -> Vec<String> {
+    self
+      .inner
+      .verifiable_credential
+      .iter()
+      .map(|jwt| jwt.as_str().to_owned())
+      .collect()
+  }
+
+  /// Returns the JSON representation of the presentation.
+  #[napi(js_name = "toJSON")]
+  pub fn to_json(&self) -> Result<Value> {
+    self.inner.to_json_value().napi_result()
+  }
+}
+
+/// Validates JWT credentials signed with EdDSA.
+#[napi(js_name = "JwtCredentialValidator")]
+pub struct NapiJwtCredentialValidator {
+  inner: JwtCredentialValidator<EdDSAJwsVerifier>,
+}
+
+#[napi(js_class = "JwtCredentialValidator")]
+impl NapiJwtCredentialValidator {
+  /// Creates a new {@link JwtCredentialValidator}.
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Self {
+      inner: JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default()),
+    }
+  }
+
+  /// Validates the credential `jwt` issued by `issuer` according to `options`, a JSON representation of
+  /// `JwtCredentialValidationOptions`, and returns the decoded credential.
+  ///
+  /// All errors are reported unless `failFast` is set.
+  #[napi]
+  pub fn validate(
+    &self,
+    jwt: String,
+    issuer: &NapiIotaDocument,
+    options: Option<Value>,
+    fail_fast: Option<bool>,
+  ) -> Result<NapiCredential> {
+    let options: JwtCredentialValidationOptions = options
+      .map(JwtCredentialValidationOptions::from_json_value)
+      .transpose()
+      .napi_result()?
+      .unwrap_or_default();
+    let fail_fast: FailFast = if fail_fast.unwrap_or(false) {
+      FailFast::FirstError
+    } else {
+      FailFast::AllErrors
+    };
+    let issuer: RwLockReadGuard<'_, IotaDocument> = issuer.read()?;
+
+    let decoded: DecodedJwtCredential<Object> = self
+      .inner
+      .validate(&Jwt::new(jwt), &*issuer, &options, fail_fast)
+      .napi_result()?;
+    Ok(NapiCredential {
+      inner: decoded.credential,
+    })
+  }
+}
+
+/// Validates JWT presentations signed with EdDSA.
+///
+/// The credentials contained in the presentation must be validated separately with a {@link JwtCredentialValidator}.
+#[napi(js_name = "JwtPresentationValidator")]
+pub struct NapiJwtPresentationValidator {
+  inner: JwtPresentationValidator<EdDSAJwsVerifier>,
+}
+
+#[napi(js_class = "JwtPresentationValidator")]
+impl NapiJwtPresentationValidator {
+  /// Creates a new {@link JwtPresentationValidator}.
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Self {
+      inner: JwtPresentationValidator::with_signature_verifier(EdDSAJwsVerifier::default()),
+    }
+  }
+
+  /// Validates the presentation `jwt` signed by `holder` according to `options`, a JSON representation of
+  /// `JwtPresentationValidationOptions`, and returns the decoded presentation.
+  #[napi]
+  pub fn validate(&self, jwt: String, holder: &NapiIotaDocument, options: Option<Value>) -> Result<NapiPresentation> {
+    let options: JwtPresentationValidationOptions = options
+      .map(JwtPresentationValidationOptions::from_json_value)
+      .transpose()
+      .napi_result()?
+      .unwrap_or_default();
+    let holder: RwLockReadGuard<'_, IotaDocument> = holder.read()?;
+
+    let decoded: DecodedJwtPresentation<Jwt> = self.inner.validate(&Jwt::new(jwt), &*holder, &options).napi_result()?;
+    Ok(NapiPresentation {
+      inner: decoded.presentation,
+    })
+  }
+}
@@ -0,0 +1,181 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use identity_iota::core::FromJson;
+use identity_iota::core::ToJson;
+use identity_iota::credential::Credential;
+use identity_iota::credential::Jwt;
+use identity_iota::credential::JwtPresentationOptions;
+use identity_iota::credential::Presentation;
+use identity_iota::iota::IotaDocument;
+use identity_iota::iota::NetworkName;
+use identity_iota::storage::JwkDocumentExt;
+use identity_iota::storage::JwkMemStore;
+use identity_iota::storage::JwsSignatureOptions;
+use identity_iota::verification::jws::JwsAlgorithm;
+use identity_iota::verification::MethodScope;
+use napi::Env;
+use napi::JsObject;
+use napi_derive::napi;
+use serde_json::Value;
+use tokio::sync::RwLock;
+use tokio::sync::RwLockReadGuard;
+
+use crate::credential::NapiCredential;
+use crate::credential::NapiPresentation;
+use crate::error::lock_error;
+use crate::error::NapiResult;
+use crate::error::Result;
+use crate::storage::MemStorage;
+use crate::storage::NapiStorage;
+
+/// A DID Document adhering to the IOTA DID method specification.
+///
+/// Asynchronous operations modifying the document lock it until they complete.
+#[napi(js_name = "IotaDocument")]
+pub struct NapiIotaDocument {
+  pub(crate) inner: Arc<RwLock<IotaDocument>>,
+}
+
+#[napi(js_class = "IotaDocument")]
+impl NapiIotaDocument {
+  /// Constructs an empty DID Document with a placeholder identifier for the given `network`.
+  #[napi(constructor)]
+  pub fn new(network: String) -> Result<Self> {
+    let network_name: NetworkName = NetworkName::try_from(network).napi_result()?;
+    Ok(Self::from(IotaDocument::new(&network_name)))
+  }
+
+  /// Deserializes an {@link IotaDocument} from its JSON representation.
+  #[napi(factory, js_name = "fromJSON")]
+  pub fn from_json(json: Value) -> Result<Self> {
+    IotaDocument::from_json_value(json).map(Self::from).napi_result()
+  }
+
+  /// Returns the DID of the document.
+  #[napi]
+  pub fn id(&self) -> Result<String> {
+    Ok(self.read()?.id().to_string())
+  }
+
+  /// Returns the JSON representation of the document.
+  #[napi(js_name = "toJSON")]
+  pub fn to_json(&self) -> Result<Value> {
+    self.read()?.to_json_value().napi_result()
+  }
+
+  /// Generates a new Ed25519 key in `storage` and inserts a verification method for it into the document, returning
+  /// the fragment of the method. A random fragment is generated if `fragment` is not given.
+  #[napi(ts_return_type = "Promise<string>")]
+  pub fn generate_method(&self, env: Env, storage: &NapiStorage, fragment: Option<String>) -> Result<JsObject> {
+    let document: Arc<RwLock<IotaDocument>> = Arc::clone(&self.inner);
+    let storage: Arc<MemStorage> = Arc::clone(&storage.inner);
+    env.spawn_future(async move {
+      document
+        .write()
+        .await
+        .generate_method(
+          &storage,
+          JwkMemStore::ED25519_KEY_TYPE,
+          JwsAlgorithm::EdDSA,
+          fragment.as_deref(),
+          MethodScope::VerificationMethod,
+        )
+        .await
+        .napi_result()
+    })
+  }
+
+  /// Signs `credential` with the verification method identified by `fragment`, whose key is held by `storage`, and
+  /// returns the credential JWT.
+  ///
+  /// `options` is the JSON representation of `JwsSignatureOptions`.
+  #[napi(ts_return_type = "Promise<string>")]
+  pub fn create_credential_jwt(
+    &self,
+    env: Env,
+    storage: &NapiStorage,
+    fragment: String,
+    credential: &NapiCredential,
+    options: Option<Value>,
+  ) -> Result<JsObject> {
+    let document: Arc<RwLock<IotaDocument>> = Arc::clone(&self.inner);
+    let storage: Arc<MemStorage> = Arc::clone(&storage.inner);
+    let credential: Credential = credential.inner.clone();
+    let options: JwsSignatureOptions = signature_options(options)?;
+    env.spawn_future(async move {
+      let jwt: Jwt = document
+        .read()
+        .await
+        .create_credential_jwt(&credential, &storage, &fragment, &options, None)
+        .await
+        .napi_result()?;
+      Ok(String::from(jwt))
+    })
+  }
+
+  /// Signs `presentation` with the verification method identified by `fragment`, whose key is held by `storage`,
+  /// and returns the presentation JWT.
+  ///
+  /// `signatureOptions` and `presentationOptions` are the JSON representations of `JwsSignatureOptions` and
+  /// `JwtPresentationOptions`.
+  #[napi(ts_return_type = "Promise<string>")]
+  pub fn create_presentation_jwt(
+    &self,
+    env: Env,
+    storage: &NapiStorage,
+    fragment: String,
+    presentation: &NapiPresentation,
+    signature_options: Option<Value>,
+    presentation_options: Option<Value>,
+  ) -> Result<JsObject> {
+    let document: Arc<RwLock<IotaDocument>> = Arc::clone(&self.inner);
+    let storage: Arc<MemStorage> = Arc::clone(&storage.inner);
+    let presentation: Presentation<Jwt> = presentation.inner.clone();
+    let signature_options: JwsSignatureOptions = self::signature_options(signature_options)?;
+    let presentation_options: JwtPresentationOptions = presentation_options
+      .map(JwtPresentationOptions::from_json_value)
+      .transpose()
+      .napi_result()?
+      .unwrap_or_default();
+    env.spawn_future(async move {
+      let jwt: Jwt = document
+        .read()
+        .await
+        .create_presentation_jwt(
+          &presentation,
+          &storage,
+          &fragment,
+          &signature_options,
+          &presentation_options,
+        )
+        .await
+        .napi_result()?;
+      Ok(String::from(jwt))
+    })
+  }
+}
+
+impl NapiIotaDocument {
+  pub(crate) fn read(&self) -> Result<RwLockReadGuard<'_, IotaDocument>> {
+    self.inner.try_read().map_err(|_| lock_error("IotaDocument"))
+  }
+}
+
+impl From<IotaDocument> for NapiIotaDocument {
+  fn from(document: IotaDocument) -> Self {
+    Self {
+      inner: Arc::new(RwLock::new(document)),
+    }
+  }
+}
+
+fn signature_options(options: Option<Value>) -> Result<JwsSignatureOptions> {
+  options
+    .map(JwsSignatureOptions::from_json_value)
+    .transpose()
+    .napi_result()
+    .map(Option::unwrap_or_default)
+}
@@ -0,0 +1,47 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::error::Error as StdError;
+use std::fmt::Write;
+
+use napi::Error;
+use napi::Status;
+
+/// Convenience wrapper for `Result<T, napi::Error>`.
+pub type Result<T> = napi::Result<T>;
+
+/// Converts `error` into a JavaScript error whose message includes the chain of sources of `error`.
+pub fn napi_error<E>(error: E) -> Error
+where
+  E: StdError,
+{
+  let mut message: String = error.to_string();
+  let mut source: Option<&dyn StdError> = error.source();
+  while let Some(cause) = source {
+    let _ = write!(message, " -> {cause}");
+    source = cause.source();
+  }
+  Error::new(Status::GenericFailure, message)
+}
+
+/// Convenience trait to simplify `result.map_err(napi_error)` to `result.napi_result()`.
+pub trait NapiResult<T> {
+  fn napi_result(self) -> Result<T>;
+}
+
+impl<T, E> NapiResult<T> for std::result::Result<T, E>
+where
+  E: StdError,
+{
+  fn napi_result(self) -> Result<T> {
+    self.map_err(napi_error)
+  }
+}
+
+/// Returns the error raised when an object is accessed while an asynchronous operation is using it.
+pub(crate) fn lock_error(name: &str) -> Error {
+  Error::new(
+    Status::GenericFailure,
+    format!("{name} is in use by a pending operation; await it before using the {name} again"),
+  )
+}
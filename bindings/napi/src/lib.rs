@@ -0,0 +1,16 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Native Node.js bindings for IOTA Identity, built with [napi-rs](https://napi.rs).
+//!
+//! Unlike the Wasm bindings, asynchronous operations such as signing and resolution run on a multi-threaded Tokio
+//! runtime outside of the JavaScript main thread.
+
+#![allow(clippy::new_without_default)]
+
+pub mod client;
+pub mod credential;
+pub mod document;
+pub mod error;
+pub mod resolver;
+pub mod storage;
@@ -0,0 +1,46 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use identity_iota::did::CoreDID;
+use identity_iota::iota::IotaDocument;
+use identity_iota::resolver::Resolver;
+use napi::Env;
+use napi::JsObject;
+use napi_derive::napi;
+
+use crate::client::NapiIotaClient;
+use crate::document::NapiIotaDocument;
+use crate::error::NapiResult;
+use crate::error::Result;
+
+/// Resolves DID Documents of the IOTA DID method.
+#[napi(js_name = "Resolver")]
+pub struct NapiResolver {
+  inner: Arc<Resolver<IotaDocument>>,
+}
+
+#[napi(js_class = "Resolver")]
+impl NapiResolver {
+  /// Constructs a new {@link Resolver} resolving IOTA DIDs with `client`.
+  #[napi(constructor)]
+  pub fn new(client: &NapiIotaClient) -> Self {
+    let mut resolver: Resolver<IotaDocument> = Resolver::new();
+    resolver.attach_iota_handler(client.inner.clone());
+    Self {
+      inner: Arc::new(resolver),
+    }
+  }
+
+  /// Fetches the DID Document of `did`.
+  #[napi(ts_return_type = "Promise<IotaDocument>")]
+  pub fn resolve(&self, env: Env, did: String) -> Result<JsObject> {
+    let resolver: Arc<Resolver<IotaDocument>> = Arc::clone(&self.inner);
+    let did: CoreDID = CoreDID::parse(did).napi_result()?;
+    env.spawn_future(async move {
+      let document: IotaDocument = resolver.resolve(&did).await.napi_result()?;
+      Ok(NapiIotaDocument::from(document))
+    })
+  }
+}
@@ -0,0 +1,42 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use identity_iota::storage::JwkMemStore;
+use identity_iota::storage::KeyIdMemStore;
+use identity_iota::storage::Storage;
+use napi::Env;
+use napi::JsObject;
+use napi_derive::napi;
+
+use crate::error::Result;
+
+/// The storage used by the native bindings, holding keys in memory.
+pub(crate) type MemStorage = Storage<JwkMemStore, KeyIdMemStore>;
+
+/// A storage holding the private keys of verification methods in memory.
+///
+/// The keys are lost when the storage is dropped, so it is intended for testing and short-lived processes.
+#[napi(js_name = "Storage")]
+pub struct NapiStorage {
+  pub(crate) inner: Arc<MemStorage>,
+}
+
+#[napi(js_class = "Storage")]
+impl NapiStorage {
+  /// Creates a new, empty {@link Storage}.
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Self {
+      inner: Arc::new(Storage::new(JwkMemStore::new(), KeyIdMemStore::new())),
+    }
+  }
+
+  /// Returns the number of keys held by the storage.
+  #[napi(ts_return_type = "Promise<number>")]
+  pub fn count(&self, env: Env) -> Result<JsObject> {
+    let storage: Arc<MemStorage> = Arc::clone(&self.inner);
+    env.spawn_future(async move { Ok(storage.key_storage().count().await as u32) })
+  }
+}
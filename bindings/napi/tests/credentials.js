@@ -0,0 +1,68 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+const assert = require("assert");
+const {
+    Credential,
+    IotaDocument,
+    JwtCredentialValidator,
+    JwtPresentationValidator,
+    Presentation,
+    Storage,
+} = require("../index");
+
+describe("IotaDocument", function() {
+    describe("#generateMethod", function() {
+        it("should insert a method backed by the storage", async () => {
+            const storage = new Storage();
+            const document = new IotaDocument("tst");
+            const fragment = await document.generateMethod(storage, "key-1");
+
+            assert.deepStrictEqual(fragment, "key-1");
+            assert.deepStrictEqual(await storage.count(), 1);
+            assert.deepStrictEqual(document.toJSON().doc.verificationMethod.length, 1);
+
+            const roundtrip = IotaDocument.fromJSON(document.toJSON());
+            assert.deepStrictEqual(roundtrip.id(), document.id());
+        });
+    });
+});
+
+describe("JwtCredentialValidator", function() {
+    describe("#validate", function() {
+        it("should validate credentials and presentations", async () => {
+            const storage = new Storage();
+            const issuer = new IotaDocument("tst");
+            const issuerFragment = await issuer.generateMethod(storage);
+            const holder = new IotaDocument("tst2");
+            const holderFragment = await holder.generateMethod(storage);
+
+            const credential = new Credential({
+                "@context": "https://www.w3.org/2018/credentials/v1",
+                type: ["VerifiableCredential", "UniversityDegreeCredential"],
+                issuer: issuer.id(),
+                issuanceDate: "2010-01-01T00:00:00Z",
+                credentialSubject: {
+                    id: holder.id(),
+                    degree: "Bachelor of Science and Arts",
+                },
+            });
+            const credentialJwt = await issuer.createCredentialJwt(storage, issuerFragment, credential);
+
+            const decoded = new JwtCredentialValidator().validate(credentialJwt, issuer);
+            assert.deepStrictEqual(decoded.issuer(), issuer.id());
+            assert.throws(() => new JwtCredentialValidator().validate(credentialJwt, holder));
+
+            const presentation = new Presentation({
+                "@context": "https://www.w3.org/2018/credentials/v1",
+                type: "VerifiablePresentation",
+                verifiableCredential: [credentialJwt],
+                holder: holder.id(),
+            });
+            const presentationJwt = await holder.createPresentationJwt(storage, holderFragment, presentation);
+
+            const decodedPresentation = new JwtPresentationValidator().validate(presentationJwt, holder);
+            assert.deepStrictEqual(decodedPresentation.verifiableCredential(), [credentialJwt]);
+        });
+    });
+});
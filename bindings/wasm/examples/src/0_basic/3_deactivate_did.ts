@@ -58,7 +58,7 @@ export async function deactivateIdentity() {
     }
 
     // Re-activate the DID by publishing a valid DID document.
-    let reactivatedOutput: AliasOutput = await didClient.updateDidOutput(document);
+    let reactivatedOutput: AliasOutput = await didClient.reactivateDidOutput(document);
 
     // Increase the storage deposit to the minimum again, if it was reclaimed during deactivation.
     reactivatedOutput = await client.buildAliasOutput({
@@ -108,7 +108,8 @@ export async function domainLinkage() {
     // → Case 1: starting from domain
     // =====================================================
 
-    // Fetch the DID Configuration resource (For example using the Fetch API).
+    // Fetch the DID Configuration resource (For example using `fetchDomainLinkageConfiguration`, which accepts
+    // a custom `fetch` function).
     // Note that according to the specs, the DID Configuration resource must exist
     // at the origin's root, well-known Resource directory.
     const _configurationUrl = `${domainFoo}/.well-known/did-configuration.json")`;
@@ -147,7 +148,8 @@ export async function domainLinkage() {
     // Note that in this example only the first entry in the service is validated.
     let domains: string[] = linkedDomainServices[0].domains();
 
-    // Fetch the DID Configuration resource (For example using the Fetch API).
+    // Fetch the DID Configuration resource (For example using `fetchDomainLinkageConfiguration`, which accepts
+    // a custom `fetch` function).
     // Note that according to the specs, the DID Configuration resource must exist
     // at the origin's root, Well-Known Resource directory.
     const __configurationUrl = `${domains[0]}/.well-known/did-configuration.json")`;
@@ -0,0 +1,44 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { DomainLinkageConfiguration } from "~identity_wasm";
+import { FetchLike, resolveFetch } from "./fetch";
+
+/** The maximum size of a DID Configuration resource fetched by {@link fetchDomainLinkageConfiguration}. */
+const MAX_CONFIGURATION_SIZE = 1_048_576;
+
+/** Options for {@link fetchDomainLinkageConfiguration}. */
+export interface FetchDomainLinkageConfigurationOptions {
+    /** The function used for the request, by default the one set with `setDefaultFetch` or the global `fetch`. */
+    fetch?: FetchLike;
+}
+
+/** Fetches the DID Configuration resource of `domain` from its well-known location
+ * "`domain`/.well-known/did-configuration.json".
+ *
+ * `domain` must be an `https` origin without path, query or fragment. Redirects are not followed and resources larger
+ * than 1 MiB are rejected.
+ */
+export async function fetchDomainLinkageConfiguration(
+    domain: string,
+    options: FetchDomainLinkageConfigurationOptions = {},
+): Promise<DomainLinkageConfiguration> {
+    const origin = new URL(domain);
+    if (origin.protocol !== "https:") {
+        throw new Error("domain does not use `https` protocol");
+    }
+    if ((origin.pathname !== "/" && origin.pathname !== "") || origin.search || origin.hash) {
+        throw new Error("domain must not include any path, query or fragment");
+    }
+
+    const url = new URL("/.well-known/did-configuration.json", origin).toString();
+    const response = await resolveFetch(options.fetch)(url, { method: "GET", redirect: "error" });
+    if (!response.ok) {
+        throw new Error(`failed to fetch the DID Configuration resource: ${response.status} ${response.statusText}`);
+    }
+    const body = await response.text();
+    if (body.length > MAX_CONFIGURATION_SIZE) {
+        throw new Error("domain linkage configuration can not exceed 1 MiB");
+    }
+    return DomainLinkageConfiguration.fromJSON(JSON.parse(body));
+}
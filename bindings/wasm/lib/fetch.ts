@@ -0,0 +1,25 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/** A function compatible with the global `fetch`, e.g. one routing requests through a proxy, adding authentication
+ * or answering from an offline cache. */
+export type FetchLike = (input: string, init?: RequestInit) => Promise<Response>;
+
+let defaultFetch: FetchLike | undefined;
+
+/** Sets the function used for the HTTP requests of this library that are not passed a `fetch` function explicitly.
+ *
+ * Passing `undefined` restores the global `fetch`.
+ */
+export function setDefaultFetch(fetch?: FetchLike) {
+    defaultFetch = fetch;
+}
+
+/** Returns `fetch` if set, otherwise the function set with {@link setDefaultFetch} or the global `fetch`. */
+export function resolveFetch(fetch?: FetchLike): FetchLike {
+    const resolved = fetch ?? defaultFetch ?? globalThis.fetch?.bind(globalThis);
+    if (!resolved) {
+        throw new Error("no fetch implementation available, pass one or set a default with `setDefaultFetch`");
+    }
+    return resolved;
+}
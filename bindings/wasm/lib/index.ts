@@ -2,9 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import "./append_functions.js";
+export * from "./domain_linkage";
+export * from "./fetch";
 export * from "./iota_identity_client.js";
 export * from "./jose";
 export * from "./jwk_storage";
 export * from "./key_id_storage";
+export * from "./webauthn_jwk_storage";
 
 export * from "~identity_wasm";
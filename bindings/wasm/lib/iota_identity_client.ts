@@ -18,24 +18,92 @@ import {
     UTXOInput,
 } from "~sdk-wasm";
 
-/** Provides operations for IOTA DID Documents with Alias Outputs. */
+/** Exponential backoff between the attempts of a failed node request. */
+export interface BackoffOptions {
+    /** The delay in milliseconds before the first retry. Defaults to 500. */
+    initialDelay?: number;
+    /** The maximum delay in milliseconds between two attempts. Defaults to 10000. */
+    maxDelay?: number;
+    /** The factor the delay is multiplied with after each retry. Defaults to 2. */
+    factor?: number;
+}
+
+/** Options controlling how an {@link IotaIdentityClient} handles failed node requests. */
+export interface IotaIdentityClientOptions {
+    /** URLs of additional nodes to fail over to when a request fails, each of which is connected to through its own
+     * `Client`. */
+    endpoints?: string[];
+    /** Additional clients to fail over to when a request fails, tried after the clients created from `endpoints`. */
+    fallbackClients?: Client[];
+    /** How often a failed request is retried, switching to the next client on every retry. Defaults to 0. */
+    maxRetries?: number;
+    /** The delay between retries. */
+    backoff?: BackoffOptions;
+    /** Decides whether a request that failed with `error` may be retried. By default all errors are retried. */
+    isRetryable?: (error: unknown) => boolean;
+}
+
+/** Provides operations for IOTA DID Documents with Alias Outputs.
+ *
+ * Requests to the node are retried and fail over to the other configured clients according to the
+ * {@link IotaIdentityClientOptions}. Blocks are only posted once, since re-posting a transaction could conflict with an
+ * earlier attempt that was accepted by the node nonetheless; waiting for their inclusion is retried.
+ */
 export class IotaIdentityClient implements IIotaIdentityClient {
+    /** The primary client. */
     client: Client;
+    private clients: Client[];
+    private active: number;
+    private maxRetries: number;
+    private backoff: Required<BackoffOptions>;
+    private isRetryable: (error: unknown) => boolean;
 
-    constructor(client: Client) {
+    constructor(client: Client, options: IotaIdentityClientOptions = {}) {
         this.client = client;
+        this.clients = [
+            client,
+            ...(options.endpoints ?? []).map((endpoint) => new Client({ nodes: [endpoint] })),
+            ...(options.fallbackClients ?? []),
+        ];
+        this.active = 0;
+        this.maxRetries = options.maxRetries ?? 0;
+        this.backoff = {
+            initialDelay: options.backoff?.initialDelay ?? 500,
+            maxDelay: options.backoff?.maxDelay ?? 10000,
+            factor: options.backoff?.factor ?? 2,
+        };
+        this.isRetryable = options.isRetryable ?? (() => true);
+    }
+
+    /** Runs `request` against the active client, retrying it with the next client on failure. */
+    private async withRetry<T>(request: (client: Client) => Promise<T>): Promise<T> {
+        let delay = this.backoff.initialDelay;
+        for (let attempt = 0;; attempt++) {
+            try {
+                return await request(this.clients[this.active]);
+            } catch (error) {
+                if (attempt >= this.maxRetries || !this.isRetryable(error)) {
+                    throw error;
+                }
+                // Fail over to the next client, which remains active for subsequent requests.
+                this.active = (this.active + 1) % this.clients.length;
+                await new Promise((resolve) => setTimeout(resolve, delay));
+                delay = Math.min(delay * this.backoff.factor, this.backoff.maxDelay);
+            }
+        }
     }
 
     async getNetworkHrp() {
-        return await this.client.getBech32Hrp();
+        return await this.withRetry((client) => client.getBech32Hrp());
     }
 
     async getAliasOutput(aliasId: string) {
-        // Lookup latest OutputId from the indexer plugin.
-        const outputId = await this.client.aliasOutputId(aliasId);
-
-        // Fetch AliasOutput.
-        const outputResponse: OutputResponse = await this.client.getOutput(outputId);
+        // Lookup latest OutputId from the indexer plugin and fetch the AliasOutput from the same node.
+        const [outputId, outputResponse] = await this.withRetry(async (client) => {
+            const outputId = await client.aliasOutputId(aliasId);
+            const outputResponse: OutputResponse = await client.getOutput(outputId);
+            return [outputId, outputResponse] as const;
+        });
         const output = outputResponse.output;
         if (output.getType() != OutputType.Alias) {
             throw new Error("AliasId '" + aliasId + "' returned incorrect output type '" + output.getType() + "'");
@@ -47,16 +115,16 @@ export class IotaIdentityClient implements IIotaIdentityClient {
     }
 
     async getRentStructure(): Promise<IRent> {
-        const info: INodeInfoWrapper = await this.client.getInfo();
+        const info: INodeInfoWrapper = await this.withRetry((client) => client.getInfo());
         return info.nodeInfo.protocol.rentStructure;
     }
 
     async getTokenSupply(): Promise<string> {
-        return await this.client.getTokenSupply();
+        return await this.withRetry((client) => client.getTokenSupply());
     }
 
     async getProtocolParameters(): Promise<INodeInfoProtocol> {
-        const protocolParameters: INodeInfoProtocol = await this.client.getProtocolParameters();
+        const protocolParameters: INodeInfoProtocol = await this.withRetry((client) => client.getProtocolParameters());
         return protocolParameters;
     }
 
@@ -76,7 +144,7 @@ export class IotaIdentityClient implements IIotaIdentityClient {
             document,
             rentStructure,
         );
-        return await this.client.buildAliasOutput(aliasOutputParams);
+        return await this.withRetry((client) => client.buildAliasOutput(aliasOutputParams));
     }
 
     /** Fetches the associated Alias Output and updates it with `document` in its state metadata.
@@ -87,7 +155,7 @@ export class IotaIdentityClient implements IIotaIdentityClient {
      */
     async updateDidOutput(document: IotaDocument): Promise<AliasOutput> {
         const aliasOutputParams: AliasOutputBuilderParams = await IotaIdentityClientExt.updateDidOutput(this, document);
-        return await this.client.buildAliasOutput(aliasOutputParams);
+        return await this.withRetry((client) => client.buildAliasOutput(aliasOutputParams));
     }
 
     /** Removes the DID document from the state metadata of its Alias Output,
@@ -101,7 +169,23 @@ export class IotaIdentityClient implements IIotaIdentityClient {
      */
     async deactivateDidOutput(did: IotaDID): Promise<AliasOutput> {
         const aliasOutputParams: AliasOutputBuilderParams = await IotaIdentityClientExt.deactivateDidOutput(this, did);
-        return await this.client.buildAliasOutput(aliasOutputParams);
+        return await this.withRetry((client) => client.buildAliasOutput(aliasOutputParams));
+    }
+
+    /** Fetches the Alias Output of a deactivated DID and re-activates it by setting `document`
+     * as its state metadata. The storage deposit on the output is left unchanged. If it was
+     * reallocated after the deactivation, the amount should be increased manually.
+     *
+     * Fails if the published document is not deactivated.
+     *
+     * NOTE: this does *not* publish the updated Alias Output.
+     */
+    async reactivateDidOutput(document: IotaDocument): Promise<AliasOutput> {
+        const aliasOutputParams: AliasOutputBuilderParams = await IotaIdentityClientExt.reactivateDidOutput(
+            this,
+            document,
+        );
+        return await this.withRetry((client) => client.buildAliasOutput(aliasOutputParams));
     }
 
     /** Resolve a {@link IotaDocument}. Returns an empty, deactivated document if the state
@@ -111,10 +195,17 @@ export class IotaIdentityClient implements IIotaIdentityClient {
         return await IotaIdentityClientExt.resolveDid(this, did);
     }
 
+    /** Resolve a {@link IotaDocument}, failing instead of returning an empty document if the DID
+     * is deactivated.
+     */
+    async resolveActiveDid(did: IotaDID): Promise<IotaDocument> {
+        return await IotaIdentityClientExt.resolveActiveDid(this, did);
+    }
+
     /** Fetches the Alias Output associated with the given DID. */
     async resolveDidOutput(did: IotaDID): Promise<AliasOutput> {
         const aliasOutputParams: AliasOutputBuilderParams = await IotaIdentityClientExt.resolveDidOutput(this, did);
-        return await this.client.buildAliasOutput(aliasOutputParams);
+        return await this.withRetry((client) => client.buildAliasOutput(aliasOutputParams));
     }
 
     /** Publish the given `aliasOutput` with the provided `secretManager`, and returns
@@ -129,10 +220,10 @@ export class IotaIdentityClient implements IIotaIdentityClient {
     async publishDidOutput(secretManager: SecretManagerType, aliasOutput: AliasOutput): Promise<IotaDocument> {
         const networkHrp = await this.getNetworkHrp();
         // Publish block.
-        const [blockId, block] = await this.client.buildAndPostBlock(secretManager, {
+        const [blockId, block] = await this.clients[this.active].buildAndPostBlock(secretManager, {
             outputs: [aliasOutput],
         });
-        await this.client.retryUntilIncluded(blockId);
+        await this.withRetry((client) => client.retryUntilIncluded(blockId));
 
         // Extract document with computed AliasId.
         const documents = IotaDocument.unpackFromBlock(networkHrp, block);
@@ -165,22 +256,24 @@ export class IotaIdentityClient implements IIotaIdentityClient {
         const aliasInput: UTXOInput = UTXOInput.fromOutputId(outputId);
 
         // Send funds to the address.
-        const basicOutput = await this.client.buildBasicOutput({
-            amount: aliasOutput.getAmount(),
-            nativeTokens: aliasOutput.getNativeTokens(),
-            unlockConditions: [
-                new AddressUnlockCondition(address),
-            ],
-        });
+        const basicOutput = await this.withRetry((client) =>
+            client.buildBasicOutput({
+                amount: aliasOutput.getAmount(),
+                nativeTokens: aliasOutput.getNativeTokens(),
+                unlockConditions: [
+                    new AddressUnlockCondition(address),
+                ],
+            })
+        );
 
         // Publish block.
-        const [blockId, _block] = await this.client.buildAndPostBlock(secretManager, {
+        const [blockId, _block] = await this.clients[this.active].buildAndPostBlock(secretManager, {
             inputs: [aliasInput],
             outputs: [basicOutput],
             burn: {
                 aliases: [aliasId],
             },
         });
-        await this.client.retryUntilIncluded(blockId);
+        await this.withRetry((client) => client.retryUntilIncluded(blockId));
     }
 }
@@ -1,5 +1,14 @@
 import * as ed from "@noble/ed25519";
-import { decodeB64, encodeB64, Jwk, JwkGenOutput, JwkStorage, ProofAlgorithm, ProofUpdateCtx } from "~identity_wasm";
+import {
+    decodeB64,
+    encodeB64,
+    Jwk,
+    JwkGenOutput,
+    JwkStorage,
+    ProofAlgorithm,
+    ProofUpdateCtx,
+    SignRequest,
+} from "~identity_wasm";
 import { EdCurve, JwkType, JwsAlgorithm } from "./jose";
 
 type Ed25519PrivateKey = Uint8Array;
@@ -80,6 +89,14 @@ export class JwkMemStore implements JwkStorage {
         return keyId;
     }
 
+    public async insertMany(jwks: Array<Jwk>): Promise<Array<string>> {
+        return Promise.all(jwks.map((jwk) => this.insert(jwk)));
+    }
+
+    public async signMany(requests: Array<SignRequest>): Promise<Array<Uint8Array>> {
+        return Promise.all(requests.map((request) => this.sign(request.keyId, request.data, request.publicKey)));
+    }
+
     public async delete(keyId: string): Promise<void> {
         this._keys.delete(keyId);
     }
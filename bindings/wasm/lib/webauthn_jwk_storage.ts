@@ -0,0 +1,181 @@
+import { decodeB64, encodeB64, Jwk, JwkGenOutput, JwkStorage } from "~identity_wasm";
+import { JwkType, JwsAlgorithm } from "./jose";
+
+/** The COSE algorithm identifier of ES256. */
+const COSE_ALG_ES256 = -7;
+
+/** Options for the WebAuthn ceremonies performed by a `WebAuthnJwkStore`. */
+export interface WebAuthnJwkStoreOptions {
+    /** The relying party the credentials are scoped to. */
+    rp: PublicKeyCredentialRpEntity;
+    /** The user account new credentials are created for. */
+    user: PublicKeyCredentialUserEntity;
+    /** Whether the authenticator must verify the user, e.g. through a PIN or biometrics. Defaults to `preferred`. */
+    userVerification?: UserVerificationRequirement;
+    /** The time in milliseconds the user has to complete a ceremony. */
+    timeout?: number;
+}
+
+/** A `JwkStorage` whose P-256 keys are held by WebAuthn authenticators, such as platform authenticators storing
+ * passkeys.
+ *
+ * Keys are created with `navigator.credentials.create` and used with `navigator.credentials.get`, so the private keys
+ * never leave the authenticator. The key id of a key is the base64url encoded credential id, which allows adding
+ * methods for existing credentials through `insertWebAuthnMethod` of the DID documents.
+ *
+ * Authenticators do not sign the JWS signing input directly, but an assertion over a challenge derived from it.
+ * Signatures created by this storage must therefore be verified with the `WebAuthnJwsVerifier`.
+ *
+ * Only usable in browsers supporting WebAuthn. */
+export class WebAuthnJwkStore implements JwkStorage {
+    private _options: WebAuthnJwkStoreOptions;
+    /** The base64url encoded ids of the credentials known to this storage. */
+    private _credentials: Set<string>;
+
+    /** Creates a new `WebAuthnJwkStore` aware of the given credentials, e.g. ones created in a previous session. */
+    constructor(options: WebAuthnJwkStoreOptions, credentialIds: string[] = []) {
+        this._options = options;
+        this._credentials = new Set(credentialIds);
+    }
+
+    public static webAuthnKeyType(): string {
+        return "WebAuthn";
+    }
+
+    /** Returns the base64url encoded ids of the credentials known to this storage. */
+    public credentialIds(): string[] {
+        return Array.from(this._credentials);
+    }
+
+    public async generate(keyType: string, algorithm: JwsAlgorithm): Promise<JwkGenOutput> {
+        if (keyType !== WebAuthnJwkStore.webAuthnKeyType()) {
+            throw new Error(`unsupported key type ${keyType}`);
+        }
+
+        if (algorithm !== JwsAlgorithm.ES256) {
+            throw new Error(`unsupported algorithm`);
+        }
+
+        const credential = await navigator.credentials.create({
+            publicKey: {
+                rp: this._options.rp,
+                user: this._options.user,
+                challenge: crypto.getRandomValues(new Uint8Array(32)),
+                pubKeyCredParams: [{ type: "public-key", alg: COSE_ALG_ES256 }],
+                authenticatorSelection: {
+                    residentKey: "preferred",
+                    userVerification: this._options.userVerification,
+                },
+                timeout: this._options.timeout,
+            },
+        }) as PublicKeyCredential | null;
+        if (!credential) {
+            throw new Error("no WebAuthn credential was created");
+        }
+
+        const response = credential.response as AuthenticatorAttestationResponse;
+        const spki = response.getPublicKey();
+        if (!spki || response.getPublicKeyAlgorithm() !== COSE_ALG_ES256) {
+            throw new Error("the authenticator did not create a P-256 key");
+        }
+        const publicKey = await crypto.subtle.importKey("spki", spki, { name: "ECDSA", namedCurve: "P-256" }, true, [
+            "verify",
+        ]);
+        const { x, y } = await crypto.subtle.exportKey("jwk", publicKey);
+
+        const keyId = encodeB64(new Uint8Array(credential.rawId));
+        this._credentials.add(keyId);
+
+        return new JwkGenOutput(
+            keyId,
+            new Jwk({
+                "kty": JwkType.Ec,
+                "crv": "P-256",
+                x: x!,
+                y: y!,
+                alg: algorithm,
+            }),
+        );
+    }
+
+    public async sign(keyId: string, data: Uint8Array, publicKey: Jwk): Promise<Uint8Array> {
+        if (publicKey.alg() !== JwsAlgorithm.ES256) {
+            throw new Error("unsupported JWS algorithm");
+        }
+
+        const challenge = new Uint8Array(await crypto.subtle.digest("SHA-256", data));
+        const credential = await navigator.credentials.get({
+            publicKey: {
+                challenge,
+                rpId: this._options.rp.id,
+                allowCredentials: [{ type: "public-key", id: decodeB64(new TextEncoder().encode(keyId)) }],
+                userVerification: this._options.userVerification,
+                timeout: this._options.timeout,
+            },
+        }) as PublicKeyCredential | null;
+        if (!credential) {
+            throw new Error(`key with id ${keyId} not found`);
+        }
+
+        const response = credential.response as AuthenticatorAssertionResponse;
+        return encodeAssertion(
+            new Uint8Array(response.authenticatorData),
+            new Uint8Array(response.clientDataJSON),
+            derToRawSignature(new Uint8Array(response.signature)),
+        );
+    }
+
+    public async insert(_jwk: Jwk): Promise<string> {
+        throw new Error("private keys cannot be inserted into a WebAuthn authenticator");
+    }
+
+    /** Forgets the credential identified by `keyId`.
+     *
+     * The credential itself can only be removed by the user through the authenticator. */
+    public async delete(keyId: string): Promise<void> {
+        this._credentials.delete(keyId);
+    }
+
+    public async exists(keyId: string): Promise<boolean> {
+        return this._credentials.has(keyId);
+    }
+}
+
+// Encodes an assertion into the JWS signature format expected by the `WebAuthnJwsVerifier`: the length-prefixed
+// authenticator data and client data JSON, followed by the raw signature.
+function encodeAssertion(authenticatorData: Uint8Array, clientDataJSON: Uint8Array, signature: Uint8Array): Uint8Array {
+    const encoded = new Uint8Array(4 + authenticatorData.length + clientDataJSON.length + signature.length);
+    const view = new DataView(encoded.buffer);
+    let offset = 0;
+    for (const field of [authenticatorData, clientDataJSON]) {
+        view.setUint16(offset, field.length);
+        encoded.set(field, offset + 2);
+        offset += 2 + field.length;
+    }
+    encoded.set(signature, offset);
+    return encoded;
+}
+
+// Converts a DER encoded ECDSA signature into the concatenated 32 byte `r` and `s` values.
+function derToRawSignature(der: Uint8Array): Uint8Array {
+    // SEQUENCE { INTEGER r, INTEGER s }, each of which is at most 33 bytes long for P-256.
+    if (der[0] !== 0x30) {
+        throw new Error("invalid DER signature");
+    }
+    const raw = new Uint8Array(64);
+    let offset = 2;
+    for (let index = 0; index < 2; index++) {
+        if (der[offset] !== 0x02) {
+            throw new Error("invalid DER signature");
+        }
+        const length = der[offset + 1];
+        let integer = der.subarray(offset + 2, offset + 2 + length);
+        // Strip the leading zero byte of positive integers with the high bit set.
+        while (integer.length > 32 && integer[0] === 0) {
+            integer = integer.subarray(1);
+        }
+        raw.set(integer, index * 32 + 32 - integer.length);
+        offset += 2 + length;
+    }
+    return raw;
+}
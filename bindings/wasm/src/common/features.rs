@@ -0,0 +1,49 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::FeatureReport;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::error::Result;
+use crate::error::WasmResult;
+
+/// Returns a report of the features, signature algorithms, credential formats and deprecated APIs compiled into
+/// these bindings, allowing applications to check their configuration at startup.
+#[wasm_bindgen(js_name = features)]
+pub fn features() -> Result<IFeatureReport> {
+  let report: FeatureReport = identity_iota::features();
+  JsValue::from_serde(&report).map(JsCast::unchecked_into).wasm_result()
+}
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "IFeatureReport")]
+  pub type IFeatureReport;
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const I_FEATURE_REPORT: &'static str = r#"
+/** A deprecated API that is still compiled in, together with its replacement. */
+interface IDeprecatedApi {
+    /** The path of the deprecated item. */
+    readonly path: string;
+    /** The version in which the item was deprecated. */
+    readonly since: string;
+    /** A note on what to use instead. */
+    readonly note: string;
+}
+
+/** The capabilities compiled into the bindings, as returned by {@link features}. */
+interface IFeatureReport {
+    /** The version of the underlying `identity_iota` crate. */
+    readonly version: string;
+    /** The enabled cargo features of the underlying `identity_iota` crate. */
+    readonly features: Array<string>;
+    /** The signature algorithms supported by the built-in key storages and verifiers, as `alg` header values. */
+    readonly algorithms: Array<string>;
+    /** The supported credential formats, e.g. `jwt-vc` or `sd-jwt-vc`. */
+    readonly credentialFormats: Array<string>;
+    /** The deprecated APIs compiled in. */
+    readonly deprecatedApis: Array<IDeprecatedApi>;
+}"#;
@@ -38,6 +38,19 @@ impl WasmTimestamp {
     Self(Timestamp::now_utc())
   }
 
+  /// Creates a new {@link Timestamp} from the given Unix timestamp, in seconds.
+  #[wasm_bindgen(js_name = fromUnix)]
+  pub fn from_unix(seconds: i64) -> Result<WasmTimestamp> {
+    Ok(Self(Timestamp::from_unix(seconds).wasm_result()?))
+  }
+
+  /// Returns the {@link Timestamp} as a Unix timestamp, in seconds.
+  #[wasm_bindgen(js_name = toUnix)]
+  #[allow(clippy::wrong_self_convention)]
+  pub fn to_unix(&self) -> i64 {
+    self.0.to_unix()
+  }
+
   /// Returns the {@link Timestamp} as an RFC 3339 `String`.
   #[wasm_bindgen(js_name = toRFC3339)]
   #[allow(clippy::wrong_self_convention)]
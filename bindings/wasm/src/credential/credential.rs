@@ -239,9 +239,19 @@ impl WasmCredential {
         .unchecked_into::<RecordStringAny>(),
     )
   }
+
+  /// Computes a digest of the content of the {@link Credential}, encoded as base64url.
+  ///
+  /// The `proof` is ignored, such that re-issued credentials, or the same credential secured as a JWT, yield the same
+  /// hash. Can be used to de-duplicate credentials or to detect the same credential across presentations.
+  #[wasm_bindgen(js_name = "contentHash")]
+  pub fn content_hash(&self) -> Result<String> {
+    self.0.content_hash().map(|hash| hash.to_string()).wasm_result()
+  }
 }
 
 impl_wasm_json!(WasmCredential, Credential);
+impl_wasm_json_string!(WasmCredential, Credential);
 impl_wasm_clone!(WasmCredential, Credential);
 
 impl From<Credential> for WasmCredential {
@@ -0,0 +1,171 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::rc::Rc;
+
+use identity_iota::core::OrderedSet;
+use identity_iota::core::Timestamp;
+use identity_iota::core::Url;
+use identity_iota::storage::storage::DomainLinkageGenerator;
+use identity_iota::storage::storage::GeneratedDomainLinkage;
+use identity_iota::storage::storage::JwsSignatureOptions;
+use js_sys::Array;
+use js_sys::Promise;
+use proc_typescript::typescript;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+use crate::common::ArrayString;
+use crate::common::ImportedDocumentLock;
+use crate::credential::WasmDomainLinkageConfiguration;
+use crate::did::IToCoreDocument;
+use crate::did::WasmDIDUrl;
+use crate::error::Result;
+use crate::error::WasmResult;
+use crate::storage::WasmStorage;
+use crate::storage::WasmStorageInner;
+
+/// Creates the DID Configuration resources linking a DID to a set of domains, and keeps the `LinkedDomains`
+/// service of the DID Document in sync with them.
+///
+/// Each generated {@link DomainLinkageConfiguration} must be served at "`origin`/.well-known/did-configuration.json".
+/// See: <https://identity.foundation/.well-known/resources/did-configuration/>
+#[wasm_bindgen(js_name = DomainLinkageGenerator, inspectable)]
+pub struct WasmDomainLinkageGenerator(pub(crate) DomainLinkageGenerator);
+
+#[wasm_bindgen(js_class = DomainLinkageGenerator)]
+impl WasmDomainLinkageGenerator {
+  /// Constructs a new {@link DomainLinkageGenerator}.
+  #[wasm_bindgen(constructor)]
+  pub fn new(values: IDomainLinkageGenerator) -> Result<WasmDomainLinkageGenerator> {
+    let IDomainLinkageGeneratorHelper {
+      domains,
+      expiration_date,
+      issuance_date,
+      signature_options,
+    } = values.into_serde::<IDomainLinkageGeneratorHelper>().wasm_result()?;
+
+    let mut generator: DomainLinkageGenerator =
+      DomainLinkageGenerator::new(domains.into_iter().collect::<OrderedSet<Url>>(), expiration_date);
+    if let Some(issuance_date) = issuance_date {
+      generator = generator.issuance_date(issuance_date);
+    }
+    if let Some(signature_options) = signature_options {
+      generator = generator.signature_options(signature_options);
+    }
+    Ok(Self(generator))
+  }
+
+  /// Returns the domains the generator links to.
+  #[wasm_bindgen]
+  pub fn domains(&self) -> ArrayString {
+    self
+      .0
+      .domains()
+      .iter()
+      .map(|domain| JsValue::from_str(domain.as_str()))
+      .collect::<Array>()
+      .unchecked_into::<ArrayString>()
+  }
+
+  /// Creates a DID Configuration resource for every domain, each containing a single Domain Linkage Credential
+  /// signed with the verification method identified by `fragment`, whose key is held by `storage`.
+  #[wasm_bindgen]
+  pub fn generate(
+    &self,
+    document: &IToCoreDocument,
+    storage: &WasmStorage,
+    fragment: String,
+  ) -> Result<PromiseArrayGeneratedDomainLinkage> {
+    let generator: DomainLinkageGenerator = self.0.clone();
+    let document_lock: ImportedDocumentLock = ImportedDocumentLock::from(document);
+    let storage_clone: Rc<WasmStorageInner> = storage.0.clone();
+    let promise: Promise = future_to_promise(async move {
+      let linkages: Vec<GeneratedDomainLinkage> = match document_lock {
+        ImportedDocumentLock::Core(lock) => generator.generate(&*lock.read().await, &storage_clone, &fragment).await,
+        ImportedDocumentLock::Iota(lock) => generator.generate(&*lock.read().await, &storage_clone, &fragment).await,
+      }
+      .wasm_result()?;
+
+      Ok(
+        linkages
+          .into_iter()
+          .map(WasmGeneratedDomainLinkage)
+          .map(JsValue::from)
+          .collect::<Array>()
+          .into(),
+      )
+    });
+    Ok(promise.unchecked_into())
+  }
+
+  /// Inserts a `LinkedDomains` service with the id `#serviceFragment` listing exactly the domains of this generator
+  /// into `document`, replacing an existing service with the same id. Returns the id of the service.
+  ///
+  /// The document must still be published for the change to take effect.
+  #[wasm_bindgen(js_name = syncLinkedDomainService)]
+  #[allow(non_snake_case)]
+  pub fn sync_linked_domain_service(&self, document: &IToCoreDocument, serviceFragment: &str) -> Result<WasmDIDUrl> {
+    match ImportedDocumentLock::from(document) {
+      ImportedDocumentLock::Core(lock) => self
+        .0
+        .sync_linked_domain_service(&mut *lock.try_write()?, serviceFragment),
+      ImportedDocumentLock::Iota(lock) => self
+        .0
+        .sync_linked_domain_service(&mut *lock.try_write()?, serviceFragment),
+    }
+    .map(WasmDIDUrl::from)
+    .wasm_result()
+  }
+}
+
+impl_wasm_clone!(WasmDomainLinkageGenerator, DomainLinkageGenerator);
+
+/// A DID Configuration resource created by a {@link DomainLinkageGenerator} for a single origin.
+#[wasm_bindgen(js_name = GeneratedDomainLinkage, inspectable)]
+pub struct WasmGeneratedDomainLinkage(pub(crate) GeneratedDomainLinkage);
+
+#[wasm_bindgen(js_class = GeneratedDomainLinkage)]
+impl WasmGeneratedDomainLinkage {
+  /// The origin the configuration links to the DID.
+  #[wasm_bindgen]
+  pub fn origin(&self) -> String {
+    self.0.origin.to_string()
+  }
+
+  /// The DID Configuration resource to be served at "`origin`/.well-known/did-configuration.json".
+  #[wasm_bindgen]
+  pub fn configuration(&self) -> WasmDomainLinkageConfiguration {
+    WasmDomainLinkageConfiguration(self.0.configuration.clone())
+  }
+}
+
+impl_wasm_clone!(WasmGeneratedDomainLinkage, GeneratedDomainLinkage);
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "IDomainLinkageGenerator")]
+  pub type IDomainLinkageGenerator;
+
+  #[wasm_bindgen(typescript_type = "Promise<Array<GeneratedDomainLinkage>>")]
+  pub type PromiseArrayGeneratedDomainLinkage;
+}
+
+/// Fields to create a new {@link DomainLinkageGenerator}.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[typescript(name = "IDomainLinkageGenerator", readonly, optional)]
+struct IDomainLinkageGeneratorHelper {
+  /// The origins to link to the DID, e.g. `https://foo.example.com`.
+  #[typescript(optional = false, type = "Array<string>")]
+  domains: Vec<Url>,
+  /// A timestamp of when the Domain Linkage Credentials should no longer be considered valid.
+  #[typescript(optional = false, name = "expirationDate", type = "Timestamp")]
+  expiration_date: Timestamp,
+  /// A timestamp of when the Domain Linkage Credentials become valid. Defaults to the current datetime.
+  #[typescript(name = "issuanceDate", type = "Timestamp")]
+  issuance_date: Option<Timestamp>,
+  /// The options used when signing the Domain Linkage Credentials.
+  #[typescript(name = "signatureOptions", type = "JwsSignatureOptions")]
+  signature_options: Option<JwsSignatureOptions>,
+}
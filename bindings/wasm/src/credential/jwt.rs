@@ -1,9 +1,14 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_iota::core::Base;
+use identity_iota::core::BaseEncoding;
 use identity_iota::credential::Jwt;
 use wasm_bindgen::prelude::*;
 
+use crate::error::Result;
+use crate::error::WasmResult;
+
 /// A wrapper around a JSON Web Token (JWK).
 #[wasm_bindgen(js_name = Jwt)]
 pub struct WasmJwt(pub(crate) Jwt);
@@ -25,6 +30,42 @@ impl WasmJwt {
   pub fn to_string_clone(&self) -> String {
     self.0.as_str().to_owned()
   }
+
+  /// Creates a new {@link Jwt} from the UTF-8 encoded bytes of a JWT string, e.g. as received over the network.
+  #[wasm_bindgen(js_name = fromBytes)]
+  pub fn from_bytes(bytes: &[u8]) -> Result<WasmJwt> {
+    std::str::from_utf8(bytes)
+      .map(|jwt| Self(Jwt::from(jwt.to_owned())))
+      .map_err(|_| JsError::new("JWT is not valid UTF-8").into())
+  }
+
+  /// Returns the UTF-8 encoded bytes of the JWT string.
+  #[wasm_bindgen(js_name = toBytes)]
+  pub fn to_bytes(&self) -> Vec<u8> {
+    self.0.as_str().as_bytes().to_vec()
+  }
+
+  /// Returns the length of the JWT string.
+  #[wasm_bindgen(getter)]
+  pub fn length(&self) -> usize {
+    self.0.as_str().len()
+  }
+
+  /// Returns the decoded payload of the JWT as a JSON string, without verifying its signature.
+  ///
+  /// The claims are not deserialized, such that large credentials and presentations can be inspected lazily,
+  /// e.g. with `JSON.parse`, before deciding whether to validate them.
+  #[wasm_bindgen(js_name = payloadJSON)]
+  pub fn payload_json(&self) -> Result<String> {
+    let payload: &str = self
+      .0
+      .as_str()
+      .split('.')
+      .nth(1)
+      .ok_or_else(|| JsError::new("JWT is missing a payload"))?;
+    let decoded: Vec<u8> = BaseEncoding::decode(payload, Base::Base64Url).wasm_result()?;
+    String::from_utf8(decoded).map_err(|_| JsError::new("JWT payload is not valid UTF-8").into())
+  }
 }
 
 impl_wasm_json!(WasmJwt, Jwt);
@@ -3,12 +3,22 @@
 
 use identity_iota::core::Object;
 use identity_iota::core::Url;
+use identity_iota::credential::DecodedJwtCredential;
+use identity_iota::credential::FailFast;
+use identity_iota::credential::JwtCredentialValidationOptions;
 use identity_iota::credential::JwtCredentialValidator;
 use identity_iota::credential::JwtCredentialValidatorUtils;
+use identity_iota::credential::JwtValidationError;
+use identity_iota::credential::SignerContext;
 use identity_iota::credential::StatusCheck;
 use identity_iota::did::CoreDID;
+use js_sys::Array;
 
 use super::options::WasmJwtCredentialValidationOptions;
+use super::presentation_credential_result::ArrayPresentationCredentialResult;
+use super::presentation_credential_result::WasmCredentialStatusOutcome;
+use super::presentation_credential_result::WasmPresentationCredentialResult;
+use super::unknown_credential::UnknownCredential;
 use crate::common::ImportedDocumentLock;
 use crate::common::ImportedDocumentReadGuard;
 use crate::common::WasmTimestamp;
@@ -18,6 +28,7 @@ use crate::credential::WasmCredential;
 use crate::credential::WasmDecodedJwtCredential;
 use crate::credential::WasmFailFast;
 use crate::credential::WasmJwt;
+use crate::credential::WasmPresentation;
 use crate::credential::WasmSubjectHolderRelationship;
 use crate::did::ArrayIToCoreDocument;
 use crate::did::IToCoreDocument;
@@ -126,6 +137,107 @@ impl WasmJwtCredentialValidator {
       .map(WasmDecodedJwtCredential)
   }
 
+  /// Validates every credential of `presentation` on its own, returning a {@link PresentationCredentialResult} per
+  /// credential instead of failing on the first invalid one.
+  ///
+  /// Each credential is validated like {@link JwtCredentialValidator.validate} against the document of its issuer
+  /// among `trustedIssuers`. The status check set in `options` is reported separately as the
+  /// {@link CredentialStatusOutcome} of each result and only performed for credentials that passed all other checks.
+  ///
+  /// Only credentials encoded as JWT can be validated, any other credential fails with the code
+  /// `"UnsupportedCredentialFormat"`.
+  ///
+  /// # Warning
+  /// The presentation itself is not validated, see {@link JwtPresentationValidator.validate}.
+  #[wasm_bindgen(js_name = validatePresentationCredentials)]
+  #[allow(non_snake_case)]
+  pub fn validate_presentation_credentials(
+    &self,
+    presentation: &WasmPresentation,
+    trustedIssuers: &ArrayIToCoreDocument,
+    options: &WasmJwtCredentialValidationOptions,
+  ) -> Result<ArrayPresentationCredentialResult> {
+    let issuer_locks: Vec<ImportedDocumentLock> = trustedIssuers.into();
+    let trusted_issuers: Vec<ImportedDocumentReadGuard<'_>> = issuer_locks
+      .iter()
+      .map(ImportedDocumentLock::try_read)
+      .collect::<Result<Vec<ImportedDocumentReadGuard<'_>>>>(
+    )?;
+
+    let status_check: StatusCheck = options.0.status;
+    let mut validation_options: JwtCredentialValidationOptions = options.0.clone();
+    validation_options.status = StatusCheck::SkipAll;
+
+    Ok(
+      presentation
+        .0
+        .verifiable_credential
+        .iter()
+        .enumerate()
+        .map(|(index, credential)| {
+          let mut result = WasmPresentationCredentialResult::new(index);
+          let UnknownCredential::Jwt(jwt) = credential else {
+            result.errors.push((
+              "UnsupportedCredentialFormat",
+              "only credentials encoded as JWT can be validated".to_owned(),
+            ));
+            return result;
+          };
+
+          let issuer: Option<&ImportedDocumentReadGuard<'_>> =
+            match JwtCredentialValidatorUtils::extract_issuer_from_jwt::<CoreDID>(jwt) {
+              Ok(issuer_did) => trusted_issuers
+                .iter()
+                .find(|issuer| issuer.as_ref().id() == &issuer_did),
+              Err(error) => {
+                result.push_error(&error);
+                return result;
+              }
+            };
+          let Some(issuer) = issuer else {
+            result.push_error(&JwtValidationError::DocumentMismatch(SignerContext::Issuer));
+            return result;
+          };
+
+          let decoded: DecodedJwtCredential =
+            match self.0.validate(jwt, issuer, &validation_options, FailFast::AllErrors) {
+              Ok(decoded) => decoded,
+              Err(compound) => {
+                compound
+                  .validation_errors
+                  .iter()
+                  .for_each(|error| result.push_error(error));
+                return result;
+              }
+            };
+          result.id = decoded.credential.id.as_ref().map(ToString::to_string);
+
+          if status_check == StatusCheck::SkipAll || decoded.credential.credential_status.is_none() {
+            return result;
+          }
+          result.status = match JwtCredentialValidatorUtils::check_status(
+            &decoded.credential,
+            std::slice::from_ref(issuer),
+            status_check,
+          ) {
+            Ok(()) => WasmCredentialStatusOutcome::Valid,
+            Err(error) => {
+              result.push_error(&error);
+              match error {
+                JwtValidationError::Revoked => WasmCredentialStatusOutcome::Revoked,
+                JwtValidationError::Suspended => WasmCredentialStatusOutcome::Suspended,
+                _ => WasmCredentialStatusOutcome::Invalid,
+              }
+            }
+          };
+          result
+        })
+        .map(JsValue::from)
+        .collect::<Array>()
+        .unchecked_into::<ArrayPresentationCredentialResult>(),
+    )
+  }
+
   /// Validate that the credential expires on or after the specified timestamp.
   #[wasm_bindgen(js_name = checkExpiresOnOrAfter)]
   pub fn check_expires_on_or_after(credential: &WasmCredential, timestamp: &WasmTimestamp) -> Result<()> {
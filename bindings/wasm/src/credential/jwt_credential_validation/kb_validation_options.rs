@@ -80,4 +80,17 @@ interface IKeyBindingJWTValidationOptions {
      */
     readonly latestIssuanceDate?: Timestamp;
 
+    /**
+     * The number of seconds by which the `iat` value of the KB-JWT may deviate from the earliest and latest
+     * issuance dates, to account for clock skew between holder and verifier.
+     *
+     * Default: 0.
+     */
+    readonly leeway?: number;
+
+    /**
+     * Claims that must be present in the KB-JWT in addition to `iat`, `aud`, `nonce` and `_sd_hash`.
+     */
+    readonly requiredClaims?: string[];
+
 }"#;
@@ -5,6 +5,7 @@ mod decoded_jwt_credential;
 mod jwt_credential_validator;
 mod kb_validation_options;
 mod options;
+mod presentation_credential_result;
 mod sd_jwt_validator;
 mod unknown_credential;
 
@@ -12,5 +13,6 @@ pub use self::decoded_jwt_credential::*;
 pub use self::jwt_credential_validator::*;
 pub use self::kb_validation_options::*;
 pub use self::options::*;
+pub use self::presentation_credential_result::*;
 pub use self::sd_jwt_validator::*;
 pub use self::unknown_credential::*;
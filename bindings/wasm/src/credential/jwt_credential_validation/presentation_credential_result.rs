@@ -0,0 +1,106 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::credential::JwtValidationError;
+use js_sys::Array;
+use wasm_bindgen::prelude::*;
+
+use crate::common::ArrayString;
+
+/// The outcome of checking the `credentialStatus` of a credential in a presentation.
+#[wasm_bindgen(js_name = CredentialStatusOutcome)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WasmCredentialStatusOutcome {
+  /// The status was checked and the credential is neither revoked nor suspended.
+  Valid = 0,
+  /// The credential is revoked.
+  Revoked = 1,
+  /// The credential is suspended.
+  Suspended = 2,
+  /// The status could not be checked, e.g. because it is malformed or of an unsupported type.
+  Invalid = 3,
+  /// The status was not checked, because the credential has no status, the status check was skipped or the
+  /// credential failed validation beforehand.
+  NotChecked = 4,
+}
+
+/// The result of validating a single credential of a presentation, returned by
+/// {@link JwtCredentialValidator.validatePresentationCredentials}.
+#[wasm_bindgen(js_name = PresentationCredentialResult, inspectable)]
+#[derive(Clone, Debug)]
+pub struct WasmPresentationCredentialResult {
+  pub(crate) index: usize,
+  pub(crate) id: Option<String>,
+  pub(crate) status: WasmCredentialStatusOutcome,
+  pub(crate) errors: Vec<(&'static str, String)>,
+}
+
+#[wasm_bindgen(js_class = PresentationCredentialResult)]
+impl WasmPresentationCredentialResult {
+  /// The index of the credential in the `verifiableCredential` property of the presentation.
+  #[wasm_bindgen]
+  pub fn index(&self) -> usize {
+    self.index
+  }
+
+  /// The `id` of the credential, if it has one and could be decoded.
+  #[wasm_bindgen]
+  pub fn id(&self) -> Option<String> {
+    self.id.clone()
+  }
+
+  /// Whether the credential passed all checks, including its status check.
+  #[wasm_bindgen]
+  pub fn valid(&self) -> bool {
+    self.errors.is_empty()
+  }
+
+  /// The outcome of the status check of the credential.
+  #[wasm_bindgen]
+  pub fn status(&self) -> WasmCredentialStatusOutcome {
+    self.status
+  }
+
+  /// The codes of the errors the credential failed with, e.g. `"Revoked"` or `"ExpirationDate"`.
+  #[wasm_bindgen(js_name = errorCodes)]
+  pub fn error_codes(&self) -> ArrayString {
+    self
+      .errors
+      .iter()
+      .map(|(code, _)| JsValue::from_str(code))
+      .collect::<Array>()
+      .unchecked_into::<ArrayString>()
+  }
+
+  /// The messages of the errors the credential failed with, in the same order as {@link errorCodes}.
+  #[wasm_bindgen(js_name = errorMessages)]
+  pub fn error_messages(&self) -> ArrayString {
+    self
+      .errors
+      .iter()
+      .map(|(_, message)| JsValue::from_str(message))
+      .collect::<Array>()
+      .unchecked_into::<ArrayString>()
+  }
+}
+
+impl WasmPresentationCredentialResult {
+  pub(crate) fn new(index: usize) -> Self {
+    Self {
+      index,
+      id: None,
+      status: WasmCredentialStatusOutcome::NotChecked,
+      errors: Vec::new(),
+    }
+  }
+
+  pub(crate) fn push_error(&mut self, error: &JwtValidationError) {
+    self.errors.push((error.into(), error.to_string()));
+  }
+}
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "Array<PresentationCredentialResult>")]
+  pub type ArrayPresentationCredentialResult;
+}
@@ -69,4 +69,11 @@ interface IJwtPresentationValidationOptions {
      * Uses the current datetime during validation if not set. 
      */
     readonly latestIssuanceDate?: Timestamp;
+
+    /**
+     * The number of seconds by which the expiration and issuance dates of the presentation may deviate from
+     * `earliestExpiryDate` and `latestIssuanceDate`, to account for clock skew between holder and verifier.
+     * Defaults to zero.
+     */
+    readonly leeway?: number;
 }"#;
@@ -6,6 +6,7 @@
 pub use self::credential::WasmCredential;
 pub use self::credential_builder::*;
 pub use self::domain_linkage_configuration::WasmDomainLinkageConfiguration;
+pub use self::domain_linkage_generator::*;
 pub use self::jpt::*;
 pub use self::jpt_credential_validator::*;
 pub use self::jpt_presentiation_validation::*;
@@ -25,6 +26,7 @@ mod credential;
 mod credential_builder;
 mod domain_linkage_configuration;
 mod domain_linkage_credential_builder;
+mod domain_linkage_generator;
 mod domain_linkage_validator;
 mod jpt;
 mod jpt_credential_validator;
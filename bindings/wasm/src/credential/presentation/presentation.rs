@@ -10,6 +10,7 @@ use wasm_bindgen::JsCast;
 
 use crate::common::ArrayString;
 use crate::common::MapStringAny;
+use crate::common::WasmTimestamp;
 use crate::credential::ArrayContext;
 use crate::credential::ArrayPolicy;
 use crate::credential::ArrayRefreshService;
@@ -95,12 +96,42 @@ impl WasmPresentation {
       .unchecked_into::<ArrayUnknownCredential>()
   }
 
+  /// Returns the number of credentials in the presentation.
+  #[wasm_bindgen(js_name = verifiableCredentialCount)]
+  pub fn verifiable_credential_count(&self) -> usize {
+    self.0.verifiable_credential.len()
+  }
+
+  /// Returns a copy of the credential at `index`, without copying the other credentials of the presentation like
+  /// {@link Presentation.verifiableCredential} does.
+  #[wasm_bindgen(js_name = verifiableCredentialAt)]
+  pub fn verifiable_credential_at(&self, index: usize) -> Option<WasmUnknownCredentialContainer> {
+    self
+      .0
+      .verifiable_credential
+      .get(index)
+      .cloned()
+      .map(WasmUnknownCredentialContainer::new)
+  }
+
   /// Returns a copy of the URI of the entity that generated the presentation.
   #[wasm_bindgen]
   pub fn holder(&self) -> String {
     self.0.holder.as_ref().to_string()
   }
 
+  /// Returns a copy of the timestamp of when the presentation becomes valid.
+  #[wasm_bindgen(js_name = "issuanceDate")]
+  pub fn issuance_date(&self) -> Option<WasmTimestamp> {
+    self.0.issuance_date.map(WasmTimestamp::from)
+  }
+
+  /// Returns a copy of the timestamp of when the presentation should no longer be considered valid.
+  #[wasm_bindgen(js_name = "expirationDate")]
+  pub fn expiration_date(&self) -> Option<WasmTimestamp> {
+    self.0.expiration_date.map(WasmTimestamp::from)
+  }
+
   /// Returns a copy of the service(s) used to refresh an expired {@link Credential} in the presentation.
   #[wasm_bindgen(js_name = "refreshService")]
   pub fn refresh_service(&self) -> Result<ArrayRefreshService> {
@@ -149,6 +180,7 @@ impl WasmPresentation {
 }
 
 impl_wasm_json!(WasmPresentation, Presentation);
+impl_wasm_json_string!(WasmPresentation, Presentation);
 impl_wasm_clone!(WasmPresentation, Presentation);
 
 impl From<Presentation<UnknownCredential>> for WasmPresentation {
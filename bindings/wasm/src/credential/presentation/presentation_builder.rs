@@ -4,6 +4,7 @@
 use identity_iota::core::Context;
 use identity_iota::core::Object;
 use identity_iota::core::OneOrMany;
+use identity_iota::core::Timestamp;
 use identity_iota::core::Url;
 use identity_iota::credential::Policy;
 use identity_iota::credential::PresentationBuilder;
@@ -24,6 +25,8 @@ impl TryFrom<IPresentation> for PresentationBuilder<UnknownCredential> {
       r#type,
       verifiable_credential,
       holder,
+      issuance_date,
+      expiration_date,
       refresh_service,
       terms_of_use,
       properties,
@@ -48,6 +51,12 @@ impl TryFrom<IPresentation> for PresentationBuilder<UnknownCredential> {
     for credential in verifiable_credential.into_vec() {
       builder = builder.credential(credential);
     }
+    if let Some(issuance_date) = issuance_date {
+      builder = builder.issuance_date(issuance_date);
+    }
+    if let Some(expiration_date) = expiration_date {
+      builder = builder.expiration_date(expiration_date);
+    }
     if let Some(refresh_service) = refresh_service {
       for service in refresh_service.into_vec() {
         builder = builder.refresh_service(service);
@@ -93,6 +102,12 @@ struct IPresentationHelper {
   /// The entity that generated the presentation.
   #[typescript(optional = false, type = "string | CoreDID | IotaDID")]
   holder: String,
+  /// A timestamp of when the presentation becomes valid.
+  #[typescript(name = "issuanceDate", type = "Timestamp")]
+  issuance_date: Option<Timestamp>,
+  /// A timestamp of when the presentation should no longer be considered valid.
+  #[typescript(name = "expirationDate", type = "Timestamp")]
+  expiration_date: Option<Timestamp>,
   /// Service(s) used to refresh an expired {@link Credential} in the presentation.
   #[typescript(name = "refreshService", type = "RefreshService | Array<RefreshService>")]
   refresh_service: Option<OneOrMany<RefreshService>>,
@@ -27,6 +27,16 @@ impl WasmDIDJwk {
     let did = get_core_did_clone(&did).0;
     DIDJwk::try_from(did).wasm_result().map(Self)
   }
+  /// Creates a {@link DIDJwk} encoding the given public key. If the key has no `alg` parameter, it is set to the
+  /// algorithm implied by the key's curve.
+  ///
+  /// ### Errors
+  /// Throws an error if the key is a symmetric key or contains private key material.
+  #[wasm_bindgen(js_name = fromJwk)]
+  pub fn from_jwk(jwk: &WasmJwk) -> Result<WasmDIDJwk> {
+    DIDJwk::new(&jwk.0).wasm_result().map(Self)
+  }
+
   /// Parses a {@link DIDJwk} from the given `input`.
   ///
   /// ### Errors
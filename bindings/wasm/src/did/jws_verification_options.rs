@@ -42,6 +42,19 @@ impl WasmJwsVerificationOptions {
   pub fn set_method_id(&mut self, value: &WasmDIDUrl) {
     self.0.method_id = Some(value.0.clone());
   }
+
+  /// Require that the controller of the signing method is the document's id or one of the document's controllers.
+  #[wasm_bindgen(js_name = setRequireControlledMethod)]
+  pub fn set_require_controlled_method(&mut self, value: bool) {
+    self.0.require_controlled_method = value;
+  }
+
+  /// Require that the signing method is in the verification relationship appropriate to the verified object, i.e.
+  /// `assertionMethod` for credentials and `authentication` for presentations.
+  #[wasm_bindgen(js_name = setRequireMethodPurpose)]
+  pub fn set_require_method_purpose(&mut self, value: bool) {
+    self.0.require_method_purpose = value;
+  }
 }
 
 impl_wasm_json!(WasmJwsVerificationOptions, JwsVerificationOptions);
@@ -71,4 +84,18 @@ interface IJwsVerificationOptions {
      * If unset, the `kid` of the JWS is used as the DID Url.
      */
     readonly methodId?: DIDUrl;
+
+    /** Verify that the controller of the signing method is the document's id or one of the document's controllers.
+     *
+     * Default: false.
+     */
+    readonly requireControlledMethod?: boolean;
+
+    /** Require that the signing method is in the verification relationship appropriate to the verified object,
+     * i.e. `assertionMethod` for credentials and `authentication` for presentations.
+     * Ignored if `methodScope` is set.
+     *
+     * Default: false.
+     */
+    readonly requireMethodPurpose?: boolean;
 }"#;
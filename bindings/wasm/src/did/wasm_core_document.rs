@@ -12,6 +12,7 @@ use crate::common::ArrayVerificationMethod;
 use crate::common::MapStringAny;
 use crate::common::OptionOneOrManyString;
 use crate::common::PromiseString;
+use crate::common::PromiseUint8Array;
 use crate::common::PromiseVoid;
 use crate::common::RecordStringAny;
 use crate::common::UDIDUrlQuery;
@@ -28,6 +29,7 @@ use crate::did::WasmDIDJwk;
 use crate::error::Result;
 use crate::error::WasmResult;
 use crate::jose::WasmDecodedJws;
+use crate::jose::WasmJwk;
 use crate::jose::WasmJwsAlgorithm;
 use crate::storage::WasmJwsSignatureOptions;
 use crate::storage::WasmJwtPresentationOptions;
@@ -55,12 +57,15 @@ use identity_iota::document::Service;
 use identity_iota::storage::key_storage::KeyType;
 use identity_iota::storage::storage::JwkDocumentExt;
 use identity_iota::storage::storage::JwsSignatureOptions;
+use identity_iota::storage::storage::WebAuthnDocumentExt;
+use identity_iota::verification::jose::jwk::Jwk;
 use identity_iota::verification::jose::jws::JwsAlgorithm;
 use identity_iota::verification::MethodRef;
 use identity_iota::verification::MethodScope;
 use identity_iota::verification::VerificationMethod;
 
 use js_sys::Promise;
+use js_sys::Uint8Array;
 use proc_typescript::typescript;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
@@ -663,6 +668,56 @@ impl WasmCoreDocument {
     Ok(promise.unchecked_into())
   }
 
+  /// Insert a new verification method with the P-256 `publicKey` of the WebAuthn credential identified by
+  /// `credentialId`, e.g. a passkey registered beforehand, and record the mapping from the method to the credential in
+  /// the `storage`.
+  ///
+  /// If no `fragment` is given, the `kid` of the key is used, defaulting to its JWK thumbprint.
+  ///
+  /// The fragment of the inserted method is returned.
+  #[wasm_bindgen(js_name = insertWebAuthnMethod)]
+  #[allow(non_snake_case)]
+  pub fn insert_webauthn_method(
+    &self,
+    storage: &WasmStorage,
+    credentialId: Vec<u8>,
+    publicKey: &WasmJwk,
+    fragment: Option<String>,
+    scope: WasmMethodScope,
+  ) -> Result<PromiseString> {
+    let document_lock_clone: Rc<CoreDocumentLock> = self.0.clone();
+    let storage_clone: Rc<WasmStorageInner> = storage.0.clone();
+    let public_key: Jwk = publicKey.0.clone();
+    let scope: MethodScope = scope.0;
+    let promise: Promise = future_to_promise(async move {
+      let method_fragment: String = document_lock_clone
+        .write()
+        .await
+        .insert_webauthn_method(&storage_clone, &credentialId, public_key, fragment.as_deref(), scope)
+        .await
+        .wasm_result()?;
+      Ok(JsValue::from(method_fragment))
+    });
+    Ok(promise.unchecked_into())
+  }
+
+  /// Returns the id of the WebAuthn credential holding the key of the method identified by `fragment`.
+  #[wasm_bindgen(js_name = webAuthnCredentialId)]
+  pub fn webauthn_credential_id(&self, storage: &WasmStorage, fragment: String) -> Result<PromiseUint8Array> {
+    let document_lock_clone: Rc<CoreDocumentLock> = self.0.clone();
+    let storage_clone: Rc<WasmStorageInner> = storage.0.clone();
+    let promise: Promise = future_to_promise(async move {
+      let credential_id: Vec<u8> = document_lock_clone
+        .read()
+        .await
+        .webauthn_credential_id(&storage_clone, &fragment)
+        .await
+        .wasm_result()?;
+      Ok(JsValue::from(Uint8Array::from(credential_id.as_slice())))
+    });
+    Ok(promise.unchecked_into())
+  }
+
   /// Sign the `payload` according to `options` with the storage backed private key corresponding to the public key
   /// material in the verification method identified by the given `fragment.
   ///
@@ -692,6 +747,24 @@ impl WasmCoreDocument {
     Ok(promise.unchecked_into())
   }
 
+  /// Returns the signing input that `createJws` signs for the given `fragment`, `payload` and `options`, i.e. the
+  /// encoded protected header and payload separated by a `.`.
+  ///
+  /// No key is involved, which allows comparing the bytes signed by different implementations byte for byte.
+  #[wasm_bindgen(js_name = jwsSigningInput)]
+  pub fn jws_signing_input(
+    &self,
+    fragment: String,
+    payload: String,
+    options: &WasmJwsSignatureOptions,
+  ) -> Result<Vec<u8>> {
+    self
+      .0
+      .try_read()?
+      .jws_signing_input(&fragment, payload.as_bytes(), &options.0)
+      .wasm_result()
+  }
+
   /// Produces a JWT where the payload is produced from the given `credential`
   /// in accordance with [VC Data Model v1.1](https://www.w3.org/TR/vc-data-model/#json-web-token).
   ///
@@ -773,6 +846,16 @@ impl WasmCoreDocument {
   pub fn expand_did_jwk(did: WasmDIDJwk) -> Result<WasmCoreDocument> {
     CoreDocument::expand_did_jwk(did.0).wasm_result().map(Self::from)
   }
+
+  /// Returns the {@link DIDJwk} encoding the only verification method of this document.
+  ///
+  /// ### Errors
+  /// Throws an error if the document does not contain exactly one verification method, or if its key is not an
+  /// asymmetric public JWK.
+  #[wasm_bindgen(js_name = toDIDJwk)]
+  pub fn to_did_jwk(&self) -> Result<WasmDIDJwk> {
+    self.0.try_read()?.to_did_jwk().wasm_result().map(WasmDIDJwk)
+  }
 }
 
 #[wasm_bindgen]
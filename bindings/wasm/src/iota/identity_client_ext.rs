@@ -143,6 +143,32 @@ impl WasmIotaIdentityClientExt {
     Ok(promise.unchecked_into::<PromiseAliasOutputBuilderParams>())
   }
 
+  /// Fetches the Alias Output of a deactivated DID and re-activates it by setting `document` as its state metadata.
+  /// The storage deposit on the output is left unchanged. If it was reallocated after the deactivation,
+  /// the amount should be increased manually.
+  ///
+  /// Fails if the published document is not deactivated.
+  ///
+  /// NOTE: this does *not* publish the updated Alias Output.
+  #[wasm_bindgen(js_name = reactivateDidOutput)]
+  pub fn reactivate_did_output(
+    client: WasmIotaIdentityClient,
+    document: &WasmIotaDocument,
+  ) -> Result<PromiseAliasOutputBuilderParams> {
+    let document: IotaDocument = document.0.try_read()?.clone();
+    let promise: Promise = future_to_promise(async move {
+      let output: AliasOutput = IotaIdentityClientExt::reactivate_did_output(&client, document)
+        .await
+        .wasm_result()?;
+      // Use DTO for correct serialization.
+      let dto: AliasOutputDto = AliasOutputDto::from(&output);
+      JsValue::from_serde(&dto).wasm_result()
+    });
+
+    // WARNING: this does not validate the return type. Check carefully.
+    Ok(promise.unchecked_into::<PromiseAliasOutputBuilderParams>())
+  }
+
   /// Resolve a {@link IotaDocument}. Returns an empty, deactivated document if the state metadata
   /// of the Alias Output is empty.
   #[wasm_bindgen(js_name = resolveDid)]
@@ -160,6 +186,22 @@ impl WasmIotaIdentityClientExt {
     Ok(promise.unchecked_into::<PromiseIotaDocument>())
   }
 
+  /// Resolve a {@link IotaDocument}, failing instead of returning an empty document if the DID is deactivated.
+  #[wasm_bindgen(js_name = resolveActiveDid)]
+  pub fn resolve_active_did(client: WasmIotaIdentityClient, did: &WasmIotaDID) -> Result<PromiseIotaDocument> {
+    let did: IotaDID = did.0.clone();
+    let promise: Promise = future_to_promise(async move {
+      IotaIdentityClientExt::resolve_active_did(&client, &did)
+        .await
+        .map(WasmIotaDocument::from)
+        .map(Into::into)
+        .wasm_result()
+    });
+
+    // WARNING: this does not validate the return type. Check carefully.
+    Ok(promise.unchecked_into::<PromiseIotaDocument>())
+  }
+
   /// Fetches the `IAliasOutput` associated with the given DID.
   #[wasm_bindgen(js_name = resolveDidOutput)]
   pub fn resolve_did_output(
@@ -43,3 +43,29 @@ macro_rules! impl_wasm_json {
     }
   };
 }
+
+/// Implements `toJSONString` and `fromJSONString`, exchanging the JSON representation as a string instead of a
+/// JavaScript object.
+///
+/// Avoids converting large objects to JavaScript values only for them to be stored or sent over the network.
+#[macro_export]
+macro_rules! impl_wasm_json_string {
+  ($wasm_class:ident, $js_class:ident) => {
+    #[wasm_bindgen(js_class = $js_class)]
+    impl $wasm_class {
+      /// Serializes this to a JSON string.
+      #[wasm_bindgen(js_name = toJSONString)]
+      pub fn to_json_string(&self) -> $crate::error::Result<String> {
+        use $crate::error::WasmResult;
+        identity_iota::core::ToJson::to_json(&self.0).wasm_result()
+      }
+
+      /// Deserializes an instance from a JSON string.
+      #[wasm_bindgen(js_name = fromJSONString)]
+      pub fn from_json_string(json: &str) -> $crate::error::Result<$wasm_class> {
+        use $crate::error::WasmResult;
+        identity_iota::core::FromJson::from_json(json).map(Self).wasm_result()
+      }
+    }
+  };
+}
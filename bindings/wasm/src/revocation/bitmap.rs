@@ -1,15 +1,12 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::borrow::Cow;
-
 use identity_iota::credential::RevocationBitmap;
 use wasm_bindgen::prelude::*;
 
 use crate::did::WasmDIDUrl;
 use crate::did::WasmService;
 use crate::error::Result;
-use crate::error::WasmError;
 use crate::error::WasmResult;
 
 /// A compressed bitmap for managing credential revocation.
@@ -56,9 +53,8 @@ impl WasmRevocationBitmap {
   /// Returns the number of revoked credentials.
   #[wasm_bindgen]
   #[allow(clippy::len_without_is_empty)]
-  pub fn len(&self) -> Result<u32> {
-    u32::try_from(self.0.len())
-      .map_err(|err| WasmError::new(Cow::Borrowed("TryFromIntError"), Cow::Owned(err.to_string())).into())
+  pub fn len(&self) -> u64 {
+    self.0.len()
   }
 
   /// Return a `Service` with:
@@ -8,7 +8,9 @@ use crate::error::Result;
 use crate::error::WasmResult;
 use identity_iota::core::Timestamp;
 use identity_iota::core::ToJson;
+use identity_iota::credential::KeyBindingJwtBuilder;
 use identity_iota::sd_jwt_payload::KeyBindingJwtClaims;
+use identity_iota::sd_jwt_payload::SdJwt;
 use identity_iota::sd_jwt_payload::Sha256Hasher;
 use js_sys::Array;
 use js_sys::JsString;
@@ -25,6 +27,9 @@ impl WasmKeyBindingJwtClaims {
   /// Creates a new [`KeyBindingJwtClaims`].
   /// When `issued_at` is left as None, it will automatically default to the current time.
   ///
+  /// The `sd_hash` is computed over `<JWT>~<Disclosure 1>~...~<Disclosure N>~`, also when no disclosures are
+  /// presented.
+  ///
   /// # Error
   /// When `issued_at` is set to `None` and the system returns time earlier than `SystemTime::UNIX_EPOCH`.
   #[wasm_bindgen(constructor)]
@@ -41,16 +46,19 @@ impl WasmKeyBindingJwtClaims {
       .iter()
       .map(|item| item.dyn_into::<JsString>().map(String::from))
       .collect::<Result<Vec<String>>>()?;
+    let hasher = Sha256Hasher::new();
+    let sd_jwt = SdJwt::new(jwt, disclosures, None);
     let mut claims = KeyBindingJwtClaims::new(
-      &Sha256Hasher::new(),
-      jwt,
-      disclosures,
+      &hasher,
+      sd_jwt.jwt.clone(),
+      sd_jwt.disclosures.clone(),
       nonce,
       aud,
       issued_at
         .map(|value| value.0.to_unix())
         .unwrap_or(Timestamp::now_utc().to_unix()),
     );
+    claims.sd_hash = KeyBindingJwtBuilder::sd_hash(&sd_jwt, &hasher);
     if let Some(custom_properties) = custom_properties {
       let custom_properties: BTreeMap<String, Value> = custom_properties.into_serde().wasm_result()?;
       claims.properties = custom_properties
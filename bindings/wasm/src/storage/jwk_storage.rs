@@ -29,6 +29,10 @@ extern "C" {
   pub type PromiseJwkGenOutput;
   #[wasm_bindgen(typescript_type = "Promise<Jwk>")]
   pub type PromiseJwk;
+  #[wasm_bindgen(typescript_type = "Promise<Array<string>>")]
+  pub type PromiseArrayString;
+  #[wasm_bindgen(typescript_type = "Promise<Array<Uint8Array>>")]
+  pub type PromiseArrayUint8Array;
 }
 
 #[wasm_bindgen]
@@ -53,6 +57,18 @@ extern "C" {
 
   #[wasm_bindgen(method)]
   pub(crate) fn _get_key(this: &WasmJwkStorage, key_id: &str) -> Option<WasmJwk>;
+
+  #[wasm_bindgen(method, js_name = insertMany)]
+  pub fn insert_many(this: &WasmJwkStorage, jwks: Array) -> PromiseArrayString;
+
+  #[wasm_bindgen(method, getter = insertMany)]
+  fn insert_many_fn(this: &WasmJwkStorage) -> Option<js_sys::Function>;
+
+  #[wasm_bindgen(method, js_name = signMany)]
+  pub fn sign_many(this: &WasmJwkStorage, requests: Array) -> PromiseArrayUint8Array;
+
+  #[wasm_bindgen(method, getter = signMany)]
+  fn sign_many_fn(this: &WasmJwkStorage) -> Option<js_sys::Function>;
 }
 
 #[async_trait::async_trait(?Send)]
@@ -91,6 +107,75 @@ impl JwkStorage for WasmJwkStorage {
     let result: JsValueResult = JsFuture::from(promise).await.into();
     result.into()
   }
+
+  async fn insert_many(&self, jwks: Vec<Jwk>) -> KeyStorageResult<Vec<KeyId>> {
+    // `insertMany` is optional, fall back to inserting the keys one by one.
+    if self.insert_many_fn().is_none() {
+      let mut key_ids: Vec<KeyId> = Vec::with_capacity(jwks.len());
+      for jwk in jwks {
+        key_ids.push(JwkStorage::insert(self, jwk).await?);
+      }
+      return Ok(key_ids);
+    }
+
+    let jwks: Array = jwks.into_iter().map(WasmJwk::from).map(JsValue::from).collect();
+    let promise: Promise = Promise::resolve(&WasmJwkStorage::insert_many(self, jwks));
+    let result: JsValueResult = JsFuture::from(promise).await.into();
+    result.into()
+  }
+
+  async fn sign_many(&self, requests: &[(&KeyId, &[u8], &Jwk)]) -> KeyStorageResult<Vec<Vec<u8>>> {
+    // `signMany` is optional, fall back to signing the payloads one by one.
+    if self.sign_many_fn().is_none() {
+      let mut signatures: Vec<Vec<u8>> = Vec::with_capacity(requests.len());
+      for (key_id, data, public_key) in requests {
+        signatures.push(JwkStorage::sign(self, key_id, data, public_key).await?);
+      }
+      return Ok(signatures);
+    }
+
+    let js_requests: Array = Array::new();
+    for (key_id, data, public_key) in requests {
+      js_requests.push(&sign_request(key_id, data, public_key)?);
+    }
+    let promise: Promise = Promise::resolve(&WasmJwkStorage::sign_many(self, js_requests));
+    let result: JsValueResult = JsFuture::from(promise).await.into();
+    let signatures: JsValue = result.to_key_storage_error()?;
+    if !Array::is_array(&signatures) {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::SerializationError)
+          .with_custom_message("expected Array<Uint8Array>".to_owned()),
+      );
+    }
+    let signatures: Vec<Vec<u8>> = Array::from(&signatures)
+      .iter()
+      .map(uint8array_to_bytes)
+      .collect::<KeyStorageResult<_>>()?;
+    if signatures.len() != requests.len() {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("`signMany` returned a different number of signatures than requested".to_owned()),
+      );
+    }
+    Ok(signatures)
+  }
+}
+
+/// Creates the `SignRequest` object passed to `signMany`.
+fn sign_request(key_id: &KeyId, data: &[u8], public_key: &Jwk) -> KeyStorageResult<JsValue> {
+  let request: js_sys::Object = js_sys::Object::new();
+  let entries: [(&str, JsValue); 3] = [
+    ("keyId", JsValue::from_str(key_id.as_str())),
+    ("data", Uint8Array::from(data).into()),
+    ("publicKey", WasmJwk(public_key.clone()).into()),
+  ];
+  for (key, value) in entries {
+    js_sys::Reflect::set(&request, &JsValue::from_str(key), &value).map_err(|_| {
+      KeyStorageError::new(KeyStorageErrorKind::SerializationError)
+        .with_custom_message("unable to create a sign request".to_owned())
+    })?;
+  }
+  Ok(request.into())
 }
 
 #[wasm_bindgen(typescript_custom_section)]
@@ -115,6 +200,25 @@ interface JwkStorage {
   delete: (keyId: string) => Promise<void>;
   /** Returns `true` if the key with the given `keyId` exists in storage, `false` otherwise. */
   exists: (keyId: string) => Promise<boolean>;
+  /** Insert multiple existing JSON Web Keys into the storage, returning their key ids in the order of `jwks`.
+   * 
+   * Optional, keys are inserted one by one with `insert` if not implemented. */
+  insertMany?: (jwks: Array<Jwk>) => Promise<Array<string>>;
+  /** Sign the `data` of each request like `sign`, returning the signatures in the order of `requests`.
+   * 
+   * Optional, payloads are signed one by one with `sign` if not implemented. Implementing it allows adapters of
+   * remote key management services to sign multiple payloads in a single round trip. */
+  signMany?: (requests: Array<SignRequest>) => Promise<Array<Uint8Array>>;
+}
+
+/** A request to sign `data` with the key identified by `keyId`, as passed to `JwkStorage.signMany`. */
+interface SignRequest {
+  /** The id of the signing key. */
+  readonly keyId: string;
+  /** The data to sign. */
+  readonly data: Uint8Array;
+  /** The public key corresponding to `keyId`. */
+  readonly publicKey: Jwk;
 }"#;
 
 fn uint8array_to_bytes(value: JsValue) -> KeyStorageResult<Vec<u8>> {
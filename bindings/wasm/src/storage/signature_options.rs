@@ -23,6 +23,24 @@ impl WasmJwsSignatureOptions {
     }
   }
 
+  /// Options for credentials and presentations encoded as JWTs, as defined by the VC Data Model v1.1.
+  #[wasm_bindgen(js_name = vcJwt11)]
+  pub fn vc_jwt_1_1() -> WasmJwsSignatureOptions {
+    WasmJwsSignatureOptions(JwsSignatureOptions::vc_jwt_1_1())
+  }
+
+  /// Options for the issuer-signed JWT of an SD-JWT VC, as defined by SD-JWT VC draft 04.
+  #[wasm_bindgen(js_name = sdJwtVcDraft04)]
+  pub fn sd_jwt_vc_draft_04() -> WasmJwsSignatureOptions {
+    WasmJwsSignatureOptions(JwsSignatureOptions::sd_jwt_vc_draft_04())
+  }
+
+  /// Options for the Key Binding JWT of an SD-JWT.
+  #[wasm_bindgen(js_name = kbJwt)]
+  pub fn kb_jwt() -> WasmJwsSignatureOptions {
+    WasmJwsSignatureOptions(JwsSignatureOptions::kb_jwt())
+  }
+
   /// Replace the value of the `attachJwk` field.
   #[wasm_bindgen(js_name = setAttachJwk)]
   pub fn set_attach_jwk(&mut self, value: bool) {
@@ -72,6 +90,12 @@ impl WasmJwsSignatureOptions {
     self.0.detached_payload = value;
   }
 
+  /// Replace the value of the `crit` field.
+  #[wasm_bindgen(js_name = setCrit)]
+  pub fn set_crit(&mut self, value: Vec<String>) {
+    self.0.crit = Some(value);
+  }
+
   /// Add additional header parameters.
   #[wasm_bindgen(js_name = setCustomHeaderParameters)]
   pub fn set_custom_header_parameters(&mut self, value: RecordStringAny) -> Result<()> {
@@ -144,6 +168,12 @@ interface IJwsSignatureOptions {
      */
     readonly detachedPayload?: boolean
 
+    /** Header parameters to be listed as critical in the protected header, in addition to `b64` if it is disabled.
+     *
+     * [More Info](https://www.rfc-editor.org/rfc/rfc7515#section-4.1.11)
+     */
+    readonly crit?: string[];
+
     /**
      * Additional header parameters.
      */
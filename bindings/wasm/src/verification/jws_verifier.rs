@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_ecdsa_verifier::EcDSAJwsVerifier;
+use identity_ecdsa_verifier::WebAuthnJwsVerifier;
 use identity_eddsa_verifier::Ed25519Verifier;
 use identity_eddsa_verifier::EdDSAJwsVerifier;
 use identity_iota::verification::jws::JwsAlgorithm;
@@ -121,3 +122,44 @@ impl WasmEcDSAJwsVerifier {
     EcDSAJwsVerifier::default().verify(input, &publicKey.0).wasm_result()
   }
 }
+
+/// An implementor of `IJwsVerifier` that can handle `ES256` signatures created by WebAuthn authenticators,
+/// such as passkeys, in addition to plain `ES256` signatures.
+#[wasm_bindgen(js_name = WebAuthnJwsVerifier)]
+pub struct WasmWebAuthnJwsVerifier(WebAuthnJwsVerifier);
+
+#[wasm_bindgen(js_class = WebAuthnJwsVerifier)]
+impl WasmWebAuthnJwsVerifier {
+  /// Constructs a WebAuthnJwsVerifier.
+  ///
+  /// If `rpId` is given, only assertions created for the relying party with that id are accepted.
+  #[wasm_bindgen(constructor)]
+  #[allow(non_snake_case)]
+  pub fn new(rpId: Option<String>) -> Self {
+    let verifier = WebAuthnJwsVerifier::new();
+    Self(match rpId {
+      Some(rp_id) => verifier.with_rp_id(rp_id),
+      None => verifier,
+    })
+  }
+
+  /// Verify a JWS signature secured with the `ES256` algorithm, created either by a WebAuthn authenticator or
+  /// directly with the private key.
+  #[wasm_bindgen]
+  #[allow(non_snake_case)]
+  pub fn verify(
+    &self,
+    alg: WasmJwsAlgorithm,
+    signingInput: &[u8],
+    decodedSignature: &[u8],
+    publicKey: &WasmJwk,
+  ) -> Result<(), JsValue> {
+    let alg: JwsAlgorithm = JwsAlgorithm::try_from(alg)?;
+    let input: VerificationInput = VerificationInput {
+      alg,
+      signing_input: signingInput.into(),
+      decoded_signature: decodedSignature.into(),
+    };
+    self.0.verify(input, &publicKey.0).wasm_result()
+  }
+}
@@ -6,12 +6,15 @@ import {
     CoreDID,
     CoreDocument,
     EdCurve,
+    features,
     Jwk,
     JwkType,
     MethodRelationship,
     MethodScope,
     MethodType,
+    RevocationBitmap,
     Service,
+    Timestamp,
     VerificationMethod,
 } from "../node";
 
@@ -330,3 +333,26 @@ describe("CoreDocument", function() {
         });
     });
 });
+
+describe("features", function() {
+    it("reports the compiled in capabilities", () => {
+        const report = features();
+        assert.ok(report.version);
+        assert.ok(report.features.includes("client"));
+        assert.ok(report.credentialFormats.includes("jwt-vc"));
+        assert.ok(report.deprecatedApis.length > 0);
+    });
+});
+
+describe("BigInt", function() {
+    it("round-trips Unix timestamps", () => {
+        const timestamp = Timestamp.fromUnix(1700000000n);
+        assert.deepStrictEqual(timestamp.toUnix(), 1700000000n);
+        assert.deepStrictEqual(timestamp.toRFC3339(), "2023-11-14T22:13:20Z");
+    });
+    it("returns the revocation bitmap length", () => {
+        const bitmap = new RevocationBitmap();
+        bitmap.revoke(4294967295);
+        assert.deepStrictEqual(bitmap.len(), 1n);
+    });
+});
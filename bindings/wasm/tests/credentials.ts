@@ -2,17 +2,24 @@ import * as assert from "assert";
 import {
     CoreDocument,
     Credential,
+    CredentialStatusOutcome,
+    DomainLinkageConfiguration,
     EdDSAJwsVerifier,
+    fetchDomainLinkageConfiguration,
     JwkMemStore,
     JwsAlgorithm,
     JwsSignatureOptions,
     JwsVerificationOptions,
+    Jwt,
+    JwtCredentialValidationOptions,
+    JwtCredentialValidator,
     JwtPresentationOptions,
     JwtPresentationValidationOptions,
     JwtPresentationValidator,
     KeyIdMemStore,
     MethodScope,
     Presentation,
+    setDefaultFetch,
     Storage,
     Timestamp,
     UnknownCredential,
@@ -132,6 +139,8 @@ const presentationFields = {
         "eyJraWQiOiJkaWQ6aW90YTp0c3QxOjB4MDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMCNrZXktMSIsImFsZyI6IkVkRFNBIn0.eyJpc3MiOiJkaWQ6aW90YTp0c3QxOjB4MDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMCIsIm5iZiI6MTY4NzUyMTI2MiwianRpIjoiaHR0cHM6Ly9leGFtcGxlLmVkdS9jcmVkZW50aWFscy8zNzMyIiwic3ViIjoiZGlkOmlvdGE6dHN0MjoweDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAiLCJ2YyI6eyJAY29udGV4dCI6WyJodHRwczovL3d3dy53My5vcmcvMjAxOC9jcmVkZW50aWFscy92MSIsImh0dHBzOi8vd3d3LnczLm9yZy8yMDE4L2NyZWRlbnRpYWxzL2V4YW1wbGVzL3YxIl0sInR5cGUiOlsiVmVyaWZpYWJsZUNyZWRlbnRpYWwiLCJVbml2ZXJzaXR5RGVncmVlQ3JlZGVudGlhbCJdLCJjcmVkZW50aWFsU3ViamVjdCI6eyJkZWdyZWUiOnsibmFtZSI6IkJhY2hlbG9yIG9mIFNjaWVuY2UgYW5kIEFydHMiLCJ0eXBlIjoiQmFjaGVsb3JEZWdyZWUifX19fQ.5WmLOTwOBa5Vxuu1cGkGX4wnD6efNulg1tATy-B3_ZsyC8koG1vTpKH4WWoLMkSyQX2F2qw6EyMSjRFJ_dy4Bg",
     ],
     holder: "did:example:1234",
+    issuanceDate: Timestamp.parse("2023-09-14T13:42:31Z"),
+    expirationDate: Timestamp.parse("2023-09-14T13:52:31Z"),
     refreshService: {
         id: "https://example.edu/refresh/3732",
         type: "ManualRefreshService2018",
@@ -171,6 +180,14 @@ describe("Presentation", function() {
                 presentationFields.verifiableCredential[0],
             );
             assert.deepStrictEqual(presentation.holder(), presentationFields.holder);
+            assert.deepStrictEqual(
+                presentation.issuanceDate()!.toRFC3339(),
+                presentationFields.issuanceDate.toRFC3339(),
+            );
+            assert.deepStrictEqual(
+                presentation.expirationDate()!.toRFC3339(),
+                presentationFields.expirationDate.toRFC3339(),
+            );
             assert.deepStrictEqual(presentation.refreshService(), [
                 presentationFields.refreshService,
             ]);
@@ -278,6 +295,68 @@ describe("Presentation", function() {
                 unsignedVc.toJSON(),
             );
             assert.deepStrictEqual(credentials[2].tryIntoRaw()!, otherCredential);
+
+            // Credentials are validated one by one.
+            const results = new JwtCredentialValidator(new EdDSAJwsVerifier()).validatePresentationCredentials(
+                decodedPresentation.presentation(),
+                [doc],
+                new JwtCredentialValidationOptions(),
+            );
+            assert.deepStrictEqual(results.length, 3);
+            assert.deepStrictEqual(results[0].valid(), true);
+            assert.deepStrictEqual(results[0].id(), unsignedVc.id());
+            assert.deepStrictEqual(results[0].status(), CredentialStatusOutcome.NotChecked);
+            assert.deepStrictEqual(results[1].index(), 1);
+            assert.deepStrictEqual(results[1].valid(), false);
+            assert.deepStrictEqual(results[1].errorCodes(), ["UnsupportedCredentialFormat"]);
+
+            // Credentials can be accessed one at a time.
+            const presentation = decodedPresentation.presentation();
+            assert.deepStrictEqual(presentation.verifiableCredentialCount(), 3);
+            assert.deepStrictEqual(
+                presentation.verifiableCredentialAt(0)?.tryIntoJwt()?.toString(),
+                credentialJwt.toString(),
+            );
+            assert.deepStrictEqual(presentation.verifiableCredentialAt(3), undefined);
+
+            // JSON strings and raw bytes are exchanged without intermediate objects.
+            assert.deepStrictEqual(
+                Presentation.fromJSONString(presentation.toJSONString()).toJSON(),
+                presentation.toJSON(),
+            );
+            const jwtBytes = presentationJwt.toBytes();
+            assert.deepStrictEqual(jwtBytes.length, presentationJwt.length);
+            assert.deepStrictEqual(Jwt.fromBytes(jwtBytes).toString(), presentationJwt.toString());
+            assert.deepStrictEqual(JSON.parse(presentationJwt.payloadJSON()).iss, doc.id().toString());
+        });
+    });
+});
+
+describe("DomainLinkageConfiguration", function() {
+    describe("#fetch", () => {
+        it("uses the injected fetch", async () => {
+            const configuration = new DomainLinkageConfiguration([new Jwt("header.payload.signature")]);
+            const requested: string[] = [];
+            const fetch = async (input: string) => {
+                requested.push(input);
+                return new Response(JSON.stringify(configuration.toJSON()));
+            };
+
+            const fetched = await fetchDomainLinkageConfiguration("https://foo.example.com", { fetch });
+            assert.deepStrictEqual(requested, ["https://foo.example.com/.well-known/did-configuration.json"]);
+            assert.deepStrictEqual(fetched.toJSON(), configuration.toJSON());
+
+            // The default fetch is used if none is passed.
+            setDefaultFetch(fetch);
+            try {
+                await fetchDomainLinkageConfiguration("https://bar.example.com");
+            } finally {
+                setDefaultFetch(undefined);
+            }
+            assert.deepStrictEqual(requested[1], "https://bar.example.com/.well-known/did-configuration.json");
+
+            await assert.rejects(fetchDomainLinkageConfiguration("http://foo.example.com", { fetch }));
+            await assert.rejects(fetchDomainLinkageConfiguration("https://foo.example.com/path", { fetch }));
         });
     });
 });
@@ -6,6 +6,7 @@ import {
     EdCurve,
     IotaDID,
     IotaDocument,
+    IotaIdentityClient,
     Jwk,
     JwkType,
     MethodRelationship,
@@ -245,3 +246,61 @@ describe("IotaDocument", function() {
         });
     });
 });
+
+describe("IotaIdentityClient", function() {
+    // Fakes a client whose requests fail the given number of times before succeeding.
+    function fakeClient(hrp: string, failures: number): any {
+        const client = {
+            calls: 0,
+            async getBech32Hrp() {
+                client.calls += 1;
+                if (client.calls <= failures) {
+                    throw new Error("node unavailable");
+                }
+                return hrp;
+            },
+        };
+        return client;
+    }
+
+    describe("#retry", function() {
+        it("should not retry by default", async () => {
+            const primary = fakeClient(networkName, 1);
+            const identityClient = new IotaIdentityClient(primary);
+            await assert.rejects(identityClient.getNetworkHrp(), /node unavailable/);
+            assert.deepStrictEqual(primary.calls, 1);
+        });
+        it("should retry with backoff", async () => {
+            const primary = fakeClient(networkName, 2);
+            const identityClient = new IotaIdentityClient(primary, { maxRetries: 2, backoff: { initialDelay: 1 } });
+            assert.deepStrictEqual(await identityClient.getNetworkHrp(), networkName);
+            assert.deepStrictEqual(primary.calls, 3);
+        });
+        it("should not retry non-retryable errors", async () => {
+            const primary = fakeClient(networkName, 1);
+            const identityClient = new IotaIdentityClient(primary, {
+                maxRetries: 2,
+                backoff: { initialDelay: 1 },
+                isRetryable: () => false,
+            });
+            await assert.rejects(identityClient.getNetworkHrp(), /node unavailable/);
+            assert.deepStrictEqual(primary.calls, 1);
+        });
+    });
+    describe("#failover", function() {
+        it("should fail over to the fallback clients", async () => {
+            const primary = fakeClient(networkName, Infinity);
+            const fallback = fakeClient(networkName, 0);
+            const identityClient = new IotaIdentityClient(primary, {
+                fallbackClients: [fallback],
+                maxRetries: 1,
+                backoff: { initialDelay: 1 },
+            });
+            assert.deepStrictEqual(await identityClient.getNetworkHrp(), networkName);
+            // The fallback client remains active.
+            assert.deepStrictEqual(await identityClient.getNetworkHrp(), networkName);
+            assert.deepStrictEqual(primary.calls, 1);
+            assert.deepStrictEqual(fallback.calls, 2);
+        });
+    });
+});
@@ -19,6 +19,13 @@ describe("#JwkMemstore", function() {
         assert.ok(await memstore.exists(keyId));
         assert.ok(!await memstore.exists("non-existent-key-id"));
 
+        const signatures = await memstore.signMany([
+            { keyId, data: testData, publicKey: jwk.toPublic()! },
+            { keyId, data: Uint8Array.from([0x01]), publicKey: jwk.toPublic()! },
+        ]);
+        assert.deepStrictEqual(signatures.length, 2);
+        assert.deepStrictEqual(signatures[0], signature);
+
         assert.doesNotReject(async () => {
             await memstore.delete(keyId);
         });
@@ -1,5 +1,22 @@
 import * as assert from "assert";
-import { SdObjectDecoder, SdObjectEncoder } from "../node";
+import {
+    CoreDocument,
+    EdDSAJwsVerifier,
+    JwkMemStore,
+    JwsAlgorithm,
+    JwsSignatureOptions,
+    JwsVerificationOptions,
+    KeyBindingJwtClaims,
+    KeyBindingJWTValidationOptions,
+    KeyIdMemStore,
+    MethodScope,
+    SdJwt,
+    SdJwtCredentialValidator,
+    SdObjectDecoder,
+    SdObjectEncoder,
+    Storage,
+    Timestamp,
+} from "../node";
 
 describe("sd-jwt-payload", function() {
     describe("#encoder", function() {
@@ -51,4 +68,52 @@ describe("sd-jwt-payload", function() {
             assert.deepStrictEqual(obj, decoded);
         });
     });
+
+    describe("#key binding", function() {
+        it("should validate a KB-JWT without disclosures", async () => {
+            const storage = new Storage(new JwkMemStore(), new KeyIdMemStore());
+            const holder = new CoreDocument({ id: "did:example:holder" });
+            const fragment = "#key-1";
+            await holder.generateMethod(
+                storage,
+                JwkMemStore.ed25519KeyType(),
+                JwsAlgorithm.EdDSA,
+                fragment,
+                MethodScope.VerificationMethod(),
+            );
+
+            let encoder = new SdObjectEncoder({ "sub": "did:example:holder" });
+            encoder.addSdAlgProperty();
+            const jwt = await holder.createJws(
+                storage,
+                fragment,
+                encoder.encodeToString(),
+                new JwsSignatureOptions(),
+            );
+
+            const claims = new KeyBindingJwtClaims(
+                jwt.toString(),
+                [],
+                "nonce",
+                "did:example:verifier",
+                Timestamp.nowUTC(),
+            );
+            const kbJwt = await holder.createJws(
+                storage,
+                fragment,
+                claims.toString(),
+                new JwsSignatureOptions({ typ: KeyBindingJwtClaims.keyBindingJwtHeaderTyp() }),
+            );
+            const sdJwt = new SdJwt(jwt.toString(), [], kbJwt.toString());
+
+            const validator = new SdJwtCredentialValidator(new EdDSAJwsVerifier());
+            const options = new KeyBindingJWTValidationOptions({
+                aud: "did:example:verifier",
+                nonce: "nonce",
+                jwsOptions: new JwsVerificationOptions(),
+            });
+            const validated = validator.validateKeyBindingJwt(sdJwt, holder, options);
+            assert.deepStrictEqual(validated.sdHash(), claims.sdHash());
+        });
+    });
 });
@@ -3,6 +3,7 @@ import {
     CoreDocument,
     Credential,
     DecodedJwtPresentation,
+    DomainLinkageGenerator,
     Duration,
     EdDSAJwsVerifier,
     FailFast,
@@ -16,6 +17,7 @@ import {
     Jwt,
     JwtCredentialValidationOptions,
     JwtCredentialValidator,
+    JwtDomainLinkageValidator,
     JwtPresentationOptions,
     JwtPresentationValidationOptions,
     JwtPresentationValidator,
@@ -535,3 +537,41 @@ describe("#Documents throw error on concurrent synchronous access", async functi
         assert.ok(resolvedToError, "Promise.all did not throw an error");
     });
 });
+
+describe("#DomainLinkageGenerator", function() {
+    it("generates domain linkage configurations and syncs the service", async () => {
+        const storage = new Storage(new JwkMemStore(), new KeyIdMemStore());
+        const doc = new CoreDocument({
+            id: "did:example:123",
+        });
+        const fragment = "#key-1";
+        await doc.generateMethod(
+            storage,
+            JwkMemStore.ed25519KeyType(),
+            JwsAlgorithm.EdDSA,
+            fragment,
+            MethodScope.VerificationMethod(),
+        );
+
+        const domains = ["https://foo.example.com/", "https://bar.example.com/"];
+        const generator = new DomainLinkageGenerator({
+            domains,
+            expirationDate: Timestamp.nowUTC().checkedAdd(Duration.weeks(10))!,
+        });
+        assert.deepStrictEqual(generator.domains(), domains);
+
+        const linkages = await generator.generate(doc, storage, fragment);
+        assert.deepStrictEqual(linkages.map((linkage) => linkage.origin()), domains);
+        for (const linkage of linkages) {
+            new JwtDomainLinkageValidator(new EdDSAJwsVerifier()).validateLinkage(
+                doc,
+                linkage.configuration(),
+                linkage.origin(),
+                new JwtCredentialValidationOptions(),
+            );
+        }
+
+        const serviceId = generator.syncLinkedDomainService(doc, "linked-domains");
+        assert.deepStrictEqual(doc.resolveService(serviceId.toString())?.type(), ["LinkedDomains"]);
+    });
+});
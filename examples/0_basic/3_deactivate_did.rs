@@ -64,7 +64,7 @@ async fn main() -> anyhow::Result<()> {
   assert_eq!(deactivated.metadata.deactivated, Some(true));
 
   // Re-activate the DID by publishing a valid DID document.
-  let reactivated_output: AliasOutput = client.update_did_output(document.clone()).await?;
+  let reactivated_output: AliasOutput = client.reactivate_did_output(document.clone()).await?;
 
   // Increase the storage deposit to the minimum again, if it was reclaimed during deactivation.
   let rent_structure = client.get_rent_structure().await?;
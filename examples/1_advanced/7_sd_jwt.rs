@@ -15,7 +15,6 @@ use identity_eddsa_verifier::EdDSAJwsVerifier;
 use identity_iota::core::json;
 use identity_iota::core::FromJson;
 use identity_iota::core::Object;
-use identity_iota::core::Timestamp;
 use identity_iota::core::ToJson;
 use identity_iota::core::Url;
 use identity_iota::credential::Credential;
@@ -24,6 +23,7 @@ use identity_iota::credential::FailFast;
 use identity_iota::credential::Jws;
 use identity_iota::credential::JwtCredentialValidationOptions;
 use identity_iota::credential::KeyBindingJWTValidationOptions;
+use identity_iota::credential::KeyBindingJwtBuilder;
 use identity_iota::credential::SdJwtCredentialValidator;
 use identity_iota::credential::Subject;
 use identity_iota::did::DID;
@@ -167,17 +167,14 @@ async fn main() -> anyhow::Result<()> {
   ];
 
   // Optionally, the holder can add a Key Binding JWT (KB-JWT). This is dependent on the verifier's policy.
-  // Issuing the KB-JWT is done by creating the claims set and setting the header `typ` value
-  // with the help of `KeyBindingJwtClaims`.
-  let binding_claims = KeyBindingJwtClaims::new(
-    &Sha256Hasher::new(),
-    sd_jwt.jwt.as_str().to_string(),
-    disclosures.clone(),
-    nonce.to_string(),
-    VERIFIER_DID.to_string(),
-    Timestamp::now_utc().to_unix(),
-  )
-  .to_json()?;
+  // Issuing the KB-JWT is done by creating the claims set, whose `sd_hash` is computed from the presented SD-JWT,
+  // with the help of `KeyBindingJwtBuilder` and setting the header `typ` value.
+  let mut sd_jwt_obj = SdJwt::new(sd_jwt.jwt, disclosures, None);
+  let binding_claims = KeyBindingJwtBuilder::new()
+    .nonce(nonce)
+    .aud(VERIFIER_DID)
+    .finish(&sd_jwt_obj, &Sha256Hasher::new())?
+    .to_json()?;
 
   // Setting the `typ` in the header is required.
   let options = JwsSignatureOptions::new().typ(KeyBindingJwtClaims::KB_JWT_HEADER_TYP);
@@ -188,7 +185,7 @@ async fn main() -> anyhow::Result<()> {
     .await?;
 
   // Create the final SD-JWT.
-  let sd_jwt_obj = SdJwt::new(sd_jwt.jwt, disclosures, Some(kb_jwt.into()));
+  sd_jwt_obj.key_binding_jwt = Some(kb_jwt.into());
 
   // ===========================================================================
   // Step 6: Holder presents the SD-JWT to the verifier.
@@ -0,0 +1,156 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use crate::common::Duration;
+use crate::common::Timestamp;
+
+/// A source of the current time.
+///
+/// Allows validation to rely on a time other than the local system time, e.g. [`NetworkTime`].
+pub trait Clock {
+  /// Returns the current time according to this clock.
+  fn now(&self) -> Timestamp;
+}
+
+/// A [`Clock`] returning the local system time through [`Timestamp::now_utc`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+  fn now(&self) -> Timestamp {
+    Timestamp::now_utc()
+  }
+}
+
+/// A [`Clock`] following the time of a network, e.g. the timestamp of the latest milestone reported by a node.
+///
+/// Created from a sample of the network time and the local time at which it was taken. The current network time is
+/// estimated by applying the offset between both to the local time, which also allows detecting a drift of the
+/// local clock with [`NetworkTime::check_drift`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NetworkTime {
+  network_time: Timestamp,
+  local_time: Timestamp,
+}
+
+impl NetworkTime {
+  /// Creates a new [`NetworkTime`] from the `network_time` observed at `local_time`.
+  pub fn new(network_time: Timestamp, local_time: Timestamp) -> Self {
+    Self {
+      network_time,
+      local_time,
+    }
+  }
+
+  /// Creates a new [`NetworkTime`] from the `network_time` observed just now.
+  pub fn sample(network_time: Timestamp) -> Self {
+    Self::new(network_time, Timestamp::now_utc())
+  }
+
+  /// Returns the sampled network time.
+  pub fn network_time(&self) -> Timestamp {
+    self.network_time
+  }
+
+  /// Returns the local time at which the network time was sampled.
+  pub fn local_time(&self) -> Timestamp {
+    self.local_time
+  }
+
+  /// Returns the offset of the network time from the local time in seconds, which is positive if the local clock is
+  /// behind the network.
+  pub fn offset_seconds(&self) -> i64 {
+    self.network_time.to_unix() - self.local_time.to_unix()
+  }
+
+  /// Returns a [`ClockDrift`] if the local time differs from the network time by more than `threshold`.
+  ///
+  /// Note that the network time is typically slightly behind the actual time, e.g. by the interval at which
+  /// milestones are issued, which should be accounted for in `threshold`.
+  pub fn check_drift(&self, threshold: Duration) -> Option<ClockDrift> {
+    let lower_bound: Option<Timestamp> = self.network_time.checked_sub(threshold);
+    let upper_bound: Option<Timestamp> = self.network_time.checked_add(threshold);
+    let is_within_threshold: bool = lower_bound.map_or(true, |bound| self.local_time >= bound)
+      && upper_bound.map_or(true, |bound| self.local_time <= bound);
+
+    (!is_within_threshold).then_some(ClockDrift {
+      offset_seconds: self.offset_seconds(),
+    })
+  }
+}
+
+impl Clock for NetworkTime {
+  fn now(&self) -> Timestamp {
+    let now: Timestamp = Timestamp::now_utc();
+    Timestamp::from_unix(now.to_unix() + self.offset_seconds()).unwrap_or(now)
+  }
+}
+
+/// A drift of the local clock from the network time exceeding a threshold, as detected by
+/// [`NetworkTime::check_drift`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClockDrift {
+  offset_seconds: i64,
+}
+
+impl ClockDrift {
+  /// Returns the offset of the network time from the local time in seconds, which is positive if the local clock is
+  /// behind the network.
+  pub fn offset_seconds(&self) -> i64 {
+    self.offset_seconds
+  }
+}
+
+impl Display for ClockDrift {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    let direction: &str = if self.offset_seconds > 0 { "behind" } else { "ahead of" };
+    write!(
+      f,
+      "the local clock is {} seconds {direction} the network time",
+      self.offset_seconds.unsigned_abs()
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn timestamp(input: &str) -> Timestamp {
+    Timestamp::parse(input).unwrap()
+  }
+
+  #[test]
+  fn test_check_drift() {
+    let network_time = timestamp("2024-01-01T12:00:00Z");
+
+    let in_sync = NetworkTime::new(network_time, timestamp("2024-01-01T12:00:20Z"));
+    assert_eq!(in_sync.offset_seconds(), -20);
+    assert!(in_sync.check_drift(Duration::minutes(1)).is_none());
+
+    let ahead = NetworkTime::new(network_time, timestamp("2024-01-01T12:05:00Z"));
+    let drift: ClockDrift = ahead.check_drift(Duration::minutes(1)).unwrap();
+    assert_eq!(drift.offset_seconds(), -300);
+    assert_eq!(
+      drift.to_string(),
+      "the local clock is 300 seconds ahead of the network time"
+    );
+
+    let behind = NetworkTime::new(network_time, timestamp("2024-01-01T11:58:30Z"));
+    assert_eq!(behind.check_drift(Duration::minutes(1)).unwrap().offset_seconds(), 90);
+  }
+
+  #[test]
+  fn test_network_time_now() {
+    let local_time: Timestamp = Timestamp::now_utc();
+    let network_time: Timestamp = local_time.checked_add(Duration::hours(1)).unwrap();
+    let clock = NetworkTime::new(network_time, local_time);
+
+    let now: Timestamp = clock.now();
+    assert!(now >= network_time);
+    assert!(now < network_time.checked_add(Duration::minutes(1)).unwrap());
+  }
+}
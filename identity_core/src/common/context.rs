@@ -16,6 +16,7 @@ use crate::common::Url;
 ///
 /// [More Info](https://www.w3.org/TR/vc-data-model/#contexts)
 #[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum Context {
   /// A JSON-LD context expressed as a Url.
@@ -0,0 +1,261 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::OneOrMany;
+use super::Value;
+
+/// A string tagged with the language and, optionally, the base direction it is written in.
+///
+/// Serialized as a JSON-LD value object, e.g. `{ "@value": "Bachelor of Science", "@language": "en" }`.
+/// See: <https://www.w3.org/TR/vc-data-model-2.0/#language-and-base-direction>
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LanguageValue {
+  /// The string value.
+  #[serde(rename = "@value")]
+  pub value: String,
+  /// The BCP 47 language tag of `value`, if any.
+  #[serde(rename = "@language", default, skip_serializing_if = "Option::is_none")]
+  pub language: Option<String>,
+  /// The base direction of `value`, either `ltr` or `rtl`, if any.
+  #[serde(rename = "@direction", default, skip_serializing_if = "Option::is_none")]
+  pub direction: Option<String>,
+}
+
+impl LanguageValue {
+  /// Creates a new [`LanguageValue`] of `value` written in `language`.
+  pub fn new(value: impl Into<String>, language: impl Into<String>) -> Self {
+    Self {
+      value: value.into(),
+      language: Some(language.into()),
+      direction: None,
+    }
+  }
+
+  /// Creates a new [`LanguageValue`] without a language.
+  pub fn untagged(value: impl Into<String>) -> Self {
+    Self {
+      value: value.into(),
+      language: None,
+      direction: None,
+    }
+  }
+
+  /// Sets the base direction of the value.
+  #[must_use]
+  pub fn with_direction(mut self, direction: impl Into<String>) -> Self {
+    self.direction = Some(direction.into());
+    self
+  }
+
+  /// Returns whether the value is written in `language`, compared case-insensitively.
+  pub fn is_language(&self, language: &str) -> bool {
+    self
+      .language
+      .as_deref()
+      .is_some_and(|tag| tag.eq_ignore_ascii_case(language))
+  }
+}
+
+/// A string available in several languages, e.g. the name or description of a credential.
+///
+/// Serialized as a single value object, or an array of value objects for more than one language. Plain strings are
+/// accepted as untagged values when deserializing.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "OneOrMany<LanguageValueRepr>", into = "OneOrMany<LanguageValue>")]
+pub struct LanguageMap(Vec<LanguageValue>);
+
+impl LanguageMap {
+  /// Creates an empty [`LanguageMap`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the number of values.
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Returns whether there are no values.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Returns an iterator over the values.
+  pub fn iter(&self) -> impl Iterator<Item = &LanguageValue> {
+    self.0.iter()
+  }
+
+  /// Inserts `value`, replacing and returning an existing value in the same language.
+  pub fn insert(&mut self, value: LanguageValue) -> Option<LanguageValue> {
+    let existing: Option<&mut LanguageValue> = self.0.iter_mut().find(|entry| match &value.language {
+      Some(language) => entry.is_language(language),
+      None => entry.language.is_none(),
+    });
+    match existing {
+      Some(entry) => Some(std::mem::replace(entry, value)),
+      None => {
+        self.0.push(value);
+        None
+      }
+    }
+  }
+
+  /// Inserts `value` written in `language`. See [`LanguageMap::insert`].
+  #[must_use]
+  pub fn with(mut self, language: impl Into<String>, value: impl Into<String>) -> Self {
+    self.insert(LanguageValue::new(value, language));
+    self
+  }
+
+  /// Returns the value written in exactly `language`, compared case-insensitively.
+  pub fn get(&self, language: &str) -> Option<&LanguageValue> {
+    self.0.iter().find(|entry| entry.is_language(language))
+  }
+
+  /// Returns the value without a language, if any.
+  pub fn untagged(&self) -> Option<&LanguageValue> {
+    self.0.iter().find(|entry| entry.language.is_none())
+  }
+
+  /// Returns the value best matching the first possible locale of `locales`, in order of preference.
+  ///
+  /// Each locale is matched by truncating its subtags from the end, e.g. `de-CH` falls back to `de`. If no locale
+  /// matches, the untagged value is returned, followed by the first value.
+  pub fn lookup<'a, I>(&self, locales: I) -> Option<&LanguageValue>
+  where
+    I: IntoIterator<Item = &'a str>,
+  {
+    locales
+      .into_iter()
+      .find_map(|locale| fallback_chain(locale).find_map(|language| self.get(language)))
+      .or_else(|| self.untagged())
+      .or_else(|| self.0.first())
+  }
+}
+
+impl FromIterator<LanguageValue> for LanguageMap {
+  fn from_iter<I: IntoIterator<Item = LanguageValue>>(iter: I) -> Self {
+    let mut map: Self = Self::new();
+    for value in iter {
+      map.insert(value);
+    }
+    map
+  }
+}
+
+impl From<LanguageValue> for LanguageMap {
+  fn from(value: LanguageValue) -> Self {
+    Self(vec![value])
+  }
+}
+
+impl From<LanguageValue> for Value {
+  fn from(value: LanguageValue) -> Self {
+    let mut object: serde_json::Map<String, Value> = serde_json::Map::new();
+    object.insert("@value".to_owned(), Value::String(value.value));
+    if let Some(language) = value.language {
+      object.insert("@language".to_owned(), Value::String(language));
+    }
+    if let Some(direction) = value.direction {
+      object.insert("@direction".to_owned(), Value::String(direction));
+    }
+    Value::Object(object)
+  }
+}
+
+impl From<LanguageMap> for Value {
+  fn from(map: LanguageMap) -> Self {
+    match OneOrMany::from(map) {
+      OneOrMany::One(value) => value.into(),
+      OneOrMany::Many(values) => Value::Array(values.into_iter().map(Value::from).collect()),
+    }
+  }
+}
+
+impl From<LanguageMap> for OneOrMany<LanguageValue> {
+  fn from(map: LanguageMap) -> Self {
+    OneOrMany::from(map.0)
+  }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for LanguageMap {
+  fn schema_name() -> String {
+    "LanguageMap".to_owned()
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    <OneOrMany<LanguageValue>>::json_schema(gen)
+  }
+}
+
+impl From<OneOrMany<LanguageValueRepr>> for LanguageMap {
+  fn from(values: OneOrMany<LanguageValueRepr>) -> Self {
+    values
+      .into_iter()
+      .map(|value| match value {
+        LanguageValueRepr::String(value) => LanguageValue::untagged(value),
+        LanguageValueRepr::Value(value) => value,
+      })
+      .collect()
+  }
+}
+
+/// Accepts plain strings in place of value objects.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum LanguageValueRepr {
+  String(String),
+  Value(LanguageValue),
+}
+
+/// Yields `locale` followed by its prefixes, e.g. `de-CH-1996`, `de-CH` and `de`.
+fn fallback_chain(locale: &str) -> impl Iterator<Item = &str> {
+  std::iter::successors(Some(locale), |tag| tag.rfind('-').map(|idx| &tag[..idx])).filter(|tag| !tag.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::convert::FromJson;
+  use crate::convert::ToJson;
+
+  use super::*;
+
+  #[test]
+  fn test_serde() {
+    let map: LanguageMap = LanguageMap::new().with("en", "Example University");
+    assert_eq!(
+      map.to_json().unwrap(),
+      r#"{"@value":"Example University","@language":"en"}"#
+    );
+
+    let map: LanguageMap = map.with("ar", "جامعة المثال");
+    assert_eq!(LanguageMap::from_json(&map.to_json().unwrap()).unwrap(), map);
+
+    let map: LanguageMap = LanguageMap::from_json(r#"["Example", {"@value": "Beispiel", "@language": "de"}]"#).unwrap();
+    assert_eq!(map.untagged().unwrap().value, "Example");
+    assert_eq!(map.get("DE").unwrap().value, "Beispiel");
+  }
+
+  #[test]
+  fn test_lookup() {
+    let map: LanguageMap = LanguageMap::new()
+      .with("en", "Colour")
+      .with("en-US", "Color")
+      .with("de", "Farbe");
+
+    assert_eq!(map.lookup(["en-US"]).unwrap().value, "Color");
+    assert_eq!(map.lookup(["en-GB"]).unwrap().value, "Colour");
+    assert_eq!(map.lookup(["fr-CH", "de-CH"]).unwrap().value, "Farbe");
+    assert_eq!(map.lookup(["fr"]).unwrap().value, "Colour");
+    assert!(LanguageMap::new().lookup(["en"]).is_none());
+
+    let map: LanguageMap = map.with("en", "Hue");
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.lookup(["en"]).unwrap().value, "Hue");
+  }
+}
@@ -3,8 +3,14 @@
 
 //! Definitions of common types (`Url`, `Timestamp`, JSON types, etc).
 
+pub use self::clock::Clock;
+pub use self::clock::ClockDrift;
+pub use self::clock::NetworkTime;
+pub use self::clock::SystemClock;
 pub use self::context::Context;
 pub use self::key_comparable::KeyComparable;
+pub use self::language_map::LanguageMap;
+pub use self::language_map::LanguageValue;
 pub use self::object::Object;
 pub use self::object::Value;
 pub use self::one_or_many::OneOrMany;
@@ -16,8 +22,10 @@ pub use self::timestamp::Timestamp;
 pub use self::url::Url;
 pub use string_or_url::StringOrUrl;
 
+mod clock;
 mod context;
 mod key_comparable;
+mod language_map;
 mod object;
 mod one_or_many;
 mod one_or_set;
@@ -316,6 +316,32 @@ where
   }
 }
 
+/// Either a single instance of `T` or a non-empty array of unique instances.
+#[cfg(feature = "schemars")]
+impl<T> schemars::JsonSchema for OneOrSet<T>
+where
+  T: KeyComparable + schemars::JsonSchema,
+{
+  fn schema_name() -> String {
+    format!("OneOrSet_of_{}", T::schema_name())
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    let mut set: schemars::schema::SchemaObject =
+      <OrderedSet<T> as schemars::JsonSchema>::json_schema(gen).into_object();
+    set.array().min_items = Some(1);
+
+    schemars::schema::SchemaObject {
+      subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+        any_of: Some(vec![gen.subschema_for::<T>(), set.into()]),
+        ..Default::default()
+      })),
+      ..Default::default()
+    }
+    .into()
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use crate::convert::FromJson;
@@ -573,4 +599,14 @@ mod tests {
       assert!(empty_space.is_err());
     }
   }
+
+  #[cfg(feature = "schemars")]
+  #[test]
+  fn test_json_schema() {
+    let schema = serde_json::to_value(schemars::schema_for!(OneOrSet<String>)).unwrap();
+    assert_eq!(schema["anyOf"][0]["type"], "string");
+    assert_eq!(schema["anyOf"][1]["type"], "array");
+    assert_eq!(schema["anyOf"][1]["minItems"], 1);
+    assert_eq!(schema["anyOf"][1]["uniqueItems"], true);
+  }
 }
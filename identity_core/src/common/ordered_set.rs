@@ -285,6 +285,29 @@ where
   }
 }
 
+#[cfg(feature = "schemars")]
+impl<T> schemars::JsonSchema for OrderedSet<T>
+where
+  T: schemars::JsonSchema,
+{
+  fn schema_name() -> String {
+    format!("OrderedSet_of_{}", T::schema_name())
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+      instance_type: Some(schemars::schema::InstanceType::Array.into()),
+      array: Some(Box::new(schemars::schema::ArrayValidation {
+        items: Some(gen.subschema_for::<T>().into()),
+        unique_items: Some(true),
+        ..Default::default()
+      })),
+      ..Default::default()
+    }
+    .into()
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use std::collections::HashSet;
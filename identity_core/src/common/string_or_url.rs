@@ -12,6 +12,7 @@ use super::Url;
 
 /// A type that represents either an arbitrary string or a URL.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum StringOrUrl {
   /// A well-formed URL.
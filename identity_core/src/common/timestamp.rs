@@ -188,6 +188,22 @@ impl FromStr for Timestamp {
   }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Timestamp {
+  fn schema_name() -> String {
+    "Timestamp".to_owned()
+  }
+
+  fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+      instance_type: Some(schemars::schema::InstanceType::String.into()),
+      format: Some("date-time".to_owned()),
+      ..Default::default()
+    }
+    .into()
+  }
+}
+
 /// Truncates an `OffsetDateTime` to the second.
 fn truncate_fractional_seconds(offset_date_time: OffsetDateTime) -> OffsetDateTime {
   offset_date_time - time::Duration::nanoseconds(offset_date_time.nanosecond() as i64)
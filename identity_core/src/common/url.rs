@@ -97,6 +97,22 @@ impl KeyComparable for Url {
   }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Url {
+  fn schema_name() -> String {
+    "Url".to_owned()
+  }
+
+  fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+      instance_type: Some(schemars::schema::InstanceType::String.into()),
+      format: Some("uri".to_owned()),
+      ..Default::default()
+    }
+    .into()
+  }
+}
+
 impl AsRef<str> for Url {
   fn as_ref(&self) -> &str {
     self.as_str()
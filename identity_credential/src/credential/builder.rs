@@ -212,10 +212,12 @@ where
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::LanguageMap;
   use identity_core::common::Object;
   use identity_core::common::Timestamp;
   use identity_core::common::Url;
   use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
   use serde_json::json;
   use serde_json::Value;
 
@@ -282,6 +284,41 @@ mod tests {
     assert_eq!(credential.proof.unwrap().type_, "test-type");
   }
 
+  #[test]
+  fn test_credential_builder_localized_property() {
+    let name: LanguageMap = LanguageMap::new()
+      .with("en", "University Degree")
+      .with("de", "Hochschulabschluss");
+    let credential: Credential = CredentialBuilder::default()
+      .issuer(issuer())
+      .subject(subject())
+      .issuance_date(Timestamp::now_utc())
+      .property("name", name.clone())
+      .build()
+      .unwrap();
+
+    let credential: Credential = Credential::from_json(&credential.to_json().unwrap()).unwrap();
+    assert_eq!(credential.localized_property("name").unwrap(), name);
+    assert_eq!(
+      credential
+        .localized_property("name")
+        .unwrap()
+        .lookup(["de-CH"])
+        .unwrap()
+        .value,
+      "Hochschulabschluss"
+    );
+    assert!(credential.localized_property("missing").is_none());
+    assert_eq!(
+      credential
+        .credential_subject
+        .get(0)
+        .unwrap()
+        .localized_property("degree"),
+      None
+    );
+  }
+
   #[test]
   #[should_panic = "MissingSubject"]
   fn test_builder_missing_subjects() {
@@ -0,0 +1,103 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A digest identifying the content of a [`Credential`] independently of how it is secured.
+///
+/// The hash is the SHA-256 digest of the canonical JSON serialization of the credential without its `proof`, in which
+/// object members are sorted by key and no insignificant whitespace is emitted. The same credential issued again, e.g.
+/// as a JWT with a different signature, therefore yields the same hash. This allows wallets to de-duplicate
+/// re-issued credentials and verifiers to recognize the same credential across presentations.
+///
+/// The hash is displayed and parsed as unpadded base64url.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CredentialContentHash([u8; SHA256_LEN]);
+
+impl CredentialContentHash {
+  /// Computes the content hash of `credential`.
+  pub fn new<T: Serialize>(credential: &Credential<T>) -> Result<Self> {
+    let mut value: Value = serde_json::to_value(credential).map_err(|err| Error::ContentHashError(err.into()))?;
+    if let Value::Object(object) = &mut value {
+      object.remove("proof");
+    }
+
+    let mut canonical: String = String::new();
+    write_canonical_json(&value, &mut canonical).map_err(|err| Error::ContentHashError(err.into()))?;
+
+    let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+    SHA256(canonical.as_bytes(), &mut digest);
+    Ok(Self(digest))
+  }
+
+  /// Returns the bytes of the digest.
+  pub fn as_bytes(&self) -> &[u8; SHA256_LEN] {
+    &self.0
+  }
+}
+
+impl Display for CredentialContentHash {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(&BaseEncoding::encode(&self.0, Base::Base64Url))
+  }
+}
+
+impl FromStr for CredentialContentHash {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    BaseEncoding::decode(s, Base::Base64Url)
+      .map_err(|err| Error::ContentHashError(err.into()))?
+      .try_into()
+      .map(Self)
+      .map_err(|_| Error::ContentHashError(format!("expected a digest of {SHA256_LEN} bytes").into()))
+  }
+}
+
+/// Serializes `value` without insignificant whitespace and with object members sorted by key.
+///
+/// The order of object members is not left to `serde_json`, whose map type preserves insertion order if its
+/// `preserve_order` feature is enabled anywhere in the dependency graph.
+pub(crate) fn write_canonical_json(value: &Value, out: &mut String) -> serde_json::Result<()> {
+  match value {
+    Value::Object(object) => {
+      let mut members: Vec<(&String, &Value)> = object.iter().collect();
+      members.sort_unstable_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+      out.push('{');
+      for (index, (key, value)) in members.into_iter().enumerate() {
+        if index > 0 {
+          out.push(',');
+        }
+        out.push_str(&serde_json::to_string(key)?);
+        out.push(':');
+        write_canonical_json(value, out)?;
+      }
+      out.push('}');
+    }
+    Value::Array(values) => {
+      out.push('[');
+      for (index, value) in values.iter().enumerate() {
+        if index > 0 {
+          out.push(',');
+        }
+        write_canonical_json(value, out)?;
+      }
+      out.push(']');
+    }
+    primitive => out.push_str(&serde_json::to_string(primitive)?),
+  }
+  Ok(())
+}
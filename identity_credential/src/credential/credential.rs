@@ -12,6 +12,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use identity_core::common::Context;
+use identity_core::common::LanguageMap;
 use identity_core::common::Object;
 use identity_core::common::OneOrMany;
 use identity_core::common::Timestamp;
@@ -19,6 +20,7 @@ use identity_core::common::Url;
 use identity_core::convert::FmtJson;
 
 use crate::credential::CredentialBuilder;
+use crate::credential::CredentialContentHash;
 use crate::credential::Evidence;
 use crate::credential::Issuer;
 use crate::credential::Policy;
@@ -37,6 +39,7 @@ static BASE_CONTEXT: Lazy<Context> =
 
 /// Represents a set of claims describing an entity.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Credential<T = Object> {
   /// The JSON-LD context(s) applicable to the `Credential`.
   #[serde(rename = "@context")]
@@ -186,6 +189,31 @@ impl<T> Credential<T> {
     let jwt_representation: CredentialJwtClaims<'_, T> = CredentialJwtClaims::new(self, custom_claims)?;
     Ok(jwt_representation.into())
   }
+
+  /// Computes the [`CredentialContentHash`] of the [`Credential`].
+  ///
+  /// The hash ignores the `proof`, such that re-issuing the same credential, or securing it as a JWT instead,
+  /// yields the same hash.
+  pub fn content_hash(&self) -> Result<CredentialContentHash>
+  where
+    T: Serialize,
+  {
+    CredentialContentHash::new(self)
+  }
+}
+
+impl Credential<Object> {
+  /// Returns the custom property `key` as a [`LanguageMap`], or `None` if it is absent or not a language-tagged
+  /// string.
+  ///
+  /// Use [`CredentialBuilder::property`] with a [`LanguageMap`] to add localized properties, e.g. a `name` or
+  /// `description` per locale.
+  pub fn localized_property(&self, key: &str) -> Option<LanguageMap> {
+    self
+      .properties
+      .get(key)
+      .and_then(|value| LanguageMap::deserialize(value).ok())
+  }
 }
 
 impl<T> Display for Credential<T>
@@ -199,9 +227,15 @@ where
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
   use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
 
   use crate::credential::Credential;
+  use crate::credential::CredentialContentHash;
+  use crate::credential::Proof;
+  use crate::credential::Subject;
 
   const JSON1: &str = include_str!("../../tests/fixtures/credential-1.json");
   const JSON2: &str = include_str!("../../tests/fixtures/credential-2.json");
@@ -215,6 +249,7 @@ mod tests {
   const JSON10: &str = include_str!("../../tests/fixtures/credential-10.json");
   const JSON11: &str = include_str!("../../tests/fixtures/credential-11.json");
   const JSON12: &str = include_str!("../../tests/fixtures/credential-12.json");
+  const GOLDEN_V1: &str = include_str!("../../tests/fixtures/golden/credential-v1.json");
 
   #[test]
   fn test_from_json() {
@@ -231,4 +266,59 @@ mod tests {
     let _credential: Credential = Credential::from_json(JSON11).unwrap();
     let _credential: Credential = Credential::from_json(JSON12).unwrap();
   }
+
+  #[cfg(feature = "schemars")]
+  #[test]
+  fn test_json_schema() {
+    let schema = schemars::schema_for!(Credential);
+    let object = schema.schema.object.as_ref().unwrap();
+    for property in ["@context", "type", "credentialSubject", "issuer", "issuanceDate"] {
+      assert!(object.required.contains(property), "{property} should be required");
+    }
+    assert!(object.properties.contains_key("credentialStatus"));
+    assert!(!object.required.contains("credentialStatus"));
+    assert!(schema.definitions.contains_key("Timestamp"));
+  }
+
+  #[test]
+  fn test_content_hash_ignores_proof() {
+    let credential: Credential = Credential::from_json(JSON1).unwrap();
+    assert!(credential.proof.is_some());
+    let hash: CredentialContentHash = credential.content_hash().unwrap();
+
+    let mut without_proof: Credential = credential.clone();
+    without_proof.set_proof(None);
+    assert_eq!(without_proof.content_hash().unwrap(), hash);
+
+    let mut other_proof: Credential = credential.clone();
+    other_proof.set_proof(Some(Proof::new("Ed25519Signature2020".to_owned(), Object::new())));
+    assert_eq!(other_proof.content_hash().unwrap(), hash);
+  }
+
+  #[test]
+  fn test_content_hash_detects_changes() {
+    let credential: Credential = Credential::from_json(JSON1).unwrap();
+    let mut changed: Credential = credential.clone();
+    changed.credential_subject = Subject::with_id(Url::parse("did:example:other").unwrap()).into();
+
+    assert_ne!(changed.content_hash().unwrap(), credential.content_hash().unwrap());
+  }
+
+  #[test]
+  fn test_content_hash_roundtrip() {
+    let credential: Credential = Credential::from_json(JSON1).unwrap();
+    let hash: CredentialContentHash = credential.content_hash().unwrap();
+    assert_eq!(hash.to_string().parse::<CredentialContentHash>().unwrap(), hash);
+    assert!("AAAA".parse::<CredentialContentHash>().is_err());
+  }
+
+  #[test]
+  fn test_golden_v1() {
+    // The serialization of a credential must match the fixture of the first stable release exactly.
+    let credential: Credential = Credential::from_json(GOLDEN_V1).unwrap();
+    assert_eq!(
+      credential.to_json_value().unwrap(),
+      serde_json::Value::from_json(GOLDEN_V1).unwrap()
+    );
+  }
 }
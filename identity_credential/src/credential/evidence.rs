@@ -11,6 +11,7 @@ use identity_core::common::OneOrMany;
 ///
 /// [More Info](https://www.w3.org/TR/vc-data-model/#evidence)
 #[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Evidence {
   /// A Url that allows retrieval of information about the evidence.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -11,6 +11,7 @@ use identity_core::common::Url;
 ///
 /// [More Info](https://www.w3.org/TR/vc-data-model/#issuer)
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct IssuerData {
   /// A Url identifying the credential issuer.
   pub id: Url,
@@ -23,6 +24,7 @@ pub struct IssuerData {
 ///
 /// [More Info](https://www.w3.org/TR/vc-data-model/#issuer)
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum Issuer {
   /// A credential issuer expressed as a Url.
@@ -1,9 +1,18 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_verification::jose::error::Error as JoseError;
+use identity_verification::jws::Decoder;
+use identity_verification::jws::JwsHeader;
+use identity_verification::jws::JwsValidationItem;
+use identity_verification::jwt::JwtClaims;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::error::Error;
+use crate::error::Result;
+
 /// A wrapper around a JSON Web Token (JWK).
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub struct Jwt(String);
@@ -18,6 +27,36 @@ impl Jwt {
   pub fn as_str(&self) -> &str {
     &self.0
   }
+
+  /// Decodes the protected header of the JWT **without verifying its signature**.
+  ///
+  /// The header is untrusted input. Use it only for routing decisions, e.g. selecting the issuer document by `kid`
+  /// or the validator by `alg` or `typ`, before verifying the JWT.
+  pub fn peek_header(&self) -> Result<JwsHeader> {
+    self
+      .decode()?
+      .protected_header()
+      .cloned()
+      .ok_or(Error::JwtDecodingError(JoseError::MissingHeader("protected header")))
+  }
+
+  /// Decodes the claims of the JWT **without verifying its signature**.
+  ///
+  /// Registered claims such as `iss` are accessible on the returned [`JwtClaims`], all other claims are deserialized
+  /// into `T`, e.g. [`Object`](identity_core::common::Object). Like [`Jwt::peek_header`], the claims are untrusted
+  /// input and must only be used to decide how to verify the JWT.
+  pub fn peek_claims<T>(&self) -> Result<JwtClaims<T>>
+  where
+    T: DeserializeOwned,
+  {
+    serde_json::from_slice(self.decode()?.claims()).map_err(|err| Error::JwtClaimsSetDeserializationError(err.into()))
+  }
+
+  fn decode(&self) -> Result<JwsValidationItem<'_>> {
+    Decoder::new()
+      .decode_compact_serialization(self.0.as_bytes(), None)
+      .map_err(Error::JwtDecodingError)
+  }
 }
 
 impl From<String> for Jwt {
@@ -31,3 +70,53 @@ impl From<Jwt> for String {
     jwt.0
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_verification::jws::JwsAlgorithm;
+  use identity_verification::jwu;
+  use serde_json::json;
+
+  use super::*;
+
+  fn jwt(header: serde_json::Value, claims: serde_json::Value) -> Jwt {
+    Jwt::new(format!(
+      "{}.{}.{}",
+      jwu::encode_b64(header.to_string()),
+      jwu::encode_b64(claims.to_string()),
+      jwu::encode_b64("signature")
+    ))
+  }
+
+  #[test]
+  fn peek_header_and_claims() {
+    let jwt: Jwt = jwt(
+      json!({ "alg": "EdDSA", "kid": "did:example:issuer#key-1", "typ": "JWT" }),
+      json!({ "iss": "did:example:issuer", "nbf": 1_700_000_000, "vc": { "type": "VerifiableCredential" } }),
+    );
+
+    let header: JwsHeader = jwt.peek_header().unwrap();
+    assert_eq!(header.alg(), Some(JwsAlgorithm::EdDSA));
+    assert_eq!(header.kid(), Some("did:example:issuer#key-1"));
+
+    let claims: JwtClaims<Object> = jwt.peek_claims().unwrap();
+    assert_eq!(claims.iss(), Some("did:example:issuer"));
+    assert_eq!(claims.nbf(), Some(1_700_000_000));
+    assert!(claims.custom().unwrap().contains_key("vc"));
+  }
+
+  #[test]
+  fn peek_rejects_malformed_jwts() {
+    assert!(matches!(
+      Jwt::new("not a jwt".to_owned()).peek_header(),
+      Err(Error::JwtDecodingError(_))
+    ));
+
+    let jwt: Jwt = jwt(json!({ "alg": "EdDSA" }), json!(["not", "an", "object"]));
+    assert!(matches!(
+      jwt.peek_claims::<Object>(),
+      Err(Error::JwtClaimsSetDeserializationError(_))
+    ));
+  }
+}
@@ -728,4 +728,24 @@ mod tests {
       Error::InconsistentCredentialJwtClaims("inconsistent credential expirationDate")
     ));
   }
+
+  #[test]
+  fn golden_v1() {
+    // Credentials issued as JWTs must remain verifiable, so the claims set must not change between releases.
+    const CREDENTIAL: &str = include_str!("../../tests/fixtures/golden/credential-v1.json");
+    const CLAIMS: &str = include_str!("../../tests/fixtures/golden/credential-jwt-claims-v1.json");
+
+    let credential: Credential = Credential::from_json(CREDENTIAL).unwrap();
+    let serialized: String = CredentialJwtClaims::new(&credential, None).unwrap().to_json().unwrap();
+    assert_eq!(
+      Object::from_json(&serialized).unwrap(),
+      Object::from_json(CLAIMS).unwrap()
+    );
+
+    let decoded: Credential = CredentialJwtClaims::<'static, Object>::from_json(CLAIMS)
+      .unwrap()
+      .try_into_credential()
+      .unwrap();
+    assert_eq!(decoded, credential);
+  }
 }
@@ -6,6 +6,7 @@
 #![allow(clippy::module_inception)]
 
 mod builder;
+mod content_hash;
 mod credential;
 mod evidence;
 mod issuer;
@@ -26,8 +27,13 @@ mod revocation_bitmap_status;
 mod schema;
 mod status;
 mod subject;
+mod subject_schema;
+mod template;
 
 pub use self::builder::CredentialBuilder;
+#[cfg(feature = "jsonld")]
+pub(crate) use self::content_hash::write_canonical_json;
+pub use self::content_hash::CredentialContentHash;
 pub use self::credential::Credential;
 pub use self::evidence::Evidence;
 pub use self::issuer::Issuer;
@@ -49,6 +55,9 @@ pub use self::revocation_bitmap_status::RevocationBitmapStatus;
 pub use self::schema::Schema;
 pub use self::status::Status;
 pub use self::subject::Subject;
+pub use self::subject_schema::CredentialSubjectSchema;
+pub use self::template::CredentialTemplate;
+pub use self::template::CredentialTemplateRegistry;
 
 #[cfg(feature = "validator")]
 pub(crate) use self::jwt_serialization::CredentialJwtClaims;
@@ -13,6 +13,7 @@ use identity_core::common::Url;
 ///
 /// [More Info](https://www.w3.org/TR/vc-data-model/#terms-of-use)
 #[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Policy {
   /// The instance id of the credential terms-of-use.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -15,6 +15,7 @@ use serde::Serialize;
 /// Note that this proof is not related to JWT and can be used in combination or as an alternative
 /// to it.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Proof {
   /// Type of proof.
   #[serde(rename = "type")]
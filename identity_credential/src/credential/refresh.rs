@@ -12,6 +12,7 @@ use identity_core::common::Url;
 ///
 /// [More Info](https://www.w3.org/TR/vc-data-model/#refreshing)
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RefreshService {
   /// The Url of the credential refresh service.
   pub id: Url,
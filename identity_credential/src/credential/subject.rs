@@ -4,6 +4,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use identity_core::common::LanguageMap;
 use identity_core::common::Object;
 use identity_core::common::Url;
 
@@ -11,6 +12,7 @@ use identity_core::common::Url;
 ///
 /// [More Info](https://www.w3.org/TR/vc-data-model/#credential-subject)
 #[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Subject {
   /// A URI identifying the credential subject.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -43,6 +45,14 @@ impl Subject {
       properties,
     }
   }
+
+  /// Returns the property `key` as a [`LanguageMap`], or `None` if it is absent or not a language-tagged string.
+  pub fn localized_property(&self, key: &str) -> Option<LanguageMap> {
+    self
+      .properties
+      .get(key)
+      .and_then(|value| LanguageMap::deserialize(value).ok())
+  }
 }
 
 #[cfg(test)]
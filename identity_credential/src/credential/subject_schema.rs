@@ -0,0 +1,191 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::credential::Subject;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The typed claims of a [`Subject`], converted to and from its properties.
+///
+/// Usually implemented with the [`credential_subject_schema!`](crate::credential_subject_schema) macro, which names
+/// every claim after its field.
+pub trait CredentialSubjectSchema: Serialize + DeserializeOwned {
+  /// The names of the claims that are selectively disclosed when the credential is issued as an SD-JWT.
+  const DISCLOSABLE_CLAIMS: &'static [&'static str] = &[];
+
+  /// Checks the values of the claims, e.g. their ranges or formats.
+  fn validate_claims(&self) -> core::result::Result<(), String> {
+    Ok(())
+  }
+
+  /// Validates the claims and converts them into a [`Subject`] identified by `id`.
+  fn to_subject(&self, id: Option<Url>) -> Result<Subject> {
+    self.validate_claims().map_err(Error::InvalidSubjectClaims)?;
+    let properties: Object = match self.to_json_value() {
+      Ok(Value::Object(properties)) => properties.into_iter().collect(),
+      Ok(_) => {
+        return Err(Error::InvalidSubjectClaims(
+          "claims must serialize to a JSON object".to_owned(),
+        ))
+      }
+      Err(err) => return Err(Error::InvalidSubjectClaims(err.to_string())),
+    };
+    Ok(Subject { id, properties })
+  }
+
+  /// Converts the properties of `subject` into the typed claims and validates them.
+  ///
+  /// Properties of `subject` without a corresponding claim are ignored unless the implementation denies unknown
+  /// fields.
+  fn from_subject(subject: &Subject) -> Result<Self> {
+    let properties: Value = Value::Object(subject.properties.clone().into_iter().collect());
+    let claims: Self = Self::from_json_value(properties).map_err(|err| Error::InvalidSubjectClaims(err.to_string()))?;
+    claims.validate_claims().map_err(Error::InvalidSubjectClaims)?;
+    Ok(claims)
+  }
+
+  /// Returns the JSON pointers of the [disclosable claims](Self::DISCLOSABLE_CLAIMS) below `subject_pointer`, the
+  /// pointer of the subject in the SD-JWT payload, e.g. `/vc/credentialSubject`.
+  ///
+  /// The pointers are meant to be concealed one by one with an SD-JWT encoder.
+  fn disclosure_plan(subject_pointer: &str) -> Vec<String> {
+    Self::DISCLOSABLE_CLAIMS
+      .iter()
+      .map(|claim| {
+        format!(
+          "{}/{}",
+          subject_pointer.trim_end_matches('/'),
+          escape_pointer_token(claim)
+        )
+      })
+      .collect()
+  }
+}
+
+/// Escapes a JSON pointer reference token, see [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901#section-3).
+fn escape_pointer_token(token: &str) -> String {
+  token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Defines a struct of typed credential subject claims and implements [`CredentialSubjectSchema`] for it.
+///
+/// Each field is a claim named after the field. The optional `disclosable` list names the claims that are
+/// selectively disclosed in SD-JWTs, and the optional `validate` function checks the values of the claims. The
+/// crate using the macro must depend on `serde`.
+///
+/// ```
+/// use identity_credential::credential::CredentialSubjectSchema;
+/// use identity_credential::credential_subject_schema;
+///
+/// fn check_gpa(degree: &Degree) -> Result<(), String> {
+///   (0.0..=4.0)
+///     .contains(&degree.gpa)
+///     .then_some(())
+///     .ok_or_else(|| "gpa out of range".to_owned())
+/// }
+///
+/// credential_subject_schema! {
+///   #[derive(Debug, Clone, PartialEq)]
+///   pub struct Degree {
+///     pub name: String,
+///     pub gpa: f64,
+///   }
+///   disclosable: [gpa];
+///   validate: check_gpa;
+/// }
+///
+/// let degree = Degree { name: "Bachelor of Science".to_owned(), gpa: 3.5 };
+/// let subject = degree.to_subject(None).unwrap();
+/// assert_eq!(Degree::from_subject(&subject).unwrap(), degree);
+/// assert_eq!(Degree::disclosure_plan("/vc/credentialSubject"), ["/vc/credentialSubject/gpa"]);
+/// ```
+#[macro_export]
+macro_rules! credential_subject_schema {
+  (
+    $(#[$meta:meta])*
+    $vis:vis struct $name:ident {
+      $($(#[$field_meta:meta])* $field_vis:vis $field:ident : $ty:ty),* $(,)?
+    }
+    $(disclosable: [$($disclosable:ident),* $(,)?];)?
+    $(validate: $validate:path;)?
+  ) => {
+    $(#[$meta])*
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    $vis struct $name {
+      $($(#[$field_meta])* $field_vis $field: $ty),*
+    }
+
+    impl $crate::credential::CredentialSubjectSchema for $name {
+      const DISCLOSABLE_CLAIMS: &'static [&'static str] = &[$($(::core::stringify!($disclosable)),*)?];
+
+      $(
+        fn validate_claims(&self) -> ::core::result::Result<(), ::std::string::String> {
+          $validate(self)
+        }
+      )?
+    }
+  };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn check_age(person: &Person) -> core::result::Result<(), String> {
+    if person.age >= 18 {
+      Ok(())
+    } else {
+      Err("not an adult".to_owned())
+    }
+  }
+
+  crate::credential_subject_schema! {
+    #[derive(Debug, PartialEq)]
+    struct Person {
+      name: String,
+      age: u8,
+      #[serde(skip_serializing_if = "Option::is_none")]
+      nickname: Option<String>,
+    }
+    disclosable: [age, nickname];
+    validate: check_age;
+  }
+
+  #[test]
+  fn test_subject_schema() {
+    let person: Person = Person {
+      name: "Alice".to_owned(),
+      age: 42,
+      nickname: None,
+    };
+    let id: Url = Url::parse("did:example:alice").unwrap();
+    let subject: Subject = person.to_subject(Some(id.clone())).unwrap();
+    assert_eq!(subject.id, Some(id));
+    assert_eq!(subject.properties.len(), 2);
+    assert_eq!(Person::from_subject(&subject).unwrap(), person);
+    assert_eq!(
+      Person::disclosure_plan("/vc/credentialSubject/"),
+      ["/vc/credentialSubject/age", "/vc/credentialSubject/nickname"]
+    );
+
+    // INVALID: fails validation.
+    let minor: Person = Person { age: 12, ..person };
+    assert!(matches!(minor.to_subject(None), Err(Error::InvalidSubjectClaims(_))));
+
+    // INVALID: wrong claim type.
+    let mut subject: Subject = subject;
+    subject.properties.insert("age".to_owned(), Value::from("old"));
+    assert!(matches!(
+      Person::from_subject(&subject),
+      Err(Error::InvalidSubjectClaims(_))
+    ));
+  }
+}
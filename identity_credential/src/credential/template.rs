@@ -0,0 +1,433 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use identity_core::common::Context;
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::convert::FromJson;
+use serde_json::Value;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+
+const PLACEHOLDER_START: &str = "{{";
+const PLACEHOLDER_END: &str = "}}";
+
+/// A JSON credential with `{{variable}}` placeholders, instantiated into [`Credential`]s.
+///
+/// A string value consisting of a single placeholder is replaced by the value of the variable, which may be of any
+/// JSON type. Placeholders within longer strings are replaced by the string representation of the variable. The
+/// `@context` and `type` of a template must not contain placeholders.
+///
+/// # Example
+///
+/// ```
+/// # use identity_core::common::Object;
+/// # use identity_credential::credential::Credential;
+/// # use identity_credential::credential::CredentialTemplate;
+/// let template: CredentialTemplate = CredentialTemplate::from_json(
+///   r#"{
+///     "@context": "https://www.w3.org/2018/credentials/v1",
+///     "type": ["VerifiableCredential", "UniversityDegreeCredential"],
+///     "issuer": "did:example:issuer",
+///     "issuanceDate": "{{issuanceDate}}",
+///     "credentialSubject": {
+///       "id": "{{holder}}",
+///       "degree": { "name": "Bachelor of {{field}}" },
+///       "GPA": "{{gpa}}"
+///     }
+///   }"#,
+/// )?
+/// .with_default("issuanceDate", "2024-01-01T00:00:00Z");
+///
+/// let variables: Object = serde_json::from_value(serde_json::json!({
+///   "holder": "did:example:holder",
+///   "field": "Science and Arts",
+///   "gpa": 4.0,
+/// }))?;
+/// let credential: Credential = template.instantiate(&variables)?;
+/// assert_eq!(credential.credential_subject[0].properties["GPA"], 4.0);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CredentialTemplate {
+  context: OneOrMany<Context>,
+  types: OneOrMany<String>,
+  template: Value,
+  variables: BTreeSet<String>,
+  defaults: Object,
+}
+
+impl CredentialTemplate {
+  /// Parses a [`CredentialTemplate`] from a JSON string.
+  pub fn from_json(json: &str) -> Result<Self> {
+    let template: Value = serde_json::from_str(json).map_err(|_| Error::InvalidCredentialTemplate("not valid JSON"))?;
+    Self::from_json_value(template)
+  }
+
+  /// Creates a [`CredentialTemplate`] from a JSON value.
+  pub fn from_json_value(template: Value) -> Result<Self> {
+    let Value::Object(ref object) = template else {
+      return Err(Error::InvalidCredentialTemplate("expected a JSON object"));
+    };
+
+    let (Some(context), Some(types)) = (object.get("@context"), object.get("type")) else {
+      return Err(Error::InvalidCredentialTemplate("missing `@context` or `type`"));
+    };
+    let mut fixed: BTreeSet<String> = BTreeSet::new();
+    collect_variables(context, &mut fixed)?;
+    collect_variables(types, &mut fixed)?;
+    if !fixed.is_empty() {
+      return Err(Error::InvalidCredentialTemplate(
+        "`@context` and `type` must not contain placeholders",
+      ));
+    }
+    let context: OneOrMany<Context> =
+      serde_json::from_value(context.clone()).map_err(|_| Error::InvalidCredentialTemplate("invalid `@context`"))?;
+    let types: OneOrMany<String> =
+      serde_json::from_value(types.clone()).map_err(|_| Error::InvalidCredentialTemplate("invalid `type`"))?;
+
+    let mut variables: BTreeSet<String> = BTreeSet::new();
+    collect_variables(&template, &mut variables)?;
+
+    Ok(Self {
+      context,
+      types,
+      template,
+      variables,
+      defaults: Object::new(),
+    })
+  }
+
+  /// Sets the value used for the variable `name` if it is not passed to [`Self::instantiate`].
+  #[must_use]
+  pub fn with_default(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+    self.defaults.insert(name.into(), value.into());
+    self
+  }
+
+  /// Returns the JSON-LD context(s) of the credentials instantiated from the template.
+  pub fn context(&self) -> &OneOrMany<Context> {
+    &self.context
+  }
+
+  /// Returns the types of the credentials instantiated from the template.
+  pub fn types(&self) -> &OneOrMany<String> {
+    &self.types
+  }
+
+  /// Returns the most specific type of the template, i.e. its last type other than
+  /// [`Credential::base_type`], if any.
+  pub fn credential_type(&self) -> Option<&str> {
+    self
+      .types
+      .iter()
+      .rev()
+      .map(String::as_str)
+      .find(|type_| *type_ != Credential::<Object>::base_type())
+  }
+
+  /// Returns the names of all variables used in the template.
+  pub fn variables(&self) -> impl Iterator<Item = &str> + '_ {
+    self.variables.iter().map(String::as_str)
+  }
+
+  /// Returns the names of the variables that must be passed to [`Self::instantiate`], i.e. those without a default.
+  pub fn required_variables(&self) -> impl Iterator<Item = &str> + '_ {
+    self.variables().filter(|name| !self.defaults.contains_key(*name))
+  }
+
+  /// Creates a [`Credential`] by substituting the placeholders of the template with `variables`, falling back to
+  /// the defaults of the template.
+  ///
+  /// Fails with [`Error::MissingTemplateVariable`] if a required variable is missing and with
+  /// [`Error::CredentialTemplateInstantiation`] if the result is not a valid credential.
+  pub fn instantiate(&self, variables: &Object) -> Result<Credential> {
+    if let Some(missing) = self.required_variables().find(|name| !variables.contains_key(*name)) {
+      return Err(Error::MissingTemplateVariable(missing.to_owned()));
+    }
+
+    let mut values: Object = self.defaults.clone();
+    values.extend(variables.iter().map(|(name, value)| (name.clone(), value.clone())));
+    let credential: Credential = Credential::from_json_value(substitute(&self.template, &values))
+      .map_err(|err| Error::CredentialTemplateInstantiation(Box::new(err)))?;
+    credential.check_structure()?;
+
+    Ok(credential)
+  }
+}
+
+/// A registry of [`CredentialTemplate`]s by credential type, ensuring each type is always used with the same
+/// `@context`.
+#[derive(Clone, Debug, Default)]
+pub struct CredentialTemplateRegistry {
+  templates: BTreeMap<String, CredentialTemplate>,
+}
+
+impl CredentialTemplateRegistry {
+  /// Creates an empty [`CredentialTemplateRegistry`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Registers `template` under its [`CredentialTemplate::credential_type`], returning the template it replaces.
+  ///
+  /// Fails with [`Error::CredentialTemplateConflict`] if any of the types of `template` is used by another
+  /// registered template with a different `@context`.
+  pub fn register(&mut self, template: CredentialTemplate) -> Result<Option<CredentialTemplate>> {
+    let key: String = template
+      .credential_type()
+      .ok_or(Error::InvalidCredentialTemplate(
+        "missing a type other than the base type",
+      ))?
+      .to_owned();
+
+    for (other_key, other) in &self.templates {
+      if *other_key == key || other.context == template.context {
+        continue;
+      }
+      if let Some(type_) = template
+        .types
+        .iter()
+        .filter(|type_| type_.as_str() != Credential::<Object>::base_type())
+        .find(|type_| other.types.contains(type_))
+      {
+        return Err(Error::CredentialTemplateConflict(type_.clone()));
+      }
+    }
+
+    Ok(self.templates.insert(key, template))
+  }
+
+  /// Removes and returns the template registered for `credential_type`.
+  pub fn unregister(&mut self, credential_type: &str) -> Option<CredentialTemplate> {
+    self.templates.remove(credential_type)
+  }
+
+  /// Returns the template registered for `credential_type`.
+  pub fn get(&self, credential_type: &str) -> Option<&CredentialTemplate> {
+    self.templates.get(credential_type)
+  }
+
+  /// Returns the `@context` the type `type_` is used with by the registered templates.
+  pub fn context(&self, type_: &str) -> Option<&OneOrMany<Context>> {
+    self
+      .templates
+      .values()
+      .find(|template| template.types.iter().any(|other| other == type_))
+      .map(CredentialTemplate::context)
+  }
+
+  /// Returns the registered credential types.
+  pub fn credential_types(&self) -> impl Iterator<Item = &str> + '_ {
+    self.templates.keys().map(String::as_str)
+  }
+
+  /// Instantiates the template registered for `credential_type`, see [`CredentialTemplate::instantiate`].
+  pub fn instantiate(&self, credential_type: &str, variables: &Object) -> Result<Credential> {
+    self
+      .get(credential_type)
+      .ok_or_else(|| Error::UnknownCredentialTemplate(credential_type.to_owned()))?
+      .instantiate(variables)
+  }
+}
+
+/// Splits `string` into its literal parts and placeholders, the latter being returned as `Err(name)`.
+fn parse(string: &str) -> Result<Vec<std::result::Result<&str, &str>>> {
+  let mut parts = Vec::new();
+  let mut rest: &str = string;
+  while let Some(start) = rest.find(PLACEHOLDER_START) {
+    let Some(end) = rest[start..].find(PLACEHOLDER_END) else {
+      return Err(Error::InvalidCredentialTemplate("unterminated placeholder"));
+    };
+    let name: &str = rest[start + PLACEHOLDER_START.len()..start + end].trim();
+    if name.is_empty() {
+      return Err(Error::InvalidCredentialTemplate("empty placeholder"));
+    }
+    if start > 0 {
+      parts.push(Ok(&rest[..start]));
+    }
+    parts.push(Err(name));
+    rest = &rest[start + end + PLACEHOLDER_END.len()..];
+  }
+  if !rest.is_empty() {
+    parts.push(Ok(rest));
+  }
+  Ok(parts)
+}
+
+fn collect_variables(value: &Value, variables: &mut BTreeSet<String>) -> Result<()> {
+  match value {
+    Value::String(string) => {
+      for part in parse(string)? {
+        if let Err(name) = part {
+          variables.insert(name.to_owned());
+        }
+      }
+    }
+    Value::Array(values) => {
+      for value in values {
+        collect_variables(value, variables)?;
+      }
+    }
+    Value::Object(object) => {
+      for value in object.values() {
+        collect_variables(value, variables)?;
+      }
+    }
+    Value::Null | Value::Bool(_) | Value::Number(_) => {}
+  }
+  Ok(())
+}
+
+/// Substitutes the placeholders in `value`, which has been checked to be well-formed by [`collect_variables`].
+fn substitute(value: &Value, values: &Object) -> Value {
+  let lookup = |name: &str| values.get(name).unwrap_or(&Value::Null);
+  match value {
+    Value::String(string) => {
+      let parts = parse(string).unwrap_or_default();
+      match parts.as_slice() {
+        [Err(name)] => lookup(name).clone(),
+        _ => Value::String(
+          parts
+            .into_iter()
+            .map(|part| match part {
+              Ok(literal) => literal.to_owned(),
+              Err(name) => match lookup(name) {
+                Value::String(string) => string.clone(),
+                other => other.to_string(),
+              },
+            })
+            .collect(),
+        ),
+      }
+    }
+    Value::Array(items) => Value::Array(items.iter().map(|item| substitute(item, values)).collect()),
+    Value::Object(object) => Value::Object(
+      object
+        .iter()
+        .map(|(key, value)| (key.clone(), substitute(value, values)))
+        .collect(),
+    ),
+    other => other.clone(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn template(type_: &str, context: &str) -> CredentialTemplate {
+    CredentialTemplate::from_json_value(json!({
+      "@context": ["https://www.w3.org/2018/credentials/v1", context],
+      "type": ["VerifiableCredential", type_],
+      "issuer": "{{issuer}}",
+      "issuanceDate": "2024-01-01T00:00:00Z",
+      "credentialSubject": {
+        "id": "{{holder}}",
+        "name": "{{ firstName }} {{lastName}}",
+        "age": "{{age}}"
+      }
+    }))
+    .unwrap()
+  }
+
+  fn variables(value: Value) -> Object {
+    serde_json::from_value(value).unwrap()
+  }
+
+  #[test]
+  fn test_instantiate() {
+    let template: CredentialTemplate =
+      template("PersonCredential", "https://example.com/person/v1").with_default("issuer", "did:example:issuer");
+    assert_eq!(
+      template.variables().collect::<Vec<_>>(),
+      ["age", "firstName", "holder", "issuer", "lastName"]
+    );
+    assert_eq!(template.required_variables().count(), 4);
+
+    let credential: Credential = template
+      .instantiate(&variables(json!({
+        "holder": "did:example:holder",
+        "firstName": "Alice",
+        "lastName": "Smith",
+        "age": 42,
+      })))
+      .unwrap();
+    assert_eq!(credential.issuer.url().as_str(), "did:example:issuer");
+    let subject = &credential.credential_subject[0];
+    assert_eq!(subject.id.as_ref().unwrap().as_str(), "did:example:holder");
+    assert_eq!(subject.properties["name"], "Alice Smith");
+    assert_eq!(subject.properties["age"], 42);
+
+    assert!(matches!(
+      template.instantiate(&variables(json!({ "holder": "did:example:holder" }))),
+      Err(Error::MissingTemplateVariable(name)) if name == "age"
+    ));
+  }
+
+  #[test]
+  fn test_invalid_template() {
+    for template in [
+      json!({ "type": "VerifiableCredential" }),
+      json!({ "@context": "https://www.w3.org/2018/credentials/v1", "type": "{{type}}" }),
+      json!({ "@context": "https://www.w3.org/2018/credentials/v1", "type": "VerifiableCredential", "issuer": "{{issuer" }),
+      json!({ "@context": "https://www.w3.org/2018/credentials/v1", "type": "VerifiableCredential", "issuer": "{{}}" }),
+    ] {
+      assert!(matches!(
+        CredentialTemplate::from_json_value(template),
+        Err(Error::InvalidCredentialTemplate(_))
+      ));
+    }
+  }
+
+  #[test]
+  fn test_registry() {
+    let mut registry: CredentialTemplateRegistry = CredentialTemplateRegistry::new();
+    registry
+      .register(template("PersonCredential", "https://example.com/person/v1"))
+      .unwrap();
+    registry
+      .register(template("EmployeeCredential", "https://example.com/employee/v1"))
+      .unwrap();
+    assert_eq!(
+      registry.credential_types().collect::<Vec<_>>(),
+      ["EmployeeCredential", "PersonCredential"]
+    );
+    assert_eq!(
+      registry.context("PersonCredential").unwrap()[1],
+      "https://example.com/person/v1"
+    );
+
+    // The same type with a different context conflicts with the registered template.
+    let conflicting: CredentialTemplate = CredentialTemplate::from_json_value(json!({
+      "@context": ["https://www.w3.org/2018/credentials/v1", "https://example.com/other/v1"],
+      "type": ["VerifiableCredential", "PersonCredential", "StudentCredential"],
+      "issuer": "did:example:issuer",
+      "issuanceDate": "2024-01-01T00:00:00Z",
+      "credentialSubject": { "id": "{{holder}}" }
+    }))
+    .unwrap();
+    assert!(matches!(
+      registry.register(conflicting),
+      Err(Error::CredentialTemplateConflict(type_)) if type_ == "PersonCredential"
+    ));
+
+    // Replacing a template may change its context.
+    assert!(registry
+      .register(template("PersonCredential", "https://example.com/person/v2"))
+      .unwrap()
+      .is_some());
+
+    assert!(matches!(
+      registry.instantiate("StudentCredential", &Object::new()),
+      Err(Error::UnknownCredentialTemplate(_))
+    ));
+  }
+}
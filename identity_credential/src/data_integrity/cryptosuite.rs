@@ -0,0 +1,78 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+
+use identity_verification::jws::JwsAlgorithm;
+
+use super::Error;
+
+/// The Data Integrity cryptosuites supported by [`DataIntegrityProcessor`](super::DataIntegrityProcessor).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Cryptosuite {
+  /// [`eddsa-rdfc-2022`](https://www.w3.org/TR/vc-di-eddsa/#eddsa-rdfc-2022): Ed25519 signatures over
+  /// RDFC-1.0 canonicalized documents.
+  EddsaRdfc2022,
+  /// [`ecdsa-rdfc-2019`](https://www.w3.org/TR/vc-di-ecdsa/#ecdsa-rdfc-2019): ECDSA signatures over
+  /// RDFC-1.0 canonicalized documents. Only P-256 keys are supported.
+  EcdsaRdfc2019,
+  /// [`ecdsa-sd-2023`](https://www.w3.org/TR/vc-di-ecdsa/#ecdsa-sd-2023): selectively disclosable ECDSA proofs.
+  /// The issuer creates a base proof with the P-256 key of its verification method, from which holders derive
+  /// proofs disclosing parts of the document.
+  EcdsaSd2023,
+}
+
+impl Cryptosuite {
+  /// Returns the identifier of the cryptosuite, as used in the `cryptosuite` property of proofs.
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::EddsaRdfc2022 => "eddsa-rdfc-2022",
+      Self::EcdsaRdfc2019 => "ecdsa-rdfc-2019",
+      Self::EcdsaSd2023 => "ecdsa-sd-2023",
+    }
+  }
+
+  /// Returns the JWS algorithm whose signatures the cryptosuite uses.
+  ///
+  /// Signatures can therefore be created with a `JwkStorage` and verified with a
+  /// [`JwsVerifier`](identity_verification::jws::JwsVerifier).
+  pub const fn algorithm(&self) -> JwsAlgorithm {
+    match self {
+      Self::EddsaRdfc2022 => JwsAlgorithm::EdDSA,
+      Self::EcdsaRdfc2019 | Self::EcdsaSd2023 => JwsAlgorithm::ES256,
+    }
+  }
+
+  /// Returns the cryptosuite signing with keys of the JWS algorithm `alg`, if any.
+  ///
+  /// `ES256` keys map to `ecdsa-rdfc-2019`, base proofs of `ecdsa-sd-2023` must be requested explicitly.
+  pub fn from_algorithm(alg: JwsAlgorithm) -> Option<Self> {
+    match alg {
+      JwsAlgorithm::EdDSA => Some(Self::EddsaRdfc2022),
+      JwsAlgorithm::ES256 => Some(Self::EcdsaRdfc2019),
+      _ => None,
+    }
+  }
+}
+
+impl Display for Cryptosuite {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+impl FromStr for Cryptosuite {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    match string {
+      "eddsa-rdfc-2022" => Ok(Self::EddsaRdfc2022),
+      "ecdsa-rdfc-2019" => Ok(Self::EcdsaRdfc2019),
+      "ecdsa-sd-2023" => Ok(Self::EcdsaSd2023),
+      _ => Err(Error::UnsupportedCryptosuite(string.to_owned())),
+    }
+  }
+}
@@ -0,0 +1,55 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jws::SignatureVerificationError;
+
+/// Alias for a `Result` with the error type [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Failures of creating or verifying Data Integrity proofs.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum Error {
+  /// The document or its proof could not be processed as JSON-LD.
+  #[error("JSON-LD processing failed")]
+  JsonLd(#[from] crate::jsonld::Error),
+  /// The document is not a JSON object or its proof is malformed.
+  #[error("invalid document: {0}")]
+  InvalidDocument(&'static str),
+  /// The document has no proof.
+  #[error("document has no proof")]
+  MissingProof,
+  /// The proof is not a `DataIntegrityProof`.
+  #[error("unsupported proof type \"{0}\"")]
+  UnsupportedProofType(String),
+  /// The cryptosuite of the proof is not supported.
+  #[error("unsupported cryptosuite \"{0}\"")]
+  UnsupportedCryptosuite(String),
+  /// The `proofValue` is missing or malformed.
+  #[error("invalid proof value")]
+  InvalidProofValue,
+  /// The proof expired.
+  #[error("proof expired")]
+  ProofExpired,
+  /// The purpose, domain or challenge of the proof does not match the expected one.
+  #[error("proof {0} mismatch")]
+  ProofMismatch(&'static str),
+  /// The verification method of the proof could not be resolved in the issuer's document for the proof purpose.
+  #[error("verification method \"{0}\" not found")]
+  MethodNotFound(String),
+  /// The key material of the verification method cannot be used with the cryptosuite.
+  #[error("unsupported verification method: {0}")]
+  UnsupportedVerificationMethod(&'static str),
+  /// The issuer of the document does not match the DID document the proof was verified with.
+  #[error("issuer of the document does not match the provided DID document")]
+  IssuerMismatch,
+  /// A JSON pointer selecting the disclosed parts of a document is malformed or does not match the document.
+  #[error("invalid JSON pointer \"{0}\"")]
+  InvalidPointer(String),
+  /// A selectively disclosable proof cannot be derived from or does not match the document.
+  #[error("selective disclosure failed: {0}")]
+  SelectiveDisclosure(&'static str),
+  /// The signature does not match the document.
+  #[error("signature verification failed")]
+  Signature(#[source] SignatureVerificationError),
+}
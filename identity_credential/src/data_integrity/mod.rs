@@ -0,0 +1,28 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! [Data Integrity](https://www.w3.org/TR/vc-data-integrity/) proofs for credentials and presentations.
+//!
+//! Supports creating and verifying proofs of the `eddsa-rdfc-2022` and `ecdsa-rdfc-2019` cryptosuites, which sign
+//! the documents canonicalized with RDF Dataset Canonicalization. Documents are processed as JSON-LD in safe mode:
+//! any term not defined by the contexts loaded by the [`DocumentLoader`](crate::jsonld::DocumentLoader) is an error.
+//!
+//! The `ecdsa-sd-2023` cryptosuite supports selective disclosure: the issuer secures a document with a base proof
+//! ([`DataIntegrityProcessor::prepare_sd_base_proof`]), from which the holder derives a document disclosing only the
+//! values selected by JSON pointers ([`DataIntegrityProcessor::derive_sd_proof`]). Derived proofs are verified with
+//! [`DataIntegrityProcessor::verify`] like any other proof.
+
+mod cryptosuite;
+mod error;
+mod processor;
+mod proof;
+mod selective_disclosure;
+
+pub use cryptosuite::Cryptosuite;
+pub use error::Error;
+pub use error::Result;
+pub use processor::DataIntegrityProcessor;
+pub use processor::DataIntegrityVerificationOptions;
+pub use proof::DataIntegrityProof;
+pub use proof::DataIntegrityProofOptions;
+pub use selective_disclosure::UnsignedSdBaseProof;
@@ -0,0 +1,484 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::common::Timestamp;
+use identity_core::convert::BaseEncoding;
+use identity_document::document::CoreDocument;
+use identity_verification::jwk::EdCurve;
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkParamsOkp;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::VerificationInput;
+use identity_verification::jwu;
+use identity_verification::MethodData;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::proof::proof_purpose;
+use super::Cryptosuite;
+use super::DataIntegrityProof;
+use super::Error;
+use super::Result;
+use crate::jsonld::DocumentLoader;
+use crate::jsonld::JsonLdProcessor;
+
+/// The multicodec prefix of Ed25519 public keys in `Multikey` verification methods.
+const ED25519_MULTICODEC: [u8; 2] = [0xed, 0x01];
+
+/// Options for verifying Data Integrity proofs with [`DataIntegrityProcessor::verify`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DataIntegrityVerificationOptions {
+  /// The verification relationship the proofs must have been created for.
+  ///
+  /// Default: [`MethodRelationship::AssertionMethod`].
+  pub purpose: MethodRelationship,
+  /// The domain the proofs must be bound to, if any.
+  pub domain: Option<String>,
+  /// The challenge the proofs must be bound to, if any.
+  pub challenge: Option<String>,
+}
+
+impl Default for DataIntegrityVerificationOptions {
+  fn default() -> Self {
+    Self {
+      purpose: MethodRelationship::AssertionMethod,
+      domain: None,
+      challenge: None,
+    }
+  }
+}
+
+impl DataIntegrityVerificationOptions {
+  /// Sets the verification relationship the proofs must have been created for.
+  pub fn purpose(mut self, purpose: MethodRelationship) -> Self {
+    self.purpose = purpose;
+    self
+  }
+
+  /// Sets the domain the proofs must be bound to.
+  pub fn domain(mut self, domain: impl Into<String>) -> Self {
+    self.domain = Some(domain.into());
+    self
+  }
+
+  /// Sets the challenge the proofs must be bound to.
+  pub fn challenge(mut self, challenge: impl Into<String>) -> Self {
+    self.challenge = Some(challenge.into());
+    self
+  }
+}
+
+/// Creates and verifies Data Integrity proofs, loading the JSON-LD contexts of documents with a
+/// [`DocumentLoader`].
+///
+/// Creating a proof is split into [`signing_input`](Self::signing_input()) and [`secure`](Self::secure()), such
+/// that the signature can be created by any signer, e.g. a `JwkStorage`.
+///
+/// # Example
+///
+/// ```ignore
+/// let processor = DataIntegrityProcessor::new(vetted_contexts);
+/// let proof = DataIntegrityProof::new(Cryptosuite::EddsaRdfc2022, method_id, &DataIntegrityProofOptions::default());
+/// let signing_input: Vec<u8> = processor.signing_input(&credential, &proof)?;
+/// let signature: Vec<u8> = storage.key_storage().sign(&key_id, &signing_input, &public_key).await?;
+/// let secured: Value = processor.secure(&credential, proof, &signature)?;
+///
+/// processor.verify(&secured, &issuer_document, &EdDSAJwsVerifier::default(), &Default::default())?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct DataIntegrityProcessor<L> {
+  jsonld: JsonLdProcessor<L>,
+}
+
+impl<L: DocumentLoader> DataIntegrityProcessor<L> {
+  /// Creates a new [`DataIntegrityProcessor`] loading contexts with `loader`.
+  pub fn new(loader: L) -> Self {
+    Self {
+      jsonld: JsonLdProcessor::new(loader),
+    }
+  }
+
+  /// Returns the [`JsonLdProcessor`] used to canonicalize documents.
+  pub fn jsonld(&self) -> &JsonLdProcessor<L> {
+    &self.jsonld
+  }
+
+  /// Returns the data to sign to secure `document` with `proof`, i.e. the concatenated SHA-256 hashes of the
+  /// canonicalized proof configuration and of the canonicalized document without its existing proofs.
+  pub fn signing_input(&self, document: &Value, proof: &DataIntegrityProof) -> Result<Vec<u8>> {
+    let unsecured: Map<String, Value> = unsecured(document)?;
+    let mut config: Map<String, Value> = to_object(proof)?;
+    config.remove("proofValue");
+    self.hash_data(unsecured, config)
+  }
+
+  /// Returns `document` secured with `proof` and the `signature` over its [`signing_input`](Self::signing_input()).
+  ///
+  /// Existing proofs of the document are kept, such that the proofs form a proof set.
+  pub fn secure(&self, document: &Value, mut proof: DataIntegrityProof, signature: &[u8]) -> Result<Value> {
+    proof.proof_value = Some(BaseEncoding::encode_multibase(&signature, None));
+    add_proof(document, &proof)
+  }
+
+  /// Serializes `value`, e.g. a [`Credential`](crate::credential::Credential), and [secures](Self::secure()) it.
+  pub fn secure_serializable<T: Serialize + ?Sized>(
+    &self,
+    value: &T,
+    proof: DataIntegrityProof,
+    signature: &[u8],
+  ) -> Result<Value> {
+    let document: Value =
+      serde_json::to_value(value).map_err(|_| Error::InvalidDocument("document cannot be serialized"))?;
+    self.secure(&document, proof, signature)
+  }
+
+  /// Verifies all Data Integrity proofs of `document` with the verification methods of `issuer`, returning them.
+  ///
+  /// If the document has an `issuer` or `holder`, it must be the DID of `issuer`. Proofs of the `ecdsa-sd-2023`
+  /// cryptosuite must be [derived](Self::derive_sd_proof()) proofs.
+  pub fn verify<V: JwsVerifier>(
+    &self,
+    document: &Value,
+    issuer: &CoreDocument,
+    verifier: &V,
+    options: &DataIntegrityVerificationOptions,
+  ) -> Result<Vec<DataIntegrityProof>> {
+    let unsecured: Map<String, Value> = unsecured(document)?;
+    let controller: Option<&str> = ["issuer", "holder"]
+      .into_iter()
+      .find_map(|property| unsecured.get(property))
+      .and_then(|controller| controller.as_str().or_else(|| controller.get("id")?.as_str()));
+    if controller.is_some_and(|controller| controller != issuer.id().as_str()) {
+      return Err(Error::IssuerMismatch);
+    }
+
+    let proofs: Vec<Value> = match document.get("proof") {
+      None | Some(Value::Null) => return Err(Error::MissingProof),
+      Some(Value::Array(proofs)) if proofs.is_empty() => return Err(Error::MissingProof),
+      Some(Value::Array(proofs)) => proofs.clone(),
+      Some(proof) => vec![proof.clone()],
+    };
+    proofs
+      .into_iter()
+      .map(|proof| self.verify_proof(unsecured.clone(), proof, issuer, verifier, options))
+      .collect()
+  }
+
+  /// Serializes `value`, e.g. a [`Credential`](crate::credential::Credential), and [verifies](Self::verify()) its
+  /// proofs.
+  pub fn verify_serializable<T: Serialize + ?Sized, V: JwsVerifier>(
+    &self,
+    value: &T,
+    issuer: &CoreDocument,
+    verifier: &V,
+    options: &DataIntegrityVerificationOptions,
+  ) -> Result<Vec<DataIntegrityProof>> {
+    let document: Value =
+      serde_json::to_value(value).map_err(|_| Error::InvalidDocument("document cannot be serialized"))?;
+    self.verify(&document, issuer, verifier, options)
+  }
+
+  fn verify_proof<V: JwsVerifier>(
+    &self,
+    unsecured: Map<String, Value>,
+    proof: Value,
+    issuer: &CoreDocument,
+    verifier: &V,
+    options: &DataIntegrityVerificationOptions,
+  ) -> Result<DataIntegrityProof> {
+    let Value::Object(mut config) = proof else {
+      return Err(Error::InvalidDocument("proof is not an object"));
+    };
+    let parsed: DataIntegrityProof = serde_json::from_value(Value::Object(config.clone()))
+      .map_err(|_| Error::InvalidDocument("proof is not a valid DataIntegrityProof"))?;
+
+    if parsed.type_ != DataIntegrityProof::TYPE {
+      return Err(Error::UnsupportedProofType(parsed.type_));
+    }
+    let cryptosuite: Cryptosuite = parsed.cryptosuite.parse()?;
+    if parsed.expires.is_some_and(|expires| expires < Timestamp::now_utc()) {
+      return Err(Error::ProofExpired);
+    }
+    if parsed.proof_purpose != proof_purpose(options.purpose) {
+      return Err(Error::ProofMismatch("purpose"));
+    }
+    if options.domain.is_some() && parsed.domain != options.domain {
+      return Err(Error::ProofMismatch("domain"));
+    }
+    if options.challenge.is_some() && parsed.challenge != options.challenge {
+      return Err(Error::ProofMismatch("challenge"));
+    }
+
+    let Some(Value::String(proof_value)) = config.remove("proofValue") else {
+      return Err(Error::InvalidProofValue);
+    };
+
+    let method: &VerificationMethod = issuer
+      .resolve_method(
+        parsed.verification_method.as_str(),
+        Some(MethodScope::from(options.purpose)),
+      )
+      .ok_or_else(|| Error::MethodNotFound(parsed.verification_method.clone()))?;
+    let public_key: Jwk = public_key(method, cryptosuite)?;
+
+    let (signing_input, signature): (Vec<u8>, Vec<u8>) = match cryptosuite {
+      Cryptosuite::EcdsaSd2023 => self.sd_verify_data(unsecured, config, &proof_value)?,
+      _ if proof_value.starts_with('z') => (
+        self.hash_data(unsecured, config)?,
+        BaseEncoding::decode_multibase(&proof_value).map_err(|_| Error::InvalidProofValue)?,
+      ),
+      _ => return Err(Error::InvalidProofValue),
+    };
+    verifier
+      .verify(
+        VerificationInput {
+          alg: cryptosuite.algorithm(),
+          signing_input: signing_input.into_boxed_slice(),
+          decoded_signature: signature.into_boxed_slice(),
+        },
+        &public_key,
+      )
+      .map_err(Error::Signature)?;
+
+    Ok(parsed)
+  }
+
+  /// Hashes the proof configuration `config` and the `unsecured` document, after checking that all their terms are
+  /// defined and canonicalizing them.
+  fn hash_data(&self, unsecured: Map<String, Value>, config: Map<String, Value>) -> Result<Vec<u8>> {
+    let mut hash_data: Vec<u8> = Vec::with_capacity(2 * SHA256_LEN);
+    hash_data.extend_from_slice(&self.proof_config_hash(&unsecured, config)?);
+    hash_data.extend_from_slice(&self.hash_canonical(&Value::Object(unsecured))?);
+    Ok(hash_data)
+  }
+
+  /// Hashes the proof configuration `config` with the `@context` of the `unsecured` document.
+  pub(super) fn proof_config_hash(
+    &self,
+    unsecured: &Map<String, Value>,
+    mut config: Map<String, Value>,
+  ) -> Result<[u8; SHA256_LEN]> {
+    let context: Value = unsecured
+      .get("@context")
+      .cloned()
+      .ok_or(Error::InvalidDocument("document has no @context"))?;
+    config.insert("@context".to_owned(), context);
+    self.hash_canonical(&Value::Object(config))
+  }
+
+  /// Checks that all terms of `value` are defined and hashes its canonical form.
+  fn hash_canonical(&self, value: &Value) -> Result<[u8; SHA256_LEN]> {
+    self.jsonld.validate(value)?;
+    let canonical: String = self.jsonld.canonicalize(value)?;
+    let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+    SHA256(canonical.as_bytes(), &mut digest);
+    Ok(digest)
+  }
+}
+
+/// Returns `document` without its proofs.
+pub(super) fn unsecured(document: &Value) -> Result<Map<String, Value>> {
+  let mut unsecured: Map<String, Value> = document
+    .as_object()
+    .cloned()
+    .ok_or(Error::InvalidDocument("document is not an object"))?;
+  unsecured.remove("proof");
+  Ok(unsecured)
+}
+
+/// Returns `document` with `proof` added to its proof set.
+pub(super) fn add_proof(document: &Value, proof: &DataIntegrityProof) -> Result<Value> {
+  let mut secured: Map<String, Value> = document
+    .as_object()
+    .cloned()
+    .ok_or(Error::InvalidDocument("document is not an object"))?;
+  let proof: Value = Value::Object(to_object(proof)?);
+  let proofs: Value = match secured.remove("proof") {
+    None => proof,
+    Some(Value::Array(mut proofs)) => {
+      proofs.push(proof);
+      Value::Array(proofs)
+    }
+    Some(existing) => Value::Array(vec![existing, proof]),
+  };
+  secured.insert("proof".to_owned(), proofs);
+  Ok(Value::Object(secured))
+}
+
+pub(super) fn to_object(proof: &DataIntegrityProof) -> Result<Map<String, Value>> {
+  match serde_json::to_value(proof) {
+    Ok(Value::Object(object)) => Ok(object),
+    _ => Err(Error::InvalidDocument("proof cannot be serialized")),
+  }
+}
+
+/// Returns the public key of `method` as a JWK, converting `Multikey` Ed25519 keys.
+fn public_key(method: &VerificationMethod, cryptosuite: Cryptosuite) -> Result<Jwk> {
+  match method.data() {
+    MethodData::PublicKeyJwk(jwk) => Ok(jwk.clone()),
+    MethodData::PublicKeyMultibase(_) if cryptosuite == Cryptosuite::EddsaRdfc2022 => {
+      let decoded: Vec<u8> = method
+        .data()
+        .try_decode()
+        .map_err(|_| Error::UnsupportedVerificationMethod("invalid multibase key"))?;
+      let key: &[u8] = match decoded.strip_prefix(&ED25519_MULTICODEC) {
+        Some(key) => key,
+        // Ed25519VerificationKey2020 methods encode the key without multicodec prefix.
+        None => &decoded,
+      };
+      if key.len() != 32 {
+        return Err(Error::UnsupportedVerificationMethod("expected an Ed25519 public key"));
+      }
+      let mut params: JwkParamsOkp = JwkParamsOkp::new();
+      params.crv = EdCurve::Ed25519.name().to_owned();
+      params.x = jwu::encode_b64(key);
+      Ok(Jwk::from_params(params))
+    }
+    _ => Err(Error::UnsupportedVerificationMethod(
+      "expected publicKeyJwk or an Ed25519 publicKeyMultibase",
+    )),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::data_integrity::DataIntegrityProofOptions;
+  use crate::jsonld::ContextCache;
+  use crate::validator::test_utils;
+
+  const CREDENTIALS_V1: &str = "https://www.w3.org/2018/credentials/v1";
+  const DATA_INTEGRITY_V2: &str = "https://w3id.org/security/data-integrity/v2";
+
+  /// Minimal stand-ins for the credentials and Data Integrity contexts.
+  fn contexts() -> ContextCache {
+    let mut cache: ContextCache = ContextCache::new();
+    cache.insert(
+      CREDENTIALS_V1,
+      json!({
+        "@context": {
+          "id": "@id",
+          "type": "@type",
+          "cred": "https://www.w3.org/2018/credentials#",
+          "xsd": "http://www.w3.org/2001/XMLSchema#",
+          "VerifiableCredential": {
+            "@id": "cred:VerifiableCredential",
+            "@context": {
+              "credentialSubject": { "@id": "cred:credentialSubject", "@type": "@id" },
+              "issuer": { "@id": "cred:issuer", "@type": "@id" },
+              "issuanceDate": { "@id": "cred:issuanceDate", "@type": "xsd:dateTime" },
+              "proof": { "@id": "https://w3id.org/security#proof", "@type": "@id", "@container": "@graph" }
+            }
+          }
+        }
+      }),
+    );
+    cache.insert(
+      DATA_INTEGRITY_V2,
+      json!({
+        "@context": {
+          "id": "@id",
+          "type": "@type",
+          "sec": "https://w3id.org/security#",
+          "xsd": "http://www.w3.org/2001/XMLSchema#",
+          "DataIntegrityProof": {
+            "@id": "sec:DataIntegrityProof",
+            "@context": {
+              "challenge": "sec:challenge",
+              "created": { "@id": "http://purl.org/dc/terms/created", "@type": "xsd:dateTime" },
+              "domain": "sec:domain",
+              "expires": { "@id": "sec:expiration", "@type": "xsd:dateTime" },
+              "cryptosuite": { "@id": "sec:cryptosuite", "@type": "https://w3id.org/security#cryptosuiteString" },
+              "proofPurpose": { "@id": "sec:proofPurpose", "@type": "@vocab" },
+              "proofValue": { "@id": "sec:proofValue", "@type": "sec:multibase" },
+              "verificationMethod": { "@id": "sec:verificationMethod", "@type": "@id" },
+              "assertionMethod": { "@id": "sec:assertionMethod", "@type": "@id", "@container": "@set" }
+            }
+          }
+        }
+      }),
+    );
+    cache.insert(
+      "https://example.com/name/v1",
+      json!({ "@context": { "name": "https://schema.org/name" } }),
+    );
+    cache
+  }
+
+  #[test]
+  fn test_eddsa_rdfc_2022_roundtrip() {
+    let (issuer_doc, secret_key, fragment) = test_utils::generate_jwk_document_with_keys();
+    let credential: Credential = CredentialBuilder::default()
+      .context(Url::parse(DATA_INTEGRITY_V2).unwrap())
+      .context(Url::parse("https://example.com/name/v1").unwrap())
+      .issuer(Url::parse(issuer_doc.id().as_str()).unwrap())
+      .issuance_date(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+      .subject(Subject::from_json_value(json!({ "id": "did:example:holder", "name": "Alice" })).unwrap())
+      .build()
+      .unwrap();
+    let document: Value = serde_json::to_value(&credential).unwrap();
+
+    let processor: DataIntegrityProcessor<ContextCache> = DataIntegrityProcessor::new(contexts());
+    let method_id: String = issuer_doc.resolve_method(&fragment, None).unwrap().id().to_string();
+    let proof: DataIntegrityProof = DataIntegrityProof::new(
+      Cryptosuite::EddsaRdfc2022,
+      method_id,
+      &DataIntegrityProofOptions::default(),
+    );
+    let signing_input: Vec<u8> = processor.signing_input(&document, &proof).unwrap();
+    let signature: [u8; 64] = secret_key.sign(&signing_input).to_bytes();
+    let secured: Value = processor.secure(&document, proof.clone(), &signature).unwrap();
+
+    let options: DataIntegrityVerificationOptions = DataIntegrityVerificationOptions::default();
+    let verified: Vec<DataIntegrityProof> = processor
+      .verify(&secured, &issuer_doc, &EdDSAJwsVerifier::default(), &options)
+      .unwrap();
+    assert_eq!(verified.len(), 1);
+    assert_eq!(verified[0].verification_method, proof.verification_method);
+
+    // Secured credentials deserialize into `Credential` and verify after serializing them again.
+    let deserialized: Credential = Credential::from_json_value(secured.clone()).unwrap();
+    processor
+      .verify_serializable(&deserialized, &issuer_doc, &EdDSAJwsVerifier::default(), &options)
+      .unwrap();
+
+    let mut tampered: Value = secured.clone();
+    tampered["credentialSubject"]["name"] = json!("Mallory");
+    assert!(matches!(
+      processor.verify(&tampered, &issuer_doc, &EdDSAJwsVerifier::default(), &options),
+      Err(Error::Signature(_))
+    ));
+
+    let mut undefined: Value = secured.clone();
+    undefined["credentialSubject"]["age"] = json!(42);
+    assert!(matches!(
+      processor.verify(&undefined, &issuer_doc, &EdDSAJwsVerifier::default(), &options),
+      Err(Error::JsonLd(crate::jsonld::Error::UndefinedTerms(_)))
+    ));
+
+    assert!(matches!(
+      processor.verify(
+        &secured,
+        &issuer_doc,
+        &EdDSAJwsVerifier::default(),
+        &options.clone().purpose(MethodRelationship::Authentication)
+      ),
+      Err(Error::ProofMismatch("purpose"))
+    ));
+  }
+}
@@ -0,0 +1,144 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_verification::MethodRelationship;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Cryptosuite;
+
+/// A [`DataIntegrityProof`](https://www.w3.org/TR/vc-data-integrity/#dataintegrityproof) securing a credential or
+/// presentation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataIntegrityProof {
+  /// The type of the proof, always `DataIntegrityProof`.
+  #[serde(rename = "type")]
+  pub type_: String,
+  /// The identifier of the cryptosuite, e.g. `eddsa-rdfc-2022`.
+  pub cryptosuite: String,
+  /// The time the proof was created.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub created: Option<Timestamp>,
+  /// The time the proof expires.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires: Option<Timestamp>,
+  /// The DID URL of the verification method the proof was created with.
+  pub verification_method: String,
+  /// The verification relationship the proof was created for, e.g. `assertionMethod`.
+  pub proof_purpose: String,
+  /// The domain the proof is bound to, e.g. the verifier of a presentation.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub domain: Option<String>,
+  /// The challenge the proof is bound to, e.g. a nonce chosen by the verifier of a presentation.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub challenge: Option<String>,
+  /// The multibase encoded proof value: a base58-btc signature, or the base64url encoded CBOR proof value of
+  /// `ecdsa-sd-2023` proofs.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub proof_value: Option<String>,
+  /// Additional properties of the proof, e.g. `id` or `previousProof`.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl DataIntegrityProof {
+  /// The `type` of Data Integrity proofs.
+  pub const TYPE: &'static str = "DataIntegrityProof";
+
+  /// Creates a new, unsigned [`DataIntegrityProof`] of the `cryptosuite`, created now with the verification method
+  /// identified by `verification_method`.
+  pub fn new(
+    cryptosuite: Cryptosuite,
+    verification_method: impl Into<String>,
+    options: &DataIntegrityProofOptions,
+  ) -> Self {
+    Self {
+      type_: Self::TYPE.to_owned(),
+      cryptosuite: cryptosuite.name().to_owned(),
+      created: Some(options.created.unwrap_or_else(Timestamp::now_utc)),
+      expires: options.expires,
+      verification_method: verification_method.into(),
+      proof_purpose: proof_purpose(options.purpose).to_owned(),
+      domain: options.domain.clone(),
+      challenge: options.challenge.clone(),
+      proof_value: None,
+      properties: Object::new(),
+    }
+  }
+}
+
+/// Options for creating a [`DataIntegrityProof`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DataIntegrityProofOptions {
+  /// The verification relationship the proof is created for.
+  ///
+  /// Default: [`MethodRelationship::AssertionMethod`].
+  pub purpose: MethodRelationship,
+  /// The time the proof is created. Defaults to the current time.
+  pub created: Option<Timestamp>,
+  /// The time the proof expires, if any.
+  pub expires: Option<Timestamp>,
+  /// The domain the proof is bound to, e.g. the verifier of a presentation.
+  pub domain: Option<String>,
+  /// The challenge the proof is bound to, e.g. a nonce chosen by the verifier of a presentation.
+  pub challenge: Option<String>,
+}
+
+impl Default for DataIntegrityProofOptions {
+  fn default() -> Self {
+    Self {
+      purpose: MethodRelationship::AssertionMethod,
+      created: None,
+      expires: None,
+      domain: None,
+      challenge: None,
+    }
+  }
+}
+
+impl DataIntegrityProofOptions {
+  /// Sets the verification relationship the proof is created for.
+  pub fn purpose(mut self, purpose: MethodRelationship) -> Self {
+    self.purpose = purpose;
+    self
+  }
+
+  /// Sets the time the proof is created.
+  pub fn created(mut self, created: Timestamp) -> Self {
+    self.created = Some(created);
+    self
+  }
+
+  /// Sets the time the proof expires.
+  pub fn expires(mut self, expires: Timestamp) -> Self {
+    self.expires = Some(expires);
+    self
+  }
+
+  /// Sets the domain the proof is bound to.
+  pub fn domain(mut self, domain: impl Into<String>) -> Self {
+    self.domain = Some(domain.into());
+    self
+  }
+
+  /// Sets the challenge the proof is bound to.
+  pub fn challenge(mut self, challenge: impl Into<String>) -> Self {
+    self.challenge = Some(challenge.into());
+    self
+  }
+}
+
+/// Returns the `proofPurpose` corresponding to `relationship`.
+pub(crate) fn proof_purpose(relationship: MethodRelationship) -> &'static str {
+  match relationship {
+    MethodRelationship::Authentication => "authentication",
+    MethodRelationship::AssertionMethod => "assertionMethod",
+    MethodRelationship::KeyAgreement => "keyAgreement",
+    MethodRelationship::CapabilityDelegation => "capabilityDelegation",
+    MethodRelationship::CapabilityInvocation => "capabilityInvocation",
+  }
+}
@@ -0,0 +1,664 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use ciborium::Value as CborValue;
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use crypto::macs::hmac::HMAC_SHA256;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_verification::jws::SignatureVerificationErrorKind;
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::signature::Verifier;
+use p256::ecdsa::Signature;
+use p256::ecdsa::SigningKey;
+use p256::ecdsa::VerifyingKey;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::processor::add_proof;
+use super::processor::to_object;
+use super::processor::unsecured;
+use super::Cryptosuite;
+use super::DataIntegrityProcessor;
+use super::DataIntegrityProof;
+use super::Error;
+use super::Result;
+use crate::jsonld::canonical_labels;
+use crate::jsonld::relabel;
+use crate::jsonld::DocumentLoader;
+use crate::jsonld::Quad;
+
+/// The CBOR tag of `ecdsa-sd-2023` base proof values.
+const BASE_PROOF_TAG: u64 = 0x5d00;
+/// The CBOR tag of `ecdsa-sd-2023` derived proof values.
+const DERIVED_PROOF_TAG: u64 = 0x5d01;
+/// The multicodec prefix of compressed P-256 public keys in `Multikey` format.
+const P256_MULTICODEC: [u8; 2] = [0x80, 0x24];
+const HMAC_KEY_LEN: usize = 32;
+const CANONICAL_PREFIX: &str = "c14n";
+
+/// A base proof of the `ecdsa-sd-2023` cryptosuite, prepared with
+/// [`DataIntegrityProcessor::prepare_sd_base_proof`] and awaiting the issuer's signature over its
+/// [`signing_input`](Self::signing_input()).
+///
+/// Every statement of the document that is not mandatory to disclose is signed individually with an ephemeral
+/// P-256 key, such that holders can disclose any subset of them. The blank nodes of the document are relabeled
+/// with an HMAC, whose key is only shared with the holder in the base proof.
+#[derive(Clone)]
+pub struct UnsignedSdBaseProof {
+  proof: DataIntegrityProof,
+  signing_input: Vec<u8>,
+  public_key: Vec<u8>,
+  hmac_key: Vec<u8>,
+  signatures: Vec<Vec<u8>>,
+  mandatory_pointers: Vec<String>,
+}
+
+impl UnsignedSdBaseProof {
+  /// Returns the proof, without `proofValue`.
+  pub fn proof(&self) -> &DataIntegrityProof {
+    &self.proof
+  }
+
+  /// Returns the data to sign with the key of the proof's verification method, i.e. the hash of the proof
+  /// configuration, the ephemeral public key and the hash of the mandatory statements.
+  pub fn signing_input(&self) -> &[u8] {
+    &self.signing_input
+  }
+}
+
+impl<L: DocumentLoader> DataIntegrityProcessor<L> {
+  /// Prepares an `ecdsa-sd-2023` base proof securing `document`.
+  ///
+  /// `mandatory_pointers` are JSON pointers, e.g. `/issuer` or `/credentialSubject/type`, to the values holders
+  /// must always disclose.
+  pub fn prepare_sd_base_proof(
+    &self,
+    document: &Value,
+    proof: DataIntegrityProof,
+    mandatory_pointers: &[String],
+  ) -> Result<UnsignedSdBaseProof> {
+    if proof.cryptosuite != Cryptosuite::EcdsaSd2023.name() {
+      return Err(Error::UnsupportedCryptosuite(proof.cryptosuite));
+    }
+    let unsecured: Map<String, Value> = unsecured(document)?;
+    let mut config: Map<String, Value> = to_object(&proof)?;
+    config.remove("proofValue");
+    let proof_hash: [u8; SHA256_LEN] = self.proof_config_hash(&unsecured, config)?;
+    let document: Value = Value::Object(unsecured);
+    self.jsonld().validate(&document)?;
+
+    let mut hmac_key: Vec<u8> = vec![0; HMAC_KEY_LEN];
+    let mut secret: [u8; 32] = [0; 32];
+    crypto::utils::rand::fill(&mut hmac_key)
+      .and_then(|_| crypto::utils::rand::fill(&mut secret))
+      .map_err(|_| Error::SelectiveDisclosure("failed to generate randomness"))?;
+    let signing_key: SigningKey =
+      SigningKey::from_slice(&secret).map_err(|_| Error::SelectiveDisclosure("failed to generate the proof key"))?;
+    let public_key: Vec<u8> = [
+      P256_MULTICODEC.as_slice(),
+      signing_key.verifying_key().to_encoded_point(true).as_bytes(),
+    ]
+    .concat();
+
+    let labels: HashMap<String, String> = self.hmac_labels(&document, &hmac_key)?;
+    let nquads: Vec<String> = self.labeled_nquads(&document, &labels)?;
+    let mandatory: BTreeSet<String> = self.selected_nquads(&document, mandatory_pointers, &labels)?;
+    if mandatory.iter().any(|nquad| nquads.binary_search(nquad).is_err()) {
+      return Err(Error::SelectiveDisclosure(
+        "mandatory statements are not statements of the document",
+      ));
+    }
+    let signatures: Vec<Vec<u8>> = nquads
+      .iter()
+      .filter(|nquad| !mandatory.contains(*nquad))
+      .map(|nquad| {
+        let signature: Signature = signing_key.sign(nquad.as_bytes());
+        signature.to_bytes().to_vec()
+      })
+      .collect();
+
+    let signing_input: Vec<u8> = [proof_hash.as_slice(), &public_key, &hash_nquads(&mandatory)].concat();
+    Ok(UnsignedSdBaseProof {
+      proof,
+      signing_input,
+      public_key,
+      hmac_key,
+      signatures,
+      mandatory_pointers: mandatory_pointers.to_vec(),
+    })
+  }
+
+  /// Returns `document` secured with the `ecdsa-sd-2023` base proof and the issuer's `signature` over its
+  /// [`signing_input`](UnsignedSdBaseProof::signing_input()).
+  ///
+  /// The base proof is meant for the holder only, who [derives](Self::derive_sd_proof()) the proofs presented to
+  /// verifiers from it.
+  pub fn secure_sd_base_proof(&self, document: &Value, base: UnsignedSdBaseProof, signature: &[u8]) -> Result<Value> {
+    let proof_value: String = encode_proof_value(
+      BASE_PROOF_TAG,
+      vec![
+        CborValue::Bytes(signature.to_vec()),
+        CborValue::Bytes(base.public_key),
+        CborValue::Bytes(base.hmac_key),
+        CborValue::Array(base.signatures.into_iter().map(CborValue::Bytes).collect()),
+        CborValue::Array(base.mandatory_pointers.into_iter().map(CborValue::Text).collect()),
+      ],
+    )?;
+    let mut proof: DataIntegrityProof = base.proof;
+    proof.proof_value = Some(proof_value);
+    add_proof(document, &proof)
+  }
+
+  /// Derives a document disclosing the mandatory values and the values selected by the JSON pointers
+  /// `selective_pointers` from `document` secured with an `ecdsa-sd-2023` base proof.
+  ///
+  /// The returned document holds the values selected by the pointers, the `id` and `type` of the objects containing
+  /// them, and a derived proof that can be [verified](Self::verify()) by anyone.
+  pub fn derive_sd_proof(&self, document: &Value, selective_pointers: &[String]) -> Result<Value> {
+    let proofs: Vec<&Value> = match document.get("proof") {
+      Some(Value::Array(proofs)) => proofs.iter().collect(),
+      Some(proof) => vec![proof],
+      None => Vec::new(),
+    };
+    let mut proof: DataIntegrityProof = proofs
+      .into_iter()
+      .filter_map(|proof| serde_json::from_value::<DataIntegrityProof>(proof.clone()).ok())
+      .find(|proof| proof.cryptosuite == Cryptosuite::EcdsaSd2023.name())
+      .ok_or(Error::MissingProof)?;
+    let [signature, public_key, hmac_key, signatures, mandatory_pointers]: [CborValue; 5] =
+      decode_proof_value(proof.proof_value.as_deref().unwrap_or_default(), BASE_PROOF_TAG)?;
+    let hmac_key: Vec<u8> = into_bytes(hmac_key)?;
+    let signatures: Vec<Vec<u8>> = into_array(signatures)?
+      .into_iter()
+      .map(into_bytes)
+      .collect::<Result<_>>()?;
+    let mandatory_pointers: Vec<String> = into_array(mandatory_pointers)?
+      .into_iter()
+      .map(|pointer| pointer.into_text().map_err(|_| Error::InvalidProofValue))
+      .collect::<Result<_>>()?;
+
+    let document: Value = Value::Object(unsecured(document)?);
+    self.jsonld().validate(&document)?;
+    let labels: HashMap<String, String> = self.hmac_labels(&document, &hmac_key)?;
+    let nquads: Vec<String> = self.labeled_nquads(&document, &labels)?;
+    let mandatory: BTreeSet<String> = self.selected_nquads(&document, &mandatory_pointers, &labels)?;
+    let non_mandatory: Vec<&String> = nquads.iter().filter(|nquad| !mandatory.contains(*nquad)).collect();
+    if non_mandatory.len() != signatures.len() {
+      return Err(Error::SelectiveDisclosure("base proof does not match the document"));
+    }
+
+    let pointers: Vec<String> = mandatory_pointers
+      .into_iter()
+      .chain(selective_pointers.to_vec())
+      .collect();
+    if pointers.is_empty() {
+      return Err(Error::SelectiveDisclosure("no values selected for disclosure"));
+    }
+    let mut reveal: Value = select(&document, &pointers)?;
+    let reveal_quads: Vec<Quad> = self.jsonld().to_rdf_positional(&reveal)?;
+    let reveal_nquads: Vec<String> = relabel_nquads(&reveal_quads, &labels)?;
+
+    // The verifier canonicalizes the disclosed document anew, so the HMAC labels are keyed by the canonical labels.
+    let label_map: Vec<(CborValue, CborValue)> = canonical_labels(&reveal_quads)?
+      .into_iter()
+      .map(|(label, canonical)| {
+        let index: Option<u64> = canonical
+          .strip_prefix(CANONICAL_PREFIX)
+          .and_then(|index| index.parse().ok());
+        let digest: Option<Vec<u8>> = labels
+          .get(&label)
+          .and_then(|label| label.strip_prefix('u'))
+          .and_then(|digest| BaseEncoding::decode(digest, Base::Base64Url).ok());
+        index
+          .zip(digest)
+          .map(|(index, digest)| (index, CborValue::Bytes(digest)))
+          .ok_or(Error::SelectiveDisclosure("blank node without label"))
+      })
+      .collect::<Result<BTreeMap<u64, CborValue>>>()?
+      .into_iter()
+      .map(|(index, digest)| (index.into(), digest))
+      .collect();
+    let mandatory_indexes: Vec<CborValue> = reveal_nquads
+      .iter()
+      .enumerate()
+      .filter(|(_, nquad)| mandatory.contains(*nquad))
+      .map(|(index, _)| (index as u64).into())
+      .collect();
+    let signatures: Vec<CborValue> = non_mandatory
+      .into_iter()
+      .zip(signatures)
+      .filter(|(nquad, _)| reveal_nquads.binary_search(nquad).is_ok())
+      .map(|(_, signature)| CborValue::Bytes(signature))
+      .collect();
+
+    proof.proof_value = Some(encode_proof_value(
+      DERIVED_PROOF_TAG,
+      vec![
+        signature,
+        public_key,
+        CborValue::Array(signatures),
+        CborValue::Map(label_map),
+        CborValue::Array(mandatory_indexes),
+      ],
+    )?);
+    strip_nulls(&mut reveal);
+    add_proof(&reveal, &proof)
+  }
+
+  /// Verifies the statement signatures of a derived `ecdsa-sd-2023` proof, returning the signing input and the
+  /// signature of the base proof, to be verified with the issuer's key.
+  pub(super) fn sd_verify_data(
+    &self,
+    unsecured: Map<String, Value>,
+    config: Map<String, Value>,
+    proof_value: &str,
+  ) -> Result<(Vec<u8>, Vec<u8>)> {
+    let [signature, public_key, signatures, label_map, mandatory_indexes]: [CborValue; 5] =
+      decode_proof_value(proof_value, DERIVED_PROOF_TAG)?;
+    let signature: Vec<u8> = into_bytes(signature)?;
+    let public_key: Vec<u8> = into_bytes(public_key)?;
+    let signatures: Vec<Vec<u8>> = into_array(signatures)?
+      .into_iter()
+      .map(into_bytes)
+      .collect::<Result<_>>()?;
+    let label_map: HashMap<u64, Vec<u8>> = label_map
+      .into_map()
+      .map_err(|_| Error::InvalidProofValue)?
+      .into_iter()
+      .map(|(index, digest)| Ok((into_u64(index)?, into_bytes(digest)?)))
+      .collect::<Result<_>>()?;
+    let mandatory_indexes: BTreeSet<u64> = into_array(mandatory_indexes)?
+      .into_iter()
+      .map(into_u64)
+      .collect::<Result<_>>()?;
+
+    let proof_hash: [u8; SHA256_LEN] = self.proof_config_hash(&unsecured, config)?;
+    let document: Value = Value::Object(unsecured);
+    self.jsonld().validate(&document)?;
+    let quads: Vec<Quad> = self.jsonld().to_rdf(&document)?;
+    let labels: HashMap<String, String> = canonical_labels(&quads)?
+      .into_iter()
+      .map(|(label, canonical)| {
+        canonical
+          .strip_prefix(CANONICAL_PREFIX)
+          .and_then(|index| index.parse::<u64>().ok())
+          .and_then(|index| label_map.get(&index))
+          .map(|digest| (label, hmac_label(digest)))
+          .ok_or(Error::SelectiveDisclosure("label map does not match the document"))
+      })
+      .collect::<Result<_>>()?;
+    let nquads: Vec<String> = relabel_nquads(&quads, &labels)?;
+
+    let (mandatory, non_mandatory): (Vec<(usize, &String)>, Vec<(usize, &String)>) = nquads
+      .iter()
+      .enumerate()
+      .partition(|(index, _)| mandatory_indexes.contains(&(*index as u64)));
+    if mandatory.len() != mandatory_indexes.len() {
+      return Err(Error::SelectiveDisclosure(
+        "mandatory indexes do not match the document",
+      ));
+    }
+    if non_mandatory.len() != signatures.len() {
+      return Err(Error::SelectiveDisclosure("signatures do not match the document"));
+    }
+
+    let verifying_key: VerifyingKey = public_key
+      .strip_prefix(&P256_MULTICODEC)
+      .and_then(|key| VerifyingKey::from_sec1_bytes(key).ok())
+      .ok_or(Error::InvalidProofValue)?;
+    for ((_, nquad), signature) in non_mandatory.into_iter().zip(&signatures) {
+      Signature::from_slice(signature)
+        .and_then(|signature| verifying_key.verify(nquad.as_bytes(), &signature))
+        .map_err(|_| Error::Signature(SignatureVerificationErrorKind::InvalidSignature.into()))?;
+    }
+
+    let mandatory: BTreeSet<String> = mandatory.into_iter().map(|(_, nquad)| nquad.clone()).collect();
+    let signing_input: Vec<u8> = [proof_hash.as_slice(), &public_key, &hash_nquads(&mandatory)].concat();
+    Ok((signing_input, signature))
+  }
+
+  /// Returns the HMAC label of each blank node of `document`, keyed by its positional label.
+  fn hmac_labels(&self, document: &Value, hmac_key: &[u8]) -> Result<HashMap<String, String>> {
+    let quads: Vec<Quad> = self.jsonld().to_rdf_positional(document)?;
+    Ok(
+      canonical_labels(&quads)?
+        .into_iter()
+        .map(|(label, canonical)| {
+          let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+          HMAC_SHA256(canonical.as_bytes(), hmac_key, &mut digest);
+          (label, hmac_label(&digest))
+        })
+        .collect(),
+    )
+  }
+
+  /// Returns the sorted N-Quads of `document`, with the blank nodes relabeled by `labels`.
+  fn labeled_nquads(&self, document: &Value, labels: &HashMap<String, String>) -> Result<Vec<String>> {
+    relabel_nquads(&self.jsonld().to_rdf_positional(document)?, labels)
+  }
+
+  /// Returns the labeled N-Quads of the values of `document` selected by `pointers`.
+  fn selected_nquads(
+    &self,
+    document: &Value,
+    pointers: &[String],
+    labels: &HashMap<String, String>,
+  ) -> Result<BTreeSet<String>> {
+    if pointers.is_empty() {
+      return Ok(BTreeSet::new());
+    }
+    Ok(
+      self
+        .labeled_nquads(&select(document, pointers)?, labels)?
+        .into_iter()
+        .collect(),
+    )
+  }
+}
+
+fn hmac_label(digest: &[u8]) -> String {
+  format!("u{}", BaseEncoding::encode(digest, Base::Base64Url))
+}
+
+fn hash_nquads(nquads: &BTreeSet<String>) -> [u8; SHA256_LEN] {
+  let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+  SHA256(
+    nquads.iter().map(String::as_str).collect::<String>().as_bytes(),
+    &mut digest,
+  );
+  digest
+}
+
+/// Relabels the blank nodes of `quads` with `labels`, returning their sorted N-Quads without duplicates.
+fn relabel_nquads(quads: &[Quad], labels: &HashMap<String, String>) -> Result<Vec<String>> {
+  let mut nquads: Vec<String> = Vec::with_capacity(quads.len());
+  for quad in quads {
+    let labeled: bool = [&quad.subject, &quad.predicate, &quad.object]
+      .into_iter()
+      .chain(quad.graph.as_ref())
+      .filter_map(|term| term.as_blank_node())
+      .all(|label| labels.contains_key(label));
+    if !labeled {
+      return Err(Error::SelectiveDisclosure("blank node without label"));
+    }
+    nquads.push(relabel(quad, |label| labels[label].clone()).to_string());
+  }
+  nquads.sort();
+  nquads.dedup();
+  Ok(nquads)
+}
+
+/// Returns the values of `document` selected by the JSON `pointers`, keeping the `@context`, `id` and `type` of the
+/// objects containing them and the positions of array items, with `null` in place of unselected items.
+fn select(document: &Value, pointers: &[String]) -> Result<Value> {
+  let mut selection: Value = skeleton(document);
+  for pointer in pointers {
+    let invalid = || Error::InvalidPointer(pointer.clone());
+    let segments: Vec<String> = match pointer.strip_prefix('/') {
+      Some(segments) => segments
+        .split('/')
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect(),
+      None if pointer.is_empty() => Vec::new(),
+      None => return Err(invalid()),
+    };
+
+    let mut source: &Value = document;
+    let mut target: &mut Value = &mut selection;
+    for segment in segments {
+      (source, target) = match (source, target) {
+        (Value::Object(source), Value::Object(target)) => {
+          let child: &Value = source.get(&segment).ok_or_else(invalid)?;
+          (child, target.entry(segment).or_insert_with(|| skeleton(child)))
+        }
+        (Value::Array(source), Value::Array(target)) => {
+          let index: usize = segment.parse().map_err(|_| invalid())?;
+          let child: &Value = source.get(index).ok_or_else(invalid)?;
+          let item: &mut Value = &mut target[index];
+          if item.is_null() {
+            *item = skeleton(child);
+          }
+          (child, item)
+        }
+        _ => return Err(invalid()),
+      };
+    }
+    *target = source.clone();
+  }
+  Ok(selection)
+}
+
+/// Returns the part of `value` identifying it: the `@context`, `id` and `type` of objects and the length of arrays.
+fn skeleton(value: &Value) -> Value {
+  match value {
+    Value::Object(object) => Value::Object(
+      object
+        .iter()
+        .filter(|(key, _)| ["@context", "id", "@id", "type", "@type"].contains(&key.as_str()))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect(),
+    ),
+    Value::Array(items) => Value::Array(vec![Value::Null; items.len()]),
+    _ => Value::Null,
+  }
+}
+
+/// Removes the `null` placeholders of unselected array items.
+fn strip_nulls(value: &mut Value) {
+  match value {
+    Value::Array(items) => {
+      items.retain(|item| !item.is_null());
+      items.iter_mut().for_each(strip_nulls);
+    }
+    Value::Object(object) => object.values_mut().for_each(strip_nulls),
+    _ => {}
+  }
+}
+
+fn encode_proof_value(tag: u64, items: Vec<CborValue>) -> Result<String> {
+  let mut bytes: Vec<u8> = Vec::new();
+  ciborium::into_writer(&CborValue::Tag(tag, Box::new(CborValue::Array(items))), &mut bytes)
+    .map_err(|_| Error::InvalidProofValue)?;
+  Ok(BaseEncoding::encode_multibase(&bytes, Some(Base::Base64Url)))
+}
+
+fn decode_proof_value<const N: usize>(proof_value: &str, tag: u64) -> Result<[CborValue; N]> {
+  if !proof_value.starts_with('u') {
+    return Err(Error::InvalidProofValue);
+  }
+  let bytes: Vec<u8> = BaseEncoding::decode_multibase(proof_value).map_err(|_| Error::InvalidProofValue)?;
+  match ciborium::from_reader::<CborValue, _>(bytes.as_slice()) {
+    Ok(CborValue::Tag(value_tag, value)) if value_tag == tag => {
+      into_array(*value)?.try_into().map_err(|_| Error::InvalidProofValue)
+    }
+    _ => Err(Error::InvalidProofValue),
+  }
+}
+
+fn into_bytes(value: CborValue) -> Result<Vec<u8>> {
+  value.into_bytes().map_err(|_| Error::InvalidProofValue)
+}
+
+fn into_array(value: CborValue) -> Result<Vec<CborValue>> {
+  value.into_array().map_err(|_| Error::InvalidProofValue)
+}
+
+fn into_u64(value: CborValue) -> Result<u64> {
+  value
+    .into_integer()
+    .ok()
+    .and_then(|integer| u64::try_from(integer).ok())
+    .ok_or(Error::InvalidProofValue)
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Timestamp;
+  use identity_did::CoreDID;
+  use identity_document::document::CoreDocument;
+  use identity_ecdsa_verifier::EcDSAJwsVerifier;
+  use identity_verification::jwk::EcCurve;
+  use identity_verification::jwk::Jwk;
+  use identity_verification::jwk::JwkParamsEc;
+  use identity_verification::jws::JwsAlgorithm;
+  use identity_verification::jwu;
+  use identity_verification::VerificationMethod;
+  use p256::EncodedPoint;
+  use serde_json::json;
+
+  use super::*;
+  use crate::data_integrity::DataIntegrityProofOptions;
+  use crate::data_integrity::DataIntegrityVerificationOptions;
+  use crate::jsonld::ContextCache;
+
+  const CONTEXT: &str = "https://example.com/credentials/v1";
+
+  fn processor() -> DataIntegrityProcessor<ContextCache> {
+    let mut cache: ContextCache = ContextCache::new();
+    cache.insert(
+      CONTEXT,
+      json!({
+        "@context": {
+          "id": "@id",
+          "type": "@type",
+          "@vocab": "https://example.com/vocab#",
+          "xsd": "http://www.w3.org/2001/XMLSchema#",
+          "sec": "https://w3id.org/security#",
+          "issuer": { "@type": "@id" },
+          "verificationMethod": { "@id": "sec:verificationMethod", "@type": "@id" },
+          "proofPurpose": { "@id": "sec:proofPurpose", "@type": "@vocab" },
+          "created": { "@id": "http://purl.org/dc/terms/created", "@type": "xsd:dateTime" }
+        }
+      }),
+    );
+    DataIntegrityProcessor::new(cache)
+  }
+
+  fn issuer() -> (CoreDocument, SigningKey, String) {
+    let signing_key: SigningKey = SigningKey::from_slice(&[7; 32]).unwrap();
+    let point: EncodedPoint = signing_key.verifying_key().to_encoded_point(false);
+    let mut params: JwkParamsEc = JwkParamsEc::new();
+    params.crv = EcCurve::P256.name().to_owned();
+    params.x = jwu::encode_b64(point.x().unwrap());
+    params.y = jwu::encode_b64(point.y().unwrap());
+    let mut jwk: Jwk = Jwk::from_params(params);
+    jwk.set_alg(JwsAlgorithm::ES256.name());
+
+    let did: CoreDID = CoreDID::parse("did:example:issuer").unwrap();
+    let fragment: String = "#key".to_owned();
+    let document: CoreDocument = CoreDocument::builder(Object::new())
+      .id(did.clone())
+      .assertion_method(VerificationMethod::new_from_jwk(did, jwk, Some(&fragment)).unwrap())
+      .build()
+      .unwrap();
+    (document, signing_key, fragment)
+  }
+
+  fn secured() -> (Value, CoreDocument) {
+    let (issuer, signing_key, fragment) = issuer();
+    let document: Value = json!({
+      "@context": CONTEXT,
+      "type": "VerifiableCredential",
+      "issuer": issuer.id().as_str(),
+      "credentialSubject": {
+        "name": "Alice",
+        "birthDate": "2000-01-01",
+        "degrees": [
+          { "type": "Degree", "name": "Bachelor" },
+          { "type": "Degree", "name": "Master" }
+        ]
+      }
+    });
+    let processor: DataIntegrityProcessor<ContextCache> = processor();
+    let options: DataIntegrityProofOptions =
+      DataIntegrityProofOptions::default().created(Timestamp::parse("2024-01-01T00:00:00Z").unwrap());
+    let method_id: String = issuer.resolve_method(&fragment, None).unwrap().id().to_string();
+    let proof: DataIntegrityProof = DataIntegrityProof::new(Cryptosuite::EcdsaSd2023, method_id, &options);
+    let base: UnsignedSdBaseProof = processor
+      .prepare_sd_base_proof(&document, proof, &["/issuer".to_owned()])
+      .unwrap();
+    let signature: Signature = signing_key.sign(base.signing_input());
+    let secured: Value = processor
+      .secure_sd_base_proof(&document, base, &signature.to_bytes())
+      .unwrap();
+    (secured, issuer)
+  }
+
+  #[test]
+  fn derive_and_verify_sd_proof() {
+    let (secured, issuer) = secured();
+    let processor: DataIntegrityProcessor<ContextCache> = processor();
+    let options: DataIntegrityVerificationOptions = DataIntegrityVerificationOptions::default();
+
+    let derived: Value = processor
+      .derive_sd_proof(
+        &secured,
+        &[
+          "/credentialSubject/name".to_owned(),
+          "/credentialSubject/degrees/1".to_owned(),
+        ],
+      )
+      .unwrap();
+    assert_eq!(derived["issuer"], secured["issuer"]);
+    assert_eq!(derived["credentialSubject"]["name"], json!("Alice"));
+    assert!(derived["credentialSubject"].get("birthDate").is_none());
+    assert_eq!(
+      derived["credentialSubject"]["degrees"],
+      json!([{ "type": "Degree", "name": "Master" }])
+    );
+    processor
+      .verify(&derived, &issuer, &EcDSAJwsVerifier::default(), &options)
+      .unwrap();
+
+    // Only mandatory values.
+    let minimal: Value = processor.derive_sd_proof(&secured, &[]).unwrap();
+    assert!(minimal.get("credentialSubject").is_none());
+    processor
+      .verify(&minimal, &issuer, &EcDSAJwsVerifier::default(), &options)
+      .unwrap();
+
+    // INVALID: base proofs are not verifiable.
+    assert!(matches!(
+      processor.verify(&secured, &issuer, &EcDSAJwsVerifier::default(), &options),
+      Err(Error::InvalidProofValue)
+    ));
+
+    // INVALID: disclosed value was changed.
+    let mut tampered: Value = derived.clone();
+    tampered["credentialSubject"]["name"] = json!("Mallory");
+    assert!(matches!(
+      processor.verify(&tampered, &issuer, &EcDSAJwsVerifier::default(), &options),
+      Err(Error::Signature(_))
+    ));
+
+    // INVALID: mandatory value was changed.
+    let mut tampered: Value = derived.clone();
+    tampered["issuer"] = json!("did:example:other");
+    assert!(processor
+      .verify(&tampered, &issuer, &EcDSAJwsVerifier::default(), &options)
+      .is_err());
+  }
+
+  #[test]
+  fn derive_rejects_invalid_pointers() {
+    let (secured, _) = secured();
+    let processor: DataIntegrityProcessor<ContextCache> = processor();
+    for pointer in [
+      "credentialSubject",
+      "/credentialSubject/age",
+      "/credentialSubject/degrees/2",
+    ] {
+      assert!(matches!(
+        processor.derive_sd_proof(&secured, &[pointer.to_owned()]),
+        Err(Error::InvalidPointer(_))
+      ));
+    }
+  }
+}
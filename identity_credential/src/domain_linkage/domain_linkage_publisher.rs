@@ -0,0 +1,123 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+
+use crate::domain_linkage::DomainLinkageConfiguration;
+use crate::error::Result;
+
+/// A type capable of making a [`DomainLinkageConfiguration`] available at the well-known location of an origin,
+/// i.e. at "`origin`/.well-known/did-configuration.json".
+///
+/// Implementations can upload the resource to a web server, an object storage bucket or a CDN.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait DomainLinkagePublisher {
+  /// Publishes `configuration` for `origin`, replacing any previously published configuration.
+  async fn publish(&self, origin: &Url, configuration: &DomainLinkageConfiguration) -> Result<()>;
+}
+
+#[cfg(feature = "domain-linkage-fetch")]
+mod __http_put_publisher {
+  use async_trait::async_trait;
+  use identity_core::common::Url;
+  use identity_core::convert::ToJson;
+  use reqwest::header::HeaderMap;
+  use reqwest::header::HeaderName;
+  use reqwest::header::HeaderValue;
+  use reqwest::header::CONTENT_TYPE;
+  use reqwest::Client;
+
+  use super::DomainLinkagePublisher;
+  use crate::domain_linkage::DomainLinkageConfiguration;
+  use crate::error::Result;
+  use crate::Error::DomainLinkageError;
+
+  type UploadUrlFn = Box<dyn Fn(&Url) -> Result<Url> + Send + Sync>;
+
+  /// A [`DomainLinkagePublisher`] uploading DID Configuration resources with HTTP `PUT` requests.
+  ///
+  /// By default the resource is uploaded directly to "`origin`/.well-known/did-configuration.json". A custom
+  /// upload location, e.g. a pre-signed S3 URL or a deployment API, can be set with
+  /// [`HttpPutPublisher::with_upload_url`].
+  pub struct HttpPutPublisher {
+    client: Client,
+    headers: HeaderMap,
+    upload_url: UploadUrlFn,
+  }
+
+  impl HttpPutPublisher {
+    /// Creates a new `HttpPutPublisher` uploading to the well-known location of each origin.
+    pub fn new() -> Self {
+      Self {
+        client: Client::new(),
+        headers: HeaderMap::new(),
+        upload_url: Box::new(|origin| {
+          let mut url: Url = origin.clone();
+          url.set_path(".well-known/did-configuration.json");
+          Ok(url)
+        }),
+      }
+    }
+
+    /// Sets the function mapping an origin to the URL the configuration is uploaded to.
+    #[must_use]
+    pub fn with_upload_url<F>(mut self, upload_url: F) -> Self
+    where
+      F: Fn(&Url) -> Result<Url> + Send + Sync + 'static,
+    {
+      self.upload_url = Box::new(upload_url);
+      self
+    }
+
+    /// Adds a header sent with every upload request, e.g. `Authorization`.
+    pub fn with_header(mut self, name: &str, value: &str) -> Result<Self> {
+      let name: HeaderName = HeaderName::try_from(name).map_err(|err| DomainLinkageError(Box::new(err)))?;
+      let value: HeaderValue = HeaderValue::try_from(value).map_err(|err| DomainLinkageError(Box::new(err)))?;
+      self.headers.insert(name, value);
+      Ok(self)
+    }
+  }
+
+  impl Default for HttpPutPublisher {
+    fn default() -> Self {
+      Self::new()
+    }
+  }
+
+  impl std::fmt::Debug for HttpPutPublisher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      f.debug_struct("HttpPutPublisher")
+        .field("headers", &self.headers.keys().collect::<Vec<_>>())
+        .finish_non_exhaustive()
+    }
+  }
+
+  #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+  #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+  impl DomainLinkagePublisher for HttpPutPublisher {
+    async fn publish(&self, origin: &Url, configuration: &DomainLinkageConfiguration) -> Result<()> {
+      let url: Url = (self.upload_url)(origin)?;
+      let body: Vec<u8> = configuration
+        .to_json_vec()
+        .map_err(|err| DomainLinkageError(Box::new(err)))?;
+
+      self
+        .client
+        .put(url.to_string())
+        .headers(self.headers.clone())
+        .header(CONTENT_TYPE, "application/json")
+        .body(body)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|err| DomainLinkageError(Box::new(err)))?;
+
+      Ok(())
+    }
+  }
+}
+
+#[cfg(feature = "domain-linkage-fetch")]
+pub use __http_put_publisher::HttpPutPublisher;
@@ -210,7 +210,6 @@ impl<V: JwsVerifier> JwtDomainLinkageValidator<V> {
 #[cfg(test)]
 mod tests {
   use crate::credential::Credential;
-  use crate::credential::Jws;
   use crate::credential::Jwt;
   use crate::domain_linkage::DomainLinkageConfiguration;
   use crate::domain_linkage::DomainLinkageCredentialBuilder;
@@ -218,6 +217,7 @@ mod tests {
   use crate::domain_linkage::DomainLinkageValidationResult;
   use crate::domain_linkage::JwtDomainLinkageValidator;
   use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_credential_jwt;
   use crate::validator::JwtCredentialValidationOptions;
 
   use crypto::signatures::ed25519::SecretKey;
@@ -228,15 +228,7 @@ mod tests {
   use identity_core::common::Timestamp;
   use identity_core::common::Url;
   use identity_did::CoreDID;
-  use identity_document::document::CoreDocument;
   use identity_eddsa_verifier::EdDSAJwsVerifier;
-  use identity_verification::jws::CharSet;
-  use identity_verification::jws::CompactJwsEncoder;
-  use identity_verification::jws::CompactJwsEncodingOptions;
-  use identity_verification::jws::JwsAlgorithm;
-  use identity_verification::jws::JwsHeader;
-  use identity_verification::MethodData;
-  use identity_verification::VerificationMethod;
   use once_cell::sync::Lazy;
 
   static JWT_DOMAIN_LINKAGE_VALIDATOR_ED25519: Lazy<JwtDomainLinkageValidator<EdDSAJwsVerifier>> =
@@ -536,40 +528,4 @@ mod tests {
       .unwrap();
     credential
   }
-
-  fn sign_credential_jwt(
-    credential: &Credential,
-    document: &CoreDocument,
-    fragment: &str,
-    secret_key: &SecretKey,
-  ) -> Jwt {
-    let payload: String = credential.serialize_jwt(None).unwrap();
-    Jwt::new(sign_bytes(document, fragment, payload.as_ref(), secret_key).into())
-  }
-
-  fn sign_bytes(document: &CoreDocument, fragment: &str, payload: &[u8], secret_key: &SecretKey) -> Jws {
-    let method: &VerificationMethod = document.resolve_method(fragment, None).unwrap();
-    let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
-      panic!("not a jwk");
-    };
-    let alg: JwsAlgorithm = jwk.alg().unwrap_or("").parse().unwrap();
-
-    let header: JwsHeader = {
-      let mut header = JwsHeader::new();
-      header.set_alg(alg);
-      header.set_kid(method.id().to_string());
-      header
-    };
-
-    let encoding_options: CompactJwsEncodingOptions = CompactJwsEncodingOptions::NonDetached {
-      charset_requirements: CharSet::Default,
-    };
-
-    let jws_encoder: CompactJwsEncoder<'_> =
-      CompactJwsEncoder::new_with_options(payload, &header, encoding_options).unwrap();
-
-    let signature: [u8; 64] = secret_key.sign(jws_encoder.signing_input()).to_bytes();
-
-    Jws::new(jws_encoder.into_jws(&signature))
-  }
 }
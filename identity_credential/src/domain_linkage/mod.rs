@@ -5,10 +5,12 @@
 
 mod domain_linkage_configuration;
 mod domain_linkage_credential_builder;
+mod domain_linkage_publisher;
 mod domain_linkage_validator;
 mod error;
 
 pub use self::domain_linkage_configuration::*;
 pub use self::domain_linkage_credential_builder::*;
+pub use self::domain_linkage_publisher::*;
 pub use self::domain_linkage_validator::*;
 pub use error::*;
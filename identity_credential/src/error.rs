@@ -40,6 +40,12 @@ pub enum Error {
   /// Caused when constructing an invalid `LinkedVerifiablePresentationService`.
   #[error("linked verifiable presentation error: {0}")]
   LinkedVerifiablePresentationError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// Caused by a failure to fetch a status list credential.
+  #[error("could not fetch status list credential: {0}")]
+  StatusListFetchError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// Caused by a failure to load or query a trust registry.
+  #[error("trust registry error: {0}")]
+  TrustRegistryError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
   /// Caused when attempting to encode a `Credential` containing multiple subjects as a JWT.  
   #[error("could not create JWT claim set from verifiable credential: more than one subject")]
   MoreThanOneSubjectInJwt,
@@ -53,6 +59,10 @@ pub enum Error {
   #[error("empty verifiableCredential array in presentation")]
   EmptyVerifiableCredentialArray,
 
+  /// Caused when constructing a `Presentation` whose `expirationDate` precedes its `issuanceDate`.
+  #[error("invalid presentation validity period: expirationDate precedes issuanceDate")]
+  InvalidPresentationValidityPeriod,
+
   /// Caused when attempting to convert a JWT to a `Presentation` that has conflicting values
   /// between the registered claims and those in the `vp` object.
   #[error("could not convert JWT to the VP data model: {0}")]
@@ -72,6 +82,10 @@ pub enum Error {
   #[error("could not deserialize JWT claims set")]
   JwtClaimsSetDeserializationError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
 
+  /// Caused by a failure to decode the compact serialization of a JWT, e.g. when inspecting it before verification.
+  #[error("could not decode JWT")]
+  JwtDecodingError(#[source] identity_verification::jose::error::Error),
+
   /// Caused by a failure to deserialize the JPT claims set representation of a `Credential` JSON.
   #[error("could not deserialize JWT claims set")]
   JptClaimsSetDeserializationError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
@@ -80,6 +94,42 @@ pub enum Error {
   #[error("Attribute Not found")]
   SelectiveDisclosureError,
 
+  /// Caused by a failure to compute or parse the content hash of a `Credential`.
+  #[error("could not compute credential content hash")]
+  ContentHashError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+
+  /// Caused by a malformed `CredentialTemplate`.
+  #[error("invalid credential template: {0}")]
+  InvalidCredentialTemplate(&'static str),
+  /// Caused when instantiating a `CredentialTemplate` without a value for one of its required variables.
+  #[error("missing value for credential template variable `{0}`")]
+  MissingTemplateVariable(String),
+  /// Caused when instantiating a `CredentialTemplate` does not result in a valid credential.
+  #[error("could not instantiate credential template")]
+  CredentialTemplateInstantiation(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// Caused when registering a `CredentialTemplate` using a type that is already registered with a different
+  /// `@context`.
+  #[error("credential type `{0}` is already registered with a different context")]
+  CredentialTemplateConflict(String),
+  /// Caused when no `CredentialTemplate` is registered for a credential type.
+  #[error("no credential template registered for type `{0}`")]
+  UnknownCredentialTemplate(String),
+  /// Caused when the claims of a credential subject do not match a `CredentialSubjectSchema`.
+  #[error("invalid credential subject claims: {0}")]
+  InvalidSubjectClaims(String),
+
+  /// Caused by a failure of a `CredentialFormat` to encode, sign or decode a credential.
+  #[error("credential format `{format}` failed: {message}")]
+  CredentialFormatError {
+    /// The identifier of the format.
+    format: String,
+    /// A message providing more context.
+    message: &'static str,
+    /// The source of the error, if any.
+    #[source]
+    source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  },
+
   /// Failure of an SD-JWT VC operation.
   #[cfg(feature = "sd-jwt-vc")]
   #[error(transparent)]
@@ -0,0 +1,139 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use serde::Serialize;
+use serde::Serializer;
+use serde_json::Value;
+
+use crate::credential::Credential;
+use crate::credential::Status;
+use crate::validator::JwtValidationError;
+use crate::Result;
+
+/// A credential encoded in a specific [`CredentialFormat`].
+///
+/// Serializes as its encoded value, such that it can be included in a
+/// [`Presentation`](crate::presentation::Presentation) as is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncodedCredential {
+  format: String,
+  value: Value,
+}
+
+impl EncodedCredential {
+  /// Creates a new [`EncodedCredential`] of the format identified by `format`.
+  pub fn new(format: impl Into<String>, value: Value) -> Self {
+    Self {
+      format: format.into(),
+      value,
+    }
+  }
+
+  /// Returns the identifier of the format of the credential, see [`CredentialFormat::id`].
+  pub fn format(&self) -> &str {
+    &self.format
+  }
+
+  /// Returns the encoded credential.
+  pub fn value(&self) -> &Value {
+    &self.value
+  }
+
+  /// Returns the encoded credential if it is a string, e.g. a compact JWS.
+  pub fn as_str(&self) -> Option<&str> {
+    self.value.as_str()
+  }
+
+  /// Consumes the credential, returning its encoded value.
+  pub fn into_value(self) -> Value {
+    self.value
+  }
+}
+
+impl Serialize for EncodedCredential {
+  fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    self.value.serialize(serializer)
+  }
+}
+
+/// Parameters describing the key a credential is signed with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FormatSigningParams {
+  /// The signature algorithm, e.g. `EdDSA`.
+  pub algorithm: String,
+  /// The identifier of the signing key, usually the DID URL of a verification method of the issuer.
+  pub key_id: String,
+}
+
+impl FormatSigningParams {
+  /// Creates new [`FormatSigningParams`].
+  pub fn new(algorithm: impl Into<String>, key_id: impl Into<String>) -> Self {
+    Self {
+      algorithm: algorithm.into(),
+      key_id: key_id.into(),
+    }
+  }
+}
+
+/// A format in which [`Credential`]s can be secured, exchanged and validated.
+///
+/// Implement this trait to support formats beyond JWT, e.g. ISO mDoc or Gordian Envelopes, and register them with a
+/// [`CredentialFormatRegistry`](crate::format::CredentialFormatRegistry).
+///
+/// Issuing a credential is split into [`encode`](CredentialFormat::encode()) and
+/// [`sign`](CredentialFormat::sign()), such that the signature can be created by any signer, e.g. a
+/// `JwkStorage`, independently of the format.
+///
+/// # Example
+///
+/// ```ignore
+/// let format = JwtCredentialFormat::new(EdDSAJwsVerifier::default());
+/// let params = FormatSigningParams::new("EdDSA", method.id().to_string());
+/// let signing_input: Vec<u8> = format.encode(&credential, &params, None)?;
+/// let signature: Vec<u8> = storage.key_storage().sign(&key_id, &signing_input, &public_key).await?;
+/// let issued: EncodedCredential = format.sign(&signing_input, &signature)?;
+/// ```
+pub trait CredentialFormat {
+  /// Returns the identifier of the format, e.g. `jwt_vc_json` or `mso_mdoc`.
+  fn id(&self) -> &str;
+
+  /// Returns whether `value` is a credential encoded in this format.
+  fn accepts(&self, value: &Value) -> bool;
+
+  /// Encodes `credential` with the given `custom_claims`, returning the input to create the issuer's signature over.
+  fn encode(
+    &self,
+    credential: &Credential,
+    params: &FormatSigningParams,
+    custom_claims: Option<Object>,
+  ) -> Result<Vec<u8>>;
+
+  /// Combines the `signing_input` returned by [`encode`](CredentialFormat::encode()) with the `signature` over it
+  /// into the issued credential.
+  fn sign(&self, signing_input: &[u8], signature: &[u8]) -> Result<EncodedCredential>;
+
+  /// Verifies the signature of `credential` with the verification material of its `issuer`.
+  fn verify(
+    &self,
+    credential: &EncodedCredential,
+    issuer: &CoreDocument,
+    options: &JwsVerificationOptions,
+  ) -> std::result::Result<(), JwtValidationError>;
+
+  /// Decodes the claims of `credential` into a [`Credential`] without verifying its signature.
+  fn extract_claims(&self, credential: &EncodedCredential) -> Result<Credential>;
+
+  /// Returns the `credentialStatus` of `credential` without verifying its signature.
+  fn extract_status(&self, credential: &EncodedCredential) -> Result<Option<Status>> {
+    self
+      .extract_claims(credential)
+      .map(|credential| credential.credential_status)
+  }
+}
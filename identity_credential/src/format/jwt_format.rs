@@ -0,0 +1,115 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsHeader;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jwu;
+use serde_json::Value;
+
+use crate::credential::Credential;
+use crate::credential::Jwt;
+use crate::format::CredentialFormat;
+use crate::format::EncodedCredential;
+use crate::format::FormatSigningParams;
+use crate::validator::JwtCredentialValidator;
+use crate::validator::JwtValidationError;
+use crate::Error;
+use crate::Result;
+
+const JWT_FORMAT_ID: &str = "jwt_vc_json";
+
+/// The [`CredentialFormat`] of credentials secured as JWTs, as created by
+/// [`Credential::serialize_jwt`] and validated by [`JwtCredentialValidator`].
+pub struct JwtCredentialFormat<V: JwsVerifier>(JwtCredentialValidator<V>);
+
+impl<V: JwsVerifier> JwtCredentialFormat<V> {
+  /// The identifier of the format.
+  pub const ID: &'static str = JWT_FORMAT_ID;
+
+  /// Creates a new [`JwtCredentialFormat`] verifying signatures with `signature_verifier`.
+  pub fn new(signature_verifier: V) -> Self {
+    Self(JwtCredentialValidator::with_signature_verifier(signature_verifier))
+  }
+
+  fn jwt(credential: &EncodedCredential) -> Result<Jwt> {
+    credential
+      .as_str()
+      .map(|jwt| Jwt::new(jwt.to_owned()))
+      .ok_or_else(|| format_error("expected a compact JWS", None))
+  }
+}
+
+impl<V: JwsVerifier> CredentialFormat for JwtCredentialFormat<V> {
+  fn id(&self) -> &str {
+    Self::ID
+  }
+
+  fn accepts(&self, value: &Value) -> bool {
+    let Some(jwt) = value.as_str() else {
+      return false;
+    };
+    let segments: Vec<&str> = jwt.split('.').collect();
+    segments.len() == 3 && jwu::decode_b64_json::<Object>(segments[0]).is_ok()
+  }
+
+  fn encode(
+    &self,
+    credential: &Credential,
+    params: &FormatSigningParams,
+    custom_claims: Option<Object>,
+  ) -> Result<Vec<u8>> {
+    let alg: JwsAlgorithm = params
+      .algorithm
+      .parse()
+      .map_err(|err| format_error("unsupported algorithm", Some(Box::new(err))))?;
+    let mut header: JwsHeader = JwsHeader::new();
+    header.set_alg(alg);
+    header.set_kid(params.key_id.clone());
+    // https://www.w3.org/TR/vc-data-model/#jwt-encoding
+    header.set_typ("JWT");
+
+    let header: String =
+      jwu::encode_b64_json(&header).map_err(|err| format_error("could not encode header", Some(Box::new(err))))?;
+    let claims: String = jwu::encode_b64(credential.serialize_jwt(custom_claims)?);
+
+    Ok(format!("{header}.{claims}").into_bytes())
+  }
+
+  fn sign(&self, signing_input: &[u8], signature: &[u8]) -> Result<EncodedCredential> {
+    let signing_input: &str =
+      std::str::from_utf8(signing_input).map_err(|err| format_error("invalid signing input", Some(Box::new(err))))?;
+    let jws: String = format!("{signing_input}.{}", jwu::encode_b64(signature));
+
+    Ok(EncodedCredential::new(Self::ID, Value::String(jws)))
+  }
+
+  fn verify(
+    &self,
+    credential: &EncodedCredential,
+    issuer: &CoreDocument,
+    options: &JwsVerificationOptions,
+  ) -> std::result::Result<(), JwtValidationError> {
+    let jwt: Jwt = Self::jwt(credential).map_err(JwtValidationError::CredentialStructure)?;
+    self
+      .0
+      .verify_signature::<_, Object>(&jwt, std::slice::from_ref(issuer), options)
+      .map(|_| ())
+  }
+
+  fn extract_claims(&self, credential: &EncodedCredential) -> Result<Credential> {
+    let jwt: Jwt = Self::jwt(credential)?;
+    JwtCredentialValidator::<V>::decode_unverified(&jwt).map_err(|err| format_error("invalid JWT", Some(Box::new(err))))
+  }
+}
+
+fn format_error(message: &'static str, source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>) -> Error {
+  Error::CredentialFormatError {
+    format: JWT_FORMAT_ID.to_owned(),
+    message,
+    source,
+  }
+}
@@ -0,0 +1,15 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Extension points for plugging credential formats other than JWT, e.g. mDoc or Gordian Envelopes, into
+//! validation and presentation assembly.
+
+mod credential_format;
+mod jwt_format;
+mod registry;
+
+pub use credential_format::CredentialFormat;
+pub use credential_format::EncodedCredential;
+pub use credential_format::FormatSigningParams;
+pub use jwt_format::JwtCredentialFormat;
+pub use registry::CredentialFormatRegistry;
@@ -0,0 +1,226 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use serde_json::Value;
+
+use crate::credential::Credential;
+use crate::format::CredentialFormat;
+use crate::format::EncodedCredential;
+use crate::presentation::Presentation;
+use crate::validator::CompoundCredentialValidationError;
+use crate::validator::FailFast;
+use crate::validator::JwtCredentialValidationOptions;
+use crate::validator::JwtCredentialValidatorUtils;
+use crate::validator::JwtValidationError;
+use crate::validator::SignerContext;
+
+/// The [`CredentialFormat`]s supported by an application, used to decode and validate credentials regardless of
+/// their format.
+///
+/// # Example
+///
+/// ```ignore
+/// let registry = CredentialFormatRegistry::new()
+///   .with_format(JwtCredentialFormat::new(EdDSAJwsVerifier::default()))
+///   .with_format(MdocCredentialFormat::new());
+///
+/// let presentation: Presentation<Value> = /* decoded presentation */;
+/// for credential in registry.decode_presentation(&presentation)? {
+///   registry.validate(&credential, &trusted_issuers, &options, FailFast::FirstError)?;
+/// }
+/// ```
+#[derive(Default)]
+pub struct CredentialFormatRegistry {
+  formats: Vec<Box<dyn CredentialFormat>>,
+}
+
+impl CredentialFormatRegistry {
+  /// Creates an empty [`CredentialFormatRegistry`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds `format` to the registry, replacing any format with the same [`CredentialFormat::id`].
+  pub fn with_format(mut self, format: impl CredentialFormat + 'static) -> Self {
+    self.register(Box::new(format));
+    self
+  }
+
+  /// Adds `format` to the registry, replacing and returning any format with the same [`CredentialFormat::id`].
+  pub fn register(&mut self, format: Box<dyn CredentialFormat>) -> Option<Box<dyn CredentialFormat>> {
+    match self.formats.iter_mut().find(|other| other.id() == format.id()) {
+      Some(other) => Some(std::mem::replace(other, format)),
+      None => {
+        self.formats.push(format);
+        None
+      }
+    }
+  }
+
+  /// Returns the format identified by `id`.
+  pub fn get(&self, id: &str) -> Option<&dyn CredentialFormat> {
+    self.formats.iter().find(|format| format.id() == id).map(AsRef::as_ref)
+  }
+
+  /// Returns the identifiers of the registered formats.
+  pub fn ids(&self) -> impl Iterator<Item = &str> + '_ {
+    self.formats.iter().map(|format| format.id())
+  }
+
+  /// Returns the first registered format accepting `value`.
+  pub fn detect(&self, value: &Value) -> Option<&dyn CredentialFormat> {
+    self
+      .formats
+      .iter()
+      .find(|format| format.accepts(value))
+      .map(AsRef::as_ref)
+  }
+
+  /// Wraps `value` in an [`EncodedCredential`] of the first registered format accepting it.
+  pub fn decode(&self, value: Value) -> Result<EncodedCredential, JwtValidationError> {
+    let format: &dyn CredentialFormat = self
+      .detect(&value)
+      .ok_or(JwtValidationError::UnsupportedCredentialFormat)?;
+    Ok(EncodedCredential::new(format.id(), value))
+  }
+
+  /// Decodes the credentials of `presentation`, failing on the first credential of an unsupported format.
+  pub fn decode_presentation<T>(
+    &self,
+    presentation: &Presentation<Value, T>,
+  ) -> Result<Vec<EncodedCredential>, JwtValidationError> {
+    presentation
+      .verifiable_credential
+      .iter()
+      .map(|value| self.decode(value.clone()))
+      .collect()
+  }
+
+  /// Validates `credential` like
+  /// [`JwtCredentialValidator::validate`](crate::validator::JwtCredentialValidator::validate()), using its
+  /// registered format to verify its signature and extract its claims.
+  ///
+  /// The issuer of the credential must be one of the `trusted_issuers`.
+  pub fn validate<DOC>(
+    &self,
+    credential: &EncodedCredential,
+    trusted_issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<Credential, CompoundCredentialValidationError>
+  where
+    DOC: AsRef<CoreDocument>,
+  {
+    let single = |error: JwtValidationError| CompoundCredentialValidationError {
+      validation_errors: vec![error],
+    };
+
+    let format: &dyn CredentialFormat = self
+      .get(credential.format())
+      .ok_or_else(|| single(JwtValidationError::UnsupportedCredentialFormat))?;
+    let claims: Credential = format
+      .extract_claims(credential)
+      .map_err(|err| single(JwtValidationError::CredentialStructure(err)))?;
+
+    let issuer_did: CoreDID = JwtCredentialValidatorUtils::extract_issuer(&claims).map_err(single)?;
+    let issuer: &CoreDocument = trusted_issuers
+      .iter()
+      .map(AsRef::as_ref)
+      .find(|issuer| issuer.id() == &issuer_did)
+      .ok_or_else(|| single(JwtValidationError::DocumentMismatch(SignerContext::Issuer)))?;
+    format
+      .verify(credential, issuer, &options.verification_options)
+      .map_err(single)?;
+
+    let checks = [
+      JwtCredentialValidatorUtils::check_issued_on_or_before(&claims, options.latest_issuance_date.unwrap_or_default()),
+      JwtCredentialValidatorUtils::check_expires_on_or_after(&claims, options.earliest_expiry_date.unwrap_or_default()),
+      JwtCredentialValidatorUtils::check_structure(&claims),
+      JwtCredentialValidatorUtils::check_trusted_issuer(&claims, options),
+      options
+        .subject_holder_relationship
+        .as_ref()
+        .map(|(holder, relationship)| {
+          JwtCredentialValidatorUtils::check_subject_holder_relationship(&claims, holder, *relationship)
+        })
+        .unwrap_or(Ok(())),
+      JwtCredentialValidatorUtils::check_status_with_options(&claims, trusted_issuers, options),
+    ];
+    let errors = checks.into_iter().filter_map(Result::err);
+    let validation_errors: Vec<JwtValidationError> = match fail_fast {
+      FailFast::FirstError => errors.take(1).collect(),
+      FailFast::AllErrors => errors.collect(),
+    };
+
+    if validation_errors.is_empty() {
+      Ok(claims)
+    } else {
+      Err(CompoundCredentialValidationError { validation_errors })
+    }
+  }
+}
+
+impl std::fmt::Debug for CredentialFormatRegistry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_list().entries(self.ids()).finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+  use identity_verification::jws::JwsAlgorithm;
+
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::format::FormatSigningParams;
+  use crate::format::JwtCredentialFormat;
+  use crate::validator::test_utils;
+
+  #[test]
+  fn test_jwt_format_roundtrip() {
+    let (issuer_doc, secret_key, fragment) = test_utils::generate_jwk_document_with_keys();
+    let credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(issuer_doc.id().as_str()).unwrap())
+      .issuance_date(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+      .subject(Subject::from_json_value(serde_json::json!({ "id": "did:example:holder", "name": "Alice" })).unwrap())
+      .build()
+      .unwrap();
+
+    let registry: CredentialFormatRegistry =
+      CredentialFormatRegistry::new().with_format(JwtCredentialFormat::new(EdDSAJwsVerifier::default()));
+    let format: &dyn CredentialFormat = registry.get(JwtCredentialFormat::<EdDSAJwsVerifier>::ID).unwrap();
+
+    let method_id: String = issuer_doc.resolve_method(&fragment, None).unwrap().id().to_string();
+    let params: FormatSigningParams = FormatSigningParams::new(JwsAlgorithm::EdDSA.name(), method_id);
+    let signing_input: Vec<u8> = format.encode(&credential, &params, None).unwrap();
+    let signature: [u8; 64] = secret_key.sign(&signing_input).to_bytes();
+    let issued: EncodedCredential = format.sign(&signing_input, &signature).unwrap();
+
+    let decoded: EncodedCredential = registry.decode(issued.value().clone()).unwrap();
+    assert_eq!(decoded, issued);
+    assert_eq!(format.extract_claims(&decoded).unwrap(), credential);
+
+    let options: JwtCredentialValidationOptions = JwtCredentialValidationOptions::default();
+    let validated: Credential = registry
+      .validate(&decoded, &[&issuer_doc], &options, FailFast::FirstError)
+      .unwrap();
+    assert_eq!(validated, credential);
+
+    let tampered: EncodedCredential = format.sign(&signing_input, &[0; 64]).unwrap();
+    assert!(registry
+      .validate(&tampered, &[&issuer_doc], &options, FailFast::FirstError)
+      .is_err());
+
+    assert!(matches!(
+      registry.decode(Value::Object(Default::default())),
+      Err(JwtValidationError::UnsupportedCredentialFormat)
+    ));
+  }
+}
@@ -0,0 +1,51 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use ciborium::Value;
+use identity_verification::jws::JwsAlgorithm;
+
+use super::Error;
+use super::Result;
+
+/// COSE algorithm identifier of `EdDSA`.
+const COSE_ALG_EDDSA: i64 = -8;
+/// COSE algorithm identifier of `ES256`.
+const COSE_ALG_ES256: i64 = -7;
+
+pub(crate) fn encode(value: &Value) -> Result<Vec<u8>> {
+  let mut bytes: Vec<u8> = Vec::new();
+  ciborium::into_writer(value, &mut bytes).map_err(|err| Error::Encoding(err.to_string()))?;
+  Ok(bytes)
+}
+
+pub(crate) fn decode(bytes: &[u8]) -> Result<Value> {
+  ciborium::from_reader(bytes).map_err(|err| Error::Decoding(err.to_string()))
+}
+
+/// Returns the value of the integer `label` in the CBOR map `entries`.
+pub(crate) fn get(entries: &[(Value, Value)], label: i64) -> Option<&Value> {
+  entries
+    .iter()
+    .find(|(key, _)| as_i64(key) == Some(label))
+    .map(|(_, value)| value)
+}
+
+pub(crate) fn as_i64(value: &Value) -> Option<i64> {
+  value.as_integer().and_then(|integer| i64::try_from(integer).ok())
+}
+
+pub(crate) fn to_cose_algorithm(alg: &JwsAlgorithm) -> Result<i64> {
+  match alg {
+    JwsAlgorithm::EdDSA => Ok(COSE_ALG_EDDSA),
+    JwsAlgorithm::ES256 => Ok(COSE_ALG_ES256),
+    _ => Err(Error::UnsupportedAlgorithm),
+  }
+}
+
+pub(crate) fn from_cose_algorithm(alg: i64) -> Result<JwsAlgorithm> {
+  match alg {
+    COSE_ALG_EDDSA => Ok(JwsAlgorithm::EdDSA),
+    COSE_ALG_ES256 => Ok(JwsAlgorithm::ES256),
+    _ => Err(Error::UnsupportedAlgorithm),
+  }
+}
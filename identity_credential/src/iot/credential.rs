@@ -0,0 +1,29 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::Error;
+use super::Result;
+use crate::credential::Credential;
+
+impl<T> Credential<T> {
+  /// Encodes the credential as CBOR, e.g. as the payload of a [`CoseSign1`](super::CoseSign1).
+  pub fn to_cbor(&self) -> Result<Vec<u8>>
+  where
+    T: Serialize,
+  {
+    let mut bytes: Vec<u8> = Vec::new();
+    ciborium::into_writer(self, &mut bytes).map_err(|err| Error::Encoding(err.to_string()))?;
+    Ok(bytes)
+  }
+
+  /// Decodes a credential encoded with [`Credential::to_cbor`].
+  pub fn from_cbor(bytes: &[u8]) -> Result<Self>
+  where
+    T: DeserializeOwned,
+  {
+    ciborium::from_reader(bytes).map_err(|err| Error::Decoding(err.to_string()))
+  }
+}
@@ -0,0 +1,48 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jws::SignatureVerificationError;
+
+use crate::validator::CompoundCredentialValidationError;
+
+/// Alias for a `Result` with the error type [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Failures of encoding, decoding or verifying CBOR credentials and issuance requests.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum Error {
+  /// A value could not be encoded as CBOR.
+  #[error("CBOR encoding failed: {0}")]
+  Encoding(String),
+  /// The input is not valid CBOR or does not have the expected shape.
+  #[error("CBOR decoding failed: {0}")]
+  Decoding(String),
+  /// A COSE structure is malformed.
+  #[error("invalid COSE structure: {0}")]
+  InvalidStructure(&'static str),
+  /// The signature algorithm is not supported by this profile.
+  #[error("unsupported algorithm")]
+  UnsupportedAlgorithm,
+  /// The key type or curve is not supported by this profile.
+  #[error("unsupported key: {0}")]
+  UnsupportedKey(&'static str),
+  /// The signing key could not be found in the issuer's or device's DID document.
+  #[error("verification method \"{0}\" not found")]
+  MethodNotFound(String),
+  /// The credential was not signed by one of the trusted issuers.
+  #[error("issuer is not trusted")]
+  UntrustedIssuer,
+  /// The issuer of the credential does not match the DID document the signature was verified with.
+  #[error("issuer of the credential does not match the signing DID document")]
+  IssuerMismatch,
+  /// The nonce of an issuance request does not match the expected one.
+  #[error("nonce mismatch")]
+  NonceMismatch,
+  /// The signature does not match the payload.
+  #[error("signature verification failed")]
+  Signature(#[source] SignatureVerificationError),
+  /// The signature is valid, but the credential failed validation.
+  #[error("credential validation failed")]
+  Validation(#[source] CompoundCredentialValidationError),
+}
@@ -0,0 +1,252 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use ciborium::Value;
+use identity_verification::jwk::EcCurve;
+use identity_verification::jwk::EdCurve;
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkParams;
+use identity_verification::jwk::JwkParamsEc;
+use identity_verification::jwk::JwkParamsOkp;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jwu;
+
+use super::cbor;
+use super::Error;
+use super::Result;
+
+const LABEL_KTY: i64 = 1;
+const LABEL_KID: i64 = 2;
+const LABEL_ALG: i64 = 3;
+const LABEL_CRV: i64 = -1;
+const LABEL_X: i64 = -2;
+const LABEL_Y: i64 = -3;
+const LABEL_D: i64 = -4;
+
+const KTY_OKP: i64 = 1;
+const KTY_EC2: i64 = 2;
+const CRV_P256: i64 = 1;
+const CRV_ED25519: i64 = 6;
+
+const COORDINATE_LEN: usize = 32;
+
+/// A public key in the [COSE_Key](https://www.rfc-editor.org/rfc/rfc9052#section-7) format.
+///
+/// Only Ed25519 (`OKP`) and P-256 (`EC2`) keys are supported. Private key parameters are never encoded and are
+/// rejected when decoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoseKey {
+  kid: Option<Vec<u8>>,
+  params: CoseKeyParams,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum CoseKeyParams {
+  Ed25519 { x: Vec<u8> },
+  P256 { x: Vec<u8>, y: Vec<u8> },
+}
+
+impl CoseKey {
+  /// Converts the public part of `jwk` into a [`CoseKey`], keeping its `kid`.
+  pub fn from_jwk(jwk: &Jwk) -> Result<Self> {
+    let params: CoseKeyParams = match jwk.params() {
+      JwkParams::Okp(JwkParamsOkp { crv, x, .. }) if crv == EdCurve::Ed25519.name() => CoseKeyParams::Ed25519 {
+        x: decode_coordinate(x)?,
+      },
+      JwkParams::Ec(JwkParamsEc { crv, x, y, .. }) if crv == EcCurve::P256.name() => CoseKeyParams::P256 {
+        x: decode_coordinate(x)?,
+        y: decode_coordinate(y)?,
+      },
+      _ => return Err(Error::UnsupportedKey("expected an Ed25519 or P-256 key")),
+    };
+    Ok(Self {
+      kid: jwk.kid().map(|kid| kid.as_bytes().to_vec()),
+      params,
+    })
+  }
+
+  /// Converts the key into a public [`Jwk`].
+  ///
+  /// The `kid` is carried over if it is valid UTF-8.
+  pub fn to_jwk(&self) -> Jwk {
+    let mut jwk: Jwk = match &self.params {
+      CoseKeyParams::Ed25519 { x } => {
+        let mut params: JwkParamsOkp = JwkParamsOkp::new();
+        params.crv = EdCurve::Ed25519.name().to_owned();
+        params.x = jwu::encode_b64(x);
+        Jwk::from_params(params)
+      }
+      CoseKeyParams::P256 { x, y } => {
+        let mut params: JwkParamsEc = JwkParamsEc::new();
+        params.crv = EcCurve::P256.name().to_owned();
+        params.x = jwu::encode_b64(x);
+        params.y = jwu::encode_b64(y);
+        Jwk::from_params(params)
+      }
+    };
+    jwk.set_alg(self.algorithm().to_string());
+    if let Some(kid) = self.kid.as_deref().and_then(|kid| std::str::from_utf8(kid).ok()) {
+      jwk.set_kid(kid);
+    }
+    jwk
+  }
+
+  /// Returns the key identifier, if any.
+  pub fn kid(&self) -> Option<&[u8]> {
+    self.kid.as_deref()
+  }
+
+  /// Sets the key identifier.
+  pub fn set_kid(&mut self, kid: impl Into<Vec<u8>>) {
+    self.kid = Some(kid.into());
+  }
+
+  /// Returns the signature algorithm used with the key: `EdDSA` for Ed25519 and `ES256` for P-256 keys.
+  pub fn algorithm(&self) -> JwsAlgorithm {
+    match self.params {
+      CoseKeyParams::Ed25519 { .. } => JwsAlgorithm::EdDSA,
+      CoseKeyParams::P256 { .. } => JwsAlgorithm::ES256,
+    }
+  }
+
+  /// Returns whether `self` and `other` hold the same key material, ignoring their key identifiers.
+  pub fn same_key(&self, other: &CoseKey) -> bool {
+    self.params == other.params
+  }
+
+  /// Encodes the key as CBOR.
+  pub fn to_bytes(&self) -> Result<Vec<u8>> {
+    cbor::encode(&self.to_cbor()?)
+  }
+
+  /// Decodes a key from CBOR.
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+    Self::from_cbor(&cbor::decode(bytes)?)
+  }
+
+  pub(crate) fn to_cbor(&self) -> Result<Value> {
+    let alg: i64 = cbor::to_cose_algorithm(&self.algorithm())?;
+    let mut entries: Vec<(Value, Value)> = match &self.params {
+      CoseKeyParams::Ed25519 { x } => vec![
+        (LABEL_KTY.into(), KTY_OKP.into()),
+        (LABEL_CRV.into(), CRV_ED25519.into()),
+        (LABEL_X.into(), Value::Bytes(x.clone())),
+      ],
+      CoseKeyParams::P256 { x, y } => vec![
+        (LABEL_KTY.into(), KTY_EC2.into()),
+        (LABEL_CRV.into(), CRV_P256.into()),
+        (LABEL_X.into(), Value::Bytes(x.clone())),
+        (LABEL_Y.into(), Value::Bytes(y.clone())),
+      ],
+    };
+    entries.push((LABEL_ALG.into(), alg.into()));
+    if let Some(kid) = &self.kid {
+      entries.push((LABEL_KID.into(), Value::Bytes(kid.clone())));
+    }
+    Ok(Value::Map(entries))
+  }
+
+  pub(crate) fn from_cbor(value: &Value) -> Result<Self> {
+    let entries: &[(Value, Value)] = value.as_map().ok_or(Error::InvalidStructure("COSE_Key is not a map"))?;
+    if cbor::get(entries, LABEL_D).is_some() {
+      return Err(Error::UnsupportedKey("private keys must not be encoded"));
+    }
+
+    let int = |label: i64| cbor::get(entries, label).and_then(cbor::as_i64);
+    let coordinate = |label: i64| -> Result<Vec<u8>> {
+      match cbor::get(entries, label).and_then(Value::as_bytes) {
+        Some(bytes) if bytes.len() == COORDINATE_LEN => Ok(bytes.clone()),
+        _ => Err(Error::UnsupportedKey("invalid key coordinate")),
+      }
+    };
+
+    let params: CoseKeyParams = match (int(LABEL_KTY), int(LABEL_CRV)) {
+      (Some(KTY_OKP), Some(CRV_ED25519)) => CoseKeyParams::Ed25519 {
+        x: coordinate(LABEL_X)?,
+      },
+      (Some(KTY_EC2), Some(CRV_P256)) => CoseKeyParams::P256 {
+        x: coordinate(LABEL_X)?,
+        y: coordinate(LABEL_Y)?,
+      },
+      _ => return Err(Error::UnsupportedKey("expected an Ed25519 or P-256 key")),
+    };
+    let kid: Option<Vec<u8>> = match cbor::get(entries, LABEL_KID) {
+      Some(kid) => Some(
+        kid
+          .as_bytes()
+          .cloned()
+          .ok_or(Error::InvalidStructure("kid is not a byte string"))?,
+      ),
+      None => None,
+    };
+    let key: CoseKey = CoseKey { kid, params };
+
+    if let Some(alg) = cbor::get(entries, LABEL_ALG) {
+      let alg: JwsAlgorithm = cbor::as_i64(alg)
+        .ok_or(Error::UnsupportedAlgorithm)
+        .and_then(cbor::from_cose_algorithm)?;
+      if alg != key.algorithm() {
+        return Err(Error::UnsupportedKey("algorithm does not match the key type"));
+      }
+    }
+    Ok(key)
+  }
+}
+
+fn decode_coordinate(value: &str) -> Result<Vec<u8>> {
+  match jwu::decode_b64(value) {
+    Ok(bytes) if bytes.len() == COORDINATE_LEN => Ok(bytes),
+    _ => Err(Error::UnsupportedKey("invalid key coordinate")),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::validator::test_utils::encode_public_ed25519_jwk;
+
+  #[test]
+  fn jwk_round_trip() {
+    let public = crypto::signatures::ed25519::SecretKey::generate().unwrap().public_key();
+    let mut jwk: Jwk = encode_public_ed25519_jwk(&public);
+    jwk.set_kid("device-key");
+
+    let key: CoseKey = CoseKey::from_jwk(&jwk).unwrap();
+    assert_eq!(key.algorithm(), JwsAlgorithm::EdDSA);
+    assert_eq!(key.kid(), Some(b"device-key".as_slice()));
+
+    let decoded: CoseKey = CoseKey::from_bytes(&key.to_bytes().unwrap()).unwrap();
+    assert_eq!(decoded, key);
+    let converted: Jwk = decoded.to_jwk();
+    assert_eq!(converted.params(), jwk.params());
+    assert_eq!(converted.kid(), Some("device-key"));
+  }
+
+  #[test]
+  fn rejects_private_and_unsupported_keys() {
+    let key = Value::Map(vec![
+      (LABEL_KTY.into(), KTY_OKP.into()),
+      (LABEL_CRV.into(), CRV_ED25519.into()),
+      (LABEL_X.into(), Value::Bytes(vec![0; 32])),
+      (LABEL_D.into(), Value::Bytes(vec![0; 32])),
+    ]);
+    assert!(matches!(CoseKey::from_cbor(&key), Err(Error::UnsupportedKey(_))));
+
+    // X25519
+    let key = Value::Map(vec![
+      (LABEL_KTY.into(), KTY_OKP.into()),
+      (LABEL_CRV.into(), 4.into()),
+      (LABEL_X.into(), Value::Bytes(vec![0; 32])),
+    ]);
+    assert!(matches!(CoseKey::from_cbor(&key), Err(Error::UnsupportedKey(_))));
+
+    // Algorithm does not match the key type.
+    let key = Value::Map(vec![
+      (LABEL_KTY.into(), KTY_OKP.into()),
+      (LABEL_CRV.into(), CRV_ED25519.into()),
+      (LABEL_X.into(), Value::Bytes(vec![0; 32])),
+      (LABEL_ALG.into(), (-7).into()),
+    ]);
+    assert!(matches!(CoseKey::from_cbor(&key), Err(Error::UnsupportedKey(_))));
+  }
+}
@@ -0,0 +1,30 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A compact issuance and verification profile for IoT devices.
+//!
+//! Credentials and issuance requests are encoded as CBOR and secured with
+//! [COSE_Sign1](https://www.rfc-editor.org/rfc/rfc9052#section-4.2), keys are exchanged as
+//! [COSE_Key](https://www.rfc-editor.org/rfc/rfc9052#section-7)s. Ed25519 (`EdDSA`) and P-256 (`ES256`) keys are
+//! supported.
+//!
+//! A device proves possession of its key with a signed [`DeviceCredentialRequest`], the issuer answers with a
+//! credential about the device's DID secured as COSE_Sign1, and relying parties verify it with the
+//! [`LiteCoseCredentialVerifier`], which, like the
+//! [`LiteJwtCredentialVerifier`](crate::validator::LiteJwtCredentialVerifier), works against pre-supplied issuer
+//! documents without resolving DIDs.
+
+mod cbor;
+mod credential;
+mod error;
+mod key;
+mod request;
+mod sign1;
+mod verifier;
+
+pub use error::Error;
+pub use error::Result;
+pub use key::CoseKey;
+pub use request::DeviceCredentialRequest;
+pub use sign1::CoseSign1;
+pub use verifier::LiteCoseCredentialVerifier;
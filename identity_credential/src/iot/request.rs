@@ -0,0 +1,179 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use ciborium::Value;
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::VerificationMethod;
+
+use super::cbor;
+use super::CoseKey;
+use super::CoseSign1;
+use super::Error;
+use super::Result;
+
+const LABEL_DEVICE: i64 = 1;
+const LABEL_KEY: i64 = 2;
+const LABEL_NONCE: i64 = 3;
+
+/// A device's request for a credential attesting its identity.
+///
+/// The request is encoded as a CBOR map of the device's DID, its public key and a nonce chosen by the issuer, and is
+/// signed with the device key as [`CoseSign1`] to prove possession of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceCredentialRequest {
+  device: CoreDID,
+  key: CoseKey,
+  nonce: Vec<u8>,
+}
+
+impl DeviceCredentialRequest {
+  /// Creates a new [`DeviceCredentialRequest`] of the `device` holding `key`, answering the issuer's `nonce`.
+  pub fn new(device: CoreDID, key: CoseKey, nonce: impl Into<Vec<u8>>) -> Self {
+    Self {
+      device,
+      key,
+      nonce: nonce.into(),
+    }
+  }
+
+  /// Returns the DID of the device.
+  pub fn device(&self) -> &CoreDID {
+    &self.device
+  }
+
+  /// Returns the public key of the device.
+  pub fn key(&self) -> &CoseKey {
+    &self.key
+  }
+
+  /// Returns the nonce of the issuer.
+  pub fn nonce(&self) -> &[u8] {
+    &self.nonce
+  }
+
+  /// Returns the unsigned [`CoseSign1`] of the request, to be signed by the device with its key.
+  pub fn to_cose_sign1(&self) -> Result<CoseSign1> {
+    let payload: Vec<u8> = cbor::encode(&Value::Map(vec![
+      (LABEL_DEVICE.into(), Value::Text(self.device.to_string())),
+      (LABEL_KEY.into(), self.key.to_cbor()?),
+      (LABEL_NONCE.into(), Value::Bytes(self.nonce.clone())),
+    ]))?;
+    let kid: Option<String> = self
+      .key
+      .kid()
+      .and_then(|kid| std::str::from_utf8(kid).ok())
+      .map(ToOwned::to_owned);
+    CoseSign1::new(self.key.algorithm(), kid, payload)
+  }
+
+  /// Decodes a signed request and verifies that it answers `nonce` and is signed with the key it contains.
+  ///
+  /// This does not check that the key belongs to the device, see [`DeviceCredentialRequest::resolve_method`].
+  pub fn verify<V: JwsVerifier>(request: &[u8], verifier: &V, nonce: &[u8]) -> Result<Self> {
+    let sign1: CoseSign1 = CoseSign1::from_bytes(request)?;
+    let payload: Value = cbor::decode(sign1.payload())?;
+    let entries: &[(Value, Value)] = payload
+      .as_map()
+      .ok_or(Error::InvalidStructure("request is not a map"))?;
+
+    let device: CoreDID = cbor::get(entries, LABEL_DEVICE)
+      .and_then(Value::as_text)
+      .and_then(|device| CoreDID::parse(device).ok())
+      .ok_or(Error::InvalidStructure("invalid device DID"))?;
+    let key: CoseKey =
+      CoseKey::from_cbor(cbor::get(entries, LABEL_KEY).ok_or(Error::InvalidStructure("missing device key"))?)?;
+    let request_nonce: &[u8] = cbor::get(entries, LABEL_NONCE)
+      .and_then(Value::as_bytes)
+      .ok_or(Error::InvalidStructure("missing nonce"))?;
+
+    if request_nonce != nonce {
+      return Err(Error::NonceMismatch);
+    }
+    if sign1.algorithm() != &key.algorithm() {
+      return Err(Error::UnsupportedAlgorithm);
+    }
+    sign1.verify(verifier, &key.to_jwk())?;
+
+    Ok(Self::new(device, key, request_nonce.to_vec()))
+  }
+
+  /// Returns the verification method of the device's DID document holding the key of the request, proving that the
+  /// key belongs to the device.
+  pub fn resolve_method<'doc>(&self, document: &'doc CoreDocument) -> Result<&'doc VerificationMethod> {
+    if document.id() != &self.device {
+      return Err(Error::MethodNotFound(self.device.to_string()));
+    }
+    document
+      .methods(None)
+      .into_iter()
+      .find(|method| {
+        method
+          .data()
+          .public_key_jwk()
+          .and_then(|jwk| CoseKey::from_jwk(jwk).ok())
+          .is_some_and(|key| key.same_key(&self.key))
+      })
+      .ok_or_else(|| Error::MethodNotFound(self.device.to_string()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+  use identity_verification::jwk::Jwk;
+  use identity_verification::MethodData;
+
+  use super::*;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+
+  fn signed_request(request: &DeviceCredentialRequest, secret: &SecretKey) -> Vec<u8> {
+    let unsigned: CoseSign1 = request.to_cose_sign1().unwrap();
+    let signature: [u8; 64] = secret.sign(&unsigned.signing_input().unwrap()).to_bytes();
+    unsigned.with_signature(signature.to_vec()).to_bytes().unwrap()
+  }
+
+  #[test]
+  fn verify_request_and_resolve_device_method() {
+    let (device, secret, fragment) = generate_jwk_document_with_keys();
+    let method: &VerificationMethod = device.resolve_method(&fragment, None).unwrap();
+    let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
+      panic!("not a jwk");
+    };
+    let key: CoseKey = CoseKey::from_jwk(jwk).unwrap();
+    let request = DeviceCredentialRequest::new(device.id().clone(), key, b"nonce".to_vec());
+    let bytes: Vec<u8> = signed_request(&request, &secret);
+
+    let verified = DeviceCredentialRequest::verify(&bytes, &EdDSAJwsVerifier::default(), b"nonce").unwrap();
+    assert_eq!(verified, request);
+    assert_eq!(verified.resolve_method(&device).unwrap().id(), method.id());
+
+    // INVALID: wrong nonce.
+    assert!(matches!(
+      DeviceCredentialRequest::verify(&bytes, &EdDSAJwsVerifier::default(), b"other"),
+      Err(Error::NonceMismatch)
+    ));
+
+    // INVALID: the key is not a method of another document.
+    let (other, _, _) = generate_jwk_document_with_keys();
+    assert!(matches!(verified.resolve_method(&other), Err(Error::MethodNotFound(_))));
+  }
+
+  #[test]
+  fn verify_rejects_request_signed_with_other_key() {
+    let (device, _, fragment) = generate_jwk_document_with_keys();
+    let jwk: &Jwk = device
+      .resolve_method(&fragment, None)
+      .and_then(|method| method.data().public_key_jwk())
+      .unwrap();
+    let request = DeviceCredentialRequest::new(device.id().clone(), CoseKey::from_jwk(jwk).unwrap(), Vec::new());
+    let bytes: Vec<u8> = signed_request(&request, &SecretKey::generate().unwrap());
+
+    assert!(matches!(
+      DeviceCredentialRequest::verify(&bytes, &EdDSAJwsVerifier::default(), &[]),
+      Err(Error::Signature(_))
+    ));
+  }
+}
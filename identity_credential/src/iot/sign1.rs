@@ -0,0 +1,229 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use ciborium::Value;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::VerificationInput;
+
+use super::cbor;
+use super::Error;
+use super::Result;
+
+/// The CBOR tag of COSE_Sign1 structures.
+const COSE_SIGN1_TAG: u64 = 18;
+/// The context string of the `Sig_structure` of COSE_Sign1.
+const SIGNATURE1_CONTEXT: &str = "Signature1";
+
+const HEADER_ALG: i64 = 1;
+const HEADER_KID: i64 = 4;
+
+/// A payload signed with a single signature, encoded as
+/// [COSE_Sign1](https://www.rfc-editor.org/rfc/rfc9052#section-4.2).
+///
+/// The algorithm and the key identifier are part of the protected header. Signing happens outside of this type:
+/// create the structure with [`CoseSign1::new`], sign the [`CoseSign1::signing_input`] with the key and attach the
+/// signature with [`CoseSign1::with_signature`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoseSign1 {
+  protected: Vec<u8>,
+  algorithm: JwsAlgorithm,
+  kid: Option<String>,
+  payload: Vec<u8>,
+  signature: Vec<u8>,
+}
+
+impl CoseSign1 {
+  /// Creates an unsigned [`CoseSign1`] over `payload` for the given algorithm and key identifier, typically the DID
+  /// URL of a verification method.
+  pub fn new(algorithm: JwsAlgorithm, kid: Option<String>, payload: Vec<u8>) -> Result<Self> {
+    let mut header: Vec<(Value, Value)> = vec![(HEADER_ALG.into(), cbor::to_cose_algorithm(&algorithm)?.into())];
+    if let Some(kid) = &kid {
+      header.push((HEADER_KID.into(), Value::Bytes(kid.as_bytes().to_vec())));
+    }
+    Ok(Self {
+      protected: cbor::encode(&Value::Map(header))?,
+      algorithm,
+      kid,
+      payload,
+      signature: Vec::new(),
+    })
+  }
+
+  /// Returns the signature algorithm.
+  pub fn algorithm(&self) -> &JwsAlgorithm {
+    &self.algorithm
+  }
+
+  /// Returns the key identifier, if any.
+  pub fn kid(&self) -> Option<&str> {
+    self.kid.as_deref()
+  }
+
+  /// Returns the signed payload.
+  pub fn payload(&self) -> &[u8] {
+    &self.payload
+  }
+
+  /// Returns the signature, which is empty until [`CoseSign1::with_signature`] is called.
+  pub fn signature(&self) -> &[u8] {
+    &self.signature
+  }
+
+  /// Returns the bytes to sign, i.e. the encoded `Sig_structure` without external additional authenticated data.
+  pub fn signing_input(&self) -> Result<Vec<u8>> {
+    cbor::encode(&Value::Array(vec![
+      Value::Text(SIGNATURE1_CONTEXT.to_owned()),
+      Value::Bytes(self.protected.clone()),
+      Value::Bytes(Vec::new()),
+      Value::Bytes(self.payload.clone()),
+    ]))
+  }
+
+  /// Attaches the signature of the [`CoseSign1::signing_input`].
+  ///
+  /// `ES256` signatures are the concatenation of `r` and `s`, as in JWS.
+  pub fn with_signature(mut self, signature: Vec<u8>) -> Self {
+    self.signature = signature;
+    self
+  }
+
+  /// Verifies the signature with `public_key`.
+  pub fn verify<V: JwsVerifier>(&self, verifier: &V, public_key: &Jwk) -> Result<()> {
+    verifier
+      .verify(
+        VerificationInput {
+          alg: self.algorithm.clone(),
+          signing_input: self.signing_input()?.into_boxed_slice(),
+          decoded_signature: self.signature.clone().into_boxed_slice(),
+        },
+        public_key,
+      )
+      .map_err(Error::Signature)
+  }
+
+  /// Encodes the structure as tagged COSE_Sign1.
+  pub fn to_bytes(&self) -> Result<Vec<u8>> {
+    cbor::encode(&Value::Tag(
+      COSE_SIGN1_TAG,
+      Box::new(Value::Array(vec![
+        Value::Bytes(self.protected.clone()),
+        Value::Map(Vec::new()),
+        Value::Bytes(self.payload.clone()),
+        Value::Bytes(self.signature.clone()),
+      ])),
+    ))
+  }
+
+  /// Decodes a tagged or untagged COSE_Sign1 structure.
+  ///
+  /// The algorithm must be in the protected header, the key identifier may be in either header.
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+    let value: Value = match cbor::decode(bytes)? {
+      Value::Tag(COSE_SIGN1_TAG, value) => *value,
+      Value::Tag(..) => return Err(Error::InvalidStructure("unexpected tag")),
+      value => value,
+    };
+    let [protected, unprotected, payload, signature]: [Value; 4] = value
+      .into_array()
+      .ok()
+      .and_then(|array| array.try_into().ok())
+      .ok_or(Error::InvalidStructure("COSE_Sign1 is not an array of four elements"))?;
+
+    let protected: Vec<u8> = protected
+      .into_bytes()
+      .map_err(|_| Error::InvalidStructure("protected header is not a byte string"))?;
+    let protected_header: Value = if protected.is_empty() {
+      Value::Map(Vec::new())
+    } else {
+      cbor::decode(&protected)?
+    };
+    let protected_header: &[(Value, Value)] = protected_header
+      .as_map()
+      .ok_or(Error::InvalidStructure("protected header is not a map"))?;
+    let unprotected_header: &[(Value, Value)] = unprotected
+      .as_map()
+      .ok_or(Error::InvalidStructure("unprotected header is not a map"))?;
+
+    let algorithm: JwsAlgorithm = cbor::get(protected_header, HEADER_ALG)
+      .and_then(cbor::as_i64)
+      .ok_or(Error::InvalidStructure("missing algorithm in protected header"))
+      .and_then(cbor::from_cose_algorithm)?;
+    let kid: Option<String> =
+      match cbor::get(protected_header, HEADER_KID).or(cbor::get(unprotected_header, HEADER_KID)) {
+        Some(kid) => Some(
+          kid
+            .as_bytes()
+            .and_then(|kid| String::from_utf8(kid.clone()).ok())
+            .ok_or(Error::InvalidStructure("kid is not a UTF-8 byte string"))?,
+        ),
+        None => None,
+      };
+
+    Ok(Self {
+      protected,
+      algorithm,
+      kid,
+      payload: payload
+        .into_bytes()
+        .map_err(|_| Error::InvalidStructure("payload is not a byte string"))?,
+      signature: signature
+        .into_bytes()
+        .map_err(|_| Error::InvalidStructure("signature is not a byte string"))?,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+  use super::*;
+  use crate::validator::test_utils::encode_public_ed25519_jwk;
+
+  #[test]
+  fn sign_encode_and_verify() {
+    let secret: SecretKey = SecretKey::generate().unwrap();
+    let jwk: Jwk = encode_public_ed25519_jwk(&secret.public_key());
+
+    let unsigned = CoseSign1::new(
+      JwsAlgorithm::EdDSA,
+      Some("did:example:123#key".to_owned()),
+      b"payload".to_vec(),
+    )
+    .unwrap();
+    let signature: [u8; 64] = secret.sign(&unsigned.signing_input().unwrap()).to_bytes();
+    let bytes: Vec<u8> = unsigned.with_signature(signature.to_vec()).to_bytes().unwrap();
+
+    let decoded: CoseSign1 = CoseSign1::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.kid(), Some("did:example:123#key"));
+    assert_eq!(decoded.payload(), b"payload");
+    decoded.verify(&EdDSAJwsVerifier::default(), &jwk).unwrap();
+
+    // INVALID: tampered payload.
+    let mut tampered: CoseSign1 = decoded.clone();
+    tampered.payload = b"tampered".to_vec();
+    assert!(matches!(
+      tampered.verify(&EdDSAJwsVerifier::default(), &jwk),
+      Err(Error::Signature(_))
+    ));
+  }
+
+  #[test]
+  fn rejects_malformed_structures() {
+    let bytes: Vec<u8> = cbor::encode(&Value::Array(vec![Value::Bytes(Vec::new())])).unwrap();
+    assert!(matches!(CoseSign1::from_bytes(&bytes), Err(Error::InvalidStructure(_))));
+
+    // No algorithm in the protected header.
+    let bytes: Vec<u8> = cbor::encode(&Value::Array(vec![
+      Value::Bytes(Vec::new()),
+      Value::Map(vec![(HEADER_ALG.into(), (-8).into())]),
+      Value::Bytes(Vec::new()),
+      Value::Bytes(Vec::new()),
+    ]))
+    .unwrap();
+    assert!(matches!(CoseSign1::from_bytes(&bytes), Err(Error::InvalidStructure(_))));
+  }
+}
@@ -0,0 +1,189 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::VerificationMethod;
+use serde::de::DeserializeOwned;
+
+use super::CoseSign1;
+use super::Error;
+use super::Result;
+use crate::credential::Credential;
+use crate::validator::CompoundCredentialValidationError;
+use crate::validator::JwtCredentialValidatorUtils;
+use crate::validator::JwtValidationError;
+use crate::validator::StatusCheck;
+
+/// A minimal verifier for [`Credential`]s encoded as CBOR and secured with [`CoseSign1`], intended for constrained
+/// devices.
+///
+/// Like the [`LiteJwtCredentialVerifier`](crate::validator::LiteJwtCredentialVerifier), it does not resolve DIDs:
+/// the DID Documents of all trusted issuers must be supplied up front. The `kid` of the COSE_Sign1 must be the DID
+/// URL of a verification method of one of them. Only the signature, the expiration and issuance dates and the
+/// `RevocationBitmap2022` status are checked.
+pub struct LiteCoseCredentialVerifier<V: JwsVerifier> {
+  signature_verifier: V,
+  trusted_issuers: Vec<CoreDocument>,
+}
+
+impl<V: JwsVerifier> LiteCoseCredentialVerifier<V> {
+  /// Creates a new [`LiteCoseCredentialVerifier`] that delegates cryptographic signature verification to
+  /// `signature_verifier` and accepts credentials issued by one of the `trusted_issuers`.
+  pub fn new(signature_verifier: V, trusted_issuers: impl IntoIterator<Item = CoreDocument>) -> Self {
+    Self {
+      signature_verifier,
+      trusted_issuers: trusted_issuers.into_iter().collect(),
+    }
+  }
+
+  /// Returns the DID Documents of the trusted issuers.
+  pub fn trusted_issuers(&self) -> &[CoreDocument] {
+    &self.trusted_issuers
+  }
+
+  /// Adds or replaces the DID Document of a trusted issuer, e.g. after a key rotation.
+  pub fn set_trusted_issuer(&mut self, issuer: CoreDocument) {
+    match self
+      .trusted_issuers
+      .iter_mut()
+      .find(|trusted| trusted.id() == issuer.id())
+    {
+      Some(trusted) => *trusted = issuer,
+      None => self.trusted_issuers.push(issuer),
+    }
+  }
+
+  /// Decodes and verifies a credential secured with [`CoseSign1`], using `now` as the current time.
+  ///
+  /// # Errors
+  /// Fails if the structure cannot be decoded, the signer is not trusted or the signature is invalid. Otherwise, all
+  /// failed checks of the credential are returned in [`Error::Validation`].
+  pub fn verify<T>(&self, credential: &[u8], now: Timestamp) -> Result<Credential<T>>
+  where
+    T: DeserializeOwned,
+  {
+    let sign1: CoseSign1 = CoseSign1::from_bytes(credential)?;
+    let kid: &str = sign1
+      .kid()
+      .ok_or(Error::InvalidStructure("missing kid in protected header"))?;
+    let method_id: DIDUrl = DIDUrl::parse(kid).map_err(|_| Error::InvalidStructure("kid is not a DID URL"))?;
+
+    let issuer: &CoreDocument = self
+      .trusted_issuers
+      .iter()
+      .find(|issuer| issuer.id() == method_id.did())
+      .ok_or(Error::UntrustedIssuer)?;
+    let method: &VerificationMethod = issuer
+      .resolve_method(&method_id, None)
+      .ok_or_else(|| Error::MethodNotFound(kid.to_owned()))?;
+    let public_key = method
+      .data()
+      .public_key_jwk()
+      .ok_or(Error::UnsupportedKey("expected publicKeyJwk"))?;
+    sign1.verify(&self.signature_verifier, public_key)?;
+
+    let credential: Credential<T> = Credential::from_cbor(sign1.payload())?;
+    if credential.issuer.url().as_str() != issuer.id().as_str() {
+      return Err(Error::IssuerMismatch);
+    }
+
+    let validation_errors: Vec<JwtValidationError> = [
+      JwtCredentialValidatorUtils::check_expires_on_or_after(&credential, now),
+      JwtCredentialValidatorUtils::check_issued_on_or_before(&credential, now),
+      JwtCredentialValidatorUtils::check_status(&credential, &self.trusted_issuers, StatusCheck::Strict),
+    ]
+    .into_iter()
+    .filter_map(std::result::Result::err)
+    .collect();
+
+    if validation_errors.is_empty() {
+      Ok(credential)
+    } else {
+      Err(Error::Validation(CompoundCredentialValidationError {
+        validation_errors,
+      }))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_core::common::Duration;
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+  use identity_verification::jwk::Jwk;
+
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+
+  fn now() -> Timestamp {
+    Timestamp::parse("2024-06-01T00:00:00Z").unwrap()
+  }
+
+  fn issue(issuer: &CoreDocument, fragment: &str, secret: &SecretKey) -> Vec<u8> {
+    let credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(issuer.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:device").unwrap()))
+      .issuance_date(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+      .expiration_date(Timestamp::parse("2025-01-01T00:00:00Z").unwrap())
+      .build()
+      .unwrap();
+    let method: &VerificationMethod = issuer.resolve_method(fragment, None).unwrap();
+    let jwk: &Jwk = method.data().public_key_jwk().unwrap();
+    let unsigned: CoseSign1 = CoseSign1::new(
+      jwk.alg().unwrap().parse().unwrap(),
+      Some(method.id().to_string()),
+      credential.to_cbor().unwrap(),
+    )
+    .unwrap();
+    let signature: [u8; 64] = secret.sign(&unsigned.signing_input().unwrap()).to_bytes();
+    unsigned.with_signature(signature.to_vec()).to_bytes().unwrap()
+  }
+
+  #[test]
+  fn verify_checks_signature_and_expiry() {
+    let (issuer, secret, fragment) = generate_jwk_document_with_keys();
+    let bytes: Vec<u8> = issue(&issuer, &fragment, &secret);
+
+    let verifier = LiteCoseCredentialVerifier::new(EdDSAJwsVerifier::default(), [issuer.clone()]);
+    let credential: Credential<Object> = verifier.verify(&bytes, now()).unwrap();
+    assert_eq!(credential.issuer.url().as_str(), issuer.id().as_str());
+
+    // INVALID: expired.
+    let error = verifier
+      .verify::<Object>(&bytes, now().checked_add(Duration::days(365)).unwrap())
+      .unwrap_err();
+    assert!(matches!(
+      error,
+      Error::Validation(CompoundCredentialValidationError { ref validation_errors })
+        if matches!(validation_errors.as_slice(), [JwtValidationError::ExpirationDate])
+    ));
+  }
+
+  #[test]
+  fn verify_rejects_untrusted_issuer_and_forged_signature() {
+    let (issuer, secret, fragment) = generate_jwk_document_with_keys();
+    let (other_issuer, _, _) = generate_jwk_document_with_keys();
+    let bytes: Vec<u8> = issue(&issuer, &fragment, &secret);
+
+    let verifier = LiteCoseCredentialVerifier::new(EdDSAJwsVerifier::default(), [other_issuer]);
+    assert!(matches!(
+      verifier.verify::<Object>(&bytes, now()),
+      Err(Error::UntrustedIssuer)
+    ));
+
+    let forged: Vec<u8> = issue(&issuer, &fragment, &SecretKey::generate().unwrap());
+    let verifier = LiteCoseCredentialVerifier::new(EdDSAJwsVerifier::default(), [issuer]);
+    assert!(matches!(
+      verifier.verify::<Object>(&forged, now()),
+      Err(Error::Signature(_))
+    ));
+  }
+}
@@ -0,0 +1,427 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use serde_json::Value;
+
+use super::DocumentLoader;
+use super::Error;
+use super::JsonLdProcessor;
+use super::Quad;
+use super::Result;
+use super::Term;
+
+/// The maximum number of N-degree hashes and permutations computed while canonicalizing a dataset, bounding the
+/// work spent on datasets crafted to make canonicalization intractable.
+const MAX_STEPS: usize = 65_536;
+
+impl<L: DocumentLoader> JsonLdProcessor<L> {
+  /// Converts `document` to RDF and returns its canonical N-Quads serialization, as used as input to Data Integrity
+  /// proofs.
+  ///
+  /// See [`canonicalize`].
+  pub fn canonicalize(&self, document: &Value) -> Result<String> {
+    let quads: Vec<Quad> = canonicalize(&self.to_rdf(document)?)?;
+    Ok(quads.iter().map(ToString::to_string).collect())
+  }
+}
+
+/// Canonicalizes `quads` with the [RDF Dataset Canonicalization algorithm](https://www.w3.org/TR/rdf-canon/)
+/// (RDFC-1.0, formerly URDNA2015).
+///
+/// Returns the quads with their blank nodes relabeled to `c14n0`, `c14n1`, etc. and sorted by their N-Quads
+/// serialization, without duplicates. Isomorphic datasets result in identical quads.
+pub fn canonicalize(quads: &[Quad]) -> Result<Vec<Quad>> {
+  let labels: HashMap<String, String> = canonical_labels(quads)?;
+  let mut canonical: Vec<(String, Quad)> = quads
+    .iter()
+    .map(|quad| {
+      let quad: Quad = relabel(quad, |label| {
+        labels
+          .get(label)
+          .cloned()
+          .expect("all blank nodes are issued an identifier")
+      });
+      (quad.to_string(), quad)
+    })
+    .collect();
+  canonical.sort_by(|(line_a, _), (line_b, _)| line_a.cmp(line_b));
+  canonical.dedup_by(|(line_a, _), (line_b, _)| line_a == line_b);
+  Ok(canonical.into_iter().map(|(_, quad)| quad).collect())
+}
+
+/// Returns the canonical label, e.g. `c14n0`, of each blank node label of `quads`, as issued by [`canonicalize`].
+pub(crate) fn canonical_labels(quads: &[Quad]) -> Result<HashMap<String, String>> {
+  let mut state: Canonicalizer<'_> = Canonicalizer::new(quads);
+
+  let mut hash_to_blank_nodes: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+  for label in state.blank_nodes.clone() {
+    let hash: String = state.hash_first_degree(label);
+    hash_to_blank_nodes.entry(hash).or_default().push(label);
+  }
+
+  // Blank nodes with a unique first degree hash are labeled in the order of their hashes.
+  hash_to_blank_nodes.retain(|_, labels| {
+    if let [label] = labels.as_slice() {
+      state.canonical.issue(label);
+      false
+    } else {
+      true
+    }
+  });
+
+  // The remaining blank nodes are distinguished by the hashes of the paths to their related blank nodes.
+  for labels in hash_to_blank_nodes.into_values() {
+    let mut paths: Vec<(String, IdentifierIssuer)> = Vec::new();
+    for label in labels {
+      if state.canonical.get(label).is_some() {
+        continue;
+      }
+      let mut issuer: IdentifierIssuer = IdentifierIssuer::new("_:b");
+      issuer.issue(label);
+      paths.push(state.hash_n_degree(label, issuer)?);
+    }
+    paths.sort_by(|(hash_a, _), (hash_b, _)| hash_a.cmp(hash_b));
+    for (_, issuer) in paths {
+      for label in issuer.order {
+        state.canonical.issue(&label);
+      }
+    }
+  }
+
+  Ok(
+    state
+      .canonical
+      .issued
+      .into_iter()
+      .map(|(label, id)| (label, id.trim_start_matches("_:").to_owned()))
+      .collect(),
+  )
+}
+
+/// Issues identifiers with a common prefix and a counter, recording the order they were issued in.
+#[derive(Clone, Debug)]
+struct IdentifierIssuer {
+  prefix: &'static str,
+  issued: HashMap<String, String>,
+  order: Vec<String>,
+}
+
+impl IdentifierIssuer {
+  fn new(prefix: &'static str) -> Self {
+    Self {
+      prefix,
+      issued: HashMap::new(),
+      order: Vec::new(),
+    }
+  }
+
+  fn get(&self, label: &str) -> Option<&str> {
+    self.issued.get(label).map(String::as_str)
+  }
+
+  /// Returns the identifier issued for `label`, issuing a new one if none was issued yet.
+  fn issue(&mut self, label: &str) -> String {
+    if let Some(id) = self.issued.get(label) {
+      return id.clone();
+    }
+    let id: String = format!("{}{}", self.prefix, self.order.len());
+    self.issued.insert(label.to_owned(), id.clone());
+    self.order.push(label.to_owned());
+    id
+  }
+}
+
+struct Canonicalizer<'a> {
+  quads: &'a [Quad],
+  /// The labels of the blank nodes, in the order of their first occurrence.
+  blank_nodes: Vec<&'a str>,
+  /// The indices of the quads each blank node occurs in.
+  blank_node_quads: HashMap<&'a str, Vec<usize>>,
+  first_degree_hashes: HashMap<&'a str, String>,
+  canonical: IdentifierIssuer,
+  steps: usize,
+}
+
+impl<'a> Canonicalizer<'a> {
+  fn new(quads: &'a [Quad]) -> Self {
+    let mut blank_nodes: Vec<&'a str> = Vec::new();
+    let mut blank_node_quads: HashMap<&'a str, Vec<usize>> = HashMap::new();
+    for (index, quad) in quads.iter().enumerate() {
+      for label in components(quad).filter_map(|(term, _)| term.as_blank_node()) {
+        let indices: &mut Vec<usize> = blank_node_quads.entry(label).or_insert_with(|| {
+          blank_nodes.push(label);
+          Vec::new()
+        });
+        if indices.last() != Some(&index) {
+          indices.push(index);
+        }
+      }
+    }
+
+    Self {
+      quads,
+      blank_nodes,
+      blank_node_quads,
+      first_degree_hashes: HashMap::new(),
+      canonical: IdentifierIssuer::new("_:c14n"),
+      steps: 0,
+    }
+  }
+
+  fn step(&mut self) -> Result<()> {
+    self.steps += 1;
+    if self.steps > MAX_STEPS {
+      Err(Error::CanonicalizationLimitExceeded)
+    } else {
+      Ok(())
+    }
+  }
+
+  fn hash_first_degree(&mut self, label: &'a str) -> String {
+    if let Some(hash) = self.first_degree_hashes.get(label) {
+      return hash.clone();
+    }
+    let mut lines: Vec<String> = self.blank_node_quads[label]
+      .iter()
+      .map(|index| {
+        relabel(&self.quads[*index], |other| {
+          if other == label { "a" } else { "z" }.to_owned()
+        })
+        .to_string()
+      })
+      .collect();
+    lines.sort_unstable();
+    let hash: String = sha256_hex(&lines.concat());
+    self.first_degree_hashes.insert(label, hash.clone());
+    hash
+  }
+
+  fn hash_related(&mut self, related: &'a str, quad: &Quad, issuer: &IdentifierIssuer, position: &str) -> String {
+    let identifier: String = match self.canonical.get(related).or_else(|| issuer.get(related)) {
+      Some(id) => id.to_owned(),
+      None => self.hash_first_degree(related),
+    };
+    let mut input: String = position.to_owned();
+    if position != "g" {
+      input.push_str(&quad.predicate.to_string());
+    }
+    input.push_str(&identifier);
+    sha256_hex(&input)
+  }
+
+  fn hash_n_degree(&mut self, label: &'a str, mut issuer: IdentifierIssuer) -> Result<(String, IdentifierIssuer)> {
+    self.step()?;
+
+    let mut hash_to_related: BTreeMap<String, Vec<&'a str>> = BTreeMap::new();
+    let quads: &'a [Quad] = self.quads;
+    for index in self.blank_node_quads[label].clone() {
+      let quad: &'a Quad = &quads[index];
+      for (term, position) in components(quad) {
+        if let Some(related) = term.as_blank_node().filter(|related| *related != label) {
+          let hash: String = self.hash_related(related, quad, &issuer, position);
+          hash_to_related.entry(hash).or_default().push(related);
+        }
+      }
+    }
+
+    let mut data: String = String::new();
+    for (related_hash, related) in hash_to_related {
+      data.push_str(&related_hash);
+      let mut chosen: Option<(String, IdentifierIssuer)> = None;
+
+      'permutations: for permutation in permutations(&related) {
+        self.step()?;
+        let exceeds_chosen = |path: &str, chosen: &Option<(String, IdentifierIssuer)>| {
+          chosen
+            .as_ref()
+            .is_some_and(|(chosen, _)| path.len() >= chosen.len() && path > chosen.as_str())
+        };
+
+        let mut issuer_copy: IdentifierIssuer = issuer.clone();
+        let mut path: String = String::new();
+        let mut recursion: Vec<&'a str> = Vec::new();
+        for related in permutation {
+          match self.canonical.get(related) {
+            Some(id) => path.push_str(id),
+            None => {
+              if issuer_copy.get(related).is_none() {
+                recursion.push(related);
+              }
+              path.push_str(&issuer_copy.issue(related));
+            }
+          }
+          if exceeds_chosen(&path, &chosen) {
+            continue 'permutations;
+          }
+        }
+
+        for related in recursion {
+          let (hash, result_issuer) = self.hash_n_degree(related, issuer_copy.clone())?;
+          path.push_str(&issuer_copy.issue(related));
+          path.push('<');
+          path.push_str(&hash);
+          path.push('>');
+          issuer_copy = result_issuer;
+          if exceeds_chosen(&path, &chosen) {
+            continue 'permutations;
+          }
+        }
+
+        let is_preferred: bool = match &chosen {
+          Some((chosen_path, _)) => path < *chosen_path,
+          None => true,
+        };
+        if is_preferred {
+          chosen = Some((path, issuer_copy));
+        }
+      }
+
+      if let Some((path, chosen_issuer)) = chosen {
+        data.push_str(&path);
+        issuer = chosen_issuer;
+      }
+    }
+
+    Ok((sha256_hex(&data), issuer))
+  }
+}
+
+/// Returns the subject, object and graph of `quad` with the position identifier used in hashes.
+fn components(quad: &Quad) -> impl Iterator<Item = (&Term, &'static str)> {
+  [
+    (Some(&quad.subject), "s"),
+    (Some(&quad.object), "o"),
+    (quad.graph.as_ref(), "g"),
+  ]
+  .into_iter()
+  .filter_map(|(term, position)| term.map(|term| (term, position)))
+}
+
+/// Returns a copy of `quad` with its blank nodes relabeled by `label`.
+pub(crate) fn relabel(quad: &Quad, label: impl Fn(&str) -> String) -> Quad {
+  let relabel_term = |term: &Term| match term {
+    Term::BlankNode(old) => Term::BlankNode(label(old)),
+    other => other.clone(),
+  };
+  Quad {
+    subject: relabel_term(&quad.subject),
+    predicate: quad.predicate.clone(),
+    object: relabel_term(&quad.object),
+    graph: quad.graph.as_ref().map(relabel_term),
+  }
+}
+
+/// Returns all permutations of `items`, generated with Heap's algorithm.
+fn permutations<T: Copy>(items: &[T]) -> Vec<Vec<T>> {
+  let mut items: Vec<T> = items.to_vec();
+  let mut counters: Vec<usize> = vec![0; items.len()];
+  let mut permutations: Vec<Vec<T>> = vec![items.clone()];
+  let mut index: usize = 1;
+  while index < items.len() {
+    if counters[index] < index {
+      let swap: usize = if index % 2 == 0 { 0 } else { counters[index] };
+      items.swap(swap, index);
+      permutations.push(items.clone());
+      counters[index] += 1;
+      index = 1;
+    } else {
+      counters[index] = 0;
+      index += 1;
+    }
+  }
+  permutations
+}
+
+fn sha256_hex(input: &str) -> String {
+  let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+  SHA256(input.as_bytes(), &mut digest);
+  BaseEncoding::encode(&digest, Base::Base16Lower)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn blank(label: &str) -> Term {
+    Term::BlankNode(label.to_owned())
+  }
+
+  fn iri(iri: &str) -> Term {
+    Term::Iri(iri.to_owned())
+  }
+
+  fn literal(value: &str) -> Term {
+    Term::Literal {
+      value: value.to_owned(),
+      datatype: "http://www.w3.org/2001/XMLSchema#string".to_owned(),
+      language: None,
+    }
+  }
+
+  fn quad(subject: Term, predicate: &str, object: Term) -> Quad {
+    Quad {
+      subject,
+      predicate: iri(predicate),
+      object,
+      graph: None,
+    }
+  }
+
+  fn nquads(quads: &[Quad]) -> String {
+    canonicalize(quads).unwrap().iter().map(ToString::to_string).collect()
+  }
+
+  #[test]
+  fn test_permutations() {
+    let mut permutations: Vec<Vec<u8>> = permutations(&[1, 2, 3]);
+    assert_eq!(permutations.len(), 6);
+    permutations.sort();
+    permutations.dedup();
+    assert_eq!(permutations.len(), 6);
+  }
+
+  #[test]
+  fn test_symmetric_blank_nodes() {
+    let quads: Vec<Quad> = vec![
+      quad(blank("x"), "https://example.com/p", blank("y")),
+      quad(blank("y"), "https://example.com/p", blank("x")),
+    ];
+    assert_eq!(
+      nquads(&quads),
+      "_:c14n0 <https://example.com/p> _:c14n1 .\n_:c14n1 <https://example.com/p> _:c14n0 .\n"
+    );
+  }
+
+  #[test]
+  fn test_isomorphic_datasets() {
+    let dataset = |labels: [&str; 4]| {
+      let [a, b, c, d] = labels.map(blank);
+      vec![
+        quad(a.clone(), "https://example.com/p", b.clone()),
+        quad(b.clone(), "https://example.com/p", c.clone()),
+        quad(c.clone(), "https://example.com/p", d.clone()),
+        quad(d.clone(), "https://example.com/p", a.clone()),
+        quad(a, "https://example.com/name", literal("a")),
+        quad(c, "https://example.com/name", literal("c")),
+        Quad {
+          graph: Some(b),
+          ..quad(iri("did:example:1"), "https://example.com/q", d)
+        },
+      ]
+    };
+
+    let canonical: String = nquads(&dataset(["a", "b", "c", "d"]));
+    let mut relabeled: Vec<Quad> = dataset(["w", "x", "y", "z"]);
+    relabeled.reverse();
+    assert_eq!(nquads(&relabeled), canonical);
+    assert!(!canonical.contains("_:a") && !canonical.contains("_:w"));
+    assert_eq!(canonical.lines().count(), 7);
+  }
+}
@@ -0,0 +1,36 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Alias for a `Result` with the error type [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Failures of JSON-LD processing.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum Error {
+  /// A context could not be loaded.
+  #[error("failed to load context \"{url}\"")]
+  ContextLoading {
+    /// The URL of the context.
+    url: String,
+    /// The source of the error, if any.
+    #[source]
+    source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  },
+  /// A context does not match the digest it was vetted with.
+  #[error("context \"{0}\" does not match its vetted digest")]
+  ContextDigestMismatch(String),
+  /// A context is malformed.
+  #[error("invalid context: {0}")]
+  InvalidContext(&'static str),
+  /// Contexts reference each other too deeply, e.g. in a cycle.
+  #[error("maximum context nesting depth exceeded")]
+  ContextDepthExceeded,
+  /// Canonicalizing a dataset exceeded the maximum number of steps, e.g. because it was crafted to make
+  /// canonicalization intractable.
+  #[error("maximum number of canonicalization steps exceeded")]
+  CanonicalizationLimitExceeded,
+  /// The document uses terms that are not defined by its contexts.
+  #[error("undefined terms: {}", .0.join(", "))]
+  UndefinedTerms(Vec<String>),
+}
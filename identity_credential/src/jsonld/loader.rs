@@ -0,0 +1,108 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use serde_json::Value;
+
+use super::Error;
+use super::Result;
+
+/// Loads the JSON-LD documents referenced by URL in a `@context`.
+///
+/// Implementations decide whether documents may be fetched from the network. Verifiers that must not depend on
+/// remote contexts should use a [`ContextCache`] populated with vetted contexts instead.
+pub trait DocumentLoader {
+  /// Returns the document at `url`, i.e. a JSON object with an `@context` property.
+  fn load(&self, url: &str) -> Result<Value>;
+}
+
+impl<F> DocumentLoader for F
+where
+  F: Fn(&str) -> Result<Value>,
+{
+  fn load(&self, url: &str) -> Result<Value> {
+    self(url)
+  }
+}
+
+/// An offline [`DocumentLoader`] serving only the contexts inserted into it.
+///
+/// # Example
+///
+/// ```
+/// # use identity_credential::jsonld::ContextCache;
+/// # use identity_credential::jsonld::DocumentLoader;
+/// let context: &[u8] = br#"{ "@context": { "@vocab": "https://example.com/vocab#" } }"#;
+/// let digest: [u8; 32] = ContextCache::digest(context);
+///
+/// let mut cache: ContextCache = ContextCache::new();
+/// cache.insert_vetted("https://example.com/context/v1", context, &digest)?;
+/// assert!(cache.load("https://example.com/context/v1").is_ok());
+/// assert!(cache.load("https://example.com/context/v2").is_err());
+/// # Ok::<(), identity_credential::jsonld::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ContextCache {
+  contexts: HashMap<String, Value>,
+}
+
+impl ContextCache {
+  /// Creates an empty [`ContextCache`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the SHA-256 digest of the serialized context `json`, as expected by [`Self::insert_vetted`].
+  pub fn digest(json: &[u8]) -> [u8; SHA256_LEN] {
+    let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+    SHA256(json, &mut digest);
+    digest
+  }
+
+  /// Inserts the context `document` served at `url`, replacing and returning any context previously inserted for it.
+  pub fn insert(&mut self, url: impl Into<String>, document: Value) -> Option<Value> {
+    self.contexts.insert(url.into(), document)
+  }
+
+  /// Inserts the serialized context `json` served at `url`, after checking that its SHA-256 digest matches
+  /// `expected_digest`, e.g. the digest of a copy of the context reviewed beforehand.
+  pub fn insert_vetted(
+    &mut self,
+    url: impl Into<String>,
+    json: &[u8],
+    expected_digest: &[u8; SHA256_LEN],
+  ) -> Result<()> {
+    let url: String = url.into();
+    if Self::digest(json) != *expected_digest {
+      return Err(Error::ContextDigestMismatch(url));
+    }
+    let document: Value = serde_json::from_slice(json).map_err(|err| Error::ContextLoading {
+      url: url.clone(),
+      source: Some(Box::new(err)),
+    })?;
+    self.contexts.insert(url, document);
+    Ok(())
+  }
+
+  /// Returns whether a context is cached for `url`.
+  pub fn contains(&self, url: &str) -> bool {
+    self.contexts.contains_key(url)
+  }
+
+  /// Returns the URLs of the cached contexts.
+  pub fn urls(&self) -> impl Iterator<Item = &str> + '_ {
+    self.contexts.keys().map(String::as_str)
+  }
+}
+
+impl DocumentLoader for ContextCache {
+  fn load(&self, url: &str) -> Result<Value> {
+    self.contexts.get(url).cloned().ok_or_else(|| Error::ContextLoading {
+      url: url.to_owned(),
+      source: None,
+    })
+  }
+}
@@ -0,0 +1,33 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Optional JSON-LD processing of credentials and presentations.
+//!
+//! Supports loading the `@context` of a document through a pluggable [`DocumentLoader`], e.g. an offline
+//! [`ContextCache`] of vetted contexts, detecting terms the contexts do not define, expanding terms to IRIs,
+//! converting documents to RDF and [canonicalizing](canonicalize()) the resulting datasets.
+//!
+//! Only the subset of the [JSON-LD 1.1 processing algorithms](https://www.w3.org/TR/json-ld11-api/) used by
+//! credentials is implemented: term definitions with type coercion and `@list` and `@graph` containers, `@vocab`,
+//! compact IRIs, keyword aliases, and embedded, property-scoped and type-scoped contexts. Base IRIs, `@reverse`,
+//! `@nest` and language and index maps are not supported.
+
+mod canonicalize;
+mod error;
+mod loader;
+mod processor;
+mod rdf;
+
+#[cfg(feature = "data-integrity")]
+pub(crate) use canonicalize::canonical_labels;
+pub use canonicalize::canonicalize;
+#[cfg(feature = "data-integrity")]
+pub(crate) use canonicalize::relabel;
+pub use error::Error;
+pub use error::Result;
+pub use loader::ContextCache;
+pub use loader::DocumentLoader;
+pub use processor::JsonLdProcessor;
+pub use processor::TermReport;
+pub use rdf::Quad;
+pub use rdf::Term;
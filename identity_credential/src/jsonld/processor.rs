@@ -0,0 +1,604 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::DocumentLoader;
+use super::Error;
+use super::Result;
+
+/// The maximum depth of contexts referencing other contexts and of term definitions referencing other terms.
+const MAX_DEPTH: usize = 16;
+
+/// Keywords whose values are not node objects and are therefore not checked for undefined terms.
+const LITERAL_KEYWORDS: &[&str] = &["@id", "@type", "@value", "@language", "@direction", "@index", "@json"];
+
+/// The terms of a document and the IRIs they expand to, as determined by [`JsonLdProcessor::check_terms`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TermReport {
+  /// The terms used as property names or types, mapped to the IRI or keyword they expand to.
+  pub expanded: BTreeMap<String, String>,
+  /// The JSON pointers of the property names and types that are not defined by the contexts of the document.
+  pub undefined: Vec<String>,
+}
+
+impl TermReport {
+  /// Returns whether all terms of the document are defined.
+  pub fn is_valid(&self) -> bool {
+    self.undefined.is_empty()
+  }
+}
+
+#[derive(Clone, Debug)]
+pub(super) enum IriMapping {
+  /// The term expands to the given IRI, compact IRI, term or keyword.
+  Iri(String),
+  /// The term is defined without an `@id` and expands relative to the vocabulary mapping.
+  Vocab,
+  /// The term is explicitly undefined.
+  Null,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct TermDefinition {
+  pub(super) iri: IriMapping,
+  /// The type values of the term are coerced to, e.g. `@id` or a datatype IRI, as written in the context.
+  pub(super) type_mapping: Option<String>,
+  /// The container mapping of the term, e.g. `@list` or `@graph`.
+  pub(super) container: Vec<String>,
+  /// The scoped context of the term, applied to its values or to nodes having it as type.
+  pub(super) context: Option<Value>,
+}
+
+impl TermDefinition {
+  fn new(iri: IriMapping) -> Self {
+    Self {
+      iri,
+      type_mapping: None,
+      container: Vec::new(),
+      context: None,
+    }
+  }
+
+  /// Returns whether the container mapping of the term includes `container`.
+  pub(super) fn has_container(&self, container: &str) -> bool {
+    self.container.iter().any(|entry| entry == container)
+  }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct ActiveContext {
+  terms: HashMap<String, TermDefinition>,
+  vocab: Option<String>,
+  /// The context before any non-propagating type-scoped context was applied, restored for nested nodes.
+  previous: Option<Box<ActiveContext>>,
+}
+
+impl ActiveContext {
+  /// Returns the definition of `term`, if any.
+  pub(super) fn definition(&self, term: &str) -> Option<&TermDefinition> {
+    self.terms.get(term)
+  }
+
+  /// Expands a term used as a property name or type to an IRI or keyword.
+  pub(super) fn expand_term(&self, term: &str, depth: usize) -> Option<String> {
+    if term.starts_with('@') {
+      return Some(term.to_owned());
+    }
+    match self.terms.get(term).map(|definition| &definition.iri) {
+      Some(IriMapping::Iri(iri)) if iri != term && depth < MAX_DEPTH => self.expand_iri(iri, depth + 1),
+      Some(IriMapping::Iri(_)) | Some(IriMapping::Vocab) => self.vocab_relative(term),
+      Some(IriMapping::Null) => None,
+      None if term.contains(':') => self.expand_iri(term, depth + 1),
+      None => self.vocab_relative(term),
+    }
+  }
+
+  /// Expands an absolute IRI, compact IRI, keyword or term.
+  pub(super) fn expand_iri(&self, value: &str, depth: usize) -> Option<String> {
+    if value.starts_with('@') {
+      return Some(value.to_owned());
+    }
+    let Some((prefix, suffix)) = value.split_once(':') else {
+      return self.expand_term(value, depth);
+    };
+    if suffix.starts_with("//") || depth >= MAX_DEPTH {
+      return Some(value.to_owned());
+    }
+    match self.terms.get(prefix).map(|definition| &definition.iri) {
+      Some(IriMapping::Iri(_)) => self
+        .expand_term(prefix, depth + 1)
+        .map(|prefix_iri| format!("{prefix_iri}{suffix}")),
+      // Any other value with a scheme, e.g. `did:` or `urn:`, is an absolute IRI.
+      _ => Some(value.to_owned()),
+    }
+  }
+
+  /// Expands a node identifier, i.e. the value of `@id` or of a term coerced to `@id`.
+  ///
+  /// Unlike property names, identifiers are never expanded with term definitions or the vocabulary mapping. Relative
+  /// IRI references cannot be resolved without a base IRI and expand to `None`.
+  pub(super) fn expand_id(&self, value: &str) -> Option<String> {
+    if value.starts_with("_:") {
+      return Some(value.to_owned());
+    }
+    match value.split_once(':') {
+      Some((prefix, _)) if !prefix.is_empty() => self.expand_iri(value, 0),
+      _ => None,
+    }
+  }
+
+  /// Returns the expanded type mapping of `term`, e.g. `@id`, `@json` or a datatype IRI.
+  pub(super) fn type_mapping(&self, term: &str) -> Option<String> {
+    let type_mapping: &str = self.terms.get(term)?.type_mapping.as_deref()?;
+    if type_mapping.starts_with('@') {
+      Some(type_mapping.to_owned())
+    } else {
+      self.expand_iri(type_mapping, 0)
+    }
+  }
+
+  fn vocab_relative(&self, term: &str) -> Option<String> {
+    self.vocab.as_ref().map(|vocab| format!("{vocab}{term}"))
+  }
+}
+
+/// The active contexts of a node object.
+pub(super) struct NodeContext {
+  /// The context the property names and values of the node are expanded with.
+  pub(super) properties: ActiveContext,
+  /// The context the types of the node are expanded with, i.e. without the type-scoped contexts of these types.
+  pub(super) types: ActiveContext,
+}
+
+/// Processes credentials and presentations as JSON-LD, loading their contexts with a [`DocumentLoader`].
+///
+/// # Example
+///
+/// ```
+/// # use identity_credential::jsonld::ContextCache;
+/// # use identity_credential::jsonld::JsonLdProcessor;
+/// # use identity_credential::jsonld::TermReport;
+/// let mut cache: ContextCache = ContextCache::new();
+/// cache.insert(
+///   "https://example.com/context/v1",
+///   serde_json::json!({ "@context": { "id": "@id", "name": "https://schema.org/name" } }),
+/// );
+///
+/// let processor: JsonLdProcessor<ContextCache> = JsonLdProcessor::new(cache);
+/// let report: TermReport = processor.check_terms(&serde_json::json!({
+///   "@context": "https://example.com/context/v1",
+///   "id": "did:example:123",
+///   "name": "Alice",
+///   "age": 42,
+/// }))?;
+/// assert_eq!(report.expanded["name"], "https://schema.org/name");
+/// assert_eq!(report.undefined, ["/age"]);
+/// # Ok::<(), identity_credential::jsonld::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct JsonLdProcessor<L> {
+  loader: L,
+}
+
+impl<L: DocumentLoader> JsonLdProcessor<L> {
+  /// Creates a new [`JsonLdProcessor`] loading contexts with `loader`.
+  pub fn new(loader: L) -> Self {
+    Self { loader }
+  }
+
+  /// Returns the [`DocumentLoader`] of the processor.
+  pub fn loader(&self) -> &L {
+    &self.loader
+  }
+
+  /// Determines the IRIs the terms of `document` expand to and which of its terms are not defined by its contexts.
+  pub fn check_terms(&self, document: &Value) -> Result<TermReport> {
+    let mut report: TermReport = TermReport::default();
+    self.walk(&ActiveContext::default(), None, document, "", &mut report)?;
+    Ok(report)
+  }
+
+  /// Checks that all terms of `document` are defined by its contexts, failing with [`Error::UndefinedTerms`]
+  /// otherwise.
+  ///
+  /// Undefined terms are silently dropped by JSON-LD processors, such that the claims using them are not covered by
+  /// any signature over the canonicalized document.
+  pub fn validate(&self, document: &Value) -> Result<()> {
+    let report: TermReport = self.check_terms(document)?;
+    if report.is_valid() {
+      Ok(())
+    } else {
+      Err(Error::UndefinedTerms(report.undefined))
+    }
+  }
+
+  /// Serializes `value`, e.g. a [`Credential`](crate::credential::Credential) or
+  /// [`Presentation`](crate::presentation::Presentation), and [validates](Self::validate) it.
+  pub fn validate_serializable<T: Serialize + ?Sized>(&self, value: &T) -> Result<()> {
+    let document: Value = serde_json::to_value(value).map_err(|_| Error::InvalidContext("document is not JSON"))?;
+    self.validate(&document)
+  }
+
+  /// Expands the property names and types of `document` to IRIs, dropping properties with undefined terms and the
+  /// `@context`.
+  ///
+  /// Values are kept as is rather than converted to value objects, such that the result is a simplified form of
+  /// the JSON-LD expanded document form. Use [`Self::to_rdf`] for the complete interpretation of the document.
+  pub fn expand(&self, document: &Value) -> Result<Value> {
+    self.expand_value(&ActiveContext::default(), None, document)
+  }
+
+  fn process_context(&self, active: &mut ActiveContext, local: &Value, depth: usize) -> Result<()> {
+    if depth > MAX_DEPTH {
+      return Err(Error::ContextDepthExceeded);
+    }
+
+    match local {
+      Value::Null => *active = ActiveContext::default(),
+      Value::String(url) => {
+        let mut document: Value = self.loader.load(url)?;
+        let context: Value = document
+          .get_mut("@context")
+          .map(Value::take)
+          .ok_or(Error::InvalidContext("loaded document has no @context"))?;
+        self.process_context(active, &context, depth + 1)?;
+      }
+      Value::Array(contexts) => {
+        for context in contexts {
+          self.process_context(active, context, depth)?;
+        }
+      }
+      Value::Object(definitions) => {
+        if let Some(import) = definitions.get("@import") {
+          self.process_context(active, import, depth + 1)?;
+        }
+        match definitions.get("@vocab") {
+          Some(Value::String(vocab)) => active.vocab = active.expand_iri(vocab, 0).or_else(|| Some(vocab.clone())),
+          Some(Value::Null) => active.vocab = None,
+          Some(_) => return Err(Error::InvalidContext("@vocab must be a string or null")),
+          None => {}
+        }
+
+        for (term, definition) in definitions {
+          if term.starts_with('@') {
+            continue;
+          }
+          let definition: TermDefinition = match definition {
+            Value::Null => TermDefinition::new(IriMapping::Null),
+            Value::String(iri) => TermDefinition::new(IriMapping::Iri(iri.clone())),
+            Value::Object(expanded) => Self::expanded_term_definition(term, expanded)?,
+            _ => {
+              return Err(Error::InvalidContext(
+                "term definitions must be a string, object or null",
+              ))
+            }
+          };
+          active.terms.insert(term.clone(), definition);
+        }
+      }
+      _ => return Err(Error::InvalidContext("a context must be a URL, object, array or null")),
+    }
+
+    Ok(())
+  }
+
+  fn expanded_term_definition(term: &str, expanded: &Map<String, Value>) -> Result<TermDefinition> {
+    let iri: IriMapping = match expanded.get("@id") {
+      Some(Value::String(iri)) => IriMapping::Iri(iri.clone()),
+      Some(Value::Null) => IriMapping::Null,
+      Some(_) => {
+        return Err(Error::InvalidContext(
+          "@id of a term definition must be a string or null",
+        ))
+      }
+      None if term.contains(':') => IriMapping::Iri(term.to_owned()),
+      None => IriMapping::Vocab,
+    };
+    let type_mapping: Option<String> = match expanded.get("@type") {
+      Some(Value::String(type_mapping)) => Some(type_mapping.clone()),
+      None => None,
+      Some(_) => return Err(Error::InvalidContext("@type of a term definition must be a string")),
+    };
+    let container: Vec<String> = match expanded.get("@container") {
+      Some(Value::String(container)) => vec![container.clone()],
+      Some(Value::Array(containers)) => containers
+        .iter()
+        .map(|container| container.as_str().map(ToOwned::to_owned))
+        .collect::<Option<Vec<String>>>()
+        .ok_or(Error::InvalidContext(
+          "@container of a term definition must contain strings",
+        ))?,
+      Some(Value::Null) | None => Vec::new(),
+      Some(_) => {
+        return Err(Error::InvalidContext(
+          "@container of a term definition must be a string or array",
+        ))
+      }
+    };
+
+    Ok(TermDefinition {
+      iri,
+      type_mapping,
+      container,
+      context: expanded.get("@context").cloned(),
+    })
+  }
+
+  /// Determines the active contexts of `object`, the value of `property` in the `active` context.
+  ///
+  /// Applies, in this order, the scoped context of `property`, the embedded `@context` of `object` and the
+  /// type-scoped contexts of its types. Type-scoped contexts do not propagate to nested nodes.
+  pub(super) fn node_context(
+    &self,
+    active: &ActiveContext,
+    property: Option<&str>,
+    object: &Map<String, Value>,
+  ) -> Result<NodeContext> {
+    let mut context: ActiveContext = active.clone();
+
+    let is_value_or_reference: bool = object
+      .keys()
+      .any(|key| active.expand_term(key, 0).as_deref() == Some("@value"))
+      || (object.len() == 1
+        && object
+          .keys()
+          .all(|key| active.expand_term(key, 0).as_deref() == Some("@id")));
+    if !is_value_or_reference {
+      if let Some(previous) = context.previous.take() {
+        context = *previous;
+      }
+    }
+
+    if let Some(scoped) = property.and_then(|property| active.definition(property)?.context.as_ref()) {
+      self.process_context(&mut context, scoped, 0)?;
+    }
+    if let Some(embedded) = object.get("@context") {
+      self.process_context(&mut context, embedded, 0)?;
+    }
+
+    let types_context: ActiveContext = context.clone();
+    let mut types: Vec<&str> = object
+      .iter()
+      .filter(|(key, _)| types_context.expand_term(key, 0).as_deref() == Some("@type"))
+      .flat_map(|(_, value)| match value {
+        Value::String(type_) => vec![type_.as_str()],
+        Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+      })
+      .collect();
+    types.sort_unstable();
+    for type_ in types {
+      if let Some(scoped) = types_context
+        .definition(type_)
+        .and_then(|definition| definition.context.as_ref())
+      {
+        if context.previous.is_none() {
+          context.previous = Some(Box::new(types_context.clone()));
+        }
+        self.process_context(&mut context, scoped, 0)?;
+      }
+    }
+
+    Ok(NodeContext {
+      properties: context,
+      types: types_context,
+    })
+  }
+
+  fn walk(
+    &self,
+    active: &ActiveContext,
+    property: Option<&str>,
+    value: &Value,
+    path: &str,
+    report: &mut TermReport,
+  ) -> Result<()> {
+    match value {
+      Value::Array(items) => {
+        for (index, item) in items.iter().enumerate() {
+          self.walk(active, property, item, &format!("{path}/{index}"), report)?;
+        }
+      }
+      Value::Object(object) => {
+        let NodeContext { properties, types } = self.node_context(active, property, object)?;
+
+        for (key, value) in object {
+          if key == "@context" {
+            continue;
+          }
+          let key_path: String = format!("{path}/{}", escape_pointer(key));
+          let Some(iri) = properties.expand_term(key, 0) else {
+            report.undefined.push(key_path);
+            continue;
+          };
+
+          if iri == "@type" {
+            let type_values: Vec<(String, &str)> = match value {
+              Value::String(type_) => vec![(key_path.clone(), type_.as_str())],
+              Value::Array(type_values) => type_values
+                .iter()
+                .enumerate()
+                .filter_map(|(index, type_)| type_.as_str().map(|type_| (format!("{key_path}/{index}"), type_)))
+                .collect(),
+              _ => Vec::new(),
+            };
+            for (type_path, type_) in type_values {
+              match types.expand_term(type_, 0) {
+                Some(type_iri) => {
+                  report.expanded.entry(type_.to_owned()).or_insert(type_iri);
+                }
+                None => report.undefined.push(type_path),
+              }
+            }
+          } else if !LITERAL_KEYWORDS.contains(&iri.as_str())
+            && properties.type_mapping(key).as_deref() != Some("@json")
+          {
+            self.walk(&properties, Some(key), value, &key_path, report)?;
+          }
+          report.expanded.entry(key.clone()).or_insert(iri);
+        }
+      }
+      _ => {}
+    }
+    Ok(())
+  }
+
+  fn expand_value(&self, active: &ActiveContext, property: Option<&str>, value: &Value) -> Result<Value> {
+    match value {
+      Value::Array(items) => items
+        .iter()
+        .map(|item| self.expand_value(active, property, item))
+        .collect::<Result<Vec<Value>>>()
+        .map(Value::Array),
+      Value::Object(object) => {
+        let NodeContext { properties, types } = self.node_context(active, property, object)?;
+        let expand_type = |type_: &Value| match type_ {
+          Value::String(type_) => Value::String(types.expand_term(type_, 0).unwrap_or_else(|| type_.clone())),
+          other => other.clone(),
+        };
+
+        let mut expanded: Map<String, Value> = Map::new();
+        for (key, value) in object {
+          if key == "@context" {
+            continue;
+          }
+          let Some(iri) = properties.expand_term(key, 0) else {
+            continue;
+          };
+          let value: Value = if iri == "@type" {
+            match value {
+              Value::Array(type_values) => Value::Array(type_values.iter().map(expand_type).collect()),
+              other => expand_type(other),
+            }
+          } else if LITERAL_KEYWORDS.contains(&iri.as_str()) || properties.type_mapping(key).as_deref() == Some("@json")
+          {
+            value.clone()
+          } else {
+            self.expand_value(&properties, Some(key), value)?
+          };
+          expanded.insert(iri, value);
+        }
+        Ok(Value::Object(expanded))
+      }
+      other => Ok(other.clone()),
+    }
+  }
+}
+
+/// Escapes `key` for use in a JSON pointer.
+fn escape_pointer(key: &str) -> String {
+  key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+  use crate::jsonld::ContextCache;
+
+  fn processor() -> JsonLdProcessor<ContextCache> {
+    let mut cache: ContextCache = ContextCache::new();
+    cache.insert(
+      "https://example.com/credentials/v1",
+      json!({
+        "@context": {
+          "id": "@id",
+          "type": "@type",
+          "cred": "https://example.com/credentials#",
+          "VerifiableCredential": "cred:VerifiableCredential",
+          "credentialSubject": { "@id": "cred:credentialSubject", "@type": "@id" },
+          "issuer": { "@id": "cred:issuer", "@type": "@id" }
+        }
+      }),
+    );
+    cache.insert(
+      "https://example.com/degree/v1",
+      json!({ "@context": { "@vocab": "https://example.com/degree#" } }),
+    );
+    JsonLdProcessor::new(cache)
+  }
+
+  #[test]
+  fn test_undefined_terms() {
+    let processor: JsonLdProcessor<ContextCache> = processor();
+    let mut credential: Value = json!({
+      "@context": "https://example.com/credentials/v1",
+      "type": ["VerifiableCredential", "DegreeCredential"],
+      "issuer": "did:example:issuer",
+      "credentialSubject": { "id": "did:example:holder", "degree": "Bachelor" }
+    });
+
+    let report: TermReport = processor.check_terms(&credential).unwrap();
+    assert_eq!(report.undefined, ["/type/1", "/credentialSubject/degree"]);
+    assert_eq!(
+      report.expanded["VerifiableCredential"],
+      "https://example.com/credentials#VerifiableCredential"
+    );
+    assert_eq!(report.expanded["type"], "@type");
+    assert!(matches!(
+      processor.validate(&credential),
+      Err(Error::UndefinedTerms(terms)) if terms.len() == 2
+    ));
+
+    credential["@context"] = json!(["https://example.com/credentials/v1", "https://example.com/degree/v1"]);
+    processor.validate(&credential).unwrap();
+    let report: TermReport = processor.check_terms(&credential).unwrap();
+    assert_eq!(report.expanded["degree"], "https://example.com/degree#degree");
+  }
+
+  #[test]
+  fn test_embedded_context_and_compact_iris() {
+    let processor: JsonLdProcessor<ContextCache> = processor();
+    let document: Value = json!({
+      "@context": ["https://example.com/credentials/v1", { "schema": "https://schema.org/" }],
+      "credentialSubject": {
+        "@context": { "name": "schema:name" },
+        "name": "Alice",
+        "schema:age": 42,
+        "did:example:property": true
+      }
+    });
+    processor.validate(&document).unwrap();
+
+    let expanded: Value = processor.expand(&document).unwrap();
+    assert_eq!(
+      expanded,
+      json!({
+        "https://example.com/credentials#credentialSubject": {
+          "https://schema.org/name": "Alice",
+          "https://schema.org/age": 42,
+          "did:example:property": true
+        }
+      })
+    );
+  }
+
+  #[test]
+  fn test_context_loading_errors() {
+    let processor: JsonLdProcessor<ContextCache> = processor();
+    assert!(matches!(
+      processor.check_terms(&json!({ "@context": "https://example.com/unknown" })),
+      Err(Error::ContextLoading { .. })
+    ));
+
+    let cyclic = JsonLdProcessor::new(|_: &str| Ok(json!({ "@context": "https://example.com/cycle" })));
+    assert!(matches!(
+      cyclic.check_terms(&json!({ "@context": "https://example.com/cycle" })),
+      Err(Error::ContextDepthExceeded)
+    ));
+
+    let mut cache: ContextCache = ContextCache::new();
+    assert!(matches!(
+      cache.insert_vetted("https://example.com/context", b"{}", &[0; 32]),
+      Err(Error::ContextDigestMismatch(_))
+    ));
+  }
+}
@@ -0,0 +1,599 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+
+use super::processor::ActiveContext;
+use super::processor::NodeContext;
+use super::DocumentLoader;
+use super::Error;
+use super::JsonLdProcessor;
+use super::Result;
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const RDF_FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+const RDF_REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+const RDF_NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+const RDF_JSON: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON";
+const RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+const XSD_DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+
+/// A node or literal of an RDF [`Quad`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Term {
+  /// An absolute IRI.
+  Iri(String),
+  /// A blank node, identified by its label without the `_:` prefix.
+  BlankNode(String),
+  /// A literal.
+  Literal {
+    /// The lexical form of the literal.
+    value: String,
+    /// The datatype IRI of the literal.
+    datatype: String,
+    /// The language tag of the literal, if its datatype is `rdf:langString`.
+    language: Option<String>,
+  },
+}
+
+impl Term {
+  /// Returns the label of the blank node, if the term is one.
+  pub fn as_blank_node(&self) -> Option<&str> {
+    match self {
+      Self::BlankNode(label) => Some(label),
+      _ => None,
+    }
+  }
+
+  fn literal(value: impl Into<String>, datatype: impl Into<String>) -> Self {
+    Self::Literal {
+      value: value.into(),
+      datatype: datatype.into(),
+      language: None,
+    }
+  }
+}
+
+impl Display for Term {
+  /// Formats the term in its canonical N-Quads form.
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    match self {
+      Self::Iri(iri) => write!(f, "<{iri}>"),
+      Self::BlankNode(label) => write!(f, "_:{label}"),
+      Self::Literal {
+        value,
+        datatype,
+        language,
+      } => {
+        f.write_str("\"")?;
+        for char in value.chars() {
+          match char {
+            '\u{8}' => f.write_str("\\b")?,
+            '\t' => f.write_str("\\t")?,
+            '\n' => f.write_str("\\n")?,
+            '\u{c}' => f.write_str("\\f")?,
+            '\r' => f.write_str("\\r")?,
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\u{0}'..='\u{1f}' | '\u{7f}' => write!(f, "\\u{:04X}", char as u32)?,
+            _ => write!(f, "{char}")?,
+          }
+        }
+        f.write_str("\"")?;
+        match language {
+          Some(language) => write!(f, "@{language}"),
+          None if datatype == XSD_STRING => Ok(()),
+          None => write!(f, "^^<{datatype}>"),
+        }
+      }
+    }
+  }
+}
+
+/// A statement of an RDF dataset, i.e. a triple in the default graph or in a named graph.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Quad {
+  /// The subject of the statement.
+  pub subject: Term,
+  /// The predicate of the statement.
+  pub predicate: Term,
+  /// The object of the statement.
+  pub object: Term,
+  /// The graph of the statement, `None` for the default graph.
+  pub graph: Option<Term>,
+}
+
+impl Display for Quad {
+  /// Formats the quad as a line in canonical N-Quads form, including the trailing newline.
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{} {} {} ", self.subject, self.predicate, self.object)?;
+    if let Some(graph) = &self.graph {
+      write!(f, "{graph} ")?;
+    }
+    f.write_str(".\n")
+  }
+}
+
+impl<L: DocumentLoader> JsonLdProcessor<L> {
+  /// Converts `document` to the RDF dataset it represents, following the JSON-LD 1.1 deserialization algorithm.
+  ///
+  /// Like any JSON-LD processor, this drops properties with undefined terms and relative IRIs. Use
+  /// [`Self::validate`] beforehand to reject such documents.
+  pub fn to_rdf(&self, document: &Value) -> Result<Vec<Quad>> {
+    self.write_rdf(document, None)
+  }
+
+  /// Converts `document` to RDF like [`Self::to_rdf`], but labels blank nodes by their position in the document.
+  ///
+  /// A document containing a subset of the values of `document` at the same positions, with `null` in place of
+  /// omitted array items, therefore results in a subset of the quads of `document`.
+  #[cfg(feature = "data-integrity")]
+  pub(crate) fn to_rdf_positional(&self, document: &Value) -> Result<Vec<Quad>> {
+    self.write_rdf(document, Some(Vec::new()))
+  }
+
+  fn write_rdf(&self, document: &Value, path: Option<Vec<String>>) -> Result<Vec<Quad>> {
+    let mut writer: RdfWriter<'_, L> = RdfWriter {
+      processor: self,
+      quads: Vec::new(),
+      blank_nodes: 0,
+      path,
+    };
+    let active: ActiveContext = ActiveContext::default();
+    match document {
+      Value::Array(nodes) => {
+        for (index, node) in nodes.iter().enumerate() {
+          writer.enter(index);
+          writer.top_level(&active, node)?;
+          writer.leave();
+        }
+      }
+      node => writer.top_level(&active, node)?,
+    }
+    Ok(writer.quads)
+  }
+}
+
+struct RdfWriter<'a, L> {
+  processor: &'a JsonLdProcessor<L>,
+  quads: Vec<Quad>,
+  blank_nodes: usize,
+  /// The path of the value being converted, if blank nodes are labeled by their position in the document.
+  path: Option<Vec<String>>,
+}
+
+impl<L: DocumentLoader> RdfWriter<'_, L> {
+  fn enter(&mut self, segment: impl ToString) {
+    if let Some(path) = &mut self.path {
+      path.push(segment.to_string());
+    }
+  }
+
+  fn leave(&mut self) {
+    if let Some(path) = &mut self.path {
+      path.pop();
+    }
+  }
+
+  /// Returns a new blank node, distinguished by `suffix` from other blank nodes created at the same position.
+  fn blank_node(&mut self, suffix: &str) -> Term {
+    let Some(path) = &self.path else {
+      let label: String = format!("b{}", self.blank_nodes);
+      self.blank_nodes += 1;
+      return Term::BlankNode(label);
+    };
+
+    let mut position: String = String::new();
+    for segment in path {
+      position.push('/');
+      position.push_str(&segment.replace('~', "~0").replace('/', "~1").replace('#', "~2"));
+    }
+    position.push('#');
+    position.push_str(suffix);
+    let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+    SHA256(position.as_bytes(), &mut digest);
+    Term::BlankNode(format!("p{}", BaseEncoding::encode(&digest[..16], Base::Base16Lower)))
+  }
+
+  fn push(&mut self, subject: Term, predicate: Term, object: Term, graph: Option<&Term>) {
+    self.quads.push(Quad {
+      subject,
+      predicate,
+      object,
+      graph: graph.cloned(),
+    });
+  }
+
+  fn top_level(&mut self, active: &ActiveContext, node: &Value) -> Result<()> {
+    let Value::Object(object) = node else {
+      return Err(Error::InvalidContext(
+        "a JSON-LD document must be an object or an array of objects",
+      ));
+    };
+    // A top-level object with only `@graph` (and `@context`) holds the nodes of the default graph.
+    let context: NodeContext = self.processor.node_context(active, None, object)?;
+    let graph_only: bool = object
+      .keys()
+      .filter(|key| *key != "@context")
+      .all(|key| context.properties.expand_term(key, 0).as_deref() == Some("@graph"));
+    if graph_only {
+      for (key, nodes) in object.iter().filter(|(key, _)| *key != "@context") {
+        self.enter(key);
+        for (index, node) in as_slice(nodes).iter().enumerate() {
+          if let Value::Object(node) = node {
+            self.enter_item(nodes, index);
+            self.node(&context.properties, None, node, None)?;
+            self.leave_item(nodes);
+          }
+        }
+        self.leave();
+      }
+    } else {
+      self.node(active, None, object, None)?;
+    }
+    Ok(())
+  }
+
+  /// Enters the item at `index` of `value`, which is only a position of its own if `value` is an array.
+  fn enter_item(&mut self, value: &Value, index: usize) {
+    if value.is_array() {
+      self.enter(index);
+    }
+  }
+
+  fn leave_item(&mut self, value: &Value) {
+    if value.is_array() {
+      self.leave();
+    }
+  }
+
+  fn id(&self, context: &ActiveContext, id: &str) -> Option<Term> {
+    let id: String = context.expand_id(id)?;
+    Some(match id.strip_prefix("_:") {
+      Some(label) => Term::BlankNode(format!("j{label}")),
+      None => Term::Iri(id),
+    })
+  }
+
+  /// Writes the quads of the node `object`, the value of `property`, returning the subject of the node.
+  fn node(
+    &mut self,
+    active: &ActiveContext,
+    property: Option<&str>,
+    object: &Map<String, Value>,
+    graph: Option<&Term>,
+  ) -> Result<Term> {
+    let NodeContext { properties, types } = self.processor.node_context(active, property, object)?;
+
+    let id: Option<&str> = object
+      .iter()
+      .find(|(key, _)| properties.expand_term(key, 0).as_deref() == Some("@id"))
+      .and_then(|(_, id)| id.as_str());
+    let subject: Term = match id.and_then(|id| self.id(&properties, id)) {
+      Some(subject) => subject,
+      None => self.blank_node(""),
+    };
+
+    for (key, value) in object {
+      if key == "@context" {
+        continue;
+      }
+      let Some(iri) = properties.expand_term(key, 0) else {
+        continue;
+      };
+      self.enter(key);
+      match iri.as_str() {
+        "@type" => {
+          for type_ in as_slice(value).iter().filter_map(Value::as_str) {
+            if let Some(type_) = types.expand_term(type_, 0).and_then(|type_| self.id(&types, &type_)) {
+              self.push(subject.clone(), Term::Iri(RDF_TYPE.to_owned()), type_, graph);
+            }
+          }
+        }
+        "@graph" => {
+          for (index, node) in as_slice(value).iter().enumerate() {
+            if let Value::Object(node) = node {
+              self.enter_item(value, index);
+              self.node(&properties, None, node, Some(&subject))?;
+              self.leave_item(value);
+            }
+          }
+        }
+        keyword if keyword.starts_with('@') => {}
+        predicate if predicate.starts_with("_:") || !predicate.contains(':') => {}
+        predicate => {
+          let predicate: Term = Term::Iri(predicate.to_owned());
+          let is_list: bool = properties
+            .definition(key)
+            .map(|definition| definition.has_container("@list"))
+            .unwrap_or_default();
+          if is_list {
+            let list: Term = self.list(&properties, key, as_slice(value), graph)?;
+            self.push(subject.clone(), predicate, list, graph);
+          } else {
+            for (index, item) in flatten(value).into_iter().enumerate() {
+              self.enter_item(value, index);
+              let object: Option<Term> = self.object(&properties, key, item, graph)?;
+              self.leave_item(value);
+              if let Some(object) = object {
+                self.push(subject.clone(), predicate.clone(), object, graph);
+              }
+            }
+          }
+        }
+      }
+      self.leave();
+    }
+
+    Ok(subject)
+  }
+
+  /// Converts `value`, a value of `property`, to the object of a quad.
+  fn object(
+    &mut self,
+    context: &ActiveContext,
+    property: &str,
+    value: &Value,
+    graph: Option<&Term>,
+  ) -> Result<Option<Term>> {
+    let type_mapping: Option<String> = context.type_mapping(property);
+    if type_mapping.as_deref() == Some("@json") {
+      return json_literal(value).map(Some);
+    }
+
+    let object: &Map<String, Value> = match value {
+      Value::Null => return Ok(None),
+      Value::String(string) => {
+        return Ok(match type_mapping.as_deref() {
+          Some("@id") => self.id(context, string),
+          Some("@vocab") => context.expand_term(string, 0).and_then(|iri| self.id(context, &iri)),
+          Some(datatype) if !datatype.starts_with('@') => Some(Term::literal(string.as_str(), datatype)),
+          _ => Some(Term::literal(string.as_str(), XSD_STRING)),
+        });
+      }
+      Value::Bool(_) | Value::Number(_) => {
+        let datatype: Option<&str> = type_mapping.as_deref().filter(|datatype| !datatype.starts_with('@'));
+        return Ok(Some(native_literal(value, datatype)));
+      }
+      // Arrays are flattened unless they are items of a list, which makes them nested lists.
+      Value::Array(items) => return self.list(context, property, items, graph).map(Some),
+      Value::Object(object) => object,
+    };
+
+    let keyword = |keyword: &str| {
+      object
+        .iter()
+        .find(|(key, _)| context.expand_term(key, 0).as_deref() == Some(keyword))
+    };
+
+    if let Some((_, value)) = keyword("@value") {
+      let datatype: Option<String> = keyword("@type")
+        .and_then(|(_, datatype)| datatype.as_str())
+        .and_then(|datatype| context.expand_term(datatype, 0));
+      let language: Option<&str> = keyword("@language").and_then(|(_, language)| language.as_str());
+      return match (value, datatype, language) {
+        (Value::Null, _, _) => Ok(None),
+        (value, Some(datatype), _) if datatype == "@json" => json_literal(value).map(Some),
+        (Value::String(string), _, Some(language)) => Ok(Some(Term::Literal {
+          value: string.clone(),
+          datatype: RDF_LANG_STRING.to_owned(),
+          language: Some(language.to_ascii_lowercase()),
+        })),
+        (Value::String(string), datatype, None) => Ok(Some(Term::literal(
+          string.as_str(),
+          datatype.as_deref().unwrap_or(XSD_STRING),
+        ))),
+        (Value::Bool(_) | Value::Number(_), datatype, _) => Ok(Some(native_literal(value, datatype.as_deref()))),
+        _ => Err(Error::InvalidContext("@value must be a scalar")),
+      };
+    }
+
+    if let Some((key, items)) = keyword("@list") {
+      self.enter(key);
+      let list: Result<Term> = self.list(context, property, as_slice(items), graph);
+      self.leave();
+      return list.map(Some);
+    }
+
+    let is_graph: bool = context
+      .definition(property)
+      .map(|definition| definition.has_container("@graph"))
+      .unwrap_or_default();
+    if is_graph {
+      let name: Term = self.blank_node("graph");
+      self.node(context, Some(property), object, Some(&name))?;
+      Ok(Some(name))
+    } else {
+      self.node(context, Some(property), object, graph).map(Some)
+    }
+  }
+
+  fn list(&mut self, context: &ActiveContext, property: &str, items: &[Value], graph: Option<&Term>) -> Result<Term> {
+    let mut objects: Vec<Term> = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+      self.enter(index);
+      let object: Option<Term> = self.object(context, property, item, graph)?;
+      self.leave();
+      objects.extend(object);
+    }
+
+    let mut head: Term = Term::Iri(RDF_NIL.to_owned());
+    for (index, object) in objects.into_iter().enumerate().rev() {
+      let node: Term = self.blank_node(&format!("list{index}"));
+      self.push(node.clone(), Term::Iri(RDF_FIRST.to_owned()), object, graph);
+      self.push(node.clone(), Term::Iri(RDF_REST.to_owned()), head, graph);
+      head = node;
+    }
+    Ok(head)
+  }
+}
+
+/// Returns `value` as a slice of values, wrapping it if it is not an array.
+fn as_slice(value: &Value) -> &[Value] {
+  match value {
+    Value::Array(values) => values,
+    value => std::slice::from_ref(value),
+  }
+}
+
+/// Returns the values of `value`, flattening nested arrays.
+fn flatten(value: &Value) -> Vec<&Value> {
+  match value {
+    Value::Array(values) => values.iter().flat_map(flatten).collect(),
+    value => vec![value],
+  }
+}
+
+/// Converts a boolean or number to a literal with the given `datatype`, or its native datatype.
+fn native_literal(value: &Value, datatype: Option<&str>) -> Term {
+  match value {
+    Value::Bool(bool) => Term::literal(bool.to_string(), datatype.unwrap_or(XSD_BOOLEAN)),
+    Value::Number(number) => {
+      let is_double: bool = datatype == Some(XSD_DOUBLE) || !is_integral(number);
+      if is_double {
+        let double: f64 = number.as_f64().unwrap_or_default();
+        Term::literal(canonical_double(double), datatype.unwrap_or(XSD_DOUBLE))
+      } else {
+        let integer: String = match number.as_i64().map(i128::from).or(number.as_u64().map(i128::from)) {
+          Some(integer) => integer.to_string(),
+          None => format!("{:.0}", number.as_f64().unwrap_or_default()),
+        };
+        Term::literal(integer, datatype.unwrap_or(XSD_INTEGER))
+      }
+    }
+    _ => unreachable!("only called for booleans and numbers"),
+  }
+}
+
+/// Returns whether `number` has no fractional part and is small enough to be represented as `xsd:integer`.
+fn is_integral(number: &Number) -> bool {
+  if number.is_i64() || number.is_u64() {
+    return true;
+  }
+  let double: f64 = number.as_f64().unwrap_or_default();
+  double.fract() == 0.0 && double.abs() < 1e21
+}
+
+/// Formats `double` in the canonical lexical form of `xsd:double`, e.g. `1.5E1`.
+fn canonical_double(double: f64) -> String {
+  let formatted: String = format!("{double:E}");
+  match formatted.split_once('E') {
+    Some((mantissa, exponent)) if !mantissa.contains('.') => format!("{mantissa}.0E{exponent}"),
+    _ => formatted,
+  }
+}
+
+/// Converts `value` to an `rdf:JSON` literal of its canonical serialization.
+fn json_literal(value: &Value) -> Result<Term> {
+  let mut canonical: String = String::new();
+  crate::credential::write_canonical_json(value, &mut canonical)
+    .map_err(|_| Error::InvalidContext("JSON literal cannot be serialized"))?;
+  Ok(Term::literal(canonical, RDF_JSON))
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+  use crate::jsonld::ContextCache;
+
+  fn to_nquads(document: Value) -> String {
+    let processor: JsonLdProcessor<ContextCache> = JsonLdProcessor::new(ContextCache::new());
+    let mut lines: Vec<String> = processor
+      .to_rdf(&document)
+      .unwrap()
+      .iter()
+      .map(ToString::to_string)
+      .collect();
+    lines.sort();
+    lines.concat()
+  }
+
+  #[test]
+  fn test_literals_and_coercion() {
+    let nquads: String = to_nquads(json!({
+      "@context": {
+        "@vocab": "https://example.com/#",
+        "xsd": "http://www.w3.org/2001/XMLSchema#",
+        "issuer": { "@type": "@id" },
+        "issued": { "@type": "xsd:dateTime" },
+        "claims": { "@type": "@json" }
+      },
+      "@id": "did:example:123",
+      "@type": "Credential",
+      "issuer": "did:example:issuer",
+      "issued": "2024-01-01T00:00:00Z",
+      "name": "Alice \"A\"\n",
+      "age": 42,
+      "height": 1.5,
+      "verified": true,
+      "claims": { "b": 1, "a": [true, null] }
+    }));
+
+    assert_eq!(
+      nquads,
+      concat!(
+        "<did:example:123> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://example.com/#Credential> .\n",
+        "<did:example:123> <https://example.com/#age> \"42\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n",
+        "<did:example:123> <https://example.com/#claims> \"{\\\"a\\\":[true,null],\\\"b\\\":1}\"^^<http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON> .\n",
+        "<did:example:123> <https://example.com/#height> \"1.5E0\"^^<http://www.w3.org/2001/XMLSchema#double> .\n",
+        "<did:example:123> <https://example.com/#issued> \"2024-01-01T00:00:00Z\"^^<http://www.w3.org/2001/XMLSchema#dateTime> .\n",
+        "<did:example:123> <https://example.com/#issuer> <did:example:issuer> .\n",
+        "<did:example:123> <https://example.com/#name> \"Alice \\\"A\\\"\\n\" .\n",
+        "<did:example:123> <https://example.com/#verified> \"true\"^^<http://www.w3.org/2001/XMLSchema#boolean> .\n",
+      )
+    );
+  }
+
+  #[test]
+  fn test_nested_nodes_lists_and_graphs() {
+    let context: Value = json!({
+      "@vocab": "https://example.com/#",
+      "items": { "@container": "@list" },
+      "proof": { "@container": "@graph" }
+    });
+
+    let nquads: String = to_nquads(json!({
+      "@context": context,
+      "@id": "did:example:root",
+      "subject": { "@id": "did:example:bob", "name": "Bob" },
+      "items": ["x"]
+    }));
+    assert_eq!(
+      nquads,
+      concat!(
+        "<did:example:bob> <https://example.com/#name> \"Bob\" .\n",
+        "<did:example:root> <https://example.com/#items> _:b0 .\n",
+        "<did:example:root> <https://example.com/#subject> <did:example:bob> .\n",
+        "_:b0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"x\" .\n",
+        "_:b0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n",
+      )
+    );
+
+    let nquads: String = to_nquads(json!({
+      "@context": context,
+      "@id": "did:example:root",
+      "proof": { "@id": "did:example:proof", "value": "z" }
+    }));
+    assert_eq!(
+      nquads,
+      concat!(
+        "<did:example:proof> <https://example.com/#value> \"z\" _:b0 .\n",
+        "<did:example:root> <https://example.com/#proof> _:b0 .\n",
+      )
+    );
+  }
+}
@@ -16,9 +16,17 @@
 
 #[cfg(feature = "credential")]
 pub mod credential;
+#[cfg(feature = "data-integrity")]
+pub mod data_integrity;
 #[cfg(feature = "domain-linkage")]
 pub mod domain_linkage;
 pub mod error;
+#[cfg(feature = "validator")]
+pub mod format;
+#[cfg(feature = "iot")]
+pub mod iot;
+#[cfg(feature = "jsonld")]
+pub mod jsonld;
 #[cfg(feature = "presentation")]
 pub mod presentation;
 #[cfg(feature = "revocation-bitmap")]
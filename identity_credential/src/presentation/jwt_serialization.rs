@@ -71,6 +71,8 @@ where
       types,
       verifiable_credential,
       holder,
+      issuance_date,
+      expiration_date,
       refresh_service,
       terms_of_use,
       properties,
@@ -91,8 +93,10 @@ where
         proof: proof.as_ref().map(Cow::Borrowed),
         holder: None,
       },
-      exp: options.expiration_date.map(|expiration_date| expiration_date.to_unix()),
-      issuance_date: options.issuance_date.map(IssuanceDateClaims::new),
+      exp: expiration_date
+        .or(options.expiration_date)
+        .map(|expiration_date| expiration_date.to_unix()),
+      issuance_date: issuance_date.or(options.issuance_date).map(IssuanceDateClaims::new),
       aud: options.audience.clone(),
       custom: options.custom_claims.clone(),
     })
@@ -170,6 +174,8 @@ where
       types: types.into_owned(),
       verifiable_credential: verifiable_credential.into_owned(),
       holder: iss.into_owned(),
+      issuance_date: None,
+      expiration_date: None,
       refresh_service: refresh_service.into_owned(),
       terms_of_use: terms_of_use.into_owned(),
       properties: properties.into_owned(),
@@ -367,4 +373,52 @@ mod test {
       Error::InconsistentPresentationJwtClaims("inconsistent presentation id")
     ));
   }
+
+  #[test]
+  fn presentation_dates() {
+    let presentation_json: &str = r#"
+    {
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "type": "VerifiablePresentation",
+      "verifiableCredential": [],
+      "holder": "did:iota:tst:0x6a58aa12afcfa698a5bf59918369c0aa39955f1ae5a7e516bc6bd4dc2712d6c7",
+      "issuanceDate": "2023-09-14T13:42:31Z",
+      "expirationDate": "2023-09-14T13:52:31Z"
+    }
+    "#;
+    let presentation: Presentation<Jwt> = Presentation::from_json(presentation_json).unwrap();
+    let options = JwtPresentationOptions {
+      expiration_date: Some(Timestamp::from_unix(1694700000).unwrap()),
+      issuance_date: Some(Timestamp::from_unix(1694690000).unwrap()),
+      audience: None,
+      custom_claims: None,
+    };
+
+    // The dates of the presentation take precedence over those of the options.
+    let claims: Object = Object::from_json(
+      &PresentationJwtClaims::<'_, Jwt>::new(&presentation, &options)
+        .unwrap()
+        .to_json()
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(claims["nbf"], 1694698951);
+    assert_eq!(claims["exp"], 1694699551);
+    assert!(!claims["vp"].as_object().unwrap().contains_key("issuanceDate"));
+    assert!(!claims["vp"].as_object().unwrap().contains_key("expirationDate"));
+
+    // Without dates, those of the options are used.
+    let mut presentation: Presentation<Jwt> = presentation;
+    presentation.issuance_date = None;
+    presentation.expiration_date = None;
+    let claims: Object = Object::from_json(
+      &PresentationJwtClaims::<'_, Jwt>::new(&presentation, &options)
+        .unwrap()
+        .to_json()
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(claims["nbf"], 1694690000);
+    assert_eq!(claims["exp"], 1694700000);
+  }
 }
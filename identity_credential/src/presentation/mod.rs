@@ -13,12 +13,14 @@ mod jwt_presentation_options;
 mod jwt_serialization;
 mod presentation;
 mod presentation_builder;
+mod presentation_request;
 
 #[cfg(feature = "jpt-bbs-plus")]
 pub use self::jwp_presentation_builder::SelectiveDisclosurePresentation;
 pub use self::jwt_presentation_options::JwtPresentationOptions;
 pub use self::presentation::Presentation;
 pub use self::presentation_builder::PresentationBuilder;
+pub use self::presentation_request::PresentationRequest;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use jwp_presentation_options::JwpPresentationOptions;
 
@@ -11,6 +11,7 @@ use serde::Serialize;
 use identity_core::common::Context;
 use identity_core::common::Object;
 use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::FmtJson;
 use identity_core::convert::ToJson;
@@ -28,6 +29,7 @@ use super::PresentationBuilder;
 
 /// Represents a bundle of one or more [`Credential`]s.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Presentation<CRED, T = Object> {
   /// The JSON-LD context(s) applicable to the `Presentation`.
   #[serde(rename = "@context")]
@@ -40,10 +42,17 @@ pub struct Presentation<CRED, T = Object> {
   pub types: OneOrMany<String>,
   /// Credential(s) expressing the claims of the `Presentation`.
   #[rustfmt::skip]
+  #[cfg_attr(feature = "schemars", schemars(with = "OneOrMany<CRED>"))]
   #[serde(default = "Default::default", rename = "verifiableCredential", skip_serializing_if = "Vec::is_empty", deserialize_with = "deserialize_verifiable_credential", bound(deserialize = "CRED: serde::de::DeserializeOwned"))]
   pub verifiable_credential: Vec<CRED>,
   /// The entity that generated the `Presentation`.
   pub holder: Url,
+  /// A timestamp of when the `Presentation` becomes valid.
+  #[serde(rename = "issuanceDate", default, skip_serializing_if = "Option::is_none")]
+  pub issuance_date: Option<Timestamp>,
+  /// A timestamp of when the `Presentation` should no longer be considered valid.
+  #[serde(rename = "expirationDate", default, skip_serializing_if = "Option::is_none")]
+  pub expiration_date: Option<Timestamp>,
   /// Service(s) used to refresh an expired [`Credential`] in the `Presentation`.
   #[serde(default, rename = "refreshService", skip_serializing_if = "OneOrMany::is_empty")]
   pub refresh_service: OneOrMany<RefreshService>,
@@ -96,6 +105,8 @@ impl<CRED, T> Presentation<CRED, T> {
       types: builder.types.into(),
       verifiable_credential: builder.credentials,
       holder: builder.holder,
+      issuance_date: builder.issuance_date,
+      expiration_date: builder.expiration_date,
       refresh_service: builder.refresh_service.into(),
       terms_of_use: builder.terms_of_use.into(),
       properties: builder.properties,
@@ -123,13 +134,23 @@ impl<CRED, T> Presentation<CRED, T> {
     if !self.types.iter().any(|type_| type_ == Self::base_type()) {
       return Err(Error::MissingBaseType);
     }
+
+    // The presentation must not expire before it becomes valid
+    if let (Some(issuance_date), Some(expiration_date)) = (self.issuance_date, self.expiration_date) {
+      if expiration_date < issuance_date {
+        return Err(Error::InvalidPresentationValidityPeriod);
+      }
+    }
     Ok(())
   }
 
   /// Serializes the [`Presentation`] as a JWT claims set
   /// in accordance with [VC Data Model v1.1](https://www.w3.org/TR/vc-data-model/#json-web-token).
   ///
-  /// The resulting string can be used as the payload of a JWS when issuing the credential.  
+  /// The resulting string can be used as the payload of a JWS when issuing the credential.
+  ///
+  /// The `issuanceDate` and `expirationDate` of the presentation are encoded as the `nbf` and `exp` claims, taking
+  /// precedence over the dates set in `options`.
   pub fn serialize_jwt(&self, options: &JwtPresentationOptions) -> Result<String>
   where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
@@ -3,6 +3,7 @@
 
 use identity_core::common::Context;
 use identity_core::common::Object;
+use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::common::Value;
 
@@ -20,6 +21,8 @@ pub struct PresentationBuilder<CRED, T = Object> {
   pub(crate) types: Vec<String>,
   pub(crate) credentials: Vec<CRED>,
   pub(crate) holder: Url,
+  pub(crate) issuance_date: Option<Timestamp>,
+  pub(crate) expiration_date: Option<Timestamp>,
   pub(crate) refresh_service: Vec<RefreshService>,
   pub(crate) terms_of_use: Vec<Policy>,
   pub(crate) properties: T,
@@ -34,6 +37,8 @@ impl<CRED, T> PresentationBuilder<CRED, T> {
       types: vec![Presentation::<T>::base_type().into()],
       credentials: Vec::new(),
       holder,
+      issuance_date: None,
+      expiration_date: None,
       refresh_service: Vec::new(),
       terms_of_use: Vec::new(),
       properties,
@@ -68,6 +73,20 @@ impl<CRED, T> PresentationBuilder<CRED, T> {
     self
   }
 
+  /// Sets the value of the `Presentation` `issuanceDate`.
+  #[must_use]
+  pub fn issuance_date(mut self, value: Timestamp) -> Self {
+    self.issuance_date = Some(value);
+    self
+  }
+
+  /// Sets the value of the `Presentation` `expirationDate`.
+  #[must_use]
+  pub fn expiration_date(mut self, value: Timestamp) -> Self {
+    self.expiration_date = Some(value);
+    self
+  }
+
   /// Adds a value to the `refreshService` set.
   #[must_use]
   pub fn refresh_service(mut self, value: RefreshService) -> Self {
@@ -121,6 +140,7 @@ mod tests {
   use serde_json::Value;
 
   use identity_core::common::Object;
+  use identity_core::common::Timestamp;
   use identity_core::common::Url;
   use identity_core::convert::FromJson;
 
@@ -130,6 +150,7 @@ mod tests {
   use crate::credential::Subject;
   use crate::presentation::Presentation;
   use crate::presentation::PresentationBuilder;
+  use crate::Error;
 
   fn subject() -> Subject {
     let json: Value = json!({
@@ -192,4 +213,25 @@ mod tests {
     assert_eq!(presentation.types.get(1).unwrap(), "ExamplePresentation");
     assert_eq!(presentation.verifiable_credential.len(), 0);
   }
+
+  #[test]
+  fn test_presentation_builder_validity_period() {
+    let issuance_date: Timestamp = Timestamp::parse("2023-09-14T13:42:31Z").unwrap();
+    let expiration_date: Timestamp = Timestamp::parse("2023-09-14T13:52:31Z").unwrap();
+
+    let presentation: Presentation<Jwt> = PresentationBuilder::new(Url::parse("did:test:abc1").unwrap(), Object::new())
+      .issuance_date(issuance_date)
+      .expiration_date(expiration_date)
+      .build()
+      .unwrap();
+    assert_eq!(presentation.issuance_date, Some(issuance_date));
+    assert_eq!(presentation.expiration_date, Some(expiration_date));
+
+    let result: Result<Presentation<Jwt>, _> =
+      PresentationBuilder::new(Url::parse("did:test:abc1").unwrap(), Object::new())
+        .issuance_date(expiration_date)
+        .expiration_date(issuance_date)
+        .build();
+    assert!(matches!(result, Err(Error::InvalidPresentationValidityPeriod)));
+  }
 }
@@ -0,0 +1,103 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A verifier's request for a verifiable presentation.
+///
+/// Carries the challenge the holder binds the presentation to, such that the presentation cannot be replayed to
+/// other verifiers or at a later time.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PresentationRequest {
+  /// A unique random challenge, expected as the `nonce` of the protected header of the presentation JWT.
+  pub nonce: String,
+  /// The verifier, expected as the `aud` claim of the presentation JWT.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub audience: Option<Url>,
+  /// The point in time after which the presentation must no longer be considered valid.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub expiration_date: Option<Timestamp>,
+  /// The types of the requested credentials. Any credential is requested if empty.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub credential_types: Vec<String>,
+}
+
+impl PresentationRequest {
+  /// Creates a new [`PresentationRequest`] with the given `nonce`.
+  pub fn new(nonce: impl Into<String>) -> Self {
+    Self {
+      nonce: nonce.into(),
+      audience: None,
+      expiration_date: None,
+      credential_types: Vec::new(),
+    }
+  }
+
+  /// Sets the audience of the presentation.
+  #[must_use]
+  pub fn audience(mut self, value: Url) -> Self {
+    self.audience = Some(value);
+    self
+  }
+
+  /// Sets the expiration date of the presentation.
+  #[must_use]
+  pub fn expiration_date(mut self, value: Timestamp) -> Self {
+    self.expiration_date = Some(value);
+    self
+  }
+
+  /// Adds a requested credential type.
+  #[must_use]
+  pub fn credential_type(mut self, value: impl Into<String>) -> Self {
+    self.credential_types.push(value.into());
+    self
+  }
+
+  /// Returns whether a credential with the given `types` satisfies the request, i.e. whether one of them is
+  /// requested or no types are requested at all.
+  pub fn requests<'a>(&self, types: impl IntoIterator<Item = &'a str>) -> bool {
+    self.credential_types.is_empty()
+      || types
+        .into_iter()
+        .any(|type_| self.credential_types.iter().any(|requested| requested == type_))
+  }
+
+  /// Returns the options to validate a presentation responding to this request with, expecting its `nonce`.
+  ///
+  /// The audience of the validated presentation must still be compared with
+  /// [`DecodedJwtPresentation::aud`](crate::validator::DecodedJwtPresentation::aud).
+  #[cfg(feature = "validator")]
+  pub fn validation_options(&self) -> crate::validator::JwtPresentationValidationOptions {
+    crate::validator::JwtPresentationValidationOptions::new().presentation_verifier_options(
+      identity_document::verifiable::JwsVerificationOptions::new().nonce(self.nonce.clone()),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use super::*;
+
+  #[test]
+  fn test_requests() {
+    let request: PresentationRequest = PresentationRequest::new("nonce").credential_type("UniversityDegreeCredential");
+    assert!(request.requests(["VerifiableCredential", "UniversityDegreeCredential"]));
+    assert!(!request.requests(["VerifiableCredential"]));
+    assert!(PresentationRequest::new("nonce").requests(["VerifiableCredential"]));
+
+    let request: PresentationRequest = request.audience(Url::parse("did:example:verifier").unwrap());
+    assert_eq!(
+      PresentationRequest::from_json(&request.to_json().unwrap()).unwrap(),
+      request
+    );
+  }
+}
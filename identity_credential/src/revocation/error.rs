@@ -21,6 +21,11 @@ pub enum RevocationError {
   /// Indicates a failure to decode a bitmap from a base64 string representation.
   #[error("unable to decode base64 string: `{0}`")]
   Base64DecodingError(String, #[source] identity_core::error::Error),
+  /// Indicates an invalid revocation id or non-revocation proof of a `MerkleRevocationList2024`, or a credential
+  /// that is revoked.
+  #[cfg(feature = "merkle-revocation")]
+  #[error("merkle revocation: {0}")]
+  MerkleRevocationError(&'static str),
   #[error("could not parse url")]
   #[non_exhaustive]
   /// Indicates a failure to construct a URL when attempting to construct a `ServiceEndpoint`.
@@ -0,0 +1,10 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Revocation with sparse Merkle trees, publishing only the root of the issuer's revocation list.
+
+mod revocation_list;
+mod revocation_status;
+
+pub use revocation_list::*;
+pub use revocation_status::*;
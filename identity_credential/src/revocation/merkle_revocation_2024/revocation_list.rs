@@ -0,0 +1,454 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+use std::collections::BTreeSet;
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_document::service::Service;
+use identity_document::service::ServiceEndpoint;
+use identity_document::utils::DIDUrlQuery;
+use identity_document::utils::Queryable;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::revocation::RevocationError;
+use crate::revocation::RevocationResult;
+
+const DATA_URL_PATTERN: &str = "data:application/octet-stream;base64,";
+
+/// The depth of the sparse Merkle tree, i.e. the length of a [`RevocationId`] in bits.
+const DEPTH: usize = SHA256_LEN * 8;
+
+/// The value of a leaf whose credential is not revoked.
+const EMPTY_LEAF: Digest = [0; SHA256_LEN];
+
+/// Domain separation prefixes of leaves and inner nodes.
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+type Digest = [u8; SHA256_LEN];
+
+/// The identifier of a credential in a [`MerkleRevocationList`], determining the position of its leaf in the tree.
+///
+/// Identifiers must be chosen at random, see [`RevocationId::random`], so that they reveal nothing about the number
+/// of credentials issued. They are displayed, parsed and serialized as unpadded base64url.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RevocationId([u8; SHA256_LEN]);
+
+impl RevocationId {
+  /// Generates a new random identifier.
+  pub fn random() -> RevocationResult<Self> {
+    let mut bytes: Digest = [0; SHA256_LEN];
+    crypto::utils::rand::fill(&mut bytes)
+      .map_err(|_| RevocationError::MerkleRevocationError("failed to generate a revocation id"))?;
+    Ok(Self(bytes))
+  }
+
+  /// Creates an identifier from its byte representation.
+  pub fn from_bytes(bytes: [u8; SHA256_LEN]) -> Self {
+    Self(bytes)
+  }
+
+  /// Returns the byte representation of the identifier.
+  pub fn as_bytes(&self) -> &[u8; SHA256_LEN] {
+    &self.0
+  }
+}
+
+impl Display for RevocationId {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(&BaseEncoding::encode(&self.0, Base::Base64Url))
+  }
+}
+
+impl FromStr for RevocationId {
+  type Err = RevocationError;
+
+  fn from_str(s: &str) -> RevocationResult<Self> {
+    decode_digest(s)
+      .map(Self)
+      .ok_or(RevocationError::MerkleRevocationError("invalid revocation id"))
+  }
+}
+
+impl Serialize for RevocationId {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for RevocationId {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let encoded: String = String::deserialize(deserializer)?;
+    encoded.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+/// A revocation list committing to the revoked credentials of an issuer with the root of a sparse Merkle tree.
+///
+/// Unlike [`RevocationBitmap`](crate::revocation::RevocationBitmap) and status lists, only the
+/// [`MerkleRevocationRoot`] is published, e.g. in a `MerkleRevocationList2024` service of the issuer's DID document,
+/// which reveals neither the number of issued nor of revoked credentials. The list itself is kept by the issuer, who
+/// hands each holder a [`NonRevocationProof`] for their credential.
+///
+/// Every revocation rotates the root and invalidates all outstanding proofs, so holders must obtain a fresh proof
+/// from the issuer after each rotation. Issuing credentials does not affect the root.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MerkleRevocationList {
+  revoked: BTreeSet<RevocationId>,
+}
+
+impl MerkleRevocationList {
+  /// The name of the service type.
+  pub const TYPE: &'static str = "MerkleRevocationList2024";
+
+  /// Constructs a new empty [`MerkleRevocationList`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns `true` if the credential with the given `id` is revoked.
+  pub fn is_revoked(&self, id: &RevocationId) -> bool {
+    self.revoked.contains(id)
+  }
+
+  /// Mark the credential with the given `id` as revoked.
+  ///
+  /// Returns true if the credential was not revoked before.
+  pub fn revoke(&mut self, id: RevocationId) -> bool {
+    self.revoked.insert(id)
+  }
+
+  /// Mark the credential with the given `id` as not revoked.
+  ///
+  /// Returns true if the credential was revoked before.
+  pub fn unrevoke(&mut self, id: &RevocationId) -> bool {
+    self.revoked.remove(id)
+  }
+
+  /// Returns the number of revoked credentials.
+  pub fn len(&self) -> usize {
+    self.revoked.len()
+  }
+
+  /// Returns `true` if no credentials are revoked, `false` otherwise.
+  pub fn is_empty(&self) -> bool {
+    self.revoked.is_empty()
+  }
+
+  /// Returns the root of the list.
+  pub fn root(&self) -> MerkleRevocationRoot {
+    MerkleRevocationRoot(subtree_root(&self.leaves(), 0, &default_hashes()))
+  }
+
+  /// Returns the proof that the credential with the given `id` is not revoked under the current root, or `None` if
+  /// it is revoked.
+  pub fn non_revocation_proof(&self, id: &RevocationId) -> Option<NonRevocationProof> {
+    if self.is_revoked(id) {
+      return None;
+    }
+
+    let defaults: Vec<Digest> = default_hashes();
+    let leaves: Vec<Digest> = self.leaves();
+    let mut levels: Digest = [0; SHA256_LEN];
+    let mut siblings: Vec<String> = Vec::new();
+    let mut subtree: &[Digest] = &leaves;
+    for depth in 0..DEPTH {
+      let (left, right) = subtree.split_at(subtree.partition_point(|leaf| !bit(leaf, depth)));
+      let (next, sibling) = if bit(id.as_bytes(), depth) {
+        (right, left)
+      } else {
+        (left, right)
+      };
+      if !sibling.is_empty() {
+        set_bit(&mut levels, depth);
+        siblings.push(BaseEncoding::encode(
+          &subtree_root(sibling, depth + 1, &defaults),
+          Base::Base64Url,
+        ));
+      }
+      subtree = next;
+    }
+    // Siblings are ordered from the leaf to the root.
+    siblings.reverse();
+
+    Some(NonRevocationProof {
+      levels: BaseEncoding::encode(&levels, Base::Base64Url),
+      siblings,
+    })
+  }
+
+  /// Return a [`Service`] with:
+  /// - the service's id set to `service_id`,
+  /// - of type `MerkleRevocationList2024`,
+  /// - and with the current root embedded in a data url in the service's endpoint.
+  pub fn to_service(&self, service_id: DIDUrl) -> RevocationResult<Service> {
+    Service::builder(Object::new())
+      .id(service_id)
+      .type_(Self::TYPE)
+      .service_endpoint(self.root().to_endpoint()?)
+      .build()
+      .map_err(|_| RevocationError::InvalidService("service builder error"))
+  }
+
+  /// Rotates the root published in the `MerkleRevocationList2024` service of `document` identified by
+  /// `service_query` to the current root of the list.
+  pub fn rotate_root<'query, Q>(&self, document: &mut CoreDocument, service_query: Q) -> RevocationResult<()>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    let service: &mut Service = document
+      .service_mut_unchecked()
+      .query_mut(service_query)
+      .ok_or(RevocationError::InvalidService("invalid id - service not found"))?;
+    // Only the roots of `MerkleRevocationList2024` services may be rotated.
+    MerkleRevocationRoot::try_from(&*service)?;
+
+    *service.service_endpoint_mut() = self.root().to_endpoint()?;
+    Ok(())
+  }
+
+  fn leaves(&self) -> Vec<Digest> {
+    self.revoked.iter().map(|id| id.0).collect()
+  }
+}
+
+/// The root of a [`MerkleRevocationList`], as published by the issuer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MerkleRevocationRoot(Digest);
+
+impl MerkleRevocationRoot {
+  /// Returns the bytes of the root.
+  pub fn as_bytes(&self) -> &[u8; SHA256_LEN] {
+    &self.0
+  }
+
+  /// Verifies that `proof` shows that the credential with the given `id` is not revoked under this root.
+  ///
+  /// # Errors
+  /// Fails if the proof is malformed, if the credential is revoked, or if the proof was issued for a previous root.
+  pub fn verify(&self, id: &RevocationId, proof: &NonRevocationProof) -> RevocationResult<()> {
+    let levels: Digest =
+      decode_digest(&proof.levels).ok_or(RevocationError::MerkleRevocationError("invalid proof levels"))?;
+    let mut siblings = proof.siblings.iter();
+
+    let defaults: Vec<Digest> = default_hashes();
+    let mut hash: Digest = EMPTY_LEAF;
+    for depth in (0..DEPTH).rev() {
+      let sibling: Digest = if bit(&levels, depth) {
+        siblings
+          .next()
+          .and_then(|sibling| decode_digest(sibling))
+          .ok_or(RevocationError::MerkleRevocationError(
+            "missing or invalid proof sibling",
+          ))?
+      } else {
+        defaults[DEPTH - 1 - depth]
+      };
+      hash = if bit(id.as_bytes(), depth) {
+        node_hash(&sibling, &hash)
+      } else {
+        node_hash(&hash, &sibling)
+      };
+    }
+    if siblings.next().is_some() {
+      return Err(RevocationError::MerkleRevocationError("unexpected proof sibling"));
+    }
+
+    if hash != self.0 {
+      return Err(RevocationError::MerkleRevocationError(
+        "credential is revoked or the proof does not match the current root",
+      ));
+    }
+    Ok(())
+  }
+
+  /// Return the root as a data url embedded in a service endpoint.
+  pub(crate) fn to_endpoint(self) -> RevocationResult<ServiceEndpoint> {
+    let data_url = format!("{DATA_URL_PATTERN}{}", BaseEncoding::encode(&self.0, Base::Base64Url));
+    Url::parse(data_url)
+      .map(ServiceEndpoint::One)
+      .map_err(|e| RevocationError::UrlConstructionError(e.into()))
+  }
+}
+
+impl TryFrom<&Service> for MerkleRevocationRoot {
+  type Error = RevocationError;
+
+  /// Try to extract the root from a `MerkleRevocationList2024` service.
+  fn try_from(service: &Service) -> RevocationResult<Self> {
+    if !service.type_().contains(MerkleRevocationList::TYPE) {
+      return Err(RevocationError::InvalidService(
+        "invalid type - expected `MerkleRevocationList2024`",
+      ));
+    }
+    let ServiceEndpoint::One(url) = service.service_endpoint() else {
+      return Err(RevocationError::InvalidService(
+        "invalid endpoint - expected a single data url",
+      ));
+    };
+    url
+      .as_str()
+      .strip_prefix(DATA_URL_PATTERN)
+      .and_then(decode_digest)
+      .map(Self)
+      .ok_or(RevocationError::InvalidService(
+        "invalid url - expected an `application/octet-stream;base64` data url of 32 bytes",
+      ))
+  }
+}
+
+/// A proof that a credential is not revoked under a [`MerkleRevocationRoot`], obtained from the issuer with
+/// [`MerkleRevocationList::non_revocation_proof`].
+///
+/// The proof consists of the siblings of the path from the credential's leaf to the root that differ from the root
+/// of an empty subtree, together with a bitmap of the levels at which they occur.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NonRevocationProof {
+  levels: String,
+  siblings: Vec<String>,
+}
+
+fn decode_digest(encoded: &str) -> Option<Digest> {
+  BaseEncoding::decode(encoded, Base::Base64Url)
+    .ok()
+    .and_then(|bytes| Digest::try_from(bytes).ok())
+}
+
+/// Returns the bit of `key` at `depth`, most significant bit first.
+fn bit(key: &Digest, depth: usize) -> bool {
+  (key[depth / 8] >> (7 - depth % 8)) & 1 == 1
+}
+
+fn set_bit(key: &mut Digest, depth: usize) {
+  key[depth / 8] |= 1 << (7 - depth % 8);
+}
+
+fn leaf_hash(id: &Digest) -> Digest {
+  let mut hash: Digest = [0; SHA256_LEN];
+  SHA256(&[[LEAF_PREFIX].as_slice(), id.as_slice()].concat(), &mut hash);
+  hash
+}
+
+fn node_hash(left: &Digest, right: &Digest) -> Digest {
+  let mut hash: Digest = [0; SHA256_LEN];
+  SHA256(
+    &[[NODE_PREFIX].as_slice(), left.as_slice(), right.as_slice()].concat(),
+    &mut hash,
+  );
+  hash
+}
+
+/// Returns the roots of empty subtrees, indexed by their height.
+fn default_hashes() -> Vec<Digest> {
+  let mut defaults: Vec<Digest> = Vec::with_capacity(DEPTH + 1);
+  defaults.push(EMPTY_LEAF);
+  for height in 1..=DEPTH {
+    defaults.push(node_hash(&defaults[height - 1], &defaults[height - 1]));
+  }
+  defaults
+}
+
+/// Computes the root of the subtree at `depth` containing the sorted `leaves`.
+fn subtree_root(leaves: &[Digest], depth: usize, defaults: &[Digest]) -> Digest {
+  match leaves {
+    [] => defaults[DEPTH - depth],
+    [leaf] if depth == DEPTH => leaf_hash(leaf),
+    _ => {
+      let (left, right) = leaves.split_at(leaves.partition_point(|leaf| !bit(leaf, depth)));
+      node_hash(
+        &subtree_root(left, depth + 1, defaults),
+        &subtree_root(right, depth + 1, defaults),
+      )
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+
+  use super::*;
+
+  fn id(byte: u8) -> RevocationId {
+    RevocationId::from_bytes([byte; SHA256_LEN])
+  }
+
+  #[test]
+  fn test_empty_list_root() {
+    let defaults: Vec<Digest> = default_hashes();
+    assert_eq!(MerkleRevocationList::new().root().as_bytes(), &defaults[DEPTH]);
+  }
+
+  #[test]
+  fn test_non_revocation_proofs() {
+    let mut list = MerkleRevocationList::new();
+    for byte in [0x01, 0x80, 0x81, 0xfe] {
+      assert!(list.revoke(id(byte)));
+    }
+    let root: MerkleRevocationRoot = list.root();
+
+    for byte in [0x00, 0x02, 0x7f, 0x82, 0xff] {
+      let proof: NonRevocationProof = list.non_revocation_proof(&id(byte)).unwrap();
+      root.verify(&id(byte), &proof).unwrap();
+      // The proof is bound to the credential.
+      assert!(root.verify(&id(0x01), &proof).is_err());
+    }
+    assert!(list.non_revocation_proof(&id(0x80)).is_none());
+  }
+
+  #[test]
+  fn test_root_rotation_invalidates_proofs() {
+    let mut list = MerkleRevocationList::new();
+    let proof: NonRevocationProof = list.non_revocation_proof(&id(0x42)).unwrap();
+    list.root().verify(&id(0x42), &proof).unwrap();
+
+    // A proof for a revoked credential cannot be forged from an earlier one.
+    list.revoke(id(0x42));
+    assert!(list.root().verify(&id(0x42), &proof).is_err());
+
+    // Other holders need a fresh proof after a rotation.
+    let other: NonRevocationProof = list.non_revocation_proof(&id(0x43)).unwrap();
+    list.revoke(id(0x44));
+    assert!(list.root().verify(&id(0x43), &other).is_err());
+    let other: NonRevocationProof = list.non_revocation_proof(&id(0x43)).unwrap();
+    list.root().verify(&id(0x43), &other).unwrap();
+  }
+
+  #[test]
+  fn test_service_roundtrip_and_rotation() {
+    let mut document: CoreDocument = CoreDocument::from_json(r#"{"id": "did:example:1234"}"#).unwrap();
+    let service_id: DIDUrl = DIDUrl::parse("did:example:1234#merkle-revocation").unwrap();
+    let mut list = MerkleRevocationList::new();
+    document
+      .insert_service(list.to_service(service_id.clone()).unwrap())
+      .unwrap();
+
+    list.revoke(id(0x01));
+    list.rotate_root(&mut document, &service_id).unwrap();
+    let service: &Service = document.resolve_service(&service_id).unwrap();
+    assert_eq!(MerkleRevocationRoot::try_from(service).unwrap(), list.root());
+  }
+
+  #[test]
+  fn test_revocation_id_serialization() {
+    let id: RevocationId = RevocationId::random().unwrap();
+    assert_eq!(id.to_string().parse::<RevocationId>().unwrap(), id);
+    let json: String = serde_json::to_string(&id).unwrap();
+    assert_eq!(serde_json::from_str::<RevocationId>(&json).unwrap(), id);
+    assert!("AAAA".parse::<RevocationId>().is_err());
+  }
+}
@@ -0,0 +1,150 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+
+use super::MerkleRevocationList;
+use super::MerkleRevocationRoot;
+use super::NonRevocationProof;
+use super::RevocationId;
+use crate::credential::Status;
+use crate::error::Error;
+use crate::error::Result;
+use crate::revocation::RevocationError;
+use crate::revocation::RevocationResult;
+
+/// Information used to determine the current status of a [`Credential`][crate::credential::Credential]
+/// using a [`MerkleRevocationList`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleRevocationStatus(Status);
+
+impl MerkleRevocationStatus {
+  const REVOCATION_ID_PROPERTY: &'static str = "revocationId";
+
+  /// Creates a new `MerkleRevocationStatus` of the credential with the given `revocation_id`, whose root is
+  /// published in the service identified by `id`.
+  pub fn new(id: DIDUrl, revocation_id: &RevocationId) -> Self {
+    let mut object = Object::new();
+    object.insert(
+      Self::REVOCATION_ID_PROPERTY.to_owned(),
+      Value::String(revocation_id.to_string()),
+    );
+    MerkleRevocationStatus(Status::new_with_properties(
+      Url::from(id),
+      MerkleRevocationList::TYPE.to_owned(),
+      object,
+    ))
+  }
+
+  /// Returns the [`DIDUrl`] of the `MerkleRevocationStatus`, which should resolve
+  /// to a `MerkleRevocationList2024` service in a DID Document.
+  pub fn id(&self) -> Result<DIDUrl> {
+    DIDUrl::parse(self.0.id.as_str())
+      .map_err(|err| Error::InvalidStatus(format!("invalid DID Url '{}': {:?}", self.0.id, err)))
+  }
+
+  /// Returns the identifier of the credential in the issuer's revocation list.
+  pub fn revocation_id(&self) -> Result<RevocationId> {
+    match self.0.properties.get(Self::REVOCATION_ID_PROPERTY) {
+      Some(Value::String(revocation_id)) => revocation_id
+        .parse()
+        .map_err(|err| Error::InvalidStatus(format!("invalid {}: {err}", Self::REVOCATION_ID_PROPERTY))),
+      _ => Err(Error::InvalidStatus(format!(
+        "expected {} to be a base64url encoded string",
+        Self::REVOCATION_ID_PROPERTY
+      ))),
+    }
+  }
+
+  /// Checks that `proof` shows that the credential is not revoked under the root published by `issuer`.
+  ///
+  /// # Errors
+  /// Fails if the status does not reference a valid `MerkleRevocationList2024` service of `issuer`, or if the proof
+  /// is invalid, e.g. because the credential is revoked or the root was rotated since the proof was issued.
+  pub fn check(&self, issuer: &CoreDocument, proof: &NonRevocationProof) -> RevocationResult<()> {
+    let invalid_status = |_| RevocationError::MerkleRevocationError("invalid credential status");
+    let service_id: DIDUrl = self.id().map_err(invalid_status)?;
+    let revocation_id: RevocationId = self.revocation_id().map_err(invalid_status)?;
+
+    let root: MerkleRevocationRoot = issuer
+      .resolve_service(&service_id)
+      .ok_or(RevocationError::InvalidService(
+        "merkle revocation list service not found",
+      ))
+      .and_then(MerkleRevocationRoot::try_from)?;
+    root.verify(&revocation_id, proof)
+  }
+}
+
+impl TryFrom<Status> for MerkleRevocationStatus {
+  type Error = Error;
+
+  fn try_from(status: Status) -> Result<Self> {
+    if status.type_ != MerkleRevocationList::TYPE {
+      return Err(Error::InvalidStatus(format!(
+        "expected type '{}', got '{}'",
+        MerkleRevocationList::TYPE,
+        status.type_
+      )));
+    }
+
+    let status = Self(status);
+    status.revocation_id()?;
+    Ok(status)
+  }
+}
+
+impl From<MerkleRevocationStatus> for Status {
+  fn from(status: MerkleRevocationStatus) -> Self {
+    status.0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+
+  use super::*;
+
+  #[test]
+  fn test_check_status() {
+    let service_id: DIDUrl = DIDUrl::parse("did:example:1234#merkle-revocation").unwrap();
+    let revocation_id: RevocationId = RevocationId::random().unwrap();
+    let mut list = MerkleRevocationList::new();
+    let mut issuer: CoreDocument = CoreDocument::from_json(r#"{"id": "did:example:1234"}"#).unwrap();
+    issuer
+      .insert_service(list.to_service(service_id.clone()).unwrap())
+      .unwrap();
+
+    let status: MerkleRevocationStatus = MerkleRevocationStatus::try_from(Status::from(MerkleRevocationStatus::new(
+      service_id.clone(),
+      &revocation_id,
+    )))
+    .unwrap();
+    assert_eq!(status.revocation_id().unwrap(), revocation_id);
+
+    let proof: NonRevocationProof = list.non_revocation_proof(&revocation_id).unwrap();
+    status.check(&issuer, &proof).unwrap();
+
+    list.revoke(revocation_id);
+    list.rotate_root(&mut issuer, &service_id).unwrap();
+    assert!(status.check(&issuer, &proof).is_err());
+  }
+
+  #[test]
+  fn test_invalid_status() {
+    let mut status: Status = MerkleRevocationStatus::new(
+      DIDUrl::parse("did:example:1234#merkle-revocation").unwrap(),
+      &RevocationId::from_bytes([0; 32]),
+    )
+    .into();
+    status
+      .properties
+      .insert("revocationId".to_owned(), Value::String("AAAA".to_owned()));
+    assert!(MerkleRevocationStatus::try_from(status).is_err());
+  }
+}
@@ -5,6 +5,8 @@
 //! framework.
 
 mod error;
+#[cfg(feature = "merkle-revocation")]
+mod merkle_revocation_2024;
 mod revocation_bitmap_2022;
 #[cfg(feature = "status-list-2021")]
 pub mod status_list_2021;
@@ -14,6 +16,8 @@ pub mod validity_timeframe_2024;
 
 pub use self::error::RevocationError;
 pub use self::error::RevocationResult;
+#[cfg(feature = "merkle-revocation")]
+pub use merkle_revocation_2024::*;
 pub use revocation_bitmap_2022::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use validity_timeframe_2024::*;
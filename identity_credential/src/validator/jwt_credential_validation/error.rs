@@ -76,6 +76,10 @@ pub enum JwtValidationError {
   #[non_exhaustive]
   DocumentMismatch(SignerContext),
 
+  /// Indicates that no version of the issuer's DID Document is known for the time a credential is validated against.
+  #[error("no version of the issuer's DID Document is known at the required time")]
+  MissingIssuerDocumentVersion,
+
   /// Indicates that the structure of the [Credential](crate::credential::Credential) is not semantically
   /// correct.
   #[error("the credential's structure is not semantically correct")]
@@ -104,6 +108,35 @@ pub enum JwtValidationError {
   /// Indicates that the credential has been suspended.
   #[error("credential has been suspended")]
   Suspended,
+  /// Indicates that the issuer of a credential is not trusted by a
+  /// [`TrustRegistry`](crate::validator::TrustRegistry) or not accepted by a
+  /// [`VerificationPolicy`](crate::validator::VerificationPolicy).
+  #[error("the issuer of the credential is not trusted")]
+  UntrustedIssuer,
+  /// Indicates that none of the types of a credential is accepted by a
+  /// [`VerificationPolicy`](crate::validator::VerificationPolicy).
+  #[error("the credential type is not accepted")]
+  CredentialType,
+  /// Indicates that a credential was issued earlier than allowed by a
+  /// [`VerificationPolicy`](crate::validator::VerificationPolicy).
+  #[error("the credential is older than allowed")]
+  CredentialAge,
+  /// Indicates that the format of a credential is not supported, i.e. not registered with the
+  /// [`CredentialFormatRegistry`](crate::format::CredentialFormatRegistry).
+  #[error("unsupported credential format")]
+  UnsupportedCredentialFormat,
+  /// Indicates that the issuer's signature of a credential in a format other than JWT could not be verified.
+  #[error("could not verify the issuer's signature of a `{format}` credential")]
+  FormatSignature {
+    /// The identifier of the format.
+    format: String,
+    /// Signature verification error.
+    source: Box<dyn std::error::Error + Send + Sync + 'static>,
+  },
+  /// Indicates that the X.509 certificate chain of the issuer's key could not be validated.
+  #[cfg(feature = "x509")]
+  #[error("could not validate the certificate chain of the issuer's key")]
+  X509Binding(#[source] crate::validator::X509Error),
   /// Indicates that the credential's timeframe interval is not valid
   #[cfg(feature = "jpt-bbs-plus")]
   #[error("timeframe interval not valid")]
@@ -0,0 +1,42 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+
+/// A historical version of an issuer's DID Document, together with the time from which it was current.
+///
+/// For an [`IotaDocument`](https://docs.rs/identity_iota_core/latest/identity_iota_core/struct.IotaDocument.html)
+/// this is typically the `updated` timestamp of its metadata.
+#[derive(Clone, Debug)]
+pub struct IssuerDocumentVersion<DOC> {
+  document: DOC,
+  valid_from: Timestamp,
+}
+
+impl<DOC> IssuerDocumentVersion<DOC> {
+  /// Creates a new [`IssuerDocumentVersion`] of `document`, which was current from `valid_from` until it was
+  /// replaced by the next version.
+  pub fn new(document: DOC, valid_from: Timestamp) -> Self {
+    Self { document, valid_from }
+  }
+
+  /// Returns a reference to the document.
+  pub fn document(&self) -> &DOC {
+    &self.document
+  }
+
+  /// Returns the time from which the document was current.
+  pub fn valid_from(&self) -> Timestamp {
+    self.valid_from
+  }
+
+  /// Returns the document of the version in `history` that was current at `timestamp`, i.e. the one with the latest
+  /// `valid_from` that is not after `timestamp`.
+  pub fn current_at(history: &[Self], timestamp: Timestamp) -> Option<&DOC> {
+    history
+      .iter()
+      .filter(|version| version.valid_from <= timestamp)
+      .max_by_key(|version| version.valid_from)
+      .map(|version| &version.document)
+  }
+}
@@ -1,17 +1,24 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::Arc;
+
+use identity_core::common::Clock;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_document::verifiable::JwsVerificationOptions;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::validator::IssuerDocumentPolicy;
+use crate::validator::StatusCheckProvider;
 use crate::validator::SubjectHolderRelationship;
+use crate::validator::TrustRegistry;
 
 /// Options to declare validation criteria for [`Credential`](crate::credential::Credential)s.
 #[non_exhaustive]
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct JwtCredentialValidationOptions {
   /// Declares that the credential is **not** considered valid if it expires before this
@@ -32,6 +39,21 @@ pub struct JwtCredentialValidationOptions {
   #[serde(default)]
   pub status: crate::validator::StatusCheck,
 
+  /// Checks the [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status) of the credential.
+  ///
+  /// Default: `RevocationBitmap2022` statuses are checked against the issuer's DID document, see
+  /// [`RevocationBitmapStatusProvider`](crate::validator::RevocationBitmapStatusProvider).
+  #[serde(skip)]
+  #[cfg_attr(feature = "schemars", schemars(skip))]
+  pub status_provider: Option<Arc<dyn StatusCheckProvider>>,
+
+  /// Rejects credentials whose issuer is not trusted by this [`TrustRegistry`].
+  ///
+  /// Default: all issuers are accepted.
+  #[serde(skip)]
+  #[cfg_attr(feature = "schemars", schemars(skip))]
+  pub trust_registry: Option<Arc<dyn TrustRegistry>>,
+
   /// Declares how credential subjects must relate to the presentation holder during validation.
   ///
   /// <https://www.w3.org/TR/vc-data-model/#subject-holder-relationships>
@@ -40,6 +62,18 @@ pub struct JwtCredentialValidationOptions {
   /// Options which affect the verification of the signature on the credential.
   #[serde(default)]
   pub verification_options: JwsVerificationOptions,
+
+  /// Declares which version of the issuer's DID Document the signature is verified against by
+  /// [`JwtCredentialValidator::validate_with_issuer_history`](crate::validator::JwtCredentialValidator::validate_with_issuer_history).
+  ///
+  /// Default: [`IssuerDocumentPolicy::Latest`].
+  #[serde(default)]
+  pub issuer_document_policy: IssuerDocumentPolicy,
+
+  /// Selects the version of the issuer's DID Document current at this [`Timestamp`] instead of at the issuance date
+  /// of the credential, when the [`IssuerDocumentPolicy`] allows historical versions.
+  #[serde(default)]
+  pub issuer_document_as_of: Option<Timestamp>,
 }
 
 impl JwtCredentialValidationOptions {
@@ -62,12 +96,34 @@ impl JwtCredentialValidationOptions {
     self
   }
 
+  /// Use the current time of `clock` as both the earliest expiry date and the latest issuance date of the credential.
+  ///
+  /// Allows validating against a time other than the local system time, e.g. a
+  /// [`NetworkTime`](identity_core::common::NetworkTime).
+  pub fn clock(self, clock: &dyn Clock) -> Self {
+    let now: Timestamp = clock.now();
+    self.earliest_expiry_date(now).latest_issuance_date(now)
+  }
+
   /// Sets the validation behaviour for [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
   pub fn status_check(mut self, status_check: crate::validator::StatusCheck) -> Self {
     self.status = status_check;
     self
   }
 
+  /// Sets the [`StatusCheckProvider`] checking the
+  /// [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status) of the credential.
+  pub fn status_provider(mut self, provider: Arc<dyn StatusCheckProvider>) -> Self {
+    self.status_provider = Some(provider);
+    self
+  }
+
+  /// Rejects credentials whose issuer is not trusted by `registry`.
+  pub fn trust_registry(mut self, registry: Arc<dyn TrustRegistry>) -> Self {
+    self.trust_registry = Some(registry);
+    self
+  }
+
   /// Declares how credential subjects must relate to the presentation holder during validation.
   ///
   /// <https://www.w3.org/TR/vc-data-model/#subject-holder-relationships>
@@ -85,4 +141,17 @@ impl JwtCredentialValidationOptions {
     self.verification_options = options;
     self
   }
+
+  /// Declare which version of the issuer's DID Document the signature is verified against.
+  pub fn issuer_document_policy(mut self, policy: IssuerDocumentPolicy) -> Self {
+    self.issuer_document_policy = policy;
+    self
+  }
+
+  /// Select the version of the issuer's DID Document current at this [`Timestamp`] instead of at the issuance date
+  /// of the credential.
+  pub fn issuer_document_as_of(mut self, timestamp: Timestamp) -> Self {
+    self.issuer_document_as_of = Some(timestamp);
+    self
+  }
 }
@@ -1,19 +1,25 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
 use identity_core::convert::FromJson;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
 use identity_document::document::CoreDocument;
 use identity_document::verifiable::JwsVerificationOptions;
 use identity_verification::jwk::Jwk;
+use identity_verification::jws::AsyncJwsVerifier;
 use identity_verification::jws::DecodedJws;
 use identity_verification::jws::Decoder;
 use identity_verification::jws::JwsValidationItem;
 use identity_verification::jws::JwsVerifier;
+use identity_verification::MethodRelationship;
+use identity_verification::VerificationMethod;
 
 use super::CompoundCredentialValidationError;
 use super::DecodedJwtCredential;
+use super::IssuerDocumentVersion;
 use super::JwtCredentialValidationOptions;
 use super::JwtCredentialValidatorUtils;
 use super::JwtValidationError;
@@ -21,19 +27,16 @@ use super::SignerContext;
 use crate::credential::Credential;
 use crate::credential::CredentialJwtClaims;
 use crate::credential::Jwt;
+use crate::validator::kid_resolver::resolve_jws_kid;
 use crate::validator::FailFast;
+use crate::validator::IssuerDocumentPolicy;
+use crate::validator::KidResolver;
 
 /// A type for decoding and validating [`Credential`]s.
 #[non_exhaustive]
-pub struct JwtCredentialValidator<V: JwsVerifier>(V);
+pub struct JwtCredentialValidator<V>(V);
 
 impl<V: JwsVerifier> JwtCredentialValidator<V> {
-  /// Create a new [`JwtCredentialValidator`] that delegates cryptographic signature verification to the given
-  /// `signature_verifier`.
-  pub fn with_signature_verifier(signature_verifier: V) -> Self {
-    Self(signature_verifier)
-  }
-
   /// Decodes and validates a [`Credential`] issued as a JWT. A [`DecodedJwtCredential`] is returned upon success.
   ///
   /// The following properties are validated according to `options`:
@@ -86,6 +89,143 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     )
   }
 
+  /// Decodes and validates a [`Credential`] issued as a JWT like [`Self::validate`], but obtains the issuer's DID
+  /// Document and verification method by passing the `kid` of the JWS to the given [`KidResolver`].
+  ///
+  /// This allows validating credentials whose `kid` is not a DID URL. The `method_id` of
+  /// `options.verification_options` is ignored in favour of the method returned by the `kid_resolver`.
+  ///
+  /// # Warning
+  /// The same caveats as for [`Self::validate`] apply. In particular, the `kid_resolver` must return an up-to-date
+  /// DID Document.
+  ///
+  /// # Errors
+  /// An error is returned if the `kid` cannot be resolved or whenever a validated condition is not satisfied.
+  pub async fn validate_with_kid_resolver<DOC, T, R>(
+    &self,
+    credential_jwt: &Jwt,
+    kid_resolver: &R,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+    R: KidResolver<DOC> + ?Sized,
+  {
+    let (issuer, method_id): (DOC, DIDUrl) =
+      resolve_jws_kid(credential_jwt.as_str(), kid_resolver)
+        .await
+        .map_err(|err| CompoundCredentialValidationError {
+          validation_errors: [err].into(),
+        })?;
+
+    let mut options: JwtCredentialValidationOptions = options.clone();
+    options.verification_options.method_id = Some(method_id);
+
+    self.validate(credential_jwt, &issuer, &options, fail_fast)
+  }
+
+  /// Decodes and validates a [`Credential`] issued as a JWT like [`Self::validate`], but verifies the signature
+  /// against the version of the issuer's DID Document selected by `options.issuer_document_policy`.
+  ///
+  /// `latest` is the current DID Document of the issuer and `history` contains its previous versions. This allows
+  /// validating long-lived credentials signed with a key that has since been rotated out of the document. All other
+  /// checks, including the status of the credential, are performed against `latest`.
+  ///
+  /// With [`IssuerDocumentPolicy::AtIssuance`] or [`IssuerDocumentPolicy::Either`], the version current at
+  /// `options.issuer_document_as_of`, or at the issuance date of the credential if unset, is selected from `history`.
+  ///
+  /// # Warning
+  /// The issuance date is claimed by the signer of the credential. If a key may have been compromised, the issuance
+  /// date alone does not prove that the credential was signed while the key was part of the document.
+  ///
+  /// # Errors
+  /// An error is returned if no version of the document was current at the selected time, or whenever a validated
+  /// condition is not satisfied.
+  pub fn validate_with_issuer_history<DOC, T>(
+    &self,
+    credential_jwt: &Jwt,
+    latest: &DOC,
+    history: &[IssuerDocumentVersion<DOC>],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let historical_issuer = || Self::historical_issuer(credential_jwt, history, options.issuer_document_as_of);
+    let verify = |issuer: &CoreDocument| {
+      self.verify_signature::<CoreDocument, T>(
+        credential_jwt,
+        std::slice::from_ref(issuer),
+        &options.verification_options,
+      )
+    };
+
+    let credential_token: DecodedJwtCredential<T> = match options.issuer_document_policy {
+      IssuerDocumentPolicy::Latest => verify(latest.as_ref()),
+      IssuerDocumentPolicy::AtIssuance => historical_issuer().and_then(&verify),
+      IssuerDocumentPolicy::Either => verify(latest.as_ref()).or_else(|err| match historical_issuer() {
+        Ok(issuer) => verify(issuer),
+        Err(_) => Err(err),
+      }),
+    }
+    .map_err(|err| CompoundCredentialValidationError {
+      validation_errors: [err].into(),
+    })?;
+
+    Self::validate_decoded_credential::<CoreDocument, T>(
+      credential_token,
+      std::slice::from_ref(latest.as_ref()),
+      options,
+      fail_fast,
+    )
+  }
+
+  /// Decodes and validates a [`Credential`] issued as a JWT like [`Self::validate`], and additionally validates the
+  /// X.509 certificate chain carried in the `x5c` parameter of the issuer's JWK against `trust_store`.
+  ///
+  /// Upon success, the identity asserted by the end-entity certificate of the chain is returned alongside the
+  /// credential. The chain is validated at `options.earliest_expiry_date`, or the current time if unset.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied, or with
+  /// [`JwtValidationError::X509Binding`] if the issuer's JWK has no valid certificate chain leading to a root of
+  /// `trust_store`.
+  #[cfg(feature = "x509")]
+  pub fn validate_with_x509_binding<DOC, T>(
+    &self,
+    credential_jwt: &Jwt,
+    issuer: &DOC,
+    trust_store: &crate::validator::X509TrustStore,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<(DecodedJwtCredential<T>, crate::validator::X509CertificateIdentity), CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let credential_token: DecodedJwtCredential<T> = self.validate(credential_jwt, issuer, options, fail_fast)?;
+
+    let into_compound = |err: JwtValidationError| CompoundCredentialValidationError {
+      validation_errors: [err].into(),
+    };
+    let decoded: JwsValidationItem<'_> = Self::decode(credential_jwt.as_str()).map_err(into_compound)?;
+    let (public_key, _) = Self::parse_jwk(
+      &decoded,
+      std::slice::from_ref(issuer.as_ref()),
+      &options.verification_options,
+    )
+    .map_err(into_compound)?;
+    let identity = trust_store
+      .validate_jwk(public_key, options.earliest_expiry_date.unwrap_or_default(), &self.0)
+      .map_err(|err| into_compound(JwtValidationError::X509Binding(err)))?;
+
+    Ok((credential_token, identity))
+  }
+
   /// Decode and verify the JWS signature of a [`Credential`] issued as a JWT using the DID Document of a trusted
   /// issuer.
   ///
@@ -115,6 +255,68 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     Self::verify_signature_with_verifier(&self.0, credential, trusted_issuers, options)
   }
 
+  /// Stateless version of [`Self::verify_signature`]
+  fn verify_signature_with_verifier<DOC, S, T>(
+    signature_verifier: &S,
+    credential: &Jwt,
+    trusted_issuers: &[DOC],
+    options: &JwsVerificationOptions,
+  ) -> Result<DecodedJwtCredential<T>, JwtValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+    S: JwsVerifier,
+  {
+    // Note the below steps are necessary because `CoreDocument::verify_jws` decodes the JWS and then searches for a
+    // method with a fragment (or full DID Url) matching `kid` in the given document. We do not want to carry out
+    // that process for potentially every document in `trusted_issuers`.
+
+    // Start decoding the credential
+    let decoded: JwsValidationItem<'_> = Self::decode(credential.as_str())?;
+    let (public_key, method_id) = Self::parse_jwk(&decoded, trusted_issuers, options)?;
+
+    let credential_token = Self::verify_decoded_signature(decoded, public_key, signature_verifier)?;
+
+    Self::check_signer(&credential_token, &method_id)?;
+    Ok(credential_token)
+  }
+
+  pub(crate) fn verify_signature_raw<'a, S: JwsVerifier>(
+    decoded: JwsValidationItem<'a>,
+    public_key: &Jwk,
+    signature_verifier: &S,
+  ) -> Result<DecodedJws<'a>, JwtValidationError> {
+    decoded
+      .verify(signature_verifier, public_key)
+      .map_err(|err| JwtValidationError::Signature {
+        source: err,
+        signer_ctx: SignerContext::Issuer,
+      })
+  }
+
+  /// Verify the signature using the given `public_key` and `signature_verifier`.
+  pub(crate) fn verify_decoded_signature<S: JwsVerifier, T>(
+    decoded: JwsValidationItem<'_>,
+    public_key: &Jwk,
+    signature_verifier: &S,
+  ) -> Result<DecodedJwtCredential<T>, JwtValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    // Verify the JWS signature and obtain the decoded token containing the protected header and raw claims
+    let decoded: DecodedJws<'_> = Self::verify_signature_raw(decoded, public_key, signature_verifier)?;
+
+    Self::credential_from_decoded_jws(decoded)
+  }
+}
+
+impl<V> JwtCredentialValidator<V> {
+  /// Create a new [`JwtCredentialValidator`] that delegates cryptographic signature verification to the given
+  /// `signature_verifier`.
+  pub fn with_signature_verifier(signature_verifier: V) -> Self {
+    Self(signature_verifier)
+  }
+
   // This method takes a slice of issuer's instead of a single issuer in order to better accommodate presentation
   // validation. It also validates the relationship between a holder and the credential subjects when
   // `relationship_criterion` is Some.
@@ -157,15 +359,18 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
         .unwrap_or(Ok(()))
     });
 
+    let trusted_issuer_validation =
+      std::iter::once_with(|| JwtCredentialValidatorUtils::check_trusted_issuer(credential, options));
+
     let validation_units_iter = issuance_date_validation
       .chain(expiry_date_validation)
       .chain(structure_validation)
+      .chain(trusted_issuer_validation)
       .chain(subject_holder_validation);
 
-    #[cfg(feature = "revocation-bitmap")]
     let validation_units_iter = {
       let revocation_validation =
-        std::iter::once_with(|| JwtCredentialValidatorUtils::check_status(credential, issuers, options.status));
+        std::iter::once_with(|| JwtCredentialValidatorUtils::check_status_with_options(credential, issuers, options));
       validation_units_iter.chain(revocation_validation)
     };
 
@@ -230,9 +435,27 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
       .ok_or(JwtValidationError::DocumentMismatch(SignerContext::Issuer))?;
 
     // Obtain the public key from the issuer's DID document
-    issuer
-      .resolve_method(&method_id, options.method_scope)
-      .and_then(|method| method.data().public_key_jwk())
+    let method: &VerificationMethod = issuer
+      .resolve_method(
+        &method_id,
+        options.scope_for_purpose(MethodRelationship::AssertionMethod),
+      )
+      .ok_or(JwtValidationError::MethodDataLookupError {
+        source: None,
+        message: "could not find a method identified by kid",
+        signer_ctx: SignerContext::Issuer,
+      })?;
+    if options.require_controlled_method && !issuer.is_controlled_by(method.controller()) {
+      return Err(JwtValidationError::MethodDataLookupError {
+        source: Some(identity_document::error::Error::InvalidMethodController(method.id().clone()).into()),
+        message: "the method identified by kid is not controlled by the issuer",
+        signer_ctx: SignerContext::Issuer,
+      });
+    }
+
+    method
+      .data()
+      .public_key_jwk()
       .ok_or_else(|| JwtValidationError::MethodDataLookupError {
         source: None,
         message: "could not extract JWK from a method identified by kid",
@@ -241,37 +464,37 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
       .map(move |jwk| (jwk, method_id))
   }
 
-  /// Stateless version of [`Self::verify_signature`]
-  fn verify_signature_with_verifier<DOC, S, T>(
-    signature_verifier: &S,
-    credential: &Jwt,
-    trusted_issuers: &[DOC],
-    options: &JwsVerificationOptions,
-  ) -> Result<DecodedJwtCredential<T>, JwtValidationError>
+  /// Returns the version of the issuer's DID Document in `history` current at `as_of`, or at the issuance date of
+  /// the credential if unset.
+  fn historical_issuer<'h, DOC>(
+    credential_jwt: &Jwt,
+    history: &'h [IssuerDocumentVersion<DOC>],
+    as_of: Option<Timestamp>,
+  ) -> Result<&'h CoreDocument, JwtValidationError>
   where
-    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
-    S: JwsVerifier,
   {
-    // Note the below steps are necessary because `CoreDocument::verify_jws` decodes the JWS and then searches for a
-    // method with a fragment (or full DID Url) matching `kid` in the given document. We do not want to carry out
-    // that process for potentially every document in `trusted_issuers`.
-
-    // Start decoding the credential
-    let decoded: JwsValidationItem<'_> = Self::decode(credential.as_str())?;
-    let (public_key, method_id) = Self::parse_jwk(&decoded, trusted_issuers, options)?;
-
-    let credential_token = Self::verify_decoded_signature(decoded, public_key, signature_verifier)?;
-
-    // Check that the DID component of the parsed `kid` does indeed correspond to the issuer in the credential before
-    // returning.
-    let issuer_id: CoreDID = JwtCredentialValidatorUtils::extract_issuer(&credential_token.credential)?;
-    if &issuer_id != method_id.did() {
-      return Err(JwtValidationError::IdentifierMismatch {
-        signer_ctx: SignerContext::Issuer,
-      });
+    let as_of: Timestamp = match as_of {
+      Some(as_of) => as_of,
+      None => Self::decode_unverified::<Object>(credential_jwt)?.issuance_date,
     };
-    Ok(credential_token)
+    IssuerDocumentVersion::current_at(history, as_of)
+      .map(AsRef::as_ref)
+      .ok_or(JwtValidationError::MissingIssuerDocumentVersion)
+  }
+
+  /// Decodes the credential without verifying its signature.
+  pub(crate) fn decode_unverified<T>(credential_jws: &Jwt) -> Result<Credential<T>, JwtValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    let decoded: JwsValidationItem<'_> = Self::decode(credential_jws.as_str())?;
+    CredentialJwtClaims::<'_, T>::from_json_slice(decoded.claims())
+      .map_err(|err| {
+        JwtValidationError::CredentialStructure(crate::Error::JwtClaimsSetDeserializationError(err.into()))
+      })?
+      .try_into_credential()
+      .map_err(JwtValidationError::CredentialStructure)
   }
 
   /// Decode the credential into a [`JwsValidationItem`].
@@ -283,30 +506,12 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
       .map_err(JwtValidationError::JwsDecodingError)
   }
 
-  pub(crate) fn verify_signature_raw<'a, S: JwsVerifier>(
-    decoded: JwsValidationItem<'a>,
-    public_key: &Jwk,
-    signature_verifier: &S,
-  ) -> Result<DecodedJws<'a>, JwtValidationError> {
-    decoded
-      .verify(signature_verifier, public_key)
-      .map_err(|err| JwtValidationError::Signature {
-        source: err,
-        signer_ctx: SignerContext::Issuer,
-      })
-  }
-
-  /// Verify the signature using the given `public_key` and `signature_verifier`.
-  pub(crate) fn verify_decoded_signature<S: JwsVerifier, T>(
-    decoded: JwsValidationItem<'_>,
-    public_key: &Jwk,
-    signature_verifier: &S,
-  ) -> Result<DecodedJwtCredential<T>, JwtValidationError>
+  /// Constructs the credential token from a JWS whose signature has been verified.
+  fn credential_from_decoded_jws<T>(decoded: DecodedJws<'_>) -> Result<DecodedJwtCredential<T>, JwtValidationError>
   where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
   {
-    // Verify the JWS signature and obtain the decoded token containing the protected header and raw claims
-    let DecodedJws { protected, claims, .. } = Self::verify_signature_raw(decoded, public_key, signature_verifier)?;
+    let DecodedJws { protected, claims, .. } = decoded;
 
     let credential_claims: CredentialJwtClaims<'_, T> =
       CredentialJwtClaims::from_json_slice(&claims).map_err(|err| {
@@ -326,6 +531,89 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
       custom_claims,
     })
   }
+
+  /// Checks that the DID component of the parsed `kid` does indeed correspond to the issuer in the credential.
+  fn check_signer<T>(credential_token: &DecodedJwtCredential<T>, method_id: &DIDUrl) -> Result<(), JwtValidationError> {
+    let issuer_id: CoreDID = JwtCredentialValidatorUtils::extract_issuer(&credential_token.credential)?;
+    if &issuer_id != method_id.did() {
+      return Err(JwtValidationError::IdentifierMismatch {
+        signer_ctx: SignerContext::Issuer,
+      });
+    };
+    Ok(())
+  }
+}
+
+impl<V: AsyncJwsVerifier> JwtCredentialValidator<V> {
+  /// Asynchronous version of [`Self::validate`], delegating cryptographic signature verification to an
+  /// [`AsyncJwsVerifier`], e.g. a remote verification service.
+  ///
+  /// # Warning
+  /// The same caveats as for [`Self::validate`] apply.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied.
+  pub async fn validate_async<DOC, T>(
+    &self,
+    credential_jwt: &Jwt,
+    issuer: &DOC,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let credential_token = self
+      .verify_signature_async(
+        credential_jwt,
+        std::slice::from_ref(issuer.as_ref()),
+        &options.verification_options,
+      )
+      .await
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })?;
+
+    Self::validate_decoded_credential::<CoreDocument, T>(
+      credential_token,
+      std::slice::from_ref(issuer.as_ref()),
+      options,
+      fail_fast,
+    )
+  }
+
+  /// Asynchronous version of [`Self::verify_signature`], delegating cryptographic signature verification to an
+  /// [`AsyncJwsVerifier`].
+  ///
+  /// # Errors
+  /// See [`Self::verify_signature`].
+  pub async fn verify_signature_async<DOC, T>(
+    &self,
+    credential: &Jwt,
+    trusted_issuers: &[DOC],
+    options: &JwsVerificationOptions,
+  ) -> Result<DecodedJwtCredential<T>, JwtValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let decoded: JwsValidationItem<'_> = Self::decode(credential.as_str())?;
+    let (public_key, method_id) = Self::parse_jwk(&decoded, trusted_issuers, options)?;
+
+    let decoded: DecodedJws<'_> =
+      decoded
+        .verify_async(&self.0, public_key)
+        .await
+        .map_err(|err| JwtValidationError::Signature {
+          source: err,
+          signer_ctx: SignerContext::Issuer,
+        })?;
+    let credential_token: DecodedJwtCredential<T> = Self::credential_from_decoded_jws(decoded)?;
+
+    Self::check_signer(&credential_token, &method_id)?;
+    Ok(credential_token)
+  }
 }
 
 #[cfg(test)]
@@ -539,4 +827,224 @@ mod tests {
       assert!(JwtCredentialValidatorUtils::check_issued_on_or_before(&SIMPLE_CREDENTIAL, later_than_issuance_date).is_ok());
     }
   }
+
+  #[tokio::test]
+  async fn validate_async() {
+    use identity_eddsa_verifier::EdDSAJwsVerifier;
+    use identity_verification::jws::SignatureVerificationError;
+    use identity_verification::jws::VerificationInput;
+
+    use crate::validator::test_utils::generate_jwk_document_with_keys;
+    use crate::validator::test_utils::sign_credential_jwt;
+
+    struct RemoteVerifier;
+
+    #[async_trait::async_trait]
+    impl AsyncJwsVerifier for RemoteVerifier {
+      async fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+        JwsVerifier::verify(&EdDSAJwsVerifier::default(), input, public_key)
+      }
+    }
+
+    let (issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let (other, _, _) = generate_jwk_document_with_keys();
+    let credential: Credential = Credential::builder(Object::new())
+      .issuer(Url::parse(issuer.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap();
+    let jwt: Jwt = sign_credential_jwt(&credential, &issuer, &fragment, &secret_key);
+    let options = JwtCredentialValidationOptions::default();
+
+    let validator = JwtCredentialValidator::with_signature_verifier(RemoteVerifier);
+    let decoded: DecodedJwtCredential = validator
+      .validate_async(&jwt, &issuer, &options, FailFast::FirstError)
+      .await
+      .unwrap();
+    assert_eq!(decoded.credential, credential);
+
+    // Synchronous verifiers can be used asynchronously.
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+    assert!(validator
+      .validate_async::<_, Object>(&jwt, &issuer, &options, FailFast::FirstError)
+      .await
+      .is_ok());
+
+    // INVALID: the credential was not issued by `other`.
+    let error = validator
+      .verify_signature_async::<_, Object>(&jwt, &[&other], &options.verification_options)
+      .await
+      .unwrap_err();
+    assert!(matches!(
+      error,
+      JwtValidationError::DocumentMismatch(SignerContext::Issuer)
+    ));
+  }
+
+  #[test]
+  fn verify_signature_requires_method_purpose() {
+    use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+    use crate::validator::test_utils::generate_jwk_document_with_keys;
+    use crate::validator::test_utils::sign_credential_jwt;
+
+    let (mut issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let credential: Credential = Credential::builder(Object::new())
+      .issuer(Url::parse(issuer.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap();
+    let jwt: Jwt = sign_credential_jwt(&credential, &issuer, &fragment, &secret_key);
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+    let options: JwsVerificationOptions = JwsVerificationOptions::new().require_method_purpose(true);
+
+    // INVALID: the signing method is not an assertion method.
+    assert!(matches!(
+      validator.verify_signature::<_, Object>(&jwt, &[&issuer], &options),
+      Err(JwtValidationError::MethodDataLookupError { .. })
+    ));
+    // The purpose is only enforced if required.
+    assert!(validator
+      .verify_signature::<_, Object>(&jwt, &[&issuer], &JwsVerificationOptions::new())
+      .is_ok());
+
+    issuer
+      .attach_method_relationship(fragment.as_str(), MethodRelationship::AssertionMethod)
+      .unwrap();
+    assert!(validator
+      .verify_signature::<_, Object>(&jwt, &[&issuer], &options)
+      .is_ok());
+  }
+
+  #[test]
+  fn verify_signature_requires_controlled_method() {
+    use identity_core::common::OneOrSet;
+    use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+    use crate::validator::test_utils::generate_jwk_document_with_keys;
+    use crate::validator::test_utils::sign_credential_jwt;
+
+    let (mut issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let credential: Credential = Credential::builder(Object::new())
+      .issuer(Url::parse(issuer.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap();
+    let jwt: Jwt = sign_credential_jwt(&credential, &issuer, &fragment, &secret_key);
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+    let options = JwtCredentialValidationOptions::default()
+      .verification_options(JwsVerificationOptions::new().require_controlled_method(true));
+    assert!(validator
+      .validate::<_, Object>(&jwt, &issuer, &options, FailFast::FirstError)
+      .is_ok());
+
+    // Hand control of the signing method to another DID.
+    let other: CoreDID = CoreDID::parse("did:example:other").unwrap();
+    *issuer
+      .resolve_method_mut(fragment.as_str(), None)
+      .unwrap()
+      .controller_mut() = other.clone();
+
+    // INVALID: the signing method is not controlled by the issuer.
+    let error = validator
+      .validate::<_, Object>(&jwt, &issuer, &options, FailFast::FirstError)
+      .unwrap_err();
+    assert!(matches!(
+      error.validation_errors.as_slice(),
+      [JwtValidationError::MethodDataLookupError { .. }]
+    ));
+    // The controller is only enforced if required.
+    assert!(validator
+      .validate::<_, Object>(
+        &jwt,
+        &issuer,
+        &JwtCredentialValidationOptions::default(),
+        FailFast::FirstError
+      )
+      .is_ok());
+
+    // The method is accepted once its controller is a controller of the issuer.
+    *issuer.controller_mut() = Some(OneOrSet::new_one(other));
+    assert!(validator
+      .validate::<_, Object>(&jwt, &issuer, &options, FailFast::FirstError)
+      .is_ok());
+  }
+
+  #[cfg(feature = "x509")]
+  #[test]
+  fn validate_with_x509_binding() {
+    use identity_eddsa_verifier::EdDSAJwsVerifier;
+    use identity_verification::VerificationMethod;
+
+    use crate::validator::test_utils::generate_jwk_document_with_keys;
+    use crate::validator::test_utils::sign_credential_jwt;
+    use crate::validator::test_utils::x509_bound_jwk;
+    use crate::validator::test_utils::X509_OTHER_ROOT_CERTIFICATE;
+    use crate::validator::test_utils::X509_ROOT_CERTIFICATE;
+    use crate::validator::X509CertificateIdentity;
+    use crate::validator::X509TrustStore;
+
+    let (jwk, secret_key) = x509_bound_jwk();
+    let did: CoreDID = CoreDID::parse("did:example:x509-issuer").unwrap();
+    let issuer: CoreDocument = CoreDocument::builder(Object::new())
+      .id(did.clone())
+      .verification_method(VerificationMethod::new_from_jwk(did, jwk, Some("#x509")).unwrap())
+      .build()
+      .unwrap();
+    let credential: Credential = Credential::builder(Object::new())
+      .issuer(Url::parse(issuer.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap();
+    let jwt: Jwt = sign_credential_jwt(&credential, &issuer, "#x509", &secret_key);
+
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+    let options = JwtCredentialValidationOptions::default();
+    let trust_store = |root: &[u8]| X509TrustStore::new().with_root(root).unwrap();
+
+    let (decoded, identity): (DecodedJwtCredential, X509CertificateIdentity) = validator
+      .validate_with_x509_binding(
+        &jwt,
+        &issuer,
+        &trust_store(X509_ROOT_CERTIFICATE),
+        &options,
+        FailFast::FirstError,
+      )
+      .unwrap();
+    assert_eq!(decoded.credential, credential);
+    assert_eq!(identity.subject, "CN=Test Attested Key");
+
+    // INVALID: the chain does not lead to a trusted root.
+    let error = validator
+      .validate_with_x509_binding::<_, Object>(
+        &jwt,
+        &issuer,
+        &trust_store(X509_OTHER_ROOT_CERTIFICATE),
+        &options,
+        FailFast::FirstError,
+      )
+      .unwrap_err();
+    assert!(matches!(
+      error.validation_errors.as_slice(),
+      [JwtValidationError::X509Binding(_)]
+    ));
+
+    // INVALID: the issuer's key carries no certificate chain.
+    let (other_issuer, other_secret_key, fragment) = generate_jwk_document_with_keys();
+    let other_credential: Credential = Credential::builder(Object::new())
+      .issuer(Url::parse(other_issuer.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap();
+    let other_jwt: Jwt = sign_credential_jwt(&other_credential, &other_issuer, &fragment, &other_secret_key);
+    assert!(validator
+      .validate_with_x509_binding::<_, Object>(
+        &other_jwt,
+        &other_issuer,
+        &trust_store(X509_ROOT_CERTIFICATE),
+        &options,
+        FailFast::FirstError,
+      )
+      .is_err());
+  }
 }
@@ -10,6 +10,7 @@ use identity_core::convert::FromJson;
 use identity_did::DID;
 use identity_verification::jws::Decoder;
 
+use super::JwtCredentialValidationOptions;
 use super::JwtValidationError;
 use super::SignerContext;
 use crate::credential::Credential;
@@ -17,6 +18,7 @@ use crate::credential::CredentialJwtClaims;
 use crate::credential::Jwt;
 #[cfg(feature = "status-list-2021")]
 use crate::revocation::status_list_2021::StatusList2021Credential;
+use crate::validator::StatusCheckProvider;
 use crate::validator::SubjectHolderRelationship;
 
 /// Utility functions for verifying JWT credentials.
@@ -124,14 +126,47 @@ impl JwtCredentialValidatorUtils {
       }
     }
   }
+  /// Checks that the issuer of the credential is trusted by the [`TrustRegistry`](crate::validator::TrustRegistry)
+  /// of `options`, if any.
+  pub fn check_trusted_issuer<T>(
+    credential: &Credential<T>,
+    options: &JwtCredentialValidationOptions,
+  ) -> ValidationUnitResult {
+    let Some(registry) = options.trust_registry.as_deref() else {
+      return Ok(());
+    };
+    let issuer: identity_did::CoreDID = Self::extract_issuer(credential)?;
+    if registry.is_trusted_issuer(&issuer, credential.types.as_slice()) {
+      Ok(())
+    } else {
+      Err(JwtValidationError::UntrustedIssuer)
+    }
+  }
+
   /// Checks whether the credential status has been revoked.
   ///
-  /// Only supports `RevocationBitmap2022`.
+  /// Only supports `RevocationBitmap2022`, see [`Self::check_status_with_provider`] for other status types.
   #[cfg(feature = "revocation-bitmap")]
   pub fn check_status<DOC: AsRef<identity_document::document::CoreDocument>, T>(
     credential: &Credential<T>,
     trusted_issuers: &[DOC],
     status_check: crate::validator::StatusCheck,
+  ) -> ValidationUnitResult {
+    Self::check_status_with_provider(
+      credential,
+      trusted_issuers,
+      status_check,
+      &crate::validator::RevocationBitmapStatusProvider,
+    )
+  }
+
+  /// Checks whether the credential status has been revoked or suspended with `provider`, against the DID document of
+  /// the credential's issuer among `trusted_issuers`.
+  pub fn check_status_with_provider<DOC: AsRef<identity_document::document::CoreDocument>, T>(
+    credential: &Credential<T>,
+    trusted_issuers: &[DOC],
+    status_check: crate::validator::StatusCheck,
+    provider: &dyn StatusCheckProvider,
   ) -> ValidationUnitResult {
     use identity_did::CoreDID;
     use identity_document::document::CoreDocument;
@@ -144,7 +179,7 @@ impl JwtCredentialValidatorUtils {
       None => Ok(()),
       Some(status) => {
         // Check status is supported.
-        if status.type_ != crate::revocation::RevocationBitmap::TYPE {
+        if !provider.supports(&status.type_) {
           if status_check == crate::validator::StatusCheck::SkipUnsupported {
             return Ok(());
           }
@@ -153,21 +188,34 @@ impl JwtCredentialValidatorUtils {
             status.type_
           ))));
         }
-        let status: crate::credential::RevocationBitmapStatus =
-          crate::credential::RevocationBitmapStatus::try_from(status.clone())
-            .map_err(JwtValidationError::InvalidStatus)?;
 
-        // Check the credential index against the issuer's DID Document.
+        // Check the status against the issuer's DID Document.
         let issuer_did: CoreDID = Self::extract_issuer(credential)?;
         trusted_issuers
           .iter()
           .find(|issuer| <CoreDocument>::id(issuer.as_ref()) == &issuer_did)
           .ok_or(JwtValidationError::DocumentMismatch(SignerContext::Issuer))
-          .and_then(|issuer| Self::check_revocation_bitmap_status(issuer, status))
+          .and_then(|issuer| provider.check_status(status, issuer.as_ref()))
       }
     }
   }
 
+  /// Checks the credential status with the [`StatusCheckProvider`] of `options`, or with the
+  /// `RevocationBitmap2022` services of the issuer's DID document if none is set.
+  pub(crate) fn check_status_with_options<DOC: AsRef<identity_document::document::CoreDocument>, T>(
+    credential: &Credential<T>,
+    trusted_issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+  ) -> ValidationUnitResult {
+    match options.status_provider.as_deref() {
+      Some(provider) => Self::check_status_with_provider(credential, trusted_issuers, options.status, provider),
+      #[cfg(feature = "revocation-bitmap")]
+      None => Self::check_status(credential, trusted_issuers, options.status),
+      #[cfg(not(feature = "revocation-bitmap"))]
+      None => Ok(()),
+    }
+  }
+
   /// Check the given `status` against the matching [`RevocationBitmap`] service in the
   /// issuer's DID Document.
   #[cfg(feature = "revocation-bitmap")]
@@ -4,12 +4,14 @@
 //! Contains functionality for validating credentials issued as JWTs.
 mod decoded_jwt_credential;
 mod error;
+mod issuer_document_version;
 mod jwt_credential_validation_options;
 mod jwt_credential_validator;
 mod jwt_credential_validator_utils;
 
 pub use decoded_jwt_credential::*;
 pub use error::*;
+pub use issuer_document_version::*;
 pub use jwt_credential_validation_options::*;
 pub use jwt_credential_validator::*;
 pub use jwt_credential_validator_utils::*;
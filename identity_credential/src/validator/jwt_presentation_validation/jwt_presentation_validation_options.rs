@@ -4,11 +4,13 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use identity_core::common::Clock;
 use identity_core::common::Timestamp;
 use identity_document::verifiable::JwsVerificationOptions;
 
 /// Criteria for validating a [`Presentation`](crate::presentation::Presentation).
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 #[serde(rename_all = "camelCase")]
 pub struct JwtPresentationValidationOptions {
@@ -27,6 +29,12 @@ pub struct JwtPresentationValidationOptions {
   /// Uses the current datetime during validation if not set.
   #[serde(default)]
   pub latest_issuance_date: Option<Timestamp>,
+
+  /// The number of seconds by which the expiration and issuance dates of the presentation may deviate from the
+  /// earliest expiry and latest issuance dates, to account for clock skew between holder and verifier.
+  /// Defaults to zero.
+  #[serde(default)]
+  pub leeway: u32,
 }
 
 impl JwtPresentationValidationOptions {
@@ -54,4 +62,20 @@ impl JwtPresentationValidationOptions {
     self.latest_issuance_date = Some(timestamp);
     self
   }
+
+  /// Allow the expiration and issuance dates of the presentation to deviate by up to `seconds` from the earliest
+  /// expiry and latest issuance dates, to account for clock skew between holder and verifier.
+  pub fn leeway(mut self, seconds: u32) -> Self {
+    self.leeway = seconds;
+    self
+  }
+
+  /// Use the current time of `clock` as both the earliest expiry date and the latest issuance date of the presentation.
+  ///
+  /// Allows validating against a time other than the local system time, e.g. a
+  /// [`NetworkTime`](identity_core::common::NetworkTime).
+  pub fn clock(self, clock: &dyn Clock) -> Self {
+    let now: Timestamp = clock.now();
+    self.earliest_expiry_date(now).latest_issuance_date(now)
+  }
 }
@@ -1,14 +1,17 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Duration;
 use identity_core::common::Object;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_did::CoreDID;
 use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
 use identity_verification::jws::DecodedJws;
 use identity_verification::jws::JwsVerifier;
+use identity_verification::MethodRelationship;
 use std::str::FromStr;
 
 use crate::credential::Jwt;
@@ -39,10 +42,11 @@ where
   ///
   /// The following properties are validated according to `options`:
   /// - the JWT can be decoded into a semantically valid presentation.
-  /// - the expiration and issuance date contained in the JWT claims.
+  /// - the expiration and issuance date contained in the JWT claims, allowing for the leeway set in `options`.
   /// - the holder's signature.
   ///
-  /// Validation is done with respect to the properties set in `options`.
+  /// Validation is done with respect to the properties set in `options`. The `issuanceDate` and `expirationDate` of
+  /// the returned presentation are those of the JWT claims.
   ///
   /// # Warning
   ///
@@ -71,15 +75,12 @@ where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     CRED: ToOwned<Owned = CRED> + serde::Serialize + serde::de::DeserializeOwned + Clone,
   {
-    // Verify JWS.
+    // Verify JWS, signed by a method in the authentication relationship of the holder if required.
+    let mut verifier_options: JwsVerificationOptions = options.presentation_verifier_options.clone();
+    verifier_options.method_scope = verifier_options.scope_for_purpose(MethodRelationship::Authentication);
     let decoded_jws: DecodedJws<'_> = holder
       .as_ref()
-      .verify_jws(
-        presentation.as_str(),
-        None,
-        &self.0,
-        &options.presentation_verifier_options,
-      )
+      .verify_jws(presentation.as_str(), None, &self.0, &verifier_options)
       .map_err(|err| {
         CompoundJwtPresentationValidationError::one_presentation_error(JwtValidationError::PresentationJwsError(err))
       })?;
@@ -117,11 +118,14 @@ where
       })
       .transpose()?;
 
-    (expiration_date.is_none() || expiration_date >= Some(options.earliest_expiry_date.unwrap_or_default()))
-      .then_some(())
-      .ok_or(CompoundJwtPresentationValidationError::one_presentation_error(
-        JwtValidationError::ExpirationDate,
-      ))?;
+    let leeway: Duration = Duration::seconds(options.leeway);
+    let earliest_expiry_date: Timestamp = options.earliest_expiry_date.unwrap_or_default();
+    (expiration_date.is_none()
+      || expiration_date >= Some(earliest_expiry_date.checked_sub(leeway).unwrap_or(earliest_expiry_date)))
+    .then_some(())
+    .ok_or(CompoundJwtPresentationValidationError::one_presentation_error(
+      JwtValidationError::ExpirationDate,
+    ))?;
 
     // Check issuance date.
     let issuance_date: Option<Timestamp> = match claims.issuance_date {
@@ -139,18 +143,22 @@ where
       None => None,
     };
 
-    (issuance_date.is_none() || issuance_date <= Some(options.latest_issuance_date.unwrap_or_default()))
-      .then_some(())
-      .ok_or(CompoundJwtPresentationValidationError::one_presentation_error(
-        JwtValidationError::IssuanceDate,
-      ))?;
+    let latest_issuance_date: Timestamp = options.latest_issuance_date.unwrap_or_default();
+    (issuance_date.is_none()
+      || issuance_date <= Some(latest_issuance_date.checked_add(leeway).unwrap_or(latest_issuance_date)))
+    .then_some(())
+    .ok_or(CompoundJwtPresentationValidationError::one_presentation_error(
+      JwtValidationError::IssuanceDate,
+    ))?;
 
     let aud: Option<Url> = claims.aud.clone();
     let custom_claims: Option<Object> = claims.custom.clone();
 
-    let presentation: Presentation<CRED, T> = claims.try_into_presentation().map_err(|err| {
+    let mut presentation: Presentation<CRED, T> = claims.try_into_presentation().map_err(|err| {
       CompoundJwtPresentationValidationError::one_presentation_error(JwtValidationError::PresentationStructure(err))
     })?;
+    presentation.issuance_date = issuance_date;
+    presentation.expiration_date = expiration_date;
 
     let decoded_jwt_presentation: DecodedJwtPresentation<CRED, T> = DecodedJwtPresentation {
       presentation,
@@ -164,3 +172,113 @@ where
     Ok(decoded_jwt_presentation)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::OneOrSet;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+  use super::*;
+  use crate::presentation::JwtPresentationOptions;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_presentation_jwt;
+
+  #[test]
+  fn validate_requires_controlled_method() {
+    let (mut holder, secret_key, fragment) = generate_jwk_document_with_keys();
+    let presentation: Presentation<Jwt> =
+      Presentation::builder(Url::parse(holder.id().as_str()).unwrap(), Object::new())
+        .build()
+        .unwrap();
+    let jwt: Jwt = sign_presentation_jwt(
+      &presentation,
+      &JwtPresentationOptions::default(),
+      &holder,
+      &fragment,
+      &secret_key,
+    );
+    let validator = JwtPresentationValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+    let options = JwtPresentationValidationOptions::default()
+      .presentation_verifier_options(JwsVerificationOptions::new().require_controlled_method(true));
+    assert!(validator.validate::<_, Jwt, Object>(&jwt, &holder, &options).is_ok());
+
+    // Hand control of the signing method to another DID.
+    let other: CoreDID = CoreDID::parse("did:example:other").unwrap();
+    *holder
+      .resolve_method_mut(fragment.as_str(), None)
+      .unwrap()
+      .controller_mut() = other.clone();
+
+    // INVALID: the signing method is not controlled by the holder.
+    let error = validator
+      .validate::<_, Jwt, Object>(&jwt, &holder, &options)
+      .unwrap_err();
+    assert!(matches!(
+      error.presentation_validation_errors.as_slice(),
+      [JwtValidationError::PresentationJwsError(
+        identity_document::error::Error::InvalidMethodController(_)
+      )]
+    ));
+    assert!(validator
+      .validate::<_, Jwt, Object>(&jwt, &holder, &JwtPresentationValidationOptions::default())
+      .is_ok());
+
+    // The method is accepted once its controller is a controller of the holder.
+    *holder.controller_mut() = Some(OneOrSet::new_one(other));
+    assert!(validator.validate::<_, Jwt, Object>(&jwt, &holder, &options).is_ok());
+  }
+
+  #[test]
+  fn validate_dates_with_leeway() {
+    let (holder, secret_key, fragment) = generate_jwk_document_with_keys();
+    let issuance_date: Timestamp = Timestamp::parse("2023-09-14T13:42:31Z").unwrap();
+    let expiration_date: Timestamp = Timestamp::parse("2023-09-14T13:52:31Z").unwrap();
+    let presentation: Presentation<Jwt> =
+      Presentation::builder(Url::parse(holder.id().as_str()).unwrap(), Object::new())
+        .issuance_date(issuance_date)
+        .expiration_date(expiration_date)
+        .build()
+        .unwrap();
+    let jwt: Jwt = sign_presentation_jwt(
+      &presentation,
+      &JwtPresentationOptions::default(),
+      &holder,
+      &fragment,
+      &secret_key,
+    );
+    let validator = JwtPresentationValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+    let validate = |options: JwtPresentationValidationOptions| {
+      validator
+        .validate::<_, Jwt, Object>(&jwt, &holder, &options)
+        .map_err(|err| err.presentation_validation_errors)
+    };
+    let options = |at: Timestamp| {
+      JwtPresentationValidationOptions::default()
+        .earliest_expiry_date(at)
+        .latest_issuance_date(at)
+    };
+
+    let decoded: DecodedJwtPresentation<Jwt> = validate(options(issuance_date)).unwrap();
+    assert_eq!(decoded.issuance_date, Some(issuance_date));
+    assert_eq!(decoded.expiration_date, Some(expiration_date));
+    assert_eq!(decoded.presentation.issuance_date, Some(issuance_date));
+    assert_eq!(decoded.presentation.expiration_date, Some(expiration_date));
+
+    // INVALID: validated 30 seconds before the issuance date.
+    let early: Timestamp = issuance_date.checked_sub(Duration::seconds(30)).unwrap();
+    assert!(matches!(
+      validate(options(early)).unwrap_err().as_slice(),
+      [JwtValidationError::IssuanceDate]
+    ));
+    assert!(validate(options(early).leeway(30)).is_ok());
+
+    // INVALID: validated 30 seconds after the expiration date.
+    let late: Timestamp = expiration_date.checked_add(Duration::seconds(30)).unwrap();
+    assert!(matches!(
+      validate(options(late)).unwrap_err().as_slice(),
+      [JwtValidationError::ExpirationDate]
+    ));
+    assert!(validate(options(late).leeway(30)).is_ok());
+    assert!(validate(options(late).leeway(29)).is_err());
+  }
+}
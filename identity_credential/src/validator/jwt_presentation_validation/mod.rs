@@ -6,9 +6,11 @@ mod error;
 mod jwt_presentation_validation_options;
 mod jwt_presentation_validator;
 mod jwt_presentation_validator_utils;
+mod validation_cache;
 
 pub use decoded_jwt_presentation::*;
 pub use error::*;
 pub use jwt_presentation_validation_options::*;
 pub use jwt_presentation_validator::*;
 pub use jwt_presentation_validator_utils::*;
+pub use validation_cache::PresentationValidationCache;
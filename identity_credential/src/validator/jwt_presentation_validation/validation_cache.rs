@@ -0,0 +1,232 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::common::Duration;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsVerifier;
+
+use super::CompoundJwtPresentationValidationError;
+use super::DecodedJwtPresentation;
+use super::JwtPresentationValidationOptions;
+use super::JwtPresentationValidator;
+use crate::credential::Jwt;
+
+const NONCE: &str = "nonce";
+
+#[derive(Debug)]
+struct CacheEntry<CRED, T> {
+  presentation: DecodedJwtPresentation<CRED, T>,
+  expires: Timestamp,
+}
+
+/// A short-lived cache of successfully validated presentations, for verifiers that see the same presentation several
+/// times, e.g. on retries or behind a load balancer. See [`JwtPresentationValidator::validate_cached`].
+///
+/// Entries are keyed by the SHA-256 hash of the presentation JWT, the holder's DID and a policy version chosen by the
+/// verifier, and expire after the time to live of the cache or the expiration of the presentation, whichever is
+/// earlier. Failed validations are never cached.
+#[derive(Debug)]
+pub struct PresentationValidationCache<CRED = Jwt, T = Object> {
+  ttl: Duration,
+  max_entries: usize,
+  entries: Mutex<HashMap<[u8; SHA256_LEN], CacheEntry<CRED, T>>>,
+}
+
+impl<CRED, T> PresentationValidationCache<CRED, T> {
+  /// Creates a new cache holding at most `max_entries` presentations for at most `ttl` each.
+  pub fn new(ttl: Duration, max_entries: usize) -> Self {
+    Self {
+      ttl,
+      max_entries,
+      entries: Mutex::new(HashMap::new()),
+    }
+  }
+
+  /// Returns the number of cached presentations, including expired ones not yet evicted.
+  pub fn len(&self) -> usize {
+    self.entries.lock().unwrap_or_else(PoisonError::into_inner).len()
+  }
+
+  /// Returns `true` if the cache holds no presentations.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Removes all cached presentations, e.g. after the verifier's trusted documents changed.
+  pub fn clear(&self) {
+    self.entries.lock().unwrap_or_else(PoisonError::into_inner).clear();
+  }
+
+  fn key(presentation: &Jwt, holder: &CoreDocument, policy_version: &str) -> [u8; SHA256_LEN] {
+    // Neither DIDs nor compact JWTs contain line breaks, so the components cannot be confused.
+    let input: String = [policy_version, holder.id().as_str(), presentation.as_str()].join("\n");
+    let mut key: [u8; SHA256_LEN] = [0; SHA256_LEN];
+    SHA256(input.as_bytes(), &mut key);
+    key
+  }
+
+  fn get(&self, key: &[u8; SHA256_LEN], now: Timestamp) -> Option<DecodedJwtPresentation<CRED, T>>
+  where
+    CRED: Clone,
+    T: Clone,
+  {
+    let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+    match entries.get(key) {
+      Some(entry) if entry.expires > now => Some(entry.presentation.clone()),
+      Some(_) => {
+        entries.remove(key);
+        None
+      }
+      None => None,
+    }
+  }
+
+  fn insert(&self, key: [u8; SHA256_LEN], presentation: DecodedJwtPresentation<CRED, T>, now: Timestamp) {
+    let Some(mut expires) = now.checked_add(self.ttl) else {
+      return;
+    };
+    if let Some(expiration_date) = presentation.expiration_date {
+      expires = expires.min(expiration_date);
+    }
+    if self.max_entries == 0 || expires <= now {
+      return;
+    }
+
+    let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+    entries.retain(|_, entry| entry.expires > now);
+    if !entries.contains_key(&key) && entries.len() >= self.max_entries {
+      let earliest: Option<[u8; SHA256_LEN]> = entries
+        .iter()
+        .min_by_key(|(_, entry)| entry.expires)
+        .map(|(key, _)| *key);
+      if let Some(earliest) = earliest {
+        entries.remove(&earliest);
+      }
+    }
+    entries.insert(key, CacheEntry { presentation, expires });
+  }
+}
+
+impl<V: JwsVerifier> JwtPresentationValidator<V> {
+  /// Validates a presentation like [`Self::validate`](JwtPresentationValidator::validate()), returning the result of
+  /// an earlier successful validation from `cache` if present.
+  ///
+  /// `policy_version` identifies the verifier's validation policy and must change whenever `options` or the trusted
+  /// documents change, so that results of a previous policy are not reused.
+  ///
+  /// Nonce-bound presentations are never cached, so that every presentation answering a challenge goes through the
+  /// verifier's replay protection: validation bypasses the cache if `options` expect a nonce, or if the presentation
+  /// carries a `nonce` in its protected header or claims.
+  pub fn validate_cached<HDOC, CRED, T>(
+    &self,
+    presentation: &Jwt,
+    holder: &HDOC,
+    options: &JwtPresentationValidationOptions,
+    cache: &PresentationValidationCache<CRED, T>,
+    policy_version: &str,
+  ) -> Result<DecodedJwtPresentation<CRED, T>, CompoundJwtPresentationValidationError>
+  where
+    HDOC: AsRef<CoreDocument> + ?Sized,
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned + Clone,
+    CRED: ToOwned<Owned = CRED> + serde::Serialize + serde::de::DeserializeOwned + Clone,
+  {
+    if options.presentation_verifier_options.nonce.is_some() {
+      return self.validate(presentation, holder, options);
+    }
+
+    let now: Timestamp = Timestamp::now_utc();
+    let key: [u8; SHA256_LEN] =
+      PresentationValidationCache::<CRED, T>::key(presentation, holder.as_ref(), policy_version);
+    if let Some(decoded) = cache.get(&key, now) {
+      return Ok(decoded);
+    }
+
+    let decoded: DecodedJwtPresentation<CRED, T> = self.validate(presentation, holder, options)?;
+    let nonce_bound: bool = decoded.header.nonce().is_some()
+      || decoded
+        .custom_claims
+        .as_ref()
+        .is_some_and(|claims| claims.contains_key(NONCE));
+    if !nonce_bound {
+      cache.insert(key, decoded.clone(), now);
+    }
+    Ok(decoded)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+  use super::*;
+  use crate::presentation::JwtPresentationOptions;
+  use crate::presentation::Presentation;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_presentation_jwt;
+
+  #[test]
+  fn validate_cached() {
+    let (holder, secret_key, fragment) = generate_jwk_document_with_keys();
+    let presentation: Presentation<Jwt> =
+      Presentation::builder(Url::parse(holder.id().as_str()).unwrap(), Object::new())
+        .build()
+        .unwrap();
+    let validator = JwtPresentationValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+    let options = JwtPresentationValidationOptions::default();
+    let cache: PresentationValidationCache = PresentationValidationCache::new(Duration::minutes(5), 10);
+
+    let jwt: Jwt = sign_presentation_jwt(
+      &presentation,
+      &JwtPresentationOptions::default(),
+      &holder,
+      &fragment,
+      &secret_key,
+    );
+    validator
+      .validate_cached(&jwt, &holder, &options, &cache, "v1")
+      .unwrap();
+    assert_eq!(cache.len(), 1);
+    validator
+      .validate_cached(&jwt, &holder, &options, &cache, "v1")
+      .unwrap();
+    assert_eq!(cache.len(), 1);
+    // A new policy version does not reuse earlier results.
+    validator
+      .validate_cached(&jwt, &holder, &options, &cache, "v2")
+      .unwrap();
+    assert_eq!(cache.len(), 2);
+
+    // Nonce-bound presentations are not cached.
+    let mut presentation_options = JwtPresentationOptions::default();
+    presentation_options.custom_claims = Some(Object::from_iter([(NONCE.to_owned(), "challenge".into())]));
+    let jwt: Jwt = sign_presentation_jwt(&presentation, &presentation_options, &holder, &fragment, &secret_key);
+    validator
+      .validate_cached(&jwt, &holder, &options, &cache, "v1")
+      .unwrap();
+    assert_eq!(cache.len(), 2);
+
+    // Presentations that failed validation are not cached.
+    let (other, _, _) = generate_jwk_document_with_keys();
+    let jwt: Jwt = sign_presentation_jwt(
+      &presentation,
+      &JwtPresentationOptions::default(),
+      &holder,
+      &fragment,
+      &secret_key,
+    );
+    assert!(validator.validate_cached(&jwt, &other, &options, &cache, "v1").is_err());
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+  }
+}
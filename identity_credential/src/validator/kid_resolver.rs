@@ -0,0 +1,167 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::Decoder;
+
+use crate::validator::JwtValidationError;
+use crate::validator::SignerContext;
+
+/// A user-supplied hook mapping the `kid` of a JWS protected header to the DID document of the signer and the
+/// fragment of the verification method that produced the signature.
+///
+/// By default the `kid` of a JWS is expected to be the DID URL of a verification method. Implementing this trait
+/// allows validating JWS whose `kid` follows a different scheme, e.g. URNs mapped to DIDs through a key registry.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait KidResolver<DOC> {
+  /// Resolves `kid` to the DID document of the signer and the fragment of the verification method identified by it.
+  async fn resolve_kid(&self, kid: &str) -> anyhow::Result<(DOC, String)>;
+}
+
+/// Extracts the `kid` from the protected header of the issuer's `jws` and resolves it to a document and the id of one
+/// of its methods using `resolver`.
+pub(crate) async fn resolve_jws_kid<DOC, R>(jws: &str, resolver: &R) -> Result<(DOC, DIDUrl), JwtValidationError>
+where
+  DOC: AsRef<CoreDocument>,
+  R: KidResolver<DOC> + ?Sized,
+{
+  let kid: String = {
+    let decoded = Decoder::new()
+      .decode_compact_serialization(jws.as_bytes(), None)
+      .map_err(JwtValidationError::JwsDecodingError)?;
+    decoded
+      .protected_header()
+      .and_then(|header| header.kid())
+      .ok_or(JwtValidationError::MethodDataLookupError {
+        source: None,
+        message: "could not extract kid from protected header",
+        signer_ctx: SignerContext::Issuer,
+      })?
+      .to_owned()
+  };
+
+  let (document, fragment): (DOC, String) =
+    resolver
+      .resolve_kid(&kid)
+      .await
+      .map_err(|err| JwtValidationError::MethodDataLookupError {
+        source: Some(err.into()),
+        message: "could not resolve kid",
+        signer_ctx: SignerContext::Issuer,
+      })?;
+
+  let mut method_id: DIDUrl = document.as_ref().id().to_url();
+  method_id
+    .set_fragment(Some(&fragment))
+    .map_err(|err| JwtValidationError::MethodDataLookupError {
+      source: Some(err.into()),
+      message: "the resolved fragment is invalid",
+      signer_ctx: SignerContext::Issuer,
+    })?;
+
+  Ok((document, method_id))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use async_trait::async_trait;
+  use identity_core::common::Object;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_document::document::CoreDocument;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+  use super::KidResolver;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Jwt;
+  use crate::credential::Subject;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_credential_jwt_with_kid;
+  use crate::validator::FailFast;
+  use crate::validator::JwtCredentialValidationOptions;
+  use crate::validator::JwtCredentialValidator;
+  use crate::validator::JwtValidationError;
+
+  const KID: &str = "urn:example:registry:key-1";
+
+  /// Maps URNs to the documents and fragments registered for them.
+  struct Registry(HashMap<String, (CoreDocument, String)>);
+
+  #[async_trait]
+  impl KidResolver<CoreDocument> for Registry {
+    async fn resolve_kid(&self, kid: &str) -> anyhow::Result<(CoreDocument, String)> {
+      self
+        .0
+        .get(kid)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("unknown kid {kid}"))
+    }
+  }
+
+  fn setup() -> (Registry, Jwt) {
+    let (document, secret_key, fragment) = generate_jwk_document_with_keys();
+    let credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(document.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .issuance_date(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+      .build()
+      .unwrap();
+    let jwt: Jwt = sign_credential_jwt_with_kid(&credential, &document, &fragment, &secret_key, KID);
+
+    (Registry([(KID.to_owned(), (document, fragment))].into()), jwt)
+  }
+
+  #[tokio::test]
+  async fn validate_with_kid_resolver() {
+    let (registry, jwt) = setup();
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+
+    validator
+      .validate_with_kid_resolver::<_, Object, _>(
+        &jwt,
+        &registry,
+        &JwtCredentialValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .await
+      .unwrap();
+
+    // Without the resolver, the `kid` cannot be parsed as a DID URL.
+    let (issuer, _) = registry.0.get(KID).unwrap();
+    assert!(validator
+      .validate::<_, Object>(
+        &jwt,
+        issuer,
+        &JwtCredentialValidationOptions::default(),
+        FailFast::FirstError
+      )
+      .is_err());
+  }
+
+  #[tokio::test]
+  async fn validate_with_kid_resolver_unknown_kid() {
+    let (_, jwt) = setup();
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+
+    let error = validator
+      .validate_with_kid_resolver::<_, Object, _>(
+        &jwt,
+        &Registry(HashMap::new()),
+        &JwtCredentialValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .await
+      .unwrap_err();
+    assert!(matches!(
+      error.validation_errors.as_slice(),
+      [JwtValidationError::MethodDataLookupError { .. }]
+    ));
+  }
+}
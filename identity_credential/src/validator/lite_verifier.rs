@@ -0,0 +1,190 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jws::JwsVerifier;
+
+use super::CompoundCredentialValidationError;
+use super::DecodedJwtCredential;
+use super::JwtCredentialValidator;
+use super::JwtCredentialValidatorUtils;
+use super::JwtValidationError;
+use super::StatusCheck;
+use crate::credential::Jwt;
+
+/// A minimal verifier for [`Credential`](crate::credential::Credential)s issued as JWTs, intended for constrained
+/// environments such as enclaves or embedded devices.
+///
+/// The verifier does not resolve DIDs. Instead, the DID Documents of all trusted issuers must be supplied up front,
+/// e.g. bundled with the firmware or provisioned over an authenticated channel. Only the following checks are
+/// performed:
+/// - the issuer's signature on the JWS,
+/// - the expiration and issuance dates against a caller-supplied time,
+/// - the `RevocationBitmap2022` status against the issuer's DID Document.
+///
+/// Enable it with the `verifier-lite` feature, ideally with `default-features = false`.
+pub struct LiteJwtCredentialVerifier<V: JwsVerifier> {
+  validator: JwtCredentialValidator<V>,
+  trusted_issuers: Vec<CoreDocument>,
+}
+
+impl<V: JwsVerifier> LiteJwtCredentialVerifier<V> {
+  /// Creates a new [`LiteJwtCredentialVerifier`] that delegates cryptographic signature verification to
+  /// `signature_verifier` and accepts credentials issued by one of the `trusted_issuers`.
+  pub fn new(signature_verifier: V, trusted_issuers: impl IntoIterator<Item = CoreDocument>) -> Self {
+    Self {
+      validator: JwtCredentialValidator::with_signature_verifier(signature_verifier),
+      trusted_issuers: trusted_issuers.into_iter().collect(),
+    }
+  }
+
+  /// Returns the DID Documents of the trusted issuers.
+  pub fn trusted_issuers(&self) -> &[CoreDocument] {
+    &self.trusted_issuers
+  }
+
+  /// Adds or replaces the DID Document of a trusted issuer, e.g. after a key rotation.
+  pub fn set_trusted_issuer(&mut self, issuer: CoreDocument) {
+    match self
+      .trusted_issuers
+      .iter_mut()
+      .find(|trusted| trusted.id() == issuer.id())
+    {
+      Some(trusted) => *trusted = issuer,
+      None => self.trusted_issuers.push(issuer),
+    }
+  }
+
+  /// Decodes and verifies a [`Credential`](crate::credential::Credential) issued as a JWT, using `now` as the
+  /// current time.
+  ///
+  /// Passing the time explicitly allows the verifier to be used on targets without a system clock.
+  ///
+  /// # Errors
+  /// Returns all failed checks. If the signature cannot be verified no further checks are performed.
+  pub fn verify<T>(
+    &self,
+    credential: &Jwt,
+    now: Timestamp,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    let decoded: DecodedJwtCredential<T> = self
+      .validator
+      .verify_signature(credential, &self.trusted_issuers, &JwsVerificationOptions::default())
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })?;
+
+    let validation_errors: Vec<JwtValidationError> = [
+      JwtCredentialValidatorUtils::check_expires_on_or_after(&decoded.credential, now),
+      JwtCredentialValidatorUtils::check_issued_on_or_before(&decoded.credential, now),
+      JwtCredentialValidatorUtils::check_status(&decoded.credential, &self.trusted_issuers, StatusCheck::Strict),
+    ]
+    .into_iter()
+    .filter_map(Result::err)
+    .collect();
+
+    if validation_errors.is_empty() {
+      Ok(decoded)
+    } else {
+      Err(CompoundCredentialValidationError { validation_errors })
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Duration;
+  use identity_core::common::Object;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_did::DIDUrl;
+  use identity_did::DID;
+  use identity_document::document::CoreDocument;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+  use super::LiteJwtCredentialVerifier;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Jwt;
+  use crate::credential::RevocationBitmapStatus;
+  use crate::credential::Subject;
+  use crate::revocation::RevocationBitmap;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_credential_jwt;
+  use crate::validator::JwtValidationError;
+
+  const REVOCATION_INDEX: u32 = 5;
+
+  fn credential(issuer: &CoreDocument, revocation_service: &DIDUrl) -> Credential {
+    CredentialBuilder::default()
+      .issuer(Url::parse(issuer.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .issuance_date(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+      .expiration_date(Timestamp::parse("2025-01-01T00:00:00Z").unwrap())
+      .status(RevocationBitmapStatus::new(
+        revocation_service.clone(),
+        REVOCATION_INDEX,
+      ))
+      .build()
+      .unwrap()
+  }
+
+  fn now() -> Timestamp {
+    Timestamp::parse("2024-06-01T00:00:00Z").unwrap()
+  }
+
+  #[test]
+  fn verify_checks_signature_expiry_and_revocation() {
+    let (mut issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let service_id: DIDUrl = issuer.id().to_url().join("#revocation").unwrap();
+    issuer
+      .insert_service(RevocationBitmap::new().to_service(service_id.clone()).unwrap())
+      .unwrap();
+    let jwt: Jwt = sign_credential_jwt(&credential(&issuer, &service_id), &issuer, &fragment, &secret_key);
+
+    let mut verifier = LiteJwtCredentialVerifier::new(EdDSAJwsVerifier::default(), [issuer.clone()]);
+    verifier.verify::<Object>(&jwt, now()).unwrap();
+
+    // INVALID: expired.
+    let error = verifier
+      .verify::<Object>(&jwt, now().checked_add(Duration::days(365)).unwrap())
+      .unwrap_err();
+    assert!(matches!(
+      error.validation_errors.as_slice(),
+      [JwtValidationError::ExpirationDate]
+    ));
+
+    // INVALID: revoked.
+    let mut bitmap = RevocationBitmap::new();
+    bitmap.revoke(REVOCATION_INDEX);
+    issuer.remove_service(&service_id);
+    issuer.insert_service(bitmap.to_service(service_id).unwrap()).unwrap();
+    verifier.set_trusted_issuer(issuer);
+    assert_eq!(verifier.trusted_issuers().len(), 1);
+    let error = verifier.verify::<Object>(&jwt, now()).unwrap_err();
+    assert!(matches!(
+      error.validation_errors.as_slice(),
+      [JwtValidationError::Revoked]
+    ));
+  }
+
+  #[test]
+  fn verify_rejects_untrusted_issuer() {
+    let (issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let (other_issuer, _, _) = generate_jwk_document_with_keys();
+    let service_id: DIDUrl = issuer.id().to_url().join("#revocation").unwrap();
+    let jwt: Jwt = sign_credential_jwt(&credential(&issuer, &service_id), &issuer, &fragment, &secret_key);
+
+    let verifier = LiteJwtCredentialVerifier::new(EdDSAJwsVerifier::default(), [other_issuer]);
+    let error = verifier.verify::<Object>(&jwt, now()).unwrap_err();
+    assert!(matches!(
+      error.validation_errors.as_slice(),
+      [JwtValidationError::DocumentMismatch(_)]
+    ));
+  }
+}
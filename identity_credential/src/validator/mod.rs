@@ -9,11 +9,21 @@ pub use self::jpt_credential_validation::*;
 pub use self::jpt_presentation_validation::*;
 pub use self::jwt_credential_validation::*;
 pub use self::jwt_presentation_validation::*;
+pub use self::kid_resolver::KidResolver;
+#[cfg(feature = "verifier-lite")]
+pub use self::lite_verifier::LiteJwtCredentialVerifier;
 pub use self::options::FailFast;
+pub use self::options::IssuerDocumentPolicy;
 pub use self::options::StatusCheck;
 pub use self::options::SubjectHolderRelationship;
 #[cfg(feature = "sd-jwt")]
 pub use self::sd_jwt::*;
+pub use self::status_check_provider::*;
+pub use self::trust_registry::*;
+pub use self::validation_record::*;
+pub use self::verification_policy::*;
+#[cfg(feature = "x509")]
+pub use self::x509::*;
 
 #[cfg(feature = "jpt-bbs-plus")]
 mod jpt_credential_validation;
@@ -21,8 +31,17 @@ mod jpt_credential_validation;
 mod jpt_presentation_validation;
 mod jwt_credential_validation;
 mod jwt_presentation_validation;
+mod kid_resolver;
+#[cfg(feature = "verifier-lite")]
+mod lite_verifier;
 mod options;
 #[cfg(feature = "sd-jwt")]
 mod sd_jwt;
+mod status_check_provider;
 #[cfg(test)]
 pub(crate) mod test_utils;
+mod trust_registry;
+mod validation_record;
+mod verification_policy;
+#[cfg(feature = "x509")]
+mod x509;
@@ -7,12 +7,14 @@ use serde::Serialize;
 /// Controls validation behaviour when checking whether or not a credential has been revoked by its
 /// [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
 pub enum StatusCheck {
   /// Validate the status if supported, reject any unsupported
   /// [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status) types.
   ///
-  /// Only `RevocationBitmap2022` is currently supported.
+  /// The supported types are those of the
+  /// [`StatusCheckProvider`](crate::validator::StatusCheckProvider) in use, by default only `RevocationBitmap2022`.
   ///
   /// This is the default.
   Strict = 0,
@@ -34,6 +36,7 @@ impl Default for StatusCheck {
 /// See also the [Subject-Holder Relationship](https://www.w3.org/TR/vc-data-model/#subject-holder-relationships) section of the specification.
 // Need to use serde_repr to make this work with duck typed interfaces in the Wasm bindings.
 #[derive(Debug, Clone, Copy, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
 pub enum SubjectHolderRelationship {
   /// The holder must always match the subject on all credentials, regardless of their [`nonTransferable`](https://www.w3.org/TR/vc-data-model/#nontransferable-property) property.
@@ -52,8 +55,29 @@ impl Default for SubjectHolderRelationship {
   }
 }
 
+/// Declares which version of the issuer's DID Document the signature of a credential is verified against.
+///
+/// See [`JwtCredentialValidator::validate_with_issuer_history`](crate::validator::JwtCredentialValidator::validate_with_issuer_history).
+// Need to use serde_repr to make this work with duck typed interfaces in the Wasm bindings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[repr(u8)]
+pub enum IssuerDocumentPolicy {
+  /// Verify the signature against the latest version of the issuer's DID Document.
+  ///
+  /// This is the default.
+  #[default]
+  Latest = 0,
+  /// Verify the signature against the version of the issuer's DID Document that was current when the credential was
+  /// issued.
+  AtIssuance = 1,
+  /// Accept the signature if it can be verified against either the latest version or the version current at issuance.
+  Either = 2,
+}
+
 /// Declares when validation should return if an error occurs.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum FailFast {
   /// Return all errors that occur during validation.
   AllErrors,
@@ -42,4 +42,12 @@ pub enum KeyBindingJwtError {
   /// Header value `typ` is invalid.
   #[error("header `typ` value is missing or not equal to `kb+jwt`")]
   InvalidHeaderTypValue,
+
+  /// A required claim is missing.
+  #[error("the required claim `{0}` is missing")]
+  MissingRequiredClaim(String),
+
+  /// The hasher does not implement the hash algorithm of the SD-JWT.
+  #[error("the hasher `{0}` does not match the `_sd_alg` value of the SD-JWT")]
+  HasherMismatch(String),
 }
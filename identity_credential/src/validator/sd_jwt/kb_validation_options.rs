@@ -28,6 +28,14 @@ pub struct KeyBindingJWTValidationOptions {
   /// Uses the current timestamp during validation if not set.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub latest_issuance_date: Option<Timestamp>,
+  /// The number of seconds by which the `iat` value of the KB-JWT may deviate from the earliest and latest issuance
+  /// dates, to account for clock skew between holder and verifier.
+  /// Defaults to zero.
+  #[serde(default)]
+  pub leeway: u32,
+  /// Claims that must be present in the KB-JWT in addition to `iat`, `aud`, `nonce` and `_sd_hash`.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub required_claims: Vec<String>,
 }
 
 impl KeyBindingJWTValidationOptions {
@@ -68,4 +76,17 @@ impl KeyBindingJWTValidationOptions {
     self.latest_issuance_date = Some(latest_issuance_date);
     self
   }
+
+  /// Allow the `iat` value of the KB-JWT to deviate by up to `seconds` from the earliest and latest issuance dates, to
+  /// account for clock skew between holder and verifier.
+  pub fn leeway(mut self, seconds: u32) -> Self {
+    self.leeway = seconds;
+    self
+  }
+
+  /// Declares that the KB-JWT is considered invalid if it does not contain the claim `claim`.
+  pub fn required_claim(mut self, claim: impl Into<String>) -> Self {
+    self.required_claims.push(claim.into());
+    self
+  }
 }
@@ -0,0 +1,209 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::validator::JwtValidationError;
+use crate::validator::SignerContext;
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::DecodedJws;
+use identity_verification::jws::Decoder;
+use identity_verification::jws::JwsValidationItem;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::MethodRelationship;
+use sd_jwt_payload::KeyBindingJwtClaims;
+use sd_jwt_payload::SdJwt;
+use sd_jwt_payload::SdObjectDecoder;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::KeyBindingJWTValidationOptions;
+use super::KeyBindingJwtBuilder;
+use super::KeyBindingJwtError;
+
+/// A type for validating the Key Binding JWT (KB-JWT) of an [`SdJwt`].
+#[non_exhaustive]
+pub struct KeyBindingJWTValidator<V: JwsVerifier>(V, SdObjectDecoder);
+
+impl<V: JwsVerifier> KeyBindingJWTValidator<V> {
+  /// Creates a new [`KeyBindingJWTValidator`] that delegates cryptographic signature verification to the given
+  /// `signature_verifier` and determines the hash algorithm of the `sd_hash` claim with `sd_decoder`.
+  pub fn with_signature_verifier(signature_verifier: V, sd_decoder: SdObjectDecoder) -> Self {
+    Self(signature_verifier, sd_decoder)
+  }
+
+  /// Validates the KB-JWT of `sd_jwt` according to `https://www.ietf.org/archive/id/draft-ietf-oauth-selective-disclosure-jwt-07.html#name-key-binding-jwt`.
+  ///
+  /// The validation process includes:
+  ///   * Signature validation using public key materials defined in the `holder` document.
+  ///   * `typ` value in KB-JWT header.
+  ///   * `sd_hash` claim value in the KB-JWT claim.
+  ///   * Presence of the claims required by `options`.
+  ///   * Optional `nonce`, `aud` and issuance date validation, allowing for the leeway set in `options`.
+  pub fn validate<DOC>(
+    &self,
+    sd_jwt: &SdJwt,
+    holder: &DOC,
+    options: &KeyBindingJWTValidationOptions,
+  ) -> Result<KeyBindingJwtClaims, KeyBindingJwtError>
+  where
+    DOC: AsRef<CoreDocument>,
+  {
+    validate_key_binding_jwt(&self.0, &self.1, sd_jwt, holder, options)
+  }
+}
+
+/// Validates the KB-JWT of `sd_jwt`, see [`KeyBindingJWTValidator::validate`].
+pub(super) fn validate_key_binding_jwt<V, DOC>(
+  verifier: &V,
+  sd_decoder: &SdObjectDecoder,
+  sd_jwt: &SdJwt,
+  holder: &DOC,
+  options: &KeyBindingJWTValidationOptions,
+) -> Result<KeyBindingJwtClaims, KeyBindingJwtError>
+where
+  V: JwsVerifier,
+  DOC: AsRef<CoreDocument>,
+{
+  // Check if KB exists in the SD-JWT.
+  let kb_jwt: &str = sd_jwt
+    .key_binding_jwt
+    .as_deref()
+    .ok_or(KeyBindingJwtError::MissingKeyBindingJwt)?;
+
+  // Calculate the digest from the `sd_jwt.jwt` and the disclosures.
+  let sd_jwt_claims: Map<String, Value> = decode_sd_jwt_claims(&sd_jwt.jwt)?;
+  let hasher = sd_decoder.determine_hasher(&sd_jwt_claims)?;
+  let digest: String = KeyBindingJwtBuilder::sd_hash(sd_jwt, hasher);
+
+  // Verify the signature of the KB-JWT and extract claims.
+  let kb_decoded: JwsValidationItem<'_> = Decoder::new()
+    .decode_compact_serialization(kb_jwt.as_bytes(), None)
+    .map_err(JwtValidationError::JwsDecodingError)?;
+  let typ: &str = kb_decoded
+    .protected_header()
+    .ok_or(KeyBindingJwtError::InvalidHeaderTypValue)?
+    .typ()
+    .ok_or(KeyBindingJwtError::InvalidHeaderTypValue)?;
+
+  if typ != KeyBindingJwtClaims::KB_JWT_HEADER_TYP {
+    return Err(KeyBindingJwtError::InvalidHeaderTypValue);
+  }
+  let method_id: DIDUrl = match &options.jws_options.method_id {
+    Some(method_id) => method_id.clone(),
+    None => {
+      let kid: &str = kb_decoded.protected_header().and_then(|header| header.kid()).ok_or(
+        JwtValidationError::MethodDataLookupError {
+          source: None,
+          message: "could not extract kid from protected header",
+          signer_ctx: SignerContext::Holder,
+        },
+      )?;
+
+      // Convert kid to DIDUrl
+      DIDUrl::parse(kid).map_err(|err| JwtValidationError::MethodDataLookupError {
+        source: Some(err.into()),
+        message: "could not parse kid as a DID Url",
+        signer_ctx: SignerContext::Holder,
+      })?
+    }
+  };
+
+  // Obtain the public key from the holder's DID document
+  let public_key: &Jwk = holder
+    .as_ref()
+    .resolve_method(
+      &method_id,
+      options
+        .jws_options
+        .scope_for_purpose(MethodRelationship::Authentication),
+    )
+    .and_then(|method| method.data().public_key_jwk())
+    .ok_or_else(|| JwtValidationError::MethodDataLookupError {
+      source: None,
+      message: "could not extract JWK from a method identified by kid",
+      signer_ctx: SignerContext::Holder,
+    })?;
+  let decoded_kb_jws: DecodedJws<'_> =
+    kb_decoded
+      .verify(verifier, public_key)
+      .map_err(|source| JwtValidationError::Signature {
+        source,
+        signer_ctx: SignerContext::Holder,
+      })?;
+
+  let kb_jwt_claims: Value = serde_json::from_slice(&decoded_kb_jws.claims)
+    .map_err(|_| KeyBindingJwtError::DeserializationError("failed to deserialize kb-jwt claims".into()))?;
+  if let Some(claim) = options
+    .required_claims
+    .iter()
+    .find(|claim| kb_jwt_claims.get(claim.as_str()).is_none())
+  {
+    return Err(KeyBindingJwtError::MissingRequiredClaim(claim.clone()));
+  }
+  let kb_jwt_claims: KeyBindingJwtClaims = serde_json::from_value(kb_jwt_claims)
+    .map_err(|_| KeyBindingJwtError::DeserializationError("failed to deserialize kb-jwt claims".into()))?;
+
+  // Check if the `_sd_hash` matches.
+  if kb_jwt_claims.sd_hash != digest {
+    return Err(KeyBindingJwtError::InvalidDigest);
+  }
+
+  if let Some(nonce) = &options.nonce {
+    if *nonce != kb_jwt_claims.nonce {
+      return Err(KeyBindingJwtError::InvalidNonce);
+    }
+  }
+
+  if let Some(aud) = &options.aud {
+    if *aud != kb_jwt_claims.aud {
+      return Err(KeyBindingJwtError::AudianceMismatch);
+    }
+  }
+
+  let issuance_date = Timestamp::from_unix(kb_jwt_claims.iat)
+    .map_err(|_| KeyBindingJwtError::IssuanceDate("deserialization of `iat` failed".to_string()))?;
+  let leeway: Duration = Duration::seconds(options.leeway);
+
+  if let Some(earliest_issuance_date) = options.earliest_issuance_date {
+    if issuance_date
+      < earliest_issuance_date
+        .checked_sub(leeway)
+        .unwrap_or(earliest_issuance_date)
+    {
+      return Err(KeyBindingJwtError::IssuanceDate(
+        "value is earlier than `earliest_issuance_date`".to_string(),
+      ));
+    }
+  }
+
+  if let Some(latest_issuance_date) = options.latest_issuance_date {
+    if issuance_date > latest_issuance_date.checked_add(leeway).unwrap_or(latest_issuance_date) {
+      return Err(KeyBindingJwtError::IssuanceDate(
+        "value is later than `latest_issuance_date`".to_string(),
+      ));
+    }
+  } else {
+    let now: Timestamp = Timestamp::now_utc();
+    if issuance_date > now.checked_add(leeway).unwrap_or(now) {
+      return Err(KeyBindingJwtError::IssuanceDate("value is in the future".to_string()));
+    }
+  }
+
+  Ok(kb_jwt_claims)
+}
+
+/// Decodes the claims of the issuer-signed JWT of an SD-JWT without verifying its signature.
+pub(super) fn decode_sd_jwt_claims(jwt: &str) -> Result<Map<String, Value>, KeyBindingJwtError> {
+  let decoded: JwsValidationItem<'_> = Decoder::new()
+    .decode_compact_serialization(jwt.as_bytes(), None)
+    .map_err(JwtValidationError::JwsDecodingError)?;
+  match serde_json::from_slice(decoded.claims()) {
+    Ok(Value::Object(claims)) => Ok(claims),
+    _ => Err(KeyBindingJwtError::DeserializationError(
+      "failed to deserialize sd-jwt claims".to_string(),
+    )),
+  }
+}
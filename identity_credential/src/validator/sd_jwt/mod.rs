@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod error;
+mod kb_jwt_builder;
 mod kb_validation_options;
+mod kb_validator;
 mod validator;
 
 pub use error::*;
+pub use kb_jwt_builder::*;
 pub use kb_validation_options::*;
+pub use kb_validator::*;
 pub use validator::*;
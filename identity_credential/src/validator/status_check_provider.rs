@@ -0,0 +1,425 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+
+use identity_document::document::CoreDocument;
+
+use crate::credential::Status;
+use crate::validator::JwtValidationError;
+
+/// Checks the [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status) of credentials during validation.
+///
+/// Decouples status checks from how status information is obtained, e.g. from the issuer's DID document, from
+/// status list credentials fetched over HTTPS or from a cache of such lists when offline. Select a provider with
+/// [`JwtCredentialValidationOptions::status_provider`](crate::validator::JwtCredentialValidationOptions::status_provider).
+pub trait StatusCheckProvider: Debug + Send + Sync {
+  /// Returns whether statuses of type `status_type` can be checked.
+  ///
+  /// Unsupported statuses are rejected or skipped according to the
+  /// [`StatusCheck`](crate::validator::StatusCheck) of the validation options.
+  fn supports(&self, status_type: &str) -> bool;
+
+  /// Checks `status` of a credential issued by `issuer`, failing if the credential was revoked or suspended.
+  fn check_status(&self, status: &Status, issuer: &CoreDocument) -> Result<(), JwtValidationError>;
+}
+
+/// A [`StatusCheckProvider`] checking `RevocationBitmap2022` statuses against the bitmaps embedded in the services
+/// of the issuer's DID document.
+///
+/// This is the default provider.
+#[cfg(feature = "revocation-bitmap")]
+#[derive(Debug, Default, Clone, Copy)]
+#[non_exhaustive]
+pub struct RevocationBitmapStatusProvider;
+
+#[cfg(feature = "revocation-bitmap")]
+impl StatusCheckProvider for RevocationBitmapStatusProvider {
+  fn supports(&self, status_type: &str) -> bool {
+    status_type == crate::revocation::RevocationBitmap::TYPE
+  }
+
+  fn check_status(&self, status: &Status, issuer: &CoreDocument) -> Result<(), JwtValidationError> {
+    let status: crate::credential::RevocationBitmapStatus =
+      crate::credential::RevocationBitmapStatus::try_from(status.clone()).map_err(JwtValidationError::InvalidStatus)?;
+    crate::validator::JwtCredentialValidatorUtils::check_revocation_bitmap_status(issuer, status)
+  }
+}
+
+#[cfg(feature = "status-list-2021")]
+pub use self::status_list::*;
+
+#[cfg(feature = "status-list-2021")]
+mod status_list {
+  use std::collections::HashMap;
+  use std::sync::RwLock;
+
+  use identity_core::common::Duration;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_document::document::CoreDocument;
+
+  use super::StatusCheckProvider;
+  use crate::credential::Status;
+  use crate::revocation::status_list_2021::CredentialStatus;
+  use crate::revocation::status_list_2021::StatusList2021Credential;
+  use crate::revocation::status_list_2021::StatusList2021Entry;
+  use crate::validator::JwtValidationError;
+
+  fn invalid_status(message: String) -> JwtValidationError {
+    JwtValidationError::InvalidStatus(crate::Error::InvalidStatus(message))
+  }
+
+  #[derive(Debug)]
+  struct CachedStatusList {
+    credential: StatusList2021Credential,
+    fetched_at: Timestamp,
+  }
+
+  /// A [`StatusCheckProvider`] checking `StatusList2021Entry` statuses against a cache of status list credentials,
+  /// e.g. for validating credentials offline.
+  ///
+  /// Status lists are added with [`CachedStatusListProvider::insert`] and are accepted up to the maximum age, if
+  /// any, after they were fetched. The status list credentials must have been verified before inserting them, only
+  /// their issuer is checked to be the issuer of the credential.
+  #[derive(Debug, Default)]
+  pub struct CachedStatusListProvider {
+    lists: RwLock<HashMap<Url, CachedStatusList>>,
+    max_age: Option<Duration>,
+  }
+
+  impl CachedStatusListProvider {
+    /// Creates an empty [`CachedStatusListProvider`] accepting status lists of any age.
+    pub fn new() -> Self {
+      Self::default()
+    }
+
+    /// Only accept status lists fetched at most `max_age` ago.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+      self.max_age = Some(max_age);
+      self
+    }
+
+    /// Caches `credential`, fetched at `fetched_at`, under its `id`, replacing any list with the same `id`.
+    pub fn insert(&self, credential: StatusList2021Credential, fetched_at: Timestamp) -> Result<(), crate::Error> {
+      let id: Url = credential
+        .id
+        .clone()
+        .ok_or_else(|| crate::Error::InvalidStatus("status list credential has no id".to_owned()))?;
+      self
+        .lists
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(id, CachedStatusList { credential, fetched_at });
+      Ok(())
+    }
+
+    /// Returns whether the status list credential identified by `id` is cached and within the maximum age.
+    pub fn is_fresh(&self, id: &Url) -> bool {
+      self
+        .lists
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(id)
+        .is_some_and(|list| self.within_max_age(list.fetched_at))
+    }
+
+    fn within_max_age(&self, fetched_at: Timestamp) -> bool {
+      match self.max_age {
+        Some(max_age) => fetched_at
+          .checked_add(max_age)
+          .is_some_and(|expiry| expiry >= Timestamp::now_utc()),
+        None => true,
+      }
+    }
+  }
+
+  impl StatusCheckProvider for CachedStatusListProvider {
+    fn supports(&self, status_type: &str) -> bool {
+      status_type == StatusList2021Entry::TYPE
+    }
+
+    fn check_status(&self, status: &Status, issuer: &CoreDocument) -> Result<(), JwtValidationError> {
+      let entry: StatusList2021Entry =
+        StatusList2021Entry::try_from(status).map_err(|err| invalid_status(err.to_string()))?;
+      let lists = self.lists.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+      let list: &CachedStatusList = lists.get(entry.status_list_credential()).ok_or_else(|| {
+        invalid_status(format!(
+          "status list `{}` is not available",
+          entry.status_list_credential()
+        ))
+      })?;
+      if !self.within_max_age(list.fetched_at) {
+        return Err(invalid_status(format!(
+          "cached status list `{}` exceeds the maximum age",
+          entry.status_list_credential()
+        )));
+      }
+      if list.credential.issuer.url().as_str() != issuer.id().as_str() {
+        return Err(invalid_status(
+          "the status list was not issued by the issuer of the credential".to_owned(),
+        ));
+      }
+      if entry.purpose() != list.credential.purpose() {
+        return Err(invalid_status(
+          "the given statusListCredential doesn't match the credential's status".to_owned(),
+        ));
+      }
+
+      match list
+        .credential
+        .entry(entry.index())
+        .map_err(|err| invalid_status(err.to_string()))?
+      {
+        CredentialStatus::Revoked => Err(JwtValidationError::Revoked),
+        CredentialStatus::Suspended => Err(JwtValidationError::Suspended),
+        CredentialStatus::Valid => Ok(()),
+      }
+    }
+  }
+
+  #[cfg(feature = "status-list-2021-fetch")]
+  pub use self::fetch::HttpStatusListProvider;
+
+  #[cfg(feature = "status-list-2021-fetch")]
+  mod fetch {
+    use futures::StreamExt;
+    use identity_core::common::Duration;
+    use identity_core::common::Timestamp;
+    use identity_core::common::Url;
+    use identity_core::convert::FromJson;
+    use identity_document::document::CoreDocument;
+    use reqwest::Client;
+
+    use super::CachedStatusListProvider;
+    use super::StatusCheckProvider;
+    use crate::credential::Credential;
+    use crate::credential::Status;
+    use crate::revocation::status_list_2021::StatusList2021Credential;
+    use crate::revocation::status_list_2021::StatusList2021Entry;
+    use crate::validator::JwtValidationError;
+    use crate::Error::StatusListFetchError;
+
+    /// The maximum size of fetched status list credentials.
+    const MAX_RESPONSE_SIZE: usize = 1_048_576;
+
+    /// A [`StatusCheckProvider`] checking `StatusList2021Entry` statuses against status list credentials hosted
+    /// over HTTPS.
+    ///
+    /// Validation does not perform I/O: fetch the status lists of credentials with
+    /// [`HttpStatusListProvider::prefetch`] before validating them. Fetched lists are cached and only fetched again
+    /// once they exceed the maximum age, such that validation keeps working offline within that age.
+    ///
+    /// The integrity of the status list credentials relies on HTTPS only, their proofs are not verified.
+    #[derive(Debug)]
+    pub struct HttpStatusListProvider {
+      client: Client,
+      cache: CachedStatusListProvider,
+    }
+
+    impl HttpStatusListProvider {
+      /// Creates a new [`HttpStatusListProvider`] accepting status lists fetched at most `max_age` ago.
+      pub fn new(max_age: Duration) -> Result<Self, crate::Error> {
+        let client: Client = reqwest::ClientBuilder::new()
+          .https_only(true)
+          .build()
+          .map_err(|err| StatusListFetchError(Box::new(err)))?;
+        Ok(Self {
+          client,
+          cache: CachedStatusListProvider::new().max_age(max_age),
+        })
+      }
+
+      /// Returns the cache of fetched status lists.
+      pub fn cache(&self) -> &CachedStatusListProvider {
+        &self.cache
+      }
+
+      /// Fetches the status list credential referenced by the `StatusList2021Entry` status of `credential`, unless
+      /// a cached copy is within the maximum age.
+      ///
+      /// Credentials without such a status are ignored.
+      pub async fn prefetch<T>(&self, credential: &Credential<T>) -> Result<(), crate::Error> {
+        let Some(status) = credential
+          .credential_status
+          .as_ref()
+          .filter(|status| status.type_ == StatusList2021Entry::TYPE)
+        else {
+          return Ok(());
+        };
+        let entry: StatusList2021Entry =
+          StatusList2021Entry::try_from(status).map_err(|err| crate::Error::InvalidStatus(err.to_string()))?;
+        if self.cache.is_fresh(entry.status_list_credential()) {
+          return Ok(());
+        }
+        self.fetch(entry.status_list_credential()).await
+      }
+
+      /// Fetches the status list credential at `url` and caches it.
+      ///
+      /// The maximum size of the status list credential that can be retrieved with this method is 1 MiB.
+      pub async fn fetch(&self, url: &Url) -> Result<(), crate::Error> {
+        let mut stream = self
+          .client
+          .get(url.as_str())
+          .send()
+          .await
+          .and_then(|response| response.error_for_status())
+          .map_err(|err| StatusListFetchError(Box::new(err)))?
+          .bytes_stream();
+
+        let mut json: Vec<u8> = Vec::new();
+        while let Some(item) = stream.next().await {
+          json.extend(item.map_err(|err| StatusListFetchError(Box::new(err)))?);
+          if json.len() > MAX_RESPONSE_SIZE {
+            return Err(StatusListFetchError(
+              "status list credential can not exceed 1 MiB".into(),
+            ));
+          }
+        }
+        let credential: StatusList2021Credential =
+          StatusList2021Credential::from_json_slice(&json).map_err(|err| StatusListFetchError(Box::new(err)))?;
+        if credential.id.as_ref() != Some(url) {
+          return Err(StatusListFetchError(
+            "the id of the status list credential does not match its URL".into(),
+          ));
+        }
+        self.cache.insert(credential, Timestamp::now_utc())
+      }
+    }
+
+    impl StatusCheckProvider for HttpStatusListProvider {
+      fn supports(&self, status_type: &str) -> bool {
+        self.cache.supports(status_type)
+      }
+
+      fn check_status(&self, status: &Status, issuer: &CoreDocument) -> Result<(), JwtValidationError> {
+        self.cache.check_status(status, issuer)
+      }
+    }
+  }
+}
+
+#[cfg(all(test, feature = "status-list-2021"))]
+mod tests {
+  use std::sync::Arc;
+
+  use identity_core::common::Duration;
+  use identity_core::common::Object;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_did::CoreDID;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Issuer;
+  use crate::credential::Subject;
+  use crate::revocation::status_list_2021::StatusList2021;
+  use crate::revocation::status_list_2021::StatusList2021Credential;
+  use crate::revocation::status_list_2021::StatusList2021CredentialBuilder;
+  use crate::revocation::status_list_2021::StatusList2021Entry;
+  use crate::revocation::status_list_2021::StatusPurpose;
+  use crate::validator::JwtCredentialValidationOptions;
+  use crate::validator::JwtCredentialValidatorUtils;
+  use crate::validator::StatusCheck;
+
+  const LIST_URL: &str = "https://example.com/status/1";
+
+  fn issuer() -> CoreDocument {
+    CoreDocument::from_json(r#"{ "id": "did:example:issuer" }"#).unwrap()
+  }
+
+  fn status_list(revoked: &[usize]) -> StatusList2021Credential {
+    let mut status_list: StatusList2021 = StatusList2021::default();
+    for index in revoked {
+      status_list.set(*index, true).unwrap();
+    }
+    StatusList2021CredentialBuilder::new(status_list)
+      .subject_id(Url::parse(LIST_URL).unwrap())
+      .issuer(Issuer::Url(Url::parse("did:example:issuer").unwrap()))
+      .build()
+      .unwrap()
+  }
+
+  fn credential(index: usize) -> Credential {
+    let status = StatusList2021Entry::new(Url::parse(LIST_URL).unwrap(), StatusPurpose::Revocation, index, None);
+    CredentialBuilder::default()
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .status(status)
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn cached_status_list_provider() {
+    let provider: CachedStatusListProvider = CachedStatusListProvider::new();
+    provider.insert(status_list(&[3]), Timestamp::now_utc()).unwrap();
+
+    let check = |index: usize| {
+      JwtCredentialValidatorUtils::check_status_with_provider(
+        &credential(index),
+        &[issuer()],
+        StatusCheck::Strict,
+        &provider,
+      )
+    };
+    assert!(check(1).is_ok());
+    assert!(matches!(check(3), Err(JwtValidationError::Revoked)));
+
+    // INVALID: the embedded bitmap provider does not support status lists.
+    assert!(matches!(
+      JwtCredentialValidatorUtils::check_status_with_provider(
+        &credential(1),
+        &[issuer()],
+        StatusCheck::Strict,
+        &RevocationBitmapStatusProvider,
+      ),
+      Err(JwtValidationError::InvalidStatus(_))
+    ));
+  }
+
+  #[test]
+  fn cached_status_list_provider_rejects_stale_and_foreign_lists() {
+    let stale: CachedStatusListProvider = CachedStatusListProvider::new().max_age(Duration::hours(1));
+    let fetched_at: Timestamp = Timestamp::from_unix(Timestamp::now_utc().to_unix() - 7200).unwrap();
+    stale.insert(status_list(&[]), fetched_at).unwrap();
+    assert!(!stale.is_fresh(&Url::parse(LIST_URL).unwrap()));
+    assert!(matches!(
+      stale.check_status(credential(1).credential_status.as_ref().unwrap(), &issuer()),
+      Err(JwtValidationError::InvalidStatus(_))
+    ));
+
+    let provider: CachedStatusListProvider = CachedStatusListProvider::new();
+    provider.insert(status_list(&[]), Timestamp::now_utc()).unwrap();
+    let other: CoreDocument = CoreDocument::from_json(r#"{ "id": "did:example:other" }"#).unwrap();
+    assert!(matches!(
+      provider.check_status(credential(1).credential_status.as_ref().unwrap(), &other),
+      Err(JwtValidationError::InvalidStatus(_))
+    ));
+  }
+
+  #[test]
+  fn status_provider_is_selected_by_options() {
+    let provider: Arc<CachedStatusListProvider> = Arc::new(CachedStatusListProvider::new());
+    provider.insert(status_list(&[5]), Timestamp::now_utc()).unwrap();
+    let options: JwtCredentialValidationOptions = JwtCredentialValidationOptions::new().status_provider(provider);
+
+    let credential: Credential<Object> = credential(5);
+    let issuer_did: CoreDID = JwtCredentialValidatorUtils::extract_issuer(&credential).unwrap();
+    assert_eq!(issuer_did.as_str(), "did:example:issuer");
+    assert!(matches!(
+      JwtCredentialValidatorUtils::check_status_with_options(&credential, &[issuer()], &options),
+      Err(JwtValidationError::Revoked)
+    ));
+    // The default provider does not support status lists.
+    assert!(JwtCredentialValidatorUtils::check_status_with_options(
+      &credential,
+      &[issuer()],
+      &JwtCredentialValidationOptions::new()
+    )
+    .is_err());
+  }
+}
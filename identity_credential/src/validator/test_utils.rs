@@ -5,15 +5,28 @@ use crypto::signatures::ed25519::PublicKey;
 use crypto::signatures::ed25519::SecretKey;
 use identity_core::common::Object;
 use identity_core::convert::BaseEncoding;
+#[cfg(feature = "x509")]
+use identity_core::convert::FromJson;
 use identity_did::CoreDID;
 use identity_document::document::CoreDocument;
 use identity_verification::jwk::EdCurve;
 use identity_verification::jwk::Jwk;
 use identity_verification::jwk::JwkParamsOkp;
+use identity_verification::jws::CharSet;
+use identity_verification::jws::CompactJwsEncoder;
+use identity_verification::jws::CompactJwsEncodingOptions;
 use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsHeader;
 use identity_verification::jwu;
+use identity_verification::MethodData;
 use identity_verification::VerificationMethod;
 
+use crate::credential::Credential;
+use crate::credential::Jws;
+use crate::credential::Jwt;
+use crate::presentation::JwtPresentationOptions;
+use crate::presentation::Presentation;
+
 pub(crate) fn encode_public_ed25519_jwk(public_key: &PublicKey) -> Jwk {
   let x = jwu::encode_b64(public_key.as_ref());
   let mut params = JwkParamsOkp::new();
@@ -39,3 +52,100 @@ pub(crate) fn generate_jwk_document_with_keys() -> (CoreDocument, SecretKey, Str
     .unwrap();
   (document, secret, fragment)
 }
+
+pub(crate) fn sign_credential_jwt(
+  credential: &Credential,
+  document: &CoreDocument,
+  fragment: &str,
+  secret_key: &SecretKey,
+) -> Jwt {
+  let payload: String = credential.serialize_jwt(None).unwrap();
+  Jwt::new(sign_bytes(document, fragment, payload.as_ref(), secret_key, None).into())
+}
+
+/// Signs `credential` like [`sign_credential_jwt`], but sets the `kid` of the JWS to the given value instead of the
+/// method's id.
+pub(crate) fn sign_credential_jwt_with_kid(
+  credential: &Credential,
+  document: &CoreDocument,
+  fragment: &str,
+  secret_key: &SecretKey,
+  kid: &str,
+) -> Jwt {
+  let payload: String = credential.serialize_jwt(None).unwrap();
+  Jwt::new(sign_bytes(document, fragment, payload.as_ref(), secret_key, Some(kid)).into())
+}
+
+pub(crate) fn sign_presentation_jwt(
+  presentation: &Presentation<Jwt>,
+  options: &JwtPresentationOptions,
+  document: &CoreDocument,
+  fragment: &str,
+  secret_key: &SecretKey,
+) -> Jwt {
+  let payload: String = presentation.serialize_jwt(options).unwrap();
+  Jwt::new(sign_bytes(document, fragment, payload.as_ref(), secret_key, None).into())
+}
+
+fn sign_bytes(
+  document: &CoreDocument,
+  fragment: &str,
+  payload: &[u8],
+  secret_key: &SecretKey,
+  kid: Option<&str>,
+) -> Jws {
+  let method: &VerificationMethod = document.resolve_method(fragment, None).unwrap();
+  let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
+    panic!("not a jwk");
+  };
+  let alg: JwsAlgorithm = jwk.alg().unwrap_or("").parse().unwrap();
+
+  let header: JwsHeader = {
+    let mut header = JwsHeader::new();
+    header.set_alg(alg);
+    header.set_kid(kid.map(ToOwned::to_owned).unwrap_or_else(|| method.id().to_string()));
+    header
+  };
+
+  let encoding_options: CompactJwsEncodingOptions = CompactJwsEncodingOptions::NonDetached {
+    charset_requirements: CharSet::Default,
+  };
+
+  let jws_encoder: CompactJwsEncoder<'_> =
+    CompactJwsEncoder::new_with_options(payload, &header, encoding_options).unwrap();
+
+  let signature: [u8; 64] = secret_key.sign(jws_encoder.signing_input()).to_bytes();
+
+  Jws::new(jws_encoder.into_jws(&signature))
+}
+
+// Ed25519 certificate chain: root -> intermediate -> leaf. The leaf certifies the key `X509_LEAF_KEY`.
+// The fixtures are shared with the key attestation tests of `identity_storage`.
+#[cfg(feature = "x509")]
+pub(crate) const X509_ROOT_CERTIFICATE: &[u8] = include_bytes!("../../tests/fixtures/x509/root.der");
+#[cfg(feature = "x509")]
+pub(crate) const X509_INTERMEDIATE_CERTIFICATE: &[u8] = include_bytes!("../../tests/fixtures/x509/intermediate.der");
+#[cfg(feature = "x509")]
+pub(crate) const X509_LEAF_CERTIFICATE: &[u8] = include_bytes!("../../tests/fixtures/x509/leaf.der");
+#[cfg(feature = "x509")]
+pub(crate) const X509_OTHER_ROOT_CERTIFICATE: &[u8] = include_bytes!("../../tests/fixtures/x509/other-root.der");
+#[cfg(feature = "x509")]
+pub(crate) const X509_LEAF_KEY: &str = include_str!("../../tests/fixtures/x509/leaf-key.json");
+
+/// Returns the Ed25519 JWK certified by `X509_LEAF_CERTIFICATE` with the chain of the leaf and intermediate
+/// certificates as `x5c`, and its secret key.
+#[cfg(feature = "x509")]
+pub(crate) fn x509_bound_jwk() -> (Jwk, SecretKey) {
+  let leaf_key: Jwk = Jwk::from_json(X509_LEAF_KEY).unwrap();
+  let secret_key: [u8; 32] = jwu::decode_b64(leaf_key.try_okp_params().unwrap().d.as_deref().unwrap())
+    .unwrap()
+    .try_into()
+    .unwrap();
+  let secret_key: SecretKey = SecretKey::from_bytes(&secret_key);
+  let mut jwk: Jwk = encode_public_ed25519_jwk(&secret_key.public_key());
+  jwk.set_x5c(
+    [X509_LEAF_CERTIFICATE, X509_INTERMEDIATE_CERTIFICATE]
+      .map(|certificate| BaseEncoding::encode(certificate, identity_core::convert::Base::Base64Pad)),
+  );
+  (jwk, secret_key)
+}
@@ -0,0 +1,302 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use std::collections::HashMap;
+use std::path::Path;
+
+use identity_core::convert::FromJson;
+use identity_did::CoreDID;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A source of issuers trusted by a verifier, e.g. a trust registry of an ecosystem.
+///
+/// Validation is synchronous, so implementations backed by a remote registry should fetch its entries beforehand.
+/// Select a registry with
+/// [`JwtCredentialValidationOptions::trust_registry`](crate::validator::JwtCredentialValidationOptions::trust_registry)
+/// to reject credentials of other issuers with [`JwtValidationError::UntrustedIssuer`](crate::validator::JwtValidationError::UntrustedIssuer).
+pub trait TrustRegistry: Debug + Send + Sync {
+  /// Returns whether `issuer` is trusted to issue credentials of the given `credential_types`.
+  fn is_trusted_issuer(&self, issuer: &CoreDID, credential_types: &[String]) -> bool;
+}
+
+/// An entry of a [`StaticTrustRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustedIssuer {
+  /// The DID of the issuer.
+  pub did: CoreDID,
+  /// The credential types the issuer is trusted for, or all types if empty.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub credential_types: Vec<String>,
+}
+
+/// A [`TrustRegistry`] of a fixed allowlist of issuers.
+///
+/// The allowlist can be loaded from JSON of the form:
+/// ```json
+/// {
+///   "issuers": [
+///     { "did": "did:example:university", "credentialTypes": ["UniversityDegreeCredential"] },
+///     { "did": "did:example:government" }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StaticTrustRegistry {
+  issuers: HashMap<CoreDID, Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct StaticTrustRegistryFile {
+  issuers: Vec<TrustedIssuer>,
+}
+
+impl StaticTrustRegistry {
+  /// Creates an empty [`StaticTrustRegistry`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Trusts `issuer`, for all credential types if `issuer.credential_types` is empty.
+  pub fn insert(&mut self, issuer: TrustedIssuer) {
+    self.issuers.insert(issuer.did, issuer.credential_types);
+  }
+
+  /// Trusts `issuer` for all credential types.
+  pub fn with_issuer(mut self, issuer: CoreDID) -> Self {
+    self.insert(TrustedIssuer {
+      did: issuer,
+      credential_types: Vec::new(),
+    });
+    self
+  }
+
+  /// Returns whether the registry contains no issuers.
+  pub fn is_empty(&self) -> bool {
+    self.issuers.is_empty()
+  }
+
+  /// Parses an allowlist from JSON.
+  pub fn from_json_str(json: &str) -> Result<Self, crate::Error> {
+    let file: StaticTrustRegistryFile =
+      StaticTrustRegistryFile::from_json(json).map_err(|err| crate::Error::TrustRegistryError(Box::new(err)))?;
+    let mut registry: Self = Self::new();
+    file.issuers.into_iter().for_each(|issuer| registry.insert(issuer));
+    Ok(registry)
+  }
+
+  /// Reads an allowlist from the JSON file at `path`.
+  pub fn from_file(path: impl AsRef<Path>) -> Result<Self, crate::Error> {
+    let json: String = std::fs::read_to_string(path).map_err(|err| crate::Error::TrustRegistryError(Box::new(err)))?;
+    Self::from_json_str(&json)
+  }
+}
+
+impl FromIterator<TrustedIssuer> for StaticTrustRegistry {
+  fn from_iter<I: IntoIterator<Item = TrustedIssuer>>(iter: I) -> Self {
+    let mut registry: Self = Self::new();
+    iter.into_iter().for_each(|issuer| registry.insert(issuer));
+    registry
+  }
+}
+
+impl TrustRegistry for StaticTrustRegistry {
+  fn is_trusted_issuer(&self, issuer: &CoreDID, credential_types: &[String]) -> bool {
+    self.issuers.get(issuer).is_some_and(|trusted_types| {
+      trusted_types.is_empty() || credential_types.iter().any(|type_| trusted_types.contains(type_))
+    })
+  }
+}
+
+#[cfg(feature = "trust-registry-fetch")]
+pub use self::fetch::HttpTrustRegistry;
+
+#[cfg(feature = "trust-registry-fetch")]
+mod fetch {
+  use std::collections::HashMap;
+  use std::sync::RwLock;
+
+  use identity_core::common::Duration;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_did::CoreDID;
+  use reqwest::Client;
+  use reqwest::StatusCode;
+
+  use super::TrustRegistry;
+  use crate::credential::Credential;
+  use crate::validator::JwtCredentialValidatorUtils;
+  use crate::Error::TrustRegistryError;
+
+  #[derive(Debug, Clone, Copy)]
+  struct CachedIssuer {
+    trusted: bool,
+    fetched_at: Timestamp,
+  }
+
+  /// A [`TrustRegistry`] looking up issuers in a remote registry, such as the EBSI Trusted Issuers Registry.
+  ///
+  /// An issuer is trusted if `GET {base}/issuers/{did}` succeeds, and untrusted if the registry answers
+  /// `404 Not Found`. The registry is trusted for all credential types of its issuers.
+  ///
+  /// Validation does not perform I/O: look up the issuers of credentials with [`HttpTrustRegistry::prefetch`]
+  /// before validating them. Answers are cached and only fetched again once they exceed the maximum age.
+  #[derive(Debug)]
+  pub struct HttpTrustRegistry {
+    client: Client,
+    base: Url,
+    max_age: Duration,
+    issuers: RwLock<HashMap<CoreDID, CachedIssuer>>,
+  }
+
+  impl HttpTrustRegistry {
+    /// Creates a new [`HttpTrustRegistry`] for the registry at `base`, caching its answers for `max_age`.
+    pub fn new(base: Url, max_age: Duration) -> Result<Self, crate::Error> {
+      let client: Client = reqwest::ClientBuilder::new()
+        .https_only(true)
+        .build()
+        .map_err(|err| TrustRegistryError(Box::new(err)))?;
+      Ok(Self {
+        client,
+        base,
+        max_age,
+        issuers: RwLock::new(HashMap::new()),
+      })
+    }
+
+    /// Looks up the issuer of `credential`, unless a cached answer is within the maximum age.
+    pub async fn prefetch<T>(&self, credential: &Credential<T>) -> Result<(), crate::Error> {
+      let issuer: CoreDID =
+        JwtCredentialValidatorUtils::extract_issuer(credential).map_err(|err| TrustRegistryError(Box::new(err)))?;
+      if self.cached(&issuer).is_some() {
+        return Ok(());
+      }
+      self.fetch(&issuer).await.map(|_| ())
+    }
+
+    /// Looks up `issuer` in the registry, caches and returns whether it is trusted.
+    pub async fn fetch(&self, issuer: &CoreDID) -> Result<bool, crate::Error> {
+      let url: String = format!("{}/issuers/{}", self.base.as_str().trim_end_matches('/'), issuer);
+      let response = self
+        .client
+        .get(url)
+        .send()
+        .await
+        .map_err(|err| TrustRegistryError(Box::new(err)))?;
+      let trusted: bool = match response.status() {
+        StatusCode::NOT_FOUND => false,
+        _ => response
+          .error_for_status()
+          .map(|_| true)
+          .map_err(|err| TrustRegistryError(Box::new(err)))?,
+      };
+
+      self
+        .issuers
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(
+          issuer.clone(),
+          CachedIssuer {
+            trusted,
+            fetched_at: Timestamp::now_utc(),
+          },
+        );
+      Ok(trusted)
+    }
+
+    fn cached(&self, issuer: &CoreDID) -> Option<bool> {
+      let issuers = self.issuers.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+      issuers
+        .get(issuer)
+        .filter(|cached| {
+          cached
+            .fetched_at
+            .checked_add(self.max_age)
+            .is_some_and(|expiry| expiry >= Timestamp::now_utc())
+        })
+        .map(|cached| cached.trusted)
+    }
+  }
+
+  impl TrustRegistry for HttpTrustRegistry {
+    fn is_trusted_issuer(&self, issuer: &CoreDID, _credential_types: &[String]) -> bool {
+      self.cached(issuer).unwrap_or(false)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::credential::Subject;
+  use crate::validator::JwtCredentialValidationOptions;
+  use crate::validator::JwtCredentialValidatorUtils;
+  use crate::validator::JwtValidationError;
+
+  const ALLOWLIST: &str = r#"{
+    "issuers": [
+      { "did": "did:example:university", "credentialTypes": ["DegreeCredential"] },
+      { "did": "did:example:government" }
+    ]
+  }"#;
+
+  fn credential(issuer: &str, type_: &str) -> Credential {
+    Credential::builder(Object::new())
+      .issuer(Url::parse(issuer).unwrap())
+      .type_(type_)
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn static_trust_registry_from_json() {
+    let registry: StaticTrustRegistry = StaticTrustRegistry::from_json_str(ALLOWLIST).unwrap();
+    let types = |type_: &str| vec!["VerifiableCredential".to_owned(), type_.to_owned()];
+    let did = |did: &str| CoreDID::parse(did).unwrap();
+
+    assert!(registry.is_trusted_issuer(&did("did:example:university"), &types("DegreeCredential")));
+    assert!(registry.is_trusted_issuer(&did("did:example:government"), &types("DegreeCredential")));
+    assert!(!registry.is_trusted_issuer(&did("did:example:university"), &types("DriversLicense")));
+    assert!(!registry.is_trusted_issuer(&did("did:example:other"), &types("DegreeCredential")));
+
+    assert!(matches!(
+      StaticTrustRegistry::from_json_str(r#"{ "issuers": [{ "did": "not a did" }] }"#),
+      Err(crate::Error::TrustRegistryError(_))
+    ));
+  }
+
+  #[test]
+  fn check_trusted_issuer() {
+    let registry: Arc<StaticTrustRegistry> = Arc::new(StaticTrustRegistry::from_json_str(ALLOWLIST).unwrap());
+    let options: JwtCredentialValidationOptions = JwtCredentialValidationOptions::new().trust_registry(registry);
+
+    assert!(JwtCredentialValidatorUtils::check_trusted_issuer(
+      &credential("did:example:university", "DegreeCredential"),
+      &options
+    )
+    .is_ok());
+    assert!(matches!(
+      JwtCredentialValidatorUtils::check_trusted_issuer(
+        &credential("did:example:university", "DriversLicense"),
+        &options
+      ),
+      Err(JwtValidationError::UntrustedIssuer)
+    ));
+    // Without a registry, all issuers are accepted.
+    assert!(JwtCredentialValidatorUtils::check_trusted_issuer(
+      &credential("did:example:other", "DegreeCredential"),
+      &JwtCredentialValidationOptions::new()
+    )
+    .is_ok());
+  }
+}
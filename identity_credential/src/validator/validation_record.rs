@@ -0,0 +1,280 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_core::convert::ToJson;
+use identity_document::document::CoreDocument;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::credential::Credential;
+use crate::credential::Jwt;
+use crate::validator::CompoundCredentialValidationError;
+use crate::validator::CompoundJwtPresentationValidationError;
+use crate::validator::DecodedJwtCredential;
+use crate::validator::DecodedJwtPresentation;
+use crate::validator::JwtCredentialValidator;
+use crate::validator::JwtValidationError;
+
+/// A serializable record of the outcome of validating a credential or presentation, intended for audit logs and
+/// export to SIEM systems.
+///
+/// Unlike the `Display` representation of validation errors, records have a stable, versioned JSON representation:
+/// fields are only ever added in a backwards compatible way, and [`ValidationRecord::VERSION`] is incremented
+/// otherwise. Errors are identified by stable [`ValidationErrorRecord::code`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ValidationRecord {
+  /// The version of the schema of the record.
+  pub version: u32,
+  /// The kind of the validated object.
+  pub kind: ValidationKind,
+  /// Whether the object was accepted.
+  pub outcome: ValidationOutcome,
+  /// The time of the validation.
+  pub checked_at: Timestamp,
+  /// The `id` of the validated credential or presentation, if known.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  /// The issuer of the credential or the holder of the presentation, if known.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub signer: Option<String>,
+  /// The types of the validated credential or presentation, if known.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub types: Vec<String>,
+  /// The errors that caused the object to be rejected.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub errors: Vec<ValidationErrorRecord>,
+  /// Digests of the DID documents the object was validated against.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub documents: Vec<DocumentDigest>,
+}
+
+/// The kind of object of a [`ValidationRecord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum ValidationKind {
+  /// A credential issued as a JWT.
+  JwtCredential,
+  /// A presentation issued as a JWT.
+  JwtPresentation,
+}
+
+/// The outcome of a validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ValidationOutcome {
+  /// All checks passed.
+  Accepted,
+  /// At least one check failed.
+  Rejected,
+}
+
+/// A [`JwtValidationError`] in a [`ValidationRecord`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ValidationErrorRecord {
+  /// The stable code of the error, i.e. the name of the [`JwtValidationError`] variant, such as `Revoked`.
+  pub code: String,
+  /// A human-readable description of the error, which may change between versions.
+  pub message: String,
+}
+
+impl From<&JwtValidationError> for ValidationErrorRecord {
+  fn from(error: &JwtValidationError) -> Self {
+    let code: &'static str = error.into();
+    Self {
+      code: code.to_owned(),
+      message: error.to_string(),
+    }
+  }
+}
+
+/// The digest of a DID document used in a validation, identifying the exact version of the document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DocumentDigest {
+  /// The DID of the document.
+  pub id: String,
+  /// The digest algorithm, currently always `sha-256`.
+  pub algorithm: String,
+  /// The lowercase hex encoded digest of the JSON serialization of the document.
+  pub digest: String,
+}
+
+impl DocumentDigest {
+  /// The algorithm of the digests.
+  pub const ALGORITHM: &'static str = "sha-256";
+
+  /// Computes the digest of `document`.
+  pub fn new(document: &CoreDocument) -> Self {
+    let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+    // Serializing a document to JSON cannot fail.
+    let json: Vec<u8> = document.to_json_vec().unwrap_or_default();
+    SHA256(&json, &mut digest);
+    Self {
+      id: document.id().to_string(),
+      algorithm: Self::ALGORITHM.to_owned(),
+      digest: BaseEncoding::encode(&digest, Base::Base16Lower),
+    }
+  }
+}
+
+impl ValidationRecord {
+  /// The current version of the schema of records.
+  pub const VERSION: u32 = 1;
+
+  fn new(kind: ValidationKind, errors: &[JwtValidationError], documents: Vec<DocumentDigest>) -> Self {
+    Self {
+      version: Self::VERSION,
+      kind,
+      outcome: if errors.is_empty() {
+        ValidationOutcome::Accepted
+      } else {
+        ValidationOutcome::Rejected
+      },
+      checked_at: Timestamp::now_utc(),
+      id: None,
+      signer: None,
+      types: Vec::new(),
+      errors: errors.iter().map(ValidationErrorRecord::from).collect(),
+      documents,
+    }
+  }
+
+  /// Records the `result` of validating the credential `credential_jwt` against the DID documents of `issuers`.
+  ///
+  /// If validation failed, the id, issuer and types of the credential are taken from the JWT without verifying its
+  /// signature.
+  pub fn from_credential_validation<DOC, T>(
+    credential_jwt: &Jwt,
+    result: &Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>,
+    issuers: &[DOC],
+  ) -> Self
+  where
+    DOC: AsRef<CoreDocument>,
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    let documents: Vec<DocumentDigest> = issuers
+      .iter()
+      .map(|issuer| DocumentDigest::new(issuer.as_ref()))
+      .collect();
+    match result {
+      Ok(decoded) => Self::new(ValidationKind::JwtCredential, &[], documents).describe_credential(&decoded.credential),
+      Err(error) => {
+        let record: Self = Self::new(ValidationKind::JwtCredential, &error.validation_errors, documents);
+        match JwtCredentialValidator::<()>::decode_unverified::<Object>(credential_jwt) {
+          Ok(credential) => record.describe_credential(&credential),
+          Err(_) => record,
+        }
+      }
+    }
+  }
+
+  fn describe_credential<T>(mut self, credential: &Credential<T>) -> Self {
+    self.id = credential.id.as_ref().map(ToString::to_string);
+    self.signer = Some(credential.issuer.url().to_string());
+    self.types = credential.types.as_slice().to_vec();
+    self
+  }
+
+  /// Records the `result` of validating a presentation against the DID document of its `holder`.
+  pub fn from_presentation_validation<HDOC, CRED, T>(
+    result: &Result<DecodedJwtPresentation<CRED, T>, CompoundJwtPresentationValidationError>,
+    holder: &HDOC,
+  ) -> Self
+  where
+    HDOC: AsRef<CoreDocument> + ?Sized,
+  {
+    let documents: Vec<DocumentDigest> = vec![DocumentDigest::new(holder.as_ref())];
+    let mut record: Self = match result {
+      Ok(decoded) => {
+        let mut record: Self = Self::new(ValidationKind::JwtPresentation, &[], documents);
+        record.id = decoded.presentation.id.as_ref().map(ToString::to_string);
+        record.types = decoded.presentation.types.as_slice().to_vec();
+        record
+      }
+      Err(error) => Self::new(
+        ValidationKind::JwtPresentation,
+        &error.presentation_validation_errors,
+        documents,
+      ),
+    };
+    record.signer = Some(holder.as_ref().id().to_string());
+    record
+  }
+
+  /// Sets the time of the validation, e.g. if it was performed as of a different time than the current one.
+  pub fn checked_at(mut self, timestamp: Timestamp) -> Self {
+    self.checked_at = timestamp;
+    self
+  }
+
+  /// Returns whether the validated object was accepted.
+  pub fn is_accepted(&self) -> bool {
+    self.outcome == ValidationOutcome::Accepted
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+  use super::*;
+  use crate::credential::Subject;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_credential_jwt;
+  use crate::validator::FailFast;
+  use crate::validator::JwtCredentialValidationOptions;
+
+  #[test]
+  fn credential_validation_record() {
+    let (issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let (other, _, _) = generate_jwk_document_with_keys();
+    let credential: Credential = Credential::builder(Object::new())
+      .id(Url::parse("https://example.com/credentials/1").unwrap())
+      .issuer(Url::parse(issuer.id().as_str()).unwrap())
+      .type_("DegreeCredential")
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap();
+    let jwt: Jwt = sign_credential_jwt(&credential, &issuer, &fragment, &secret_key);
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+    let options = JwtCredentialValidationOptions::default();
+
+    let result = validator.validate::<_, Object>(&jwt, &issuer, &options, FailFast::AllErrors);
+    let record: ValidationRecord = ValidationRecord::from_credential_validation(&jwt, &result, &[&issuer]);
+    assert!(record.is_accepted());
+    assert_eq!(record.version, ValidationRecord::VERSION);
+    assert_eq!(record.id.as_deref(), Some("https://example.com/credentials/1"));
+    assert_eq!(record.signer.as_deref(), Some(issuer.id().as_str()));
+    assert_eq!(record.types, ["VerifiableCredential", "DegreeCredential"]);
+    assert_eq!(record.documents, [DocumentDigest::new(&issuer)]);
+    assert_eq!(record.documents[0].digest.len(), 64);
+
+    // INVALID: validated against the wrong document.
+    let result = validator.validate::<_, Object>(&jwt, &other, &options, FailFast::AllErrors);
+    let record: ValidationRecord = ValidationRecord::from_credential_validation(&jwt, &result, &[&other]);
+    assert!(!record.is_accepted());
+    assert_eq!(record.errors[0].code, "DocumentMismatch");
+    assert_eq!(record.signer.as_deref(), Some(issuer.id().as_str()));
+
+    let json: serde_json::Value = record.to_json_value().unwrap();
+    assert_eq!(json["version"], 1);
+    assert_eq!(json["kind"], "jwtCredential");
+    assert_eq!(json["outcome"], "rejected");
+    assert_eq!(json["errors"][0]["code"], "DocumentMismatch");
+    assert_eq!(ValidationRecord::from_json_value(json).unwrap(), record);
+  }
+}
@@ -0,0 +1,453 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use identity_core::common::Duration;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jws::JwsVerifier;
+
+use crate::credential::Credential;
+use crate::credential::Jwt;
+use crate::validator::CompoundCredentialValidationError;
+use crate::validator::DecodedJwtCredential;
+use crate::validator::JwtCredentialValidationOptions;
+use crate::validator::JwtCredentialValidator;
+use crate::validator::JwtCredentialValidatorUtils;
+use crate::validator::JwtValidationError;
+use crate::validator::StatusCheck;
+use crate::validator::StatusCheckProvider;
+use crate::validator::SubjectHolderRelationship;
+use crate::validator::TrustRegistry;
+
+/// The rules a verifier applies to decide whether to accept a [`Credential`] issued as a JWT.
+///
+/// The signature, structure, issuance and expiration dates, and status of the credential are always checked. Issuers,
+/// types, maximum age and holder binding are only checked if the policy declares them.
+///
+/// ```
+/// # use identity_core::common::Duration;
+/// # use identity_credential::validator::StatusCheck;
+/// # use identity_credential::validator::SubjectHolderRelationship;
+/// # use identity_credential::validator::VerificationPolicy;
+/// # use identity_did::CoreDID;
+/// let policy = VerificationPolicy::new()
+///   .accept_issuer(CoreDID::parse("did:example:university").unwrap())
+///   .accept_type("UniversityDegreeCredential")
+///   .max_age(Duration::weeks(52))
+///   .status_check(StatusCheck::Strict)
+///   .require_holder_binding(SubjectHolderRelationship::AlwaysSubject);
+/// ```
+#[derive(Debug, Default, Clone)]
+#[non_exhaustive]
+pub struct VerificationPolicy {
+  /// The DIDs of the accepted issuers.
+  pub accepted_issuers: Vec<CoreDID>,
+  /// Trust registries whose issuers are accepted in addition to [`Self::accepted_issuers`].
+  pub trust_registries: Vec<Arc<dyn TrustRegistry>>,
+  /// The accepted credential types, of which a credential must have at least one.
+  pub accepted_types: Vec<String>,
+  /// The maximum time since the issuance of a credential.
+  pub max_age: Option<Duration>,
+  /// Validation behaviour for [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
+  pub status: StatusCheck,
+  /// Checks the [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status) of credentials, by default against
+  /// the `RevocationBitmap2022` services of the issuer's DID document.
+  pub status_provider: Option<Arc<dyn StatusCheckProvider>>,
+  /// The relationship required between the subjects of a credential and its holder.
+  pub holder_binding: Option<SubjectHolderRelationship>,
+  /// Options which affect the verification of the signature of credentials.
+  pub verification_options: JwsVerificationOptions,
+  /// Validates credentials as of this [`Timestamp`] instead of the current time.
+  pub as_of: Option<Timestamp>,
+}
+
+impl VerificationPolicy {
+  /// Creates a policy accepting any issuer and credential type.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Accepts credentials issued by `issuer`.
+  pub fn accept_issuer(mut self, issuer: CoreDID) -> Self {
+    self.accepted_issuers.push(issuer);
+    self
+  }
+
+  /// Accepts credentials issued by issuers trusted by `registry`.
+  pub fn accept_issuers_from(mut self, registry: Arc<dyn TrustRegistry>) -> Self {
+    self.trust_registries.push(registry);
+    self
+  }
+
+  /// Accepts credentials of type `type_`.
+  pub fn accept_type(mut self, type_: impl Into<String>) -> Self {
+    self.accepted_types.push(type_.into());
+    self
+  }
+
+  /// Rejects credentials issued longer than `max_age` ago.
+  pub fn max_age(mut self, max_age: Duration) -> Self {
+    self.max_age = Some(max_age);
+    self
+  }
+
+  /// Sets the validation behaviour for [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
+  pub fn status_check(mut self, status_check: StatusCheck) -> Self {
+    self.status = status_check;
+    self
+  }
+
+  /// Sets the [`StatusCheckProvider`] checking the
+  /// [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status) of credentials.
+  pub fn status_provider(mut self, provider: Arc<dyn StatusCheckProvider>) -> Self {
+    self.status_provider = Some(provider);
+    self
+  }
+
+  /// Requires credentials to be bound to the holder presenting them according to `relationship`.
+  pub fn require_holder_binding(mut self, relationship: SubjectHolderRelationship) -> Self {
+    self.holder_binding = Some(relationship);
+    self
+  }
+
+  /// Sets options which affect the verification of the signature of credentials.
+  pub fn verification_options(mut self, options: JwsVerificationOptions) -> Self {
+    self.verification_options = options;
+    self
+  }
+
+  /// Validates credentials as of `timestamp` instead of the current time.
+  pub fn as_of(mut self, timestamp: Timestamp) -> Self {
+    self.as_of = Some(timestamp);
+    self
+  }
+
+  fn check_issuer<T>(&self, credential: &Credential<T>) -> Result<(), JwtValidationError> {
+    let issuer: CoreDID = JwtCredentialValidatorUtils::extract_issuer(credential)?;
+    let types: &[String] = credential.types.as_slice();
+    if self.accepted_issuers.contains(&issuer)
+      || self
+        .trust_registries
+        .iter()
+        .any(|registry| registry.is_trusted_issuer(&issuer, types))
+    {
+      Ok(())
+    } else {
+      Err(JwtValidationError::UntrustedIssuer)
+    }
+  }
+
+  fn check_type<T>(&self, credential: &Credential<T>) -> Result<(), JwtValidationError> {
+    if credential.types.iter().any(|type_| self.accepted_types.contains(type_)) {
+      Ok(())
+    } else {
+      Err(JwtValidationError::CredentialType)
+    }
+  }
+
+  fn check_max_age<T>(credential: &Credential<T>, max_age: Duration, now: Timestamp) -> Result<(), JwtValidationError> {
+    match credential.issuance_date.checked_add(max_age) {
+      Some(valid_until) if valid_until < now => Err(JwtValidationError::CredentialAge),
+      _ => Ok(()),
+    }
+  }
+
+  fn validation_options(&self, now: Timestamp) -> JwtCredentialValidationOptions {
+    let mut options: JwtCredentialValidationOptions = JwtCredentialValidationOptions::new()
+      .earliest_expiry_date(now)
+      .latest_issuance_date(now)
+      .status_check(self.status)
+      .verification_options(self.verification_options.clone());
+    options.status_provider = self.status_provider.clone();
+    options
+  }
+}
+
+/// A rule of a [`VerificationPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyRule {
+  /// The JWS signature of the credential is valid.
+  Signature,
+  /// The credential is structurally valid.
+  Structure,
+  /// The credential was issued before the validation time.
+  IssuanceDate,
+  /// The credential has not expired at the validation time.
+  ExpirationDate,
+  /// The credential was issued by an accepted issuer, see [`VerificationPolicy::accept_issuer`].
+  AcceptedIssuer,
+  /// The credential has an accepted type, see [`VerificationPolicy::accept_type`].
+  AcceptedType,
+  /// The credential is not older than allowed, see [`VerificationPolicy::max_age`].
+  MaxAge,
+  /// The credential has not been revoked or suspended.
+  Status,
+  /// The credential is bound to its holder, see [`VerificationPolicy::require_holder_binding`].
+  HolderBinding,
+}
+
+/// The outcome of a single [`PolicyRule`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct PolicyRuleResult {
+  /// The evaluated rule.
+  pub rule: PolicyRule,
+  /// `Ok` if the credential satisfies the rule, the reason of the failure otherwise.
+  pub result: Result<(), JwtValidationError>,
+}
+
+impl PolicyRuleResult {
+  /// Returns whether the credential satisfies the rule.
+  pub fn passed(&self) -> bool {
+    self.result.is_ok()
+  }
+}
+
+/// The report of validating a credential with a [`VerificationPolicy`], listing the outcome of every evaluated rule.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct PolicyReport<T = Object> {
+  /// The decoded credential, `None` if its signature could not be verified.
+  pub credential: Option<DecodedJwtCredential<T>>,
+  /// The outcome of each evaluated rule, in order of evaluation.
+  pub rules: Vec<PolicyRuleResult>,
+}
+
+impl<T> PolicyReport<T> {
+  /// Returns whether the credential satisfies all rules of the policy.
+  pub fn is_accepted(&self) -> bool {
+    self.credential.is_some() && self.rules.iter().all(PolicyRuleResult::passed)
+  }
+
+  /// Returns the rules the credential satisfies.
+  pub fn passed(&self) -> impl Iterator<Item = PolicyRule> + '_ {
+    self.rules.iter().filter(|rule| rule.passed()).map(|rule| rule.rule)
+  }
+
+  /// Returns the outcomes of the rules the credential does not satisfy.
+  pub fn failed(&self) -> impl Iterator<Item = &PolicyRuleResult> {
+    self.rules.iter().filter(|rule| !rule.passed())
+  }
+
+  /// Returns the credential if it satisfies all rules, or the errors of the failed rules otherwise.
+  pub fn into_result(self) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError> {
+    let validation_errors: Vec<JwtValidationError> =
+      self.rules.into_iter().filter_map(|rule| rule.result.err()).collect();
+    match self.credential {
+      Some(credential) if validation_errors.is_empty() => Ok(credential),
+      _ => Err(CompoundCredentialValidationError { validation_errors }),
+    }
+  }
+}
+
+impl<V: JwsVerifier> JwtCredentialValidator<V> {
+  /// Decodes and validates a [`Credential`] issued as a JWT against the rules of `policy`, returning a
+  /// [`PolicyReport`] of the rules the credential satisfies or fails.
+  ///
+  /// `trusted_issuers` are the DID documents used to verify the signature and the status of the credential, while
+  /// `policy` declares which of their credentials are accepted. `holder` is the holder presenting the credential and
+  /// is required if the policy requires holder binding.
+  ///
+  /// If the signature cannot be verified, no other rule is evaluated.
+  pub fn validate_with_policy<DOC, T>(
+    &self,
+    credential_jwt: &Jwt,
+    trusted_issuers: &[DOC],
+    policy: &VerificationPolicy,
+    holder: Option<&Url>,
+  ) -> PolicyReport<T>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let now: Timestamp = policy.as_of.unwrap_or_else(Timestamp::now_utc);
+    let options: JwtCredentialValidationOptions = policy.validation_options(now);
+
+    let mut rules: Vec<PolicyRuleResult> = Vec::new();
+    let mut evaluate = |rule: PolicyRule, result: Result<(), JwtValidationError>| {
+      rules.push(PolicyRuleResult { rule, result });
+    };
+
+    let decoded: DecodedJwtCredential<T> =
+      match self.verify_signature(credential_jwt, trusted_issuers, &options.verification_options) {
+        Ok(decoded) => {
+          evaluate(PolicyRule::Signature, Ok(()));
+          decoded
+        }
+        Err(err) => {
+          evaluate(PolicyRule::Signature, Err(err));
+          return PolicyReport {
+            credential: None,
+            rules,
+          };
+        }
+      };
+    let credential: &Credential<T> = &decoded.credential;
+
+    evaluate(
+      PolicyRule::Structure,
+      JwtCredentialValidatorUtils::check_structure(credential),
+    );
+    evaluate(
+      PolicyRule::IssuanceDate,
+      JwtCredentialValidatorUtils::check_issued_on_or_before(credential, now),
+    );
+    evaluate(
+      PolicyRule::ExpirationDate,
+      JwtCredentialValidatorUtils::check_expires_on_or_after(credential, now),
+    );
+    if !policy.accepted_issuers.is_empty() || !policy.trust_registries.is_empty() {
+      evaluate(PolicyRule::AcceptedIssuer, policy.check_issuer(credential));
+    }
+    if !policy.accepted_types.is_empty() {
+      evaluate(PolicyRule::AcceptedType, policy.check_type(credential));
+    }
+    if let Some(max_age) = policy.max_age {
+      evaluate(
+        PolicyRule::MaxAge,
+        VerificationPolicy::check_max_age(credential, max_age, now),
+      );
+    }
+    evaluate(
+      PolicyRule::Status,
+      JwtCredentialValidatorUtils::check_status_with_options(credential, trusted_issuers, &options),
+    );
+    if let Some(relationship) = policy.holder_binding {
+      evaluate(
+        PolicyRule::HolderBinding,
+        holder
+          .ok_or(JwtValidationError::MissingPresentationHolder)
+          .and_then(|holder| {
+            JwtCredentialValidatorUtils::check_subject_holder_relationship(credential, holder, relationship)
+          }),
+      );
+    }
+
+    PolicyReport {
+      credential: Some(decoded),
+      rules,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+  use super::*;
+  use crate::credential::Subject;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_credential_jwt;
+
+  #[derive(Debug)]
+  struct Registry(CoreDID);
+
+  impl TrustRegistry for Registry {
+    fn is_trusted_issuer(&self, issuer: &CoreDID, credential_types: &[String]) -> bool {
+      issuer == &self.0 && credential_types.iter().any(|type_| type_ == "DegreeCredential")
+    }
+  }
+
+  fn credential(issuer: &CoreDocument, issuance_date: Timestamp) -> Credential {
+    Credential::builder(Object::new())
+      .issuer(Url::parse(issuer.id().as_str()).unwrap())
+      .type_("DegreeCredential")
+      .subject(Subject::from_json_value(serde_json::json!({ "id": "did:example:holder" })).unwrap())
+      .issuance_date(issuance_date)
+      .build()
+      .unwrap()
+  }
+
+  fn failed_rules(report: &PolicyReport) -> Vec<PolicyRule> {
+    report.failed().map(|rule| rule.rule).collect()
+  }
+
+  #[test]
+  fn validate_with_policy_reports_each_rule() {
+    let (issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let now: Timestamp = Timestamp::parse("2024-01-01T00:00:00Z").unwrap();
+    let jwt: Jwt = sign_credential_jwt(
+      &credential(&issuer, now.checked_sub(Duration::days(10)).unwrap()),
+      &issuer,
+      &fragment,
+      &secret_key,
+    );
+    let holder: Url = Url::parse("did:example:holder").unwrap();
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+
+    let policy: VerificationPolicy = VerificationPolicy::new()
+      .accept_issuer(issuer.id().clone())
+      .accept_type("DegreeCredential")
+      .max_age(Duration::days(30))
+      .require_holder_binding(SubjectHolderRelationship::AlwaysSubject)
+      .as_of(now);
+    let report: PolicyReport = validator.validate_with_policy(&jwt, &[&issuer], &policy, Some(&holder));
+    assert!(report.is_accepted());
+    assert_eq!(report.passed().count(), 9);
+    assert!(report.into_result().is_ok());
+
+    // INVALID: too old, wrong type and no holder.
+    let policy: VerificationPolicy = policy.max_age(Duration::days(1));
+    let policy: VerificationPolicy = VerificationPolicy {
+      accepted_types: vec!["DriversLicense".to_owned()],
+      ..policy
+    };
+    let report: PolicyReport = validator.validate_with_policy(&jwt, &[&issuer], &policy, None);
+    assert!(!report.is_accepted());
+    assert_eq!(
+      report.passed().collect::<Vec<_>>(),
+      [
+        PolicyRule::Signature,
+        PolicyRule::Structure,
+        PolicyRule::IssuanceDate,
+        PolicyRule::ExpirationDate,
+        PolicyRule::AcceptedIssuer,
+        PolicyRule::Status
+      ]
+    );
+    assert_eq!(
+      failed_rules(&report),
+      [PolicyRule::AcceptedType, PolicyRule::MaxAge, PolicyRule::HolderBinding]
+    );
+    assert_eq!(report.into_result().unwrap_err().validation_errors.len(), 3);
+  }
+
+  #[test]
+  fn validate_with_policy_checks_issuers() {
+    let (issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let (other, _, _) = generate_jwk_document_with_keys();
+    let jwt: Jwt = sign_credential_jwt(
+      &credential(&issuer, Timestamp::now_utc()),
+      &issuer,
+      &fragment,
+      &secret_key,
+    );
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+
+    let policy: VerificationPolicy = VerificationPolicy::new().accept_issuer(other.id().clone());
+    let report: PolicyReport = validator.validate_with_policy(&jwt, &[&issuer], &policy, None);
+    assert_eq!(failed_rules(&report), [PolicyRule::AcceptedIssuer]);
+    assert!(matches!(
+      report.failed().next().unwrap().result,
+      Err(JwtValidationError::UntrustedIssuer)
+    ));
+
+    let policy: VerificationPolicy = policy.accept_issuers_from(Arc::new(Registry(issuer.id().clone())));
+    let report: PolicyReport = validator.validate_with_policy(&jwt, &[&issuer], &policy, None);
+    assert!(report.is_accepted());
+
+    // INVALID: the signature cannot be verified without the issuer's document.
+    let report: PolicyReport = validator.validate_with_policy(&jwt, &[&other], &policy, None);
+    assert!(report.credential.is_none());
+    assert_eq!(report.passed().count(), 0);
+    assert_eq!(failed_rules(&report), [PolicyRule::Signature]);
+  }
+}
@@ -0,0 +1,16 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validation of X.509 certificate chains binding verification methods to certified identities.
+//!
+//! A JWK may carry the certificate chain of its key in the `x5c` parameter, or reference it through the `x5u`
+//! parameter. Validating the chain against an [`X509TrustStore`] establishes the [`X509CertificateIdentity`] of
+//! the key holder, e.g. the organisation named in the certificate of an issuer.
+
+mod x509_certificate_identity;
+mod x509_error;
+mod x509_trust_store;
+
+pub use x509_certificate_identity::*;
+pub use x509_error::*;
+pub use x509_trust_store::*;
@@ -0,0 +1,26 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The identity asserted by the end-entity certificate of a validated X.509 certificate chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct X509CertificateIdentity {
+  /// The distinguished name of the subject, e.g. `CN=Example Issuer,O=Example`.
+  pub subject: String,
+  /// The distinguished name of the issuer of the certificate.
+  pub issuer: String,
+  /// The hex encoded serial number of the certificate.
+  pub serial_number: String,
+  /// The DNS names, URIs and email addresses of the subject alternative name extension.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub subject_alt_names: Vec<String>,
+  /// The start of the validity period of the certificate.
+  pub not_before: Timestamp,
+  /// The end of the validity period of the certificate.
+  pub not_after: Timestamp,
+}
@@ -0,0 +1,60 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+
+use identity_core::common::SingleStructError;
+
+/// Error type for the validation of X.509 certificate chains.
+pub type X509Error = SingleStructError<X509ErrorKind>;
+
+/// Alias for a `Result` with the error type [`X509Error`].
+pub type X509Result<T> = Result<T, X509Error>;
+
+/// The cause of a failed X.509 certificate chain validation.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum X509ErrorKind {
+  /// Indicates that a JWK does not carry an `x5c` certificate chain.
+  MissingCertificateChain,
+  /// Indicates a certificate that could not be decoded, or that uses an unsupported key type, signature algorithm or
+  /// critical extension.
+  InvalidCertificate,
+  /// Indicates a certificate whose signature could not be verified with the key of its issuer.
+  InvalidSignature,
+  /// Indicates a certificate used outside of its validity period.
+  CertificateNotValidAtTime,
+  /// Indicates a certificate chain that does not lead to any of the trusted roots.
+  UntrustedChain,
+  /// Indicates that the end-entity certificate does not certify the expected key.
+  PublicKeyMismatch,
+  /// Indicates a malformed object identifier.
+  InvalidObjectIdentifier,
+}
+
+impl X509ErrorKind {
+  /// Returns the string representation of the error.
+  pub const fn as_str(&self) -> &str {
+    match self {
+      Self::MissingCertificateChain => "missing certificate chain",
+      Self::InvalidCertificate => "invalid certificate",
+      Self::InvalidSignature => "invalid certificate signature",
+      Self::CertificateNotValidAtTime => "certificate is not valid at the time of validation",
+      Self::UntrustedChain => "certificate chain does not lead to a trusted root",
+      Self::PublicKeyMismatch => "the certified key does not match the expected public key",
+      Self::InvalidObjectIdentifier => "invalid object identifier",
+    }
+  }
+}
+
+impl AsRef<str> for X509ErrorKind {
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl Display for X509ErrorKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
@@ -0,0 +1,749 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use der::asn1::ObjectIdentifier;
+use der::asn1::UintRef;
+use der::Decode;
+use der::Encode;
+use der::Sequence;
+use identity_core::common::Timestamp;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_verification::jwk::EcCurve;
+use identity_verification::jwk::EdCurve;
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkParamsEc;
+use identity_verification::jwk::JwkParamsOkp;
+use identity_verification::jwk::JwkParamsRsa;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::VerificationInput;
+use identity_verification::jwu;
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::ext::pkix::BasicConstraints;
+use x509_cert::ext::pkix::KeyUsage;
+use x509_cert::ext::pkix::SubjectAltName;
+use x509_cert::spki::SubjectPublicKeyInfoOwned;
+use x509_cert::time::Time;
+use x509_cert::Certificate;
+
+use super::X509CertificateIdentity;
+use super::X509Error;
+use super::X509ErrorKind;
+use super::X509Result;
+
+const EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+const ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+const SECP256R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+const SECP384R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+const SECP256K1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.10");
+
+const ECDSA_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2");
+const ECDSA_WITH_SHA384: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.3");
+const SHA256_WITH_RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.11");
+const SHA384_WITH_RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.12");
+const SHA512_WITH_RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.13");
+
+const BASIC_CONSTRAINTS: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.19");
+const KEY_USAGE: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.15");
+const SUBJECT_ALT_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.17");
+
+/// The extensions processed during chain validation, which may therefore be marked critical.
+const SUPPORTED_CRITICAL_EXTENSIONS: [ObjectIdentifier; 3] = [BASIC_CONSTRAINTS, KEY_USAGE, SUBJECT_ALT_NAME];
+
+const PEM_BEGIN_CERTIFICATE: &str = "-----BEGIN CERTIFICATE-----";
+const PEM_END_CERTIFICATE: &str = "-----END CERTIFICATE-----";
+
+/// A set of trusted root certificates against which X.509 certificate chains are validated.
+///
+/// Certificate signatures are verified with a [`JwsVerifier`], which must support the algorithms used in the
+/// chains, e.g. `ES256` or `RS256`. EC (P-256, P-384, secp256k1), RSA and Ed25519 keys are supported.
+///
+/// Certificate revocation is not checked.
+#[derive(Debug, Clone, Default)]
+pub struct X509TrustStore {
+  roots: Vec<Certificate>,
+}
+
+impl X509TrustStore {
+  /// Creates a new [`X509TrustStore`] without any trusted roots.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds the DER encoded certificate `root` to the trusted roots.
+  pub fn with_root(mut self, root: &[u8]) -> X509Result<Self> {
+    self.roots.push(decode_certificate(root)?);
+    Ok(self)
+  }
+
+  /// Returns whether the trust store contains no roots.
+  pub fn is_empty(&self) -> bool {
+    self.roots.is_empty()
+  }
+
+  /// Validates the DER encoded certificate `chain` at the given `time`.
+  ///
+  /// The chain must start with the end-entity certificate and each certificate must be issued by the next one. The
+  /// last certificate must either be one of the trusted roots or be issued by one of them.
+  ///
+  /// Following [RFC 5280](https://www.rfc-editor.org/rfc/rfc5280#section-6.1), every issuer must be a CA allowed to
+  /// sign certificates by its key usage, if restricted, and must not be followed by more intermediate CAs than its
+  /// path length constraint allows. Certificates with critical extensions other than basic constraints, key usage and
+  /// subject alternative name are rejected.
+  pub fn validate_chain<V>(&self, chain: &[Vec<u8>], time: Timestamp, verifier: &V) -> X509Result<X509CertificateChain>
+  where
+    V: JwsVerifier,
+  {
+    if chain.is_empty() {
+      return Err(X509Error::new(X509ErrorKind::UntrustedChain).with_custom_message("empty chain"));
+    }
+
+    let certificates: Vec<Certificate> = chain
+      .iter()
+      .map(|certificate| decode_certificate(certificate))
+      .collect::<X509Result<_>>()?;
+
+    for certificate in certificates.iter() {
+      check_validity(certificate, time)?;
+      check_critical_extensions(certificate)?;
+    }
+
+    for (index, pair) in certificates.windows(2).enumerate() {
+      let (certificate, issuer) = (&pair[0], &pair[1]);
+      check_issued_by(certificate, issuer, path_length(&certificates[1..=index]))?;
+      verify_signature(certificate, issuer, verifier)?;
+    }
+
+    // The chain is guaranteed to be non-empty at this point.
+    let last: &Certificate = certificates.last().expect("chain is not empty");
+    let is_trusted: bool = self.roots.iter().any(|root| {
+      root == last
+        || (check_validity(root, time).is_ok()
+          && check_critical_extensions(root).is_ok()
+          && check_issued_by(last, root, path_length(&certificates[1..])).is_ok()
+          && verify_signature(last, root, verifier).is_ok())
+    });
+
+    if !is_trusted {
+      return Err(X509ErrorKind::UntrustedChain.into());
+    }
+
+    Ok(X509CertificateChain { certificates })
+  }
+
+  /// Validates the `x5c` certificate chain of `jwk` at the given `time` and returns the identity of its end-entity
+  /// certificate.
+  ///
+  /// The end-entity certificate must certify the key of `jwk` and match its `x5t#S256` thumbprint, if present.
+  ///
+  /// A chain only referenced by `x5u` is not fetched. Such a chain must be retrieved by the caller, e.g. decoded with
+  /// [`decode_pem_certificate_chain`], and validated with [`Self::validate_chain`].
+  pub fn validate_jwk<V>(&self, jwk: &Jwk, time: Timestamp, verifier: &V) -> X509Result<X509CertificateIdentity>
+  where
+    V: JwsVerifier,
+  {
+    let chain: X509CertificateChain = self.validate_chain(&decode_x5c_certificate_chain(jwk)?, time, verifier)?;
+    chain.ensure_leaf_public_key(jwk)?;
+
+    if let Some(thumbprint) = jwk.x5t_s256() {
+      if thumbprint != chain.leaf_thumbprint_sha256_b64()? {
+        return Err(
+          X509Error::new(X509ErrorKind::PublicKeyMismatch)
+            .with_custom_message("`x5t#S256` does not match the end-entity certificate"),
+        );
+      }
+    }
+
+    chain.leaf_identity()
+  }
+}
+
+/// A certificate chain successfully validated by an [`X509TrustStore`].
+#[derive(Debug, Clone)]
+pub struct X509CertificateChain {
+  certificates: Vec<Certificate>,
+}
+
+impl X509CertificateChain {
+  /// Returns the public key certified by the end-entity certificate of the chain.
+  pub fn leaf_public_key(&self) -> X509Result<Jwk> {
+    spki_to_jwk(&self.leaf().tbs_certificate.subject_public_key_info)
+  }
+
+  /// Ensures that the end-entity certificate of the chain certifies `public_key`.
+  pub fn ensure_leaf_public_key(&self, public_key: &Jwk) -> X509Result<()> {
+    if self.leaf_public_key()?.thumbprint_sha256_b64() != public_key.thumbprint_sha256_b64() {
+      return Err(X509ErrorKind::PublicKeyMismatch.into());
+    }
+    Ok(())
+  }
+
+  /// Returns the DER encoded value of the extension identified by `oid` of the end-entity certificate, if present.
+  pub fn leaf_extension(&self, oid: &str) -> X509Result<Option<&[u8]>> {
+    let oid: ObjectIdentifier = ObjectIdentifier::new(oid)
+      .map_err(|err| X509Error::new(X509ErrorKind::InvalidObjectIdentifier).with_source(err))?;
+    Ok(find_extension(self.leaf(), &oid))
+  }
+
+  /// Returns the identity asserted by the end-entity certificate of the chain.
+  pub fn leaf_identity(&self) -> X509Result<X509CertificateIdentity> {
+    let tbs_certificate = &self.leaf().tbs_certificate;
+    let serial_number: String = tbs_certificate
+      .serial_number
+      .as_bytes()
+      .iter()
+      .map(|byte| format!("{byte:02x}"))
+      .collect();
+
+    Ok(X509CertificateIdentity {
+      subject: tbs_certificate.subject.to_string(),
+      issuer: tbs_certificate.issuer.to_string(),
+      serial_number,
+      subject_alt_names: subject_alt_names(self.leaf())?,
+      not_before: to_timestamp(&tbs_certificate.validity.not_before)?,
+      not_after: to_timestamp(&tbs_certificate.validity.not_after)?,
+    })
+  }
+
+  fn leaf_thumbprint_sha256_b64(&self) -> X509Result<String> {
+    let der: Vec<u8> = self
+      .leaf()
+      .to_der()
+      .map_err(|err| X509Error::new(X509ErrorKind::InvalidCertificate).with_source(err))?;
+    let mut thumbprint: [u8; SHA256_LEN] = Default::default();
+    SHA256(&der, &mut thumbprint);
+    Ok(jwu::encode_b64(thumbprint))
+  }
+
+  fn leaf(&self) -> &Certificate {
+    // A validated chain is never empty.
+    &self.certificates[0]
+  }
+}
+
+/// Decodes the `x5c` certificate chain of `jwk` into DER encoded certificates.
+pub fn decode_x5c_certificate_chain(jwk: &Jwk) -> X509Result<Vec<Vec<u8>>> {
+  let x5c: &[String] = jwk.x5c().ok_or_else(|| {
+    let error = X509Error::new(X509ErrorKind::MissingCertificateChain);
+    if jwk.x5u().is_some() {
+      error.with_custom_message("the chain referenced by `x5u` must be fetched and validated by the caller")
+    } else {
+      error
+    }
+  })?;
+
+  // Unlike other JWK parameters, `x5c` uses the standard base64 encoding with padding.
+  x5c
+    .iter()
+    .map(|certificate| {
+      BaseEncoding::decode(certificate, Base::Base64Pad)
+        .map_err(|err| X509Error::new(X509ErrorKind::InvalidCertificate).with_source(err))
+    })
+    .collect()
+}
+
+/// Decodes the PEM encoded certificates of `pem`, e.g. a chain retrieved from the `x5u` URL of a JWK, into DER
+/// encoded certificates.
+pub fn decode_pem_certificate_chain(pem: &str) -> X509Result<Vec<Vec<u8>>> {
+  let invalid_pem = || X509Error::new(X509ErrorKind::InvalidCertificate).with_custom_message("invalid PEM encoding");
+  let mut chain: Vec<Vec<u8>> = Vec::new();
+  let mut remaining: &str = pem;
+
+  while let Some(start) = remaining.find(PEM_BEGIN_CERTIFICATE) {
+    let body: &str = &remaining[start + PEM_BEGIN_CERTIFICATE.len()..];
+    let end: usize = body.find(PEM_END_CERTIFICATE).ok_or_else(invalid_pem)?;
+    let base64: String = body[..end].chars().filter(|char| !char.is_whitespace()).collect();
+    chain.push(BaseEncoding::decode(&base64, Base::Base64Pad).map_err(|err| invalid_pem().with_source(err))?);
+    remaining = &body[end + PEM_END_CERTIFICATE.len()..];
+  }
+
+  if chain.is_empty() {
+    return Err(invalid_pem());
+  }
+  Ok(chain)
+}
+
+/// Verifies the signature of `certificate` with the public key of `issuer`.
+fn verify_signature<V>(certificate: &Certificate, issuer: &Certificate, verifier: &V) -> X509Result<()>
+where
+  V: JwsVerifier,
+{
+  let public_key: Jwk = spki_to_jwk(&issuer.tbs_certificate.subject_public_key_info)?;
+  let alg: JwsAlgorithm = signature_algorithm(&certificate.signature_algorithm.oid, &public_key)?;
+
+  let signature: &[u8] = certificate.signature.as_bytes().ok_or_else(|| {
+    X509Error::new(X509ErrorKind::InvalidCertificate).with_custom_message("signature is not octet aligned")
+  })?;
+  // X.509 encodes ECDSA signatures as a DER sequence, whereas JWS uses the concatenation of `r` and `s`.
+  let decoded_signature: Vec<u8> = match alg {
+    JwsAlgorithm::ES256 | JwsAlgorithm::ES256K => ecdsa_signature_to_jws(signature, 32)?,
+    JwsAlgorithm::ES384 => ecdsa_signature_to_jws(signature, 48)?,
+    _ => signature.to_vec(),
+  };
+  let signing_input: Vec<u8> = certificate
+    .tbs_certificate
+    .to_der()
+    .map_err(|err| X509Error::new(X509ErrorKind::InvalidCertificate).with_source(err))?;
+
+  verifier
+    .verify(
+      VerificationInput {
+        alg,
+        signing_input: signing_input.into_boxed_slice(),
+        decoded_signature: decoded_signature.into_boxed_slice(),
+      },
+      &public_key,
+    )
+    .map_err(|err| X509Error::new(X509ErrorKind::InvalidSignature).with_source(err))
+}
+
+fn decode_certificate(certificate: &[u8]) -> X509Result<Certificate> {
+  Certificate::from_der(certificate).map_err(|err| X509Error::new(X509ErrorKind::InvalidCertificate).with_source(err))
+}
+
+fn find_extension<'c>(certificate: &'c Certificate, oid: &ObjectIdentifier) -> Option<&'c [u8]> {
+  certificate
+    .tbs_certificate
+    .extensions
+    .iter()
+    .flatten()
+    .find(|extension| extension.extn_id == *oid)
+    .map(|extension| extension.extn_value.as_bytes())
+}
+
+fn check_validity(certificate: &Certificate, time: Timestamp) -> X509Result<()> {
+  let validity = &certificate.tbs_certificate.validity;
+  let time: u64 = u64::try_from(time.to_unix()).unwrap_or_default();
+  if time < validity.not_before.to_unix_duration().as_secs() || time > validity.not_after.to_unix_duration().as_secs() {
+    return Err(
+      X509Error::new(X509ErrorKind::CertificateNotValidAtTime)
+        .with_custom_message(format!("certificate of `{}`", certificate.tbs_certificate.subject)),
+    );
+  }
+  Ok(())
+}
+
+/// Checks that `issuer` is a CA certificate allowed to sign certificates whose subject is the issuer of `certificate`.
+///
+/// `path_length` is the number of non-self-issued intermediate certificates between `issuer` and the end-entity
+/// certificate, which must not exceed the path length constraint of `issuer`.
+fn check_issued_by(certificate: &Certificate, issuer: &Certificate, path_length: usize) -> X509Result<()> {
+  if certificate.tbs_certificate.issuer != issuer.tbs_certificate.subject {
+    return Err(
+      X509Error::new(X509ErrorKind::UntrustedChain)
+        .with_custom_message(format!("certificate of `{}`", certificate.tbs_certificate.subject)),
+    );
+  }
+
+  let basic_constraints: Option<BasicConstraints> = find_extension(issuer, &BASIC_CONSTRAINTS)
+    .map(BasicConstraints::from_der)
+    .transpose()
+    .map_err(|err| X509Error::new(X509ErrorKind::InvalidCertificate).with_source(err))?;
+
+  let Some(basic_constraints) = basic_constraints.filter(|constraints| constraints.ca) else {
+    return Err(
+      X509Error::new(X509ErrorKind::UntrustedChain).with_custom_message(format!(
+        "issuer `{}` is not a certificate authority",
+        issuer.tbs_certificate.subject
+      )),
+    );
+  };
+
+  if basic_constraints
+    .path_len_constraint
+    .is_some_and(|path_len_constraint| path_length > usize::from(path_len_constraint))
+  {
+    return Err(
+      X509Error::new(X509ErrorKind::UntrustedChain).with_custom_message(format!(
+        "path length constraint of issuer `{}` exceeded",
+        issuer.tbs_certificate.subject
+      )),
+    );
+  }
+
+  let key_usage: Option<KeyUsage> = find_extension(issuer, &KEY_USAGE)
+    .map(KeyUsage::from_der)
+    .transpose()
+    .map_err(|err| X509Error::new(X509ErrorKind::InvalidCertificate).with_source(err))?;
+
+  if key_usage.is_some_and(|key_usage| !key_usage.key_cert_sign()) {
+    return Err(
+      X509Error::new(X509ErrorKind::UntrustedChain).with_custom_message(format!(
+        "key usage of issuer `{}` does not allow signing certificates",
+        issuer.tbs_certificate.subject
+      )),
+    );
+  }
+
+  Ok(())
+}
+
+/// Checks that all critical extensions of `certificate` are processed by the chain validation.
+fn check_critical_extensions(certificate: &Certificate) -> X509Result<()> {
+  let unsupported = certificate
+    .tbs_certificate
+    .extensions
+    .iter()
+    .flatten()
+    .find(|extension| extension.critical && !SUPPORTED_CRITICAL_EXTENSIONS.contains(&extension.extn_id));
+
+  if let Some(extension) = unsupported {
+    return Err(
+      X509Error::new(X509ErrorKind::InvalidCertificate).with_custom_message(format!(
+        "unsupported critical extension `{}` in certificate of `{}`",
+        extension.extn_id, certificate.tbs_certificate.subject
+      )),
+    );
+  }
+  Ok(())
+}
+
+/// Returns the number of `intermediates` that count towards path length constraints, i.e. that are not self-issued.
+fn path_length(intermediates: &[Certificate]) -> usize {
+  intermediates
+    .iter()
+    .filter(|certificate| certificate.tbs_certificate.issuer != certificate.tbs_certificate.subject)
+    .count()
+}
+
+fn subject_alt_names(certificate: &Certificate) -> X509Result<Vec<String>> {
+  let Some(extension) = find_extension(certificate, &SUBJECT_ALT_NAME) else {
+    return Ok(Vec::new());
+  };
+  let names: SubjectAltName = SubjectAltName::from_der(extension)
+    .map_err(|err| X509Error::new(X509ErrorKind::InvalidCertificate).with_source(err))?;
+
+  Ok(
+    names
+      .0
+      .into_iter()
+      .filter_map(|name| match name {
+        GeneralName::DnsName(name) | GeneralName::UniformResourceIdentifier(name) | GeneralName::Rfc822Name(name) => {
+          Some(name.to_string())
+        }
+        _ => None,
+      })
+      .collect(),
+  )
+}
+
+fn to_timestamp(time: &Time) -> X509Result<Timestamp> {
+  i64::try_from(time.to_unix_duration().as_secs())
+    .ok()
+    .and_then(|seconds| Timestamp::from_unix(seconds).ok())
+    .ok_or_else(|| X509Error::new(X509ErrorKind::InvalidCertificate).with_custom_message("invalid validity period"))
+}
+
+fn signature_algorithm(oid: &ObjectIdentifier, public_key: &Jwk) -> X509Result<JwsAlgorithm> {
+  let is_secp256k1 = || {
+    public_key
+      .try_ec_params()
+      .is_ok_and(|params| params.crv == EcCurve::Secp256K1.name())
+  };
+
+  if *oid == ECDSA_WITH_SHA256 {
+    Ok(if is_secp256k1() {
+      JwsAlgorithm::ES256K
+    } else {
+      JwsAlgorithm::ES256
+    })
+  } else if *oid == ECDSA_WITH_SHA384 {
+    Ok(JwsAlgorithm::ES384)
+  } else if *oid == SHA256_WITH_RSA_ENCRYPTION {
+    Ok(JwsAlgorithm::RS256)
+  } else if *oid == SHA384_WITH_RSA_ENCRYPTION {
+    Ok(JwsAlgorithm::RS384)
+  } else if *oid == SHA512_WITH_RSA_ENCRYPTION {
+    Ok(JwsAlgorithm::RS512)
+  } else if *oid == ED25519 {
+    Ok(JwsAlgorithm::EdDSA)
+  } else {
+    Err(
+      X509Error::new(X509ErrorKind::InvalidCertificate)
+        .with_custom_message(format!("unsupported signature algorithm `{oid}`")),
+    )
+  }
+}
+
+/// Converts the subject public key info of a certificate into a public JWK.
+fn spki_to_jwk(spki: &SubjectPublicKeyInfoOwned) -> X509Result<Jwk> {
+  let invalid_key = || X509Error::new(X509ErrorKind::InvalidCertificate).with_custom_message("invalid public key");
+  let key: &[u8] = spki.subject_public_key.as_bytes().ok_or_else(invalid_key)?;
+  let oid: ObjectIdentifier = spki.algorithm.oid;
+
+  if oid == EC_PUBLIC_KEY {
+    let curve: ObjectIdentifier = spki
+      .algorithm
+      .parameters
+      .as_ref()
+      .ok_or_else(invalid_key)?
+      .decode_as()
+      .map_err(|err| invalid_key().with_source(err))?;
+    let (curve, size): (EcCurve, usize) = if curve == SECP256R1 {
+      (EcCurve::P256, 32)
+    } else if curve == SECP384R1 {
+      (EcCurve::P384, 48)
+    } else if curve == SECP256K1 {
+      (EcCurve::Secp256K1, 32)
+    } else {
+      return Err(invalid_key().with_custom_message(format!("unsupported curve `{curve}`")));
+    };
+
+    // Only uncompressed SEC1 points are supported.
+    if key.len() != 1 + 2 * size || key[0] != 0x04 {
+      return Err(invalid_key());
+    }
+
+    let mut params = JwkParamsEc::new();
+    params.crv = curve.name().to_owned();
+    params.x = jwu::encode_b64(&key[1..=size]);
+    params.y = jwu::encode_b64(&key[1 + size..]);
+    Ok(Jwk::from_params(params))
+  } else if oid == RSA_ENCRYPTION {
+    let rsa_public_key: RsaPublicKey<'_> = RsaPublicKey::from_der(key).map_err(|err| invalid_key().with_source(err))?;
+    let mut params = JwkParamsRsa::new();
+    params.n = jwu::encode_b64(rsa_public_key.modulus.as_bytes());
+    params.e = jwu::encode_b64(rsa_public_key.public_exponent.as_bytes());
+    Ok(Jwk::from_params(params))
+  } else if oid == ED25519 {
+    let mut params = JwkParamsOkp::new();
+    params.crv = EdCurve::Ed25519.name().to_owned();
+    params.x = jwu::encode_b64(key);
+    Ok(Jwk::from_params(params))
+  } else {
+    Err(invalid_key().with_custom_message(format!("unsupported key algorithm `{oid}`")))
+  }
+}
+
+/// Converts a DER encoded ECDSA signature into the fixed size encoding used by JWS.
+fn ecdsa_signature_to_jws(signature: &[u8], size: usize) -> X509Result<Vec<u8>> {
+  let invalid_signature =
+    || X509Error::new(X509ErrorKind::InvalidCertificate).with_custom_message("invalid ECDSA signature encoding");
+  let signature: EcdsaSignature<'_> = EcdsaSignature::from_der(signature).map_err(|_| invalid_signature())?;
+
+  let mut jws_signature: Vec<u8> = vec![0; 2 * size];
+  for (component, offset) in [(signature.r, 0), (signature.s, size)] {
+    let bytes: &[u8] = component.as_bytes();
+    if bytes.len() > size {
+      return Err(invalid_signature());
+    }
+    jws_signature[offset + size - bytes.len()..offset + size].copy_from_slice(bytes);
+  }
+
+  Ok(jws_signature)
+}
+
+/// RSA public key as defined in [RFC 8017](https://www.rfc-editor.org/rfc/rfc8017#appendix-A.1.1).
+#[derive(Sequence)]
+struct RsaPublicKey<'a> {
+  modulus: UintRef<'a>,
+  public_exponent: UintRef<'a>,
+}
+
+/// ECDSA signature as defined in [RFC 3279](https://www.rfc-editor.org/rfc/rfc3279#section-2.2.3).
+#[derive(Sequence)]
+struct EcdsaSignature<'a> {
+  r: UintRef<'a>,
+  s: UintRef<'a>,
+}
+
+#[cfg(test)]
+mod tests {
+  use der::asn1::OctetString;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+  use x509_cert::ext::pkix::KeyUsages;
+  use x509_cert::ext::Extension;
+
+  use super::*;
+  use crate::validator::test_utils::x509_bound_jwk;
+  use crate::validator::test_utils::X509_INTERMEDIATE_CERTIFICATE;
+  use crate::validator::test_utils::X509_LEAF_CERTIFICATE;
+  use crate::validator::test_utils::X509_OTHER_ROOT_CERTIFICATE;
+  use crate::validator::test_utils::X509_ROOT_CERTIFICATE;
+
+  fn trust_store(root: &[u8]) -> X509TrustStore {
+    X509TrustStore::new().with_root(root).unwrap()
+  }
+
+  fn validate_chain(root: &[u8], chain: &[&[u8]]) -> X509Result<X509CertificateChain> {
+    let chain: Vec<Vec<u8>> = chain.iter().map(|certificate| certificate.to_vec()).collect();
+    trust_store(root).validate_chain(&chain, Timestamp::now_utc(), &EdDSAJwsVerifier::default())
+  }
+
+  /// Returns `certificate` with `extension` added, replacing any extension with the same identifier. The signature is
+  /// left unchanged, so this is only meaningful for trusted roots or checks preceding the signature verification.
+  fn with_extension(certificate: &[u8], extn_id: ObjectIdentifier, critical: bool, value: Vec<u8>) -> Vec<u8> {
+    let mut certificate: Certificate = Certificate::from_der(certificate).unwrap();
+    let extensions: &mut Vec<Extension> = certificate.tbs_certificate.extensions.get_or_insert_with(Vec::new);
+    extensions.retain(|extension| extension.extn_id != extn_id);
+    extensions.push(Extension {
+      extn_id,
+      critical,
+      extn_value: OctetString::new(value).unwrap(),
+    });
+    certificate.to_der().unwrap()
+  }
+
+  fn with_path_len_constraint(certificate: &[u8], path_len_constraint: u8) -> Vec<u8> {
+    let basic_constraints: BasicConstraints = BasicConstraints {
+      ca: true,
+      path_len_constraint: Some(path_len_constraint),
+    };
+    with_extension(
+      certificate,
+      BASIC_CONSTRAINTS,
+      true,
+      basic_constraints.to_der().unwrap(),
+    )
+  }
+
+  fn with_key_usage(certificate: &[u8], key_usage: KeyUsages) -> Vec<u8> {
+    with_extension(
+      certificate,
+      KEY_USAGE,
+      true,
+      KeyUsage(key_usage.into()).to_der().unwrap(),
+    )
+  }
+
+  #[test]
+  fn validate_chain_enforces_path_len_constraint() {
+    let chain: [&[u8]; 2] = [X509_LEAF_CERTIFICATE, X509_INTERMEDIATE_CERTIFICATE];
+
+    // The intermediate is the only CA between the root and the leaf.
+    assert!(validate_chain(&with_path_len_constraint(X509_ROOT_CERTIFICATE, 1), &chain).is_ok());
+    let error: X509Error = validate_chain(&with_path_len_constraint(X509_ROOT_CERTIFICATE, 0), &chain).unwrap_err();
+    assert!(matches!(error.kind(), X509ErrorKind::UntrustedChain));
+
+    // The end-entity certificate does not count towards the constraint.
+    let root: Vec<u8> = with_path_len_constraint(X509_ROOT_CERTIFICATE, 0);
+    assert!(validate_chain(&root, &[X509_INTERMEDIATE_CERTIFICATE]).is_ok());
+  }
+
+  #[test]
+  fn validate_chain_enforces_key_cert_sign() {
+    let chain: [&[u8]; 2] = [X509_LEAF_CERTIFICATE, X509_INTERMEDIATE_CERTIFICATE];
+
+    let root: Vec<u8> = with_key_usage(X509_ROOT_CERTIFICATE, KeyUsages::KeyCertSign);
+    assert!(validate_chain(&root, &chain).is_ok());
+
+    let root: Vec<u8> = with_key_usage(X509_ROOT_CERTIFICATE, KeyUsages::DigitalSignature);
+    let error: X509Error = validate_chain(&root, &chain).unwrap_err();
+    assert!(matches!(error.kind(), X509ErrorKind::UntrustedChain));
+  }
+
+  #[test]
+  fn validate_chain_rejects_unsupported_critical_extensions() {
+    let unknown: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.3.4");
+    let chain: [&[u8]; 2] = [X509_LEAF_CERTIFICATE, X509_INTERMEDIATE_CERTIFICATE];
+
+    // Unknown extensions are ignored unless they are critical.
+    let root: Vec<u8> = with_extension(X509_ROOT_CERTIFICATE, unknown, false, vec![0x05, 0x00]);
+    assert!(validate_chain(&root, &chain).is_ok());
+
+    let root: Vec<u8> = with_extension(X509_ROOT_CERTIFICATE, unknown, true, vec![0x05, 0x00]);
+    let error: X509Error = validate_chain(&root, &chain).unwrap_err();
+    assert!(matches!(error.kind(), X509ErrorKind::UntrustedChain));
+
+    // Critical extensions are checked before the signature of the certificate.
+    let leaf: Vec<u8> = with_extension(X509_LEAF_CERTIFICATE, unknown, true, vec![0x05, 0x00]);
+    let error: X509Error = validate_chain(X509_ROOT_CERTIFICATE, &[&leaf, X509_INTERMEDIATE_CERTIFICATE]).unwrap_err();
+    assert!(matches!(error.kind(), X509ErrorKind::InvalidCertificate));
+  }
+
+  #[test]
+  fn validate_jwk_x5c() {
+    let (jwk, _) = x509_bound_jwk();
+    let identity: X509CertificateIdentity = trust_store(X509_ROOT_CERTIFICATE)
+      .validate_jwk(&jwk, Timestamp::now_utc(), &EdDSAJwsVerifier::default())
+      .unwrap();
+
+    assert_eq!(identity.subject, "CN=Test Attested Key");
+    assert_eq!(identity.issuer, "CN=Test Attestation Intermediate");
+    assert_eq!(identity.serial_number, "264a8388389d7445f525029af9ad0deed38400ac");
+    assert!(identity.subject_alt_names.is_empty());
+    assert!(identity.not_before < Timestamp::now_utc() && Timestamp::now_utc() < identity.not_after);
+  }
+
+  #[test]
+  fn validate_jwk_rejects_untrusted_chain() {
+    let (jwk, _) = x509_bound_jwk();
+    let error: X509Error = trust_store(X509_OTHER_ROOT_CERTIFICATE)
+      .validate_jwk(&jwk, Timestamp::now_utc(), &EdDSAJwsVerifier::default())
+      .unwrap_err();
+    assert!(matches!(error.kind(), X509ErrorKind::UntrustedChain));
+
+    // The certificates are not valid before 2024.
+    let error: X509Error = trust_store(X509_ROOT_CERTIFICATE)
+      .validate_jwk(
+        &jwk,
+        Timestamp::parse("2023-01-01T00:00:00Z").unwrap(),
+        &EdDSAJwsVerifier::default(),
+      )
+      .unwrap_err();
+    assert!(matches!(error.kind(), X509ErrorKind::CertificateNotValidAtTime));
+  }
+
+  #[test]
+  fn validate_jwk_rejects_mismatched_key() {
+    let (jwk, _) = x509_bound_jwk();
+    let mut params: JwkParamsOkp = jwk.try_okp_params().unwrap().clone();
+    params.x = jwu::encode_b64([0; 32]);
+    let mut other: Jwk = Jwk::from_params(params);
+    other.set_x5c(jwk.x5c().unwrap().iter().cloned());
+
+    let error: X509Error = trust_store(X509_ROOT_CERTIFICATE)
+      .validate_jwk(&other, Timestamp::now_utc(), &EdDSAJwsVerifier::default())
+      .unwrap_err();
+    assert!(matches!(error.kind(), X509ErrorKind::PublicKeyMismatch));
+
+    let mut wrong_thumbprint: Jwk = jwk.clone();
+    wrong_thumbprint.set_x5t_s256(jwu::encode_b64([0; SHA256_LEN]));
+    let error: X509Error = trust_store(X509_ROOT_CERTIFICATE)
+      .validate_jwk(&wrong_thumbprint, Timestamp::now_utc(), &EdDSAJwsVerifier::default())
+      .unwrap_err();
+    assert!(matches!(error.kind(), X509ErrorKind::PublicKeyMismatch));
+  }
+
+  #[test]
+  fn validate_jwk_requires_x5c() {
+    let (jwk, _) = x509_bound_jwk();
+    let params: JwkParamsOkp = jwk.try_okp_params().unwrap().clone();
+    let mut without_chain: Jwk = Jwk::from_params(params);
+    without_chain.set_x5u(identity_core::common::Url::parse("https://example.com/chain.pem").unwrap());
+
+    let error: X509Error = trust_store(X509_ROOT_CERTIFICATE)
+      .validate_jwk(&without_chain, Timestamp::now_utc(), &EdDSAJwsVerifier::default())
+      .unwrap_err();
+    assert!(matches!(error.kind(), X509ErrorKind::MissingCertificateChain));
+  }
+
+  #[test]
+  fn decode_pem_chain() {
+    let pem: String = [X509_LEAF_CERTIFICATE, X509_INTERMEDIATE_CERTIFICATE]
+      .into_iter()
+      .map(|certificate| {
+        let base64: String = BaseEncoding::encode(certificate, Base::Base64Pad);
+        let lines: Vec<&str> = base64
+          .as_bytes()
+          .chunks(64)
+          .map(|line| std::str::from_utf8(line).unwrap())
+          .collect();
+        format!("{PEM_BEGIN_CERTIFICATE}\n{}\n{PEM_END_CERTIFICATE}\n", lines.join("\n"))
+      })
+      .collect();
+
+    let chain: Vec<Vec<u8>> = decode_pem_certificate_chain(&pem).unwrap();
+    assert_eq!(chain, [X509_LEAF_CERTIFICATE, X509_INTERMEDIATE_CERTIFICATE]);
+    assert!(trust_store(X509_ROOT_CERTIFICATE)
+      .validate_chain(&chain, Timestamp::now_utc(), &EdDSAJwsVerifier::default())
+      .is_ok());
+
+    assert!(decode_pem_certificate_chain("not a certificate").is_err());
+  }
+}
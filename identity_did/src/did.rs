@@ -265,6 +265,25 @@ impl KeyComparable for CoreDID {
   }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CoreDID {
+  fn schema_name() -> String {
+    "CoreDID".to_owned()
+  }
+
+  fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+      instance_type: Some(schemars::schema::InstanceType::String.into()),
+      string: Some(Box::new(schemars::schema::StringValidation {
+        pattern: Some("^did:[0-9a-z]+:".to_owned()),
+        ..Default::default()
+      })),
+      ..Default::default()
+    }
+    .into()
+  }
+}
+
 /// Checks whether a character satisfies DID method name constraints:
 /// { 0-9 | a-z }
 #[inline(always)]
@@ -6,7 +6,9 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use identity_jose::jwk::Jwk;
+use identity_jose::jwk::JwkParams;
 use identity_jose::jwu::decode_b64_json;
+use identity_jose::jwu::encode_b64_json;
 
 use crate::CoreDID;
 use crate::Error;
@@ -22,6 +24,28 @@ impl DIDJwk {
   /// [`DIDJwk`]'s method.
   pub const METHOD: &'static str = "jwk";
 
+  /// Creates a [`DIDJwk`] encoding the public key `jwk`, e.g. to mint an ephemeral identity offline.
+  ///
+  /// If `jwk` has no `alg` parameter, it is set to the algorithm implied by the key's curve, see
+  /// [`Jwk::infer_jws_algorithm`], so that the methods of the expanded document can be used for signing.
+  ///
+  /// # Errors
+  /// Fails if `jwk` is a symmetric key or contains private key material.
+  pub fn new(jwk: &Jwk) -> Result<Self, Error> {
+    if !jwk.is_public() || matches!(jwk.params(), JwkParams::Oct(_)) {
+      return Err(Error::Other("did:jwk requires an asymmetric public key"));
+    }
+
+    let mut jwk: Jwk = jwk.clone();
+    if jwk.alg().is_none() {
+      if let Some(alg) = jwk.infer_jws_algorithm() {
+        jwk.set_alg(alg.name());
+      }
+    }
+    let method_id: String = encode_b64_json(&jwk).map_err(|_| Error::InvalidMethodId)?;
+    CoreDID::parse(format!("did:{}:{method_id}", Self::METHOD)).map(Self)
+  }
+
   /// Tries to parse a [`DIDJwk`] from a string.
   pub fn parse(s: &str) -> Result<Self, Error> {
     s.parse()
@@ -108,6 +132,30 @@ mod tests {
     assert_eq!(did.jwk(), target_jwk);
   }
 
+  #[test]
+  fn test_new() {
+    let jwk = Jwk::from_json_value(serde_json::json!({
+      "kty": "OKP", "crv": "Ed25519", "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    }))
+    .unwrap();
+    let did = DIDJwk::new(&jwk).unwrap();
+    assert_eq!(did.jwk().alg(), Some("EdDSA"));
+    assert_eq!(DIDJwk::parse(did.as_str()).unwrap(), did);
+
+    // Keys with an `alg` are encoded as they are.
+    let did = DIDJwk::new(&did.jwk()).unwrap();
+    assert_eq!(did.jwk().alg(), Some("EdDSA"));
+
+    let private = Jwk::from_json_value(serde_json::json!({
+      "kty": "OKP", "crv": "Ed25519", "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+      "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"
+    }))
+    .unwrap();
+    assert!(DIDJwk::new(&private).is_err());
+    let symmetric = Jwk::from_json_value(serde_json::json!({ "kty": "oct", "k": "AyM1SysPpbyDfgZld3umj1qzKObwVMkoqQ-EstJQLr_T-1qS0gZH75aKtMN3Yj0iPS4hcgUuTwjAzZr1Z9CAow" })).unwrap();
+    assert!(DIDJwk::new(&symmetric).is_err());
+  }
+
   #[test]
   fn test_invalid_deserialization() {
     assert!(
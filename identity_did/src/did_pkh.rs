@@ -0,0 +1,173 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::CoreDID;
+use crate::Error;
+use crate::DID;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Deserialize, serde::Serialize)]
+#[repr(transparent)]
+#[serde(into = "CoreDID", try_from = "CoreDID")]
+/// A type representing a `did:pkh` DID, identifying a blockchain account by its
+/// [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-10.md) account id,
+/// e.g. `did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a`.
+pub struct DIDPkh(CoreDID);
+
+impl DIDPkh {
+  /// [`DIDPkh`]'s method.
+  pub const METHOD: &'static str = "pkh";
+
+  /// Tries to parse a [`DIDPkh`] from a string.
+  pub fn parse(s: &str) -> Result<Self, Error> {
+    s.parse()
+  }
+
+  /// Creates the [`DIDPkh`] of the account `address` on the chain `chain_reference` of the chain `namespace`, e.g.
+  /// `eip155`, `1` and `0xb9c5714089478a327f09197987f16f9e5d936e8a` for an Ethereum mainnet account.
+  pub fn new(namespace: &str, chain_reference: &str, address: &str) -> Result<Self, Error> {
+    format!("did:{}:{namespace}:{chain_reference}:{address}", Self::METHOD).parse()
+  }
+
+  /// Returns the CAIP-10 account id of this DID, e.g. `eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a`.
+  pub fn account_id(&self) -> &str {
+    self.method_id()
+  }
+
+  /// Returns the namespace of the chain of the account, e.g. `eip155`.
+  pub fn namespace(&self) -> &str {
+    self.components().0
+  }
+
+  /// Returns the reference identifying the chain of the account within its namespace, e.g. `1`.
+  pub fn chain_reference(&self) -> &str {
+    self.components().1
+  }
+
+  /// Returns the address of the account on its chain.
+  pub fn address(&self) -> &str {
+    self.components().2
+  }
+
+  fn components(&self) -> (&str, &str, &str) {
+    split_account_id(self.account_id()).expect("did:pkh encodes a valid CAIP-10 account id")
+  }
+}
+
+/// Splits a CAIP-10 account id into its chain namespace, chain reference and address.
+fn split_account_id(account_id: &str) -> Option<(&str, &str, &str)> {
+  let mut components = account_id.splitn(3, ':');
+  let namespace: &str = components.next()?;
+  let reference: &str = components.next()?;
+  let address: &str = components.next()?;
+
+  let valid_namespace: bool = (3..=8).contains(&namespace.len())
+    && namespace
+      .bytes()
+      .all(|byte| byte == b'-' || byte.is_ascii_lowercase() || byte.is_ascii_digit());
+  let valid_reference: bool = (1..=32).contains(&reference.len())
+    && reference
+      .bytes()
+      .all(|byte| byte == b'-' || byte == b'_' || byte.is_ascii_alphanumeric());
+  let valid_address: bool = (1..=128).contains(&address.len())
+    && address
+      .bytes()
+      .all(|byte| matches!(byte, b'-' | b'.' | b'%') || byte.is_ascii_alphanumeric());
+
+  (valid_namespace && valid_reference && valid_address).then_some((namespace, reference, address))
+}
+
+impl AsRef<CoreDID> for DIDPkh {
+  fn as_ref(&self) -> &CoreDID {
+    &self.0
+  }
+}
+
+impl From<DIDPkh> for CoreDID {
+  fn from(value: DIDPkh) -> Self {
+    value.0
+  }
+}
+
+impl<'a> TryFrom<&'a str> for DIDPkh {
+  type Error = Error;
+  fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl Display for DIDPkh {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+impl FromStr for DIDPkh {
+  type Err = Error;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    s.parse::<CoreDID>().and_then(TryFrom::try_from)
+  }
+}
+
+impl From<DIDPkh> for String {
+  fn from(value: DIDPkh) -> Self {
+    value.to_string()
+  }
+}
+
+impl TryFrom<CoreDID> for DIDPkh {
+  type Error = Error;
+  fn try_from(value: CoreDID) -> Result<Self, Self::Error> {
+    let Self::METHOD = value.method() else {
+      return Err(Error::InvalidMethodName);
+    };
+    split_account_id(value.method_id())
+      .map(|_| Self(value))
+      .ok_or(Error::InvalidMethodId)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_valid_deserialization() -> Result<(), Error> {
+    "did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a".parse::<DIDPkh>()?;
+    "did:pkh:bip122:000000000019d6689c085ae165831e93:128Lkh3S7CkDTBZ8W7BbpsN3YYizJMp8p6".parse::<DIDPkh>()?;
+    "did:pkh:solana:4sGjMW1sUnHzSxGspuhpqLDx6wiyjNtZ:CKg5d12Jhpej1JqtmxLJgaFqqeYjxgPqToJ4LBdvG9Ev".parse::<DIDPkh>()?;
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_components() {
+    let did: DIDPkh = DIDPkh::new("eip155", "1", "0xb9c5714089478a327f09197987f16f9e5d936e8a").unwrap();
+    assert_eq!(
+      did.as_str(),
+      "did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a"
+    );
+    assert_eq!(did.account_id(), "eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a");
+    assert_eq!(did.namespace(), "eip155");
+    assert_eq!(did.chain_reference(), "1");
+    assert_eq!(did.address(), "0xb9c5714089478a327f09197987f16f9e5d936e8a");
+  }
+
+  #[test]
+  fn test_invalid_deserialization() {
+    assert!(
+      "did:iota:0xf4d6f08f5a1b80dd578da7dc1b49c886d580acd4cf7d48119dfeb82b538ad88a"
+        .parse::<DIDPkh>()
+        .is_err()
+    );
+    assert!("did:pkh:".parse::<DIDPkh>().is_err());
+    assert!("did:pkh:eip155:1".parse::<DIDPkh>().is_err());
+    assert!("did:pkh:EIP155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a"
+      .parse::<DIDPkh>()
+      .is_err());
+    assert!("did:pkh:eip155:1:".parse::<DIDPkh>().is_err());
+  }
+}
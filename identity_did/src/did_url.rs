@@ -515,6 +515,25 @@ impl KeyComparable for DIDUrl {
   }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DIDUrl {
+  fn schema_name() -> String {
+    "DIDUrl".to_owned()
+  }
+
+  fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+      instance_type: Some(schemars::schema::InstanceType::String.into()),
+      string: Some(Box::new(schemars::schema::StringValidation {
+        pattern: Some("^did:[0-9a-z]+:".to_owned()),
+        ..Default::default()
+      })),
+      ..Default::default()
+    }
+    .into()
+  }
+}
+
 /// Checks whether a character satisfies DID Url path constraints.
 #[inline(always)]
 #[rustfmt::skip]
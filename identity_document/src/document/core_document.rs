@@ -4,13 +4,20 @@
 use core::convert::TryInto as _;
 use core::fmt::Display;
 use core::fmt::Formatter;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::convert::Infallible;
 
 use identity_did::DIDJwk;
+use identity_did::DIDPkh;
 use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkOperation;
+use identity_verification::jose::jwk::JwkSet;
+use identity_verification::jose::jwk::JwkUse;
+use identity_verification::jose::jws::AsyncJwsVerifier;
 use identity_verification::jose::jws::DecodedJws;
 use identity_verification::jose::jws::Decoder;
+use identity_verification::jose::jws::JwsValidationItem;
 use identity_verification::jose::jws::JwsVerifier;
 use serde::Serialize;
 
@@ -30,12 +37,16 @@ use crate::utils::Queryable;
 use crate::verifiable::JwsVerificationOptions;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
+use identity_verification::MethodBuilder;
+use identity_verification::MethodData;
 use identity_verification::MethodRef;
 use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
+use identity_verification::MethodType;
 use identity_verification::VerificationMethod;
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[rustfmt::skip]
 pub(crate) struct CoreDocumentData
 {
@@ -334,6 +345,34 @@ impl CoreDocument {
     &mut self.data.controller
   }
 
+  /// Returns an iterator over the DIDs in the `CoreDocument` controller set, which is empty if no controller is set.
+  pub fn controllers(&self) -> impl Iterator<Item = &CoreDID> {
+    self.data.controller.iter().flat_map(|controller| controller.iter())
+  }
+
+  /// Returns `true` if `did` is this document's id or one of its controllers.
+  pub fn is_controlled_by(&self, did: &CoreDID) -> bool {
+    self.id() == did || self.controllers().any(|controller| controller == did)
+  }
+
+  /// Checks that every embedded verification method is controlled by this document's id or one of its controllers.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::InvalidMethodController`] for the first method controlled by another DID.
+  pub fn check_method_controllers(&self) -> Result<()> {
+    self
+      .all_methods()
+      .try_for_each(|method| self.check_method_controller(method))
+  }
+
+  fn check_method_controller(&self, method: &VerificationMethod) -> Result<()> {
+    self
+      .is_controlled_by(method.controller())
+      .then_some(())
+      .ok_or_else(|| Error::InvalidMethodController(method.id().clone()))
+  }
+
   /// Returns a reference to the `CoreDocument` alsoKnownAs set.
   pub fn also_known_as(&self) -> &OrderedSet<Url> {
     &self.data.also_known_as
@@ -608,6 +647,116 @@ impl CoreDocument {
     }
   }
 
+  /// Attaches all `relationships` to the method resolved by `method_query`.
+  /// Returns `true` if at least one of the relationships was newly attached, `false` otherwise.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the method does not exist or if it is embedded, in which case no relationship is attached.
+  /// See [`Self::attach_method_relationship`].
+  pub fn attach_method_relationships<'query, Q, I>(&mut self, method_query: Q, relationships: I) -> Result<bool>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+    I: IntoIterator<Item = MethodRelationship>,
+  {
+    let method_query: DIDUrlQuery<'query> = method_query.into();
+    let mut was_attached: bool = false;
+    for relationship in relationships {
+      was_attached |= self.attach_method_relationship(method_query.clone(), relationship)?;
+    }
+    Ok(was_attached)
+  }
+
+  /// Detaches all `relationships` from the method resolved by `method_query`.
+  /// Returns `true` if at least one of the relationships was found and removed, `false` otherwise.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the method does not exist or if it is embedded, in which case no relationship is detached.
+  /// See [`Self::detach_method_relationship`].
+  pub fn detach_method_relationships<'query, Q, I>(&mut self, method_query: Q, relationships: I) -> Result<bool>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+    I: IntoIterator<Item = MethodRelationship>,
+  {
+    let method_query: DIDUrlQuery<'query> = method_query.into();
+    let mut was_detached: bool = false;
+    for relationship in relationships {
+      was_detached |= self.detach_method_relationship(method_query.clone(), relationship)?;
+    }
+    Ok(was_detached)
+  }
+
+  /// Moves the [`VerificationMethod`] identified by `did_url` into `scope`.
+  ///
+  /// When moved into [`MethodScope::VerificationMethod`], an embedded method keeps its relationship as a reference.
+  /// A method can only be embedded in a relationship if no other relationship references it, since references to
+  /// embedded methods are not allowed.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the method does not exist, or if `scope` is a relationship and the method is referenced by
+  /// another relationship. The document is left unchanged in both cases.
+  pub fn move_method(&mut self, did_url: &DIDUrl, scope: MethodScope) -> Result<()> {
+    let relationships: Vec<MethodRelationship> = self.method_relationships(did_url);
+    if self.resolve_method(did_url, None).is_none() {
+      return Err(Error::MethodNotFound);
+    }
+    if let Some(target) = scope.relationship() {
+      if relationships.iter().any(|relationship| *relationship != target) {
+        return Err(Error::InvalidMethodEmbedded);
+      }
+    }
+
+    let (method, _) = self.remove_method_and_scope(did_url).ok_or(Error::MethodNotFound)?;
+    let method_id: DIDUrl = method.id().clone();
+    self.insert_method(method, scope)?;
+    if scope == MethodScope::VerificationMethod {
+      self.attach_method_relationships(&method_id, relationships)?;
+    }
+    Ok(())
+  }
+
+  /// Returns the verification relationships in which the method identified by `did_url` is embedded or referenced.
+  pub fn method_relationships(&self, did_url: &DIDUrl) -> Vec<MethodRelationship> {
+    MethodRelationship::ALL
+      .into_iter()
+      .filter(|relationship| {
+        self
+          .relationship_set(*relationship)
+          .iter()
+          .any(|method_ref| method_ref.id() == did_url)
+      })
+      .collect()
+  }
+
+  /// Returns all embedded verification methods paired with the verification relationships in which they are
+  /// embedded or referenced.
+  pub fn methods_with_relationships(&self) -> Vec<(&VerificationMethod, Vec<MethodRelationship>)> {
+    let mut relationships: HashMap<&DIDUrl, Vec<MethodRelationship>> = HashMap::new();
+    for relationship in MethodRelationship::ALL {
+      for method_ref in self.relationship_set(relationship).iter() {
+        relationships.entry(method_ref.id()).or_default().push(relationship);
+      }
+    }
+
+    self
+      .all_methods()
+      .map(|method| (method, relationships.remove(method.id()).unwrap_or_default()))
+      .collect()
+  }
+
+  /// Returns the set of method references of the given verification relationship.
+  fn relationship_set(&self, relationship: MethodRelationship) -> &OrderedSet<MethodRef> {
+    match relationship {
+      MethodRelationship::Authentication => &self.data.authentication,
+      MethodRelationship::AssertionMethod => &self.data.assertion_method,
+      MethodRelationship::KeyAgreement => &self.data.key_agreement,
+      MethodRelationship::CapabilityDelegation => &self.data.capability_delegation,
+      MethodRelationship::CapabilityInvocation => &self.data.capability_invocation,
+    }
+  }
+
   /// Returns a `Vec` of verification method references whose verification relationship matches `scope`.
   ///
   /// If `scope` is `None`, an iterator over all **embedded** methods is returned.
@@ -646,6 +795,15 @@ impl CoreDocument {
     }
   }
 
+  /// Returns the verification methods of the verification relationship given by `scope`, resolving referenced
+  /// methods to the methods they refer to.
+  ///
+  /// For [`MethodScope::VerificationMethod`], the methods of the `verificationMethod` property are returned.
+  /// References that cannot be resolved within this document are skipped.
+  pub fn methods_for(&self, scope: MethodScope) -> Vec<&VerificationMethod> {
+    self.methods(Some(scope))
+  }
+
   /// Returns an iterator over all embedded verification methods in the DID Document.
   ///
   /// This excludes verification methods that are referenced by the DID Document.
@@ -784,7 +942,11 @@ impl CoreDocument {
   pub fn resolve_method_ref<'a>(&'a self, method_ref: &'a MethodRef) -> Option<&'a VerificationMethod> {
     match method_ref {
       MethodRef::Embed(method) => Some(method),
-      MethodRef::Refer(did) => self.data.verification_method.query(did),
+      MethodRef::Refer(did) => self
+        .data
+        .verification_method
+        .query(did)
+        .or_else(|| self.all_methods().find(|method| method.id() == did)),
     }
   }
 
@@ -913,6 +1075,18 @@ impl AsRef<CoreDocument> for CoreDocument {
   }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CoreDocument {
+  fn schema_name() -> String {
+    "CoreDocument".to_owned()
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    // The document is (de)serialized as its data.
+    <CoreDocumentData as schemars::JsonSchema>::json_schema(gen)
+  }
+}
+
 impl TryFrom<CoreDocumentData> for CoreDocument {
   type Error = crate::error::Error;
   fn try_from(value: CoreDocumentData) -> Result<Self, Self::Error> {
@@ -941,6 +1115,10 @@ impl CoreDocument {
   /// - The JWS must be encoded according to the JWS compact serialization.
   /// - The `kid` value in the protected header must be an identifier of a verification method in this DID document, or
   ///   set explicitly in the `options`.
+  ///
+  /// Signatures of methods with a `blockchainAccountId`, e.g. of did:pkh documents, are verified with
+  /// [`JwsVerifier::verify_blockchain_account`]. Public keys in the `publicKeyMultibase` entry of methods are decoded as
+  /// [`Multikey`](identity_verification::Multikey)s and verified as their JWK representation.
   //
   // NOTE: This is tested in `identity_storage` and `identity_credential`.
   pub fn verify_jws<'jws, T: JwsVerifier>(
@@ -950,6 +1128,55 @@ impl CoreDocument {
     signature_verifier: &T,
     options: &JwsVerificationOptions,
   ) -> Result<DecodedJws<'jws>> {
+    let (validation_item, method_data) = self.decode_jws_for_verification(jws, detached_payload, options)?;
+
+    if let Some(account_id) = method_data.blockchain_account_id() {
+      return validation_item
+        .verify_blockchain_account(signature_verifier, account_id)
+        .map_err(Error::JwsVerificationError);
+    }
+    let public_key: Cow<'_, Jwk> = method_data.try_to_public_key_jwk().map_err(Error::InvalidKeyMaterial)?;
+    validation_item
+      .verify(signature_verifier, &public_key)
+      .map_err(Error::JwsVerificationError)
+  }
+
+  /// Decodes and verifies the provided JWS according to the passed [`JwsVerificationOptions`] and
+  /// [`AsyncJwsVerifier`], e.g. a remote verification service.
+  ///
+  /// See [`Self::verify_jws`] for the conditions which must be met for a verification attempt to take place.
+  /// Signatures of methods with a `blockchainAccountId` are verified with
+  /// [`AsyncJwsVerifier::verify_blockchain_account`].
+  pub async fn verify_jws_async<'jws, T: AsyncJwsVerifier + ?Sized>(
+    &self,
+    jws: &'jws str,
+    detached_payload: Option<&'jws [u8]>,
+    signature_verifier: &T,
+    options: &JwsVerificationOptions,
+  ) -> Result<DecodedJws<'jws>> {
+    let (validation_item, method_data) = self.decode_jws_for_verification(jws, detached_payload, options)?;
+
+    if let Some(account_id) = method_data.blockchain_account_id() {
+      return validation_item
+        .verify_blockchain_account_async(signature_verifier, account_id)
+        .await
+        .map_err(Error::JwsVerificationError);
+    }
+    let public_key: Cow<'_, Jwk> = method_data.try_to_public_key_jwk().map_err(Error::InvalidKeyMaterial)?;
+
+    validation_item
+      .verify_async(signature_verifier, &public_key)
+      .await
+      .map_err(Error::JwsVerificationError)
+  }
+
+  /// Decodes the provided JWS and resolves the key material of the verification method it must be verified with.
+  fn decode_jws_for_verification<'jws>(
+    &self,
+    jws: &'jws str,
+    detached_payload: Option<&'jws [u8]>,
+    options: &JwsVerificationOptions,
+  ) -> Result<(JwsValidationItem<'jws>, &MethodData)> {
     let validation_item = Decoder::new()
       .decode_compact_serialization(jws.as_bytes(), detached_payload)
       .map_err(Error::JwsVerificationError)?;
@@ -972,16 +1199,14 @@ impl CoreDocument {
         .into(),
     };
 
-    let public_key: &Jwk = self
+    let method: &VerificationMethod = self
       .resolve_method(method_url_query, options.method_scope)
-      .ok_or(Error::MethodNotFound)?
-      .data()
-      .try_public_key_jwk()
-      .map_err(Error::InvalidKeyMaterial)?;
+      .ok_or(Error::MethodNotFound)?;
+    if options.require_controlled_method {
+      self.check_method_controller(method)?;
+    }
 
-    validation_item
-      .verify(signature_verifier, public_key)
-      .map_err(Error::JwsVerificationError)
+    Ok((validation_item, method.data()))
   }
 }
 
@@ -1000,6 +1225,101 @@ impl CoreDocument {
       .capability_delegation(verification_method_id.clone())
       .build()
   }
+
+  /// Creates a [`CoreDocument`] from a did:pkh DID, containing an `EcdsaSecp256k1RecoveryMethod2020` verification
+  /// method with the account of the DID as its `blockchainAccountId`.
+  pub fn expand_did_pkh(did_pkh: DIDPkh) -> Result<Self, Error> {
+    let id: DIDUrl = DIDUrl::parse(format!("{did_pkh}#blockchainAccountId"))
+      .map_err(|err| Error::InvalidKeyMaterial(identity_verification::Error::DIDUrlConstructionError(err)))?;
+    let verification_method: VerificationMethod = MethodBuilder::default()
+      .id(id.clone())
+      .controller(did_pkh.as_ref().clone())
+      .type_(MethodType::ECDSA_SECP256K1_RECOVERY_METHOD_2020)
+      .data(MethodData::new_blockchain_account_id(did_pkh.account_id()))
+      .build()
+      .map_err(Error::InvalidKeyMaterial)?;
+
+    DocumentBuilder::default()
+      .id(did_pkh.into())
+      .verification_method(verification_method)
+      .assertion_method(id.clone())
+      .authentication(id)
+      .build()
+  }
+
+  /// Returns the did:jwk DID encoding the only verification method of this document.
+  ///
+  /// Together with [`CoreDocument::expand_did_jwk`], this converts single-key documents, such as ephemeral identities,
+  /// to a did:jwk and back without any network access. See [`DIDJwk::new`] for how the key is encoded.
+  ///
+  /// # Errors
+  /// Fails if the document does not contain exactly one verification method, or if its key is not an asymmetric
+  /// public JWK.
+  pub fn to_did_jwk(&self) -> Result<DIDJwk> {
+    let methods: Vec<&VerificationMethod> = self.methods(None);
+    let [method] = methods.as_slice() else {
+      return Err(Error::InvalidDocument("expected exactly one verification method", None));
+    };
+    let jwk: &Jwk = method.data().try_public_key_jwk().map_err(Error::InvalidKeyMaterial)?;
+    DIDJwk::new(jwk).map_err(|_| Error::InvalidDocument("did:jwk requires an asymmetric public key", None))
+  }
+
+  /// Creates a [`CoreDocument`] with the given `id` and a verification method for each key of `jwk_set`, e.g. to mint
+  /// an ephemeral identity with separate signing and key agreement keys offline.
+  ///
+  /// Each method is identified by the `kid` of its key, or by the key's index in the set if it has none. Keys without
+  /// an `alg` parameter get the algorithm implied by their curve, see [`Jwk::infer_jws_algorithm`].
+  ///
+  /// The relationships of each method follow from the intended use of its key:
+  /// - encryption keys, i.e. keys with `"use": "enc"`, `deriveKey` or `deriveBits` operations or an `X25519` or `X448`
+  ///   curve, are referenced from `keyAgreement`;
+  /// - all other keys are referenced from `authentication`, `assertionMethod`, `capabilityInvocation` and
+  ///   `capabilityDelegation`, unless their `key_ops` do not allow verifying signatures.
+  ///
+  /// # Errors
+  /// Fails if `jwk_set` is empty, or if a key contains private key material.
+  pub fn from_jwk_set(id: CoreDID, jwk_set: &JwkSet) -> Result<Self> {
+    if jwk_set.is_empty() {
+      return Err(Error::InvalidDocument("empty jwk set", None));
+    }
+
+    let mut builder: DocumentBuilder = DocumentBuilder::default().id(id.clone());
+    for (index, jwk) in jwk_set.iter().enumerate() {
+      let key_agreement: bool = jwk.use_() == Some(JwkUse::Encryption)
+        || jwk.try_ecx_curve().is_ok()
+        || jwk.key_ops().is_some_and(|ops| {
+          ops
+            .iter()
+            .any(|op| matches!(op, JwkOperation::DeriveKey | JwkOperation::DeriveBits))
+        });
+      let signing: bool = !key_agreement && jwk.key_ops().map_or(true, |ops| ops.contains(&JwkOperation::Verify));
+
+      let mut key: Jwk = jwk.clone();
+      if signing && key.alg().is_none() {
+        if let Some(alg) = key.infer_jws_algorithm() {
+          key.set_alg(alg.name());
+        }
+      }
+      let fragment: String = index.to_string();
+      let fragment: Option<&str> = jwk.kid().is_none().then_some(fragment.as_str());
+      let method: VerificationMethod =
+        VerificationMethod::new_from_jwk(id.clone(), key, fragment).map_err(Error::InvalidKeyMaterial)?;
+      let method_id: DIDUrl = method.id().clone();
+
+      builder = builder.verification_method(method);
+      if key_agreement {
+        builder = builder.key_agreement(method_id);
+      } else if signing {
+        builder = builder
+          .authentication(method_id.clone())
+          .assertion_method(method_id.clone())
+          .capability_invocation(method_id.clone())
+          .capability_delegation(method_id);
+      }
+    }
+
+    builder.build()
+  }
 }
 
 #[cfg(test)]
@@ -1007,11 +1327,8 @@ mod tests {
   use identity_core::convert::FromJson;
   use identity_core::convert::ToJson;
   use identity_did::DID;
-  use identity_verification::MethodType;
 
   use crate::service::ServiceBuilder;
-  use identity_verification::MethodBuilder;
-  use identity_verification::MethodData;
 
   use super::*;
 
@@ -1208,6 +1525,48 @@ mod tests {
     assert_eq!(authentication.len(), 2);
   }
 
+  #[test]
+  fn test_methods_for() {
+    let mut document: CoreDocument = document();
+    let controller: CoreDID = controller();
+    // Refer to a method embedded in another verification relationship.
+    document
+      .data
+      .capability_delegation
+      .append(controller.to_url().join("#auth-key").unwrap().into());
+
+    let delegation: Vec<&VerificationMethod> = document.methods_for(MethodScope::capability_delegation());
+    assert_eq!(delegation.len(), 1);
+    assert_eq!(delegation[0].id().to_string(), "did:example:1234#auth-key");
+
+    // References to methods missing from the document are skipped.
+    assert!(document.methods_for(MethodScope::key_agreement()).is_empty());
+    assert_eq!(document.methods_for(MethodScope::VerificationMethod).len(), 3);
+  }
+
+  #[test]
+  fn test_method_controllers() {
+    let mut document: CoreDocument = document();
+    let other: CoreDID = CoreDID::parse("did:example:other").unwrap();
+    assert_eq!(document.controllers().count(), 0);
+    assert!(document.check_method_controllers().is_ok());
+
+    let mut other_method: VerificationMethod = method(document.id(), "#other-key");
+    *other_method.controller_mut() = other.clone();
+    document
+      .insert_method(other_method, MethodScope::VerificationMethod)
+      .unwrap();
+    assert!(matches!(
+      document.check_method_controllers(),
+      Err(Error::InvalidMethodController(id)) if id.fragment() == Some("other-key")
+    ));
+
+    *document.controller_mut() = Some(OneOrSet::try_from(vec![other.clone(), controller()]).unwrap());
+    assert_eq!(document.controllers().collect::<Vec<_>>(), [&other, &controller()]);
+    assert!(document.is_controlled_by(&other));
+    assert!(document.check_method_controllers().is_ok());
+  }
+
   #[test]
   fn test_attach_verification_relationships() {
     let mut document: CoreDocument = document();
@@ -1298,6 +1657,95 @@ mod tests {
       .is_err());
   }
 
+  #[test]
+  fn test_bulk_method_relationships() {
+    let mut document: CoreDocument = document();
+    let key_1: DIDUrl = document.id().to_url().join("#key-1").unwrap();
+    let relationships = [MethodRelationship::AssertionMethod, MethodRelationship::KeyAgreement];
+
+    assert!(document.attach_method_relationships(&key_1, relationships).unwrap());
+    assert!(!document.attach_method_relationships(&key_1, relationships).unwrap());
+    assert_eq!(document.method_relationships(&key_1), relationships);
+
+    assert!(document
+      .detach_method_relationships(&key_1, MethodRelationship::ALL)
+      .unwrap());
+    assert!(document.method_relationships(&key_1).is_empty());
+
+    // Embedded methods cannot be attached to other relationships.
+    let auth_key: DIDUrl = document.id().to_url().join("#auth-key").unwrap();
+    assert!(matches!(
+      document.attach_method_relationships(&auth_key, relationships),
+      Err(Error::InvalidMethodEmbedded)
+    ));
+  }
+
+  #[test]
+  fn test_move_method() {
+    let mut document: CoreDocument = document();
+    let auth_key: DIDUrl = document.id().to_url().join("#auth-key").unwrap();
+    let key_3: DIDUrl = document.id().to_url().join("#key-3").unwrap();
+
+    // An embedded method keeps its relationship as a reference.
+    document
+      .move_method(&auth_key, MethodScope::VerificationMethod)
+      .unwrap();
+    assert!(document
+      .resolve_method(&auth_key, Some(MethodScope::VerificationMethod))
+      .is_some());
+    assert_eq!(
+      document.method_relationships(&auth_key),
+      [MethodRelationship::Authentication]
+    );
+
+    // A referenced method can be embedded in the relationship referencing it.
+    document.move_method(&key_3, MethodScope::authentication()).unwrap();
+    assert!(document
+      .resolve_method(&key_3, Some(MethodScope::VerificationMethod))
+      .is_none());
+    assert!(matches!(
+      document.authentication().query(&key_3),
+      Some(MethodRef::Embed(_))
+    ));
+
+    // A method referenced by other relationships cannot be embedded.
+    document
+      .attach_method_relationship(&auth_key, MethodRelationship::AssertionMethod)
+      .unwrap();
+    let before: CoreDocument = document.clone();
+    assert!(matches!(
+      document.move_method(&auth_key, MethodScope::key_agreement()),
+      Err(Error::InvalidMethodEmbedded)
+    ));
+    assert_eq!(document, before);
+
+    let missing: DIDUrl = document.id().to_url().join("#missing").unwrap();
+    assert!(matches!(
+      document.move_method(&missing, MethodScope::VerificationMethod),
+      Err(Error::MethodNotFound)
+    ));
+  }
+
+  #[test]
+  fn test_methods_with_relationships() {
+    let document: CoreDocument = document();
+    let pairs: Vec<(&str, Vec<MethodRelationship>)> = document
+      .methods_with_relationships()
+      .into_iter()
+      .map(|(method, relationships)| (method.id().fragment().unwrap(), relationships))
+      .collect();
+
+    assert_eq!(
+      pairs,
+      [
+        ("key-1", vec![]),
+        ("key-2", vec![]),
+        ("key-3", vec![MethodRelationship::Authentication]),
+        ("auth-key", vec![MethodRelationship::Authentication]),
+      ]
+    );
+  }
+
   #[test]
   fn test_method_insert_duplication() {
     let mut document: CoreDocument = document();
@@ -1729,4 +2177,108 @@ mod tests {
 
     assert_eq!(CoreDocument::expand_did_jwk(did_jwk).unwrap(), target_doc);
   }
+
+  #[test]
+  fn test_did_pkh_expansion() {
+    let did_pkh: DIDPkh = DIDPkh::parse("did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a").unwrap();
+    let target_doc = CoreDocument::from_json_value(serde_json::json!({
+      "id": "did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a",
+      "verificationMethod": [
+        {
+          "id": "did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a#blockchainAccountId",
+          "type": "EcdsaSecp256k1RecoveryMethod2020",
+          "controller": "did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a",
+          "blockchainAccountId": "eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a"
+        }
+      ],
+      "assertionMethod": ["did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a#blockchainAccountId"],
+      "authentication": ["did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a#blockchainAccountId"]
+    }))
+    .unwrap();
+
+    assert_eq!(CoreDocument::expand_did_pkh(did_pkh).unwrap(), target_doc);
+  }
+
+  #[test]
+  fn test_did_jwk_round_trip() {
+    let jwk = Jwk::from_json_value(serde_json::json!({
+      "kty": "EC", "crv": "P-256",
+      "x": "acbIQiuMs3i8_uszEjJ2tpTtRM4EU3yz91PH6CdH2V0", "y": "_KcyLj9vWMptnmKtm46GqDz8wf74I5LKgrl2GzH3nSE"
+    }))
+    .unwrap();
+    let did_jwk: DIDJwk = DIDJwk::new(&jwk).unwrap();
+    let mut document: CoreDocument = CoreDocument::expand_did_jwk(did_jwk.clone()).unwrap();
+    assert_eq!(document.to_did_jwk().unwrap(), did_jwk);
+
+    let method: VerificationMethod =
+      VerificationMethod::new_from_jwk(document.id().clone(), did_jwk.jwk(), Some("1")).unwrap();
+    document.insert_method(method, MethodScope::VerificationMethod).unwrap();
+    assert!(matches!(document.to_did_jwk(), Err(Error::InvalidDocument(..))));
+  }
+
+  #[test]
+  fn test_from_jwk_set() {
+    let jwk_set = JwkSet::from_json_value(serde_json::json!({
+      "keys": [
+        { "kty": "OKP", "crv": "Ed25519", "kid": "sign", "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo" },
+        { "kty": "OKP", "crv": "X25519", "x": "3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08" },
+      ]
+    }))
+    .unwrap();
+    let id: CoreDID = CoreDID::parse("did:example:ephemeral").unwrap();
+    let document: CoreDocument = CoreDocument::from_jwk_set(id, &jwk_set).unwrap();
+
+    let signing: &VerificationMethod = document.resolve_method("#sign", None).unwrap();
+    assert_eq!(signing.data().try_public_key_jwk().unwrap().alg(), Some("EdDSA"));
+    for scope in [
+      MethodScope::authentication(),
+      MethodScope::assertion_method(),
+      MethodScope::capability_invocation(),
+      MethodScope::capability_delegation(),
+    ] {
+      assert!(document.resolve_method("#sign", Some(scope)).is_some());
+      assert!(document.resolve_method("#1", Some(scope)).is_none());
+    }
+    assert!(document
+      .resolve_method("#1", Some(MethodScope::key_agreement()))
+      .is_some());
+    assert!(document
+      .resolve_method("#sign", Some(MethodScope::key_agreement()))
+      .is_none());
+
+    assert!(CoreDocument::from_jwk_set(CoreDID::parse("did:example:empty").unwrap(), &JwkSet::default()).is_err());
+  }
+
+  // Golden files must never be changed: documents serialized by earlier releases have to remain readable, and
+  // serializing them again must produce the same JSON. A breaking change requires a new golden file.
+  const GOLDEN_V1: &str = include_str!("../../tests/fixtures/golden/core-document-v1.json");
+
+  #[test]
+  fn test_golden_v1() {
+    let document: CoreDocument = CoreDocument::from_json(GOLDEN_V1).unwrap();
+    assert_eq!(document.id().as_str(), "did:example:123");
+    assert!(document
+      .resolve_method(
+        "#key-1",
+        Some(MethodScope::VerificationRelationship(
+          MethodRelationship::Authentication
+        ))
+      )
+      .is_some());
+    assert!(document
+      .resolve_method(
+        "#key-2",
+        Some(MethodScope::VerificationRelationship(
+          MethodRelationship::AssertionMethod
+        ))
+      )
+      .is_some());
+    assert_eq!(document.service().len(), 1);
+    assert_eq!(document.properties().get("custom"), Some(&"property".into()));
+
+    assert_eq!(
+      document.to_json_value().unwrap(),
+      serde_json::Value::from_json(GOLDEN_V1).unwrap()
+    );
+  }
 }
@@ -0,0 +1,316 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::DIDUrl;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+
+use crate::document::CoreDocument;
+use crate::error::Error;
+use crate::service::Service;
+use crate::service::ServiceEndpointPolicy;
+
+/// A change of a [`DocumentUpdate`].
+#[derive(Debug, Clone)]
+enum Operation {
+  AddService(Service),
+  RemoveService(DIDUrl),
+  AddMethod(VerificationMethod, MethodScope),
+  RemoveMethod(DIDUrl),
+  AttachRelationship(DIDUrl, MethodRelationship),
+  DetachRelationship(DIDUrl, MethodRelationship),
+}
+
+/// A set of changes to a [`CoreDocument`] applied atomically with [`DocumentUpdate::commit`].
+///
+/// Created with [`CoreDocument::update`].
+///
+/// ```
+/// # use identity_core::common::Object;
+/// # use identity_core::common::Url;
+/// # use identity_did::DIDUrl;
+/// # use identity_document::document::CoreDocument;
+/// # use identity_document::service::Service;
+/// # use identity_verification::MethodRelationship;
+/// # fn update(document: &mut CoreDocument) -> Result<(), Box<dyn std::error::Error>> {
+/// let method_id: DIDUrl = document.id().to_url().join("#key-1")?;
+/// let service: Service = Service::builder(Object::new())
+///   .id(document.id().to_url().join("#linked-domain")?)
+///   .type_("LinkedDomains")
+///   .service_endpoint(Url::parse("https://example.com")?)
+///   .build()?;
+/// document
+///   .update()
+///   .add_service(service)
+///   .attach_relationship(method_id, MethodRelationship::Authentication)
+///   .commit()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+#[must_use = "a document update does nothing unless committed"]
+pub struct DocumentUpdate<'doc> {
+  document: &'doc mut CoreDocument,
+  operations: Vec<Operation>,
+  endpoint_policy: ServiceEndpointPolicy,
+}
+
+impl<'doc> DocumentUpdate<'doc> {
+  pub(crate) fn new(document: &'doc mut CoreDocument) -> Self {
+    Self {
+      document,
+      operations: Vec::new(),
+      endpoint_policy: ServiceEndpointPolicy::default(),
+    }
+  }
+
+  /// Sets the policy the endpoints of added services must meet. Defaults to [`ServiceEndpointPolicy::default`].
+  pub fn endpoint_policy(mut self, policy: ServiceEndpointPolicy) -> Self {
+    self.endpoint_policy = policy;
+    self
+  }
+
+  /// Adds `service` to the document.
+  ///
+  /// Its id must have a fragment not used by another method or service, and its endpoint must consist of at least one
+  /// URL, each of which must meet the [endpoint policy](Self::endpoint_policy) of the update. By default, every URL
+  /// must be a DID URL or have a host.
+  pub fn add_service(mut self, service: Service) -> Self {
+    self.operations.push(Operation::AddService(service));
+    self
+  }
+
+  /// Removes the service identified by `id`, which must exist.
+  pub fn remove_service(mut self, id: DIDUrl) -> Self {
+    self.operations.push(Operation::RemoveService(id));
+    self
+  }
+
+  /// Adds `method` to the document under `scope`.
+  ///
+  /// Its id must have a fragment not used by another method or service.
+  pub fn add_method(mut self, method: VerificationMethod, scope: MethodScope) -> Self {
+    self.operations.push(Operation::AddMethod(method, scope));
+    self
+  }
+
+  /// Removes the method identified by `id` and all references to it, which must exist.
+  pub fn remove_method(mut self, id: DIDUrl) -> Self {
+    self.operations.push(Operation::RemoveMethod(id));
+    self
+  }
+
+  /// Attaches `relationship` to the method identified by `id`, which must be a method of the document that is not
+  /// embedded in a relationship, e.g. one added by this update.
+  pub fn attach_relationship(mut self, id: DIDUrl, relationship: MethodRelationship) -> Self {
+    self.operations.push(Operation::AttachRelationship(id, relationship));
+    self
+  }
+
+  /// Detaches `relationship` from the method identified by `id`.
+  pub fn detach_relationship(mut self, id: DIDUrl, relationship: MethodRelationship) -> Self {
+    self.operations.push(Operation::DetachRelationship(id, relationship));
+    self
+  }
+
+  /// Applies all changes in order, or none of them if any change is invalid.
+  ///
+  /// # Errors
+  /// Returns a [`DocumentUpdateError`] with the error of every invalid change, in which case the document is left
+  /// unchanged.
+  pub fn commit(self) -> Result<(), DocumentUpdateError> {
+    let mut updated: CoreDocument = self.document.clone();
+    let endpoint_policy: &ServiceEndpointPolicy = &self.endpoint_policy;
+    let errors: Vec<Error> = self
+      .operations
+      .into_iter()
+      .filter_map(|operation| Self::apply(&mut updated, operation, endpoint_policy).err())
+      .collect();
+
+    if errors.is_empty() {
+      *self.document = updated;
+      Ok(())
+    } else {
+      Err(DocumentUpdateError { errors })
+    }
+  }
+
+  fn apply(
+    document: &mut CoreDocument,
+    operation: Operation,
+    endpoint_policy: &ServiceEndpointPolicy,
+  ) -> Result<(), Error> {
+    match operation {
+      Operation::AddService(service) => {
+        check_fragment(service.id()).map_err(|_| Error::InvalidService("empty id fragment"))?;
+        endpoint_policy.check(service.service_endpoint())?;
+        document.insert_service(service)
+      }
+      Operation::RemoveService(id) => document
+        .remove_service(&id)
+        .map(|_| ())
+        .ok_or(Error::InvalidService("service not found")),
+      Operation::AddMethod(method, scope) => {
+        check_fragment(method.id())?;
+        document.insert_method(method, scope)
+      }
+      Operation::RemoveMethod(id) => document.remove_method(&id).map(|_| ()).ok_or(Error::MethodNotFound),
+      Operation::AttachRelationship(id, relationship) => {
+        document.attach_method_relationship(&id, relationship).map(|_| ())
+      }
+      Operation::DetachRelationship(id, relationship) => {
+        document.detach_method_relationship(&id, relationship).map(|_| ())
+      }
+    }
+  }
+}
+
+fn check_fragment(id: &DIDUrl) -> Result<(), Error> {
+  if id.fragment().unwrap_or_default().is_empty() {
+    Err(Error::MissingIdFragment)
+  } else {
+    Ok(())
+  }
+}
+
+/// Error returned by [`DocumentUpdate::commit`], listing every invalid change of the update.
+#[derive(Debug, thiserror::Error)]
+#[error("document update failed: {} invalid change(s)", errors.len())]
+#[non_exhaustive]
+pub struct DocumentUpdateError {
+  /// The errors of the invalid changes, in the order the changes were made.
+  pub errors: Vec<Error>,
+}
+
+impl CoreDocument {
+  /// Starts a [`DocumentUpdate`] of this document, whose changes are validated and applied atomically when committed.
+  pub fn update(&mut self) -> DocumentUpdate<'_> {
+    DocumentUpdate::new(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_did::CoreDID;
+  use identity_verification::MethodData;
+  use identity_verification::MethodType;
+
+  use super::*;
+
+  fn document() -> CoreDocument {
+    CoreDocument::from_json(r#"{ "id": "did:example:1234" }"#).unwrap()
+  }
+
+  fn method(did: &CoreDID, fragment: &str) -> VerificationMethod {
+    VerificationMethod::builder(Object::new())
+      .id(did.to_url().join(fragment).unwrap())
+      .controller(did.clone())
+      .type_(MethodType::ED25519_VERIFICATION_KEY_2018)
+      .data(MethodData::PublicKeyBase58(
+        "3M5RCDjPTWPkKSN3sxUmmMqHbmRPegYP1tjcKyrDbt9J".to_owned(),
+      ))
+      .build()
+      .unwrap()
+  }
+
+  fn service(did: &CoreDID, fragment: &str, endpoint: &str) -> Service {
+    Service::builder(Object::new())
+      .id(did.to_url().join(fragment).unwrap())
+      .type_("LinkedDomains")
+      .service_endpoint(Url::parse(endpoint).unwrap())
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn commit() {
+    let mut document: CoreDocument = document();
+    let did: CoreDID = document.id().clone();
+
+    document
+      .update()
+      .add_method(method(&did, "#key-1"), MethodScope::VerificationMethod)
+      .attach_relationship(did.to_url().join("#key-1").unwrap(), MethodRelationship::Authentication)
+      .add_service(service(&did, "#linked-domain", "https://example.com"))
+      .commit()
+      .unwrap();
+
+    assert!(document
+      .resolve_method(
+        "#key-1",
+        Some(MethodScope::VerificationRelationship(
+          MethodRelationship::Authentication
+        ))
+      )
+      .is_some());
+    assert_eq!(document.service().len(), 1);
+
+    document
+      .update()
+      .remove_service(did.to_url().join("#linked-domain").unwrap())
+      .remove_method(did.to_url().join("#key-1").unwrap())
+      .commit()
+      .unwrap();
+    assert_eq!(document, self::document());
+  }
+
+  #[test]
+  fn commit_is_atomic() {
+    let mut document: CoreDocument = document();
+    let did: CoreDID = document.id().clone();
+    document
+      .update()
+      .add_method(method(&did, "#key-1"), MethodScope::VerificationMethod)
+      .commit()
+      .unwrap();
+    let original: CoreDocument = document.clone();
+
+    let error: DocumentUpdateError = document
+      .update()
+      .add_method(method(&did, "#key-2"), MethodScope::VerificationMethod)
+      // INVALID: the fragment is already in use.
+      .add_service(service(&did, "#key-1", "https://example.com"))
+      // INVALID: no host.
+      .add_service(service(&did, "#service", "urn:example:service"))
+      // INVALID: the method does not exist.
+      .attach_relationship(
+        did.to_url().join("#key-3").unwrap(),
+        MethodRelationship::AssertionMethod,
+      )
+      .commit()
+      .unwrap_err();
+
+    assert_eq!(error.errors.len(), 3);
+    assert!(matches!(error.errors[0], Error::InvalidServiceInsertion));
+    assert!(matches!(error.errors[1], Error::InvalidService(_)));
+    assert!(matches!(error.errors[2], Error::MethodNotFound));
+    assert_eq!(document, original);
+  }
+
+  #[test]
+  fn commit_with_endpoint_policy() {
+    let mut document: CoreDocument = document();
+    let did: CoreDID = document.id().clone();
+
+    document
+      .update()
+      .endpoint_policy(ServiceEndpointPolicy::new().allow_hostless_scheme("urn"))
+      .add_service(service(&did, "#service", "urn:example:service"))
+      .commit()
+      .unwrap();
+    assert_eq!(document.service().len(), 1);
+
+    let error: DocumentUpdateError = document
+      .update()
+      .endpoint_policy(ServiceEndpointPolicy::new().allow_scheme("https"))
+      // INVALID: the scheme is not allowed.
+      .add_service(service(&did, "#websocket", "wss://example.com"))
+      .commit()
+      .unwrap_err();
+    assert!(matches!(error.errors[0], Error::InvalidService(_)));
+  }
+}
@@ -7,6 +7,13 @@
 
 pub use self::builder::DocumentBuilder;
 pub use self::core_document::CoreDocument;
+pub use self::document_update::DocumentUpdate;
+pub use self::document_update::DocumentUpdateError;
+#[cfg(feature = "json-patch")]
+pub use self::patch::Patch;
 
 mod builder;
 mod core_document;
+mod document_update;
+#[cfg(feature = "json-patch")]
+mod patch;
@@ -0,0 +1,163 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+pub use json_patch::Patch;
+use serde_json::Value;
+
+use crate::document::CoreDocument;
+use crate::error::Error;
+use crate::error::Result;
+
+impl CoreDocument {
+  /// Applies an [RFC 6902](https://www.rfc-editor.org/rfc/rfc6902) JSON Patch to the JSON representation of this
+  /// document.
+  ///
+  /// The patch is applied atomically: if any operation fails or the patched JSON is not a valid DID document, an
+  /// error is returned and the document is left unchanged. The `id` of the document must not be changed by the patch.
+  ///
+  /// # Errors
+  /// - [`Error::InvalidPatch`] if an operation of the patch cannot be applied, e.g. because a `test` operation fails.
+  /// - [`Error::InvalidDocument`] if the patched JSON is not a valid DID document or the patch changes its `id`.
+  pub fn apply_patch(&mut self, patch: &Patch) -> Result<()> {
+    let mut json: Value = self
+      .to_json_value()
+      .map_err(|err| Error::InvalidDocument("failed to serialize document", Some(err)))?;
+    json_patch::patch(&mut json, patch).map_err(Error::InvalidPatch)?;
+
+    let patched: CoreDocument = CoreDocument::from_json_value(json)
+      .map_err(|err| Error::InvalidDocument("the patched document is not a valid DID document", Some(err)))?;
+    if patched.id() != self.id() {
+      return Err(Error::InvalidDocument("a patch must not change the document id", None));
+    }
+
+    *self = patched;
+    Ok(())
+  }
+
+  /// Returns the [RFC 6902](https://www.rfc-editor.org/rfc/rfc6902) JSON Patch transforming this document into
+  /// `other`.
+  ///
+  /// Applying the patch with [`CoreDocument::apply_patch`] to this document yields `other`, provided both share the
+  /// same `id`.
+  pub fn diff(&self, other: &CoreDocument) -> Result<Patch> {
+    let current: Value = self
+      .to_json_value()
+      .map_err(|err| Error::InvalidDocument("failed to serialize document", Some(err)))?;
+    let target: Value = other
+      .to_json_value()
+      .map_err(|err| Error::InvalidDocument("failed to serialize document", Some(err)))?;
+    Ok(json_patch::diff(&current, &target))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_did::DIDUrl;
+  use identity_verification::MethodScope;
+
+  use super::*;
+  use crate::service::Service;
+
+  fn document() -> CoreDocument {
+    CoreDocument::from_json(
+      r#"{
+        "id": "did:example:1234",
+        "verificationMethod": [{
+          "id": "did:example:1234#key-1",
+          "controller": "did:example:1234",
+          "type": "JsonWebKey2020",
+          "publicKeyJwk": { "kty": "OKP", "crv": "Ed25519", "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo" }
+        }]
+      }"#,
+    )
+    .unwrap()
+  }
+
+  fn patch(json: &str) -> Patch {
+    serde_json::from_str(json).unwrap()
+  }
+
+  #[test]
+  fn apply_patch() {
+    let mut document: CoreDocument = document();
+    document
+      .apply_patch(&patch(
+        r#"[
+          { "op": "add", "path": "/service", "value": [{
+            "id": "did:example:1234#linked-domain",
+            "type": "LinkedDomains",
+            "serviceEndpoint": "https://example.com"
+          }] },
+          { "op": "add", "path": "/authentication", "value": ["did:example:1234#key-1"] }
+        ]"#,
+      ))
+      .unwrap();
+
+    assert_eq!(document.service().len(), 1);
+    assert!(document
+      .resolve_method("#key-1", Some(MethodScope::authentication()))
+      .is_some());
+  }
+
+  #[test]
+  fn apply_patch_is_atomic() {
+    let original: CoreDocument = document();
+    let mut document: CoreDocument = original.clone();
+
+    // INVALID: the `test` operation fails.
+    let error = document
+      .apply_patch(&patch(
+        r#"[
+          { "op": "remove", "path": "/verificationMethod" },
+          { "op": "test", "path": "/id", "value": "did:example:other" }
+        ]"#,
+      ))
+      .unwrap_err();
+    assert!(matches!(error, Error::InvalidPatch(_)));
+    assert_eq!(document, original);
+
+    // INVALID: the patched document has two methods with the same id.
+    let error = document
+      .apply_patch(&patch(
+        r#"[{ "op": "copy", "from": "/verificationMethod/0", "path": "/verificationMethod/-" }]"#,
+      ))
+      .unwrap_err();
+    assert!(matches!(error, Error::InvalidDocument(_, Some(_))));
+
+    // INVALID: the id must not change.
+    let error = document
+      .apply_patch(&patch(
+        r#"[{ "op": "replace", "path": "/id", "value": "did:example:other" }]"#,
+      ))
+      .unwrap_err();
+    assert!(matches!(error, Error::InvalidDocument(_, None)));
+    assert_eq!(document, original);
+  }
+
+  #[test]
+  fn diff_round_trip() {
+    let original: CoreDocument = document();
+    let mut updated: CoreDocument = original.clone();
+    updated
+      .insert_service(
+        Service::builder(Object::new())
+          .id(DIDUrl::parse("did:example:1234#linked-domain").unwrap())
+          .type_("LinkedDomains")
+          .service_endpoint(Url::parse("https://example.com").unwrap())
+          .build()
+          .unwrap(),
+      )
+      .unwrap();
+    updated.remove_method(&original.methods(None)[0].id().clone()).unwrap();
+
+    let patch: Patch = original.diff(&updated).unwrap();
+    let mut patched: CoreDocument = original.clone();
+    patched.apply_patch(&patch).unwrap();
+    assert_eq!(patched, updated);
+    assert!(original.diff(&original).unwrap().0.is_empty());
+  }
+}
@@ -29,6 +29,9 @@ pub enum Error {
   /// Caused by attempting to attach or detach a relationship on an embedded method.
   #[error("unable to modify relationships on embedded methods, use insert or remove instead")]
   InvalidMethodEmbedded,
+  /// Caused by a verification method that is not controlled by the document's id or one of its controllers.
+  #[error("verification method `{0}` is not controlled by the document or its controllers")]
+  InvalidMethodController(identity_did::DIDUrl),
   /// Caused by attempting to insert a service whose id overlaps with a verification method or an already existing
   /// service.
   #[error("unable to insert service: the id is already in use")]
@@ -39,4 +42,8 @@ pub enum Error {
   /// Caused by a failure to verify a JSON Web Signature.
   #[error("jws verification failed")]
   JwsVerificationError(#[source] identity_verification::jose::error::Error),
+  /// Caused by a JSON Patch that cannot be applied to a document.
+  #[cfg(feature = "json-patch")]
+  #[error("unable to apply JSON patch")]
+  InvalidPatch(#[source] json_patch::PatchError),
 }
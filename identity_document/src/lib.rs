@@ -21,6 +21,7 @@ extern crate serde;
 
 pub mod document;
 pub mod error;
+pub mod profile;
 pub mod service;
 pub mod utils;
 pub mod verifiable;
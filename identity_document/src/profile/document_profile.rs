@@ -0,0 +1,176 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use crate::document::CoreDocument;
+use crate::profile::DocumentRule;
+use crate::profile::ForbiddenKeyCurve;
+use crate::profile::MinControllers;
+use crate::profile::RequiredService;
+use crate::profile::RuleViolation;
+
+/// A named set of [`DocumentRule`]s a trust framework imposes on DID documents in addition to the DID specification.
+///
+/// Profiles can be checked before publishing a document as well as after resolving it, and composed with
+/// [`DocumentProfile::extend`].
+///
+/// ```
+/// # use identity_document::profile::DocumentProfile;
+/// # use identity_document::profile::MinControllers;
+/// let profile = DocumentProfile::new("my-framework")
+///   .with_rule(MinControllers(2))
+///   .extend(DocumentProfile::linked_domains())
+///   .extend(DocumentProfile::no_secp256k1());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DocumentProfile {
+  name: String,
+  rules: Vec<Arc<dyn DocumentRule>>,
+}
+
+impl DocumentProfile {
+  /// Creates a profile without rules.
+  pub fn new(name: impl Into<String>) -> Self {
+    Self {
+      name: name.into(),
+      rules: Vec::new(),
+    }
+  }
+
+  /// Requires documents to declare at least two controllers.
+  pub fn multi_controller() -> Self {
+    Self::new("multi-controller").with_rule(MinControllers(2))
+  }
+
+  /// Requires documents to contain a `LinkedDomains` service, see
+  /// [DID Configuration](https://identity.foundation/.well-known/resources/did-configuration/).
+  pub fn linked_domains() -> Self {
+    Self::new("linked-domains").with_rule(RequiredService("LinkedDomains".to_owned()))
+  }
+
+  /// Forbids verification methods with secp256k1 keys.
+  pub fn no_secp256k1() -> Self {
+    Self::new("no-secp256k1").with_rule(ForbiddenKeyCurve("secp256k1".to_owned()))
+  }
+
+  /// Returns the name of the profile.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the rules of the profile.
+  pub fn rules(&self) -> &[Arc<dyn DocumentRule>] {
+    &self.rules
+  }
+
+  /// Adds `rule` to the profile.
+  pub fn with_rule(mut self, rule: impl DocumentRule + 'static) -> Self {
+    self.rules.push(Arc::new(rule));
+    self
+  }
+
+  /// Adds the rules of `other` to the profile.
+  pub fn extend(mut self, other: DocumentProfile) -> Self {
+    self.rules.extend(other.rules);
+    self
+  }
+
+  /// Checks `document` against all rules of the profile, returning every violated rule.
+  pub fn violations(&self, document: &CoreDocument) -> Vec<RuleViolation> {
+    self
+      .rules
+      .iter()
+      .filter_map(|rule| {
+        rule.check(document).err().map(|message| RuleViolation {
+          rule: rule.name().to_owned(),
+          message,
+        })
+      })
+      .collect()
+  }
+
+  /// Validates `document` against all rules of the profile.
+  ///
+  /// # Errors
+  /// Returns a [`DocumentProfileError`] listing every violated rule.
+  pub fn validate<DOC: AsRef<CoreDocument>>(&self, document: &DOC) -> Result<(), DocumentProfileError> {
+    let violations: Vec<RuleViolation> = self.violations(document.as_ref());
+    if violations.is_empty() {
+      Ok(())
+    } else {
+      Err(DocumentProfileError {
+        profile: self.name.clone(),
+        violations,
+      })
+    }
+  }
+}
+
+/// Error returned when a document does not satisfy the rules of a [`DocumentProfile`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("document violates {} rule(s) of the profile `{profile}`", violations.len())]
+#[non_exhaustive]
+pub struct DocumentProfileError {
+  /// The name of the profile.
+  pub profile: String,
+  /// The violated rules.
+  pub violations: Vec<RuleViolation>,
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_verification::jose::jwk::Jwk;
+  use identity_verification::MethodData;
+
+  use super::*;
+
+  const DOCUMENT: &str = r#"{
+    "id": "did:example:1234",
+    "controller": ["did:example:a", "did:example:b"],
+    "verificationMethod": [{
+      "id": "did:example:1234#key-1",
+      "controller": "did:example:1234",
+      "type": "JsonWebKey2020",
+      "publicKeyJwk": { "kty": "OKP", "crv": "Ed25519", "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo" }
+    }],
+    "service": [{
+      "id": "did:example:1234#linked-domain",
+      "type": "LinkedDomains",
+      "serviceEndpoint": "https://example.com"
+    }]
+  }"#;
+
+  fn profile() -> DocumentProfile {
+    DocumentProfile::multi_controller()
+      .extend(DocumentProfile::linked_domains())
+      .extend(DocumentProfile::no_secp256k1())
+  }
+
+  #[test]
+  fn validate() {
+    let document: CoreDocument = CoreDocument::from_json(DOCUMENT).unwrap();
+    assert_eq!(profile().rules().len(), 3);
+    assert!(profile().validate(&document).is_ok());
+  }
+
+  #[test]
+  fn validate_reports_all_violations() {
+    let mut document: CoreDocument = CoreDocument::from_json(DOCUMENT).unwrap();
+    *document.controller_mut() = None;
+    document.service_mut_unchecked().clear();
+    *document.resolve_method_mut("#key-1", None).unwrap().data_mut() = MethodData::PublicKeyJwk(
+      Jwk::from_json(r#"{ "kty": "EC", "crv": "secp256k1", "x": "dGVzdA", "y": "dGVzdA" }"#).unwrap(),
+    );
+
+    let error: DocumentProfileError = profile().validate(&document).unwrap_err();
+    assert_eq!(error.profile, "multi-controller");
+    let rules: Vec<&str> = error
+      .violations
+      .iter()
+      .map(|violation| violation.rule.as_str())
+      .collect();
+    assert_eq!(rules, ["MinControllers", "RequiredService", "ForbiddenKeyCurve"]);
+  }
+}
@@ -0,0 +1,107 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::VerificationMethod;
+
+use crate::document::CoreDocument;
+
+/// A rule a [`CoreDocument`] must satisfy, composed into a [`DocumentProfile`](crate::profile::DocumentProfile).
+pub trait DocumentRule: Debug + Send + Sync {
+  /// Returns a short name identifying the rule in a [`RuleViolation`].
+  fn name(&self) -> &str;
+
+  /// Checks whether `document` satisfies the rule, returning the reason of the violation otherwise.
+  fn check(&self, document: &CoreDocument) -> Result<(), String>;
+}
+
+/// A [`DocumentRule`] not satisfied by a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RuleViolation {
+  /// The name of the violated rule.
+  pub rule: String,
+  /// The reason of the violation.
+  pub message: String,
+}
+
+impl core::fmt::Display for RuleViolation {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{}: {}", self.rule, self.message)
+  }
+}
+
+/// Requires a document to declare at least the given number of controllers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinControllers(pub usize);
+
+impl DocumentRule for MinControllers {
+  fn name(&self) -> &str {
+    "MinControllers"
+  }
+
+  fn check(&self, document: &CoreDocument) -> Result<(), String> {
+    let controllers: usize = document.controllers().count();
+    if controllers >= self.0 {
+      Ok(())
+    } else {
+      Err(format!("expected at least {} controllers, found {controllers}", self.0))
+    }
+  }
+}
+
+/// Requires a document to contain a service of the given type, e.g. `LinkedDomains`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiredService(pub String);
+
+impl DocumentRule for RequiredService {
+  fn name(&self) -> &str {
+    "RequiredService"
+  }
+
+  fn check(&self, document: &CoreDocument) -> Result<(), String> {
+    if document
+      .service()
+      .iter()
+      .any(|service| service.type_().contains(&self.0))
+    {
+      Ok(())
+    } else {
+      Err(format!("expected a service of type `{}`", self.0))
+    }
+  }
+}
+
+/// Forbids verification methods with public keys on the given JWK curve, e.g. `secp256k1`.
+///
+/// Only methods whose key material is a `publicKeyJwk` are checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForbiddenKeyCurve(pub String);
+
+impl DocumentRule for ForbiddenKeyCurve {
+  fn name(&self) -> &str {
+    "ForbiddenKeyCurve"
+  }
+
+  fn check(&self, document: &CoreDocument) -> Result<(), String> {
+    let curve = |method: &VerificationMethod| -> Option<String> {
+      let jwk: &Jwk = method.data().public_key_jwk()?;
+      jwk
+        .try_ec_params()
+        .map(|params| params.crv.clone())
+        .or_else(|_| jwk.try_okp_params().map(|params| params.crv.clone()))
+        .ok()
+    };
+
+    match document
+      .methods(None)
+      .into_iter()
+      .find(|method| curve(method).as_deref() == Some(self.0.as_str()))
+    {
+      Some(method) => Err(format!("method `{}` uses a key on the curve `{}`", method.id(), self.0)),
+      None => Ok(()),
+    }
+  }
+}
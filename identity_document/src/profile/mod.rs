@@ -0,0 +1,15 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validation of DID documents against the rules of a trust framework.
+
+pub use self::document_profile::DocumentProfile;
+pub use self::document_profile::DocumentProfileError;
+pub use self::document_rule::DocumentRule;
+pub use self::document_rule::ForbiddenKeyCurve;
+pub use self::document_rule::MinControllers;
+pub use self::document_rule::RequiredService;
+pub use self::document_rule::RuleViolation;
+
+mod document_profile;
+mod document_rule;
@@ -0,0 +1,177 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_did::DIDUrl;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::service::Service;
+use crate::service::ServiceEndpoint;
+
+const ACCEPT: &str = "accept";
+const ROUTING_KEYS: &str = "routingKeys";
+
+/// The endpoint of a `DIDCommMessaging` service: the URI messages are sent to, the media types the recipient accepts
+/// and the keys of the mediators messages are routed through.
+///
+/// The URI is the `serviceEndpoint` of the service, while the media types and routing keys are given by its `accept`
+/// and `routingKeys` properties.
+///
+/// [Specification](https://identity.foundation/didcomm-messaging/spec/v2.0/#did-document-service-endpoint)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DIDCommEndpoint {
+  uri: Url,
+  accept: Vec<String>,
+  routing_keys: Vec<DIDUrl>,
+}
+
+impl DIDCommEndpoint {
+  /// The type of services with a [`DIDCommEndpoint`].
+  pub const SERVICE_TYPE: &'static str = "DIDCommMessaging";
+
+  /// Creates a new [`DIDCommEndpoint`] receiving messages at `uri`.
+  pub fn new(uri: Url) -> Self {
+    Self {
+      uri,
+      accept: Vec::new(),
+      routing_keys: Vec::new(),
+    }
+  }
+
+  /// Adds a media type accepted by the recipient, e.g. `didcomm/v2`.
+  pub fn accept(mut self, media_type: impl Into<String>) -> Self {
+    self.accept.push(media_type.into());
+    self
+  }
+
+  /// Adds the key of a mediator messages are routed through.
+  pub fn routing_key(mut self, key: DIDUrl) -> Self {
+    self.routing_keys.push(key);
+    self
+  }
+
+  /// Returns the URI messages are sent to.
+  pub fn uri(&self) -> &Url {
+    &self.uri
+  }
+
+  /// Returns the media types accepted by the recipient.
+  pub fn accepted_media_types(&self) -> &[String] {
+    &self.accept
+  }
+
+  /// Returns the keys of the mediators messages are routed through, in routing order.
+  pub fn routing_keys(&self) -> &[DIDUrl] {
+    &self.routing_keys
+  }
+
+  /// Creates a `DIDCommMessaging` [`Service`] with the given `id` for this endpoint.
+  ///
+  /// # Errors
+  /// [`Error::InvalidService`] if `id` has no fragment.
+  pub fn into_service(self, id: DIDUrl) -> Result<Service> {
+    let mut properties: Object = Object::new();
+    if !self.accept.is_empty() {
+      properties.insert(
+        ACCEPT.to_owned(),
+        Value::Array(self.accept.into_iter().map(Value::String).collect()),
+      );
+    }
+    if !self.routing_keys.is_empty() {
+      properties.insert(
+        ROUTING_KEYS.to_owned(),
+        Value::Array(
+          self
+            .routing_keys
+            .into_iter()
+            .map(|key| Value::String(key.to_string()))
+            .collect(),
+        ),
+      );
+    }
+
+    Service::builder(properties)
+      .id(id)
+      .type_(Self::SERVICE_TYPE)
+      .service_endpoint(self.uri)
+      .build()
+  }
+}
+
+impl TryFrom<&Service> for DIDCommEndpoint {
+  type Error = Error;
+
+  fn try_from(service: &Service) -> Result<Self> {
+    if !service.type_().iter().any(|type_| type_ == Self::SERVICE_TYPE) {
+      return Err(Error::InvalidService("not a DIDCommMessaging service"));
+    }
+    let ServiceEndpoint::One(uri) = service.service_endpoint() else {
+      return Err(Error::InvalidService("DIDCommMessaging endpoint must be a URI"));
+    };
+
+    let accept: Vec<String> = match service.properties().get(ACCEPT) {
+      Some(value) => Vec::from_json_value(value.clone())
+        .map_err(|_| Error::InvalidService("DIDCommMessaging accept must be an array of strings"))?,
+      None => Vec::new(),
+    };
+    let routing_keys: Vec<DIDUrl> = match service.properties().get(ROUTING_KEYS) {
+      Some(value) => Vec::from_json_value(value.clone())
+        .map_err(|_| Error::InvalidService("DIDCommMessaging routingKeys must be an array of DID URLs"))?,
+      None => Vec::new(),
+    };
+
+    Ok(Self {
+      uri: uri.clone(),
+      accept,
+      routing_keys,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_didcomm_endpoint() {
+    let service: Service = Service::from_json(
+      r#"{
+        "id": "did:example:123#didcomm-1",
+        "type": "DIDCommMessaging",
+        "serviceEndpoint": "https://example.com/path",
+        "accept": ["didcomm/v2", "didcomm/aip2;env=rfc587"],
+        "routingKeys": ["did:example:somemediator#somekey"]
+      }"#,
+    )
+    .unwrap();
+
+    let endpoint: DIDCommEndpoint = DIDCommEndpoint::try_from(&service).unwrap();
+    assert_eq!(endpoint.uri().as_str(), "https://example.com/path");
+    assert_eq!(
+      endpoint.accepted_media_types(),
+      ["didcomm/v2", "didcomm/aip2;env=rfc587"]
+    );
+    assert_eq!(
+      endpoint.routing_keys(),
+      [DIDUrl::parse("did:example:somemediator#somekey").unwrap()]
+    );
+    assert_eq!(endpoint.into_service(service.id().clone()).unwrap(), service);
+
+    // INVALID: routing keys must be DID URLs.
+    let mut invalid: Service = service.clone();
+    invalid.properties_mut().insert(
+      ROUTING_KEYS.to_owned(),
+      Value::Array(vec![Value::from("not a did url")]),
+    );
+    assert!(DIDCommEndpoint::try_from(&invalid).is_err());
+
+    // INVALID: wrong service type.
+    let mut invalid: Service = service;
+    *invalid.type_mut() = "LinkedDomains".to_owned().into();
+    assert!(DIDCommEndpoint::try_from(&invalid).is_err());
+  }
+}
@@ -0,0 +1,118 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use indexmap::map::IndexMap;
+
+use identity_core::common::OrderedSet;
+use identity_core::common::Url;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::service::Service;
+use crate::service::ServiceEndpoint;
+
+const ORIGINS: &str = "origins";
+
+/// The `serviceEndpoint` of a `LinkedDomains` service, listing the origins linked to a DID.
+///
+/// The endpoint is either a single origin or a map with a single `origins` entry.
+///
+/// [Specification](https://identity.foundation/.well-known/resources/did-configuration/#linked-domain-service-endpoint)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkedDomainsEndpoint {
+  origins: OrderedSet<Url>,
+}
+
+impl LinkedDomainsEndpoint {
+  /// The type of services with a [`LinkedDomainsEndpoint`].
+  pub const SERVICE_TYPE: &'static str = "LinkedDomains";
+
+  /// Creates a new [`LinkedDomainsEndpoint`] of the given origins.
+  ///
+  /// # Errors
+  /// [`Error::InvalidService`] if `origins` is empty.
+  pub fn new(origins: OrderedSet<Url>) -> Result<Self> {
+    if origins.is_empty() {
+      return Err(Error::InvalidService("empty LinkedDomains origins"));
+    }
+    Ok(Self { origins })
+  }
+
+  /// Returns the linked origins.
+  pub fn origins(&self) -> &[Url] {
+    self.origins.as_slice()
+  }
+
+  /// Consumes the endpoint and returns the linked origins.
+  pub fn into_origins(self) -> OrderedSet<Url> {
+    self.origins
+  }
+}
+
+impl TryFrom<&ServiceEndpoint> for LinkedDomainsEndpoint {
+  type Error = Error;
+
+  fn try_from(endpoint: &ServiceEndpoint) -> Result<Self> {
+    match endpoint {
+      ServiceEndpoint::One(origin) => Self::new(OrderedSet::from_iter([origin.clone()])),
+      ServiceEndpoint::Map(map) if map.len() == 1 => map
+        .get(ORIGINS)
+        .cloned()
+        .ok_or(Error::InvalidService("LinkedDomains endpoint map without origins"))
+        .and_then(Self::new),
+      ServiceEndpoint::Map(_) | ServiceEndpoint::Set(_) => Err(Error::InvalidService(
+        "LinkedDomains endpoint must be a URL or a map of origins",
+      )),
+    }
+  }
+}
+
+impl TryFrom<&Service> for LinkedDomainsEndpoint {
+  type Error = Error;
+
+  fn try_from(service: &Service) -> Result<Self> {
+    if !service.type_().iter().any(|type_| type_ == Self::SERVICE_TYPE) {
+      return Err(Error::InvalidService("not a LinkedDomains service"));
+    }
+    Self::try_from(service.service_endpoint())
+  }
+}
+
+impl From<LinkedDomainsEndpoint> for ServiceEndpoint {
+  fn from(endpoint: LinkedDomainsEndpoint) -> Self {
+    if endpoint.origins.len() == 1 {
+      ServiceEndpoint::One(endpoint.origins.into_vec().remove(0))
+    } else {
+      ServiceEndpoint::Map(IndexMap::from_iter([(ORIGINS.to_owned(), endpoint.origins)]))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+
+  use super::*;
+
+  #[test]
+  fn test_linked_domains_endpoint() {
+    let one = ServiceEndpoint::from_json(r#""https://foo.example.com""#).unwrap();
+    let endpoint = LinkedDomainsEndpoint::try_from(&one).unwrap();
+    assert_eq!(endpoint.origins(), [Url::parse("https://foo.example.com").unwrap()]);
+    assert_eq!(ServiceEndpoint::from(endpoint), one);
+
+    let map =
+      ServiceEndpoint::from_json(r#"{ "origins": ["https://foo.example.com", "https://bar.example.com"] }"#).unwrap();
+    let endpoint = LinkedDomainsEndpoint::try_from(&map).unwrap();
+    assert_eq!(endpoint.origins().len(), 2);
+    assert_eq!(ServiceEndpoint::from(endpoint), map);
+
+    // INVALID: sets and maps without origins.
+    let set = ServiceEndpoint::from_json(r#"["https://foo.example.com"]"#).unwrap();
+    assert!(LinkedDomainsEndpoint::try_from(&set).is_err());
+    let map = ServiceEndpoint::from_json(r#"{ "domains": ["https://foo.example.com"] }"#).unwrap();
+    assert!(LinkedDomainsEndpoint::try_from(&map).is_err());
+    let map = ServiceEndpoint::from_json(r#"{ "origins": [] }"#).unwrap();
+    assert!(LinkedDomainsEndpoint::try_from(&map).is_err());
+  }
+}
@@ -5,9 +5,17 @@
 #![allow(clippy::module_inception)]
 
 mod builder;
+mod didcomm_endpoint;
+mod linked_domains_endpoint;
 mod service;
 mod service_endpoint;
+mod service_endpoint_policy;
+mod urn_endpoint;
 
 pub use self::builder::ServiceBuilder;
+pub use self::didcomm_endpoint::DIDCommEndpoint;
+pub use self::linked_domains_endpoint::LinkedDomainsEndpoint;
 pub use self::service::Service;
 pub use self::service_endpoint::ServiceEndpoint;
+pub use self::service_endpoint_policy::ServiceEndpointPolicy;
+pub use self::urn_endpoint::UrnEndpoint;
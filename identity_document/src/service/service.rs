@@ -24,6 +24,7 @@ use identity_did::DIDUrl;
 ///
 /// [Specification](https://www.w3.org/TR/did-core/#services)
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Service {
   #[serde(deserialize_with = "deserialize_id_with_fragment")]
   pub(crate) id: DIDUrl,
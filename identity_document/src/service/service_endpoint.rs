@@ -11,15 +11,44 @@ use identity_core::common::OrderedSet;
 use identity_core::common::Url;
 use identity_core::convert::FmtJson;
 
+use crate::service::UrnEndpoint;
+
 /// A single URL, set, or map of endpoints specified in a [`Service`](crate::service::Service).
 ///
 /// [Specification](https://www.w3.org/TR/did-core/#dfn-serviceendpoint)
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum ServiceEndpoint {
   One(Url),
   Set(OrderedSet<Url>),
-  Map(IndexMap<String, OrderedSet<Url>>),
+  Map(
+    #[cfg_attr(
+      feature = "schemars",
+      schemars(with = "std::collections::BTreeMap<String, OrderedSet<Url>>")
+    )]
+    IndexMap<String, OrderedSet<Url>>,
+  ),
+}
+
+impl ServiceEndpoint {
+  /// Returns all URLs of the endpoint.
+  pub fn urls(&self) -> Vec<&Url> {
+    match self {
+      ServiceEndpoint::One(url) => vec![url],
+      ServiceEndpoint::Set(urls) => urls.iter().collect(),
+      ServiceEndpoint::Map(map) => map.values().flat_map(|urls| urls.iter()).collect(),
+    }
+  }
+
+  /// Returns all URLs of the endpoint that are well-formed URNs.
+  pub fn urns(&self) -> Vec<UrnEndpoint> {
+    self
+      .urls()
+      .into_iter()
+      .filter_map(|url| UrnEndpoint::new(url.clone()).ok())
+      .collect()
+  }
 }
 
 impl From<Url> for ServiceEndpoint {
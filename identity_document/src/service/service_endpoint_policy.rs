@@ -0,0 +1,136 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+
+use identity_core::common::Url;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::service::ServiceEndpoint;
+
+/// Criteria the URLs of a [`ServiceEndpoint`] must meet, e.g. when added through a
+/// [`DocumentUpdate`](crate::document::DocumentUpdate).
+///
+/// The default policy is strict: URLs of any scheme are allowed, but they must have a host unless they are DID URLs.
+/// Applications using endpoints of custom or intranet schemes, e.g. `urn:` or `ldap:///`, can allow them explicitly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServiceEndpointPolicy {
+  allowed_schemes: Option<BTreeSet<String>>,
+  hostless_schemes: BTreeSet<String>,
+}
+
+impl ServiceEndpointPolicy {
+  /// Creates the default, strict policy.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Restricts the URLs to the allowed schemes, to which `scheme` is added.
+  ///
+  /// URLs of any scheme are allowed if no scheme is explicitly allowed.
+  pub fn allow_scheme(mut self, scheme: impl Into<String>) -> Self {
+    self
+      .allowed_schemes
+      .get_or_insert_with(BTreeSet::new)
+      .insert(scheme.into().to_ascii_lowercase());
+    self
+  }
+
+  /// Allows URLs of `scheme` without a host, e.g. `urn:example:service`.
+  ///
+  /// If the allowed schemes are restricted, `scheme` is added to them.
+  pub fn allow_hostless_scheme(mut self, scheme: impl Into<String>) -> Self {
+    let scheme: String = scheme.into().to_ascii_lowercase();
+    if let Some(allowed_schemes) = self.allowed_schemes.as_mut() {
+      allowed_schemes.insert(scheme.clone());
+    }
+    self.hostless_schemes.insert(scheme);
+    self
+  }
+
+  /// Checks that `url` meets the policy.
+  ///
+  /// # Errors
+  /// [`Error::InvalidService`] if the scheme of `url` is not allowed, or if it has no host although required.
+  pub fn check_url(&self, url: &Url) -> Result<()> {
+    let scheme: &str = url.scheme();
+    if let Some(allowed_schemes) = &self.allowed_schemes {
+      if !allowed_schemes.contains(scheme) {
+        return Err(Error::InvalidService("service endpoint URL scheme not allowed"));
+      }
+    }
+    if !self.hostless_schemes.contains(scheme) && url.host_str().unwrap_or_default().is_empty() {
+      return Err(Error::InvalidService("service endpoint URL without host"));
+    }
+    Ok(())
+  }
+
+  /// Checks that `endpoint` consists of at least one URL and that all its URLs meet the policy.
+  ///
+  /// # Errors
+  /// [`Error::InvalidService`] if `endpoint` is empty or any of its URLs does not meet the policy.
+  pub fn check(&self, endpoint: &ServiceEndpoint) -> Result<()> {
+    let urls: Vec<&Url> = endpoint.urls();
+    if urls.is_empty() {
+      return Err(Error::InvalidService("empty service endpoint"));
+    }
+    urls.into_iter().try_for_each(|url| self.check_url(url))
+  }
+}
+
+impl Default for ServiceEndpointPolicy {
+  fn default() -> Self {
+    Self {
+      allowed_schemes: None,
+      hostless_schemes: BTreeSet::from(["did".to_owned()]),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn url(input: &str) -> Url {
+    Url::parse(input).unwrap()
+  }
+
+  #[test]
+  fn test_default_policy_is_strict() {
+    let policy = ServiceEndpointPolicy::default();
+    assert!(policy.check_url(&url("https://example.com")).is_ok());
+    assert!(policy
+      .check_url(&url("ldap://ldap.corp.example:389/dc=example"))
+      .is_ok());
+    assert!(policy.check_url(&url("did:example:123#service")).is_ok());
+    assert!(policy.check_url(&url("urn:example:service")).is_err());
+    assert!(policy.check_url(&url("ldap:///dc=example")).is_err());
+    assert!(policy.check(&ServiceEndpoint::Set(Default::default())).is_err());
+  }
+
+  #[test]
+  fn test_custom_policy() {
+    let policy = ServiceEndpointPolicy::new()
+      .allow_hostless_scheme("urn")
+      .allow_hostless_scheme("LDAP");
+    assert!(policy.check_url(&url("urn:example:service")).is_ok());
+    assert!(policy.check_url(&url("ldap:///dc=example")).is_ok());
+    assert!(policy.check_url(&url("mailto:admin@example.com")).is_err());
+
+    let policy = ServiceEndpointPolicy::new()
+      .allow_scheme("https")
+      .allow_hostless_scheme("urn");
+    assert!(policy.check_url(&url("https://example.com")).is_ok());
+    assert!(policy.check_url(&url("urn:example:service")).is_ok());
+    assert!(policy.check_url(&url("wss://example.com")).is_err());
+    assert!(policy.check_url(&url("did:example:123#service")).is_err());
+
+    let endpoint = ServiceEndpoint::Set(
+      [url("https://example.com"), url("wss://example.com")]
+        .into_iter()
+        .collect(),
+    );
+    assert!(policy.check(&endpoint).is_err());
+  }
+}
@@ -0,0 +1,115 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::service::Service;
+use crate::service::ServiceEndpoint;
+
+const URN_SCHEME: &str = "urn";
+
+/// A `serviceEndpoint` identified by a URN, e.g. `urn:example:payments`, as commonly used for endpoints of
+/// intranet services that are not reachable by a URL with a host.
+///
+/// [Specification](https://www.rfc-editor.org/rfc/rfc8141)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UrnEndpoint {
+  urn: Url,
+}
+
+impl UrnEndpoint {
+  /// Creates a new [`UrnEndpoint`] from `urn`.
+  ///
+  /// # Errors
+  /// [`Error::InvalidService`] if `urn` is not of the form `urn:<NID>:<NSS>`.
+  pub fn new(urn: Url) -> Result<Self> {
+    if urn.scheme() != URN_SCHEME {
+      return Err(Error::InvalidService("not a URN"));
+    }
+    match urn.path().split_once(':') {
+      Some((nid, nss)) if !nid.is_empty() && !nss.is_empty() => Ok(Self { urn }),
+      _ => Err(Error::InvalidService(
+        "URN without namespace identifier or namespace specific string",
+      )),
+    }
+  }
+
+  /// Returns the namespace identifier of the URN, e.g. `example` for `urn:example:payments`.
+  pub fn namespace_id(&self) -> &str {
+    self.split().0
+  }
+
+  /// Returns the namespace specific string of the URN, e.g. `payments` for `urn:example:payments`.
+  pub fn namespace_specific_string(&self) -> &str {
+    self.split().1
+  }
+
+  /// Returns the URN.
+  pub fn urn(&self) -> &Url {
+    &self.urn
+  }
+
+  /// Consumes the endpoint and returns the URN.
+  pub fn into_urn(self) -> Url {
+    self.urn
+  }
+
+  fn split(&self) -> (&str, &str) {
+    self.urn.path().split_once(':').expect("validated by UrnEndpoint::new")
+  }
+}
+
+impl TryFrom<&ServiceEndpoint> for UrnEndpoint {
+  type Error = Error;
+
+  fn try_from(endpoint: &ServiceEndpoint) -> Result<Self> {
+    match endpoint {
+      ServiceEndpoint::One(urn) => Self::new(urn.clone()),
+      ServiceEndpoint::Set(_) | ServiceEndpoint::Map(_) => Err(Error::InvalidService("URN endpoint must be a URN")),
+    }
+  }
+}
+
+impl TryFrom<&Service> for UrnEndpoint {
+  type Error = Error;
+
+  fn try_from(service: &Service) -> Result<Self> {
+    Self::try_from(service.service_endpoint())
+  }
+}
+
+impl From<UrnEndpoint> for ServiceEndpoint {
+  fn from(endpoint: UrnEndpoint) -> Self {
+    ServiceEndpoint::One(endpoint.urn)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+
+  use super::*;
+
+  #[test]
+  fn test_urn_endpoint() {
+    let one = ServiceEndpoint::from_json(r#""urn:example:payments:eu""#).unwrap();
+    let endpoint = UrnEndpoint::try_from(&one).unwrap();
+    assert_eq!(endpoint.namespace_id(), "example");
+    assert_eq!(endpoint.namespace_specific_string(), "payments:eu");
+    assert_eq!(ServiceEndpoint::from(endpoint), one);
+
+    let set =
+      ServiceEndpoint::from_json(r#"["urn:example:payments", "https://example.com", "urn:isbn:0451450523"]"#).unwrap();
+    let urns: Vec<UrnEndpoint> = set.urns();
+    assert_eq!(urns.len(), 2);
+    assert_eq!(urns[1].namespace_id(), "isbn");
+
+    // INVALID: other schemes, malformed URNs and sets.
+    assert!(UrnEndpoint::new(Url::parse("https://example.com").unwrap()).is_err());
+    assert!(UrnEndpoint::new(Url::parse("urn:example").unwrap()).is_err());
+    assert!(UrnEndpoint::new(Url::parse("urn::payments").unwrap()).is_err());
+    assert!(UrnEndpoint::try_from(&set).is_err());
+  }
+}
@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_did::DIDUrl;
+use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
 
 /// Holds additional options for verifying a JWS with
 /// [`CoreDocument::verify_jws`](crate::document::CoreDocument::verify_jws()).
 #[non_exhaustive]
 #[derive(Default, Debug, serde::Serialize, serde::Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct JwsVerificationOptions {
   /// Verify that the nonce set in the protected header matches this value.
@@ -19,6 +21,15 @@ pub struct JwsVerificationOptions {
   /// The DID URl of the method, whose JWK should be used to verify the JWS.
   /// If unset, the `kid` of the JWS is used as the DID Url.
   pub method_id: Option<DIDUrl>,
+  /// Verify that the controller of the signing method is the document's id or one of the document's controllers.
+  #[serde(default)]
+  pub require_controlled_method: bool,
+  /// Require that the signing method is in the verification relationship appropriate to the verified object, i.e.
+  /// `assertionMethod` for credentials and `authentication` for presentations.
+  ///
+  /// Only applies to validators and is ignored if [`method_scope`](Self::method_scope) is set.
+  #[serde(default)]
+  pub require_method_purpose: bool,
 }
 
 impl JwsVerificationOptions {
@@ -44,4 +55,27 @@ impl JwsVerificationOptions {
     self.method_id = Some(value);
     self
   }
+
+  /// Require that the controller of the signing method is the document's id or one of the document's controllers.
+  pub fn require_controlled_method(mut self, value: bool) -> Self {
+    self.require_controlled_method = value;
+    self
+  }
+
+  /// Require that the signing method is in the verification relationship appropriate to the verified object.
+  pub fn require_method_purpose(mut self, value: bool) -> Self {
+    self.require_method_purpose = value;
+    self
+  }
+
+  /// Returns the scope of the methods that may verify a JWS whose appropriate verification relationship is
+  /// `purpose`, as configured by [`method_scope`](Self::method_scope) and
+  /// [`require_method_purpose`](Self::require_method_purpose).
+  pub fn scope_for_purpose(&self, purpose: MethodRelationship) -> Option<MethodScope> {
+    self.method_scope.or_else(|| {
+      self
+        .require_method_purpose
+        .then_some(MethodScope::VerificationRelationship(purpose))
+    })
+  }
 }
@@ -12,7 +12,8 @@ use identity_verification::jws::SignatureVerificationErrorKind;
 /// crate is activated:
 ///
 /// - [`JwsAlgorithm::ES256`](identity_verification::jws::JwsAlgorithm::ES256).
-/// - [`JwsAlgorithm::ES256K`](identity_verification::jws::JwsAlgorithm::ES256K).
+/// - [`JwsAlgorithm::ES256K`](identity_verification::jws::JwsAlgorithm::ES256K), also for Ethereum accounts identified
+///   by a `blockchainAccountId`.
 #[derive(Debug, Default)]
 #[non_exhaustive]
 pub struct EcDSAJwsVerifier {}
@@ -31,4 +32,16 @@ impl JwsVerifier for EcDSAJwsVerifier {
       _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
     }
   }
+
+  fn verify_blockchain_account(
+    &self,
+    input: identity_verification::jws::VerificationInput,
+    account_id: &str,
+  ) -> Result<(), identity_verification::jws::SignatureVerificationError> {
+    match input.alg {
+      #[cfg(feature = "es256k")]
+      JwsAlgorithm::ES256K => crate::Secp256K1Verifier::verify_blockchain_account(&input, account_id),
+      _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
+    }
+  }
 }
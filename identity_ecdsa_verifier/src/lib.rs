@@ -18,12 +18,16 @@ mod ecdsa_jws_verifier;
 mod secp256k1;
 #[cfg(feature = "es256")]
 mod secp256r1;
+#[cfg(feature = "webauthn")]
+mod webauthn;
 
 pub use ecdsa_jws_verifier::*;
 #[cfg(feature = "es256k")]
 pub use secp256k1::*;
 #[cfg(feature = "es256")]
 pub use secp256r1::*;
+#[cfg(feature = "webauthn")]
+pub use webauthn::*;
 
 #[cfg(test)]
 mod tests;
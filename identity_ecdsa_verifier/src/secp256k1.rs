@@ -7,12 +7,16 @@ use identity_verification::jwk::JwkParamsEc;
 use identity_verification::jws::SignatureVerificationError;
 use identity_verification::jws::SignatureVerificationErrorKind;
 use identity_verification::jwu::{self};
+use k256::ecdsa::RecoveryId;
 use k256::ecdsa::Signature;
 use k256::ecdsa::VerifyingKey;
 use k256::elliptic_curve::sec1::FromEncodedPoint;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
 use k256::elliptic_curve::subtle::CtOption;
 use k256::EncodedPoint;
 use k256::PublicKey;
+use sha3::Digest;
+use sha3::Keccak256;
 
 /// A verifier that can handle the
 /// [`JwsAlgorithm::ES256K`](identity_verification::jws::JwsAlgorithm::ES256K)
@@ -90,4 +94,58 @@ impl Secp256K1Verifier {
       }
     }
   }
+
+  /// Verify a JWS signature secured with the
+  /// [`JwsAlgorithm::ES256K`](identity_verification::jws::JwsAlgorithm::ES256K)
+  /// algorithm against the Ethereum account identified by the
+  /// [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-10.md) `account_id`, e.g.
+  /// `eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a`.
+  ///
+  /// The public key of the signer is recovered from the signature, and the signature is valid if the address of the
+  /// key is the address of the account.
+  ///
+  /// # Warning
+  ///
+  /// This function does not check whether `alg = ES256K` in the protected
+  /// header. Callers are expected to assert this prior to calling the
+  /// function.
+  pub fn verify_blockchain_account(
+    input: &identity_verification::jws::VerificationInput,
+    account_id: &str,
+  ) -> Result<(), SignatureVerificationError> {
+    let address: &str = match account_id.split(':').collect::<Vec<&str>>()[..] {
+      ["eip155", _, address] => address,
+      _ => return Err(SignatureVerificationErrorKind::UnsupportedKeyParams.into()),
+    };
+
+    let mut signature: Signature = Signature::try_from(input.decoded_signature.deref()).map_err(|err| {
+      SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err)
+    })?;
+
+    if let Some(normalized) = signature.normalize_s() {
+      signature = normalized;
+    }
+
+    // JWS signatures do not include the recovery id, so both candidate keys are tried.
+    let signed_by_account = |recovery_id: u8| -> bool {
+      RecoveryId::from_byte(recovery_id)
+        .and_then(|recovery_id| VerifyingKey::recover_from_msg(&input.signing_input, &signature, recovery_id).ok())
+        .is_some_and(|verifying_key| ethereum_address(&verifying_key).eq_ignore_ascii_case(address))
+    };
+
+    if (0..=1).any(signed_by_account) {
+      Ok(())
+    } else {
+      Err(SignatureVerificationErrorKind::InvalidSignature.into())
+    }
+  }
+}
+
+/// Returns the hex-encoded Ethereum address of `verifying_key`: the last 20 bytes of the Keccak-256 hash of its
+/// uncompressed encoding.
+fn ethereum_address(verifying_key: &VerifyingKey) -> String {
+  let encoded_point: EncodedPoint = verifying_key.as_affine().to_encoded_point(false);
+  let hash = Keccak256::digest(&encoded_point.as_bytes()[1..]);
+  let hex: String = hash[12..].iter().map(|byte| format!("{byte:02x}")).collect();
+  format!("0x{hex}")
 }
@@ -3,3 +3,5 @@
 
 mod secp256;
 mod secp256k;
+#[cfg(feature = "webauthn")]
+mod webauthn;
@@ -69,6 +69,39 @@ fn test_es256k_verifier() {
     .is_ok());
 }
 
+#[test]
+fn test_es256k_blockchain_account_verifier() {
+  use k256::ecdsa::Signature;
+  use k256::ecdsa::SigningKey;
+
+  // The key and address of the Ethereum account example of web3.js.
+  let secret_key: Vec<u8> = (0..64)
+    .step_by(2)
+    .map(|index| {
+      u8::from_str_radix(
+        &"4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"[index..index + 2],
+        16,
+      )
+      .unwrap()
+    })
+    .collect();
+  let signing_key: SigningKey = SigningKey::from_slice(&secret_key).unwrap();
+
+  let header: JwsHeader = serde_json::from_str(r#"{ "typ": "JWT", "alg": "ES256K" }"#).unwrap();
+  let encoder: jws::CompactJwsEncoder<'_> = jws::CompactJwsEncoder::new(br#"{"key":"value"}"#, &header).unwrap();
+  let signature: Signature = signature::Signer::sign(&signing_key, encoder.signing_input());
+  let encoded: String = encoder.into_jws(&signature.to_bytes());
+
+  let verify = |account_id: &str| {
+    jws::Decoder::new()
+      .decode_compact_serialization(encoded.as_bytes(), None)
+      .and_then(|decoded| decoded.verify_blockchain_account(&EcDSAJwsVerifier::default(), account_id))
+  };
+  assert!(verify("eip155:1:0x2c7536E3605D9C16a7a3D7b1898e529396a65c23").is_ok());
+  assert!(verify("eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a").is_err());
+  assert!(verify("bip122:000000000019d6689c085ae165831e93:128Lkh3S7CkDTBZ8W7BbpsN3YYizJMp8p6").is_err());
+}
+
 /// In the absence of official test vectors for secp256k1,
 /// this ensures we can verify JWTs created by other libraries.
 mod test_es256k_josekit {
@@ -0,0 +1,136 @@
+// Copyright 2020-2024 IOTA Stiftung, Filancore GmbH
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jwk::Jwk;
+use identity_verification::jws;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsHeader;
+use identity_verification::jwu;
+use p256::ecdsa::Signature;
+use p256::ecdsa::SigningKey;
+use p256::SecretKey;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::WebAuthnAssertion;
+use crate::WebAuthnJwsVerifier;
+
+const RP_ID: &str = "example.com";
+
+// P-256 key taken from https://datatracker.ietf.org/doc/html/rfc7515#appendix-A.3.
+const PRIVATE_KEY: &str = r#"
+  {
+    "kty": "EC",
+    "crv": "P-256",
+    "x": "f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU",
+    "y": "x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0",
+    "d": "jpsQnnGQmL-YBIffH1136cspYG6-0iY7X1fCE9-E9LI"
+  }
+"#;
+
+fn sign(message: &[u8], private_key: &Jwk) -> Vec<u8> {
+  let d: Vec<u8> = jwu::decode_b64(private_key.try_ec_params().unwrap().d.as_deref().unwrap()).unwrap();
+  let signing_key: SigningKey = SigningKey::from(SecretKey::from_slice(&d).unwrap());
+  let signature: Signature = signature::Signer::sign(&signing_key, message);
+  signature.to_bytes().to_vec()
+}
+
+/// Mimics an authenticator asked to sign `challenge` for `rp_id`.
+fn assertion(challenge: &[u8], rp_id: &str, flags: u8, private_key: &Jwk) -> WebAuthnAssertion {
+  let mut authenticator_data: Vec<u8> = Sha256::digest(rp_id.as_bytes()).to_vec();
+  authenticator_data.push(flags);
+  authenticator_data.extend_from_slice(&1u32.to_be_bytes());
+
+  let client_data_json: Vec<u8> = serde_json::json!({
+    "type": "webauthn.get",
+    "challenge": jwu::encode_b64(challenge),
+    "origin": format!("https://{rp_id}"),
+  })
+  .to_string()
+  .into_bytes();
+
+  let signed_data: Vec<u8> = authenticator_data
+    .iter()
+    .copied()
+    .chain(Sha256::digest(&client_data_json))
+    .collect();
+
+  WebAuthnAssertion {
+    signature: sign(&signed_data, private_key),
+    authenticator_data,
+    client_data_json,
+  }
+}
+
+fn create_jws(claims: &[u8], signature: impl FnOnce(&[u8]) -> Vec<u8>) -> String {
+  let mut header: JwsHeader = JwsHeader::new();
+  header.set_alg(JwsAlgorithm::ES256);
+  let encoder: jws::CompactJwsEncoder<'_> = jws::CompactJwsEncoder::new(claims, &header).unwrap();
+  let signature: Vec<u8> = signature(encoder.signing_input());
+  encoder.into_jws(&signature)
+}
+
+fn verify(jws: &str, verifier: &WebAuthnJwsVerifier, public_key: &Jwk) -> bool {
+  jws::Decoder::new()
+    .decode_compact_serialization(jws.as_bytes(), None)
+    .unwrap()
+    .verify(verifier, public_key)
+    .is_ok()
+}
+
+#[test]
+fn test_webauthn_assertion_roundtrip() {
+  let private_key: Jwk = serde_json::from_str(PRIVATE_KEY).unwrap();
+  let assertion: WebAuthnAssertion = assertion(b"challenge", RP_ID, 0x05, &private_key);
+  assert_eq!(WebAuthnAssertion::from_bytes(&assertion.to_bytes()).unwrap(), assertion);
+  assert!(WebAuthnAssertion::from_bytes(&assertion.to_bytes()[1..]).is_err());
+}
+
+#[test]
+fn test_webauthn_verify() {
+  let private_key: Jwk = serde_json::from_str(PRIVATE_KEY).unwrap();
+  let public_key: Jwk = private_key.to_public().unwrap();
+  let claims: &[u8] = b"{\"iss\":\"joe\"}";
+
+  let jws: String = create_jws(claims, |signing_input| {
+    assertion(&WebAuthnAssertion::challenge(signing_input), RP_ID, 0x05, &private_key).to_bytes()
+  });
+  assert!(verify(&jws, &WebAuthnJwsVerifier::new(), &public_key));
+  assert!(verify(&jws, &WebAuthnJwsVerifier::new().with_rp_id(RP_ID), &public_key));
+  assert!(!verify(
+    &jws,
+    &WebAuthnJwsVerifier::new().with_rp_id("other.com"),
+    &public_key
+  ));
+
+  // Plain ES256 signatures are accepted as well.
+  let jws: String = create_jws(claims, |signing_input| sign(signing_input, &private_key));
+  assert!(verify(&jws, &WebAuthnJwsVerifier::new(), &public_key));
+}
+
+#[test]
+fn test_webauthn_verify_rejects_invalid_assertions() {
+  let private_key: Jwk = serde_json::from_str(PRIVATE_KEY).unwrap();
+  let public_key: Jwk = private_key.to_public().unwrap();
+  let claims: &[u8] = b"{\"iss\":\"joe\"}";
+
+  // The challenge is not derived from the signing input.
+  let jws: String = create_jws(claims, |_| {
+    assertion(&WebAuthnAssertion::challenge(b"other"), RP_ID, 0x05, &private_key).to_bytes()
+  });
+  assert!(!verify(&jws, &WebAuthnJwsVerifier::new(), &public_key));
+
+  // The user was not present.
+  let jws: String = create_jws(claims, |signing_input| {
+    assertion(&WebAuthnAssertion::challenge(signing_input), RP_ID, 0x04, &private_key).to_bytes()
+  });
+  assert!(!verify(&jws, &WebAuthnJwsVerifier::new(), &public_key));
+
+  // The authenticator data was tampered with.
+  let jws: String = create_jws(claims, |signing_input| {
+    let mut assertion = assertion(&WebAuthnAssertion::challenge(signing_input), RP_ID, 0x05, &private_key);
+    assertion.authenticator_data[36] ^= 1;
+    assertion.to_bytes()
+  });
+  assert!(!verify(&jws, &WebAuthnJwsVerifier::new(), &public_key));
+}
@@ -0,0 +1,185 @@
+// Copyright 2020-2024 IOTA Stiftung, Filancore GmbH
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::SignatureVerificationError;
+use identity_verification::jws::SignatureVerificationErrorKind;
+use identity_verification::jws::VerificationInput;
+use identity_verification::jwu;
+use serde_json::Value;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::Secp256R1Verifier;
+
+/// The `type` of the client data of a WebAuthn assertion.
+const CLIENT_DATA_TYPE_GET: &str = "webauthn.get";
+
+/// The length of the fixed part of the authenticator data: the RP ID hash, the flags and the signature counter.
+const AUTHENTICATOR_DATA_MIN_LENGTH: usize = 37;
+
+/// The flag of the authenticator data signalling that the user was present.
+const FLAG_USER_PRESENT: u8 = 0x01;
+
+/// The length of a raw `ES256` signature.
+const ES256_SIGNATURE_LENGTH: usize = 64;
+
+/// An assertion of a WebAuthn authenticator, as produced by `navigator.credentials.get`, carried in the signature of
+/// a JWS.
+///
+/// WebAuthn authenticators do not sign the JWS signing input, but the authenticator data followed by the SHA-256
+/// digest of the client data, which contains the [challenge](WebAuthnAssertion::challenge) derived from the signing
+/// input. To make the signature verifiable, the assertion is encoded as follows:
+///
+/// - the length of the authenticator data as a big-endian `u16`, followed by the authenticator data,
+/// - the length of the client data JSON as a big-endian `u16`, followed by the client data JSON,
+/// - the 64 byte `ES256` signature, i.e. the concatenated `r` and `s` values rather than the DER encoding returned by
+///   the authenticator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebAuthnAssertion {
+  /// The authenticator data of the assertion.
+  pub authenticator_data: Vec<u8>,
+  /// The serialized client data of the assertion.
+  pub client_data_json: Vec<u8>,
+  /// The `ES256` signature over the authenticator data and the digest of the client data.
+  pub signature: Vec<u8>,
+}
+
+impl WebAuthnAssertion {
+  /// Returns the challenge a WebAuthn authenticator must be asked to sign for the given JWS `signing_input`, i.e. its
+  /// SHA-256 digest.
+  pub fn challenge(signing_input: &[u8]) -> Vec<u8> {
+    Sha256::digest(signing_input).to_vec()
+  }
+
+  /// Encodes the assertion into the signature of a JWS.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut bytes: Vec<u8> =
+      Vec::with_capacity(4 + self.authenticator_data.len() + self.client_data_json.len() + self.signature.len());
+    for field in [&self.authenticator_data, &self.client_data_json] {
+      bytes.extend_from_slice(&(field.len() as u16).to_be_bytes());
+      bytes.extend_from_slice(field);
+    }
+    bytes.extend_from_slice(&self.signature);
+    bytes
+  }
+
+  /// Decodes an assertion from the signature of a JWS.
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, SignatureVerificationError> {
+    fn split_field(bytes: &[u8]) -> Result<(&[u8], &[u8]), SignatureVerificationError> {
+      let invalid = || {
+        SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature)
+          .with_custom_message("malformed WebAuthn assertion")
+      };
+      let (length, rest) = bytes.split_first_chunk::<2>().ok_or_else(invalid)?;
+      let length: usize = u16::from_be_bytes(*length) as usize;
+      if rest.len() < length {
+        return Err(invalid());
+      }
+      Ok(rest.split_at(length))
+    }
+
+    let (authenticator_data, rest) = split_field(bytes)?;
+    let (client_data_json, signature) = split_field(rest)?;
+    if signature.len() != ES256_SIGNATURE_LENGTH {
+      return Err(
+        SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature)
+          .with_custom_message("malformed WebAuthn assertion"),
+      );
+    }
+
+    Ok(Self {
+      authenticator_data: authenticator_data.to_vec(),
+      client_data_json: client_data_json.to_vec(),
+      signature: signature.to_vec(),
+    })
+  }
+}
+
+/// An implementor of [`JwsVerifier`] for JWS signed with a P-256 key held by a WebAuthn authenticator, such as a
+/// passkey.
+///
+/// Signatures must be [`WebAuthnAssertion`]s over the challenge derived from the signing input. Plain `ES256`
+/// signatures are verified as by the [`Secp256R1Verifier`], so this verifier can be used in place of the
+/// [`EcDSAJwsVerifier`](crate::EcDSAJwsVerifier) for `ES256`.
+///
+/// The origin in the client data is not checked, since a JWS may be verified independently of any web origin.
+#[derive(Debug, Default)]
+pub struct WebAuthnJwsVerifier {
+  rp_id: Option<String>,
+}
+
+impl WebAuthnJwsVerifier {
+  /// Constructs a [`WebAuthnJwsVerifier`] accepting assertions for any relying party.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Only accept assertions created for the relying party with the given id, e.g. `example.com`.
+  pub fn with_rp_id(mut self, rp_id: impl Into<String>) -> Self {
+    self.rp_id = Some(rp_id.into());
+    self
+  }
+
+  fn verify_assertion(
+    &self,
+    input: &VerificationInput,
+    public_key: &Jwk,
+    assertion: WebAuthnAssertion,
+  ) -> Result<(), SignatureVerificationError> {
+    let invalid = |message: &'static str| {
+      SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_custom_message(message)
+    };
+
+    let client_data: Value = serde_json::from_slice(&assertion.client_data_json)
+      .map_err(|err| invalid("invalid WebAuthn client data").with_source(err))?;
+    if client_data["type"].as_str() != Some(CLIENT_DATA_TYPE_GET) {
+      return Err(invalid("unexpected WebAuthn client data type"));
+    }
+    let challenge: String = jwu::encode_b64(WebAuthnAssertion::challenge(&input.signing_input));
+    if client_data["challenge"].as_str() != Some(challenge.as_str()) {
+      return Err(invalid("the WebAuthn challenge does not match the signing input"));
+    }
+
+    let authenticator_data: &[u8] = &assertion.authenticator_data;
+    if authenticator_data.len() < AUTHENTICATOR_DATA_MIN_LENGTH {
+      return Err(invalid("invalid WebAuthn authenticator data"));
+    }
+    if authenticator_data[32] & FLAG_USER_PRESENT == 0 {
+      return Err(invalid("the user was not present"));
+    }
+    if let Some(rp_id) = self.rp_id.as_deref() {
+      if authenticator_data[..32] != Sha256::digest(rp_id.as_bytes())[..] {
+        return Err(invalid("the WebAuthn assertion was created for another relying party"));
+      }
+    }
+
+    let signed_data: Vec<u8> = authenticator_data
+      .iter()
+      .copied()
+      .chain(Sha256::digest(&assertion.client_data_json))
+      .collect();
+    let assertion_input = VerificationInput {
+      alg: JwsAlgorithm::ES256,
+      signing_input: signed_data.into_boxed_slice(),
+      decoded_signature: assertion.signature.into_boxed_slice(),
+    };
+    Secp256R1Verifier::verify(&assertion_input, public_key)
+  }
+}
+
+impl JwsVerifier for WebAuthnJwsVerifier {
+  fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    if input.alg != JwsAlgorithm::ES256 {
+      return Err(SignatureVerificationErrorKind::UnsupportedAlg.into());
+    }
+
+    if input.decoded_signature.len() == ES256_SIGNATURE_LENGTH {
+      return Secp256R1Verifier::verify(&input, public_key);
+    }
+    let assertion: WebAuthnAssertion = WebAuthnAssertion::from_bytes(&input.decoded_signature)?;
+    self.verify_assertion(&input, public_key, assertion)
+  }
+}
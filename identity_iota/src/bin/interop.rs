@@ -0,0 +1,488 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validates a credential or presentation JWT offline against a bundle of DID Documents and prints a report of every
+//! check that was run, such that interoperability issues can be debugged without writing any code.
+//!
+//! ```text
+//! identity-interop credential <credential.jwt> --documents <documents.json> [--now <timestamp>] [--json]
+//! identity-interop presentation <presentation.jwt> --documents <documents.json> [--now <timestamp>] [--json]
+//! ```
+//!
+//! The documents file contains a JSON array of DID Documents, either in their plain form or as IOTA DID Documents
+//! including their metadata. The process exits with `0` if all checks passed, `1` if any check failed and `2` if the
+//! input could not be read.
+
+use std::error::Error;
+use std::fmt::Write;
+use std::process::ExitCode;
+
+use identity_ecdsa_verifier::EcDSAJwsVerifier;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_iota::core::FromJson;
+use identity_iota::core::Object;
+use identity_iota::core::Timestamp;
+use identity_iota::core::ToJson;
+use identity_iota::credential::Credential;
+use identity_iota::credential::DecodedJwtCredential;
+use identity_iota::credential::DecodedJwtPresentation;
+use identity_iota::credential::Jwt;
+use identity_iota::credential::JwtCredentialValidator;
+use identity_iota::credential::JwtCredentialValidatorUtils;
+use identity_iota::credential::JwtPresentationValidationOptions;
+use identity_iota::credential::JwtPresentationValidator;
+use identity_iota::credential::JwtPresentationValidatorUtils;
+use identity_iota::credential::JwtValidationError;
+use identity_iota::credential::StatusCheck;
+use identity_iota::credential::SubjectHolderRelationship;
+use identity_iota::did::CoreDID;
+use identity_iota::document::verifiable::JwsVerificationOptions;
+use identity_iota::document::CoreDocument;
+use identity_iota::iota::IotaDocument;
+use identity_iota::verification::jwk::Jwk;
+use identity_iota::verification::jws::JwsAlgorithm;
+use identity_iota::verification::jws::JwsVerifier;
+use identity_iota::verification::jws::SignatureVerificationError;
+use identity_iota::verification::jws::SignatureVerificationErrorKind;
+use identity_iota::verification::jws::VerificationInput;
+use serde::Deserialize;
+use serde::Serialize;
+
+const USAGE: &str = "\
+Usage:
+  identity-interop credential <credential.jwt> --documents <documents.json> [--now <timestamp>] [--json]
+  identity-interop presentation <presentation.jwt> --documents <documents.json> [--now <timestamp>] [--json]
+
+Options:
+  --documents <file>  JSON array of the DID Documents of all issuers and holders
+  --now <timestamp>   RFC 3339 timestamp to check expiration and issuance dates against, defaults to the current time
+  --json              Print the report as JSON";
+
+fn main() -> ExitCode {
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  match run(&args) {
+    Ok(output) => {
+      println!("{output}");
+      if output.report.valid {
+        ExitCode::SUCCESS
+      } else {
+        ExitCode::from(1)
+      }
+    }
+    Err(message) => {
+      eprintln!("error: {message}\n\n{USAGE}");
+      ExitCode::from(2)
+    }
+  }
+}
+
+fn run(args: &[String]) -> Result<Output, String> {
+  let Arguments {
+    kind,
+    input,
+    documents,
+    now,
+    json,
+  } = Arguments::parse(args)?;
+
+  let jwt: Jwt = std::fs::read_to_string(&input)
+    .map(|content| Jwt::new(content.trim().to_owned()))
+    .map_err(|err| format!("unable to read `{input}`: {err}"))?;
+  let documents: Vec<CoreDocument> = std::fs::read_to_string(&documents)
+    .map_err(|err| format!("unable to read `{documents}`: {err}"))
+    .and_then(|json| parse_documents(&json).map_err(|err| format!("invalid documents in `{documents}`: {err}")))?;
+
+  let report: Report = match kind {
+    Kind::Credential => Report::credential(&jwt, &documents, now),
+    Kind::Presentation => Report::presentation(&jwt, &documents, now),
+  };
+  Ok(Output { report, json })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Kind {
+  Credential,
+  Presentation,
+}
+
+struct Arguments {
+  kind: Kind,
+  input: String,
+  documents: String,
+  now: Timestamp,
+  json: bool,
+}
+
+impl Arguments {
+  fn parse(args: &[String]) -> Result<Self, String> {
+    let mut args = args.iter();
+    let kind: Kind = match args.next().map(String::as_str) {
+      Some("credential") => Kind::Credential,
+      Some("presentation") => Kind::Presentation,
+      Some(other) => return Err(format!("unknown command `{other}`")),
+      None => return Err("missing command".to_owned()),
+    };
+
+    let mut input: Option<String> = None;
+    let mut documents: Option<String> = None;
+    let mut now: Option<Timestamp> = None;
+    let mut json: bool = false;
+    while let Some(arg) = args.next() {
+      match arg.as_str() {
+        "--documents" => documents = Some(args.next().ok_or("missing value of `--documents`")?.clone()),
+        "--now" => {
+          let value: &String = args.next().ok_or("missing value of `--now`")?;
+          now = Some(Timestamp::parse(value).map_err(|err| format!("invalid value of `--now`: {err}"))?);
+        }
+        "--json" => json = true,
+        option if option.starts_with("--") => return Err(format!("unknown option `{option}`")),
+        path if input.is_none() => input = Some(path.to_owned()),
+        other => return Err(format!("unexpected argument `{other}`")),
+      }
+    }
+
+    Ok(Self {
+      kind,
+      input: input.ok_or("missing input file")?,
+      documents: documents.ok_or("missing `--documents`")?,
+      now: now.unwrap_or_else(Timestamp::now_utc),
+      json,
+    })
+  }
+}
+
+/// A DID Document in a bundle, either in its plain form or as an IOTA DID Document including its metadata.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BundledDocument {
+  Core(CoreDocument),
+  Iota(IotaDocument),
+}
+
+fn parse_documents(json: &str) -> Result<Vec<CoreDocument>, String> {
+  Vec::<BundledDocument>::from_json(json)
+    .map(|documents| {
+      documents
+        .into_iter()
+        .map(|document| match document {
+          BundledDocument::Core(document) => document,
+          BundledDocument::Iota(document) => document.into(),
+        })
+        .collect()
+    })
+    .map_err(|err| err.to_string())
+}
+
+/// Verifies the signatures of all algorithms with built-in support.
+struct InteropVerifier;
+
+impl JwsVerifier for InteropVerifier {
+  fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    match input.alg {
+      JwsAlgorithm::EdDSA => EdDSAJwsVerifier::default().verify(input, public_key),
+      JwsAlgorithm::ES256 | JwsAlgorithm::ES256K => EcDSAJwsVerifier::default().verify(input, public_key),
+      _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Outcome {
+  Passed,
+  Failed,
+  Skipped,
+}
+
+/// The outcome of a single check.
+#[derive(Debug, Serialize)]
+struct Check {
+  name: String,
+  outcome: Outcome,
+  /// The name of the [`JwtValidationError`] variant a failed check resulted in.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  code: Option<&'static str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  message: Option<String>,
+}
+
+/// The checks run on a credential or presentation, in the order they were run.
+#[derive(Debug, Serialize)]
+struct Report {
+  kind: Kind,
+  now: Timestamp,
+  valid: bool,
+  checks: Vec<Check>,
+}
+
+impl Report {
+  fn new(kind: Kind, now: Timestamp) -> Self {
+    Self {
+      kind,
+      now,
+      valid: true,
+      checks: Vec::new(),
+    }
+  }
+
+  fn credential(jwt: &Jwt, documents: &[CoreDocument], now: Timestamp) -> Self {
+    let mut report: Self = Self::new(Kind::Credential, now);
+    report.check_credential("", jwt, documents, now);
+    report
+  }
+
+  fn presentation(jwt: &Jwt, documents: &[CoreDocument], now: Timestamp) -> Self {
+    const PRESENTATION_CHECKS: &[&str] = &["holder_document", "presentation"];
+
+    let mut report: Self = Self::new(Kind::Presentation, now);
+    let holder: CoreDID = match JwtPresentationValidatorUtils::extract_holder::<CoreDID>(jwt) {
+      Ok(holder) => report.pass("holder", holder),
+      Err(err) => {
+        report.fail("holder", &err);
+        report.skip("", PRESENTATION_CHECKS);
+        return report;
+      }
+    };
+    let Some(holder_document) = documents.iter().find(|document| document.id() == &holder) else {
+      report.fail_with(
+        "holder_document",
+        "MissingHolderDocument",
+        format!("no document for {holder}"),
+      );
+      report.skip("", &PRESENTATION_CHECKS[1..]);
+      return report;
+    };
+    report.pass("holder_document", ());
+
+    let options: JwtPresentationValidationOptions = JwtPresentationValidationOptions::default()
+      .earliest_expiry_date(now)
+      .latest_issuance_date(now);
+    let presentation: DecodedJwtPresentation<Jwt> = match JwtPresentationValidator::with_signature_verifier(
+      InteropVerifier,
+    )
+    .validate::<_, Jwt, Object>(jwt, holder_document, &options)
+    {
+      Ok(presentation) => report.pass("presentation", presentation),
+      Err(err) => {
+        for error in &err.presentation_validation_errors {
+          report.fail("presentation", error);
+        }
+        return report;
+      }
+    };
+
+    for (index, credential_jwt) in presentation.presentation.verifiable_credential.iter().enumerate() {
+      let prefix: String = format!("credential[{index}].");
+      if let Some(credential) = report.check_credential(&prefix, credential_jwt, documents, now) {
+        match JwtCredentialValidatorUtils::check_subject_holder_relationship(
+          &credential,
+          &presentation.presentation.holder,
+          SubjectHolderRelationship::AlwaysSubject,
+        ) {
+          Ok(()) => report.pass(&format!("{prefix}subject_holder"), ()),
+          Err(err) => report.fail(&format!("{prefix}subject_holder"), &err),
+        };
+      }
+    }
+    report
+  }
+
+  /// Runs the checks on the credential `jwt`, prefixing their names with `prefix`. Returns the credential if its
+  /// signature could be verified.
+  fn check_credential(
+    &mut self,
+    prefix: &str,
+    jwt: &Jwt,
+    documents: &[CoreDocument],
+    now: Timestamp,
+  ) -> Option<Credential> {
+    const CREDENTIAL_CHECKS: &[&str] = &[
+      "issuer_document",
+      "signature",
+      "structure",
+      "expiration",
+      "issuance",
+      "status",
+    ];
+    let issuer: CoreDID = match JwtCredentialValidatorUtils::extract_issuer_from_jwt::<CoreDID>(jwt) {
+      Ok(issuer) => self.pass(&format!("{prefix}issuer"), issuer),
+      Err(err) => {
+        self.fail(&format!("{prefix}issuer"), &err);
+        self.skip(prefix, CREDENTIAL_CHECKS);
+        return None;
+      }
+    };
+    let Some(issuer_document) = documents.iter().find(|document| document.id() == &issuer) else {
+      self.fail_with(
+        &format!("{prefix}issuer_document"),
+        "MissingIssuerDocument",
+        format!("no document for {issuer}"),
+      );
+      self.skip(prefix, &CREDENTIAL_CHECKS[1..]);
+      return None;
+    };
+    self.pass(&format!("{prefix}issuer_document"), ());
+
+    let credential: DecodedJwtCredential<Object> =
+      match JwtCredentialValidator::with_signature_verifier(InteropVerifier).verify_signature(
+        jwt,
+        std::slice::from_ref(issuer_document),
+        &JwsVerificationOptions::default(),
+      ) {
+        Ok(credential) => self.pass(&format!("{prefix}signature"), credential),
+        Err(err) => {
+          self.fail(&format!("{prefix}signature"), &err);
+          self.skip(prefix, &CREDENTIAL_CHECKS[2..]);
+          return None;
+        }
+      };
+    let credential: Credential = credential.credential;
+
+    let checks: [(&str, Result<(), JwtValidationError>); 4] = [
+      ("structure", JwtCredentialValidatorUtils::check_structure(&credential)),
+      (
+        "expiration",
+        JwtCredentialValidatorUtils::check_expires_on_or_after(&credential, now),
+      ),
+      (
+        "issuance",
+        JwtCredentialValidatorUtils::check_issued_on_or_before(&credential, now),
+      ),
+      (
+        "status",
+        JwtCredentialValidatorUtils::check_status(
+          &credential,
+          std::slice::from_ref(issuer_document),
+          StatusCheck::Strict,
+        ),
+      ),
+    ];
+    for (name, result) in checks {
+      match result {
+        Ok(()) => self.pass(&format!("{prefix}{name}"), ()),
+        Err(err) => self.fail(&format!("{prefix}{name}"), &err),
+      }
+    }
+    Some(credential)
+  }
+
+  fn pass<T>(&mut self, name: &str, value: T) -> T {
+    self.push(name, Outcome::Passed, None, None);
+    value
+  }
+
+  fn fail(&mut self, name: &str, error: &JwtValidationError) {
+    self.fail_with(name, error.into(), error_chain(error));
+  }
+
+  fn fail_with(&mut self, name: &str, code: &'static str, message: String) {
+    self.valid = false;
+    self.push(name, Outcome::Failed, Some(code), Some(message));
+  }
+
+  /// Records the checks `names`, prefixed with `prefix`, as skipped because a check they depend on failed.
+  fn skip(&mut self, prefix: &str, names: &[&str]) {
+    for name in names {
+      self.push(&format!("{prefix}{name}"), Outcome::Skipped, None, None);
+    }
+  }
+
+  fn push(&mut self, name: &str, outcome: Outcome, code: Option<&'static str>, message: Option<String>) {
+    self.checks.push(Check {
+      name: name.to_owned(),
+      outcome,
+      code,
+      message,
+    });
+  }
+}
+
+/// Formats `error` followed by its sources.
+fn error_chain(error: &dyn Error) -> String {
+  let mut message: String = error.to_string();
+  let mut source: Option<&dyn Error> = error.source();
+  while let Some(cause) = source {
+    let _ = write!(message, ": {cause}");
+    source = cause.source();
+  }
+  message
+}
+
+struct Output {
+  report: Report,
+  json: bool,
+}
+
+impl std::fmt::Display for Output {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if self.json {
+      let json: String = self.report.to_json_pretty().map_err(|_| std::fmt::Error)?;
+      return f.write_str(&json);
+    }
+
+    let kind: &str = match self.report.kind {
+      Kind::Credential => "Credential",
+      Kind::Presentation => "Presentation",
+    };
+    writeln!(f, "{kind} validation report at {}", self.report.now)?;
+    for check in &self.report.checks {
+      let outcome: &str = match check.outcome {
+        Outcome::Passed => "PASS",
+        Outcome::Failed => "FAIL",
+        Outcome::Skipped => "SKIP",
+      };
+      write!(f, "  [{outcome}] {}", check.name)?;
+      if let Some(code) = check.code {
+        write!(f, " ({code})")?;
+      }
+      if let Some(message) = &check.message {
+        write!(f, ": {message}")?;
+      }
+      writeln!(f)?;
+    }
+    write!(f, "Result: {}", if self.report.valid { "VALID" } else { "INVALID" })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const DOCUMENT: &str = r#"[{
+    "id": "did:example:issuer",
+    "verificationMethod": [{
+      "id": "did:example:issuer#key-1",
+      "controller": "did:example:issuer",
+      "type": "JsonWebKey2020",
+      "publicKeyJwk": { "kty": "OKP", "crv": "Ed25519", "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo" }
+    }]
+  }]"#;
+
+  #[test]
+  fn test_parse_arguments() {
+    let args: Vec<String> = ["presentation", "vp.jwt", "--documents", "docs.json", "--json"]
+      .map(ToOwned::to_owned)
+      .to_vec();
+    let arguments: Arguments = Arguments::parse(&args).unwrap();
+    assert_eq!(arguments.kind, Kind::Presentation);
+    assert_eq!(arguments.input, "vp.jwt");
+    assert_eq!(arguments.documents, "docs.json");
+    assert!(arguments.json);
+
+    assert!(Arguments::parse(&["credential".to_owned()]).is_err());
+  }
+
+  #[test]
+  fn test_report_skips_dependent_checks() {
+    let documents: Vec<CoreDocument> = parse_documents(DOCUMENT).unwrap();
+    assert_eq!(documents.len(), 1);
+
+    let report: Report = Report::credential(&Jwt::new("invalid".to_owned()), &documents, Timestamp::now_utc());
+    assert!(!report.valid);
+    assert_eq!(report.checks[0].name, "issuer");
+    assert_eq!(report.checks[0].outcome, Outcome::Failed);
+    assert_eq!(report.checks[0].code, Some("JwsDecodingError"));
+    assert!(report.checks[1..].iter().all(|check| check.outcome == Outcome::Skipped));
+  }
+}
@@ -0,0 +1,180 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runtime report of the capabilities compiled into this crate.
+
+use serde::Serialize;
+
+/// The cargo features of this crate, each paired with whether it is enabled.
+const FEATURES: &[(&str, bool)] = &[
+  ("client", cfg!(feature = "client")),
+  ("iota-client", cfg!(feature = "iota-client")),
+  ("test-utils", cfg!(feature = "test-utils")),
+  ("compression", cfg!(feature = "compression")),
+  ("revocation-bitmap", cfg!(feature = "revocation-bitmap")),
+  ("status-list-2021", cfg!(feature = "status-list-2021")),
+  ("status-list-2021-fetch", cfg!(feature = "status-list-2021-fetch")),
+  ("merkle-revocation", cfg!(feature = "merkle-revocation")),
+  ("trust-registry-fetch", cfg!(feature = "trust-registry-fetch")),
+  ("x509", cfg!(feature = "x509")),
+  ("resolver", cfg!(feature = "resolver")),
+  ("send-sync-storage", cfg!(feature = "send-sync-storage")),
+  ("domain-linkage", cfg!(feature = "domain-linkage")),
+  ("domain-linkage-fetch", cfg!(feature = "domain-linkage-fetch")),
+  ("verifier-lite", cfg!(feature = "verifier-lite")),
+  ("no-network", cfg!(feature = "no-network")),
+  ("interop-cli", cfg!(feature = "interop-cli")),
+  ("memstore", cfg!(feature = "memstore")),
+  ("telemetry", cfg!(feature = "telemetry")),
+  ("key-backup", cfg!(feature = "key-backup")),
+  ("key-attestation", cfg!(feature = "key-attestation")),
+  ("credential-encryption", cfg!(feature = "credential-encryption")),
+  ("key-derivation", cfg!(feature = "key-derivation")),
+  ("controller-key", cfg!(feature = "controller-key")),
+  ("webauthn", cfg!(feature = "webauthn")),
+  ("threshold", cfg!(feature = "threshold")),
+  ("sd-jwt", cfg!(feature = "sd-jwt")),
+  ("sd-jwt-vc", cfg!(feature = "sd-jwt-vc")),
+  ("jsonld", cfg!(feature = "jsonld")),
+  ("data-integrity", cfg!(feature = "data-integrity")),
+  ("iot", cfg!(feature = "iot")),
+  ("json-patch", cfg!(feature = "json-patch")),
+  ("schemars", cfg!(feature = "schemars")),
+  ("jpt-bbs-plus", cfg!(feature = "jpt-bbs-plus")),
+  ("bbs-plus-blind", cfg!(feature = "bbs-plus-blind")),
+];
+
+/// The signature algorithms with built-in support, each paired with whether the feature providing it is enabled.
+const ALGORITHMS: &[(&str, bool)] = &[
+  ("EdDSA", cfg!(feature = "memstore")),
+  ("ES256", cfg!(feature = "webauthn")),
+  ("BLS12381-SHA256", cfg!(feature = "jpt-bbs-plus")),
+  ("BLS12381-SHAKE256", cfg!(feature = "jpt-bbs-plus")),
+];
+
+/// The credential formats, each paired with whether the feature providing it is enabled.
+const CREDENTIAL_FORMATS: &[(&str, bool)] = &[
+  ("jwt-vc", true),
+  ("sd-jwt", cfg!(feature = "sd-jwt")),
+  ("sd-jwt-vc", cfg!(feature = "sd-jwt-vc")),
+  ("jpt", cfg!(feature = "jpt-bbs-plus")),
+  ("ldp-vc", cfg!(feature = "data-integrity")),
+  ("cose-vc", cfg!(feature = "iot")),
+];
+
+/// The deprecated APIs compiled into this crate.
+const DEPRECATED_APIS: &[DeprecatedApi] = &[DeprecatedApi {
+  path: "identity_iota::verification::MethodType::JSON_WEB_KEY",
+  since: "1.3.0",
+  note: "use JSON_WEB_KEY_2020 instead",
+}];
+
+/// A deprecated API that is still compiled in, together with its replacement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct DeprecatedApi {
+  /// The path of the deprecated item.
+  pub path: &'static str,
+  /// The version in which the item was deprecated.
+  pub since: &'static str,
+  /// A note on what to use instead.
+  pub note: &'static str,
+}
+
+/// The capabilities compiled into this crate, as returned by [`features`].
+///
+/// Long-running services can use it to check their configuration at startup rather than failing on first use.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureReport {
+  version: &'static str,
+  features: Vec<&'static str>,
+  algorithms: Vec<&'static str>,
+  credential_formats: Vec<&'static str>,
+  deprecated_apis: Vec<DeprecatedApi>,
+}
+
+impl FeatureReport {
+  /// Returns the version of this crate.
+  pub fn version(&self) -> &'static str {
+    self.version
+  }
+
+  /// Returns the enabled cargo features.
+  pub fn features(&self) -> &[&'static str] {
+    &self.features
+  }
+
+  /// Returns the signature algorithms supported by the built-in key storages and verifiers, as `alg` header values.
+  pub fn algorithms(&self) -> &[&'static str] {
+    &self.algorithms
+  }
+
+  /// Returns the supported credential formats, e.g. `jwt-vc` or `sd-jwt-vc`.
+  pub fn credential_formats(&self) -> &[&'static str] {
+    &self.credential_formats
+  }
+
+  /// Returns the deprecated APIs compiled into this crate.
+  pub fn deprecated_apis(&self) -> &[DeprecatedApi] {
+    &self.deprecated_apis
+  }
+
+  /// Returns whether the cargo feature `feature` is enabled.
+  pub fn is_enabled(&self, feature: &str) -> bool {
+    self.features.contains(&feature)
+  }
+
+  /// Returns whether the signature algorithm `alg` is supported.
+  pub fn supports_algorithm(&self, alg: &str) -> bool {
+    self.algorithms.contains(&alg)
+  }
+
+  /// Returns whether the credential format `format` is supported.
+  pub fn supports_credential_format(&self, format: &str) -> bool {
+    self.credential_formats.contains(&format)
+  }
+
+  /// Returns the features out of `required` that are not enabled, such that a service can refuse to start if the
+  /// result is not empty.
+  pub fn missing_features<'a>(&self, required: &[&'a str]) -> Vec<&'a str> {
+    required
+      .iter()
+      .copied()
+      .filter(|feature| !self.is_enabled(feature))
+      .collect()
+  }
+}
+
+/// Returns a report of the cargo features, algorithms, credential formats and deprecated APIs compiled into this
+/// crate.
+pub fn features() -> FeatureReport {
+  fn enabled(entries: &[(&'static str, bool)]) -> Vec<&'static str> {
+    entries
+      .iter()
+      .filter_map(|(name, is_enabled)| is_enabled.then_some(*name))
+      .collect()
+  }
+
+  FeatureReport {
+    version: env!("CARGO_PKG_VERSION"),
+    features: enabled(FEATURES),
+    algorithms: enabled(ALGORITHMS),
+    credential_formats: enabled(CREDENTIAL_FORMATS),
+    deprecated_apis: DEPRECATED_APIS.to_vec(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_features() {
+    let report: FeatureReport = features();
+    assert_eq!(report.version(), env!("CARGO_PKG_VERSION"));
+    assert_eq!(report.is_enabled("resolver"), cfg!(feature = "resolver"));
+    assert!(report.supports_credential_format("jwt-vc"));
+    assert_eq!(report.supports_algorithm("EdDSA"), cfg!(feature = "memstore"));
+    assert_eq!(report.missing_features(&["unknown"]), ["unknown"]);
+  }
+}
@@ -17,6 +17,28 @@
   clippy::missing_errors_doc
 )]
 
+// Auditor builds must not contain any code path able to make network calls, see the `no-network` feature.
+#[cfg(all(
+  feature = "no-network",
+  not(docsrs),
+  any(
+    feature = "iota-client",
+    feature = "status-list-2021-fetch",
+    feature = "trust-registry-fetch",
+    feature = "domain-linkage-fetch"
+  )
+))]
+compile_error!(
+  "the `no-network` feature cannot be combined with `iota-client`, `status-list-2021-fetch`, \
+   `trust-registry-fetch` or `domain-linkage-fetch`; disable the default features"
+);
+
+pub use self::features::features;
+pub use self::features::DeprecatedApi;
+pub use self::features::FeatureReport;
+
+mod features;
+
 pub mod core {
   //! Core Traits and Types
 
@@ -34,9 +56,17 @@ pub mod credential {
   //! [Specification](https://www.w3.org/TR/vc-data-model/)
 
   pub use identity_credential::credential::*;
+  pub use identity_credential::credential_subject_schema;
+  #[cfg(feature = "data-integrity")]
+  pub use identity_credential::data_integrity;
   #[cfg(feature = "domain-linkage")]
   pub use identity_credential::domain_linkage::*;
   pub use identity_credential::error::*;
+  pub use identity_credential::format::*;
+  #[cfg(feature = "iot")]
+  pub use identity_credential::iot;
+  #[cfg(feature = "jsonld")]
+  pub use identity_credential::jsonld;
   pub use identity_credential::presentation::*;
   #[cfg(feature = "revocation-bitmap")]
   pub use identity_credential::revocation::*;
@@ -62,6 +92,7 @@ pub mod document {
   pub use identity_document::service::*;
   pub use identity_document::utils::*;
 
+  pub use identity_document::profile;
   pub use identity_document::verifiable;
 }
 
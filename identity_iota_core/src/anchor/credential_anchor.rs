@@ -0,0 +1,364 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::common::Timestamp;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialContentHash;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::Error;
+
+/// Tag of the tagged data payloads anchoring the roots of [`AnchorBatch`]es.
+#[cfg(feature = "iota-client")]
+pub(crate) const ANCHOR_TAG: &[u8] = b"identity.rs:anchor";
+
+/// Magic bytes used to mark anchored roots.
+const ANCHOR_MARKER: &[u8] = b"ITA";
+
+/// Version of the anchored root encoding.
+const ANCHOR_VERSION_V1: u8 = 1;
+
+/// Domain separation prefixes of leaves and inner nodes, as defined in RFC 9162.
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+type Digest = [u8; SHA256_LEN];
+
+/// A batch of SHA-256 digests, e.g. the content hashes of issued credentials, committed to by a single Merkle root.
+///
+/// Anchoring the root on the ledger with `IotaClientExt::anchor_batch` proves that every digest of the batch existed
+/// at the time of the milestone referencing the anchor, independently of the dates claimed by the credentials
+/// themselves. The Merkle tree follows RFC 9162, so the inclusion of each digest can be proven without revealing the
+/// other digests of the batch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnchorBatch {
+  digests: Vec<Digest>,
+}
+
+impl AnchorBatch {
+  /// Creates a new [`AnchorBatch`] of the given `digests`.
+  ///
+  /// # Errors
+  /// Fails if `digests` is empty.
+  pub fn new(digests: Vec<[u8; SHA256_LEN]>) -> Result<Self> {
+    if digests.is_empty() {
+      return Err(Error::InvalidAnchor("a batch must contain at least one digest"));
+    }
+    Ok(Self { digests })
+  }
+
+  /// Creates a new [`AnchorBatch`] of the [`CredentialContentHash`]es of `credentials`.
+  pub fn from_credentials<'a, T>(credentials: impl IntoIterator<Item = &'a Credential<T>>) -> Result<Self>
+  where
+    T: Serialize + 'a,
+  {
+    let digests: Vec<Digest> = credentials
+      .into_iter()
+      .map(|credential| {
+        CredentialContentHash::new(credential)
+          .map(|hash| *hash.as_bytes())
+          .map_err(|_| Error::InvalidAnchor("failed to compute the content hash of a credential"))
+      })
+      .collect::<Result<_>>()?;
+    Self::new(digests)
+  }
+
+  /// Returns the digests of the batch.
+  pub fn digests(&self) -> &[[u8; SHA256_LEN]] {
+    &self.digests
+  }
+
+  /// Returns the number of digests in the batch.
+  pub fn len(&self) -> usize {
+    self.digests.len()
+  }
+
+  /// Returns `true` if the batch contains no digests, which is never the case.
+  pub fn is_empty(&self) -> bool {
+    self.digests.is_empty()
+  }
+
+  /// Returns the Merkle root of the batch.
+  pub fn root(&self) -> [u8; SHA256_LEN] {
+    subtree_root(&self.leaf_hashes())
+  }
+
+  /// Returns the proof of inclusion of the digest at `index` in the batch, or `None` if `index` is out of bounds.
+  pub fn inclusion_proof(&self, index: usize) -> Option<InclusionProof> {
+    if index >= self.digests.len() {
+      return None;
+    }
+    let mut path: Vec<Digest> = Vec::new();
+    audit_path(index, &self.leaf_hashes(), &mut path);
+
+    Some(InclusionProof {
+      leaf_index: index as u64,
+      tree_size: self.digests.len() as u64,
+      path: path
+        .iter()
+        .map(|hash| BaseEncoding::encode(hash, Base::Base64Url))
+        .collect(),
+    })
+  }
+
+  /// Pack the root of the batch into bytes, suitable for the data of a tagged data payload.
+  ///
+  /// The layout is `[marker, version, root]`.
+  pub fn pack(&self) -> Vec<u8> {
+    let mut buffer: Vec<u8> = Vec::with_capacity(ANCHOR_MARKER.len() + 1 + SHA256_LEN);
+    buffer.extend_from_slice(ANCHOR_MARKER);
+    buffer.push(ANCHOR_VERSION_V1);
+    buffer.extend_from_slice(&self.root());
+    buffer
+  }
+
+  /// Unpack the root of a batch from bytes produced by [`AnchorBatch::pack`].
+  pub fn unpack_root(data: &[u8]) -> Result<[u8; SHA256_LEN]> {
+    if data.get(0..=2) != Some(ANCHOR_MARKER) {
+      return Err(Error::InvalidAnchor("missing `ITA` marker"));
+    }
+    if data.get(3) != Some(&ANCHOR_VERSION_V1) {
+      return Err(Error::InvalidAnchor("unsupported version"));
+    }
+    data
+      .get(4..)
+      .and_then(|root| Digest::try_from(root).ok())
+      .ok_or(Error::InvalidAnchor("expected a root of 32 bytes at offset 4"))
+  }
+
+  fn leaf_hashes(&self) -> Vec<Digest> {
+    self.digests.iter().map(leaf_hash).collect()
+  }
+}
+
+/// A proof of inclusion of a digest in an [`AnchorBatch`], consisting of the audit path of RFC 9162.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionProof {
+  leaf_index: u64,
+  tree_size: u64,
+  path: Vec<String>,
+}
+
+impl InclusionProof {
+  /// Returns the index of the digest in the batch.
+  pub fn leaf_index(&self) -> u64 {
+    self.leaf_index
+  }
+
+  /// Returns the number of digests in the batch.
+  pub fn tree_size(&self) -> u64 {
+    self.tree_size
+  }
+
+  /// Computes the root of the batch from `digest` and the audit path of the proof.
+  ///
+  /// The proof is valid for `digest` if the returned root is the anchored one.
+  ///
+  /// # Errors
+  /// Fails if the audit path is malformed or does not match the index and size of the proof.
+  pub fn root(&self, digest: &[u8; SHA256_LEN]) -> Result<[u8; SHA256_LEN]> {
+    if self.leaf_index >= self.tree_size {
+      return Err(Error::InvalidAnchor("leaf index is out of bounds"));
+    }
+
+    // See RFC 9162, Section 2.1.3.2.
+    let mut fn_: u64 = self.leaf_index;
+    let mut sn: u64 = self.tree_size - 1;
+    let mut root: Digest = leaf_hash(digest);
+    for encoded in self.path.iter() {
+      let sibling: Digest = BaseEncoding::decode(encoded, Base::Base64Url)
+        .ok()
+        .and_then(|sibling| Digest::try_from(sibling).ok())
+        .ok_or(Error::InvalidAnchor("invalid hash in audit path"))?;
+      if sn == 0 {
+        return Err(Error::InvalidAnchor("audit path is too long"));
+      }
+      if fn_ & 1 == 1 || fn_ == sn {
+        root = node_hash(&sibling, &root);
+        while fn_ & 1 == 0 && fn_ != 0 {
+          fn_ >>= 1;
+          sn >>= 1;
+        }
+      } else {
+        root = node_hash(&root, &sibling);
+      }
+      fn_ >>= 1;
+      sn >>= 1;
+    }
+    if sn != 0 {
+      return Err(Error::InvalidAnchor("audit path is too short"));
+    }
+
+    Ok(root)
+  }
+}
+
+/// A proof that a digest was anchored on the ledger no later than the time of the milestone referencing the anchor.
+///
+/// Obtained from `IotaClientExt::anchor_batch` and verified with `IotaClientExt::verify_timestamp_proof`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimestampProof {
+  block_id: String,
+  milestone_index: u32,
+  timestamp: Timestamp,
+  inclusion_proof: InclusionProof,
+}
+
+impl TimestampProof {
+  #[cfg(feature = "iota-client")]
+  pub(crate) fn new(
+    block_id: String,
+    milestone_index: u32,
+    timestamp: Timestamp,
+    inclusion_proof: InclusionProof,
+  ) -> Self {
+    Self {
+      block_id,
+      milestone_index,
+      timestamp,
+      inclusion_proof,
+    }
+  }
+
+  /// Returns the hex encoded identifier of the block anchoring the root of the batch.
+  pub fn block_id(&self) -> &str {
+    &self.block_id
+  }
+
+  /// Returns the index of the milestone referencing the block.
+  pub fn milestone_index(&self) -> u32 {
+    self.milestone_index
+  }
+
+  /// Returns the timestamp of the milestone referencing the block.
+  pub fn timestamp(&self) -> Timestamp {
+    self.timestamp
+  }
+
+  /// Returns the proof of inclusion of the digest in the anchored batch.
+  pub fn inclusion_proof(&self) -> &InclusionProof {
+    &self.inclusion_proof
+  }
+}
+
+fn leaf_hash(digest: &Digest) -> Digest {
+  let mut hash: Digest = [0; SHA256_LEN];
+  SHA256(&[[LEAF_PREFIX].as_slice(), digest.as_slice()].concat(), &mut hash);
+  hash
+}
+
+fn node_hash(left: &Digest, right: &Digest) -> Digest {
+  let mut hash: Digest = [0; SHA256_LEN];
+  SHA256(
+    &[[NODE_PREFIX].as_slice(), left.as_slice(), right.as_slice()].concat(),
+    &mut hash,
+  );
+  hash
+}
+
+/// Returns the largest power of two smaller than `size`, which must be greater than one.
+fn split_point(size: usize) -> usize {
+  let mut split: usize = 1;
+  while split << 1 < size {
+    split <<= 1;
+  }
+  split
+}
+
+/// Computes the root of the non-empty subtree of `hashes`.
+fn subtree_root(hashes: &[Digest]) -> Digest {
+  if let [hash] = hashes {
+    return *hash;
+  }
+  let split: usize = split_point(hashes.len());
+  node_hash(&subtree_root(&hashes[..split]), &subtree_root(&hashes[split..]))
+}
+
+/// Appends the audit path of the leaf at `index` in the subtree of `hashes` to `path`, from the leaf to the root.
+fn audit_path(index: usize, hashes: &[Digest], path: &mut Vec<Digest>) {
+  if hashes.len() <= 1 {
+    return;
+  }
+  let split: usize = split_point(hashes.len());
+  if index < split {
+    audit_path(index, &hashes[..split], path);
+    path.push(subtree_root(&hashes[split..]));
+  } else {
+    audit_path(index - split, &hashes[split..], path);
+    path.push(subtree_root(&hashes[..split]));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn batch(size: u8) -> AnchorBatch {
+    AnchorBatch::new((0..size).map(|index| [index; SHA256_LEN]).collect()).unwrap()
+  }
+
+  #[test]
+  fn test_empty_batch_is_rejected() {
+    assert!(matches!(AnchorBatch::new(Vec::new()), Err(Error::InvalidAnchor(_))));
+  }
+
+  #[test]
+  fn test_root_follows_rfc_9162() {
+    let batch: AnchorBatch = batch(3);
+    let leaves: Vec<Digest> = batch.digests().iter().map(leaf_hash).collect();
+    assert_eq!(batch.root(), node_hash(&node_hash(&leaves[0], &leaves[1]), &leaves[2]));
+    assert_eq!(self::batch(1).root(), leaf_hash(&[0; SHA256_LEN]));
+  }
+
+  #[test]
+  fn test_inclusion_proofs() {
+    for size in 1..=9 {
+      let batch: AnchorBatch = batch(size);
+      for (index, digest) in batch.digests().iter().enumerate() {
+        let proof: InclusionProof = batch.inclusion_proof(index).unwrap();
+        assert_eq!(proof.root(digest).unwrap(), batch.root());
+        assert_ne!(proof.root(&[0xff; SHA256_LEN]).unwrap(), batch.root());
+      }
+      assert!(batch.inclusion_proof(size as usize).is_none());
+    }
+  }
+
+  #[test]
+  fn test_malformed_inclusion_proofs_are_rejected() {
+    let batch: AnchorBatch = batch(5);
+    let digest: &Digest = &batch.digests()[2];
+    let proof: InclusionProof = batch.inclusion_proof(2).unwrap();
+
+    let mut too_short: InclusionProof = proof.clone();
+    too_short.path.pop();
+    assert!(too_short.root(digest).is_err());
+
+    let mut too_long: InclusionProof = proof.clone();
+    too_long.path.push(proof.path[0].clone());
+    assert!(too_long.root(digest).is_err());
+
+    let mut out_of_bounds: InclusionProof = proof;
+    out_of_bounds.leaf_index = out_of_bounds.tree_size;
+    assert!(out_of_bounds.root(digest).is_err());
+  }
+
+  #[test]
+  fn test_pack_unpack() {
+    let batch: AnchorBatch = batch(4);
+    let packed: Vec<u8> = batch.pack();
+    assert_eq!(AnchorBatch::unpack_root(&packed).unwrap(), batch.root());
+
+    assert!(AnchorBatch::unpack_root(&packed[..packed.len() - 1]).is_err());
+    let mut wrong_version: Vec<u8> = packed;
+    wrong_version[3] = 0;
+    assert!(AnchorBatch::unpack_root(&wrong_version).is_err());
+  }
+}
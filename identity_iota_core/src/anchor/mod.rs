@@ -0,0 +1,10 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod credential_anchor;
+
+pub use credential_anchor::AnchorBatch;
+pub use credential_anchor::InclusionProof;
+pub use credential_anchor::TimestampProof;
+#[cfg(feature = "iota-client")]
+pub(crate) use credential_anchor::ANCHOR_TAG;
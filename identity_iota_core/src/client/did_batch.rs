@@ -0,0 +1,212 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::block::address::Address;
+use crate::block::output::AliasOutput;
+use crate::block::output::OutputId;
+use crate::Error;
+use crate::IotaDocument;
+
+/// The maximum number of outputs of a transaction.
+pub(crate) const MAX_OUTPUTS_PER_TRANSACTION: usize = 128;
+
+/// Options for publishing a [`DIDBatch`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct DIDBatchOptions {
+  /// The address the storage deposits of the DID outputs are moved to before publishing them, which must be
+  /// controlled by the secret manager used for publishing.
+  pub funding_address: Address,
+  /// The number of DID outputs created in a single transaction.
+  ///
+  /// Default: 32. At most 128.
+  pub outputs_per_transaction: usize,
+  /// The maximum number of transactions submitted concurrently.
+  ///
+  /// Default: 4.
+  pub concurrency: usize,
+}
+
+impl DIDBatchOptions {
+  /// Creates new [`DIDBatchOptions`] funding the DID outputs from `funding_address`.
+  pub fn new(funding_address: Address) -> Self {
+    Self {
+      funding_address,
+      outputs_per_transaction: 32,
+      concurrency: 4,
+    }
+  }
+
+  /// Sets the number of DID outputs created in a single transaction.
+  pub fn outputs_per_transaction(mut self, value: usize) -> Self {
+    self.outputs_per_transaction = value.clamp(1, MAX_OUTPUTS_PER_TRANSACTION);
+    self
+  }
+
+  /// Sets the maximum number of transactions submitted concurrently.
+  pub fn concurrency(mut self, value: usize) -> Self {
+    self.concurrency = value.max(1);
+    self
+  }
+}
+
+/// The outcome of publishing a single DID output of a [`DIDBatch`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DIDBatchItem {
+  /// The DID output has not been published yet.
+  Pending,
+  /// The DID output was published, resulting in the contained document.
+  Published(IotaDocument),
+  /// Publishing the DID output failed. It is retried when the batch is published again.
+  Failed(Error),
+}
+
+impl DIDBatchItem {
+  /// Returns whether the DID output was published.
+  pub fn is_published(&self) -> bool {
+    matches!(self, Self::Published(_))
+  }
+}
+
+/// A group of DID outputs published in a single transaction, consuming a dedicated funding output.
+#[derive(Debug)]
+pub(crate) struct DIDBatchChunk {
+  pub(crate) indices: Vec<usize>,
+  pub(crate) funding: Option<OutputId>,
+}
+
+/// Many DID outputs published together with
+/// [`IotaClientExt::publish_did_batch`](crate::IotaClientExt::publish_did_batch()).
+///
+/// The DID outputs are grouped into transactions of
+/// [`DIDBatchOptions::outputs_per_transaction`] outputs. The storage deposits of all transactions are first
+/// split off the funding address into one output per transaction, such that the transactions can be submitted
+/// concurrently without competing for the same inputs.
+///
+/// The batch records the outcome of each DID output. If publishing partially fails, the same batch can be
+/// published again, which only retries the failed outputs and reuses their funding outputs.
+#[derive(Debug)]
+pub struct DIDBatch {
+  pub(crate) outputs: Vec<AliasOutput>,
+  pub(crate) items: Vec<DIDBatchItem>,
+  pub(crate) chunks: Vec<DIDBatchChunk>,
+  pub(crate) options: DIDBatchOptions,
+}
+
+impl DIDBatch {
+  /// Creates a new [`DIDBatch`] of the given DID outputs, e.g. created with
+  /// [`IotaIdentityClientExt::new_did_output`](crate::IotaIdentityClientExt::new_did_output()).
+  pub fn new(outputs: Vec<AliasOutput>, options: DIDBatchOptions) -> Self {
+    let outputs_per_transaction: usize = options.outputs_per_transaction.clamp(1, MAX_OUTPUTS_PER_TRANSACTION);
+    let chunks: Vec<DIDBatchChunk> = (0..outputs.len())
+      .collect::<Vec<usize>>()
+      .chunks(outputs_per_transaction)
+      .map(|indices| DIDBatchChunk {
+        indices: indices.to_vec(),
+        funding: None,
+      })
+      .collect();
+
+    Self {
+      items: outputs.iter().map(|_| DIDBatchItem::Pending).collect(),
+      outputs,
+      chunks,
+      options,
+    }
+  }
+
+  /// Returns the options of the batch.
+  pub fn options(&self) -> &DIDBatchOptions {
+    &self.options
+  }
+
+  /// Returns the DID outputs of the batch.
+  pub fn outputs(&self) -> &[AliasOutput] {
+    &self.outputs
+  }
+
+  /// Returns the outcome of each DID output, in the order of [`Self::outputs`].
+  pub fn items(&self) -> &[DIDBatchItem] {
+    &self.items
+  }
+
+  /// Returns the documents published so far.
+  pub fn published(&self) -> impl Iterator<Item = &IotaDocument> + '_ {
+    self.items.iter().filter_map(|item| match item {
+      DIDBatchItem::Published(document) => Some(document),
+      _ => None,
+    })
+  }
+
+  /// Returns the indices of the DID outputs that failed to publish, together with their errors.
+  pub fn failed(&self) -> impl Iterator<Item = (usize, &Error)> + '_ {
+    self.items.iter().enumerate().filter_map(|(index, item)| match item {
+      DIDBatchItem::Failed(error) => Some((index, error)),
+      _ => None,
+    })
+  }
+
+  /// Returns whether all DID outputs were published.
+  pub fn is_complete(&self) -> bool {
+    self.items.iter().all(DIDBatchItem::is_published)
+  }
+
+  /// Returns the funding output of each transaction that is not published yet, together with the amount it must
+  /// hold. Transactions without a funding output are funded when the batch is published.
+  pub fn funding_outputs(&self) -> impl Iterator<Item = (Option<OutputId>, u64)> + '_ {
+    self
+      .chunks
+      .iter()
+      .filter(|chunk| !self.is_chunk_published(chunk))
+      .map(|chunk| (chunk.funding, self.chunk_amount(chunk)))
+  }
+
+  pub(crate) fn is_chunk_published(&self, chunk: &DIDBatchChunk) -> bool {
+    chunk.indices.iter().all(|index| self.items[*index].is_published())
+  }
+
+  pub(crate) fn chunk_amount(&self, chunk: &DIDBatchChunk) -> u64 {
+    chunk.indices.iter().map(|index| self.outputs[*index].amount()).sum()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::block::address::Ed25519Address;
+  use crate::block::output::unlock_condition::GovernorAddressUnlockCondition;
+  use crate::block::output::unlock_condition::StateControllerAddressUnlockCondition;
+  use crate::block::output::AliasId;
+  use crate::block::output::AliasOutputBuilder;
+  use crate::block::output::UnlockCondition;
+
+  use super::*;
+
+  fn alias_output(amount: u64) -> AliasOutput {
+    let address = Address::Ed25519(Ed25519Address::new([1; 32]));
+    AliasOutputBuilder::new_with_amount(amount, AliasId::null())
+      .add_unlock_condition(UnlockCondition::StateControllerAddress(
+        StateControllerAddressUnlockCondition::new(address),
+      ))
+      .add_unlock_condition(UnlockCondition::GovernorAddress(GovernorAddressUnlockCondition::new(
+        address,
+      )))
+      .finish()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_batch_chunks() {
+    let options = DIDBatchOptions::new(Address::Ed25519(Ed25519Address::new([1; 32]))).outputs_per_transaction(2);
+    let batch = DIDBatch::new((1..=5).map(alias_output).collect(), options);
+
+    assert_eq!(batch.chunks.len(), 3);
+    assert_eq!(
+      batch.funding_outputs().collect::<Vec<_>>(),
+      [(None, 3), (None, 7), (None, 5)]
+    );
+    assert!(!batch.is_complete());
+    assert_eq!(batch.published().count(), 0);
+    assert_eq!(batch.failed().count(), 0);
+  }
+}
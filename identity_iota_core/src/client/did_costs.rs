@@ -0,0 +1,130 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::block::output::AliasOutput;
+use crate::block::output::AliasOutputBuilder;
+use crate::block::output::RentStructure;
+use crate::Error;
+use crate::IotaDID;
+use crate::IotaDocument;
+use crate::Result;
+
+/// An operation on a DID whose costs can be estimated with
+/// [`IotaIdentityClientExt::estimate_costs`](crate::IotaIdentityClientExt::estimate_costs()).
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum DIDOperation {
+  /// Creating a DID with the given document.
+  Create(IotaDocument),
+  /// Updating the DID of the given document with it.
+  Update(IotaDocument),
+  /// Deactivating the given DID.
+  Deactivate(IotaDID),
+}
+
+/// The estimated costs of a [`DIDOperation`].
+///
+/// Transactions are feeless, so the only cost of an operation is the storage deposit held by the Alias Output of the
+/// DID, which is returned once the output is destroyed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DIDOperationCosts {
+  /// The minimum storage deposit of the Alias Output after the operation.
+  pub storage_deposit: u64,
+  /// The amount of tokens currently held by the Alias Output, or zero if the DID is created.
+  pub current_deposit: u64,
+}
+
+impl DIDOperationCosts {
+  /// Computes the costs of replacing an Alias Output holding `current_deposit` tokens with `alias_output`.
+  pub(crate) fn new(alias_output: &AliasOutput, current_deposit: u64, rent_structure: RentStructure) -> Result<Self> {
+    let storage_deposit: u64 = AliasOutputBuilder::from(alias_output)
+      .with_minimum_storage_deposit(rent_structure)
+      .finish()
+      .map_err(Error::AliasOutputBuildError)?
+      .amount();
+
+    Ok(Self {
+      storage_deposit,
+      current_deposit,
+    })
+  }
+
+  /// Returns the amount of tokens that must be added to the Alias Output to cover its storage deposit.
+  pub fn additional_deposit(&self) -> u64 {
+    self.storage_deposit.saturating_sub(self.current_deposit)
+  }
+
+  /// Returns the amount of tokens exceeding the storage deposit, which can be reallocated after the operation.
+  pub fn releasable_deposit(&self) -> u64 {
+    self.current_deposit.saturating_sub(self.storage_deposit)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_did::DID;
+  use identity_document::service::Service;
+
+  use crate::block::address::Address;
+  use crate::block::address::Ed25519Address;
+  use crate::block::output::unlock_condition::GovernorAddressUnlockCondition;
+  use crate::block::output::unlock_condition::StateControllerAddressUnlockCondition;
+  use crate::block::output::AliasId;
+  use crate::block::output::UnlockCondition;
+  use crate::block::protocol::ProtocolParameters;
+  use crate::NetworkName;
+
+  use super::*;
+
+  fn alias_output(document: &IotaDocument, amount: u64) -> AliasOutput {
+    let address = Address::Ed25519(Ed25519Address::new([1; 32]));
+    AliasOutputBuilder::new_with_amount(amount, AliasId::from(document.id()))
+      .with_state_metadata(document.clone().pack().unwrap())
+      .add_unlock_condition(UnlockCondition::StateControllerAddress(
+        StateControllerAddressUnlockCondition::new(address),
+      ))
+      .add_unlock_condition(UnlockCondition::GovernorAddress(GovernorAddressUnlockCondition::new(
+        address,
+      )))
+      .finish()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_costs() {
+    let rent_structure: RentStructure = *ProtocolParameters::default().rent_structure();
+    let mut document: IotaDocument = IotaDocument::new(&NetworkName::try_from("smr").unwrap());
+    let published: AliasOutput = alias_output(&document, 1);
+    let costs: DIDOperationCosts = DIDOperationCosts::new(&published, published.amount(), rent_structure).unwrap();
+    assert!(costs.storage_deposit > 1);
+    assert_eq!(costs.additional_deposit(), costs.storage_deposit - 1);
+    assert_eq!(costs.releasable_deposit(), 0);
+
+    // A larger document requires a larger deposit.
+    let service: Service = Service::builder(Object::new())
+      .id(document.id().to_url().join("#linked-domain").unwrap())
+      .type_("LinkedDomains")
+      .service_endpoint(Url::parse("https://example.com/").unwrap())
+      .build()
+      .unwrap();
+    document.insert_service(service).unwrap();
+    let updated: AliasOutput = alias_output(&document, costs.storage_deposit);
+    let update_costs: DIDOperationCosts =
+      DIDOperationCosts::new(&updated, costs.storage_deposit, rent_structure).unwrap();
+    assert!(update_costs.storage_deposit > costs.storage_deposit);
+    assert!(update_costs.additional_deposit() > 0);
+
+    // Removing the document releases part of the deposit.
+    let deactivated: AliasOutput = AliasOutputBuilder::from(&updated)
+      .with_state_metadata(Vec::new())
+      .finish()
+      .unwrap();
+    let deactivate_costs: DIDOperationCosts =
+      DIDOperationCosts::new(&deactivated, update_costs.storage_deposit, rent_structure).unwrap();
+    assert_eq!(deactivate_costs.additional_deposit(), 0);
+    assert!(deactivate_costs.releasable_deposit() > 0);
+  }
+}
@@ -0,0 +1,116 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::block::BlockId;
+use crate::Error;
+use crate::IotaDID;
+use crate::IotaDocument;
+
+/// The outcome of updating a single DID with
+/// [`IotaClientExt::update_dids`](crate::IotaClientExt::update_dids()).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DIDUpdateOutcome {
+  /// The updated document was published in the block with the contained id.
+  Published {
+    /// The id of the block containing the transaction that updated the DID.
+    block_id: BlockId,
+    /// The published document.
+    document: IotaDocument,
+  },
+  /// The update failed and the DID was left unchanged.
+  Failed(Error),
+}
+
+impl DIDUpdateOutcome {
+  /// Returns whether the updated document was published.
+  pub fn is_published(&self) -> bool {
+    matches!(self, Self::Published { .. })
+  }
+
+  /// Returns the id of the block that updated the DID, if it was published.
+  pub fn block_id(&self) -> Option<BlockId> {
+    match self {
+      Self::Published { block_id, .. } => Some(*block_id),
+      Self::Failed(_) => None,
+    }
+  }
+}
+
+/// The progress of [`IotaClientExt::update_dids`](crate::IotaClientExt::update_dids()), reported after each DID.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct DIDUpdateProgress<'a> {
+  /// The DID that was processed.
+  pub did: &'a IotaDID,
+  /// The outcome of updating `did`.
+  pub outcome: &'a DIDUpdateOutcome,
+  /// The number of DIDs processed so far, including `did`.
+  pub completed: usize,
+  /// The total number of DIDs to update.
+  pub total: usize,
+}
+
+/// The summary of updating many DIDs with [`IotaClientExt::update_dids`](crate::IotaClientExt::update_dids()).
+#[derive(Debug, Default)]
+pub struct DIDUpdateSummary {
+  pub(crate) outcomes: Vec<(IotaDID, DIDUpdateOutcome)>,
+}
+
+impl DIDUpdateSummary {
+  /// Returns the outcome of each DID, in the order they were given.
+  pub fn outcomes(&self) -> &[(IotaDID, DIDUpdateOutcome)] {
+    &self.outcomes
+  }
+
+  /// Returns the id of the block that published the update of each updated DID.
+  pub fn block_ids(&self) -> impl Iterator<Item = (&IotaDID, BlockId)> + '_ {
+    self
+      .outcomes
+      .iter()
+      .filter_map(|(did, outcome)| outcome.block_id().map(|block_id| (did, block_id)))
+  }
+
+  /// Returns the DIDs whose update failed, together with their errors.
+  pub fn failed(&self) -> impl Iterator<Item = (&IotaDID, &Error)> + '_ {
+    self.outcomes.iter().filter_map(|(did, outcome)| match outcome {
+      DIDUpdateOutcome::Failed(error) => Some((did, error)),
+      DIDUpdateOutcome::Published { .. } => None,
+    })
+  }
+
+  /// Returns whether all DIDs were updated.
+  pub fn is_complete(&self) -> bool {
+    self.outcomes.iter().all(|(_, outcome)| outcome.is_published())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_summary() {
+    let did: IotaDID = IotaDID::placeholder(&crate::NetworkName::try_from("smr").unwrap());
+    let block_id: BlockId = BlockId::new([1; 32]);
+    let summary = DIDUpdateSummary {
+      outcomes: vec![
+        (
+          did.clone(),
+          DIDUpdateOutcome::Published {
+            block_id,
+            document: IotaDocument::new_with_id(did.clone()),
+          },
+        ),
+        (
+          did.clone(),
+          DIDUpdateOutcome::Failed(Error::InvalidStateMetadata("empty")),
+        ),
+      ],
+    };
+
+    assert!(!summary.is_complete());
+    assert_eq!(summary.block_ids().collect::<Vec<_>>(), [(&did, block_id)]);
+    assert_eq!(summary.failed().count(), 1);
+  }
+}
@@ -0,0 +1,302 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::block::output::dto::AliasOutputDto;
+use crate::block::output::AliasId;
+use crate::block::output::AliasOutput;
+use crate::block::output::OutputId;
+use crate::block::protocol::ProtocolParameters;
+use crate::block::TryFromDto;
+use crate::Error;
+use crate::IotaIdentityClient;
+use crate::Result;
+
+/// Node responses recorded by a [`RecordingClient`] and served by a [`ReplayClient`].
+///
+/// Fixtures are stored as JSON, such that tests of publication and resolution flows can run deterministically
+/// without a network.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientFixture {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  protocol_parameters: Option<ProtocolParameters>,
+  #[serde(default)]
+  alias_outputs: BTreeMap<String, RecordedAliasOutput>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordedAliasOutput {
+  output_id: OutputId,
+  alias_output: AliasOutputDto,
+}
+
+impl ClientFixture {
+  /// Creates an empty [`ClientFixture`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Reads a fixture from the JSON file at `path`.
+  pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+    let json: String = std::fs::read_to_string(path)
+      .map_err(|err| Error::FixtureError("unable to read the fixture file", Some(Box::new(err))))?;
+    Self::from_json(&json).map_err(|err| Error::FixtureError("invalid fixture", Some(Box::new(err))))
+  }
+
+  /// Writes the fixture as JSON to the file at `path`, replacing any existing file.
+  pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+    let json: String = self
+      .to_json_pretty()
+      .map_err(|err| Error::FixtureError("unable to serialize the fixture", Some(Box::new(err))))?;
+    std::fs::write(path, json)
+      .map_err(|err| Error::FixtureError("unable to write the fixture file", Some(Box::new(err))))
+  }
+
+  /// Returns the recorded protocol parameters, if any.
+  pub fn protocol_parameters(&self) -> Option<&ProtocolParameters> {
+    self.protocol_parameters.as_ref()
+  }
+
+  /// Returns the recorded [`OutputId`] and [`AliasOutput`] of `alias_id`, if any.
+  pub fn alias_output(&self, alias_id: &AliasId) -> Result<Option<(OutputId, AliasOutput)>> {
+    self
+      .alias_outputs
+      .get(&alias_id.to_string())
+      .map(|recorded| {
+        AliasOutput::try_from_dto(recorded.alias_output.clone())
+          .map(|alias_output| (recorded.output_id, alias_output))
+          .map_err(|err| Error::FixtureError("invalid recorded alias output", Some(Box::new(err))))
+      })
+      .transpose()
+  }
+
+  /// Records the protocol parameters.
+  pub fn insert_protocol_parameters(&mut self, protocol_parameters: ProtocolParameters) {
+    self.protocol_parameters = Some(protocol_parameters);
+  }
+
+  /// Records the [`OutputId`] and [`AliasOutput`] of `alias_id`, replacing any previous recording.
+  pub fn insert_alias_output(&mut self, alias_id: AliasId, output_id: OutputId, alias_output: &AliasOutput) {
+    self.alias_outputs.insert(
+      alias_id.to_string(),
+      RecordedAliasOutput {
+        output_id,
+        alias_output: AliasOutputDto::from(alias_output),
+      },
+    );
+  }
+}
+
+/// An [`IotaIdentityClient`] recording the responses of the wrapped client into a [`ClientFixture`].
+///
+/// Run a test once against a node with a [`RecordingClient`], save its [`RecordingClient::fixture`] and replay it
+/// with a [`ReplayClient`] afterwards.
+#[derive(Debug)]
+pub struct RecordingClient<C> {
+  client: C,
+  fixture: Mutex<ClientFixture>,
+}
+
+impl<C> RecordingClient<C> {
+  /// Creates a new [`RecordingClient`] wrapping `client`.
+  pub fn new(client: C) -> Self {
+    Self::with_fixture(client, ClientFixture::new())
+  }
+
+  /// Creates a new [`RecordingClient`] wrapping `client`, adding new recordings to `fixture`.
+  pub fn with_fixture(client: C, fixture: ClientFixture) -> Self {
+    Self {
+      client,
+      fixture: Mutex::new(fixture),
+    }
+  }
+
+  /// Returns a reference to the wrapped client.
+  pub fn client(&self) -> &C {
+    &self.client
+  }
+
+  /// Returns a copy of the responses recorded so far.
+  pub fn fixture(&self) -> ClientFixture {
+    self.fixture.lock().unwrap_or_else(PoisonError::into_inner).clone()
+  }
+
+  /// Consumes the client and returns the recorded responses.
+  pub fn into_fixture(self) -> ClientFixture {
+    self.fixture.into_inner().unwrap_or_else(PoisonError::into_inner)
+  }
+
+  fn record(&self, f: impl FnOnce(&mut ClientFixture)) {
+    f(&mut self.fixture.lock().unwrap_or_else(PoisonError::into_inner));
+  }
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+impl<C> IotaIdentityClient for RecordingClient<C>
+where
+  C: IotaIdentityClient + Send + Sync,
+{
+  async fn get_alias_output(&self, alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+    let (output_id, alias_output) = self.client.get_alias_output(alias_id).await?;
+    self.record(|fixture| fixture.insert_alias_output(alias_id, output_id, &alias_output));
+    Ok((output_id, alias_output))
+  }
+
+  async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
+    let protocol_parameters: ProtocolParameters = self.client.get_protocol_parameters().await?;
+    self.record(|fixture| fixture.insert_protocol_parameters(protocol_parameters.clone()));
+    Ok(protocol_parameters)
+  }
+
+  async fn get_alias_outputs(&self, alias_ids: &[AliasId]) -> Result<Vec<(OutputId, AliasOutput)>> {
+    let outputs: Vec<(OutputId, AliasOutput)> = self.client.get_alias_outputs(alias_ids).await?;
+    self.record(|fixture| {
+      for (alias_id, (output_id, alias_output)) in alias_ids.iter().zip(&outputs) {
+        fixture.insert_alias_output(*alias_id, *output_id, alias_output);
+      }
+    });
+    Ok(outputs)
+  }
+}
+
+/// An [`IotaIdentityClient`] serving the responses of a [`ClientFixture`] instead of querying a node.
+///
+/// Requests that were not recorded fail with [`Error::FixtureError`].
+#[derive(Clone, Debug, Default)]
+pub struct ReplayClient {
+  fixture: ClientFixture,
+}
+
+impl ReplayClient {
+  /// Creates a new [`ReplayClient`] serving the responses of `fixture`.
+  pub fn new(fixture: ClientFixture) -> Self {
+    Self { fixture }
+  }
+
+  /// Creates a new [`ReplayClient`] serving the responses of the fixture file at `path`.
+  pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+    ClientFixture::load(path).map(Self::new)
+  }
+
+  /// Returns a reference to the replayed fixture.
+  pub fn fixture(&self) -> &ClientFixture {
+    &self.fixture
+  }
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+impl IotaIdentityClient for ReplayClient {
+  async fn get_alias_output(&self, alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+    self
+      .fixture
+      .alias_output(&alias_id)?
+      .ok_or(Error::FixtureError("no alias output recorded for the alias id", None))
+  }
+
+  async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
+    self
+      .fixture
+      .protocol_parameters()
+      .cloned()
+      .ok_or(Error::FixtureError("no protocol parameters recorded", None))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::block::address::Address;
+  use crate::block::address::Ed25519Address;
+  use crate::block::output::unlock_condition::GovernorAddressUnlockCondition;
+  use crate::block::output::unlock_condition::StateControllerAddressUnlockCondition;
+  use crate::block::output::AliasOutputBuilder;
+  use crate::block::output::UnlockCondition;
+  use crate::block::payload::transaction::TransactionId;
+  use crate::IotaDID;
+  use crate::IotaDocument;
+
+  use super::*;
+
+  struct NodeClient {
+    did: IotaDID,
+    output_id: OutputId,
+    alias_output: AliasOutput,
+  }
+
+  impl NodeClient {
+    fn new() -> Self {
+      let did: IotaDID = "did:iota:0xAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"
+        .parse()
+        .unwrap();
+      let address = Address::Ed25519(Ed25519Address::new([1; 32]));
+      let alias_output: AliasOutput = AliasOutputBuilder::new_with_amount(1, AliasId::from(&did))
+        .with_state_metadata(IotaDocument::new_with_id(did.clone()).pack().unwrap())
+        .add_unlock_condition(UnlockCondition::StateControllerAddress(
+          StateControllerAddressUnlockCondition::new(address),
+        ))
+        .add_unlock_condition(UnlockCondition::GovernorAddress(GovernorAddressUnlockCondition::new(
+          address,
+        )))
+        .finish()
+        .unwrap();
+      let output_id: OutputId = OutputId::new(TransactionId::new([2; 32]), 0).unwrap();
+
+      Self {
+        did,
+        output_id,
+        alias_output,
+      }
+    }
+  }
+
+  #[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+  #[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+  impl IotaIdentityClient for NodeClient {
+    async fn get_alias_output(&self, _alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+      Ok((self.output_id, self.alias_output.clone()))
+    }
+
+    async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
+      Ok(ProtocolParameters::default())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_record_and_replay() {
+    let node = NodeClient::new();
+    let alias_id: AliasId = AliasId::from(&node.did);
+    let (output_id, alias_output) = (node.output_id, node.alias_output.clone());
+
+    let recording = RecordingClient::new(node);
+    recording.get_alias_output(alias_id).await.unwrap();
+    recording.get_protocol_parameters().await.unwrap();
+
+    let json: String = recording.into_fixture().to_json().unwrap();
+    let replay = ReplayClient::new(ClientFixture::from_json(&json).unwrap());
+    assert_eq!(
+      replay.get_alias_output(alias_id).await.unwrap(),
+      (output_id, alias_output)
+    );
+    assert_eq!(
+      replay.get_protocol_parameters().await.unwrap(),
+      ProtocolParameters::default()
+    );
+
+    assert!(matches!(
+      replay.get_alias_output(AliasId::null()).await,
+      Err(Error::FixtureError(..))
+    ));
+  }
+}
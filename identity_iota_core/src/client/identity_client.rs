@@ -1,26 +1,45 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
+use identity_core::common::OrderedSet;
+use identity_document::service::Service;
 #[cfg(feature = "test")]
 use iota_sdk::client::Client;
 
 use crate::block::address::Address;
+use crate::block::address::AliasAddress;
+use crate::block::address::Ed25519Address;
+use crate::block::output::feature::MetadataFeature;
 use crate::block::output::feature::SenderFeature;
+use crate::block::output::unlock_condition::AddressUnlockCondition;
+use crate::block::output::unlock_condition::ExpirationUnlockCondition;
 use crate::block::output::unlock_condition::GovernorAddressUnlockCondition;
 use crate::block::output::unlock_condition::StateControllerAddressUnlockCondition;
+use crate::block::output::unlock_condition::StorageDepositReturnUnlockCondition;
 use crate::block::output::AliasId;
 use crate::block::output::AliasOutput;
 use crate::block::output::AliasOutputBuilder;
+use crate::block::output::BasicOutput;
+use crate::block::output::BasicOutputBuilder;
 use crate::block::output::Feature;
 use crate::block::output::OutputId;
 use crate::block::output::RentStructure;
 use crate::block::output::UnlockCondition;
 use crate::block::protocol::ProtocolParameters;
+use crate::CredentialRegistryEntry;
+use crate::DIDNotification;
+use crate::DIDOperation;
+use crate::DIDOperationCosts;
 use crate::Error;
 use crate::IotaDID;
 use crate::IotaDocument;
+use crate::IotaDocumentSummary;
 use crate::NetworkName;
 use crate::Result;
+use crate::StateMetadataDocument;
+use crate::StateMetadataEncoding;
 
 /// Helper functions necessary for the [`IotaIdentityClientExt`] trait.
 #[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
@@ -30,6 +49,18 @@ pub trait IotaIdentityClient {
   async fn get_alias_output(&self, alias_id: AliasId) -> Result<(OutputId, AliasOutput)>;
   /// Get the protocol parameters of the node we are trying to connect to.
   async fn get_protocol_parameters(&self) -> Result<ProtocolParameters>;
+  /// Resolve multiple Alias identifiers, returning their latest [`OutputId`] and [`AliasOutput`] in the order of
+  /// `alias_ids`.
+  ///
+  /// The default implementation calls [`IotaIdentityClient::get_alias_output`] for each identifier in turn.
+  /// Implementers should override it if they are able to fetch multiple outputs at once.
+  async fn get_alias_outputs(&self, alias_ids: &[AliasId]) -> Result<Vec<(OutputId, AliasOutput)>> {
+    let mut outputs: Vec<(OutputId, AliasOutput)> = Vec::with_capacity(alias_ids.len());
+    for alias_id in alias_ids {
+      outputs.push(self.get_alias_output(*alias_id).await?);
+    }
+    Ok(outputs)
+  }
 }
 
 /// An extension trait that provides helper functions for publication
@@ -93,15 +124,7 @@ pub trait IotaIdentityClientExt: IotaIdentityClient {
     let id: AliasId = AliasId::from(document.id());
     let (_, alias_output) = self.get_alias_output(id).await?;
 
-    let mut alias_output_builder: AliasOutputBuilder = AliasOutputBuilder::from(&alias_output)
-      .with_state_index(alias_output.state_index() + 1)
-      .with_state_metadata(document.pack()?);
-
-    if alias_output.alias_id().is_null() {
-      alias_output_builder = alias_output_builder.with_alias_id(id);
-    }
-
-    alias_output_builder.finish().map_err(Error::AliasOutputBuildError)
+    build_update_output(id, &alias_output, document)
   }
 
   /// Removes the DID document from the state metadata of its Alias Output,
@@ -131,6 +154,109 @@ pub trait IotaIdentityClientExt: IotaIdentityClient {
     alias_output_builder.finish().map_err(Error::AliasOutputBuildError)
   }
 
+  /// Fetches the Alias Output of a DID deactivated with [`IotaIdentityClientExt::deactivate_did_output`] and
+  /// re-activates it by setting `document` as its state metadata.
+  ///
+  /// The storage deposit on the output is left unchanged. If it was reallocated after the deactivation, the amount
+  /// must be increased manually to cover the document.
+  ///
+  /// NOTE: this does *not* publish the updated Alias Output.
+  ///
+  /// # Errors
+  ///
+  /// - Returns `Err` when failing to resolve the DID of `document`.
+  /// - [`Error::DIDNotDeactivated`] if the published document is not deactivated.
+  async fn reactivate_did_output(&self, document: IotaDocument) -> Result<AliasOutput> {
+    let id: AliasId = AliasId::from(document.id());
+    let (_, alias_output) = self.get_alias_output(id).await?;
+
+    if !alias_output.state_metadata().is_empty() {
+      return Err(Error::DIDNotDeactivated(document.id().clone()));
+    }
+
+    build_update_output(id, &alias_output, document)
+  }
+
+  /// Create a Basic Output depositing `notification` in the inbox of the given `did`.
+  ///
+  /// The output is unlockable by the Alias Output of the DID, so only its state controller can
+  /// acknowledge it. `sender` is set as the Sender Feature, authenticating the notification, and
+  /// receives the storage deposit back once the notification is acknowledged. If the notification
+  /// expires, the sender may also reclaim the output after expiration.
+  ///
+  /// NOTE: This does *not* publish the Basic Output. It must be published by `sender`.
+  ///
+  /// # Errors
+  ///
+  /// - [`Error::NetworkMismatch`] if the network of the DID and client differ.
+  /// - [`Error::BasicOutputBuildError`] when building the Basic Output fails.
+  async fn new_notification_output(
+    &self,
+    sender: Address,
+    did: &IotaDID,
+    notification: &DIDNotification,
+  ) -> Result<BasicOutput> {
+    validate_network(self, did).await?;
+
+    let parameters: ProtocolParameters = self.get_protocol_parameters().await?;
+    let inbox: Address = Address::Alias(AliasAddress::new(AliasId::from(did)));
+    let metadata: MetadataFeature = MetadataFeature::new(notification.pack(StateMetadataEncoding::default())?)
+      .map_err(Error::BasicOutputBuildError)?;
+
+    let build_output = |return_amount: u64| -> Result<BasicOutput> {
+      let mut builder = BasicOutputBuilder::new_with_minimum_storage_deposit(*parameters.rent_structure())
+        .add_feature(Feature::Sender(SenderFeature::new(sender)))
+        .add_feature(Feature::Metadata(metadata.clone()))
+        .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(inbox)))
+        .add_unlock_condition(UnlockCondition::StorageDepositReturn(
+          StorageDepositReturnUnlockCondition::new(sender, return_amount, parameters.token_supply())
+            .map_err(Error::BasicOutputBuildError)?,
+        ));
+      if let Some(expires) = notification.expires() {
+        let timestamp: u32 = u32::try_from(expires.to_unix())
+          .map_err(|_| Error::InvalidNotification("expiration out of range for an unlock condition"))?;
+        builder = builder.add_unlock_condition(UnlockCondition::Expiration(
+          ExpirationUnlockCondition::new(sender, timestamp).map_err(Error::BasicOutputBuildError)?,
+        ));
+      }
+      builder.finish().map_err(Error::BasicOutputBuildError)
+    };
+
+    // The return amount does not affect the size of the output, so the minimum storage deposit computed
+    // with a placeholder amount is also the minimum with the full deposit being returned to the sender.
+    let amount: u64 = build_output(1)?.amount();
+    build_output(amount)
+  }
+
+  /// Create a Basic Output publishing `entry` in the credential registry of the given `did`.
+  ///
+  /// The output is held by the Alias Output of the DID and carries its alias address as Sender Feature,
+  /// authenticating the entry as published by the DID's state controller. The minimum required storage deposit
+  /// is set according to the rent structure of the network.
+  ///
+  /// NOTE: This does *not* publish the Basic Output. It must be published in the same block as a state
+  /// transition of the DID's Alias Output, e.g. with `IotaClientExt::publish_registry_entries`.
+  ///
+  /// # Errors
+  ///
+  /// - [`Error::NetworkMismatch`] if the network of the DID and client differ.
+  /// - [`Error::BasicOutputBuildError`] when building the Basic Output fails.
+  async fn new_registry_entry_output(&self, did: &IotaDID, entry: &CredentialRegistryEntry) -> Result<BasicOutput> {
+    validate_network(self, did).await?;
+
+    let rent_structure: RentStructure = self.get_rent_structure().await?;
+    let issuer: Address = Address::Alias(AliasAddress::new(AliasId::from(did)));
+    let metadata: MetadataFeature =
+      MetadataFeature::new(entry.pack(StateMetadataEncoding::default())?).map_err(Error::BasicOutputBuildError)?;
+
+    BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+      .add_feature(Feature::Sender(SenderFeature::new(issuer)))
+      .add_feature(Feature::Metadata(metadata))
+      .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(issuer)))
+      .finish()
+      .map_err(Error::BasicOutputBuildError)
+  }
+
   /// Resolve a [`IotaDocument`]. Returns an empty, deactivated document if the state metadata
   /// of the Alias Output is empty.
   ///
@@ -146,6 +272,103 @@ pub trait IotaIdentityClientExt: IotaIdentityClient {
     IotaDocument::unpack_from_output(did, &alias_output, true)
   }
 
+  /// Resolve a [`IotaDocument`] like [`IotaIdentityClientExt::resolve_did`], failing instead of returning an empty
+  /// document if the DID is deactivated.
+  ///
+  /// # Errors
+  ///
+  /// - Returns `Err` when failing to resolve the `did`.
+  /// - [`Error::DIDDeactivated`] if the DID is deactivated.
+  async fn resolve_active_did(&self, did: &IotaDID) -> Result<IotaDocument> {
+    let document: IotaDocument = self.resolve_did(did).await?;
+    if document.metadata.deactivated.unwrap_or_default() {
+      return Err(Error::DIDDeactivated(did.clone()));
+    }
+    Ok(document)
+  }
+
+  /// Resolve the [`IotaDocument`]s of multiple DIDs, returned in the order of `dids`.
+  ///
+  /// Unlike calling [`IotaIdentityClientExt::resolve_did`] for every DID, the network is validated only once and
+  /// the Alias Outputs of all distinct DIDs are fetched together through [`IotaIdentityClient::get_alias_outputs`].
+  ///
+  /// # Errors
+  ///
+  /// - [`NetworkMismatch`](Error::NetworkMismatch) if the network of any DID and the client differ.
+  /// - [`NotFound`](iota_sdk::client::Error::NoOutput) if the Alias Output of any DID was not found.
+  async fn resolve_dids(&self, dids: &[IotaDID]) -> Result<Vec<IotaDocument>> {
+    let network_hrp: String = self.get_network_hrp().await?;
+    for did in dids {
+      check_network(did, &network_hrp)?;
+    }
+
+    let mut alias_ids: Vec<AliasId> = Vec::with_capacity(dids.len());
+    for alias_id in dids.iter().map(AliasId::from) {
+      if !alias_ids.contains(&alias_id) {
+        alias_ids.push(alias_id);
+      }
+    }
+    let alias_outputs: HashMap<AliasId, AliasOutput> = alias_ids
+      .iter()
+      .copied()
+      .zip(
+        self
+          .get_alias_outputs(&alias_ids)
+          .await?
+          .into_iter()
+          .map(|(_, alias_output)| alias_output),
+      )
+      .collect();
+
+    let mut documents: Vec<IotaDocument> = Vec::with_capacity(dids.len());
+    for did in dids {
+      let alias_id: AliasId = AliasId::from(did);
+      let document: IotaDocument = match alias_outputs.get(&alias_id) {
+        Some(alias_output) => IotaDocument::unpack_from_output(did, alias_output, true)?,
+        // Only reachable if an implementation returned fewer outputs than requested.
+        None => IotaDocument::unpack_from_output(did, &self.get_alias_output(alias_id).await?.1, true)?,
+      };
+      documents.push(document);
+    }
+    Ok(documents)
+  }
+
+  /// Resolve only the metadata of a [`IotaDocument`], together with its version, without deserializing the
+  /// document itself.
+  ///
+  /// The whole Alias Output still has to be fetched, as the document is stored in its state metadata.
+  ///
+  /// # Errors
+  ///
+  /// - [`NetworkMismatch`](Error::NetworkMismatch) if the network of the DID and client differ.
+  /// - [`NotFound`](iota_sdk::client::Error::NoOutput) if the associated Alias Output was not found.
+  async fn resolve_did_summary(&self, did: &IotaDID) -> Result<IotaDocumentSummary> {
+    validate_network(self, did).await?;
+
+    let id: AliasId = AliasId::from(did);
+    let (_, alias_output) = self.get_alias_output(id).await?;
+    IotaDocumentSummary::unpack_from_output(did, &alias_output)
+  }
+
+  /// Resolve only the services of a [`IotaDocument`], without deserializing the rest of the document.
+  ///
+  /// Returns an empty set if the document is deactivated.
+  ///
+  /// # Errors
+  ///
+  /// - [`NetworkMismatch`](Error::NetworkMismatch) if the network of the DID and client differ.
+  /// - [`NotFound`](iota_sdk::client::Error::NoOutput) if the associated Alias Output was not found.
+  async fn resolve_did_services(&self, did: &IotaDID) -> Result<OrderedSet<Service>> {
+    validate_network(self, did).await?;
+
+    let id: AliasId = AliasId::from(did);
+    let (_, alias_output) = self.get_alias_output(id).await?;
+    if alias_output.state_metadata().is_empty() {
+      return Ok(OrderedSet::new());
+    }
+    StateMetadataDocument::unpack_services(alias_output.state_metadata(), did)
+  }
+
   /// Fetches the [`AliasOutput`] associated with the given DID.
   ///
   /// # Errors
@@ -159,6 +382,44 @@ pub trait IotaIdentityClientExt: IotaIdentityClient {
     self.get_alias_output(id).await.map(|(_, alias_output)| alias_output)
   }
 
+  /// Estimates the costs of `operation` under the current rent structure of the network, e.g. to show them to users
+  /// or to fund their accounts before creating or updating a DID.
+  ///
+  /// Transactions are feeless, so the costs consist of the storage deposit of the Alias Output after the operation,
+  /// see [`DIDOperationCosts`].
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` when failing to fetch the rent structure or to resolve the DID of `operation`.
+  async fn estimate_costs(&self, operation: &DIDOperation) -> Result<DIDOperationCosts> {
+    let rent_structure: RentStructure = self.get_rent_structure().await?;
+
+    match operation {
+      DIDOperation::Create(document) => {
+        // All kinds of addresses have the same length, so the storage deposit does not depend on the controller.
+        let address: Address = Address::Ed25519(Ed25519Address::new([0; 32]));
+        let alias_output: AliasOutput = self
+          .new_did_output(address, document.clone(), Some(rent_structure))
+          .await?;
+        DIDOperationCosts::new(&alias_output, 0, rent_structure)
+      }
+      DIDOperation::Update(document) => {
+        let id: AliasId = AliasId::from(document.id());
+        let (_, alias_output) = self.get_alias_output(id).await?;
+        let updated: AliasOutput = build_update_output(id, &alias_output, document.clone())?;
+        DIDOperationCosts::new(&updated, alias_output.amount(), rent_structure)
+      }
+      DIDOperation::Deactivate(did) => {
+        let (_, alias_output) = self.get_alias_output(AliasId::from(did)).await?;
+        let deactivated: AliasOutput = AliasOutputBuilder::from(&alias_output)
+          .with_state_metadata(Vec::new())
+          .finish()
+          .map_err(Error::AliasOutputBuildError)?;
+        DIDOperationCosts::new(&deactivated, alias_output.amount(), rent_structure)
+      }
+    }
+  }
+
   /// Returns the network name of the client, which is the
   /// Bech32 human-readable part (HRP) of the network.
   ///
@@ -207,11 +468,33 @@ where
     .get_protocol_parameters()
     .await
     .map(|parameters| parameters.bech32_hrp().to_string())?;
-  if did.network_str() != network_hrp.as_str() {
+  check_network(did, &network_hrp)
+}
+
+/// Checks that the network of `did` matches the `network_hrp` of the client.
+pub(super) fn check_network(did: &IotaDID, network_hrp: &str) -> Result<()> {
+  if did.network_str() != network_hrp {
     return Err(Error::NetworkMismatch {
       expected: did.network_str().to_owned(),
-      actual: network_hrp,
+      actual: network_hrp.to_owned(),
     });
   };
   Ok(())
 }
+
+/// Builds the next state of `alias_output` with `document` in its state metadata.
+pub(super) fn build_update_output(
+  id: AliasId,
+  alias_output: &AliasOutput,
+  document: IotaDocument,
+) -> Result<AliasOutput> {
+  let mut alias_output_builder: AliasOutputBuilder = AliasOutputBuilder::from(alias_output)
+    .with_state_index(alias_output.state_index() + 1)
+    .with_state_metadata(document.pack()?);
+
+  if alias_output.alias_id().is_null() {
+    alias_output_builder = alias_output_builder.with_alias_id(id);
+  }
+
+  alias_output_builder.finish().map_err(Error::AliasOutputBuildError)
+}
@@ -2,29 +2,55 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::ops::Deref;
+use std::str::FromStr;
 
+use futures::StreamExt;
+use identity_core::common::NetworkTime;
+use identity_core::common::Timestamp;
 use iota_sdk::client::api::input_selection::Burn;
+use iota_sdk::client::node_api::indexer::query_parameters::QueryParameter;
 use iota_sdk::client::secret::SecretManager;
 use iota_sdk::client::Client;
+use iota_sdk::types::block::address::ToBech32Ext;
 use iota_sdk::types::block::protocol::ProtocolParameters;
 
+use crate::anchor::ANCHOR_TAG;
 use crate::block::address::Address;
+use crate::block::address::AliasAddress;
 use crate::block::output::unlock_condition::AddressUnlockCondition;
 use crate::block::output::AliasId;
 use crate::block::output::AliasOutput;
+use crate::block::output::AliasOutputBuilder;
+use crate::block::output::BasicOutput;
 use crate::block::output::BasicOutputBuilder;
 use crate::block::output::Output;
 use crate::block::output::OutputId;
 use crate::block::output::UnlockCondition;
+use crate::block::payload::transaction::TransactionEssence;
+use crate::block::payload::Payload;
 use crate::block::Block;
+use crate::block::BlockId;
+use crate::client::did_batch::DIDBatchChunk;
+use crate::client::did_batch::MAX_OUTPUTS_PER_TRANSACTION;
+use crate::client::identity_client::build_update_output;
+use crate::client::identity_client::check_network;
 use crate::client::identity_client::validate_network;
 use crate::error::Result;
+use crate::AnchorBatch;
+use crate::CredentialRegistryEntry;
+use crate::DIDBatch;
+use crate::DIDBatchItem;
+use crate::DIDNotification;
+use crate::DIDUpdateOutcome;
+use crate::DIDUpdateProgress;
+use crate::DIDUpdateSummary;
 use crate::Error;
 use crate::IotaDID;
 use crate::IotaDocument;
 use crate::IotaIdentityClient;
 use crate::IotaIdentityClientExt;
 use crate::NetworkName;
+use crate::TimestampProof;
 
 /// An extension trait for [`Client`] that provides helper functions for publication
 /// and deletion of DID documents in Alias Outputs.
@@ -51,6 +77,118 @@ pub trait IotaClientExt: IotaIdentityClient {
   ///
   /// This destroys the Alias Output and DID document, rendering them permanently unrecoverable.
   async fn delete_did_output(&self, secret_manager: &SecretManager, address: Address, did: &IotaDID) -> Result<()>;
+
+  /// Returns the notifications currently held in the inbox of the given `did`, together with the
+  /// ids of the outputs containing them and their authenticated senders.
+  ///
+  /// Outputs addressed to the DID that do not contain a valid notification or have no Sender Feature
+  /// are skipped.
+  ///
+  /// This requires the node to expose the indexer plugin.
+  async fn notifications(&self, did: &IotaDID) -> Result<Vec<(OutputId, Address, DIDNotification)>>;
+
+  /// Acknowledge the notifications contained in the outputs with the given `output_ids`, removing them
+  /// from the inbox of `did`. The storage deposit of each notification is returned to its sender,
+  /// which serves as the acknowledgement receipt.
+  ///
+  /// This is also how notifications are deleted: a notification is only removed from the inbox by
+  /// consuming its output, and the ledger forces the storage deposit back to the sender whenever that
+  /// happens. Any output held by the inbox can be consumed this way, including ones that
+  /// [`IotaClientExt::notifications`] skips.
+  ///
+  /// Note that only the state controller of the DID's Alias Output is allowed to acknowledge
+  /// notifications, since doing so requires a state transition of the Alias Output.
+  ///
+  /// This method modifies the on-ledger state.
+  async fn acknowledge_notifications(
+    &self,
+    secret_manager: &SecretManager,
+    did: &IotaDID,
+    output_ids: &[OutputId],
+  ) -> Result<()>;
+
+  /// Publish `entries` in the credential registry of the given `did`, creating an output for each entry with
+  /// [`IotaIdentityClientExt::new_registry_entry_output`] in a state transition of the DID's Alias Output.
+  ///
+  /// Note that only the state controller of an Alias Output is allowed to publish registry entries. The storage
+  /// deposits of the entries are funded by the `secret_manager`.
+  ///
+  /// This method modifies the on-ledger state.
+  async fn publish_registry_entries(
+    &self,
+    secret_manager: &SecretManager,
+    did: &IotaDID,
+    entries: &[CredentialRegistryEntry],
+  ) -> Result<()>;
+
+  /// Returns the entries currently published in the credential registry of the given `did`, together with the
+  /// ids of the outputs containing them.
+  ///
+  /// Outputs held by the DID that were not created by it or do not contain a valid entry are skipped.
+  ///
+  /// This requires the node to expose the indexer plugin.
+  async fn credential_registry(&self, did: &IotaDID) -> Result<Vec<(OutputId, CredentialRegistryEntry)>>;
+
+  /// Returns a [`NetworkTime`] sampled from the timestamp of the latest milestone known to the node.
+  ///
+  /// Can be used as the [`Clock`](identity_core::common::Clock) during validation instead of the local system time,
+  /// or to detect a drift of the local clock with [`NetworkTime::check_drift`].
+  async fn network_time(&self) -> Result<NetworkTime>;
+
+  /// Anchor the root of `batch` on the ledger and return a [`TimestampProof`] for each digest of the batch, in the
+  /// same order.
+  ///
+  /// The root is published in the tagged data payload of a block, so anchoring requires no funds. The timestamp of
+  /// the proofs is the one of the milestone referencing the block, which attests the time of issuance independently
+  /// of the `issuanceDate` claimed by a credential.
+  ///
+  /// This method modifies the on-ledger state.
+  async fn anchor_batch(&self, batch: &AnchorBatch) -> Result<Vec<TimestampProof>>;
+
+  /// Verify that `digest`, e.g. the [`CredentialContentHash`](identity_credential::credential::CredentialContentHash)
+  /// of a credential, was anchored as stated by `proof`, returning the time at which it was anchored.
+  ///
+  /// The block and milestone of the proof are fetched from the node, which must keep them available: nodes that
+  /// prune old blocks cannot verify proofs anchored before their pruning index.
+  async fn verify_timestamp_proof(&self, digest: &[u8; 32], proof: &TimestampProof) -> Result<Timestamp>;
+
+  /// Publish the DID outputs of `batch` that are not published yet with the provided `secret_manager`, recording
+  /// the outcome of each output in the batch.
+  ///
+  /// The storage deposits of the outputs are first split off the
+  /// [`DIDBatchOptions::funding_address`](crate::DIDBatchOptions::funding_address) into one funding output per
+  /// transaction. The transactions are then submitted concurrently, with at most
+  /// [`DIDBatchOptions::concurrency`](crate::DIDBatchOptions::concurrency) in flight.
+  ///
+  /// Fails only if funding the transactions fails. Failures of individual transactions are recorded as
+  /// [`DIDBatchItem::Failed`](crate::DIDBatchItem::Failed) and retried when publishing the batch again.
+  ///
+  /// This method modifies the on-ledger state.
+  async fn publish_did_batch(&self, secret_manager: &SecretManager, batch: &mut DIDBatch) -> Result<()>;
+
+  /// Applies `update` to the DID documents of all `dids` and publishes each updated document in its own
+  /// transaction, calling `progress` after each DID.
+  ///
+  /// The current Alias Outputs of all DIDs are fetched first, and each update is applied to and consumes the output
+  /// of this snapshot. An update therefore either succeeds as a whole or leaves the DID unchanged, and fails if the
+  /// DID was updated by someone else in the meantime. A failing DID does not stop the remaining ones from being
+  /// updated, see the returned [`DIDUpdateSummary`].
+  ///
+  /// This method modifies the on-ledger state.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the Alias Outputs of the snapshot cannot be fetched, in which case no DID is updated.
+  async fn update_dids<F, P>(
+    &self,
+    secret_manager: &SecretManager,
+    dids: &[IotaDID],
+    update: F,
+    progress: P,
+  ) -> Result<DIDUpdateSummary>
+  where
+    F: FnMut(&mut IotaDocument) -> Result<()> + Send,
+    P: FnMut(DIDUpdateProgress<'_>) + Send;
 }
 
 #[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
@@ -110,6 +248,403 @@ impl IotaClientExt for Client {
 
     Ok(())
   }
+
+  async fn notifications(&self, did: &IotaDID) -> Result<Vec<(OutputId, Address, DIDNotification)>> {
+    validate_network(self, did).await?;
+
+    let inbox = AliasAddress::new(AliasId::from(did))
+      .try_to_bech32(did.network_str())
+      .map_err(|err| Error::DIDResolutionError(err.into()))?;
+    let output_ids: Vec<OutputId> = self
+      .basic_output_ids([QueryParameter::Address(inbox)])
+      .await
+      .map_err(Error::DIDResolutionError)?
+      .items;
+
+    let outputs = self
+      .get_outputs(&output_ids)
+      .await
+      .map_err(Error::DIDResolutionError)?
+      .into_iter()
+      .map(|output| (*output.metadata().output_id(), output.into_output()));
+
+    Ok(inbox_notifications(outputs))
+  }
+
+  async fn acknowledge_notifications(
+    &self,
+    secret_manager: &SecretManager,
+    did: &IotaDID,
+    output_ids: &[OutputId],
+  ) -> Result<()> {
+    validate_network(self, did).await?;
+
+    let alias_id: AliasId = AliasId::from(did);
+    let (alias_output_id, alias_output) = self.get_alias_output(alias_id).await?;
+    let token_supply: u64 = self.deref().get_token_supply().await.map_err(Error::TokenSupplyError)?;
+
+    let mut notification_outputs: Vec<BasicOutput> = Vec::with_capacity(output_ids.len());
+    for output_id in output_ids {
+      let Output::Basic(notification_output) = self
+        .get_output(output_id)
+        .await
+        .map_err(Error::DIDResolutionError)?
+        .into_output()
+      else {
+        return Err(Error::InvalidNotification("output is not a basic output"));
+      };
+      notification_outputs.push(notification_output);
+    }
+    let outputs: Vec<Output> = acknowledgement_outputs(alias_id, &alias_output, &notification_outputs, token_supply)?;
+
+    let mut block_builder = self
+      .build_block()
+      .with_secret_manager(secret_manager)
+      .with_input(alias_output_id.into())
+      .map_err(|err| Error::DIDUpdateError("acknowledge_notifications: invalid block input", Some(Box::new(err))))?;
+    for output_id in output_ids {
+      block_builder = block_builder
+        .with_input((*output_id).into())
+        .map_err(|err| Error::DIDUpdateError("acknowledge_notifications: invalid block input", Some(Box::new(err))))?;
+    }
+    let block: Block = block_builder
+      .with_outputs(outputs)
+      .map_err(|err| Error::DIDUpdateError("acknowledge_notifications: invalid block output", Some(Box::new(err))))?
+      .finish()
+      .await
+      .map_err(|err| Error::DIDUpdateError("acknowledge_notifications: publish failed", Some(Box::new(err))))?;
+    let _ = self
+      .retry_until_included(&block.id(), None, None)
+      .await
+      .map_err(|err| {
+        Error::DIDUpdateError(
+          "acknowledge_notifications: publish retry failed or timed-out",
+          Some(Box::new(err)),
+        )
+      })?;
+
+    Ok(())
+  }
+
+  async fn publish_registry_entries(
+    &self,
+    secret_manager: &SecretManager,
+    did: &IotaDID,
+    entries: &[CredentialRegistryEntry],
+  ) -> Result<()> {
+    validate_network(self, did).await?;
+
+    let alias_id: AliasId = AliasId::from(did);
+    let (_, alias_output) = self.get_alias_output(alias_id).await?;
+    let token_supply: u64 = self.deref().get_token_supply().await.map_err(Error::TokenSupplyError)?;
+
+    let mut alias_output_builder: AliasOutputBuilder =
+      AliasOutputBuilder::from(&alias_output).with_state_index(alias_output.state_index() + 1);
+    if alias_output.alias_id().is_null() {
+      alias_output_builder = alias_output_builder.with_alias_id(alias_id);
+    }
+    let mut outputs: Vec<Output> = vec![alias_output_builder
+      .finish_output(token_supply)
+      .map_err(Error::AliasOutputBuildError)?];
+    for entry in entries {
+      outputs.push(Output::Basic(self.new_registry_entry_output(did, entry).await?));
+    }
+
+    let block: Block = self
+      .build_block()
+      .with_secret_manager(secret_manager)
+      .with_outputs(outputs)
+      .map_err(|err| Error::DIDUpdateError("publish_registry_entries: invalid block output", Some(Box::new(err))))?
+      .finish()
+      .await
+      .map_err(|err| Error::DIDUpdateError("publish_registry_entries: publish failed", Some(Box::new(err))))?;
+    let _ = self
+      .retry_until_included(&block.id(), None, None)
+      .await
+      .map_err(|err| {
+        Error::DIDUpdateError(
+          "publish_registry_entries: publish retry failed or timed-out",
+          Some(Box::new(err)),
+        )
+      })?;
+
+    Ok(())
+  }
+
+  async fn credential_registry(&self, did: &IotaDID) -> Result<Vec<(OutputId, CredentialRegistryEntry)>> {
+    validate_network(self, did).await?;
+
+    let issuer: Address = Address::Alias(AliasAddress::new(AliasId::from(did)));
+    let issuer_bech32 = issuer
+      .try_to_bech32(did.network_str())
+      .map_err(|err| Error::DIDResolutionError(err.into()))?;
+    let output_ids: Vec<OutputId> = self
+      .basic_output_ids([
+        QueryParameter::Address(issuer_bech32.clone()),
+        QueryParameter::Sender(issuer_bech32),
+      ])
+      .await
+      .map_err(Error::DIDResolutionError)?
+      .items;
+
+    let entries = self
+      .get_outputs(&output_ids)
+      .await
+      .map_err(Error::DIDResolutionError)?
+      .into_iter()
+      .filter_map(|output| {
+        let output_id: OutputId = *output.metadata().output_id();
+        match output.into_output() {
+          Output::Basic(basic_output) => CredentialRegistryEntry::unpack_from_output(&issuer, &basic_output)
+            .ok()
+            .map(|entry| (output_id, entry)),
+          _ => None,
+        }
+      })
+      .collect();
+
+    Ok(entries)
+  }
+
+  async fn publish_did_batch(&self, secret_manager: &SecretManager, batch: &mut DIDBatch) -> Result<()> {
+    let token_supply: u64 = self.deref().get_token_supply().await.map_err(Error::TokenSupplyError)?;
+    let network: NetworkName = self.network_name().await?;
+
+    // Split off a funding output for each transaction, leaving room for the remainder in each split transaction.
+    let unfunded: Vec<usize> = (0..batch.chunks.len())
+      .filter(|index| {
+        let chunk: &DIDBatchChunk = &batch.chunks[*index];
+        chunk.funding.is_none() && !batch.is_chunk_published(chunk)
+      })
+      .collect();
+    for group in unfunded.chunks(MAX_OUTPUTS_PER_TRANSACTION - 1) {
+      let outputs: Vec<Output> = group
+        .iter()
+        .map(|index| {
+          BasicOutputBuilder::new_with_amount(batch.chunk_amount(&batch.chunks[*index]))
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(
+              batch.options.funding_address,
+            )))
+            .finish_output(token_supply)
+            .map_err(Error::BasicOutputBuildError)
+        })
+        .collect::<Result<_>>()?;
+
+      let block: Block = self
+        .build_block()
+        .with_secret_manager(secret_manager)
+        .with_outputs(outputs.clone())
+        .map_err(|err| Error::DIDUpdateError("publish_did_batch: invalid funding output", Some(Box::new(err))))?
+        .finish()
+        .await
+        .map_err(|err| Error::DIDUpdateError("publish_did_batch: funding failed", Some(Box::new(err))))?;
+      let _ = self
+        .retry_until_included(&block.id(), None, None)
+        .await
+        .map_err(|err| {
+          Error::DIDUpdateError(
+            "publish_did_batch: funding retry failed or timed-out",
+            Some(Box::new(err)),
+          )
+        })?;
+
+      for (index, output_id) in group.iter().zip(funding_output_ids(&block, &outputs)?) {
+        batch.chunks[*index].funding = Some(output_id);
+      }
+    }
+
+    // Publish the DID outputs of each transaction, consuming only its funding output.
+    let network: &NetworkName = &network;
+    let transactions = batch
+      .chunks
+      .iter()
+      .enumerate()
+      .filter(|(_, chunk)| !batch.is_chunk_published(chunk))
+      .filter_map(|(index, chunk)| {
+        let funding: OutputId = chunk.funding?;
+        let outputs: Vec<Output> = chunk
+          .indices
+          .iter()
+          .map(|index| Output::Alias(batch.outputs[*index].clone()))
+          .collect();
+        Some(async move {
+          let result = async {
+            let block: Block = self
+              .build_block()
+              .with_secret_manager(secret_manager)
+              .with_input(funding.into())
+              .map_err(|err| Error::DIDUpdateError("publish_did_batch: invalid block input", Some(Box::new(err))))?
+              .with_outputs(outputs)
+              .map_err(|err| Error::DIDUpdateError("publish_did_batch: invalid block output", Some(Box::new(err))))?
+              .finish()
+              .await
+              .map_err(|err| Error::DIDUpdateError("publish_did_batch: publish failed", Some(Box::new(err))))?;
+            let _ = self
+              .retry_until_included(&block.id(), None, None)
+              .await
+              .map_err(|err| {
+                Error::DIDUpdateError(
+                  "publish_did_batch: publish retry failed or timed-out",
+                  Some(Box::new(err)),
+                )
+              })?;
+            IotaDocument::unpack_from_block(network, &block)
+          }
+          .await;
+          (index, result)
+        })
+      });
+    let results: Vec<(usize, Result<Vec<IotaDocument>>)> = futures::stream::iter(transactions)
+      .buffer_unordered(batch.options.concurrency.max(1))
+      .collect()
+      .await;
+
+    for (chunk_index, result) in results {
+      let indices: Vec<usize> = batch.chunks[chunk_index].indices.clone();
+      match result {
+        Ok(documents) if documents.len() == indices.len() => {
+          for (index, document) in indices.into_iter().zip(documents) {
+            batch.items[index] = DIDBatchItem::Published(document);
+          }
+        }
+        Ok(_) => {
+          for index in indices {
+            batch.items[index] = DIDBatchItem::Failed(Error::DIDUpdateError(
+              "publish_did_batch: unexpected number of documents in published block",
+              None,
+            ));
+          }
+        }
+        Err(error) => {
+          // The error is only reported once, the other outputs of the same transaction refer to it.
+          let mut error: Option<Error> = Some(error);
+          for index in indices {
+            batch.items[index] = DIDBatchItem::Failed(error.take().unwrap_or(Error::DIDUpdateError(
+              "publish_did_batch: another output of the same transaction failed",
+              None,
+            )));
+          }
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  async fn update_dids<F, P>(
+    &self,
+    secret_manager: &SecretManager,
+    dids: &[IotaDID],
+    mut update: F,
+    mut progress: P,
+  ) -> Result<DIDUpdateSummary>
+  where
+    F: FnMut(&mut IotaDocument) -> Result<()> + Send,
+    P: FnMut(DIDUpdateProgress<'_>) + Send,
+  {
+    let network: NetworkName = self.network_name().await?;
+    for did in dids {
+      check_network(did, network.as_ref())?;
+    }
+    let alias_ids: Vec<AliasId> = dids.iter().map(AliasId::from).collect();
+    let snapshot: Vec<(OutputId, AliasOutput)> = self.get_alias_outputs(&alias_ids).await?;
+
+    let mut summary: DIDUpdateSummary = DIDUpdateSummary::default();
+    for (index, (did, (output_id, alias_output))) in dids.iter().zip(snapshot).enumerate() {
+      let outcome: DIDUpdateOutcome = match update_from_snapshot(
+        self,
+        secret_manager,
+        &network,
+        did,
+        output_id,
+        &alias_output,
+        &mut update,
+      )
+      .await
+      {
+        Ok((block_id, document)) => DIDUpdateOutcome::Published { block_id, document },
+        Err(error) => DIDUpdateOutcome::Failed(error),
+      };
+      progress(DIDUpdateProgress {
+        did,
+        outcome: &outcome,
+        completed: index + 1,
+        total: dids.len(),
+      });
+      summary.outcomes.push((did.clone(), outcome));
+    }
+
+    Ok(summary)
+  }
+
+  async fn network_time(&self) -> Result<NetworkTime> {
+    let milestone_timestamp: u32 = self
+      .get_info()
+      .await
+      .map_err(|err| Error::NetworkTimeError("network_time: failed to fetch node info", Some(err)))?
+      .node_info
+      .status
+      .latest_milestone
+      .timestamp
+      .ok_or(Error::NetworkTimeError(
+        "network_time: node reported no milestone timestamp",
+        None,
+      ))?;
+    let network_time: Timestamp = Timestamp::from_unix(milestone_timestamp.into())
+      .map_err(|_| Error::NetworkTimeError("network_time: invalid milestone timestamp", None))?;
+
+    Ok(NetworkTime::sample(network_time))
+  }
+
+  async fn anchor_batch(&self, batch: &AnchorBatch) -> Result<Vec<TimestampProof>> {
+    let block: Block = self
+      .build_block()
+      .with_tag(ANCHOR_TAG.to_vec())
+      .with_data(batch.pack())
+      .finish()
+      .await
+      .map_err(|err| Error::AnchorError("anchor_batch: publish failed", Some(Box::new(err))))?;
+    let block_id: BlockId = block.id();
+    let _ = self
+      .retry_until_included(&block_id, None, None)
+      .await
+      .map_err(|err| Error::AnchorError("anchor_batch: publish retry failed or timed-out", Some(Box::new(err))))?;
+    let (milestone_index, timestamp) = referencing_milestone(self, &block_id).await?;
+
+    Ok(
+      (0..batch.len())
+        .filter_map(|index| batch.inclusion_proof(index))
+        .map(|inclusion_proof| TimestampProof::new(block_id.to_string(), milestone_index, timestamp, inclusion_proof))
+        .collect(),
+    )
+  }
+
+  async fn verify_timestamp_proof(&self, digest: &[u8; 32], proof: &TimestampProof) -> Result<Timestamp> {
+    let root: [u8; 32] = proof.inclusion_proof().root(digest)?;
+    let block_id: BlockId =
+      BlockId::from_str(proof.block_id()).map_err(|_| Error::InvalidAnchor("invalid block id"))?;
+    let block: Block = self
+      .get_block(&block_id)
+      .await
+      .map_err(|err| Error::AnchorError("verify_timestamp_proof: failed to fetch block", Some(Box::new(err))))?;
+
+    let anchored_root: [u8; 32] = match block.payload() {
+      Some(Payload::TaggedData(payload)) if payload.tag() == ANCHOR_TAG => AnchorBatch::unpack_root(payload.data())?,
+      _ => return Err(Error::InvalidAnchor("block does not contain an anchor")),
+    };
+    if anchored_root != root {
+      return Err(Error::InvalidAnchor("digest is not part of the anchored batch"));
+    }
+
+    let (milestone_index, timestamp) = referencing_milestone(self, &block_id).await?;
+    if milestone_index != proof.milestone_index() || timestamp != proof.timestamp() {
+      return Err(Error::InvalidAnchor(
+        "anchor is not referenced by the milestone of the proof",
+      ));
+    }
+
+    Ok(timestamp)
+  }
 }
 
 #[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
@@ -137,6 +672,63 @@ impl IotaIdentityClient for Client {
       Err(Error::NotAnAliasOutput(output_id))
     }
   }
+
+  async fn get_alias_outputs(&self, alias_ids: &[AliasId]) -> Result<Vec<(OutputId, AliasOutput)>> {
+    let output_ids: Vec<OutputId> =
+      futures::future::try_join_all(alias_ids.iter().map(|alias_id| self.alias_output_id(*alias_id)))
+        .await
+        .map_err(Error::DIDResolutionError)?;
+
+    self
+      .get_outputs(&output_ids)
+      .await
+      .map_err(Error::DIDResolutionError)?
+      .into_iter()
+      .map(|output| {
+        let output_id: OutputId = *output.metadata().output_id();
+        match output.into_output() {
+          Output::Alias(alias_output) => Ok((output_id, alias_output)),
+          _ => Err(Error::NotAnAliasOutput(output_id)),
+        }
+      })
+      .collect()
+  }
+}
+
+/// Returns the ids of `outputs` in the transaction of `block`, in the same order.
+fn funding_output_ids(block: &Block, outputs: &[Output]) -> Result<Vec<OutputId>> {
+  let Some(Payload::Transaction(tx_payload)) = block.payload() else {
+    return Err(Error::DIDUpdateError(
+      "publish_did_batch: funding block has no transaction",
+      None,
+    ));
+  };
+  let TransactionEssence::Regular(regular) = tx_payload.essence();
+
+  let mut claimed: Vec<bool> = vec![false; regular.outputs().len()];
+  outputs
+    .iter()
+    .map(|output| {
+      let index: usize = regular
+        .outputs()
+        .iter()
+        .enumerate()
+        .find(|(index, candidate)| !claimed[*index] && *candidate == output)
+        .map(|(index, _)| index)
+        .ok_or(Error::DIDUpdateError(
+          "publish_did_batch: funding output missing from funding transaction",
+          None,
+        ))?;
+      claimed[index] = true;
+      OutputId::new(
+        tx_payload.id(),
+        index
+          .try_into()
+          .map_err(|_| Error::OutputIdConversionError(format!("output index {index} must fit into a u16")))?,
+      )
+      .map_err(|err| Error::OutputIdConversionError(err.to_string()))
+    })
+    .collect()
 }
 
 /// Publishes an `alias_output`.
@@ -157,3 +749,285 @@ async fn publish_output(
 
   Ok(block)
 }
+
+/// Applies `update` to the document in `alias_output` and publishes it, consuming the output with `output_id`.
+async fn update_from_snapshot<F>(
+  client: &Client,
+  secret_manager: &SecretManager,
+  network: &NetworkName,
+  did: &IotaDID,
+  output_id: OutputId,
+  alias_output: &AliasOutput,
+  update: &mut F,
+) -> Result<(BlockId, IotaDocument)>
+where
+  F: FnMut(&mut IotaDocument) -> Result<()>,
+{
+  let mut document: IotaDocument = IotaDocument::unpack_from_output(did, alias_output, true)?;
+  update(&mut document)?;
+  let updated: AliasOutput = build_update_output(AliasId::from(did), alias_output, document)?;
+
+  let block: Block = client
+    .build_block()
+    .with_secret_manager(secret_manager)
+    .with_input(output_id.into())
+    .map_err(|err| Error::DIDUpdateError("update_dids: invalid block input", Some(Box::new(err))))?
+    .with_outputs(vec![updated.into()])
+    .map_err(|err| Error::DIDUpdateError("update_dids: invalid block output", Some(Box::new(err))))?
+    .finish()
+    .await
+    .map_err(|err| Error::DIDUpdateError("update_dids: publish failed", Some(Box::new(err))))?;
+  let _ = client
+    .retry_until_included(&block.id(), None, None)
+    .await
+    .map_err(|err| Error::DIDUpdateError("update_dids: publish retry failed or timed-out", Some(Box::new(err))))?;
+
+  let document: IotaDocument = IotaDocument::unpack_from_block(network, &block)?
+    .into_iter()
+    .next()
+    .ok_or(Error::DIDUpdateError(
+      "update_dids: no document found in published block",
+      None,
+    ))?;
+  Ok((block.id(), document))
+}
+
+/// Returns the notifications contained in `outputs` of the inbox of a DID, skipping outputs without a valid
+/// notification or sender.
+fn inbox_notifications(
+  outputs: impl IntoIterator<Item = (OutputId, Output)>,
+) -> Vec<(OutputId, Address, DIDNotification)> {
+  outputs
+    .into_iter()
+    .filter_map(|(output_id, output)| match output {
+      Output::Basic(basic_output) => {
+        let sender: Address = DIDNotification::sender_from_output(&basic_output).ok()?;
+        let notification: DIDNotification = DIDNotification::unpack_from_output(&basic_output).ok()?;
+        Some((output_id, sender, notification))
+      }
+      _ => None,
+    })
+    .collect()
+}
+
+/// Returns the outputs of the transaction consuming `notification_outputs` from the inbox of the DID with
+/// `alias_id`: the next state of `alias_output`, followed by the storage deposit returns of the notifications.
+///
+/// Any amount of a notification exceeding the deposit to be returned is kept by the Alias Output.
+fn acknowledgement_outputs(
+  alias_id: AliasId,
+  alias_output: &AliasOutput,
+  notification_outputs: &[BasicOutput],
+  token_supply: u64,
+) -> Result<Vec<Output>> {
+  let mut outputs: Vec<Output> = Vec::with_capacity(notification_outputs.len() + 1);
+  let mut retained_amount: u64 = 0;
+  for notification_output in notification_outputs {
+    match notification_output.unlock_conditions().storage_deposit_return() {
+      Some(deposit_return) => {
+        outputs.push(
+          BasicOutputBuilder::new_with_amount(deposit_return.amount())
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(
+              *deposit_return.return_address(),
+            )))
+            .finish_output(token_supply)
+            .map_err(Error::BasicOutputBuildError)?,
+        );
+        retained_amount += notification_output.amount() - deposit_return.amount();
+      }
+      None => retained_amount += notification_output.amount(),
+    }
+  }
+
+  let mut alias_output_builder: AliasOutputBuilder = AliasOutputBuilder::from(alias_output)
+    .with_amount(alias_output.amount() + retained_amount)
+    .with_state_index(alias_output.state_index() + 1);
+  if alias_output.alias_id().is_null() {
+    alias_output_builder = alias_output_builder.with_alias_id(alias_id);
+  }
+  outputs.insert(
+    0,
+    alias_output_builder
+      .finish_output(token_supply)
+      .map_err(Error::AliasOutputBuildError)?,
+  );
+
+  Ok(outputs)
+}
+
+/// Returns the index and timestamp of the milestone referencing the block with `block_id`.
+async fn referencing_milestone(client: &Client, block_id: &BlockId) -> Result<(u32, Timestamp)> {
+  let milestone_index: u32 = client
+    .get_block_metadata(block_id)
+    .await
+    .map_err(|err| Error::AnchorError("failed to fetch block metadata", Some(Box::new(err))))?
+    .referenced_by_milestone_index
+    .ok_or(Error::AnchorError("block is not referenced by a milestone", None))?;
+  let milestone_timestamp: u32 = client
+    .get_milestone_by_index(milestone_index)
+    .await
+    .map_err(|err| Error::AnchorError("failed to fetch milestone", Some(Box::new(err))))?
+    .essence()
+    .timestamp();
+  let timestamp: Timestamp = Timestamp::from_unix(milestone_timestamp.into())
+    .map_err(|_| Error::InvalidAnchor("invalid milestone timestamp"))?;
+
+  Ok((milestone_index, timestamp))
+}
+
+// The `test` feature disables the blanket `IotaIdentityClientExt` implementation the tests rely on.
+#[cfg(all(test, not(feature = "test")))]
+mod tests {
+  use crate::block::address::Ed25519Address;
+  use crate::block::output::feature::SenderFeature;
+  use crate::block::output::unlock_condition::GovernorAddressUnlockCondition;
+  use crate::block::output::unlock_condition::StateControllerAddressUnlockCondition;
+  use crate::block::output::Feature;
+  use crate::block::payload::transaction::TransactionId;
+
+  use super::*;
+
+  struct NodeClient;
+
+  #[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+  #[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+  impl IotaIdentityClient for NodeClient {
+    async fn get_alias_output(&self, _alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+      unimplemented!("not needed to build notification outputs")
+    }
+
+    async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
+      Ok(ProtocolParameters::default())
+    }
+  }
+
+  fn did() -> IotaDID {
+    let network = NetworkName::try_from(ProtocolParameters::default().bech32_hrp().to_string()).unwrap();
+    IotaDID::new(&[1; 32], &network)
+  }
+
+  fn output_id(index: u16) -> OutputId {
+    OutputId::new(TransactionId::new([2; 32]), index).unwrap()
+  }
+
+  fn alias_output(did: &IotaDID, amount: u64) -> AliasOutput {
+    let address = Address::Ed25519(Ed25519Address::new([3; 32]));
+    AliasOutputBuilder::new_with_amount(amount, AliasId::from(did))
+      .with_state_index(1)
+      .add_unlock_condition(UnlockCondition::StateControllerAddress(
+        StateControllerAddressUnlockCondition::new(address),
+      ))
+      .add_unlock_condition(UnlockCondition::GovernorAddress(GovernorAddressUnlockCondition::new(
+        address,
+      )))
+      .finish()
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_new_notification_output() {
+    let did: IotaDID = did();
+    let sender = Address::Ed25519(Ed25519Address::new([4; 32]));
+    let mut notification = DIDNotification::new("your accreditation expires soon");
+    notification.set_expires(Some(Timestamp::from_unix(2_000_000_000).unwrap()));
+
+    let output: BasicOutput = NodeClient
+      .new_notification_output(sender, &did, &notification)
+      .await
+      .unwrap();
+    let unlock_conditions = output.unlock_conditions();
+    assert_eq!(
+      unlock_conditions.address().unwrap().address(),
+      &Address::Alias(AliasAddress::new(AliasId::from(&did)))
+    );
+    let deposit_return = unlock_conditions.storage_deposit_return().unwrap();
+    assert_eq!(deposit_return.return_address(), &sender);
+    assert_eq!(deposit_return.amount(), output.amount());
+    assert_eq!(unlock_conditions.expiration().unwrap().timestamp(), 2_000_000_000);
+    assert_eq!(DIDNotification::sender_from_output(&output).unwrap(), sender);
+    assert_eq!(DIDNotification::unpack_from_output(&output).unwrap(), notification);
+
+    // INVALID: the DID belongs to another network.
+    let other: IotaDID = IotaDID::new(&[1; 32], &NetworkName::try_from("other").unwrap());
+    assert!(matches!(
+      NodeClient.new_notification_output(sender, &other, &notification).await,
+      Err(Error::NetworkMismatch { .. })
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_inbox_notifications() {
+    let did: IotaDID = did();
+    let sender = Address::Ed25519(Ed25519Address::new([4; 32]));
+    let notification = DIDNotification::new("hello");
+    let output: BasicOutput = NodeClient
+      .new_notification_output(sender, &did, &notification)
+      .await
+      .unwrap();
+    let unsigned: BasicOutput = BasicOutputBuilder::from(&output)
+      .with_features([Feature::Metadata(output.features().metadata().unwrap().clone())])
+      .finish()
+      .unwrap();
+    let not_a_notification: BasicOutput = BasicOutputBuilder::from(&output)
+      .with_features([Feature::Sender(SenderFeature::new(sender))])
+      .finish()
+      .unwrap();
+
+    let notifications = inbox_notifications([
+      (output_id(0), Output::Basic(output)),
+      (output_id(1), Output::Basic(unsigned)),
+      (output_id(2), Output::Basic(not_a_notification)),
+      (output_id(3), Output::Alias(alias_output(&did, 1_000_000))),
+    ]);
+    assert_eq!(notifications, [(output_id(0), sender, notification)]);
+  }
+
+  #[tokio::test]
+  async fn test_acknowledgement_outputs() {
+    let did: IotaDID = did();
+    let token_supply: u64 = ProtocolParameters::default().token_supply();
+    let sender = Address::Ed25519(Ed25519Address::new([4; 32]));
+    let alias_output: AliasOutput = alias_output(&did, 1_000_000);
+
+    let notification: BasicOutput = NodeClient
+      .new_notification_output(sender, &did, &DIDNotification::new("hello"))
+      .await
+      .unwrap();
+    let deposit: u64 = notification.amount();
+    // A notification carrying more than the deposit to be returned.
+    let overfunded: BasicOutput = BasicOutputBuilder::from(&notification)
+      .with_amount(deposit + 5)
+      .finish()
+      .unwrap();
+    // An output without a storage deposit return, e.g. spam.
+    let unreturned: BasicOutput = BasicOutputBuilder::new_with_amount(7)
+      .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(Address::Alias(
+        AliasAddress::new(AliasId::from(&did)),
+      ))))
+      .finish()
+      .unwrap();
+
+    let outputs: Vec<Output> = acknowledgement_outputs(
+      AliasId::from(&did),
+      &alias_output,
+      &[notification, overfunded, unreturned],
+      token_supply,
+    )
+    .unwrap();
+    assert_eq!(outputs.len(), 3);
+
+    let Output::Alias(acknowledged) = &outputs[0] else {
+      panic!("the first output must be the alias output");
+    };
+    assert_eq!(acknowledged.state_index(), alias_output.state_index() + 1);
+    assert_eq!(acknowledged.amount(), alias_output.amount() + 5 + 7);
+
+    for output in &outputs[1..] {
+      let Output::Basic(returned) = output else {
+        panic!("deposit returns must be basic outputs");
+      };
+      assert_eq!(returned.amount(), deposit);
+      assert_eq!(returned.unlock_conditions().address().unwrap().address(), &sender);
+    }
+  }
+}
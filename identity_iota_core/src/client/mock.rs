@@ -0,0 +1,365 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+
+use crate::block::output::AliasId;
+use crate::block::output::AliasOutput;
+use crate::block::output::OutputId;
+use crate::block::payload::transaction::TransactionId;
+use crate::block::protocol::ProtocolParameters;
+use crate::Error;
+use crate::IotaDID;
+use crate::IotaDocument;
+use crate::IotaIdentityClient;
+use crate::NetworkName;
+use crate::Result;
+
+/// An operation of a [`MockClient`] that can be made to fail with [`MockClient::fail_next`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MockOperation {
+  /// [`IotaIdentityClient::get_alias_output`].
+  GetAliasOutput,
+  /// [`IotaIdentityClient::get_protocol_parameters`].
+  GetProtocolParameters,
+  /// [`MockClient::publish_did_output`].
+  Publish,
+  /// [`MockClient::delete_did_output`].
+  Delete,
+}
+
+/// An event emitted by a [`MockClient`], see [`MockClient::events`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MockEvent {
+  /// The Alias Output with the given id was requested.
+  AliasOutputRequested(AliasId),
+  /// The protocol parameters were requested.
+  ProtocolParametersRequested,
+  /// An Alias Output was published as the output with the given id.
+  AliasOutputPublished {
+    /// The id of the Alias Output.
+    alias_id: AliasId,
+    /// The id of the published output.
+    output_id: OutputId,
+  },
+  /// The Alias Output with the given id was destroyed.
+  AliasOutputDestroyed(AliasId),
+  /// An injected failure was returned for the given operation.
+  Failed(MockOperation),
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+  alias_outputs: HashMap<AliasId, (OutputId, AliasOutput)>,
+  history: HashMap<AliasId, Vec<(OutputId, AliasOutput)>>,
+  failures: HashMap<MockOperation, VecDeque<String>>,
+  events: Vec<MockEvent>,
+  transactions: u64,
+}
+
+/// An in-memory [`IotaIdentityClient`] for unit tests of publication and resolution logic, which run without a node.
+///
+/// Alias Outputs published with [`MockClient::publish_did_output`] are kept in memory and returned by
+/// [`IotaIdentityClient::get_alias_output`], so the [`IotaIdentityClientExt`](crate::IotaIdentityClientExt) methods
+/// work as against a node. Output ids, and thereby the DIDs of new documents, are derived from a counter and are
+/// the same in every run. Every published version of an Alias Output is kept, such that documents can be resolved
+/// at earlier states with [`MockClient::resolve_did_at`]. Every request is recorded as a [`MockEvent`], and failures
+/// can be injected with [`MockClient::fail_next`].
+///
+/// Requires the `test-utils` feature.
+#[derive(Debug)]
+pub struct MockClient {
+  protocol_parameters: ProtocolParameters,
+  state: Mutex<MockState>,
+}
+
+impl MockClient {
+  /// Creates a new [`MockClient`] with the default protocol parameters.
+  pub fn new() -> Self {
+    Self::with_protocol_parameters(ProtocolParameters::default())
+  }
+
+  /// Creates a new [`MockClient`] with the given `protocol_parameters`.
+  pub fn with_protocol_parameters(protocol_parameters: ProtocolParameters) -> Self {
+    Self {
+      protocol_parameters,
+      state: Mutex::new(MockState::default()),
+    }
+  }
+
+  /// Returns the network name of the protocol parameters.
+  pub fn network_name(&self) -> Result<NetworkName> {
+    NetworkName::try_from(self.protocol_parameters.bech32_hrp().to_string())
+  }
+
+  /// Makes the next call of `operation` fail with [`Error::MockClientError`] containing `message`.
+  ///
+  /// Failures are queued, such that calling this `n` times makes the next `n` calls of `operation` fail.
+  pub fn fail_next(&self, operation: MockOperation, message: impl Into<String>) {
+    self
+      .lock()
+      .failures
+      .entry(operation)
+      .or_default()
+      .push_back(message.into());
+  }
+
+  /// Returns the events emitted so far, in order.
+  pub fn events(&self) -> Vec<MockEvent> {
+    self.lock().events.clone()
+  }
+
+  /// Removes all events emitted so far.
+  pub fn clear_events(&self) {
+    self.lock().events.clear();
+  }
+
+  /// Publishes `alias_output` and returns the contained document, like
+  /// `IotaClientExt::publish_did_output` does on a node.
+  ///
+  /// An Alias Output without an alias id creates a new DID, derived from the id of the published output. Otherwise
+  /// the output must be a state transition of the published Alias Output, i.e. increment its state index by one.
+  ///
+  /// # Errors
+  ///
+  /// [`Error::MockClientError`] if the Alias Output to transition does not exist, the state index is not
+  /// incremented, or a failure was injected.
+  pub fn publish_did_output(&self, alias_output: AliasOutput) -> Result<IotaDocument> {
+    let network: NetworkName = self.network_name()?;
+    let mut state = self.lock();
+    state.take_failure(MockOperation::Publish)?;
+
+    let output_id: OutputId = state.next_output_id()?;
+    let alias_id: AliasId = if alias_output.alias_id().is_null() {
+      AliasId::from(&output_id)
+    } else {
+      let alias_id: AliasId = *alias_output.alias_id();
+      let (_, published) = state
+        .alias_outputs
+        .get(&alias_id)
+        .ok_or_else(|| Error::MockClientError(format!("no alias output with id {alias_id}")))?;
+      if alias_output.state_index() != published.state_index() + 1 {
+        return Err(Error::MockClientError(format!(
+          "state index {} does not follow the published state index {}",
+          alias_output.state_index(),
+          published.state_index()
+        )));
+      }
+      alias_id
+    };
+
+    let did: IotaDID = IotaDID::new(&alias_id, &network);
+    let document: IotaDocument = IotaDocument::unpack_from_output(&did, &alias_output, true)?;
+    state
+      .history
+      .entry(alias_id)
+      .or_default()
+      .push((output_id, alias_output.clone()));
+    state.alias_outputs.insert(alias_id, (output_id, alias_output));
+    state
+      .events
+      .push(MockEvent::AliasOutputPublished { alias_id, output_id });
+    Ok(document)
+  }
+
+  /// Destroys the Alias Output of `did`, like `IotaClientExt::delete_did_output` does on a node.
+  ///
+  /// The DID can no longer be resolved or updated, but its earlier states remain available through
+  /// [`MockClient::resolve_did_at`].
+  ///
+  /// # Errors
+  ///
+  /// [`Error::MockClientError`] if the Alias Output does not exist or a failure was injected.
+  pub fn delete_did_output(&self, did: &IotaDID) -> Result<()> {
+    let mut state = self.lock();
+    state.take_failure(MockOperation::Delete)?;
+
+    let alias_id: AliasId = AliasId::from(did);
+    state
+      .alias_outputs
+      .remove(&alias_id)
+      .ok_or_else(|| Error::MockClientError(format!("no alias output with id {alias_id}")))?;
+    state.events.push(MockEvent::AliasOutputDestroyed(alias_id));
+    Ok(())
+  }
+
+  /// Returns every Alias Output published for `alias_id` with its output id, oldest first, including the ones that
+  /// were transitioned or destroyed since.
+  pub fn alias_output_history(&self, alias_id: &AliasId) -> Vec<(OutputId, AliasOutput)> {
+    self.lock().history.get(alias_id).cloned().unwrap_or_default()
+  }
+
+  /// Returns the document of `did` as it was when its Alias Output had the given `state_index`.
+  ///
+  /// # Errors
+  ///
+  /// [`Error::MockClientError`] if no Alias Output of `did` with `state_index` was published.
+  pub fn resolve_did_at(&self, did: &IotaDID, state_index: u32) -> Result<IotaDocument> {
+    let alias_output: AliasOutput = self
+      .alias_output_history(&AliasId::from(did))
+      .into_iter()
+      .map(|(_, alias_output)| alias_output)
+      .find(|alias_output| alias_output.state_index() == state_index)
+      .ok_or_else(|| Error::MockClientError(format!("no alias output of {did} with state index {state_index}")))?;
+    IotaDocument::unpack_from_output(did, &alias_output, true)
+  }
+
+  fn lock(&self) -> MutexGuard<'_, MockState> {
+    self.state.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+impl Default for MockClient {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl MockState {
+  fn take_failure(&mut self, operation: MockOperation) -> Result<()> {
+    match self.failures.get_mut(&operation).and_then(VecDeque::pop_front) {
+      Some(message) => {
+        self.events.push(MockEvent::Failed(operation));
+        Err(Error::MockClientError(message))
+      }
+      None => Ok(()),
+    }
+  }
+
+  fn next_output_id(&mut self) -> Result<OutputId> {
+    self.transactions += 1;
+    let mut transaction_id: [u8; TransactionId::LENGTH] = [0; TransactionId::LENGTH];
+    transaction_id[TransactionId::LENGTH - 8..].copy_from_slice(&self.transactions.to_be_bytes());
+    OutputId::new(TransactionId::new(transaction_id), 0).map_err(|err| Error::OutputIdConversionError(err.to_string()))
+  }
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+impl IotaIdentityClient for MockClient {
+  async fn get_alias_output(&self, alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+    let mut state = self.lock();
+    state.take_failure(MockOperation::GetAliasOutput)?;
+    state.events.push(MockEvent::AliasOutputRequested(alias_id));
+    state
+      .alias_outputs
+      .get(&alias_id)
+      .cloned()
+      .ok_or_else(|| Error::MockClientError(format!("no alias output with id {alias_id}")))
+  }
+
+  async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
+    let mut state = self.lock();
+    state.take_failure(MockOperation::GetProtocolParameters)?;
+    state.events.push(MockEvent::ProtocolParametersRequested);
+    Ok(self.protocol_parameters.clone())
+  }
+}
+
+// The `test` feature disables the blanket `IotaIdentityClientExt` implementation the tests rely on.
+#[cfg(all(test, not(feature = "test")))]
+mod tests {
+  use crate::block::address::Address;
+  use crate::block::address::Ed25519Address;
+  use crate::IotaIdentityClientExt;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_publish_and_resolve() {
+    let client = MockClient::new();
+    let address = Address::Ed25519(Ed25519Address::new([1; 32]));
+    let network: NetworkName = client.network_name().unwrap();
+
+    let alias_output: AliasOutput = client
+      .new_did_output(address, IotaDocument::new(&network), None)
+      .await
+      .unwrap();
+    let document: IotaDocument = client.publish_did_output(alias_output).unwrap();
+    // Output ids and therefore DIDs are deterministic.
+    assert_eq!(
+      document.id(),
+      MockClient::new()
+        .publish_did_output(
+          client
+            .new_did_output(address, IotaDocument::new(&network), None)
+            .await
+            .unwrap()
+        )
+        .unwrap()
+        .id()
+    );
+    assert_eq!(client.resolve_did(document.id()).await.unwrap(), document);
+
+    let update: AliasOutput = client.update_did_output(document.clone()).await.unwrap();
+    client.publish_did_output(update.clone()).unwrap();
+    // Publishing the same state transition again fails, as on the ledger.
+    assert!(matches!(
+      client.publish_did_output(update),
+      Err(Error::MockClientError(_))
+    ));
+
+    let alias_id: AliasId = AliasId::from(document.id());
+    assert!(client.events().contains(&MockEvent::AliasOutputRequested(alias_id)));
+  }
+
+  #[tokio::test]
+  async fn test_history_and_delete() {
+    let client = MockClient::new();
+    let address = Address::Ed25519(Ed25519Address::new([1; 32]));
+    let network: NetworkName = client.network_name().unwrap();
+
+    let alias_output: AliasOutput = client
+      .new_did_output(address, IotaDocument::new(&network), None)
+      .await
+      .unwrap();
+    let created: IotaDocument = client.publish_did_output(alias_output).unwrap();
+    let did: IotaDID = created.id().clone();
+
+    let mut document: IotaDocument = client.resolve_did(&did).await.unwrap();
+    document
+      .also_known_as_mut()
+      .append(identity_core::common::Url::parse("did:example:123").unwrap());
+    let updated: IotaDocument = client
+      .publish_did_output(client.update_did_output(document).await.unwrap())
+      .unwrap();
+
+    let alias_id: AliasId = AliasId::from(&did);
+    assert_eq!(client.alias_output_history(&alias_id).len(), 2);
+    assert_eq!(client.resolve_did_at(&did, 0).unwrap().also_known_as().len(), 0);
+    assert_eq!(client.resolve_did_at(&did, 1).unwrap(), updated);
+    assert!(client.resolve_did_at(&did, 2).is_err());
+
+    client.delete_did_output(&did).unwrap();
+    assert!(client.resolve_did(&did).await.is_err());
+    assert!(matches!(client.delete_did_output(&did), Err(Error::MockClientError(_))));
+    // Earlier states remain available.
+    assert_eq!(client.resolve_did_at(&did, 1).unwrap(), updated);
+    assert!(client.events().contains(&MockEvent::AliasOutputDestroyed(alias_id)));
+  }
+
+  #[tokio::test]
+  async fn test_fail_next() {
+    let client = MockClient::new();
+    client.fail_next(MockOperation::GetProtocolParameters, "node unavailable");
+
+    assert!(matches!(
+      client.get_protocol_parameters().await,
+      Err(Error::MockClientError(message)) if message == "node unavailable"
+    ));
+    assert!(client.get_protocol_parameters().await.is_ok());
+    assert_eq!(
+      client.events(),
+      [
+        MockEvent::Failed(MockOperation::GetProtocolParameters),
+        MockEvent::ProtocolParametersRequested
+      ]
+    );
+  }
+}
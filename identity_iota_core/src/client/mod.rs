@@ -1,12 +1,43 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "iota-client")]
+pub use did_batch::DIDBatch;
+#[cfg(feature = "iota-client")]
+pub use did_batch::DIDBatchItem;
+#[cfg(feature = "iota-client")]
+pub use did_batch::DIDBatchOptions;
+pub use did_costs::DIDOperation;
+pub use did_costs::DIDOperationCosts;
+#[cfg(feature = "iota-client")]
+pub use did_update::DIDUpdateOutcome;
+#[cfg(feature = "iota-client")]
+pub use did_update::DIDUpdateProgress;
+#[cfg(feature = "iota-client")]
+pub use did_update::DIDUpdateSummary;
+pub use fixture::ClientFixture;
+pub use fixture::RecordingClient;
+pub use fixture::ReplayClient;
 pub use identity_client::IotaIdentityClient;
 pub use identity_client::IotaIdentityClientExt;
+#[cfg(feature = "test-utils")]
+pub use mock::MockClient;
+#[cfg(feature = "test-utils")]
+pub use mock::MockEvent;
+#[cfg(feature = "test-utils")]
+pub use mock::MockOperation;
 
 #[cfg(feature = "iota-client")]
 pub use self::iota_client::IotaClientExt;
 
+#[cfg(feature = "iota-client")]
+mod did_batch;
+mod did_costs;
+#[cfg(feature = "iota-client")]
+mod did_update;
+mod fixture;
 mod identity_client;
 #[cfg(feature = "iota-client")]
 mod iota_client;
+#[cfg(feature = "test-utils")]
+mod mock;
@@ -8,6 +8,7 @@ use identity_credential::credential::Jws;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
 use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jose::jws::AsyncJwsVerifier;
 use identity_verification::jose::jws::DecodedJws;
 use identity_verification::jose::jws::JwsVerifier;
 use serde::Deserialize;
@@ -19,6 +20,7 @@ use identity_core::common::OrderedSet;
 use identity_core::common::Url;
 use identity_core::convert::FmtJson;
 use identity_document::document::CoreDocument;
+use identity_document::document::DocumentUpdate;
 use identity_document::service::Service;
 use identity_document::utils::DIDUrlQuery;
 use identity_verification::MethodRelationship;
@@ -32,6 +34,7 @@ use crate::IotaDocumentMetadata;
 use crate::NetworkName;
 use crate::StateMetadataDocument;
 use crate::StateMetadataEncoding;
+use crate::StateMetadataSize;
 
 /// Struct used internally when deserializing [`IotaDocument`].
 #[derive(Debug, Deserialize)]
@@ -164,6 +167,36 @@ impl IotaDocument {
     self.document.also_known_as_mut()
   }
 
+  /// Adds `url` to the `alsoKnownAs` set.
+  ///
+  /// Returns `false` if the set already contains `url`.
+  ///
+  /// # Errors
+  /// Fails with [`Error::InvalidAlsoKnownAs`] if `url` refers to this document's DID, which cannot identify another
+  /// name of its subject, or has a fragment, which identifies a resource rather than a subject.
+  pub fn insert_also_known_as(&mut self, url: Url) -> Result<bool> {
+    if url.fragment().is_some() {
+      return Err(Error::InvalidAlsoKnownAs("the URL must not have a fragment"));
+    }
+    let own_did: String = self.id().to_string();
+    if let Some(suffix) = url.as_str().strip_prefix(own_did.as_str()) {
+      if suffix.is_empty() || suffix.starts_with(['/', '?', '#']) {
+        return Err(Error::InvalidAlsoKnownAs(
+          "the URL must not refer to the document's own DID",
+        ));
+      }
+    }
+
+    Ok(self.document.also_known_as_mut().append(url))
+  }
+
+  /// Removes `url` from the `alsoKnownAs` set.
+  ///
+  /// Returns `false` if the set did not contain `url`.
+  pub fn remove_also_known_as(&mut self, url: &Url) -> bool {
+    self.document.also_known_as_mut().remove(url).is_some()
+  }
+
   /// Returns a reference to the underlying [`CoreDocument`].
   pub fn core_document(&self) -> &CoreDocument {
     &self.document
@@ -221,10 +254,26 @@ impl IotaDocument {
     self.core_document_mut().remove_service(did_url)
   }
 
+  /// Starts a [`DocumentUpdate`] of the services, methods and verification relationships of the document.
+  ///
+  /// All changes are validated and applied atomically by [`DocumentUpdate::commit`].
+  pub fn update(&mut self) -> DocumentUpdate<'_> {
+    self.core_document_mut().update()
+  }
+
   // ===========================================================================
   // Verification Methods
   // ===========================================================================
 
+  /// Checks that every embedded verification method is controlled by this document's id or one of its controllers.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error for the first method controlled by another DID.
+  pub fn check_method_controllers(&self) -> Result<()> {
+    self.document.check_method_controllers().map_err(Error::InvalidDoc)
+  }
+
   /// Returns a `Vec` of verification method references whose verification relationship matches `scope`.
   ///
   /// If `scope` is `None`, all **embedded** methods are returned.
@@ -312,6 +361,66 @@ impl IotaDocument {
       .map_err(Error::InvalidDoc)
   }
 
+  /// Attaches all `relationships` to the method resolved by `method_query`.
+  /// Returns `true` if at least one of the relationships was newly attached, `false` otherwise.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the method does not exist or if it is embedded, in which case no relationship is attached.
+  pub fn attach_method_relationships<'query, Q, I>(&mut self, method_query: Q, relationships: I) -> Result<bool>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+    I: IntoIterator<Item = MethodRelationship>,
+  {
+    self
+      .core_document_mut()
+      .attach_method_relationships(method_query, relationships)
+      .map_err(Error::InvalidDoc)
+  }
+
+  /// Detaches all `relationships` from the method resolved by `method_query`.
+  /// Returns `true` if at least one of the relationships was found and removed, `false` otherwise.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the method does not exist or if it is embedded, in which case no relationship is detached.
+  pub fn detach_method_relationships<'query, Q, I>(&mut self, method_query: Q, relationships: I) -> Result<bool>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+    I: IntoIterator<Item = MethodRelationship>,
+  {
+    self
+      .core_document_mut()
+      .detach_method_relationships(method_query, relationships)
+      .map_err(Error::InvalidDoc)
+  }
+
+  /// Moves the [`VerificationMethod`] identified by `did_url` into `scope`.
+  ///
+  /// When moved into [`MethodScope::VerificationMethod`], an embedded method keeps its relationship as a reference.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the method does not exist, or if `scope` is a relationship and the method is referenced by
+  /// another relationship.
+  pub fn move_method(&mut self, did_url: &DIDUrl, scope: MethodScope) -> Result<()> {
+    self
+      .core_document_mut()
+      .move_method(did_url, scope)
+      .map_err(Error::InvalidDoc)
+  }
+
+  /// Returns the verification relationships in which the method identified by `did_url` is embedded or referenced.
+  pub fn method_relationships(&self, did_url: &DIDUrl) -> Vec<MethodRelationship> {
+    self.document.method_relationships(did_url)
+  }
+
+  /// Returns all embedded verification methods paired with the verification relationships in which they are
+  /// embedded or referenced.
+  pub fn methods_with_relationships(&self) -> Vec<(&VerificationMethod, Vec<MethodRelationship>)> {
+    self.document.methods_with_relationships()
+  }
+
   /// Returns the first [`VerificationMethod`] with an `id` property matching the
   /// provided `method_query` and the verification relationship specified by `scope` if present.
   ///
@@ -378,6 +487,24 @@ impl IotaDocument {
       .map_err(Error::JwsVerificationError)
   }
 
+  /// Decodes and verifies the provided JWS according to the passed [`JwsVerificationOptions`] and
+  /// [`AsyncJwsVerifier`], e.g. a remote verification service.
+  ///
+  /// See [`Self::verify_jws`] for the conditions which must be met for a verification attempt to take place.
+  pub async fn verify_jws_async<'jws, T: AsyncJwsVerifier + ?Sized>(
+    &self,
+    jws: &'jws Jws,
+    detached_payload: Option<&'jws [u8]>,
+    signature_verifier: &T,
+    options: &JwsVerificationOptions,
+  ) -> Result<DecodedJws<'jws>> {
+    self
+      .core_document()
+      .verify_jws_async(jws.as_str(), detached_payload, signature_verifier, options)
+      .await
+      .map_err(Error::JwsVerificationError)
+  }
+
   // ===========================================================================
   // Packing
   // ===========================================================================
@@ -389,8 +516,37 @@ impl IotaDocument {
   }
 
   /// Serializes the document for inclusion in an Alias Output's state metadata.
+  ///
+  /// The controller that [`IotaDocument::unpack_from_output`] derives from the state controller of the Alias Output
+  /// is not encoded, so that packing a resolved document does not grow its state metadata.
+  ///
+  /// # Errors
+  /// Fails with [`Error::ReservedPropertyKey`] if a custom property has the same key as a field of the document or
+  /// its metadata.
   pub fn pack_with_encoding(self, encoding: StateMetadataEncoding) -> Result<Vec<u8>> {
-    StateMetadataDocument::from(self).pack(encoding)
+    self.into_state_metadata_document().pack(encoding)
+  }
+
+  /// Returns the size of the document encoded as state metadata with the default [`StateMetadataEncoding`].
+  ///
+  /// Useful to check how much the controller, `alsoKnownAs` set and custom properties contribute to the state
+  /// metadata, whose length determines the storage deposit of the Alias Output and is bounded by
+  /// [`STATE_METADATA_MAX_LENGTH`](crate::STATE_METADATA_MAX_LENGTH).
+  pub fn state_metadata_size(&self) -> Result<StateMetadataSize> {
+    self
+      .clone()
+      .into_state_metadata_document()
+      .size(StateMetadataEncoding::default())
+  }
+
+  /// Transforms the document into the representation that is packed into state metadata.
+  fn into_state_metadata_document(self) -> StateMetadataDocument {
+    #[cfg(feature = "client")]
+    let document: IotaDocument = self.without_state_controller_did();
+    #[cfg(not(feature = "client"))]
+    let document: IotaDocument = self;
+
+    StateMetadataDocument::from(document)
   }
 }
 
@@ -469,6 +625,35 @@ mod client_document {
       Ok(())
     }
 
+    /// Removes the controller that [`IotaDocument::unpack_from_output`] derives from the state controller address
+    /// in the metadata, if it is an Alias Address.
+    pub(super) fn without_state_controller_did(mut self) -> Self {
+      let Some(Address::Alias(alias_address)) = self
+        .metadata
+        .state_controller_address
+        .as_deref()
+        .and_then(|address| Address::try_from_bech32(address).ok())
+      else {
+        return self;
+      };
+      let Ok(network_name) = NetworkName::try_from(self.id().network_str().to_owned()) else {
+        return self;
+      };
+      let state_controller_did: CoreDID = CoreDID::from(IotaDID::new(alias_address.alias_id(), &network_name));
+
+      let controller: Option<OneOrSet<CoreDID>> = self.document.controller().and_then(|controller| {
+        let remaining: Vec<CoreDID> = controller
+          .iter()
+          .filter(|did| **did != state_controller_did)
+          .cloned()
+          .collect();
+        OneOrSet::try_from(remaining).ok()
+      });
+      *self.core_document_mut().controller_mut() = controller;
+
+      self
+    }
+
     /// Returns all DID documents of the Alias Outputs contained in the block's transaction payload
     /// outputs, if any.
     ///
@@ -854,6 +1039,80 @@ mod tests {
     assert_eq!(controllers.len(), 2);
   }
 
+  #[test]
+  fn test_repack_without_state_controller_did() {
+    let document_did: IotaDID = valid_did();
+    let alias_controller: IotaDID = "did:iota:0xBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBB"
+      .parse()
+      .unwrap();
+    let external_controller_did: IotaDID =
+      "did:iota:0xCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCC"
+        .parse()
+        .unwrap();
+
+    let mut original_doc: IotaDocument = IotaDocument::new_with_id(document_did.clone());
+    original_doc.set_controller([external_controller_did.clone()]);
+    let packed: Vec<u8> = original_doc.pack().unwrap();
+
+    let alias_output: AliasOutput = AliasOutputBuilder::new_with_amount(1, AliasId::from(&document_did))
+      .with_state_metadata(packed.clone())
+      .add_unlock_condition(UnlockCondition::StateControllerAddress(
+        StateControllerAddressUnlockCondition::new(Address::Alias(AliasAddress::new(AliasId::from(&alias_controller)))),
+      ))
+      .add_unlock_condition(UnlockCondition::GovernorAddress(GovernorAddressUnlockCondition::new(
+        Address::Alias(AliasAddress::new(AliasId::from(&alias_controller))),
+      )))
+      .finish()
+      .unwrap();
+
+    let document: IotaDocument = IotaDocument::unpack_from_output(&document_did, &alias_output, true).unwrap();
+    assert_eq!(document.controller().count(), 2);
+
+    // The controller derived from the state controller is not encoded again.
+    let repacked: Vec<u8> = document.clone().pack().unwrap();
+    assert_eq!(repacked, packed);
+    assert_eq!(document.state_metadata_size().unwrap().total, packed.len());
+
+    let alias_output: AliasOutput = AliasOutputBuilder::from(&alias_output)
+      .with_state_metadata(repacked)
+      .finish()
+      .unwrap();
+    let resolved: IotaDocument = IotaDocument::unpack_from_output(&document_did, &alias_output, true).unwrap();
+    assert_eq!(resolved, document);
+  }
+
+  #[test]
+  fn test_also_known_as() {
+    let did: IotaDID = valid_did();
+    let mut document: IotaDocument = generate_document(&did);
+    let other: Url = Url::parse("did:example:123").unwrap();
+
+    assert!(document.insert_also_known_as(other.clone()).unwrap());
+    assert!(!document.insert_also_known_as(other.clone()).unwrap());
+    assert_eq!(document.also_known_as().as_slice(), &[other.clone()]);
+
+    // The document's own DID cannot be another name of its subject.
+    for url in [did.to_string(), format!("{did}/path"), format!("{did}?query=1")] {
+      assert!(matches!(
+        document.insert_also_known_as(Url::parse(url).unwrap()),
+        Err(Error::InvalidAlsoKnownAs(_))
+      ));
+    }
+    // URLs with fragments identify resources rather than subjects.
+    assert!(matches!(
+      document.insert_also_known_as(Url::parse("did:example:123#key-1").unwrap()),
+      Err(Error::InvalidAlsoKnownAs(_))
+    ));
+    // DIDs that merely start with the document's DID are distinct.
+    assert!(document
+      .insert_also_known_as(Url::parse(format!("{did}0")).unwrap())
+      .unwrap());
+
+    assert!(document.remove_also_known_as(&other));
+    assert!(!document.remove_also_known_as(&other));
+    assert_eq!(document.also_known_as().len(), 1);
+  }
+
   #[test]
   fn test_unpack_empty() {
     let controller_did: IotaDID = valid_did();
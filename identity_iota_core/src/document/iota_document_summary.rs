@@ -0,0 +1,92 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use identity_core::convert::FmtJson;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::IotaDID;
+use crate::IotaDocumentMetadata;
+
+/// The metadata of a published [`IotaDocument`](crate::IotaDocument), resolved without deserializing the document
+/// itself.
+///
+/// Intended for listing many identities, where resolving full documents is wasteful.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IotaDocumentSummary {
+  id: IotaDID,
+  version: u32,
+  metadata: IotaDocumentMetadata,
+}
+
+impl IotaDocumentSummary {
+  /// Returns the DID of the document.
+  pub fn id(&self) -> &IotaDID {
+    &self.id
+  }
+
+  /// Returns the version of the document, i.e. the state index of its Alias Output.
+  pub fn version(&self) -> u32 {
+    self.version
+  }
+
+  /// Returns the metadata of the document.
+  pub fn metadata(&self) -> &IotaDocumentMetadata {
+    &self.metadata
+  }
+
+  /// Returns whether the document is deactivated.
+  pub fn is_deactivated(&self) -> bool {
+    self.metadata.deactivated.unwrap_or_default()
+  }
+}
+
+impl Display for IotaDocumentSummary {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    self.fmt_json(f)
+  }
+}
+
+#[cfg(feature = "client")]
+mod client_summary {
+  use iota_sdk::types::block::address::Hrp;
+  use iota_sdk::types::block::address::ToBech32Ext;
+
+  use crate::block::output::AliasOutput;
+  use crate::error::Result;
+  use crate::NetworkName;
+  use crate::StateMetadataDocument;
+
+  use super::*;
+
+  impl IotaDocumentSummary {
+    /// Deserializes the metadata of the document contained in an Alias Output.
+    ///
+    /// If the state metadata of the output is empty, the summary of an empty document marked as `deactivated` is
+    /// returned, like [`IotaDocument::unpack_from_output`](crate::IotaDocument::unpack_from_output) does.
+    pub fn unpack_from_output(did: &IotaDID, alias_output: &AliasOutput) -> Result<IotaDocumentSummary> {
+      let mut metadata: IotaDocumentMetadata = if alias_output.state_metadata().is_empty() {
+        let mut metadata: IotaDocumentMetadata = IotaDocumentMetadata::new();
+        metadata.created = None;
+        metadata.updated = None;
+        metadata.deactivated = Some(true);
+        metadata
+      } else {
+        StateMetadataDocument::unpack_metadata(alias_output.state_metadata())?
+      };
+
+      let hrp: Hrp = (&NetworkName::try_from(did.network_str().to_owned())?).try_into()?;
+      metadata.governor_address = Some(alias_output.governor_address().to_bech32(hrp).to_string());
+      metadata.state_controller_address = Some(alias_output.state_controller_address().to_bech32(hrp).to_string());
+
+      Ok(IotaDocumentSummary {
+        id: did.clone(),
+        version: alias_output.state_index(),
+        metadata,
+      })
+    }
+  }
+}
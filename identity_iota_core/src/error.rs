@@ -25,7 +25,7 @@ pub enum Error {
   /// Caused by a client failure during resolution.
   #[error("DID resolution failed")]
   DIDResolutionError(#[source] iota_sdk::client::error::Error),
-  #[cfg(feature = "iota-client")]
+  #[cfg(feature = "client")]
   /// Caused by an error when building a basic output.
   #[error("basic output build error")]
   BasicOutputBuildError(#[source] iota_sdk::types::block::Error),
@@ -51,6 +51,39 @@ pub enum Error {
   /// Caused by an attempt to read state metadata that does not adhere to the IOTA DID method specification.
   #[error("invalid state metadata {0}")]
   InvalidStateMetadata(&'static str),
+  /// Caused by an attempt to read state metadata encoded with a version this crate does not support, e.g. a document
+  /// published by a more recent version of this library.
+  #[error("unsupported state metadata version {found}, the latest supported version is {latest}")]
+  UnsupportedStateMetadataVersion {
+    /// The version found in the state metadata.
+    found: u8,
+    /// The latest version supported by this crate.
+    latest: u8,
+  },
+  /// Caused by a custom property whose key collides with a field of the DID document or its metadata, which would
+  /// be dropped when the document is encoded as state metadata.
+  #[error("the custom property `{0}` collides with a field of the document or its metadata")]
+  ReservedPropertyKey(String),
+  /// Caused by an invalid `alsoKnownAs` entry.
+  #[error("invalid alsoKnownAs entry: {0}")]
+  InvalidAlsoKnownAs(&'static str),
+  #[cfg(feature = "compression")]
+  /// Caused by a failure to compress or decompress state metadata.
+  #[error("state metadata compression: {0}")]
+  CompressionError(&'static str, #[source] Option<std::io::Error>),
+  /// Caused by an attempt to read a notification that does not adhere to the notification encoding.
+  #[error("invalid notification: {0}")]
+  InvalidNotification(&'static str),
+  /// Caused by an attempt to read a credential registry entry that does not adhere to its encoding.
+  #[error("invalid credential registry entry: {0}")]
+  InvalidRegistryEntry(&'static str),
+  /// Caused by an invalid anchor, inclusion proof or timestamp proof.
+  #[error("invalid anchor: {0}")]
+  InvalidAnchor(&'static str),
+  #[cfg(feature = "iota-client")]
+  /// Caused by a client failure while anchoring a batch or verifying a timestamp proof.
+  #[error("anchoring: {0}")]
+  AnchorError(&'static str, #[source] Option<Box<iota_sdk::client::error::Error>>),
   #[cfg(feature = "revocation-bitmap")]
   /// Caused by a failure during (un)revocation of credentials.
   #[error("credential revocation error")]
@@ -73,4 +106,25 @@ pub enum Error {
   /// Caused by an error during JSON Web Signature verification.
   #[error("jws signature verification failed")]
   JwsVerificationError(#[source] identity_document::Error),
+  #[cfg(feature = "iota-client")]
+  /// Caused by a failure to obtain the network time from a node.
+  #[error("network time: {0}")]
+  NetworkTimeError(&'static str, #[source] Option<iota_sdk::client::Error>),
+  #[cfg(feature = "client")]
+  /// Caused by a failure to load, save or replay a [`ClientFixture`](crate::ClientFixture).
+  #[error("client fixture: {0}")]
+  FixtureError(
+    &'static str,
+    #[source] Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  ),
+  #[cfg(feature = "test-utils")]
+  /// Caused by a failure injected into or detected by a [`MockClient`](crate::MockClient).
+  #[error("mock client: {0}")]
+  MockClientError(String),
+  /// Caused by resolving a deactivated DID where an active DID document is required.
+  #[error("the DID `{0}` is deactivated")]
+  DIDDeactivated(crate::IotaDID),
+  /// Caused by reactivating a DID whose document is not deactivated.
+  #[error("the DID `{0}` is not deactivated")]
+  DIDNotDeactivated(crate::IotaDID),
 }
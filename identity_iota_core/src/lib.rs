@@ -23,20 +23,28 @@ pub mod block {
   pub use iota_sdk::types::TryFromDto;
 }
 
+pub use anchor::AnchorBatch;
+pub use anchor::InclusionProof;
+pub use anchor::TimestampProof;
 #[cfg(feature = "client")]
 pub use client::*;
 pub use did::IotaDID;
 pub use document::*;
 pub use network::NetworkName;
+pub use notification::*;
+pub use registry::*;
 pub use state_metadata::*;
 
 pub use self::error::Error;
 pub use self::error::Result;
 
+mod anchor;
 #[cfg(feature = "client")]
 mod client;
 mod did;
 mod document;
 mod error;
 mod network;
+mod notification;
+mod registry;
 mod state_metadata;
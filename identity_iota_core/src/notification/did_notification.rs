@@ -0,0 +1,286 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::convert::FmtJson;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::Error;
+use crate::StateMetadataEncoding;
+
+/// Magic bytes used to mark DID notifications.
+const NOTIFICATION_MARKER: &[u8] = b"NTF";
+
+/// Version of the notification encoding.
+const NOTIFICATION_VERSION_V1: u8 = 1;
+
+/// A small message addressed to the controllers of an IOTA DID.
+///
+/// Notifications are deposited by third parties as Basic Outputs unlockable by the Alias Output of the
+/// DID (see `IotaIdentityClientExt::new_notification_output`). The sender is authenticated by the ledger
+/// through the output's Sender Feature, so the notification itself only carries the message.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DIDNotification {
+  message: String,
+  created: Timestamp,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  expires: Option<Timestamp>,
+  #[serde(flatten)]
+  properties: Object,
+}
+
+impl DIDNotification {
+  /// Creates a new [`DIDNotification`] with the given `message`, created at the current system datetime.
+  pub fn new(message: impl Into<String>) -> Self {
+    Self {
+      message: message.into(),
+      created: Timestamp::now_utc(),
+      expires: None,
+      properties: Object::new(),
+    }
+  }
+
+  /// Returns the message of the notification.
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  /// Returns the time at which the notification was created.
+  pub fn created(&self) -> Timestamp {
+    self.created
+  }
+
+  /// Returns the time after which the notification is no longer relevant, if any.
+  ///
+  /// Notification outputs carrying an expiration can be reclaimed by their sender after this time.
+  pub fn expires(&self) -> Option<Timestamp> {
+    self.expires
+  }
+
+  /// Sets the time after which the notification is no longer relevant.
+  pub fn set_expires(&mut self, expires: Option<Timestamp>) {
+    self.expires = expires;
+  }
+
+  /// Returns a reference to the custom notification properties.
+  pub fn properties(&self) -> &Object {
+    &self.properties
+  }
+
+  /// Returns a mutable reference to the custom notification properties.
+  pub fn properties_mut(&mut self) -> &mut Object {
+    &mut self.properties
+  }
+
+  /// Returns `true` if the notification has expired relative to the current system datetime.
+  pub fn is_expired(&self) -> bool {
+    self.expires.is_some_and(|expires| expires <= Timestamp::now_utc())
+  }
+
+  /// Pack the notification into bytes, suitable for inclusion in the Metadata Feature of an output.
+  ///
+  /// The layout is `[marker, version, encoding, data length, data]`, mirroring the state metadata
+  /// encoding of DID documents.
+  pub fn pack(&self, encoding: StateMetadataEncoding) -> Result<Vec<u8>> {
+    let json: Vec<u8> = self
+      .to_json_vec()
+      .map_err(|err| Error::SerializationError("failed to serialize notification to JSON", Some(err)))?;
+    let mut data: Vec<u8> = encoding.encode(json)?;
+
+    let data_len: u16 =
+      u16::try_from(data.len()).map_err(|_| Error::SerializationError("failed to convert usize to u16", None))?;
+    let data_len_packed: [u8; 2] = data_len.to_le_bytes();
+    let mut buffer: Vec<u8> =
+      Vec::with_capacity(NOTIFICATION_MARKER.len() + 1 + 1 + data_len_packed.len() + data_len as usize);
+    buffer.extend_from_slice(NOTIFICATION_MARKER);
+    buffer.push(NOTIFICATION_VERSION_V1);
+    buffer.push(encoding as u8);
+    buffer.extend_from_slice(&data_len_packed);
+    buffer.append(&mut data);
+    Ok(buffer)
+  }
+
+  /// Unpack bytes into a [`DIDNotification`].
+  pub fn unpack(data: &[u8]) -> Result<Self> {
+    if data.get(0..=2) != Some(NOTIFICATION_MARKER) {
+      return Err(Error::InvalidNotification("missing `NTF` marker"));
+    }
+    if data.get(3) != Some(&NOTIFICATION_VERSION_V1) {
+      return Err(Error::InvalidNotification("unsupported version"));
+    }
+    let encoding: StateMetadataEncoding = data
+      .get(4)
+      .ok_or(Error::InvalidNotification("expected encoding at offset 4"))
+      .and_then(|encoding| {
+        StateMetadataEncoding::try_from(*encoding).map_err(|_| Error::InvalidNotification("unsupported encoding"))
+      })?;
+    let data_len: u16 = data
+      .get(5..=6)
+      .and_then(|len| <[u8; 2]>::try_from(len).ok())
+      .map(u16::from_le_bytes)
+      .ok_or(Error::InvalidNotification("expected data length at offset [5..=6]"))?;
+    let data: &[u8] = data.get(7..(7 + data_len as usize)).ok_or(Error::InvalidNotification(
+      "encoded notification shorter than length prefix",
+    ))?;
+
+    let json = encoding.decode(data)?;
+    DIDNotification::from_json_slice(&json)
+      .map_err(|err| Error::SerializationError("failed to deserialize JSON notification", Some(err)))
+  }
+}
+
+impl Display for DIDNotification {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    self.fmt_json(f)
+  }
+}
+
+#[cfg(feature = "client")]
+mod client_notification {
+  use crate::block::address::Address;
+  use crate::block::output::BasicOutput;
+  use crate::error::Result;
+  use crate::Error;
+
+  use super::DIDNotification;
+
+  impl DIDNotification {
+    /// Deserializes the notification from the Metadata Feature of a Basic Output.
+    pub fn unpack_from_output(basic_output: &BasicOutput) -> Result<DIDNotification> {
+      let metadata = basic_output
+        .features()
+        .metadata()
+        .ok_or(Error::InvalidNotification("output has no metadata feature"))?;
+      DIDNotification::unpack(metadata.data())
+    }
+
+    /// Returns the sender of the notification held in a Basic Output, i.e. the address of its Sender Feature.
+    ///
+    /// The ledger only accepts a Sender Feature whose address unlocked an input of the transaction creating the
+    /// output, so the sender is authenticated.
+    pub fn sender_from_output(basic_output: &BasicOutput) -> Result<Address> {
+      basic_output
+        .features()
+        .sender()
+        .map(|sender| *sender.address())
+        .ok_or(Error::InvalidNotification("output has no sender feature"))
+    }
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use crate::block::address::Ed25519Address;
+    use crate::block::output::feature::MetadataFeature;
+    use crate::block::output::feature::SenderFeature;
+    use crate::block::output::unlock_condition::AddressUnlockCondition;
+    use crate::block::output::BasicOutputBuilder;
+    use crate::block::output::Feature;
+    use crate::block::output::UnlockCondition;
+    use crate::StateMetadataEncoding;
+
+    use super::*;
+
+    #[test]
+    fn test_unpack_from_output() {
+      let sender = Address::Ed25519(Ed25519Address::new([1; 32]));
+      let notification: DIDNotification = DIDNotification::new("hello");
+      let metadata = MetadataFeature::new(notification.pack(StateMetadataEncoding::Json).unwrap()).unwrap();
+      let builder = BasicOutputBuilder::new_with_amount(1).add_unlock_condition(UnlockCondition::Address(
+        AddressUnlockCondition::new(Address::Ed25519(Ed25519Address::new([2; 32]))),
+      ));
+
+      let output: BasicOutput = builder
+        .clone()
+        .add_feature(Feature::Sender(SenderFeature::new(sender)))
+        .add_feature(Feature::Metadata(metadata.clone()))
+        .finish()
+        .unwrap();
+      assert_eq!(DIDNotification::unpack_from_output(&output).unwrap(), notification);
+      assert_eq!(DIDNotification::sender_from_output(&output).unwrap(), sender);
+
+      // INVALID: no sender feature.
+      let output: BasicOutput = builder
+        .clone()
+        .add_feature(Feature::Metadata(metadata))
+        .finish()
+        .unwrap();
+      assert!(matches!(
+        DIDNotification::sender_from_output(&output),
+        Err(Error::InvalidNotification(_))
+      ));
+
+      // INVALID: no metadata feature.
+      let output: BasicOutput = builder.finish().unwrap();
+      assert!(matches!(
+        DIDNotification::unpack_from_output(&output),
+        Err(Error::InvalidNotification(_))
+      ));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Duration;
+  use identity_core::common::Timestamp;
+
+  use super::*;
+
+  #[test]
+  fn test_packing_roundtrip() {
+    let mut notification: DIDNotification = DIDNotification::new("your accreditation expires soon");
+    notification.set_expires(Timestamp::now_utc().checked_add(Duration::days(30)));
+    notification
+      .properties_mut()
+      .insert("accreditation".to_owned(), "ISO-9001".into());
+
+    let packed: Vec<u8> = notification.pack(StateMetadataEncoding::Json).unwrap();
+    assert_eq!(&packed[0..3], NOTIFICATION_MARKER);
+    assert_eq!(packed[3], NOTIFICATION_VERSION_V1);
+    assert_eq!(packed[4], StateMetadataEncoding::Json as u8);
+
+    let unpacked: DIDNotification = DIDNotification::unpack(&packed).unwrap();
+    assert_eq!(unpacked, notification);
+    assert!(!unpacked.is_expired());
+  }
+
+  #[test]
+  fn test_unpack_invalid() {
+    let notification: DIDNotification = DIDNotification::new("hello");
+    let packed: Vec<u8> = notification.pack(StateMetadataEncoding::Json).unwrap();
+
+    // INVALID: wrong marker, e.g. a DID document.
+    let mut invalid: Vec<u8> = packed.clone();
+    invalid[0..3].copy_from_slice(b"DID");
+    assert!(matches!(
+      DIDNotification::unpack(&invalid),
+      Err(Error::InvalidNotification(_))
+    ));
+
+    // INVALID: unknown version.
+    let mut invalid: Vec<u8> = packed.clone();
+    invalid[3] = 0;
+    assert!(DIDNotification::unpack(&invalid).is_err());
+
+    // INVALID: truncated payload.
+    assert!(DIDNotification::unpack(&packed[..packed.len() - 1]).is_err());
+
+    assert!(DIDNotification::unpack(&packed).is_ok());
+  }
+
+  #[test]
+  fn test_expired() {
+    let mut notification: DIDNotification = DIDNotification::new("hello");
+    assert!(!notification.is_expired());
+    notification.set_expires(Timestamp::now_utc().checked_sub(Duration::seconds(1)));
+    assert!(notification.is_expired());
+  }
+}
@@ -0,0 +1,6 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod did_notification;
+
+pub use did_notification::DIDNotification;
@@ -0,0 +1,247 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_core::convert::FmtJson;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::Error;
+use crate::StateMetadataEncoding;
+
+/// Magic bytes used to mark credential registry entries.
+const REGISTRY_ENTRY_MARKER: &[u8] = b"ICR";
+
+/// Version of the credential registry entry encoding.
+const REGISTRY_ENTRY_VERSION_V1: u8 = 1;
+
+/// The position of a credential in a status list, e.g. a `RevocationBitmap2022` service or a
+/// `StatusList2021Credential`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusCoordinates {
+  status_list: Url,
+  index: u64,
+}
+
+impl StatusCoordinates {
+  /// Creates new [`StatusCoordinates`] of the entry at `index` in the status list identified by `status_list`.
+  pub fn new(status_list: Url, index: u64) -> Self {
+    Self { status_list, index }
+  }
+
+  /// Returns the identifier of the status list.
+  pub fn status_list(&self) -> &Url {
+    &self.status_list
+  }
+
+  /// Returns the index of the credential in the status list.
+  pub fn index(&self) -> u64 {
+    self.index
+  }
+}
+
+/// A record of a credential issued by an IOTA DID, published in its opt-in credential registry.
+///
+/// Registry entries are held as Basic Outputs by the Alias Output of the issuer's DID, with the DID's alias
+/// address as Sender Feature, so they can only be created by its state controller (see
+/// `IotaIdentityClientExt::new_registry_entry_output`). Listing the entries of a DID allows third parties to count
+/// and audit the credentials it issued.
+///
+/// An entry does not reveal the credential: only a salted hash of its identifier is published, together with its
+/// status coordinates, if any. The issuer can later prove that a credential is registered by disclosing its
+/// identifier and salt, which can be checked with [`CredentialRegistryEntry::matches`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialRegistryEntry {
+  credential_hash: String,
+  issued: Timestamp,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  status: Option<StatusCoordinates>,
+}
+
+impl CredentialRegistryEntry {
+  /// Creates a new [`CredentialRegistryEntry`] for the credential with the given `credential_id`, issued at the
+  /// current system datetime.
+  ///
+  /// The `salt` prevents the identifier from being guessed from the published hash and must be kept by the issuer to
+  /// prove the registration later on. It should be at least 16 random bytes, unique to each credential.
+  pub fn new(credential_id: &Url, salt: &[u8]) -> Self {
+    Self {
+      credential_hash: Self::hash(credential_id, salt),
+      issued: Timestamp::now_utc(),
+      status: None,
+    }
+  }
+
+  /// Sets the position of the credential in a status list.
+  pub fn with_status(mut self, status: StatusCoordinates) -> Self {
+    self.status = Some(status);
+    self
+  }
+
+  /// Returns the base64url encoded SHA-256 hash of the salt and identifier of the credential.
+  pub fn credential_hash(&self) -> &str {
+    &self.credential_hash
+  }
+
+  /// Returns the time at which the credential was issued.
+  pub fn issued(&self) -> Timestamp {
+    self.issued
+  }
+
+  /// Returns the position of the credential in a status list, if any.
+  pub fn status(&self) -> Option<&StatusCoordinates> {
+    self.status.as_ref()
+  }
+
+  /// Returns `true` if this entry records the credential with the given `credential_id` and `salt`.
+  pub fn matches(&self, credential_id: &Url, salt: &[u8]) -> bool {
+    self.credential_hash == Self::hash(credential_id, salt)
+  }
+
+  fn hash(credential_id: &Url, salt: &[u8]) -> String {
+    let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+    SHA256(&[salt, credential_id.as_str().as_bytes()].concat(), &mut digest);
+    BaseEncoding::encode(&digest, Base::Base64Url)
+  }
+
+  /// Pack the entry into bytes, suitable for inclusion in the Metadata Feature of an output.
+  ///
+  /// The layout is `[marker, version, encoding, data length, data]`, mirroring the state metadata
+  /// encoding of DID documents.
+  pub fn pack(&self, encoding: StateMetadataEncoding) -> Result<Vec<u8>> {
+    let json: Vec<u8> = self
+      .to_json_vec()
+      .map_err(|err| Error::SerializationError("failed to serialize registry entry to JSON", Some(err)))?;
+    let mut data: Vec<u8> = encoding.encode(json)?;
+
+    let data_len: u16 =
+      u16::try_from(data.len()).map_err(|_| Error::SerializationError("failed to convert usize to u16", None))?;
+    let data_len_packed: [u8; 2] = data_len.to_le_bytes();
+    let mut buffer: Vec<u8> =
+      Vec::with_capacity(REGISTRY_ENTRY_MARKER.len() + 1 + 1 + data_len_packed.len() + data_len as usize);
+    buffer.extend_from_slice(REGISTRY_ENTRY_MARKER);
+    buffer.push(REGISTRY_ENTRY_VERSION_V1);
+    buffer.push(encoding as u8);
+    buffer.extend_from_slice(&data_len_packed);
+    buffer.append(&mut data);
+    Ok(buffer)
+  }
+
+  /// Unpack bytes into a [`CredentialRegistryEntry`].
+  pub fn unpack(data: &[u8]) -> Result<Self> {
+    if data.get(0..=2) != Some(REGISTRY_ENTRY_MARKER) {
+      return Err(Error::InvalidRegistryEntry("missing `ICR` marker"));
+    }
+    if data.get(3) != Some(&REGISTRY_ENTRY_VERSION_V1) {
+      return Err(Error::InvalidRegistryEntry("unsupported version"));
+    }
+    let encoding: StateMetadataEncoding = data
+      .get(4)
+      .ok_or(Error::InvalidRegistryEntry("expected encoding at offset 4"))
+      .and_then(|encoding| {
+        StateMetadataEncoding::try_from(*encoding).map_err(|_| Error::InvalidRegistryEntry("unsupported encoding"))
+      })?;
+    let data_len: u16 = data
+      .get(5..=6)
+      .and_then(|len| <[u8; 2]>::try_from(len).ok())
+      .map(u16::from_le_bytes)
+      .ok_or(Error::InvalidRegistryEntry("expected data length at offset [5..=6]"))?;
+    let data: &[u8] = data.get(7..(7 + data_len as usize)).ok_or(Error::InvalidRegistryEntry(
+      "encoded registry entry shorter than length prefix",
+    ))?;
+
+    let json = encoding.decode(data)?;
+    CredentialRegistryEntry::from_json_slice(&json)
+      .map_err(|err| Error::SerializationError("failed to deserialize JSON registry entry", Some(err)))
+  }
+}
+
+impl Display for CredentialRegistryEntry {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    self.fmt_json(f)
+  }
+}
+
+#[cfg(feature = "client")]
+mod client_registry_entry {
+  use crate::block::address::Address;
+  use crate::block::output::BasicOutput;
+  use crate::error::Result;
+  use crate::Error;
+
+  use super::CredentialRegistryEntry;
+
+  impl CredentialRegistryEntry {
+    /// Deserializes the entry from the Metadata Feature of a Basic Output, checking that the output was created by
+    /// `issuer`, i.e. the alias address of the issuer's DID.
+    pub fn unpack_from_output(issuer: &Address, basic_output: &BasicOutput) -> Result<CredentialRegistryEntry> {
+      if basic_output.features().sender().map(|sender| sender.address()) != Some(issuer) {
+        return Err(Error::InvalidRegistryEntry("output was not created by the issuer"));
+      }
+      let metadata = basic_output
+        .features()
+        .metadata()
+        .ok_or(Error::InvalidRegistryEntry("output has no metadata feature"))?;
+      CredentialRegistryEntry::unpack(metadata.data())
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn credential_id() -> Url {
+    Url::parse("https://example.edu/credentials/3732").unwrap()
+  }
+
+  #[test]
+  fn test_packing_roundtrip() {
+    let entry: CredentialRegistryEntry = CredentialRegistryEntry::new(&credential_id(), b"0123456789abcdef")
+      .with_status(StatusCoordinates::new(
+        Url::parse("did:iota:0x0000#revocation").unwrap(),
+        5,
+      ));
+
+    let packed: Vec<u8> = entry.pack(StateMetadataEncoding::Json).unwrap();
+    assert_eq!(&packed[0..3], REGISTRY_ENTRY_MARKER);
+    assert_eq!(packed[3], REGISTRY_ENTRY_VERSION_V1);
+    assert_eq!(packed[4], StateMetadataEncoding::Json as u8);
+    assert_eq!(CredentialRegistryEntry::unpack(&packed).unwrap(), entry);
+
+    // INVALID: a DID notification or document.
+    let mut invalid: Vec<u8> = packed.clone();
+    invalid[0..3].copy_from_slice(b"NTF");
+    assert!(matches!(
+      CredentialRegistryEntry::unpack(&invalid),
+      Err(Error::InvalidRegistryEntry(_))
+    ));
+    // INVALID: truncated payload.
+    assert!(CredentialRegistryEntry::unpack(&packed[..packed.len() - 1]).is_err());
+  }
+
+  #[test]
+  fn test_matches() {
+    let entry: CredentialRegistryEntry = CredentialRegistryEntry::new(&credential_id(), b"0123456789abcdef");
+    assert!(entry.matches(&credential_id(), b"0123456789abcdef"));
+    assert!(!entry.matches(&credential_id(), b"fedcba9876543210"));
+    assert!(!entry.matches(
+      &Url::parse("https://example.edu/credentials/3733").unwrap(),
+      b"0123456789abcdef"
+    ));
+    assert!(!entry.to_string().contains("example.edu"));
+  }
+}
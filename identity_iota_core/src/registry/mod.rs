@@ -0,0 +1,7 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod credential_registry_entry;
+
+pub use credential_registry_entry::CredentialRegistryEntry;
+pub use credential_registry_entry::StatusCoordinates;
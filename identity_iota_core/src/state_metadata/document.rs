@@ -1,11 +1,14 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::OrderedSet;
 use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
 use identity_did::CoreDID;
 use identity_document::document::CoreDocument;
+use identity_document::service::Service;
 use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -16,6 +19,7 @@ use crate::IotaDocument;
 use crate::IotaDocumentMetadata;
 
 use super::StateMetadataEncoding;
+use super::StateMetadataSize;
 use super::StateMetadataVersion;
 
 pub(crate) static PLACEHOLDER_DID: Lazy<CoreDID> = Lazy::new(|| CoreDID::parse("did:0:0").unwrap());
@@ -23,6 +27,29 @@ pub(crate) static PLACEHOLDER_DID: Lazy<CoreDID> = Lazy::new(|| CoreDID::parse("
 /// Magic bytes used to mark DID documents.
 const DID_MARKER: &[u8] = b"DID";
 
+/// The fields of the document that custom properties must not shadow.
+const DOCUMENT_FIELDS: &[&str] = &[
+  "id",
+  "controller",
+  "alsoKnownAs",
+  "verificationMethod",
+  "authentication",
+  "assertionMethod",
+  "keyAgreement",
+  "capabilityDelegation",
+  "capabilityInvocation",
+  "service",
+];
+
+/// The fields of the metadata that custom metadata properties must not shadow.
+const METADATA_FIELDS: &[&str] = &[
+  "created",
+  "updated",
+  "deactivated",
+  "governorAddress",
+  "stateControllerAddress",
+];
+
 /// Intermediate representation of the DID document as it is contained in the state metadata of
 /// an Alias Output.
 ///
@@ -75,93 +102,234 @@ impl StateMetadataDocument {
 
   /// Pack a [`StateMetadataDocument`] into bytes, suitable for inclusion in
   /// an Alias Output's state metadata, according to the given `encoding`.
-  pub fn pack(mut self, encoding: StateMetadataEncoding) -> Result<Vec<u8>> {
+  pub fn pack(self, encoding: StateMetadataEncoding) -> Result<Vec<u8>> {
+    self.pack_with_version(StateMetadataVersion::CURRENT, encoding)
+  }
+
+  /// Pack a [`StateMetadataDocument`] into bytes like [`StateMetadataDocument::pack`], but with the given `version`,
+  /// e.g. one obtained from [`StateMetadataVersion::negotiate`] to stay readable by older verifiers.
+  ///
+  /// # Errors
+  /// Fails with [`Error::ReservedPropertyKey`] if a custom property of the document or its metadata has the same key
+  /// as one of their fields, since it could not be unpacked again.
+  pub fn pack_with_version(
+    mut self,
+    version: StateMetadataVersion,
+    encoding: StateMetadataEncoding,
+  ) -> Result<Vec<u8>> {
+    self.check_property_keys()?;
+
     // Unset Governor and State Controller Addresses to avoid bloating the payload
     self.metadata.governor_address = None;
     self.metadata.state_controller_address = None;
 
-    let encoded_message_data: Vec<u8> = match encoding {
-      StateMetadataEncoding::Json => self
-        .to_json_vec()
-        .map_err(|err| Error::SerializationError("failed to serialize document to JSON", Some(err)))?,
-    };
+    let json: Vec<u8> = self
+      .to_json_vec()
+      .map_err(|err| Error::SerializationError("failed to serialize document to JSON", Some(err)))?;
+    let encoded_message_data: Vec<u8> = encoding.encode(json)?;
 
     // Prepend flags and length.
-    let encoded_message_data_with_flags =
-      add_flags_to_message(encoded_message_data, StateMetadataVersion::CURRENT, encoding)?;
+    let encoded_message_data_with_flags = add_flags_to_message(encoded_message_data, version, encoding)?;
     Ok(encoded_message_data_with_flags)
   }
 
+  /// Returns the size of the document packed with `encoding`, broken down into its controller, `alsoKnownAs` set,
+  /// custom properties and metadata.
+  pub fn size(&self, encoding: StateMetadataEncoding) -> Result<StateMetadataSize> {
+    let total: usize = self.clone().pack(encoding)?.len();
+
+    let controller: usize = match self.document.controller() {
+      Some(controller) => entry_length("controller", controller)?,
+      None => 0,
+    };
+    let also_known_as: usize = if self.document.also_known_as().is_empty() {
+      0
+    } else {
+      entry_length("alsoKnownAs", self.document.also_known_as())?
+    };
+    let properties: usize = self
+      .document
+      .properties()
+      .iter()
+      .map(|(key, value)| entry_length(key, value))
+      .sum::<Result<usize>>()?;
+
+    // The addresses are not part of the packed metadata.
+    let mut metadata: IotaDocumentMetadata = self.metadata.clone();
+    metadata.governor_address = None;
+    metadata.state_controller_address = None;
+    let metadata: usize = entry_length("meta", &metadata)?;
+
+    Ok(StateMetadataSize {
+      total,
+      controller,
+      also_known_as,
+      properties,
+      metadata,
+    })
+  }
+
+  /// Checks that no custom property of the document or its metadata shadows one of their fields.
+  ///
+  /// Such a property would be serialized next to the field of the same name and be dropped when unpacking.
+  fn check_property_keys(&self) -> Result<()> {
+    let shadowing_key: Option<&String> = self
+      .document
+      .properties()
+      .keys()
+      .find(|key| DOCUMENT_FIELDS.contains(&key.as_str()))
+      .or_else(|| {
+        self
+          .metadata
+          .properties()
+          .keys()
+          .find(|key| METADATA_FIELDS.contains(&key.as_str()))
+      });
+
+    match shadowing_key {
+      Some(key) => Err(Error::ReservedPropertyKey(key.clone())),
+      None => Ok(()),
+    }
+  }
+
   /// Unpack bytes into a [`StateMetadataDocument`].
+  ///
+  /// Unknown fields in the encoded document are ignored. Fails with [`Error::UnsupportedStateMetadataVersion`] if
+  /// `data` is encoded with a version this crate does not support.
   pub fn unpack(data: &[u8]) -> Result<Self> {
-    // Check marker.
-    let marker: &[u8] = data
-      .get(0..=2)
+    unpack_as(data)
+  }
+
+  /// Returns the raw version byte of the state metadata `data`, without checking whether the version is supported
+  /// or decoding the document.
+  pub fn version(data: &[u8]) -> Result<u8> {
+    check_marker(data)?;
+    data
+      .get(3)
+      .copied()
       .ok_or(identity_document::Error::InvalidDocument(
-        "state metadata decoding: expected DID marker at offset [0..=2]",
+        "state metadata decoding: expected version at offset 3",
         None,
       ))
-      .map_err(Error::InvalidDoc)?;
-    if marker != DID_MARKER {
-      return Err(Error::InvalidStateMetadata("missing `DID` marker"));
-    }
+      .map_err(Error::InvalidDoc)
+  }
 
-    // Check version.
-    let version: StateMetadataVersion = StateMetadataVersion::try_from(
-      *data
-        .get(3)
-        .ok_or(identity_document::Error::InvalidDocument(
-          "state metadata decoding: expected version at offset 3",
-          None,
-        ))
-        .map_err(Error::InvalidDoc)?,
-    )?;
-    if version != StateMetadataVersion::V1 {
-      return Err(Error::InvalidStateMetadata("unsupported version"));
-    }
+  /// Unpack only the metadata of the document contained in `data`, without deserializing the document itself.
+  pub fn unpack_metadata(data: &[u8]) -> Result<IotaDocumentMetadata> {
+    unpack_as::<MetadataSection>(data).map(|section| section.metadata)
+  }
 
-    // Decode data.
-    let encoding: StateMetadataEncoding = StateMetadataEncoding::try_from(
-      *data
-        .get(4)
-        .ok_or(identity_document::Error::InvalidDocument(
-          "state metadata decoding: expected encoding at offset 4",
-          None,
-        ))
-        .map_err(Error::InvalidDoc)?,
-    )?;
+  /// Unpack only the services of the document contained in `data`, replacing placeholders with `original_did`.
+  pub fn unpack_services(data: &[u8], original_did: &IotaDID) -> Result<OrderedSet<Service>> {
+    let services: OrderedSet<Service> = unpack_as::<ServicesSection>(data)?.document.service;
+    let replace_placeholder = |did: CoreDID| -> CoreDID {
+      if did == PLACEHOLDER_DID.as_ref() {
+        CoreDID::from(original_did.clone())
+      } else {
+        did
+      }
+    };
 
-    let data_len_packed: [u8; 2] = data
-      .get(5..=6)
-      .ok_or(identity_document::Error::InvalidDocument(
-        "state metadata decoding: expected data length at offset [5..=6]",
-        None,
-      ))
-      .map_err(Error::InvalidDoc)?
-      .try_into()
-      .map_err(|_| {
-        identity_document::Error::InvalidDocument("state metadata decoding: data length conversion error", None)
-      })
-      .map_err(Error::InvalidDoc)?;
-    let data_len: u16 = u16::from_le_bytes(data_len_packed);
-
-    let data: &[u8] = data
-      .get(7..(7 + data_len as usize))
+    Ok(
+      services
+        .into_iter()
+        .map(|service| service.map(replace_placeholder))
+        .collect(),
+    )
+  }
+}
+
+/// The metadata section of a [`StateMetadataDocument`].
+#[derive(Deserialize)]
+struct MetadataSection {
+  #[serde(rename = "meta")]
+  metadata: IotaDocumentMetadata,
+}
+
+/// The services of the document section of a [`StateMetadataDocument`].
+#[derive(Deserialize)]
+struct ServicesSection {
+  #[serde(rename = "doc")]
+  document: ServicesOnly,
+}
+
+#[derive(Deserialize)]
+struct ServicesOnly {
+  #[serde(default)]
+  service: OrderedSet<Service>,
+}
+
+/// Unpack the JSON encoded in the state metadata `data` into `T`, checking the marker, version and length prefix.
+fn unpack_as<T: DeserializeOwned>(data: &[u8]) -> Result<T> {
+  // Check marker and version.
+  let _version: StateMetadataVersion = StateMetadataVersion::try_from(StateMetadataDocument::version(data)?)?;
+
+  // Decode data.
+  let encoding: StateMetadataEncoding = StateMetadataEncoding::try_from(
+    *data
+      .get(4)
       .ok_or(identity_document::Error::InvalidDocument(
-        "state metadata decoding: encoded document shorter than length prefix",
+        "state metadata decoding: expected encoding at offset 4",
         None,
       ))
-      .map_err(Error::InvalidDoc)?;
+      .map_err(Error::InvalidDoc)?,
+  )?;
+
+  let data_len_packed: [u8; 2] = data
+    .get(5..=6)
+    .ok_or(identity_document::Error::InvalidDocument(
+      "state metadata decoding: expected data length at offset [5..=6]",
+      None,
+    ))
+    .map_err(Error::InvalidDoc)?
+    .try_into()
+    .map_err(|_| {
+      identity_document::Error::InvalidDocument("state metadata decoding: data length conversion error", None)
+    })
+    .map_err(Error::InvalidDoc)?;
+  let data_len: u16 = u16::from_le_bytes(data_len_packed);
+
+  let data: &[u8] = data
+    .get(7..(7 + data_len as usize))
+    .ok_or(identity_document::Error::InvalidDocument(
+      "state metadata decoding: encoded document shorter than length prefix",
+      None,
+    ))
+    .map_err(Error::InvalidDoc)?;
+
+  let json = encoding.decode(data)?;
+  T::from_json_slice(&json).map_err(|err| {
+    Error::SerializationError(
+      "state metadata decoding: failed to deserialize JSON document",
+      Some(err),
+    )
+  })
+}
 
-    match encoding {
-      StateMetadataEncoding::Json => StateMetadataDocument::from_json_slice(data).map_err(|err| {
-        Error::SerializationError(
-          "state metadata decoding: failed to deserialize JSON document",
-          Some(err),
-        )
-      }),
-    }
+/// Returns the length of the JSON object entry `"key":value`, including the separator from the next entry.
+fn entry_length<T: Serialize>(key: &str, value: &T) -> Result<usize> {
+  let key: String = key
+    .to_json()
+    .map_err(|err| Error::SerializationError("failed to serialize document to JSON", Some(err)))?;
+  let value: String = value
+    .to_json()
+    .map_err(|err| Error::SerializationError("failed to serialize document to JSON", Some(err)))?;
+  Ok(key.len() + 1 + value.len() + 1)
+}
+
+/// Checks that `data` starts with the `DID` marker.
+fn check_marker(data: &[u8]) -> Result<()> {
+  let marker: &[u8] = data
+    .get(0..=2)
+    .ok_or(identity_document::Error::InvalidDocument(
+      "state metadata decoding: expected DID marker at offset [0..=2]",
+      None,
+    ))
+    .map_err(Error::InvalidDoc)?;
+  if marker != DID_MARKER {
+    return Err(Error::InvalidStateMetadata("missing `DID` marker"));
   }
+  Ok(())
 }
 
 /// Prepends the message flags and marker magic bytes to the data in the following order:
@@ -213,6 +381,9 @@ mod tests {
   use identity_core::common::Object;
   use identity_core::common::OneOrSet;
   use identity_core::common::Url;
+  use identity_core::common::Value;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
   use identity_did::CoreDID;
   use identity_did::DID;
   use identity_verification::MethodScope;
@@ -220,11 +391,14 @@ mod tests {
   use crate::state_metadata::document::DID_MARKER;
   use crate::state_metadata::PLACEHOLDER_DID;
   use crate::test_utils::generate_method;
+  use crate::Error;
   use crate::IotaDID;
   use crate::IotaDocument;
   use crate::StateMetadataDocument;
   use crate::StateMetadataEncoding;
+  use crate::StateMetadataSize;
   use crate::StateMetadataVersion;
+  use crate::STATE_METADATA_MAX_LENGTH;
   use identity_document::service::Service;
 
   struct TestSetup {
@@ -531,4 +705,237 @@ mod tests {
       unpacked_doc.document.properties()
     );
   }
+
+  #[test]
+  fn test_unpack_sections() {
+    let TestSetup {
+      document,
+      did_self,
+      did_foreign,
+    } = test_document();
+    let packed_bytes: Vec<u8> = document.clone().pack().unwrap();
+
+    let metadata = StateMetadataDocument::unpack_metadata(&packed_bytes).unwrap();
+    assert_eq!(metadata.created, document.metadata.created);
+    assert_eq!(metadata.updated, document.metadata.updated);
+    assert_eq!(metadata.deactivated, document.metadata.deactivated);
+
+    let services = StateMetadataDocument::unpack_services(&packed_bytes, &did_self).unwrap();
+    assert_eq!(&services, document.service());
+    assert!(services
+      .iter()
+      .any(|service| service.id().did() == did_foreign.as_ref()));
+
+    // Documents without services.
+    let packed_bytes: Vec<u8> = IotaDocument::new_with_id(did_self.clone()).pack().unwrap();
+    assert!(StateMetadataDocument::unpack_services(&packed_bytes, &did_self)
+      .unwrap()
+      .is_empty());
+  }
+
+  #[test]
+  fn test_unpack_version() {
+    let TestSetup { document, .. } = test_document();
+    let mut packed: Vec<u8> = StateMetadataDocument::from(document)
+      .pack(StateMetadataEncoding::Json)
+      .unwrap();
+    assert_eq!(
+      StateMetadataDocument::version(&packed).unwrap(),
+      StateMetadataVersion::CURRENT.as_u8()
+    );
+
+    // Documents of future versions are rejected with the version found.
+    packed[3] = 42;
+    assert_eq!(StateMetadataDocument::version(&packed).unwrap(), 42);
+    assert!(matches!(
+      StateMetadataDocument::unpack(&packed),
+      Err(Error::UnsupportedStateMetadataVersion { found: 42, .. })
+    ));
+
+    assert!(matches!(
+      StateMetadataDocument::version(b"ABC\x01"),
+      Err(Error::InvalidStateMetadata(_))
+    ));
+  }
+
+  #[test]
+  fn test_unpack_unknown_fields() {
+    let TestSetup { document, did_self, .. } = test_document();
+    let state_metadata_doc: StateMetadataDocument = StateMetadataDocument::from(document.clone());
+    let payload: String = format!(
+      "{{\"doc\":{},\"meta\":{},\"ext\":{{\"future\":true}}}}",
+      state_metadata_doc.document, state_metadata_doc.metadata
+    );
+    let mut packed: Vec<u8> = Vec::new();
+    packed.extend_from_slice(DID_MARKER);
+    packed.push(StateMetadataVersion::V1.as_u8());
+    packed.push(StateMetadataEncoding::Json as u8);
+    packed.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+    packed.extend_from_slice(payload.as_bytes());
+
+    let unpacked: IotaDocument = StateMetadataDocument::unpack(&packed)
+      .unwrap()
+      .into_iota_document(&did_self)
+      .unwrap();
+    assert_eq!(unpacked.core_document(), document.core_document());
+  }
+
+  #[test]
+  fn test_custom_properties_roundtrip() {
+    let TestSetup {
+      mut document, did_self, ..
+    } = test_document();
+    document.properties_mut_unchecked().insert(
+      "nested".to_owned(),
+      Value::from_json(r#"{"a":[1,"b",null],"c":{}}"#).unwrap(),
+    );
+    document
+      .properties_mut_unchecked()
+      .insert("number".to_owned(), 1.5.into());
+    document
+      .metadata
+      .properties_mut()
+      .insert("custom".to_owned(), "metadata".into());
+
+    let packed: Vec<u8> = document.clone().pack().unwrap();
+    let unpacked: IotaDocument = StateMetadataDocument::unpack(&packed)
+      .unwrap()
+      .into_iota_document(&did_self)
+      .unwrap();
+    assert_eq!(unpacked, document);
+
+    // Re-encoding is stable.
+    assert_eq!(unpacked.pack().unwrap(), packed);
+  }
+
+  #[test]
+  fn test_reserved_property_keys() {
+    let TestSetup { document, .. } = test_document();
+
+    let mut shadowing_document: IotaDocument = document.clone();
+    shadowing_document
+      .properties_mut_unchecked()
+      .insert("service".to_owned(), Value::Array(Vec::new()));
+    assert!(matches!(
+      shadowing_document.pack(),
+      Err(Error::ReservedPropertyKey(key)) if key == "service"
+    ));
+
+    let mut shadowing_metadata: IotaDocument = document;
+    shadowing_metadata
+      .metadata
+      .properties_mut()
+      .insert("deactivated".to_owned(), true.into());
+    assert!(matches!(
+      shadowing_metadata.pack(),
+      Err(Error::ReservedPropertyKey(key)) if key == "deactivated"
+    ));
+  }
+
+  #[test]
+  fn test_size() {
+    let TestSetup { mut document, .. } = test_document();
+    document
+      .properties_mut_unchecked()
+      .insert("custom".to_owned(), "x".repeat(100).into());
+    let state_metadata_doc: StateMetadataDocument = StateMetadataDocument::from(document);
+
+    let size: StateMetadataSize = state_metadata_doc.size(StateMetadataEncoding::Json).unwrap();
+    assert_eq!(
+      size.total,
+      state_metadata_doc
+        .clone()
+        .pack(StateMetadataEncoding::Json)
+        .unwrap()
+        .len()
+    );
+    assert!(!size.exceeds_limit());
+    assert_eq!(size.remaining(), Some(STATE_METADATA_MAX_LENGTH - size.total));
+
+    // Each part accounts for exactly the bytes it adds to the encoding.
+    let mut without_properties: StateMetadataDocument = state_metadata_doc.clone();
+    without_properties.document.properties_mut_unchecked().clear();
+    let mut without_also_known_as: StateMetadataDocument = state_metadata_doc.clone();
+    without_also_known_as.document.also_known_as_mut().clear();
+    let mut without_controller: StateMetadataDocument = state_metadata_doc;
+    *without_controller.document.controller_mut() = None;
+
+    for (document, part) in [
+      (without_properties, size.properties),
+      (without_also_known_as, size.also_known_as),
+      (without_controller, size.controller),
+    ] {
+      assert!(part > 0);
+      let reduced: StateMetadataSize = document.size(StateMetadataEncoding::Json).unwrap();
+      assert_eq!(reduced.total + part, size.total);
+    }
+  }
+
+  #[cfg(feature = "compression")]
+  #[test]
+  fn test_packing_roundtrip_compressed() {
+    let TestSetup {
+      mut document, did_self, ..
+    } = test_document();
+    for index in 0..20 {
+      document
+        .insert_service(
+          Service::builder(Object::new())
+            .id(did_self.to_url().join(format!("#linked-domain-{index}")).unwrap())
+            .type_("LinkedDomains")
+            .service_endpoint(Url::parse(format!("https://example-{index}.com/")).unwrap())
+            .build()
+            .unwrap(),
+        )
+        .unwrap();
+    }
+
+    let packed_json: Vec<u8> = document
+      .clone()
+      .pack_with_encoding(StateMetadataEncoding::Json)
+      .unwrap();
+    let packed: Vec<u8> = document
+      .clone()
+      .pack_with_encoding(StateMetadataEncoding::JsonBrotli)
+      .unwrap();
+    assert_eq!(packed[4], StateMetadataEncoding::JsonBrotli as u8);
+    assert!(packed.len() < packed_json.len());
+
+    let unpacked: IotaDocument = StateMetadataDocument::unpack(&packed)
+      .unwrap()
+      .into_iota_document(&did_self)
+      .unwrap();
+    assert_eq!(unpacked, document);
+    assert_eq!(
+      &StateMetadataDocument::unpack_services(&packed, &did_self).unwrap(),
+      document.service()
+    );
+  }
+
+  // Golden files must never be changed: documents packed by earlier releases have to remain readable, and packing them
+  // again must produce the same header and JSON. A breaking change requires a new version.
+  const GOLDEN_DOCUMENT_V1: &str = include_str!("../../tests/fixtures/golden/iota-document-v1.json");
+  const GOLDEN_STATE_METADATA_V1: &[u8] = include_bytes!("../../tests/fixtures/golden/state-metadata-v1.bin");
+
+  #[test]
+  fn test_golden_v1() {
+    let document: IotaDocument = IotaDocument::from_json(GOLDEN_DOCUMENT_V1).unwrap();
+    assert_eq!(
+      document.to_json_value().unwrap(),
+      Value::from_json(GOLDEN_DOCUMENT_V1).unwrap()
+    );
+
+    let unpacked: IotaDocument = StateMetadataDocument::unpack(GOLDEN_STATE_METADATA_V1)
+      .unwrap()
+      .into_iota_document(document.id())
+      .unwrap();
+    assert_eq!(unpacked, document);
+
+    let packed: Vec<u8> = document.pack().unwrap();
+    assert_eq!(packed[..5], GOLDEN_STATE_METADATA_V1[..5]);
+    assert_eq!(
+      Value::from_json_slice(&packed[7..]).unwrap(),
+      Value::from_json_slice(&GOLDEN_STATE_METADATA_V1[7..]).unwrap()
+    );
+  }
 }
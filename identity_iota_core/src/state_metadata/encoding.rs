@@ -1,10 +1,17 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
+
 use num_traits::FromPrimitive;
 
+use crate::error::Result;
 use crate::Error;
 
+/// The maximum length of the JSON obtained by decompressing state metadata, guarding against decompression bombs.
+#[cfg(feature = "compression")]
+const MAX_DECOMPRESSED_LENGTH: u64 = 1024 * 1024;
+
 /// Indicates the encoding of a DID document in state metadata.
 #[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq, num_derive::FromPrimitive)]
 #[non_exhaustive]
@@ -12,6 +19,56 @@ pub enum StateMetadataEncoding {
   /// State Metadata encoded as JSON.
   #[default]
   Json = 0,
+  /// State Metadata encoded as JSON compressed with Brotli.
+  ///
+  /// Reduces the size, and thus the storage deposit, of documents with many methods or services. Documents encoded
+  /// this way can only be resolved by verifiers built with the `compression` feature.
+  #[cfg(feature = "compression")]
+  JsonBrotli = 1,
+}
+
+impl StateMetadataEncoding {
+  /// Encodes the serialized `json` according to this encoding.
+  pub(crate) fn encode(self, json: Vec<u8>) -> Result<Vec<u8>> {
+    match self {
+      Self::Json => Ok(json),
+      #[cfg(feature = "compression")]
+      Self::JsonBrotli => {
+        let mut compressed: Vec<u8> = Vec::new();
+        brotli::BrotliCompress(
+          &mut json.as_slice(),
+          &mut compressed,
+          &brotli::enc::BrotliEncoderParams::default(),
+        )
+        .map_err(|err| Error::CompressionError("failed to compress state metadata", Some(err)))?;
+        Ok(compressed)
+      }
+    }
+  }
+
+  /// Decodes `data` encoded with this encoding into serialized JSON.
+  pub(crate) fn decode(self, data: &[u8]) -> Result<Cow<'_, [u8]>> {
+    match self {
+      Self::Json => Ok(Cow::Borrowed(data)),
+      #[cfg(feature = "compression")]
+      Self::JsonBrotli => {
+        use std::io::Read;
+
+        let mut json: Vec<u8> = Vec::new();
+        brotli::Decompressor::new(data, 4096)
+          .take(MAX_DECOMPRESSED_LENGTH + 1)
+          .read_to_end(&mut json)
+          .map_err(|err| Error::CompressionError("failed to decompress state metadata", Some(err)))?;
+        if json.len() as u64 > MAX_DECOMPRESSED_LENGTH {
+          return Err(Error::CompressionError(
+            "decompressed state metadata is too large",
+            None,
+          ));
+        }
+        Ok(Cow::Owned(json))
+      }
+    }
+  }
 }
 
 impl TryFrom<u8> for StateMetadataEncoding {
@@ -21,3 +78,34 @@ impl TryFrom<u8> for StateMetadataEncoding {
     FromPrimitive::from_u8(value).ok_or(Error::InvalidStateMetadata("unsupported encoding"))
   }
 }
+
+#[cfg(all(test, feature = "compression"))]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn brotli_roundtrip() {
+    let json: Vec<u8> =
+      br#"{"service":["https://example.com/","https://example.com/","https://example.com/"]}"#.to_vec();
+    let compressed: Vec<u8> = StateMetadataEncoding::JsonBrotli.encode(json.clone()).unwrap();
+    assert!(compressed.len() < json.len());
+    assert_eq!(
+      StateMetadataEncoding::JsonBrotli.decode(&compressed).unwrap().as_ref(),
+      json.as_slice()
+    );
+    assert_eq!(
+      StateMetadataEncoding::try_from(1).unwrap(),
+      StateMetadataEncoding::JsonBrotli
+    );
+  }
+
+  #[test]
+  fn rejects_decompression_bombs() {
+    let json: Vec<u8> = vec![b' '; MAX_DECOMPRESSED_LENGTH as usize + 1];
+    let compressed: Vec<u8> = StateMetadataEncoding::JsonBrotli.encode(json).unwrap();
+    assert!(matches!(
+      StateMetadataEncoding::JsonBrotli.decode(&compressed),
+      Err(Error::CompressionError(_, None))
+    ));
+  }
+}
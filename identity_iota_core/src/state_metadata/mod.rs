@@ -3,8 +3,10 @@
 
 mod document;
 mod encoding;
+mod size;
 mod version;
 
 pub use document::*;
 pub use encoding::*;
-pub(crate) use version::*;
+pub use size::*;
+pub use version::*;
@@ -0,0 +1,39 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// The maximum length in bytes of the state metadata of an Alias Output.
+pub const STATE_METADATA_MAX_LENGTH: usize = 8192;
+
+/// The size of a DID document encoded as state metadata, broken down by the parts of the document that are not
+/// bounded by the DID method.
+///
+/// Each part is the number of bytes its entries add to the JSON of the document, including their keys and separators.
+/// With a compressed [`StateMetadataEncoding`](crate::StateMetadataEncoding), only `total` reflects the compression.
+/// Obtained through [`StateMetadataDocument::size`](crate::StateMetadataDocument::size) or
+/// [`IotaDocument::state_metadata_size`](crate::IotaDocument::state_metadata_size).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StateMetadataSize {
+  /// The length of the state metadata, including the marker, version, encoding and length prefix.
+  pub total: usize,
+  /// The bytes taken by the `controller` of the document.
+  pub controller: usize,
+  /// The bytes taken by the `alsoKnownAs` set of the document.
+  pub also_known_as: usize,
+  /// The bytes taken by the custom properties of the document.
+  pub properties: usize,
+  /// The bytes taken by the metadata section, including its custom properties.
+  pub metadata: usize,
+}
+
+impl StateMetadataSize {
+  /// Returns the number of bytes left before the state metadata reaches [`STATE_METADATA_MAX_LENGTH`], or `None` if
+  /// it already exceeds it.
+  pub fn remaining(&self) -> Option<usize> {
+    STATE_METADATA_MAX_LENGTH.checked_sub(self.total)
+  }
+
+  /// Returns whether the state metadata exceeds [`STATE_METADATA_MAX_LENGTH`].
+  pub fn exceeds_limit(&self) -> bool {
+    self.total > STATE_METADATA_MAX_LENGTH
+  }
+}
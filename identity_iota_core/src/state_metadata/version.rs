@@ -6,20 +6,85 @@ use num_traits::FromPrimitive;
 use crate::Error;
 
 /// Indicates the version of a DID document in state metadata.
-#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, num_derive::FromPrimitive)]
+///
+/// The version is encoded as the byte following the `DID` marker. Fields added to the encoded document within a
+/// version are ignored by readers that do not know them, so only changes that older readers cannot tolerate
+/// require a new version.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash, num_derive::FromPrimitive)]
 #[non_exhaustive]
-pub(crate) enum StateMetadataVersion {
+pub enum StateMetadataVersion {
+  /// The first version of the state metadata encoding.
   V1 = 1,
 }
 
 impl StateMetadataVersion {
-  pub(crate) const CURRENT: Self = Self::V1;
+  /// The version used when packing documents.
+  pub const CURRENT: Self = Self::V1;
+
+  /// All versions this crate is able to read and write, in ascending order.
+  pub const SUPPORTED: &'static [Self] = &[Self::V1];
+
+  /// Returns the byte representing this version in the encoding.
+  pub const fn as_u8(self) -> u8 {
+    self as u8
+  }
+
+  /// Returns the most recent version supported by both this crate and a reader supporting the version bytes
+  /// `supported`, such that documents packed with it can be read by that reader.
+  ///
+  /// Fails with [`Error::UnsupportedStateMetadataVersion`] if there is no common version.
+  pub fn negotiate(supported: &[u8]) -> Result<Self, Error> {
+    Self::SUPPORTED
+      .iter()
+      .rev()
+      .copied()
+      .find(|version| supported.contains(&version.as_u8()))
+      .ok_or(Error::UnsupportedStateMetadataVersion {
+        found: supported.iter().copied().max().unwrap_or_default(),
+        latest: Self::CURRENT.as_u8(),
+      })
+  }
 }
 
 impl TryFrom<u8> for StateMetadataVersion {
   type Error = Error;
 
   fn try_from(value: u8) -> Result<Self, Self::Error> {
-    FromPrimitive::from_u8(value).ok_or(Error::InvalidStateMetadata("unsupported version number"))
+    FromPrimitive::from_u8(value).ok_or(Error::UnsupportedStateMetadataVersion {
+      found: value,
+      latest: Self::CURRENT.as_u8(),
+    })
+  }
+}
+
+impl From<StateMetadataVersion> for u8 {
+  fn from(version: StateMetadataVersion) -> Self {
+    version.as_u8()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_try_from() {
+    assert_eq!(StateMetadataVersion::try_from(1).unwrap(), StateMetadataVersion::V1);
+    assert!(matches!(
+      StateMetadataVersion::try_from(2),
+      Err(Error::UnsupportedStateMetadataVersion { found: 2, latest: 1 })
+    ));
+  }
+
+  #[test]
+  fn test_negotiate() {
+    assert_eq!(
+      StateMetadataVersion::negotiate(&[1, 2]).unwrap(),
+      StateMetadataVersion::V1
+    );
+    assert!(matches!(
+      StateMetadataVersion::negotiate(&[2, 3]),
+      Err(Error::UnsupportedStateMetadataVersion { found: 3, latest: 1 })
+    ));
   }
 }
@@ -19,6 +19,7 @@ use crate::jwk::JwkParamsOkp;
 use crate::jwk::JwkParamsRsa;
 use crate::jwk::JwkType;
 use crate::jwk::JwkUse;
+use crate::jws::JwsAlgorithm;
 use crate::jwu::encode_b64;
 
 /// A SHA256 JSON Web Key Thumbprint.
@@ -28,6 +29,7 @@ pub type JwkThumbprintSha256 = [u8; SHA256_LEN];
 ///
 /// [More Info](https://tools.ietf.org/html/rfc7517#section-4)
 #[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Jwk {
   /// Key Type.
   ///
@@ -427,6 +429,27 @@ impl Jwk {
     }
   }
 
+  /// Returns the [`JwsAlgorithm`] of this JWK: its `alg` parameter if set, otherwise the algorithm implied by its
+  /// curve.
+  ///
+  /// Returns `None` if `alg` is not a JWS algorithm, or if the key type allows several algorithms, as for `RSA` keys,
+  /// or none at all, as for `X25519` keys.
+  pub fn infer_jws_algorithm(&self) -> Option<JwsAlgorithm> {
+    if let Some(alg) = self.alg() {
+      return alg.parse().ok();
+    }
+    match self.params() {
+      JwkParams::Ec(params) => match params.try_ec_curve().ok()? {
+        EcCurve::P256 => Some(JwsAlgorithm::ES256),
+        EcCurve::P384 => Some(JwsAlgorithm::ES384),
+        EcCurve::P521 => Some(JwsAlgorithm::ES512),
+        EcCurve::Secp256K1 => Some(JwsAlgorithm::ES256K),
+      },
+      JwkParams::Okp(params) => params.try_ed_curve().ok().map(|_| JwsAlgorithm::EdDSA),
+      JwkParams::Rsa(_) | JwkParams::Oct(_) => None,
+    }
+  }
+
   /// Returns `true` if _all_ private key components of the key are unset, `false` otherwise.
   pub fn is_public(&self) -> bool {
     self.params.is_public()
@@ -16,6 +16,7 @@ use super::BlsCurve;
 ///
 /// [More Info](https://tools.ietf.org/html/rfc7518#section-6)
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 #[derive(Zeroize)]
 #[zeroize(drop)]
@@ -82,6 +83,7 @@ impl JwkParams {
 ///
 /// [More Info](https://tools.ietf.org/html/rfc7518#section-6.2)
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize, Zeroize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[zeroize(drop)]
 pub struct JwkParamsEc {
   /// Identifies the cryptographic curve used with the key.
@@ -184,6 +186,7 @@ impl From<JwkParamsEc> for JwkParams {
 ///
 /// [More Info](https://tools.ietf.org/html/rfc7518#section-6.3)
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize, Zeroize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[zeroize(drop)]
 pub struct JwkParamsRsa {
   /// The modulus value for the RSA public key as a base64urlUInt-encoded value.
@@ -239,6 +242,7 @@ pub struct JwkParamsRsa {
 ///
 /// [More Info](https://tools.ietf.org/html/rfc7518#section-6.3.2.7)
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize, Zeroize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[zeroize(drop)]
 pub struct JwkParamsRsaPrime {
   /// The value of a subsequent prime factor as a base64urlUInt-encoded value.
@@ -337,6 +341,7 @@ impl From<JwkParamsRsa> for JwkParams {
 ///
 /// [More Info](https://tools.ietf.org/html/rfc7518#section-6.4)
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize, Zeroize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[zeroize(drop)]
 pub struct JwkParamsOct {
   /// The symmetric key as a base64url-encoded value.
@@ -383,6 +388,7 @@ impl From<JwkParamsOct> for JwkParams {
 ///
 /// [More Info](https://tools.ietf.org/html/rfc8037#section-2)
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize, Zeroize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[zeroize(drop)]
 pub struct JwkParamsOkp {
   /// The subtype of the key pair.
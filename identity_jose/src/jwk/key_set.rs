@@ -14,6 +14,7 @@ use crate::jwk::Jwk;
 ///
 /// [More Info](https://tools.ietf.org/html/rfc7517#section-5)
 #[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct JwkSet {
   /// An array of JWK values.
   ///
@@ -9,6 +9,7 @@ use core::fmt::Result;
 ///
 /// [More Info](https://www.iana.org/assignments/jose/jose.xhtml#web-key-use)
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum JwkUse {
   /// Digital Signature or MAC.
   #[serde(rename = "sig")]
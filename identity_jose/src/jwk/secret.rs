@@ -0,0 +1,88 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+
+use zeroize::Zeroize;
+use zeroize::ZeroizeOnDrop;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jwk::Jwk;
+use crate::jwk::JwkType;
+
+/// A [`Jwk`] holding private key material.
+///
+/// The wrapped key is zeroized when the [`SecretJwk`] is dropped. Unlike [`Jwk`], this type does not implement
+/// [`serde::Serialize`] and its [`Debug`] output only contains the `kty`, `alg` and `kid` parameters, so private key
+/// components cannot accidentally end up in logs or serialized payloads. Access to the key must be requested
+/// explicitly through [`SecretJwk::expose_secret`].
+#[derive(Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(try_from = "Jwk")]
+pub struct SecretJwk(Jwk);
+
+impl SecretJwk {
+  /// Creates a new [`SecretJwk`] from a [`Jwk`] with all private key components set.
+  pub fn new(jwk: Jwk) -> Result<Self> {
+    if !jwk.is_private() {
+      return Err(Error::KeyError("expected a Jwk with all private key components set"));
+    }
+
+    Ok(Self(jwk))
+  }
+
+  /// Returns a reference to the wrapped [`Jwk`], including its private key components.
+  pub fn expose_secret(&self) -> &Jwk {
+    &self.0
+  }
+
+  /// Returns the key type of the wrapped [`Jwk`].
+  pub fn kty(&self) -> JwkType {
+    self.0.kty()
+  }
+
+  /// Returns the algorithm of the wrapped [`Jwk`], if set.
+  pub fn alg(&self) -> Option<&str> {
+    self.0.alg()
+  }
+
+  /// Returns the key id of the wrapped [`Jwk`], if set.
+  pub fn kid(&self) -> Option<&str> {
+    self.0.kid()
+  }
+
+  /// Returns a clone of the wrapped [`Jwk`] with _all_ private key components unset.
+  ///
+  /// The `None` variant is returned when `kty = oct` as this key type is not considered public by this library.
+  pub fn to_public(&self) -> Option<Jwk> {
+    self.0.to_public()
+  }
+}
+
+impl TryFrom<Jwk> for SecretJwk {
+  type Error = Error;
+
+  fn try_from(jwk: Jwk) -> Result<Self> {
+    Self::new(jwk)
+  }
+}
+
+impl Debug for SecretJwk {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("SecretJwk")
+      .field("kty", &self.0.kty())
+      .field("alg", &self.0.alg())
+      .field("kid", &self.0.kid())
+      .finish_non_exhaustive()
+  }
+}
+
+impl Zeroize for SecretJwk {
+  fn zeroize(&mut self) {
+    self.0.zeroize();
+  }
+}
+
+// The wrapped `Jwk` zeroizes its parameters when dropped.
+impl ZeroizeOnDrop for SecretJwk {}
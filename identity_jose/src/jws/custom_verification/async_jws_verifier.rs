@@ -0,0 +1,60 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use super::JwsVerifier;
+use super::SignatureVerificationError;
+use super::SignatureVerificationErrorKind;
+use super::VerificationInput;
+use crate::jwk::Jwk;
+
+/// Trait for cryptographically verifying a JWS signature asynchronously, e.g. by a remote key management or
+/// attestation service.
+///
+/// This is the asynchronous counterpart of [`JwsVerifier`], accepted by
+/// [`JwsValidationItem::verify_async`](crate::jws::JwsValidationItem::verify_async). Every [`JwsVerifier`] that
+/// is [`Sync`] is also an [`AsyncJwsVerifier`].
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait AsyncJwsVerifier {
+  /// Validate the `decoded_signature` against the `signing_input` in the manner defined by `alg` using the
+  /// `public_key`.
+  ///
+  /// See [`JwsVerifier::verify`].
+  async fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError>;
+
+  /// Validate the `decoded_signature` against the `signing_input` in the manner defined by `alg` for the blockchain
+  /// account identified by the [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-10.md)
+  /// `account_id`.
+  ///
+  /// See [`JwsVerifier::verify_blockchain_account`]. The default implementation errors with
+  /// [`SignatureVerificationErrorKind::UnsupportedKeyType`].
+  async fn verify_blockchain_account(
+    &self,
+    input: VerificationInput,
+    account_id: &str,
+  ) -> Result<(), SignatureVerificationError> {
+    let _ = (input, account_id);
+    Err(SignatureVerificationErrorKind::UnsupportedKeyType.into())
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<T> AsyncJwsVerifier for T
+where
+  T: JwsVerifier + Sync + ?Sized,
+{
+  async fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    <T as JwsVerifier>::verify(self, input, public_key)
+  }
+
+  async fn verify_blockchain_account(
+    &self,
+    input: VerificationInput,
+    account_id: &str,
+  ) -> Result<(), SignatureVerificationError> {
+    <T as JwsVerifier>::verify_blockchain_account(self, input, account_id)
+  }
+}
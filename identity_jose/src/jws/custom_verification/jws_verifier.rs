@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::SignatureVerificationError;
+use super::SignatureVerificationErrorKind;
 use crate::jwk::Jwk;
 use crate::jws::JwsAlgorithm;
 
@@ -48,12 +49,35 @@ pub trait JwsVerifier {
   /// [`SignatureVerificationErrorKind::UnsupportedAlg`](crate::jws::SignatureVerificationErrorKind::UnsupportedAlg) if
   /// they are not interested in supporting a given algorithm.
   fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError>;
+
+  /// Validate the `decoded_signature` against the `signing_input` in the manner defined by `alg` for the blockchain
+  /// account identified by the [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-10.md)
+  /// `account_id`, by recovering the signer's public key from the signature.
+  ///
+  /// The default implementation errors with
+  /// [`SignatureVerificationErrorKind::UnsupportedKeyType`].
+  fn verify_blockchain_account(
+    &self,
+    input: VerificationInput,
+    account_id: &str,
+  ) -> Result<(), SignatureVerificationError> {
+    let _ = (input, account_id);
+    Err(SignatureVerificationErrorKind::UnsupportedKeyType.into())
+  }
 }
 
 impl JwsVerifier for Box<dyn JwsVerifier> {
   fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
     <dyn JwsVerifier>::verify(self, input, public_key)
   }
+
+  fn verify_blockchain_account(
+    &self,
+    input: VerificationInput,
+    account_id: &str,
+  ) -> Result<(), SignatureVerificationError> {
+    <dyn JwsVerifier>::verify_blockchain_account(self, input, account_id)
+  }
 }
 
 // =================================================================================================================
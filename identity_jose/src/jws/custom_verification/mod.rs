@@ -1,7 +1,9 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod async_jws_verifier;
 mod error;
 mod jws_verifier;
+pub use async_jws_verifier::*;
 pub use error::*;
 pub use jws_verifier::*;
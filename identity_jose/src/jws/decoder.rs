@@ -16,6 +16,7 @@ use crate::jwu::filter_non_empty_bytes;
 use crate::jwu::parse_utf8;
 use crate::jwu::validate_jws_headers;
 
+use super::AsyncJwsVerifier;
 use super::JwsVerifier;
 use super::VerificationInput;
 
@@ -141,6 +142,74 @@ impl<'a> JwsValidationItem<'a> {
   where
     T: JwsVerifier,
   {
+    let (input, decoded): (VerificationInput, DecodedJws<'a>) = self.into_verification_input(Some(public_key))?;
+    // Call verifier
+    verifier
+      .verify(input, public_key)
+      .map_err(Error::SignatureVerificationError)?;
+
+    Ok(decoded)
+  }
+
+  /// Version of [`Self::verify`] for signatures of the blockchain account identified by the
+  /// [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-10.md) `account_id`, passing the
+  /// [`VerificationInput`] to [`JwsVerifier::verify_blockchain_account`].
+  ///
+  /// # Errors
+  /// Apart from the fallible call to [`JwsVerifier::verify_blockchain_account`] this method errors under the same
+  /// conditions as [`Self::verify`].
+  pub fn verify_blockchain_account<T>(self, verifier: &T, account_id: &str) -> Result<DecodedJws<'a>>
+  where
+    T: JwsVerifier,
+  {
+    let (input, decoded): (VerificationInput, DecodedJws<'a>) = self.into_verification_input(None)?;
+    verifier
+      .verify_blockchain_account(input, account_id)
+      .map_err(Error::SignatureVerificationError)?;
+
+    Ok(decoded)
+  }
+
+  /// Asynchronous version of [`Self::verify`], passing the [`VerificationInput`] to an [`AsyncJwsVerifier`].
+  ///
+  /// # Errors
+  /// Apart from the fallible call to [`AsyncJwsVerifier::verify`] this method errors under the same conditions as
+  /// [`Self::verify`].
+  pub async fn verify_async<T>(self, verifier: &T, public_key: &Jwk) -> Result<DecodedJws<'a>>
+  where
+    T: AsyncJwsVerifier + ?Sized,
+  {
+    let (input, decoded): (VerificationInput, DecodedJws<'a>) = self.into_verification_input(Some(public_key))?;
+    // Call verifier
+    verifier
+      .verify(input, public_key)
+      .await
+      .map_err(Error::SignatureVerificationError)?;
+
+    Ok(decoded)
+  }
+
+  /// Asynchronous version of [`Self::verify_blockchain_account`], passing the [`VerificationInput`] to an
+  /// [`AsyncJwsVerifier`].
+  ///
+  /// # Errors
+  /// Apart from the fallible call to [`AsyncJwsVerifier::verify_blockchain_account`] this method errors under the
+  /// same conditions as [`Self::verify`].
+  pub async fn verify_blockchain_account_async<T>(self, verifier: &T, account_id: &str) -> Result<DecodedJws<'a>>
+  where
+    T: AsyncJwsVerifier + ?Sized,
+  {
+    let (input, decoded): (VerificationInput, DecodedJws<'a>) = self.into_verification_input(None)?;
+    verifier
+      .verify_blockchain_account(input, account_id)
+      .await
+      .map_err(Error::SignatureVerificationError)?;
+
+    Ok(decoded)
+  }
+
+  /// Constructs the [`VerificationInput`] to pass to a verifier and the [`DecodedJws`] to return if it succeeds.
+  fn into_verification_input(self, public_key: Option<&Jwk>) -> Result<(VerificationInput, DecodedJws<'a>)> {
     // Destructure data
     let JwsValidationItem {
       headers,
@@ -156,7 +225,9 @@ impl<'a> JwsValidationItem<'a> {
 
     // Extract and validate alg from the protected header.
     let alg: JwsAlgorithm = protected.alg().ok_or(Error::ProtectedHeaderWithoutAlg)?;
-    public_key.check_alg(alg.name())?;
+    if let Some(public_key) = public_key {
+      public_key.check_alg(alg.name())?;
+    }
 
     // Construct verification input
     let input = VerificationInput {
@@ -164,16 +235,15 @@ impl<'a> JwsValidationItem<'a> {
       signing_input,
       decoded_signature,
     };
-    // Call verifier
-    verifier
-      .verify(input, public_key)
-      .map_err(Error::SignatureVerificationError)?;
 
-    Ok(DecodedJws {
-      protected,
-      unprotected,
-      claims,
-    })
+    Ok((
+      input,
+      DecodedJws {
+        protected,
+        unprotected,
+        claims,
+      },
+    ))
   }
 }
 
@@ -10,3 +10,4 @@ mod rfc7638;
 mod rfc7797;
 mod rfc8037;
 mod roundtrip;
+mod secret_jwk;
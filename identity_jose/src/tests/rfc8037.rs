@@ -2,11 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::jwk::Jwk;
+use crate::jws::AsyncJwsVerifier;
 use crate::jws::CompactJwsEncoder;
 use crate::jws::Decoder;
 use crate::jws::JwsAlgorithm;
 use crate::jws::JwsHeader;
 use crate::jws::JwsVerifierFn;
+use crate::jws::SignatureVerificationError;
 use crate::jws::VerificationInput;
 use crate::tests::ed25519;
 
@@ -67,3 +69,60 @@ fn test_rfc8037_ed25519() {
     assert_eq!(token, token_with_default);
   }
 }
+
+#[tokio::test]
+async fn test_rfc8037_ed25519_async() {
+  struct TestVector {
+    private_jwk: &'static str,
+    public_jwk: &'static str,
+    #[allow(dead_code)]
+    thumbprint_b64: &'static str,
+    header: &'static str,
+    payload: &'static str,
+    #[allow(dead_code)]
+    encoded: &'static str,
+  }
+
+  struct RemoteVerifier;
+
+  #[async_trait::async_trait]
+  impl AsyncJwsVerifier for RemoteVerifier {
+    async fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+      ed25519::verify(input, public_key)
+    }
+  }
+
+  static TVS: &[TestVector] = &include!("fixtures/rfc8037_ed25519.rs");
+
+  for tv in TVS {
+    let secret: Jwk = serde_json::from_str(tv.private_jwk).unwrap();
+    let public: Jwk = serde_json::from_str(tv.public_jwk).unwrap();
+
+    let header: JwsHeader = serde_json::from_str(tv.header).unwrap();
+    let encoder: CompactJwsEncoder<'_> = CompactJwsEncoder::new(tv.payload.as_bytes(), &header).unwrap();
+    let signature = ed25519::sign(encoder.signing_input(), &secret);
+    let jws: String = encoder.into_jws(signature.as_ref());
+
+    let decoder = Decoder::new();
+    let token = decoder
+      .decode_compact_serialization(jws.as_bytes(), None)
+      .unwrap()
+      .verify_async(&RemoteVerifier, &public)
+      .await
+      .unwrap();
+
+    assert_eq!(token.protected, header);
+    assert_eq!(token.claims, tv.payload.as_bytes());
+
+    // Every synchronous verifier is also an asynchronous one.
+    let jws_verifier = JwsVerifierFn::from(ed25519::verify);
+    let token_with_sync = decoder
+      .decode_compact_serialization(jws.as_bytes(), None)
+      .unwrap()
+      .verify_async(&jws_verifier, &public)
+      .await
+      .unwrap();
+
+    assert_eq!(token, token_with_sync);
+  }
+}
@@ -0,0 +1,36 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jwk::Jwk;
+use crate::jwk::SecretJwk;
+
+const PRIVATE_KEY: &str = r#"
+  {
+    "kty": "OKP",
+    "crv": "Ed25519",
+    "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+    "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+    "kid": "key-0"
+  }"#;
+
+#[test]
+fn test_secret_jwk_redacts_private_params() {
+  let secret: SecretJwk = serde_json::from_str(PRIVATE_KEY).unwrap();
+  let debug: String = format!("{secret:?}");
+  assert!(debug.contains("key-0"));
+  assert!(!debug.contains("nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"));
+
+  let public: Jwk = secret.to_public().unwrap();
+  assert!(public.is_public());
+  assert_eq!(public.kid(), Some("key-0"));
+}
+
+#[test]
+fn test_secret_jwk_rejects_public_keys() {
+  let public: Jwk = serde_json::from_str::<SecretJwk>(PRIVATE_KEY)
+    .unwrap()
+    .to_public()
+    .unwrap();
+  assert!(SecretJwk::new(public.clone()).is_err());
+  assert!(serde_json::from_value::<SecretJwk>(serde_json::to_value(public).unwrap()).is_err());
+}
@@ -71,4 +71,35 @@ pub enum ErrorCause {
   /// No client attached to the specific network.
   #[error("none of the attached clients support the network {0}")]
   UnsupportedNetwork(String),
+  /// A handler of a [`FallbackChain`](crate::FallbackChain) did not complete within its timeout.
+  #[error("did resolution failed: the handler did not complete within {timeout:?}")]
+  HandlerTimeout {
+    /// The timeout of the handler.
+    timeout: std::time::Duration,
+  },
+  /// All handlers of a [`FallbackChain`](crate::FallbackChain) failed to resolve the DID.
+  #[error("did resolution failed: all {} handlers of the fallback chain failed", errors.len())]
+  FallbackExhausted {
+    /// The errors of the handlers, in the order they were tried.
+    errors: Vec<Error>,
+  },
+  /// The resolved DID document does not satisfy the rules of the required
+  /// [`DocumentProfile`](identity_document::profile::DocumentProfile).
+  #[error("did resolution failed: the resolved document does not satisfy the required profile")]
+  ProfileViolation(#[source] identity_document::profile::DocumentProfileError),
+  /// The resolved DID document is deactivated, reported by resolvers configured with
+  /// [`Resolver::with_strict_deactivation`](crate::Resolver::with_strict_deactivation).
+  #[error("did resolution failed: the DID \"{did}\" is deactivated")]
+  #[non_exhaustive]
+  DIDDeactivated {
+    /// The deactivated DID.
+    did: String,
+    /// The time the DID was deactivated, if known from the `updated` metadata of the document.
+    ///
+    /// A DID deactivated by emptying its state metadata carries no timestamp.
+    deactivated_at: Option<identity_core::common::Timestamp>,
+  },
+  /// The DID URL could not be dereferenced, e.g. because it does not identify a resource of the resolved document.
+  #[error("did url dereferencing failed: {0}")]
+  DereferencingError(&'static str),
 }
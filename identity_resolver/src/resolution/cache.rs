@@ -0,0 +1,143 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use async_trait::async_trait;
+use identity_core::common::Timestamp;
+
+/// A document cached by a [`CachingResolver`](crate::CachingResolver), together with the time it was resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedDocument<DOC> {
+  document: DOC,
+  resolved: Timestamp,
+}
+
+impl<DOC> CachedDocument<DOC> {
+  /// Creates a new [`CachedDocument`] of a `document` resolved at `resolved`.
+  pub fn new(document: DOC, resolved: Timestamp) -> Self {
+    Self { document, resolved }
+  }
+
+  /// Returns a reference to the cached document.
+  pub fn document(&self) -> &DOC {
+    &self.document
+  }
+
+  /// Consumes the entry, returning the cached document.
+  pub fn into_document(self) -> DOC {
+    self.document
+  }
+
+  /// Returns the time at which the document was resolved.
+  pub fn resolved(&self) -> Timestamp {
+    self.resolved
+  }
+}
+
+/// A store of resolved documents keyed by DID, backing a [`CachingResolver`](crate::CachingResolver).
+///
+/// Implementations may be backed by an external store shared between several verifiers, e.g. Redis. Since a cache
+/// only serves to avoid resolutions, failures of the store are expected to be handled by the implementation, e.g. by
+/// treating them as cache misses.
+#[async_trait]
+pub trait ResolutionCache<DOC: Send + Sync + 'static>: Send + Sync {
+  /// Returns the document cached for the given `did`, if any.
+  async fn get(&self, did: &str) -> Option<CachedDocument<DOC>>;
+
+  /// Caches `document` for the given `did`, replacing any document previously cached for it.
+  async fn insert(&self, did: &str, document: CachedDocument<DOC>);
+
+  /// Removes the document cached for the given `did`, if any.
+  async fn remove(&self, did: &str);
+}
+
+/// An in-memory [`ResolutionCache`] holding at most a fixed number of documents, evicting the least recently used
+/// document when full.
+#[derive(Debug)]
+pub struct InMemoryResolutionCache<DOC> {
+  max_size: usize,
+  state: Mutex<LruState<DOC>>,
+}
+
+#[derive(Debug)]
+struct LruState<DOC> {
+  entries: HashMap<String, (CachedDocument<DOC>, u64)>,
+  tick: u64,
+}
+
+impl<DOC> InMemoryResolutionCache<DOC> {
+  /// Creates a new [`InMemoryResolutionCache`] holding at most `max_size` documents.
+  pub fn new(max_size: usize) -> Self {
+    Self {
+      max_size,
+      state: Mutex::new(LruState {
+        entries: HashMap::new(),
+        tick: 0,
+      }),
+    }
+  }
+
+  /// Returns the maximum number of documents held by the cache.
+  pub fn max_size(&self) -> usize {
+    self.max_size
+  }
+
+  /// Returns the number of documents currently held by the cache.
+  pub fn len(&self) -> usize {
+    self.state.lock().unwrap_or_else(PoisonError::into_inner).entries.len()
+  }
+
+  /// Returns `true` if the cache holds no documents.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+}
+
+#[async_trait]
+impl<DOC> ResolutionCache<DOC> for InMemoryResolutionCache<DOC>
+where
+  DOC: Clone + Send + Sync + 'static,
+{
+  async fn get(&self, did: &str) -> Option<CachedDocument<DOC>> {
+    let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+    state.tick += 1;
+    let tick: u64 = state.tick;
+    state.entries.get_mut(did).map(|(document, last_used)| {
+      *last_used = tick;
+      document.clone()
+    })
+  }
+
+  async fn insert(&self, did: &str, document: CachedDocument<DOC>) {
+    if self.max_size == 0 {
+      return;
+    }
+
+    let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+    if !state.entries.contains_key(did) && state.entries.len() >= self.max_size {
+      let least_recently_used: Option<String> = state
+        .entries
+        .iter()
+        .min_by_key(|(_, (_, last_used))| *last_used)
+        .map(|(did, _)| did.clone());
+      if let Some(did) = least_recently_used {
+        state.entries.remove(&did);
+      }
+    }
+    state.tick += 1;
+    let tick: u64 = state.tick;
+    state.entries.insert(did.to_owned(), (document, tick));
+  }
+
+  async fn remove(&self, did: &str) {
+    self
+      .state
+      .lock()
+      .unwrap_or_else(PoisonError::into_inner)
+      .entries
+      .remove(did);
+  }
+}
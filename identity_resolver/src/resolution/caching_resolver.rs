@@ -0,0 +1,208 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::future::Future;
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_did::CoreDID;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+
+use crate::Result;
+
+use super::cache::CachedDocument;
+use super::cache::InMemoryResolutionCache;
+use super::cache::ResolutionCache;
+use super::commands::Command;
+use super::commands::SendSyncCommand;
+use super::resolver::Resolver;
+
+/// Callback starting the revalidation of the document of the given DID in the background.
+type RevalidateCallback = Arc<dyn Fn(String) + Send + Sync>;
+
+/// A [`Resolver`] caching resolved documents in a [`ResolutionCache`].
+///
+/// Cached documents are served for `ttl` after their resolution. Afterwards, they may still be served for
+/// `stale_ttl` while being revalidated:
+/// - By default, the document is resolved again and the stale document only served if that resolution fails.
+/// - With [`CachingResolver::with_background_revalidation`], the stale document is served immediately while it is
+///   resolved again in the background.
+///
+/// # Example
+///
+/// ```
+/// # use identity_core::common::Duration;
+/// # use identity_did::CoreDID;
+/// # use identity_document::document::CoreDocument;
+/// # use identity_resolver::CachingResolver;
+/// # use identity_resolver::InMemoryResolutionCache;
+/// # use identity_resolver::Resolver;
+///
+/// async fn resolve_foo(did: CoreDID) -> std::result::Result<CoreDocument, std::io::Error> {
+///   todo!()
+/// }
+///
+/// let mut resolver: Resolver = Resolver::new();
+/// resolver.attach_handler("foo".to_owned(), resolve_foo);
+///
+/// // Cache up to 1000 documents for 5 minutes, and serve them for another minute while revalidating.
+/// let resolver: CachingResolver =
+///   CachingResolver::new(resolver, InMemoryResolutionCache::new(1000), Duration::minutes(5))
+///     .with_stale_ttl(Duration::minutes(1));
+/// ```
+pub struct CachingResolver<DOC = CoreDocument, CMD = SendSyncCommand<DOC>, C = InMemoryResolutionCache<DOC>>
+where
+  CMD: for<'r> Command<'r, Result<DOC>>,
+  DOC: Send + Sync + 'static,
+  C: ResolutionCache<DOC>,
+{
+  resolver: Arc<Resolver<DOC, CMD>>,
+  cache: Arc<C>,
+  ttl: Duration,
+  stale_ttl: Duration,
+  revalidate: Option<RevalidateCallback>,
+}
+
+impl<DOC, CMD, C> CachingResolver<DOC, CMD, C>
+where
+  CMD: for<'r> Command<'r, Result<DOC>>,
+  DOC: Clone + Send + Sync + 'static,
+  C: ResolutionCache<DOC>,
+{
+  /// Constructs a new [`CachingResolver`] caching the documents resolved by `resolver` in `cache` for `ttl`.
+  pub fn new(resolver: Resolver<DOC, CMD>, cache: C, ttl: Duration) -> Self {
+    Self {
+      resolver: Arc::new(resolver),
+      cache: Arc::new(cache),
+      ttl,
+      stale_ttl: Duration::seconds(0),
+      revalidate: None,
+    }
+  }
+
+  /// Sets the duration for which cached documents may still be served after their `ttl` elapsed, while they are
+  /// revalidated.
+  pub fn with_stale_ttl(mut self, stale_ttl: Duration) -> Self {
+    self.stale_ttl = stale_ttl;
+    self
+  }
+
+  /// Returns a reference to the wrapped [`Resolver`].
+  pub fn resolver(&self) -> &Resolver<DOC, CMD> {
+    &self.resolver
+  }
+
+  /// Returns a reference to the [`ResolutionCache`].
+  pub fn cache(&self) -> &C {
+    &self.cache
+  }
+
+  /// Fetches the DID Document of the given DID, serving it from the cache if possible.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the document is not cached and the resolver has not been configured to handle the method of the DID
+  /// or the resolution process itself fails.
+  pub async fn resolve<D: DID>(&self, did: &D) -> Result<DOC> {
+    let now: Timestamp = Timestamp::now_utc();
+    if let Some(cached) = self.cache.get(did.as_str()).await {
+      let fresh_until: Option<Timestamp> = cached.resolved().checked_add(self.ttl);
+      if fresh_until.map_or(true, |fresh_until| now < fresh_until) {
+        return Ok(cached.into_document());
+      }
+
+      let stale_until: Option<Timestamp> = fresh_until.and_then(|fresh_until| fresh_until.checked_add(self.stale_ttl));
+      if stale_until.map_or(true, |stale_until| now < stale_until) {
+        if let Some(revalidate) = &self.revalidate {
+          revalidate(did.as_str().to_owned());
+          return Ok(cached.into_document());
+        }
+        return self
+          .resolve_and_cache(did)
+          .await
+          .or_else(|_| Ok(cached.into_document()));
+      }
+    }
+
+    self.resolve_and_cache(did).await
+  }
+
+  /// Removes the document of the given DID from the cache, such that it is resolved again on the next request.
+  pub async fn invalidate<D: DID>(&self, did: &D) {
+    self.cache.remove(did.as_str()).await;
+  }
+
+  async fn resolve_and_cache<D: DID>(&self, did: &D) -> Result<DOC> {
+    let document: DOC = self.resolver.resolve(did).await?;
+    self
+      .cache
+      .insert(
+        did.as_str(),
+        CachedDocument::new(document.clone(), Timestamp::now_utc()),
+      )
+      .await;
+    Ok(document)
+  }
+}
+
+impl<DOC, C> CachingResolver<DOC, SendSyncCommand<DOC>, C>
+where
+  DOC: Clone + Send + Sync + 'static,
+  C: ResolutionCache<DOC> + 'static,
+{
+  /// Serve stale documents immediately and revalidate them in the background, using `spawn` to run the
+  /// revalidation, e.g. `|future| { tokio::spawn(future); }`.
+  ///
+  /// Only a single revalidation per DID is started at a time. Failed revalidations leave the cached document
+  /// unchanged.
+  pub fn with_background_revalidation<S>(mut self, spawn: S) -> Self
+  where
+    S: Fn(Pin<Box<dyn Future<Output = ()> + Send>>) + Send + Sync + 'static,
+  {
+    let resolver: Arc<Resolver<DOC, SendSyncCommand<DOC>>> = Arc::clone(&self.resolver);
+    let cache: Arc<C> = Arc::clone(&self.cache);
+    let in_flight: Arc<Mutex<HashSet<String>>> = Arc::default();
+
+    self.revalidate = Some(Arc::new(move |did: String| {
+      if !in_flight
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(did.clone())
+      {
+        return;
+      }
+
+      let (resolver, cache, in_flight) = (Arc::clone(&resolver), Arc::clone(&cache), Arc::clone(&in_flight));
+      spawn(Box::pin(async move {
+        if let Ok(core_did) = CoreDID::parse(&did) {
+          if let Ok(document) = resolver.resolve(&core_did).await {
+            cache
+              .insert(&did, CachedDocument::new(document, Timestamp::now_utc()))
+              .await;
+          }
+        }
+        in_flight.lock().unwrap_or_else(PoisonError::into_inner).remove(&did);
+      }));
+    }));
+    self
+  }
+}
+
+impl<DOC, CMD, C> std::fmt::Debug for CachingResolver<DOC, CMD, C>
+where
+  CMD: for<'r> Command<'r, Result<DOC>>,
+  DOC: Send + Sync + 'static,
+  C: ResolutionCache<DOC>,
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CachingResolver")
+      .field("background_revalidation", &self.revalidate.is_some())
+      .finish_non_exhaustive()
+  }
+}
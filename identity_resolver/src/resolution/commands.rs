@@ -8,6 +8,9 @@ use crate::Error;
 use crate::ErrorCause;
 use crate::Result;
 use std::pin::Pin;
+use std::sync::Arc;
+
+use super::fallback::FallbackChain;
 
 /// Internal trait used by the resolver to apply the command pattern.
 ///
@@ -89,6 +92,17 @@ impl<DOC: 'static> SendSyncCommand<DOC> {
 
     Self { fun }
   }
+
+  /// Converts a [`FallbackChain`] to a command trying its handlers in order.
+  pub(super) fn from_fallback_chain(chain: FallbackChain<DOC>) -> Self {
+    let chain: Arc<FallbackChain<DOC>> = Arc::new(chain);
+    let fun: SendSyncCallback<DOC> = Box::new(move |input: &str| {
+      let chain_clone: Arc<FallbackChain<DOC>> = Arc::clone(&chain);
+      Box::pin(async move { chain_clone.apply(input).await })
+    });
+
+    Self { fun }
+  }
 }
 
 // ===========================================================================
@@ -0,0 +1,122 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_document::service::Service;
+use identity_document::service::ServiceEndpoint;
+use identity_verification::VerificationMethod;
+
+use crate::Error;
+use crate::ErrorCause;
+use crate::Result;
+
+use super::commands::Command;
+use super::resolver::Resolver;
+
+const SERVICE: &str = "service";
+const RELATIVE_REF: &str = "relativeRef";
+
+/// The resource a DID URL dereferences to, as returned by [`Resolver::dereference`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum DereferencedResource<DOC> {
+  /// The DID document, for a DID URL without path, query and fragment.
+  Document(DOC),
+  /// The verification method identified by the fragment of the DID URL.
+  Method(VerificationMethod),
+  /// The service identified by the fragment of the DID URL.
+  Service(Service),
+  /// The service endpoint URL selected by the `service` and `relativeRef` query parameters of the DID URL.
+  Url(Url),
+}
+
+impl<DOC, M> Resolver<DOC, M>
+where
+  M: for<'r> Command<'r, Result<DOC>>,
+  DOC: AsRef<CoreDocument>,
+{
+  /// Dereferences `did_url` as defined by the
+  /// [DID URL Dereferencing specification](https://w3c-ccg.github.io/did-resolution/#dereferencing).
+  ///
+  /// After resolving the DID document of `did_url`, the DID URL is dereferenced to:
+  /// - the service endpoint URL of the service whose id fragment is given by the `service` query parameter. If a
+  ///   `relativeRef` parameter is present, it is resolved against the endpoint URL as a relative reference, e.g.
+  ///   `did:example:123?service=files&relativeRef=/path` dereferences to `https://example.com/path` if the endpoint of
+  ///   the `files` service is `https://example.com`. A fragment of `did_url` is appended to the URL.
+  /// - the verification method or service identified by the fragment of `did_url`.
+  /// - the DID document itself, if `did_url` has no path, query or fragment.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the resolution fails or `did_url` cannot be dereferenced, in which case the cause is
+  /// [`ErrorCause::DereferencingError`]. Paths and query parameters other than `service` and `relativeRef` are
+  /// method-specific and not supported.
+  pub async fn dereference(&self, did_url: &DIDUrl) -> Result<DereferencedResource<DOC>> {
+    let document: DOC = self.resolve(did_url.did()).await?;
+    dereference_document(document, did_url).map_err(|message| Error::new(ErrorCause::DereferencingError(message)))
+  }
+}
+
+fn dereference_document<DOC>(document: DOC, did_url: &DIDUrl) -> Result<DereferencedResource<DOC>, &'static str>
+where
+  DOC: AsRef<CoreDocument>,
+{
+  if did_url.path().is_some_and(|path| !path.is_empty()) {
+    return Err("DID URL paths are not supported");
+  }
+
+  let mut service: Option<String> = None;
+  let mut relative_ref: Option<String> = None;
+  for (key, value) in did_url.query_pairs() {
+    match key.as_ref() {
+      SERVICE => service = Some(value.into_owned()),
+      RELATIVE_REF => relative_ref = Some(value.into_owned()),
+      _ => return Err("unsupported DID URL query parameter"),
+    }
+  }
+
+  let core_document: &CoreDocument = document.as_ref();
+  if let Some(fragment) = service {
+    let service: &Service = core_document
+      .service()
+      .iter()
+      .find(|service| service.id().fragment() == Some(fragment.as_str()))
+      .ok_or("service not found")?;
+    let mut url: Url = select_endpoint_url(service.service_endpoint())?;
+    if let Some(relative_ref) = relative_ref {
+      url = url.join(relative_ref).map_err(|_| "invalid relativeRef")?;
+    }
+    if let Some(fragment) = did_url.fragment() {
+      url.set_fragment(Some(fragment));
+    }
+    return Ok(DereferencedResource::Url(url));
+  }
+  if relative_ref.is_some() {
+    return Err("relativeRef requires a service query parameter");
+  }
+
+  let Some(fragment) = did_url.fragment() else {
+    return Ok(DereferencedResource::Document(document));
+  };
+  if let Some(method) = core_document.resolve_method(did_url, None) {
+    return Ok(DereferencedResource::Method(method.clone()));
+  }
+  core_document
+    .service()
+    .iter()
+    .find(|service| service.id().fragment() == Some(fragment))
+    .cloned()
+    .map(DereferencedResource::Service)
+    .ok_or("no verification method or service matches the fragment")
+}
+
+/// Returns the URL of a service endpoint consisting of a single URL, or the first URL of a set.
+fn select_endpoint_url(endpoint: &ServiceEndpoint) -> Result<Url, &'static str> {
+  match endpoint {
+    ServiceEndpoint::One(url) => Ok(url.clone()),
+    ServiceEndpoint::Set(urls) => urls.head().cloned().ok_or("empty service endpoint"),
+    ServiceEndpoint::Map(_) => Err("service endpoint maps cannot be dereferenced"),
+  }
+}
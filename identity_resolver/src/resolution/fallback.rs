@@ -0,0 +1,199 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::future::Future;
+use core::time::Duration;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures::future::Either;
+use identity_did::DID;
+
+use crate::Error;
+use crate::ErrorCause;
+use crate::Result;
+
+use super::commands::Command;
+use super::commands::SendSyncCommand;
+
+/// Callback returning a future that completes after the given duration.
+type SleepCallback = Arc<dyn Fn(Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
+/// Decides which failures of a handler in a [`FallbackChain`] cause the next handler to be tried.
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub enum FallbackPolicy {
+  /// Try the next handler after any failure, except for a DID the handler is unable to parse.
+  #[default]
+  AnyError,
+  /// Only try the next handler after a handler timed out, returning any other failure immediately.
+  TimeoutOnly,
+  /// Try the next handler whenever the given function returns `true` for the cause of the failure.
+  Custom(fn(&ErrorCause) -> bool),
+}
+
+impl FallbackPolicy {
+  /// Returns whether the next handler should be tried after a handler failed with `error`.
+  pub fn falls_back(&self, error: &Error) -> bool {
+    match self {
+      Self::AnyError => !matches!(error.error_cause(), ErrorCause::DIDParsingError { .. }),
+      Self::TimeoutOnly => matches!(error.error_cause(), ErrorCause::HandlerTimeout { .. }),
+      Self::Custom(predicate) => predicate(error.error_cause()),
+    }
+  }
+}
+
+/// Handlers for the same DID method, tried in order until one of them resolves the DID.
+///
+/// Attach it to a [`Resolver`](crate::Resolver) with
+/// [`Resolver::attach_fallback_chain`](crate::Resolver::attach_fallback_chain()) to keep resolving DIDs during
+/// partial outages, e.g. by trying a primary node, then a public resolver and finally a local cache.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use identity_did::CoreDID;
+/// # use identity_document::document::CoreDocument;
+/// # use identity_resolver::FallbackChain;
+/// # use identity_resolver::Resolver;
+///
+/// async fn resolve_from_node(did: CoreDID) -> std::result::Result<CoreDocument, std::io::Error> {
+///   todo!()
+/// }
+///
+/// async fn resolve_from_cache(did: CoreDID) -> std::result::Result<CoreDocument, std::io::Error> {
+///   todo!()
+/// }
+///
+/// let chain: FallbackChain = FallbackChain::new()
+///   .with_handler_timeout(resolve_from_node, Duration::from_secs(5))
+///   .with_handler(resolve_from_cache)
+///   .with_timer(|duration| Box::pin(async move { /* e.g. tokio::time::sleep(duration).await */ }));
+///
+/// let mut resolver: Resolver = Resolver::new();
+/// resolver.attach_fallback_chain("foo".to_owned(), chain);
+/// ```
+pub struct FallbackChain<DOC: 'static = identity_document::document::CoreDocument> {
+  handlers: Vec<(SendSyncCommand<DOC>, Option<Duration>)>,
+  policy: FallbackPolicy,
+  sleep: Option<SleepCallback>,
+}
+
+impl<DOC: 'static> FallbackChain<DOC> {
+  /// Creates an empty [`FallbackChain`] falling back according to [`FallbackPolicy::AnyError`].
+  pub fn new() -> Self {
+    Self {
+      handlers: Vec::new(),
+      policy: FallbackPolicy::default(),
+      sleep: None,
+    }
+  }
+
+  /// Appends a handler without a timeout to the chain.
+  ///
+  /// The `handler` has the same requirements as the handlers passed to
+  /// [`Resolver::attach_handler`](crate::Resolver::attach_handler()).
+  pub fn with_handler<D, F, Fut, DOCUMENT, E, DIDERR>(mut self, handler: F) -> Self
+  where
+    D: DID + Send + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D) -> Fut + 'static + Clone + Send + Sync,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>> + Send,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    self.handlers.push((SendSyncCommand::new(handler), None));
+    self
+  }
+
+  /// Appends a handler to the chain, which fails with [`ErrorCause::HandlerTimeout`] if it does not complete within
+  /// `timeout`.
+  ///
+  /// Timeouts are only enforced once a timer has been set with [`Self::with_timer`](Self::with_timer()).
+  pub fn with_handler_timeout<D, F, Fut, DOCUMENT, E, DIDERR>(mut self, handler: F, timeout: Duration) -> Self
+  where
+    D: DID + Send + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D) -> Fut + 'static + Clone + Send + Sync,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>> + Send,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    self.handlers.push((SendSyncCommand::new(handler), Some(timeout)));
+    self
+  }
+
+  /// Sets the [`FallbackPolicy`] deciding which failures cause the next handler to be tried.
+  pub fn with_policy(mut self, policy: FallbackPolicy) -> Self {
+    self.policy = policy;
+    self
+  }
+
+  /// Sets the timer used to enforce handler timeouts, returning a future that completes after the given duration,
+  /// e.g. `|duration| Box::pin(tokio::time::sleep(duration))`.
+  pub fn with_timer<S>(mut self, sleep: S) -> Self
+  where
+    S: Fn(Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync + 'static,
+  {
+    self.sleep = Some(Arc::new(sleep));
+    self
+  }
+
+  /// Returns the number of handlers in the chain.
+  pub fn len(&self) -> usize {
+    self.handlers.len()
+  }
+
+  /// Returns whether the chain has no handlers.
+  pub fn is_empty(&self) -> bool {
+    self.handlers.is_empty()
+  }
+
+  /// Resolves `did` with the handlers of the chain in order, returning the first document resolved.
+  ///
+  /// Fails with the error of the last handler tried if the [`FallbackPolicy`] stops the chain, or with
+  /// [`ErrorCause::FallbackExhausted`] if all handlers failed.
+  pub(crate) async fn apply(&self, did: &str) -> Result<DOC> {
+    let mut errors: Vec<Error> = Vec::with_capacity(self.handlers.len());
+    for (command, timeout) in &self.handlers {
+      let error: Error = match self.apply_handler(command, *timeout, did).await {
+        Ok(document) => return Ok(document),
+        Err(error) => error,
+      };
+      if !self.policy.falls_back(&error) {
+        return Err(error);
+      }
+      errors.push(error);
+    }
+
+    Err(Error::new(ErrorCause::FallbackExhausted { errors }))
+  }
+
+  async fn apply_handler(&self, command: &SendSyncCommand<DOC>, timeout: Option<Duration>, did: &str) -> Result<DOC> {
+    let (Some(timeout), Some(sleep)) = (timeout, &self.sleep) else {
+      return command.apply(did).await;
+    };
+
+    match futures::future::select(command.apply(did), sleep(timeout)).await {
+      Either::Left((result, _)) => result,
+      Either::Right(_) => Err(Error::new(ErrorCause::HandlerTimeout { timeout })),
+    }
+  }
+}
+
+impl<DOC: 'static> Default for FallbackChain<DOC> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<DOC: 'static> std::fmt::Debug for FallbackChain<DOC> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("FallbackChain")
+      .field("handlers", &self.handlers)
+      .field("policy", &self.policy)
+      .field("timer", &self.sleep.is_some())
+      .finish()
+  }
+}
@@ -1,7 +1,12 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod cache;
+mod caching_resolver;
 mod commands;
+mod dereference;
+mod fallback;
+mod resolution_result;
 mod resolver;
 #[cfg(test)]
 mod tests;
@@ -9,6 +14,14 @@ mod tests;
 use self::commands::SingleThreadedCommand;
 use identity_document::document::CoreDocument;
 
+pub use cache::CachedDocument;
+pub use cache::InMemoryResolutionCache;
+pub use cache::ResolutionCache;
+pub use caching_resolver::CachingResolver;
+pub use dereference::DereferencedResource;
+pub use fallback::FallbackChain;
+pub use fallback::FallbackPolicy;
+pub use resolution_result::*;
 pub use resolver::Resolver;
 /// Alias for a [`Resolver`] that is not [`Send`] + [`Sync`].
 pub type SingleThreadedResolver<DOC = CoreDocument> = Resolver<DOC, SingleThreadedCommand<DOC>>;
@@ -0,0 +1,207 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_document::document::CoreDocument;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Error;
+use crate::ErrorCause;
+
+/// The JSON-LD context of a [`DIDResolutionResult`].
+const DID_RESOLUTION_CONTEXT: &str = "https://w3id.org/did-resolution/v1";
+
+/// The media type of the DID documents in a [`DIDResolutionResult`].
+const DID_JSON_CONTENT_TYPE: &str = "application/did+json";
+
+/// The result of resolving a DID, as defined by the
+/// [DID Resolution specification](https://w3c-ccg.github.io/did-resolution/#did-resolution-result).
+///
+/// Created by [`Resolver::resolve_with_metadata`](crate::Resolver::resolve_with_metadata). Failures are reported
+/// through the [`DIDResolutionMetadata`] instead of an error, such that the result can be returned as is by
+/// resolution endpoints.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DIDResolutionResult<DOC = CoreDocument> {
+  #[serde(rename = "@context", default = "did_resolution_context")]
+  context: String,
+  /// The resolved DID document, if the resolution succeeded.
+  #[serde(default)]
+  pub did_document: Option<DOC>,
+  /// Metadata about the resolution process.
+  pub did_resolution_metadata: DIDResolutionMetadata,
+  /// Metadata about the resolved DID document.
+  pub did_document_metadata: DIDDocumentMetadata,
+}
+
+fn did_resolution_context() -> String {
+  DID_RESOLUTION_CONTEXT.to_owned()
+}
+
+impl<DOC> DIDResolutionResult<DOC> {
+  /// Creates the result of a successful resolution of `document`.
+  pub fn from_document(document: DOC) -> Self
+  where
+    DOC: ToDocumentMetadata,
+  {
+    Self {
+      context: did_resolution_context(),
+      did_resolution_metadata: DIDResolutionMetadata {
+        content_type: Some(DID_JSON_CONTENT_TYPE.to_owned()),
+        ..DIDResolutionMetadata::default()
+      },
+      did_document_metadata: document.document_metadata(),
+      did_document: Some(document),
+    }
+  }
+
+  /// Creates the result of a failed resolution.
+  pub fn from_error(error: &Error) -> Self {
+    Self {
+      context: did_resolution_context(),
+      did_document: None,
+      did_resolution_metadata: DIDResolutionMetadata {
+        error: Some(ResolutionErrorCode::from_error_cause(error.error_cause())),
+        error_message: Some(error.to_string()),
+        ..DIDResolutionMetadata::default()
+      },
+      did_document_metadata: DIDDocumentMetadata::default(),
+    }
+  }
+
+  /// Returns `true` if the resolution succeeded.
+  pub fn is_ok(&self) -> bool {
+    self.did_resolution_metadata.error.is_none()
+  }
+}
+
+/// Metadata about the resolution process of a [`DIDResolutionResult`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DIDResolutionMetadata {
+  /// The media type of the resolved DID document.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub content_type: Option<String>,
+  /// The error code of a failed resolution.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<ResolutionErrorCode>,
+  /// A human-readable description of the error of a failed resolution.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error_message: Option<String>,
+  /// Additional resolution metadata.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+/// The error codes of a failed resolution, as defined by the
+/// [DID Resolution specification](https://w3c-ccg.github.io/did-resolution/#errors).
+///
+/// Note that deactivated DIDs are not reported as an error, but through [`DIDDocumentMetadata::deactivated`], unless
+/// the [`Resolver`](crate::Resolver) is configured with
+/// [`Resolver::with_strict_deactivation`](crate::Resolver::with_strict_deactivation).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResolutionErrorCode {
+  /// The DID is not valid.
+  InvalidDid,
+  /// The DID does not exist.
+  NotFound,
+  /// The requested representation of the DID document is not supported.
+  RepresentationNotSupported,
+  /// The DID method is not supported.
+  MethodNotSupported,
+  /// An unexpected error occurred during resolution.
+  InternalError,
+}
+
+impl ResolutionErrorCode {
+  /// Returns the error code corresponding to the given resolver error cause.
+  pub fn from_error_cause(cause: &ErrorCause) -> Self {
+    match cause {
+      ErrorCause::DIDParsingError { .. } => Self::InvalidDid,
+      ErrorCause::UnsupportedMethodError { .. } | ErrorCause::UnsupportedNetwork(_) => Self::MethodNotSupported,
+      ErrorCause::HandlerError { source } => Self::from_handler_error(source.as_ref()),
+      ErrorCause::HandlerTimeout { .. } | ErrorCause::ProfileViolation(_) => Self::InternalError,
+      ErrorCause::DereferencingError(_) => Self::NotFound,
+      // A strict resolver treats deactivated DIDs like DIDs without an active document.
+      ErrorCause::DIDDeactivated { .. } => Self::NotFound,
+      // Report the failure of the last handler tried.
+      ErrorCause::FallbackExhausted { errors } => errors
+        .last()
+        .map_or(Self::InternalError, |error| Self::from_error_cause(error.error_cause())),
+    }
+  }
+
+  fn from_handler_error(source: &(dyn std::error::Error + Send + Sync + 'static)) -> Self {
+    // Handlers attached by this crate may wrap their errors in a resolver error.
+    if let Some(error) = source.downcast_ref::<Error>() {
+      return Self::from_error_cause(error.error_cause());
+    }
+    #[cfg(feature = "iota")]
+    if let Some(error) = source.downcast_ref::<identity_iota_core::Error>() {
+      return match error {
+        identity_iota_core::Error::DIDResolutionError(iota_sdk::client::Error::NoOutput(_)) => Self::NotFound,
+        identity_iota_core::Error::NetworkMismatch { .. } => Self::MethodNotSupported,
+        _ => Self::InternalError,
+      };
+    }
+    Self::InternalError
+  }
+}
+
+/// Metadata about the DID document of a [`DIDResolutionResult`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DIDDocumentMetadata {
+  /// The time the DID document was created.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub created: Option<Timestamp>,
+  /// The time the DID document was last updated.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub updated: Option<Timestamp>,
+  /// Whether the DID is deactivated.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub deactivated: Option<bool>,
+  /// The version of the DID document.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// Additional document metadata.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+/// Documents that provide the [`DIDDocumentMetadata`] of a [`DIDResolutionResult`].
+pub trait ToDocumentMetadata {
+  /// Returns the metadata of the document.
+  fn document_metadata(&self) -> DIDDocumentMetadata {
+    DIDDocumentMetadata::default()
+  }
+}
+
+impl ToDocumentMetadata for CoreDocument {}
+
+#[cfg(feature = "iota")]
+impl ToDocumentMetadata for identity_iota_core::IotaDocument {
+  fn document_metadata(&self) -> DIDDocumentMetadata {
+    let mut properties: Object = Object::new();
+    if let Some(governor_address) = &self.metadata.governor_address {
+      properties.insert("governorAddress".to_owned(), governor_address.clone().into());
+    }
+    if let Some(state_controller_address) = &self.metadata.state_controller_address {
+      properties.insert(
+        "stateControllerAddress".to_owned(),
+        state_controller_address.clone().into(),
+      );
+    }
+
+    DIDDocumentMetadata {
+      created: self.metadata.created,
+      updated: self.metadata.updated,
+      deactivated: self.metadata.deactivated,
+      version_id: None,
+      properties,
+    }
+  }
+}
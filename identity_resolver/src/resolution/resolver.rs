@@ -3,12 +3,14 @@
 
 use core::future::Future;
 use futures::stream::FuturesUnordered;
+use futures::StreamExt;
 use futures::TryStreamExt;
 use identity_did::DIDJwk;
 use identity_did::DID;
 use std::collections::HashSet;
 
 use identity_document::document::CoreDocument;
+use identity_document::profile::DocumentProfile;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 
@@ -19,6 +21,10 @@ use crate::Result;
 use super::commands::Command;
 use super::commands::SendSyncCommand;
 use super::commands::SingleThreadedCommand;
+use super::fallback::FallbackChain;
+use super::resolution_result::DIDDocumentMetadata;
+use super::resolution_result::DIDResolutionResult;
+use super::resolution_result::ToDocumentMetadata;
 
 /// Convenience type for resolving DID documents from different DID methods.   
 ///
@@ -26,11 +32,16 @@ use super::commands::SingleThreadedCommand;
 ///
 /// The resolver will only be able to resolve DID documents for methods it has been configured for. This is done by
 /// attaching method specific handlers with [`Self::attach_handler`](Self::attach_handler()).
+///
+/// Deactivated DID documents are returned like any other document, unless the resolver is configured with
+/// [`Self::with_strict_deactivation`](Self::with_strict_deactivation()).
 pub struct Resolver<DOC = CoreDocument, CMD = SendSyncCommand<DOC>>
 where
   CMD: for<'r> Command<'r, Result<DOC>>,
 {
   command_map: HashMap<String, CMD>,
+  /// Extracts the metadata of resolved documents to reject deactivated ones, if set.
+  deactivation_metadata: Option<fn(&DOC) -> DIDDocumentMetadata>,
   _required: PhantomData<DOC>,
 }
 
@@ -54,16 +65,29 @@ where
   pub fn new() -> Self {
     Self {
       command_map: HashMap::new(),
+      deactivation_metadata: None,
       _required: PhantomData::<DOC>,
     }
   }
 
+  /// Rejects deactivated DID documents with [`ErrorCause::DIDDeactivated`] instead of returning them.
+  ///
+  /// Applies to all resolution methods of this resolver, including those resolving multiple DIDs.
+  pub fn with_strict_deactivation(mut self) -> Self
+  where
+    DOC: ToDocumentMetadata,
+  {
+    self.deactivation_metadata = Some(DOC::document_metadata);
+    self
+  }
+
   /// Fetches the DID Document of the given DID.
   ///
   /// # Errors
   ///
   /// Errors if the resolver has not been configured to handle the method corresponding to the given DID or the
-  /// resolution process itself fails.
+  /// resolution process itself fails. With [`Self::with_strict_deactivation`](Self::with_strict_deactivation()), also
+  /// errors with [`ErrorCause::DIDDeactivated`] if the DID is deactivated.
   ///
   /// ## Example
   ///
@@ -100,7 +124,50 @@ where
       })
       .map_err(Error::new)?;
 
-    delegate.apply(did.as_str()).await
+    let document: DOC = delegate.apply(did.as_str()).await?;
+    if let Some(deactivation_metadata) = self.deactivation_metadata {
+      let metadata: DIDDocumentMetadata = deactivation_metadata(&document);
+      if metadata.deactivated.unwrap_or_default() {
+        return Err(Error::new(ErrorCause::DIDDeactivated {
+          did: did.as_str().to_owned(),
+          deactivated_at: metadata.updated,
+        }));
+      }
+    }
+    Ok(document)
+  }
+
+  /// Fetches the DID Document of the given DID like [`Self::resolve`](Self::resolve()) and validates it against the
+  /// rules of `profile`, e.g. those of the trust framework the document is used in.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the resolution fails or the resolved document violates a rule of `profile`, in which case the cause is
+  /// [`ErrorCause::ProfileViolation`].
+  pub async fn resolve_with_profile<D: DID>(&self, did: &D, profile: &DocumentProfile) -> Result<DOC>
+  where
+    DOC: AsRef<CoreDocument>,
+  {
+    let document: DOC = self.resolve(did).await?;
+    profile
+      .validate(&document)
+      .map_err(|err| Error::new(ErrorCause::ProfileViolation(err)))?;
+    Ok(document)
+  }
+
+  /// Fetches the DID Document of the given DID together with its metadata, as defined by the
+  /// [DID Resolution specification](https://w3c-ccg.github.io/did-resolution/#did-resolution-result).
+  ///
+  /// Unlike [`Self::resolve`](Self::resolve()), failures are reported through the
+  /// [`DIDResolutionMetadata`](crate::DIDResolutionMetadata) of the result instead of an error.
+  pub async fn resolve_with_metadata<D: DID>(&self, did: &D) -> DIDResolutionResult<DOC>
+  where
+    DOC: ToDocumentMetadata,
+  {
+    match self.resolve(did).await {
+      Ok(document) => DIDResolutionResult::from_document(document),
+      Err(error) => DIDResolutionResult::from_error(&error),
+    }
   }
 
   /// Concurrently fetches the DID Documents of the multiple given DIDs.
@@ -127,6 +194,34 @@ where
 
     Ok(documents)
   }
+
+  /// Fetches the DID Documents of the multiple given DIDs like [`Self::resolve_multiple`](Self::resolve_multiple()),
+  /// but resolves at most `concurrency_limit` DIDs at the same time.
+  ///
+  /// Useful for resolving a large number of DIDs without overwhelming the nodes or services the handlers connect to.
+  /// A `concurrency_limit` of `0` is treated as `1`.
+  ///
+  /// # Errors
+  /// * If the resolver has not been configured to handle the method of any of the given DIDs.
+  /// * If the resolution process of any DID fails.
+  ///
+  /// ## Note
+  /// * If `dids` contains duplicates, these will be resolved only once.
+  pub async fn resolve_many<D: DID>(&self, dids: &[D], concurrency_limit: usize) -> Result<HashMap<D, DOC>> {
+    // Create set to remove duplicates to avoid unnecessary resolution.
+    let dids_set: HashSet<D> = dids.iter().cloned().collect();
+
+    let documents: HashMap<D, DOC> = futures::stream::iter(dids_set)
+      .map(|did| async move {
+        let doc = self.resolve(&did).await;
+        doc.map(|doc| (did, doc))
+      })
+      .buffer_unordered(concurrency_limit.max(1))
+      .try_collect()
+      .await?;
+
+    Ok(documents)
+  }
 }
 
 impl<DOC: 'static> Resolver<DOC, SendSyncCommand<DOC>> {
@@ -139,8 +234,8 @@ impl<DOC: 'static> Resolver<DOC, SendSyncCommand<DOC>> {
   /// below).
   ///
   /// NOTE: If there already exists a handler for this method then it will be replaced with the new handler.
-  /// In the case where one would like to have a "backup handler" for the same DID method, one can achieve this with
-  /// composition.
+  /// In the case where one would like to have a "backup handler" for the same DID method, see
+  /// [`Self::attach_fallback_chain`](Self::attach_fallback_chain()).
   ///
   /// # Example
   /// ```
@@ -187,6 +282,15 @@ impl<DOC: 'static> Resolver<DOC, SendSyncCommand<DOC>> {
     let command = SendSyncCommand::new(handler);
     self.command_map.insert(method, command);
   }
+
+  /// Attach a [`FallbackChain`] of handlers responsible for resolving DIDs of the given DID method, which are tried
+  /// in order until one of them succeeds.
+  ///
+  /// NOTE: If there already exists a handler for this method then it will be replaced with the chain.
+  pub fn attach_fallback_chain(&mut self, method: String, chain: FallbackChain<DOC>) {
+    let command = SendSyncCommand::from_fallback_chain(chain);
+    self.command_map.insert(method, command);
+  }
 }
 
 impl<DOC: 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
@@ -369,12 +473,14 @@ where
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     f.debug_struct("Resolver")
       .field("command_map", &self.command_map)
+      .field("strict_deactivation", &self.deactivation_metadata.is_some())
       .finish()
   }
 }
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::Timestamp;
   use identity_iota_core::block::output::AliasId;
   use identity_iota_core::block::output::AliasOutput;
   use identity_iota_core::block::output::OutputId;
@@ -432,6 +538,33 @@ mod tests {
     assert_eq!(doc.id(), &did2);
   }
 
+  #[tokio::test]
+  async fn test_strict_deactivation() {
+    let did = IotaDID::parse("did:iota:0x0101010101010101010101010101010101010101010101010101010101010101").unwrap();
+    let deactivated_at: Timestamp = Timestamp::parse("2024-01-01T00:00:00Z").unwrap();
+    let mut document = IotaDocument::new_with_id(did.clone());
+    document.metadata.updated = Some(deactivated_at);
+    document.metadata.deactivated = Some(true);
+
+    let mut resolver = Resolver::<IotaDocument>::new();
+    resolver.attach_iota_handler(DummyClient(document.clone()));
+    assert!(resolver.resolve(&did).await.unwrap().metadata.deactivated.unwrap());
+
+    let mut resolver = Resolver::<IotaDocument>::new().with_strict_deactivation();
+    resolver.attach_iota_handler(DummyClient(document));
+    let error: Error = resolver.resolve(&did).await.unwrap_err();
+    assert!(matches!(
+      error.error_cause(),
+      ErrorCause::DIDDeactivated { did: deactivated, deactivated_at: Some(timestamp) }
+        if deactivated == did.as_str() && *timestamp == deactivated_at
+    ));
+
+    // Active documents are still returned.
+    let mut resolver = Resolver::<IotaDocument>::new().with_strict_deactivation();
+    resolver.attach_iota_handler(DummyClient(IotaDocument::new_with_id(did.clone())));
+    assert_eq!(resolver.resolve(&did).await.unwrap().id(), &did);
+  }
+
   #[tokio::test]
   async fn test_did_jwk_resolution() {
     let mut resolver = Resolver::<CoreDocument>::new();
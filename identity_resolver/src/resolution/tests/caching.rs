@@ -0,0 +1,130 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_document::document::DocumentBuilder;
+
+use crate::CachedDocument;
+use crate::CachingResolver;
+use crate::InMemoryResolutionCache;
+use crate::ResolutionCache;
+use crate::Resolver;
+
+/// Returns a resolver for the "foo" method counting its resolutions, which fail once `fail` is set.
+fn counting_resolver(count: Arc<AtomicUsize>, fail: Arc<AtomicUsize>) -> Resolver<CoreDocument> {
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), move |did: CoreDID| {
+    let (count, fail) = (count.clone(), fail.clone());
+    async move {
+      count.fetch_add(1, Ordering::SeqCst);
+      if fail.load(Ordering::SeqCst) > 0 {
+        return Err(std::io::Error::new(std::io::ErrorKind::Other, "unavailable"));
+      }
+      Ok(DocumentBuilder::default().id(did).build().unwrap())
+    }
+  });
+  resolver
+}
+
+fn did(idx: usize) -> CoreDID {
+  CoreDID::parse(format!("did:foo:{idx}")).unwrap()
+}
+
+/// Caches the document of `did` as resolved `age` ago.
+async fn insert_aged(cache: &InMemoryResolutionCache<CoreDocument>, did: &CoreDID, age: Duration) {
+  let document: CoreDocument = DocumentBuilder::default().id(did.clone()).build().unwrap();
+  let resolved: Timestamp = Timestamp::now_utc().checked_sub(age).unwrap();
+  cache
+    .insert(did.as_str(), CachedDocument::new(document, resolved))
+    .await;
+}
+
+#[tokio::test]
+async fn caches_until_ttl_elapses() {
+  let (count, fail) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+  let resolver: CachingResolver = CachingResolver::new(
+    counting_resolver(count.clone(), fail),
+    InMemoryResolutionCache::new(10),
+    Duration::minutes(5),
+  );
+
+  for _ in 0..3 {
+    assert_eq!(resolver.resolve(&did(1)).await.unwrap().id(), &did(1));
+  }
+  assert_eq!(count.load(Ordering::SeqCst), 1);
+
+  // Expired documents are resolved again.
+  insert_aged(resolver.cache(), &did(1), Duration::minutes(10)).await;
+  resolver.resolve(&did(1)).await.unwrap();
+  assert_eq!(count.load(Ordering::SeqCst), 2);
+
+  resolver.invalidate(&did(1)).await;
+  resolver.resolve(&did(1)).await.unwrap();
+  assert_eq!(count.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn evicts_least_recently_used() {
+  let cache: InMemoryResolutionCache<CoreDocument> = InMemoryResolutionCache::new(2);
+  insert_aged(&cache, &did(1), Duration::seconds(0)).await;
+  insert_aged(&cache, &did(2), Duration::seconds(0)).await;
+  assert!(cache.get(did(1).as_str()).await.is_some());
+
+  insert_aged(&cache, &did(3), Duration::seconds(0)).await;
+  assert_eq!(cache.len(), 2);
+  assert!(cache.get(did(1).as_str()).await.is_some());
+  assert!(cache.get(did(2).as_str()).await.is_none());
+  assert!(cache.get(did(3).as_str()).await.is_some());
+}
+
+#[tokio::test]
+async fn serves_stale_document_if_resolution_fails() {
+  let (count, fail) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(1)));
+  let resolver: CachingResolver = CachingResolver::new(
+    counting_resolver(count, fail),
+    InMemoryResolutionCache::new(10),
+    Duration::minutes(5),
+  )
+  .with_stale_ttl(Duration::minutes(5));
+
+  insert_aged(resolver.cache(), &did(1), Duration::minutes(7)).await;
+  assert_eq!(resolver.resolve(&did(1)).await.unwrap().id(), &did(1));
+
+  insert_aged(resolver.cache(), &did(1), Duration::minutes(12)).await;
+  assert!(resolver.resolve(&did(1)).await.is_err());
+}
+
+#[tokio::test]
+async fn revalidates_stale_document_in_background() {
+  let (count, fail) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+  let resolver: CachingResolver = CachingResolver::new(
+    counting_resolver(count.clone(), fail),
+    InMemoryResolutionCache::new(10),
+    Duration::minutes(5),
+  )
+  .with_stale_ttl(Duration::minutes(5))
+  .with_background_revalidation(|future| {
+    tokio::spawn(future);
+  });
+
+  insert_aged(resolver.cache(), &did(1), Duration::minutes(7)).await;
+  let stale_resolved: Timestamp = resolver.cache().get(did(1).as_str()).await.unwrap().resolved();
+  resolver.resolve(&did(1)).await.unwrap();
+
+  while count.load(Ordering::SeqCst) == 0 {
+    tokio::task::yield_now().await;
+  }
+  // Wait for the revalidated document to be cached.
+  while resolver.cache().get(did(1).as_str()).await.unwrap().resolved() == stale_resolved {
+    tokio::task::yield_now().await;
+  }
+  resolver.resolve(&did(1)).await.unwrap();
+  assert_eq!(count.load(Ordering::SeqCst), 1);
+}
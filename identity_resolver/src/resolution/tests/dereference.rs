@@ -0,0 +1,98 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+
+use crate::DereferencedResource;
+use crate::ErrorCause;
+use crate::Resolver;
+
+async fn handler(did: CoreDID) -> std::result::Result<CoreDocument, std::io::Error> {
+  let json: String = format!(
+    r#"{{
+      "id": "{did}",
+      "verificationMethod": [{{
+        "id": "{did}#key-1",
+        "controller": "{did}",
+        "type": "Ed25519VerificationKey2018",
+        "publicKeyBase58": "3M5RCDjPTWPkKSN3sxUmmMqHbmRPegYP1tjcKyrDbt9J"
+      }}],
+      "service": [{{
+        "id": "{did}#files",
+        "type": "Files",
+        "serviceEndpoint": "https://example.com/base/"
+      }}]
+    }}"#
+  );
+  Ok(CoreDocument::from_json(&json).unwrap())
+}
+
+fn resolver() -> Resolver {
+  let mut resolver: Resolver = Resolver::new();
+  resolver.attach_handler("example".to_owned(), handler);
+  resolver
+}
+
+async fn dereference(url: &str) -> crate::Result<DereferencedResource<CoreDocument>> {
+  resolver().dereference(&DIDUrl::parse(url).unwrap()).await
+}
+
+#[tokio::test]
+async fn dereference_service_endpoint() {
+  let DereferencedResource::Url(url) = dereference("did:example:123?service=files&relativeRef=/path")
+    .await
+    .unwrap()
+  else {
+    panic!("expected a url");
+  };
+  assert_eq!(url.as_str(), "https://example.com/path");
+
+  let DereferencedResource::Url(url) = dereference("did:example:123?service=files&relativeRef=sub%2Ffile.txt#part")
+    .await
+    .unwrap()
+  else {
+    panic!("expected a url");
+  };
+  assert_eq!(url.as_str(), "https://example.com/base/sub/file.txt#part");
+
+  let DereferencedResource::Url(url) = dereference("did:example:123?service=files").await.unwrap() else {
+    panic!("expected a url");
+  };
+  assert_eq!(url.as_str(), "https://example.com/base/");
+}
+
+#[tokio::test]
+async fn dereference_fragment() {
+  assert!(matches!(
+    dereference("did:example:123#key-1").await.unwrap(),
+    DereferencedResource::Method(method) if method.id().fragment() == Some("key-1")
+  ));
+  assert!(matches!(
+    dereference("did:example:123#files").await.unwrap(),
+    DereferencedResource::Service(service) if service.id().fragment() == Some("files")
+  ));
+  assert!(matches!(
+    dereference("did:example:123").await.unwrap(),
+    DereferencedResource::Document(document) if document.id().as_str() == "did:example:123"
+  ));
+}
+
+#[tokio::test]
+async fn dereference_errors() {
+  for url in [
+    "did:example:123#missing",
+    "did:example:123?service=missing",
+    "did:example:123?versionId=1",
+    "did:example:123?relativeRef=/path",
+    "did:example:123/path",
+  ] {
+    let error = dereference(url).await.unwrap_err();
+    assert!(
+      matches!(error.error_cause(), ErrorCause::DereferencingError(_)),
+      "{url}: {error}"
+    );
+  }
+}
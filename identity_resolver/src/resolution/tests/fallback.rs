@@ -0,0 +1,88 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_document::document::DocumentBuilder;
+
+use crate::ErrorCause;
+use crate::FallbackChain;
+use crate::FallbackPolicy;
+use crate::Resolver;
+
+async fn resolve_ok(did: CoreDID) -> Result<CoreDocument, std::io::Error> {
+  Ok(DocumentBuilder::default().id(did).build().unwrap())
+}
+
+async fn resolve_err(_did: CoreDID) -> Result<CoreDocument, std::io::Error> {
+  Err(std::io::Error::new(std::io::ErrorKind::Other, "unavailable"))
+}
+
+async fn resolve_never(_did: CoreDID) -> Result<CoreDocument, std::io::Error> {
+  futures::future::pending().await
+}
+
+fn resolver(chain: FallbackChain) -> Resolver {
+  let mut resolver: Resolver = Resolver::new();
+  resolver.attach_fallback_chain("foo".to_owned(), chain);
+  resolver
+}
+
+fn did() -> CoreDID {
+  CoreDID::parse("did:foo:1").unwrap()
+}
+
+#[tokio::test]
+async fn falls_back_to_next_handler() {
+  let chain: FallbackChain = FallbackChain::new().with_handler(resolve_err).with_handler(resolve_ok);
+  assert_eq!(resolver(chain).resolve(&did()).await.unwrap().id(), &did());
+
+  let chain: FallbackChain = FallbackChain::new().with_handler(resolve_err).with_handler(resolve_err);
+  let error = resolver(chain).resolve(&did()).await.unwrap_err();
+  assert!(matches!(
+    error.error_cause(),
+    ErrorCause::FallbackExhausted { errors } if errors.len() == 2
+  ));
+}
+
+#[tokio::test]
+async fn handlers_time_out() {
+  // The timer completes immediately, such that handlers that do not complete immediately time out.
+  let chain: FallbackChain = FallbackChain::new()
+    .with_handler_timeout(resolve_never, Duration::from_secs(1))
+    .with_handler(resolve_ok)
+    .with_timer(|_| Box::pin(futures::future::ready(())));
+  assert_eq!(resolver(chain).resolve(&did()).await.unwrap().id(), &did());
+
+  let chain: FallbackChain = FallbackChain::new()
+    .with_handler_timeout(resolve_never, Duration::from_secs(1))
+    .with_timer(|_| Box::pin(futures::future::ready(())));
+  let error = resolver(chain).resolve(&did()).await.unwrap_err();
+  let ErrorCause::FallbackExhausted { errors } = error.error_cause() else {
+    panic!("expected the fallback chain to be exhausted");
+  };
+  assert!(matches!(
+    errors[0].error_cause(),
+    ErrorCause::HandlerTimeout { timeout } if *timeout == Duration::from_secs(1)
+  ));
+}
+
+#[tokio::test]
+async fn policy_stops_fallback() {
+  let chain: FallbackChain = FallbackChain::new()
+    .with_handler(resolve_err)
+    .with_handler(resolve_ok)
+    .with_policy(FallbackPolicy::TimeoutOnly);
+  let error = resolver(chain).resolve(&did()).await.unwrap_err();
+  assert!(matches!(error.error_cause(), ErrorCause::HandlerError { .. }));
+
+  let chain: FallbackChain = FallbackChain::new()
+    .with_handler(resolve_err)
+    .with_handler(resolve_ok)
+    .with_policy(FallbackPolicy::Custom(|cause| {
+      matches!(cause, ErrorCause::HandlerError { .. })
+    }));
+  assert!(resolver(chain).resolve(&did()).await.is_ok());
+}
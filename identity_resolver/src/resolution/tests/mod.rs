@@ -2,5 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::resolver::*;
+mod caching;
+mod dereference;
+mod fallback;
 mod resolution;
 mod send_sync;
@@ -12,6 +12,7 @@ use identity_did::Error as DIDError;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
 use identity_document::document::DocumentBuilder;
+use identity_document::profile::DocumentProfile;
 
 use crate::Error as ResolverError;
 use crate::ErrorCause;
@@ -279,3 +280,107 @@ async fn resolve_multiple() {
   assert_eq!(resolved_dids.len(), 1);
   assert_eq!(resolved_dids.get(&did_1).unwrap().id(), &did_1);
 }
+
+#[tokio::test]
+async fn resolve_many_respects_concurrency_limit() {
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::atomic::Ordering;
+  use std::sync::Arc;
+
+  let method_name: String = "foo".to_owned();
+  let active: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+  let max_active: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  let (active_clone, max_active_clone) = (active.clone(), max_active.clone());
+  resolver.attach_handler(method_name.clone(), move |did: CoreDID| {
+    let (active, max_active) = (active_clone.clone(), max_active_clone.clone());
+    async move {
+      let now_active: usize = active.fetch_add(1, Ordering::SeqCst) + 1;
+      max_active.fetch_max(now_active, Ordering::SeqCst);
+      tokio::task::yield_now().await;
+      active.fetch_sub(1, Ordering::SeqCst);
+      mock_handler(did).await
+    }
+  });
+
+  let dids: Vec<CoreDID> = (0..20)
+    .map(|idx| CoreDID::parse(format!("did:{method_name}:{idx}")).unwrap())
+    .collect();
+  let resolved_dids: HashMap<CoreDID, CoreDocument> = resolver.resolve_many(&dids, 4).await.unwrap();
+
+  assert_eq!(resolved_dids.len(), 20);
+  assert!(dids.iter().all(|did| resolved_dids.get(did).unwrap().id() == did));
+  assert!(max_active.load(Ordering::SeqCst) <= 4);
+
+  // A limit of zero still resolves the DIDs.
+  let resolved_dids: HashMap<CoreDID, CoreDocument> = resolver.resolve_many(&dids[..2], 0).await.unwrap();
+  assert_eq!(resolved_dids.len(), 2);
+}
+
+// ===========================================================================
+// Resolve with metadata.
+// ===========================================================================
+
+#[tokio::test]
+async fn resolve_with_metadata() {
+  use identity_core::common::Value;
+  use identity_core::convert::ToJson;
+
+  use crate::DIDResolutionResult;
+  use crate::ResolutionErrorCode;
+
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), mock_handler);
+  resolver.attach_handler("bar".to_owned(), |_did: CoreDID| async move {
+    Err::<CoreDocument, _>(std::io::Error::new(std::io::ErrorKind::Other, "unavailable"))
+  });
+
+  let did: CoreDID = CoreDID::parse("did:foo:1234").unwrap();
+  let result: DIDResolutionResult = resolver.resolve_with_metadata(&did).await;
+  assert!(result.is_ok());
+  assert_eq!(result.did_document.as_ref().unwrap().id(), &did);
+  let json: Value = result.to_json_value().unwrap();
+  assert_eq!(json["@context"], "https://w3id.org/did-resolution/v1");
+  assert_eq!(json["didResolutionMetadata"]["contentType"], "application/did+json");
+  assert_eq!(json["didDocument"]["id"], "did:foo:1234");
+
+  for (did, expected) in [
+    ("did:baz:1234", ResolutionErrorCode::MethodNotSupported),
+    ("did:bar:1234", ResolutionErrorCode::InternalError),
+  ] {
+    let result: DIDResolutionResult = resolver.resolve_with_metadata(&CoreDID::parse(did).unwrap()).await;
+    assert!(!result.is_ok());
+    assert!(result.did_document.is_none());
+    assert_eq!(result.did_resolution_metadata.error, Some(expected));
+  }
+
+  let json: Value = resolver
+    .resolve_with_metadata(&CoreDID::parse("did:baz:1234").unwrap())
+    .await
+    .to_json_value()
+    .unwrap();
+  assert_eq!(json["didResolutionMetadata"]["error"], "methodNotSupported");
+}
+
+#[tokio::test]
+async fn resolve_with_profile() {
+  let mut resolver: Resolver<FooDocument> = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), mock_handler);
+  let did: CoreDID = CoreDID::parse("did:foo:1234").unwrap();
+
+  let document: FooDocument = resolver
+    .resolve_with_profile(&did, &DocumentProfile::no_secp256k1())
+    .await
+    .unwrap();
+  assert_eq!(document.0.id(), &did);
+
+  let err: ResolverError = resolver
+    .resolve_with_profile(&did, &DocumentProfile::multi_controller())
+    .await
+    .unwrap_err();
+  let ErrorCause::ProfileViolation(err) = err.into_error_cause() else {
+    unreachable!()
+  };
+  assert_eq!(err.violations.len(), 1);
+}
@@ -0,0 +1,41 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use super::KeyIdStorage;
+use super::KeyIdStorageResult;
+use super::MethodDigest;
+use super::Namespace;
+use crate::key_storage::KeyId;
+
+/// Extension to the [`KeyIdStorage`] for storages that keep the key ids of multiple tenants in isolated
+/// [`Namespace`]s.
+///
+/// Entries inserted into a namespace must only be visible through that namespace and must never be returned by
+/// the methods of [`KeyIdStorage`] or for any other namespace.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait KeyIdStorageNamespaceExt: KeyIdStorage {
+  /// Insert a [`KeyId`] into `namespace` under the given [`MethodDigest`].
+  ///
+  /// If an entry for `method_digest` already exists in `namespace` an error must be returned
+  /// immediately without altering the state of the storage.
+  async fn insert_key_id_in(
+    &self,
+    namespace: &Namespace,
+    method_digest: MethodDigest,
+    key_id: KeyId,
+  ) -> KeyIdStorageResult<()>;
+
+  /// Obtain the [`KeyId`] associated with the given [`MethodDigest`] in `namespace`.
+  async fn get_key_id_in(&self, namespace: &Namespace, method_digest: &MethodDigest) -> KeyIdStorageResult<KeyId>;
+
+  /// Delete the [`KeyId`] associated with the given [`MethodDigest`] from `namespace`.
+  ///
+  /// If `method_digest` is not found in `namespace`, an Error must be returned.
+  async fn delete_key_id_in(&self, namespace: &Namespace, method_digest: &MethodDigest) -> KeyIdStorageResult<()>;
+
+  /// Returns all entries of `namespace`. An unknown namespace has no entries.
+  async fn key_ids_in(&self, namespace: &Namespace) -> KeyIdStorageResult<Vec<(MethodDigest, KeyId)>>;
+}
@@ -4,6 +4,8 @@
 use crate::key_id_storage::key_id_storage::KeyIdStorage;
 use crate::key_id_storage::key_id_storage_error::KeyIdStorageError;
 use crate::key_id_storage::key_id_storage_error::KeyIdStorageErrorKind;
+use crate::key_id_storage::KeyIdStorageNamespaceExt;
+use crate::key_id_storage::Namespace;
 use crate::key_storage::shared::Shared;
 use crate::key_storage::KeyId;
 use async_trait::async_trait;
@@ -16,10 +18,14 @@ use super::method_digest::MethodDigest;
 
 type KeyIdStore = HashMap<MethodDigest, KeyId>;
 
+/// The map from namespaces to their isolated key id stores.
+type NamespacedKeyIdStore = HashMap<Namespace, KeyIdStore>;
+
 /// An insecure, in-memory [`KeyIdStorage`] implementation that serves as an example and may be used in tests.
 #[derive(Debug)]
 pub struct KeyIdMemstore {
   key_id_store: Shared<KeyIdStore>,
+  namespaced_store: Shared<NamespacedKeyIdStore>,
 }
 
 impl KeyIdMemstore {
@@ -27,13 +33,25 @@ impl KeyIdMemstore {
   pub fn new() -> Self {
     Self {
       key_id_store: Shared::new(HashMap::new()),
+      namespaced_store: Shared::new(HashMap::new()),
     }
   }
 
-  /// Returns the number of items contained in the [`KeyIdMemstore`].
+  /// Returns the number of items contained in the [`KeyIdMemstore`], excluding those in namespaces.
   pub async fn count(&self) -> usize {
     self.key_id_store.read().await.keys().count()
   }
+
+  /// Returns the number of items contained in `namespace`.
+  pub async fn count_in(&self, namespace: &Namespace) -> usize {
+    self
+      .namespaced_store
+      .read()
+      .await
+      .get(namespace)
+      .map(HashMap::len)
+      .unwrap_or_default()
+  }
 }
 
 impl Default for KeyIdMemstore {
@@ -73,6 +91,58 @@ impl KeyIdStorage for KeyIdMemstore {
   }
 }
 
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl KeyIdStorageNamespaceExt for KeyIdMemstore {
+  async fn insert_key_id_in(&self, namespace: &Namespace, key: MethodDigest, value: KeyId) -> KeyIdStorageResult<()> {
+    let mut namespaced_store: RwLockWriteGuard<'_, NamespacedKeyIdStore> = self.namespaced_store.write().await;
+    let key_id_store: &mut KeyIdStore = namespaced_store.entry(namespace.clone()).or_default();
+    if key_id_store.contains_key(&key) {
+      return Err(KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdAlreadyExists));
+    }
+    key_id_store.insert(key, value);
+    Ok(())
+  }
+
+  async fn get_key_id_in(&self, namespace: &Namespace, key: &MethodDigest) -> KeyIdStorageResult<KeyId> {
+    let namespaced_store: RwLockReadGuard<'_, NamespacedKeyIdStore> = self.namespaced_store.read().await;
+    namespaced_store
+      .get(namespace)
+      .and_then(|key_id_store| key_id_store.get(key))
+      .cloned()
+      .ok_or_else(|| KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound))
+  }
+
+  async fn delete_key_id_in(&self, namespace: &Namespace, key: &MethodDigest) -> KeyIdStorageResult<()> {
+    let mut namespaced_store: RwLockWriteGuard<'_, NamespacedKeyIdStore> = self.namespaced_store.write().await;
+    let key_id_store: &mut KeyIdStore = namespaced_store
+      .get_mut(namespace)
+      .ok_or_else(|| KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound))?;
+    key_id_store
+      .remove(key)
+      .ok_or_else(|| KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound))?;
+    if key_id_store.is_empty() {
+      namespaced_store.remove(namespace);
+    }
+    Ok(())
+  }
+
+  async fn key_ids_in(&self, namespace: &Namespace) -> KeyIdStorageResult<Vec<(MethodDigest, KeyId)>> {
+    let namespaced_store: RwLockReadGuard<'_, NamespacedKeyIdStore> = self.namespaced_store.read().await;
+    Ok(
+      namespaced_store
+        .get(namespace)
+        .map(|key_id_store| {
+          key_id_store
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+        })
+        .unwrap_or_default(),
+    )
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use crate::key_id_storage::key_id_storage::KeyIdStorage;
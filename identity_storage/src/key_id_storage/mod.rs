@@ -11,7 +11,10 @@
 #[allow(clippy::module_inception)]
 mod key_id_storage;
 mod key_id_storage_error;
+mod key_id_storage_namespace_ext;
 mod method_digest;
+mod namespace;
+mod scoped_key_id_storage;
 
 #[cfg(feature = "memstore")]
 mod memstore;
@@ -21,6 +24,9 @@ mod tests;
 
 pub use key_id_storage::*;
 pub use key_id_storage_error::*;
+pub use key_id_storage_namespace_ext::*;
 #[cfg(feature = "memstore")]
 pub use memstore::*;
 pub use method_digest::*;
+pub use namespace::*;
+pub use scoped_key_id_storage::*;
@@ -0,0 +1,57 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::KeyIdStorageError;
+use super::KeyIdStorageErrorKind;
+
+/// An identifier isolating the keys and key ids of one tenant from those of other tenants sharing the same storage.
+///
+/// A namespace must not be empty and must not contain the [`Namespace::SEPARATOR`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Namespace(String);
+
+impl Namespace {
+  /// The character separating the namespace from the key id in the key ids of a
+  /// [`ScopedJwkStorage`](crate::key_storage::ScopedJwkStorage).
+  pub const SEPARATOR: char = ':';
+
+  /// Creates a new namespace from a string.
+  pub fn new(namespace: impl Into<String>) -> Result<Self, KeyIdStorageError> {
+    let namespace: String = namespace.into();
+    if namespace.is_empty() || namespace.contains(Self::SEPARATOR) {
+      return Err(
+        KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified).with_custom_message(format!(
+          "invalid namespace `{namespace}`: must not be empty or contain `{}`",
+          Self::SEPARATOR
+        )),
+      );
+    }
+    Ok(Self(namespace))
+  }
+
+  /// Returns the string representation of the namespace.
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl std::fmt::Display for Namespace {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+impl TryFrom<String> for Namespace {
+  type Error = KeyIdStorageError;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    Self::new(value)
+  }
+}
+
+impl From<Namespace> for String {
+  fn from(value: Namespace) -> Self {
+    value.0
+  }
+}
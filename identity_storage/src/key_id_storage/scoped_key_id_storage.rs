@@ -0,0 +1,51 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use super::KeyIdStorage;
+use super::KeyIdStorageNamespaceExt;
+use super::KeyIdStorageResult;
+use super::MethodDigest;
+use super::Namespace;
+use crate::key_storage::KeyId;
+
+/// A [`KeyIdStorage`] handle restricted to a single [`Namespace`] of a [`KeyIdStorageNamespaceExt`] implementation.
+///
+/// Typically obtained through [`Storage::scoped`](crate::storage::Storage::scoped).
+#[derive(Debug)]
+pub struct ScopedKeyIdStorage<'storage, I> {
+  storage: &'storage I,
+  namespace: Namespace,
+}
+
+impl<'storage, I> ScopedKeyIdStorage<'storage, I> {
+  /// Creates a new handle to `namespace` of `storage`.
+  pub fn new(storage: &'storage I, namespace: Namespace) -> Self {
+    Self { storage, namespace }
+  }
+
+  /// Returns the namespace this handle is restricted to.
+  pub fn namespace(&self) -> &Namespace {
+    &self.namespace
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<I: KeyIdStorageNamespaceExt> KeyIdStorage for ScopedKeyIdStorage<'_, I> {
+  async fn insert_key_id(&self, method_digest: MethodDigest, key_id: KeyId) -> KeyIdStorageResult<()> {
+    self
+      .storage
+      .insert_key_id_in(&self.namespace, method_digest, key_id)
+      .await
+  }
+
+  async fn get_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<KeyId> {
+    self.storage.get_key_id_in(&self.namespace, method_digest).await
+  }
+
+  async fn delete_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<()> {
+    self.storage.delete_key_id_in(&self.namespace, method_digest).await
+  }
+}
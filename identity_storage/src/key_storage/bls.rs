@@ -10,10 +10,16 @@ use jsonprooftoken::jpa::algs::ProofAlgorithm;
 use zkryptium::bbsplus::ciphersuites::BbsCiphersuite;
 use zkryptium::bbsplus::ciphersuites::Bls12381Sha256;
 use zkryptium::bbsplus::ciphersuites::Bls12381Shake256;
+#[cfg(feature = "bbs-plus-blind")]
+use zkryptium::bbsplus::commitment::BlindFactor;
 use zkryptium::bbsplus::keys::BBSplusPublicKey;
 use zkryptium::bbsplus::keys::BBSplusSecretKey;
 use zkryptium::keys::pair::KeyPair;
 use zkryptium::schemes::algorithms::BBSplus;
+#[cfg(feature = "bbs-plus-blind")]
+use zkryptium::schemes::generics::BlindSignature;
+#[cfg(feature = "bbs-plus-blind")]
+use zkryptium::schemes::generics::Commitment;
 use zkryptium::schemes::generics::Signature;
 
 use crate::key_storage::KeyStorageError;
@@ -201,3 +207,161 @@ pub fn update_bbs_signature(
       .with_source(e)
   })
 }
+
+/// The output of [`commit_bbs`], held by the holder until the blind signature is issued.
+#[cfg(feature = "bbs-plus-blind")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct BbsCommitment {
+  /// The commitment to the holder's messages together with its proof of correctness, sent to the issuer.
+  pub commitment_with_proof: Vec<u8>,
+  /// The secret blinding factor of the commitment, which must never leave the holder.
+  pub secret_prover_blind: Vec<u8>,
+}
+
+#[cfg(feature = "bbs-plus-blind")]
+fn _commit_bbs<S>(committed_messages: &[Vec<u8>]) -> Result<BbsCommitment, zkryptium::errors::Error>
+where
+  S: BbsCiphersuite,
+{
+  let (commitment_with_proof, secret_prover_blind) = Commitment::<BBSplus<S>>::commit(Some(committed_messages))?;
+  Ok(BbsCommitment {
+    commitment_with_proof: commitment_with_proof.to_bytes(),
+    secret_prover_blind: secret_prover_blind.to_bytes().to_vec(),
+  })
+}
+
+/// Commits to `committed_messages`, e.g. a secret bound to the holder's key, for a blind issuance.
+///
+/// The issuer signs the commitment along with its own messages through
+/// [`JwkStorageBbsPlusBlindExt::blind_sign_bbs`](crate::JwkStorageBbsPlusBlindExt::blind_sign_bbs) without learning
+/// the committed messages.
+#[cfg(feature = "bbs-plus-blind")]
+pub fn commit_bbs(alg: ProofAlgorithm, committed_messages: &[Vec<u8>]) -> KeyStorageResult<BbsCommitment> {
+  match alg {
+    ProofAlgorithm::BLS12381_SHA256 => _commit_bbs::<Bls12381Sha256>(committed_messages),
+    ProofAlgorithm::BLS12381_SHAKE256 => _commit_bbs::<Bls12381Shake256>(committed_messages),
+    _ => return Err(KeyStorageErrorKind::UnsupportedProofAlgorithm.into()),
+  }
+  .map_err(|e| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_source(e)
+      .with_custom_message("commitment failed".to_owned())
+  })
+}
+
+#[cfg(feature = "bbs-plus-blind")]
+fn _blind_sign_bbs<S>(
+  commitment_with_proof: &[u8],
+  data: &[Vec<u8>],
+  sk: &BBSplusSecretKey,
+  pk: &BBSplusPublicKey,
+  header: &[u8],
+) -> Result<Vec<u8>, zkryptium::errors::Error>
+where
+  S: BbsCiphersuite,
+{
+  BlindSignature::<BBSplus<S>>::blind_sign(sk, pk, Some(commitment_with_proof), Some(header), Some(data), None)
+    .map(|s| s.to_bytes().to_vec())
+}
+
+/// Signs data, header and the holder's `commitment_with_proof` using the given keys.
+///
+/// Fails if the proof of the commitment is invalid.
+#[cfg(feature = "bbs-plus-blind")]
+pub fn blind_sign_bbs(
+  alg: ProofAlgorithm,
+  commitment_with_proof: &[u8],
+  data: &[Vec<u8>],
+  sk: &BBSplusSecretKey,
+  pk: &BBSplusPublicKey,
+  header: &[u8],
+) -> KeyStorageResult<Vec<u8>> {
+  match alg {
+    ProofAlgorithm::BLS12381_SHA256 => _blind_sign_bbs::<Bls12381Sha256>(commitment_with_proof, data, sk, pk, header),
+    ProofAlgorithm::BLS12381_SHAKE256 => {
+      _blind_sign_bbs::<Bls12381Shake256>(commitment_with_proof, data, sk, pk, header)
+    }
+    _ => return Err(KeyStorageErrorKind::UnsupportedProofAlgorithm.into()),
+  }
+  .map_err(|e| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_source(e)
+      .with_custom_message("blind signature failed".to_owned())
+  })
+}
+
+#[cfg(feature = "bbs-plus-blind")]
+fn _verify_blind_bbs_signature<S>(
+  signature: &[u8; 80],
+  pk: &BBSplusPublicKey,
+  header: &[u8],
+  data: &[Vec<u8>],
+  committed_messages: &[Vec<u8>],
+  secret_prover_blind: &BlindFactor,
+) -> Result<(), zkryptium::errors::Error>
+where
+  S: BbsCiphersuite,
+{
+  BlindSignature::<BBSplus<S>>::from_bytes(signature)?.verify_blind_sign(
+    pk,
+    Some(header),
+    Some(data),
+    Some(committed_messages),
+    Some(secret_prover_blind),
+    None,
+  )
+}
+
+/// Verifies a blind signature issued over `data`, `header` and the holder's `commitment`, which commits to
+/// `committed_messages`.
+///
+/// This is performed by the holder upon receiving the signature, as only the holder knows the committed messages and
+/// the secret blinding factor of the commitment.
+#[cfg(feature = "bbs-plus-blind")]
+pub fn verify_blind_bbs_signature(
+  alg: ProofAlgorithm,
+  signature: &[u8],
+  pk: &BBSplusPublicKey,
+  header: &[u8],
+  data: &[Vec<u8>],
+  committed_messages: &[Vec<u8>],
+  commitment: &BbsCommitment,
+) -> KeyStorageResult<()> {
+  let exact_size_signature: &[u8; 80] = signature.try_into().map_err(|_| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message("invalid signature size".to_owned())
+  })?;
+  let secret_prover_blind: BlindFactor = commitment
+    .secret_prover_blind
+    .as_slice()
+    .try_into()
+    .ok()
+    .and_then(|bytes| BlindFactor::from_bytes(bytes).ok())
+    .ok_or_else(|| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message("invalid blinding factor".to_owned())
+    })?;
+  match alg {
+    ProofAlgorithm::BLS12381_SHA256 => _verify_blind_bbs_signature::<Bls12381Sha256>(
+      exact_size_signature,
+      pk,
+      header,
+      data,
+      committed_messages,
+      &secret_prover_blind,
+    ),
+    ProofAlgorithm::BLS12381_SHAKE256 => _verify_blind_bbs_signature::<Bls12381Shake256>(
+      exact_size_signature,
+      pk,
+      header,
+      data,
+      committed_messages,
+      &secret_prover_blind,
+    ),
+    _ => return Err(KeyStorageErrorKind::UnsupportedProofAlgorithm.into()),
+  }
+  .map_err(|e| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_source(e)
+      .with_custom_message("invalid blind signature".to_owned())
+  })
+}
@@ -61,4 +61,30 @@ pub trait JwkStorage: storage_sub_trait::StorageSendSyncMaybe {
 
   /// Returns `true` if the key with the given `key_id` exists in storage, `false` otherwise.
   async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool>;
+
+  /// Insert multiple existing JSON Web Keys into the storage, returning their [`KeyId`]s in the order of `jwks`.
+  ///
+  /// The default implementation calls [`JwkStorage::insert`] for each key in turn.
+  /// Implementers should override it if they are able to insert multiple keys at once.
+  async fn insert_many(&self, jwks: Vec<Jwk>) -> KeyStorageResult<Vec<KeyId>> {
+    let mut key_ids: Vec<KeyId> = Vec::with_capacity(jwks.len());
+    for jwk in jwks {
+      key_ids.push(self.insert(jwk).await?);
+    }
+    Ok(key_ids)
+  }
+
+  /// Sign the `data` of each `(key_id, data, public_key)` request like [`JwkStorage::sign`], returning the
+  /// signatures in the order of `requests`.
+  ///
+  /// The default implementation calls [`JwkStorage::sign`] for each request in turn.
+  /// Implementers should override it if they are able to sign multiple payloads at once, e.g. to save round trips to
+  /// a remote key management service.
+  async fn sign_many(&self, requests: &[(&KeyId, &[u8], &Jwk)]) -> KeyStorageResult<Vec<Vec<u8>>> {
+    let mut signatures: Vec<Vec<u8>> = Vec::with_capacity(requests.len());
+    for (key_id, data, public_key) in requests {
+      signatures.push(self.sign(key_id, data, public_key).await?);
+    }
+    Ok(signatures)
+  }
 }
@@ -0,0 +1,40 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use super::key_attestation::KeyAttestation;
+use super::key_attestation::KeyAttestationLevel;
+use crate::JwkStorage;
+use crate::KeyId;
+use crate::KeyStorageResult;
+
+/// Extension to the [`JwkStorage`] for storages that record the attestation level of their keys as key metadata.
+///
+/// See [`KeyAttestationDocumentExt`](crate::storage::KeyAttestationDocumentExt) for attesting the keys of
+/// verification methods.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait JwkStorageAttestationExt: JwkStorage {
+  /// Returns the attestation statement the storage obtained when generating the key identified by `key_id`, if any.
+  ///
+  /// Storages backed by hardware capable of attesting its keys, e.g. a TPM, should override this method.
+  /// The default implementation returns `None`.
+  async fn key_attestation(&self, key_id: &KeyId) -> KeyStorageResult<Option<KeyAttestation>> {
+    let _ = key_id;
+    Ok(None)
+  }
+
+  /// Records the attestation `level` of the key identified by `key_id`, replacing a previously recorded level.
+  ///
+  /// If the corresponding key does not exist in storage, a [`KeyStorageError`](crate::KeyStorageError) with kind
+  /// [`KeyNotFound`](crate::KeyStorageErrorKind::KeyNotFound) must be returned.
+  async fn set_attestation_level(&self, key_id: &KeyId, level: KeyAttestationLevel) -> KeyStorageResult<()>;
+
+  /// Returns the attestation level recorded for the key identified by `key_id`, or `None` if the key was never
+  /// attested.
+  ///
+  /// If the corresponding key does not exist in storage, a [`KeyStorageError`](crate::KeyStorageError) with kind
+  /// [`KeyNotFound`](crate::KeyStorageErrorKind::KeyNotFound) must be returned.
+  async fn attestation_level(&self, key_id: &KeyId) -> KeyStorageResult<Option<KeyAttestationLevel>>;
+}
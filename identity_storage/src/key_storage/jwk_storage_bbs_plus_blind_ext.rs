@@ -0,0 +1,31 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_verification::jwk::Jwk;
+
+use crate::JwkStorageBbsPlusExt;
+use crate::KeyId;
+use crate::KeyStorageResult;
+
+/// Extension to the JwkStorage to issue BBS+ signatures over messages committed to by the holder.
+///
+/// In a blind issuance the holder only provides a commitment to some of the signed messages, e.g. a secret bound to
+/// the holder's key, created with [`commit_bbs`](crate::key_storage::bls::commit_bbs). The issuer learns nothing about
+/// the committed messages.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait JwkStorageBbsPlusBlindExt: JwkStorageBbsPlusExt {
+  /// Sign the provided `data`, `header` and the holder's `commitment_with_proof` using the private key identified by
+  /// `key_id` according to the requirements of the corresponding `public_key` (see [`Jwk::alg`](Jwk::alg()) etc.).
+  ///
+  /// Fails if the proof of the commitment is invalid.
+  async fn blind_sign_bbs(
+    &self,
+    key_id: &KeyId,
+    commitment_with_proof: &[u8],
+    data: &[Vec<u8>],
+    header: &[u8],
+    public_key: &Jwk,
+  ) -> KeyStorageResult<Vec<u8>>;
+}
@@ -0,0 +1,39 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_verification::jose::jws::JwsAlgorithm;
+
+use super::KeyDerivationPath;
+use crate::JwkGenOutput;
+use crate::JwkStorage;
+use crate::KeyStorageResult;
+use crate::KeyType;
+
+/// Extension to the [`JwkStorage`] for storages able to deterministically derive keys from a seed, such as a seed
+/// recovered from a BIP39 mnemonic.
+///
+/// Since derived keys only depend on the seed and their [`KeyDerivationPath`], they can be recovered from the mnemonic
+/// alone instead of backing up individual keys. See
+/// [`KeyDerivationDocumentExt`](crate::storage::KeyDerivationDocumentExt) for deriving the keys of verification
+/// methods.
+///
+/// How the seed is provided to the storage is implementation specific.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait JwkStorageDerivationExt: JwkStorage {
+  /// Derive the key of the given `key_type` at `path` from the seed of the storage, store it under the key id
+  /// returned by [`KeyDerivationPath::to_key_id`] and return the key id together with the public key as a JWK.
+  ///
+  /// Deriving at the same path again must yield the same key. The `alg` parameter must be set on the returned JWK,
+  /// as in [`JwkStorage::generate`].
+  ///
+  /// If the storage holds no seed, a [`KeyStorageError`](crate::KeyStorageError) with kind
+  /// [`KeyNotFound`](crate::KeyStorageErrorKind::KeyNotFound) must be returned.
+  async fn derive(
+    &self,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+    path: &KeyDerivationPath,
+  ) -> KeyStorageResult<JwkGenOutput>;
+}
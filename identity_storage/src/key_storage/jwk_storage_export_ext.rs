@@ -0,0 +1,26 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_verification::jwk::SecretJwk;
+
+use crate::JwkStorage;
+use crate::KeyId;
+use crate::KeyStorageResult;
+
+/// Extension to the [`JwkStorage`] for storages that allow private key material to leave the storage,
+/// e.g. to create backups or migrate keys between storages.
+///
+/// Storages backed by hardware or remote key management systems will typically not implement this trait.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait JwkStorageExportExt: JwkStorage {
+  /// Export the key identified by `key_id` as a private JSON Web Key.
+  ///
+  /// The returned [`SecretJwk`] has all private key components set and can be inserted into another storage
+  /// with [`JwkStorage::insert`] through [`SecretJwk::expose_secret`].
+  ///
+  /// If the corresponding key does not exist in storage, a [`KeyStorageError`](crate::KeyStorageError) with kind
+  /// [`KeyNotFound`](crate::KeyStorageErrorKind::KeyNotFound) must be returned.
+  async fn export(&self, key_id: &KeyId) -> KeyStorageResult<SecretJwk>;
+}
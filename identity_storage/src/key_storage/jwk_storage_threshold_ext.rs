@@ -0,0 +1,77 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use frost_ed25519::keys::dkg;
+use frost_ed25519::keys::PublicKeyPackage;
+use frost_ed25519::round1::SigningCommitments;
+use frost_ed25519::round2::SignatureShare;
+use frost_ed25519::Identifier;
+use frost_ed25519::SigningPackage;
+
+use crate::JwkStorage;
+use crate::KeyId;
+use crate::KeyStorageResult;
+
+/// Extension to the [`JwkStorage`] for storages holding a share of an Ed25519 key that is controlled by several
+/// participants through [FROST](https://datatracker.ietf.org/doc/html/rfc9591) threshold signatures.
+///
+/// The methods of this trait are called by a [`ThresholdSigner`](crate::ThresholdSigner), which coordinates the
+/// distributed key generation and the signing rounds between the participants. The share held by the storage is
+/// identified by the [`KeyId`] chosen by the coordinator and must never leave the storage. A share must be removable
+/// through [`JwkStorage::delete`] and be reported by [`JwkStorage::exists`] once the key generation completed.
+///
+/// If no share or key generation state exists for a given [`KeyId`], a [`KeyStorageError`](crate::KeyStorageError)
+/// with kind [`KeyNotFound`](crate::KeyStorageErrorKind::KeyNotFound) must be returned.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait JwkStorageThresholdExt: JwkStorage {
+  /// Start the distributed key generation of a key shared by `max_signers` participants, `min_signers` of which are
+  /// required to sign, as the participant with the given `identifier`.
+  ///
+  /// The secret state of the key generation is kept under `key_id` and the package to be sent to all other
+  /// participants is returned.
+  async fn dkg_part1(
+    &self,
+    key_id: &KeyId,
+    identifier: Identifier,
+    max_signers: u16,
+    min_signers: u16,
+  ) -> KeyStorageResult<dkg::round1::Package>;
+
+  /// Continue the key generation identified by `key_id` with the first round packages of all other participants.
+  ///
+  /// Returns the packages to be sent to each of the other participants. These packages contain secret material and
+  /// must only be readable by their recipient.
+  async fn dkg_part2(
+    &self,
+    key_id: &KeyId,
+    round1_packages: &BTreeMap<Identifier, dkg::round1::Package>,
+  ) -> KeyStorageResult<BTreeMap<Identifier, dkg::round2::Package>>;
+
+  /// Complete the key generation identified by `key_id` with the first round packages of all other participants and
+  /// the second round packages sent to this participant, storing the resulting key share under `key_id`.
+  ///
+  /// Returns the public key package of the shared key.
+  async fn dkg_part3(
+    &self,
+    key_id: &KeyId,
+    round1_packages: &BTreeMap<Identifier, dkg::round1::Package>,
+    round2_packages: &BTreeMap<Identifier, dkg::round2::Package>,
+  ) -> KeyStorageResult<PublicKeyPackage>;
+
+  /// Generate the signing nonces for the next signature with the share identified by `key_id`, keeping them in
+  /// storage, and return the identifier of this participant together with the commitments to the nonces.
+  async fn threshold_commit(&self, key_id: &KeyId) -> KeyStorageResult<(Identifier, SigningCommitments)>;
+
+  /// Produce the signature share over the message of `signing_package` with the share identified by `key_id` and the
+  /// nonces committed to by the last call to [`JwkStorageThresholdExt::threshold_commit`].
+  ///
+  /// Nonces must only ever be used for a single signature share.
+  async fn threshold_sign(&self, key_id: &KeyId, signing_package: &SigningPackage) -> KeyStorageResult<SignatureShare>;
+
+  /// Returns the public key package of the shared key identified by `key_id`.
+  async fn threshold_public_key_package(&self, key_id: &KeyId) -> KeyStorageResult<PublicKeyPackage>;
+}
@@ -0,0 +1,122 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use der::asn1::AnyRef;
+use der::asn1::OctetStringRef;
+use der::Decode;
+use der::Enumerated;
+use der::Sequence;
+use identity_core::common::Timestamp;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+
+use super::CertificateChainValidator;
+use super::KeyAttestation;
+use super::KeyAttestationError;
+use super::KeyAttestationErrorKind;
+use super::KeyAttestationLevel;
+use super::KeyAttestationResult;
+use super::KeyAttestationVerifier;
+use super::ValidatedCertificateChain;
+
+/// The extension of the attestation certificate containing the key description.
+const KEY_DESCRIPTION_OID: &str = "1.3.6.1.4.1.11129.2.1.17";
+
+/// A [`KeyAttestationVerifier`] for
+/// [Android Key Attestation](https://source.android.com/docs/security/features/keystore/attestation) statements.
+///
+/// The validator should be configured with Google's attestation root certificates. The established level is the
+/// lower of the attestation and the KeyMint security level of the statement.
+#[derive(Debug)]
+pub struct AndroidKeyAttestationVerifier<V> {
+  validator: CertificateChainValidator<V>,
+  challenge: Option<Vec<u8>>,
+}
+
+impl<V> AndroidKeyAttestationVerifier<V>
+where
+  V: JwsVerifier,
+{
+  /// Creates a new [`AndroidKeyAttestationVerifier`] validating certificate chains with `validator`.
+  pub fn new(validator: CertificateChainValidator<V>) -> Self {
+    Self {
+      validator,
+      challenge: None,
+    }
+  }
+
+  /// Requires the attestation challenge of verified statements to be `challenge`.
+  #[must_use]
+  pub fn with_challenge(mut self, challenge: impl Into<Vec<u8>>) -> Self {
+    self.challenge = Some(challenge.into());
+    self
+  }
+}
+
+impl<V> KeyAttestationVerifier for AndroidKeyAttestationVerifier<V>
+where
+  V: JwsVerifier,
+{
+  fn verify(&self, attestation: &KeyAttestation, public_key: &Jwk) -> KeyAttestationResult<KeyAttestationLevel> {
+    if attestation.format() != KeyAttestation::ANDROID_KEY {
+      return Err(KeyAttestationErrorKind::UnsupportedFormat.into());
+    }
+
+    let chain: ValidatedCertificateChain = self
+      .validator
+      .validate(attestation.certificate_chain(), Timestamp::now_utc())?;
+    chain.ensure_leaf_public_key(public_key)?;
+
+    let key_description: &[u8] = chain.leaf_extension(KEY_DESCRIPTION_OID)?.ok_or_else(|| {
+      KeyAttestationError::new(KeyAttestationErrorKind::InvalidStatement).with_custom_message("missing key description")
+    })?;
+    let key_description: KeyDescription<'_> = KeyDescription::from_der(key_description)
+      .map_err(|err| KeyAttestationError::new(KeyAttestationErrorKind::InvalidStatement).with_source(err))?;
+
+    if let Some(ref challenge) = self.challenge {
+      if key_description.attestation_challenge.as_bytes() != challenge.as_slice() {
+        return Err(KeyAttestationErrorKind::ChallengeMismatch.into());
+      }
+    }
+
+    Ok(
+      key_description
+        .attestation_security_level
+        .min(key_description.key_mint_security_level)
+        .into(),
+    )
+  }
+}
+
+/// The key description of an attestation certificate. Only the fields relevant to the verification are decoded.
+///
+/// See: <https://source.android.com/docs/security/features/keystore/attestation#schema>
+#[derive(Sequence)]
+struct KeyDescription<'a> {
+  attestation_version: u32,
+  attestation_security_level: SecurityLevel,
+  key_mint_version: u32,
+  key_mint_security_level: SecurityLevel,
+  attestation_challenge: OctetStringRef<'a>,
+  unique_id: OctetStringRef<'a>,
+  software_enforced: AnyRef<'a>,
+  hardware_enforced: AnyRef<'a>,
+}
+
+#[derive(Enumerated, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+enum SecurityLevel {
+  Software = 0,
+  TrustedEnvironment = 1,
+  StrongBox = 2,
+}
+
+impl From<SecurityLevel> for KeyAttestationLevel {
+  fn from(level: SecurityLevel) -> Self {
+    match level {
+      SecurityLevel::Software => Self::Software,
+      SecurityLevel::TrustedEnvironment => Self::TrustedEnvironment,
+      SecurityLevel::StrongBox => Self::SecureHardware,
+    }
+  }
+}
@@ -0,0 +1,57 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+
+use super::CertificateChainValidator;
+use super::KeyAttestation;
+use super::KeyAttestationErrorKind;
+use super::KeyAttestationLevel;
+use super::KeyAttestationResult;
+use super::KeyAttestationVerifier;
+
+/// A [`KeyAttestationVerifier`] for statements consisting only of a certificate chain issued for the attested key.
+///
+/// Suitable for roots that only certify keys generated in a known kind of hardware, e.g. the attestation CA of a
+/// TPM or security key vendor. Every successfully verified statement establishes the configured level.
+#[derive(Debug)]
+pub struct CertificateChainAttestationVerifier<V> {
+  format: String,
+  level: KeyAttestationLevel,
+  validator: CertificateChainValidator<V>,
+}
+
+impl<V> CertificateChainAttestationVerifier<V>
+where
+  V: JwsVerifier,
+{
+  /// Creates a new [`CertificateChainAttestationVerifier`] for statements of the given `format`, establishing
+  /// `level` if the chain is valid according to `validator`.
+  pub fn new(format: impl Into<String>, level: KeyAttestationLevel, validator: CertificateChainValidator<V>) -> Self {
+    Self {
+      format: format.into(),
+      level,
+      validator,
+    }
+  }
+}
+
+impl<V> KeyAttestationVerifier for CertificateChainAttestationVerifier<V>
+where
+  V: JwsVerifier,
+{
+  fn verify(&self, attestation: &KeyAttestation, public_key: &Jwk) -> KeyAttestationResult<KeyAttestationLevel> {
+    if attestation.format() != self.format {
+      return Err(KeyAttestationErrorKind::UnsupportedFormat.into());
+    }
+
+    self
+      .validator
+      .validate(attestation.certificate_chain(), Timestamp::now_utc())?
+      .ensure_leaf_public_key(public_key)?;
+
+    Ok(self.level)
+  }
+}
@@ -0,0 +1,102 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_credential::validator::X509CertificateChain;
+use identity_credential::validator::X509Error;
+use identity_credential::validator::X509ErrorKind;
+use identity_credential::validator::X509TrustStore;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+
+use super::KeyAttestationError;
+use super::KeyAttestationErrorKind;
+use super::KeyAttestationResult;
+
+/// Validates X.509 certificate chains against a set of trusted root certificates.
+///
+/// Certificate signatures are verified with the given [`JwsVerifier`], which must support the algorithms used in
+/// the chains, e.g. `ES256` and `RS256` for Android Key Attestation. EC (P-256, P-384, secp256k1), RSA and Ed25519
+/// keys are supported.
+///
+/// Certificate revocation is not checked.
+#[derive(Debug)]
+pub struct CertificateChainValidator<V> {
+  trust_store: X509TrustStore,
+  verifier: V,
+}
+
+impl<V> CertificateChainValidator<V>
+where
+  V: JwsVerifier,
+{
+  /// Creates a new [`CertificateChainValidator`] without any trusted roots.
+  pub fn new(verifier: V) -> Self {
+    Self {
+      trust_store: X509TrustStore::new(),
+      verifier,
+    }
+  }
+
+  /// Adds the DER encoded certificate `root` to the trusted roots.
+  pub fn with_root(mut self, root: &[u8]) -> KeyAttestationResult<Self> {
+    self.trust_store = self.trust_store.with_root(root).map_err(attestation_error)?;
+    Ok(self)
+  }
+
+  /// Validates the DER encoded certificate `chain` at the given `time`.
+  ///
+  /// The chain must start with the end-entity certificate and each certificate must be issued by the next one. The
+  /// last certificate must either be one of the trusted roots or be issued by one of them. The constraints on the
+  /// issuers and critical extensions are checked as described in [`X509TrustStore::validate_chain`].
+  pub fn validate(&self, chain: &[Vec<u8>], time: Timestamp) -> KeyAttestationResult<ValidatedCertificateChain> {
+    self
+      .trust_store
+      .validate_chain(chain, time, &self.verifier)
+      .map(ValidatedCertificateChain)
+      .map_err(attestation_error)
+  }
+}
+
+/// A certificate chain successfully validated by a [`CertificateChainValidator`].
+#[derive(Debug, Clone)]
+pub struct ValidatedCertificateChain(X509CertificateChain);
+
+impl ValidatedCertificateChain {
+  /// Returns the public key certified by the end-entity certificate of the chain.
+  pub fn leaf_public_key(&self) -> KeyAttestationResult<Jwk> {
+    self.0.leaf_public_key().map_err(attestation_error)
+  }
+
+  /// Ensures that the end-entity certificate of the chain certifies `public_key`.
+  pub fn ensure_leaf_public_key(&self, public_key: &Jwk) -> KeyAttestationResult<()> {
+    self.0.ensure_leaf_public_key(public_key).map_err(attestation_error)
+  }
+
+  /// Returns the DER encoded value of the extension identified by `oid` of the end-entity certificate, if present.
+  pub fn leaf_extension(&self, oid: &str) -> KeyAttestationResult<Option<&[u8]>> {
+    self.0.leaf_extension(oid).map_err(attestation_error)
+  }
+}
+
+/// Converts an error of the X.509 chain validation into a [`KeyAttestationError`], keeping its message and source.
+fn attestation_error(error: X509Error) -> KeyAttestationError {
+  let kind: KeyAttestationErrorKind = match error.kind() {
+    X509ErrorKind::InvalidCertificate => KeyAttestationErrorKind::InvalidCertificate,
+    X509ErrorKind::InvalidSignature => KeyAttestationErrorKind::InvalidSignature,
+    X509ErrorKind::CertificateNotValidAtTime => KeyAttestationErrorKind::CertificateNotValidAtTime,
+    X509ErrorKind::UntrustedChain => KeyAttestationErrorKind::UntrustedChain,
+    X509ErrorKind::PublicKeyMismatch => KeyAttestationErrorKind::PublicKeyMismatch,
+    _ => KeyAttestationErrorKind::InvalidStatement,
+  };
+  let message: Option<String> = error.custom_message().map(ToOwned::to_owned);
+
+  let mut attestation_error: KeyAttestationError = KeyAttestationError::new(kind);
+  if let Some(message) = message {
+    attestation_error = attestation_error.with_custom_message(message);
+  }
+  if let Some(source) = error.into_source() {
+    attestation_error = attestation_error.with_source(source);
+  }
+  attestation_error
+}
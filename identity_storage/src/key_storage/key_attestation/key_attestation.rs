@@ -0,0 +1,37 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// An attestation statement for a single key, as received when onboarding a key generated in hardware.
+///
+/// The statement consists of its format, e.g. [`KeyAttestation::ANDROID_KEY`], and the DER encoded X.509
+/// certificate chain, starting with the certificate of the attested key and ending with the certificate closest to
+/// the root. The root itself may be omitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyAttestation {
+  format: String,
+  certificate_chain: Vec<Vec<u8>>,
+}
+
+impl KeyAttestation {
+  /// The format of [Android Key Attestation](https://source.android.com/docs/security/features/keystore/attestation)
+  /// statements.
+  pub const ANDROID_KEY: &'static str = "android-key";
+
+  /// Creates a new [`KeyAttestation`] of the given `format`.
+  pub fn new(format: impl Into<String>, certificate_chain: Vec<Vec<u8>>) -> Self {
+    Self {
+      format: format.into(),
+      certificate_chain,
+    }
+  }
+
+  /// Returns the format of the statement.
+  pub fn format(&self) -> &str {
+    &self.format
+  }
+
+  /// Returns the DER encoded certificate chain, starting with the certificate of the attested key.
+  pub fn certificate_chain(&self) -> &[Vec<u8>] {
+    &self.certificate_chain
+  }
+}
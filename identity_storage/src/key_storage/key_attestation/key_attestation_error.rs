@@ -0,0 +1,62 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+
+use identity_core::common::SingleStructError;
+
+/// Error type for key attestation verification.
+pub type KeyAttestationError = SingleStructError<KeyAttestationErrorKind>;
+
+/// Alias for a `Result` with the error type [`KeyAttestationError`].
+pub type KeyAttestationResult<T> = Result<T, KeyAttestationError>;
+
+/// The cause of a failed key attestation verification.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum KeyAttestationErrorKind {
+  /// Indicates that the verifier does not support the format of the attestation statement.
+  UnsupportedFormat,
+  /// Indicates a certificate that could not be decoded, or that uses an unsupported key type or signature algorithm.
+  InvalidCertificate,
+  /// Indicates a certificate whose signature could not be verified with the key of its issuer.
+  InvalidSignature,
+  /// Indicates a certificate used outside of its validity period.
+  CertificateNotValidAtTime,
+  /// Indicates a certificate chain that does not lead to any of the configured roots.
+  UntrustedChain,
+  /// Indicates that the attested key is not the expected key.
+  PublicKeyMismatch,
+  /// Indicates that the statement does not contain the expected challenge.
+  ChallengeMismatch,
+  /// Indicates that the attestation data of the statement is missing or malformed.
+  InvalidStatement,
+}
+
+impl KeyAttestationErrorKind {
+  /// Returns the string representation of the error.
+  pub const fn as_str(&self) -> &str {
+    match self {
+      Self::UnsupportedFormat => "unsupported attestation format",
+      Self::InvalidCertificate => "invalid certificate",
+      Self::InvalidSignature => "invalid certificate signature",
+      Self::CertificateNotValidAtTime => "certificate is not valid at the time of verification",
+      Self::UntrustedChain => "certificate chain does not lead to a trusted root",
+      Self::PublicKeyMismatch => "the attested key does not match the expected public key",
+      Self::ChallengeMismatch => "the attestation challenge does not match the expected challenge",
+      Self::InvalidStatement => "invalid attestation statement",
+    }
+  }
+}
+
+impl AsRef<str> for KeyAttestationErrorKind {
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl Display for KeyAttestationErrorKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
@@ -0,0 +1,37 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+
+/// The protection of a key's private material as established by verifying a [`KeyAttestation`](super::KeyAttestation).
+///
+/// Levels are ordered from weakest to strongest, so policies can be expressed as e.g.
+/// `level >= KeyAttestationLevel::TrustedEnvironment`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum KeyAttestationLevel {
+  /// The key is protected by software only.
+  Software,
+  /// The key resides in a trusted execution environment isolated from the main operating system, e.g. ARM TrustZone.
+  TrustedEnvironment,
+  /// The key resides in dedicated secure hardware, e.g. a TPM, a secure element or Android StrongBox.
+  SecureHardware,
+}
+
+impl KeyAttestationLevel {
+  /// Returns the string representation of the level.
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Software => "software",
+      Self::TrustedEnvironment => "trustedEnvironment",
+      Self::SecureHardware => "secureHardware",
+    }
+  }
+}
+
+impl Display for KeyAttestationLevel {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
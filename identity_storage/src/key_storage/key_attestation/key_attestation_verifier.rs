@@ -0,0 +1,24 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jwk::Jwk;
+
+use super::KeyAttestation;
+use super::KeyAttestationLevel;
+use super::KeyAttestationResult;
+
+/// Verifies attestation statements of a specific format.
+///
+/// Implementations validate the statement against the roots they were configured with and ensure it attests the
+/// key with the given public key.
+pub trait KeyAttestationVerifier {
+  /// Verifies that `attestation` is a valid statement for the key with the given `public_key`, returning the
+  /// level of protection it establishes.
+  fn verify(&self, attestation: &KeyAttestation, public_key: &Jwk) -> KeyAttestationResult<KeyAttestationLevel>;
+}
+
+impl KeyAttestationVerifier for Box<dyn KeyAttestationVerifier> {
+  fn verify(&self, attestation: &KeyAttestation, public_key: &Jwk) -> KeyAttestationResult<KeyAttestationLevel> {
+    <dyn KeyAttestationVerifier>::verify(self, attestation, public_key)
+  }
+}
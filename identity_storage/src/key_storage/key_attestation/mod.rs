@@ -0,0 +1,24 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Verification of attestation statements for keys generated in hardware, e.g. in a TPM or a secure enclave.
+//!
+//! An attestation statement proves that a key was generated in, and cannot be exported from, a specific kind of
+//! hardware. Verifying it against a set of trusted roots establishes a [`KeyAttestationLevel`] which can be recorded
+//! alongside the key with [`JwkStorageAttestationExt`](crate::key_storage::JwkStorageAttestationExt).
+
+mod android_key_attestation_verifier;
+mod certificate_chain_attestation_verifier;
+mod certificate_chain_validator;
+mod key_attestation;
+mod key_attestation_error;
+mod key_attestation_level;
+mod key_attestation_verifier;
+
+pub use android_key_attestation_verifier::*;
+pub use certificate_chain_attestation_verifier::*;
+pub use certificate_chain_validator::*;
+pub use key_attestation::*;
+pub use key_attestation_error::*;
+pub use key_attestation_level::*;
+pub use key_attestation_verifier::*;
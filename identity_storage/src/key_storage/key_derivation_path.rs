@@ -0,0 +1,165 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::KeyId;
+use super::KeyStorageError;
+use super::KeyStorageErrorKind;
+use super::KeyStorageResult;
+use crate::key_id_storage::Namespace;
+
+/// A [SLIP-10](https://github.com/satoshilabs/slips/blob/master/slip-0010.md) derivation path consisting only of
+/// hardened indices, e.g. `m/44'/4218'/0'/0'/0'`.
+///
+/// Keys derived with a [`JwkStorageDerivationExt`](crate::key_storage::JwkStorageDerivationExt) are stored under the
+/// key id returned by [`KeyDerivationPath::to_key_id`], so that the path a key was derived from can be recovered from
+/// the [`KeyIdStorage`](crate::key_id_storage::KeyIdStorage).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyDerivationPath(Vec<u32>);
+
+impl KeyDerivationPath {
+  /// The prefix of the key ids of derived keys.
+  pub const KEY_ID_PREFIX: &'static str = "slip10:";
+  /// The largest index of a path segment, before hardening.
+  pub const MAX_INDEX: u32 = (1 << 31) - 1;
+  /// The bit set on hardened indices.
+  pub const HARDENED_BIT: u32 = 1 << 31;
+
+  /// Creates a new path from the given indices, which are implicitly hardened.
+  ///
+  /// Fails if no index is given or an index exceeds [`KeyDerivationPath::MAX_INDEX`].
+  pub fn new(indices: impl IntoIterator<Item = u32>) -> KeyStorageResult<Self> {
+    let indices: Vec<u32> = indices.into_iter().collect();
+    if indices.is_empty() {
+      return Err(invalid_path("a derivation path must contain at least one index"));
+    }
+    if let Some(index) = indices.iter().find(|index| **index > Self::MAX_INDEX) {
+      return Err(invalid_path(format!(
+        "index {index} exceeds the maximum index {}",
+        Self::MAX_INDEX
+      )));
+    }
+    Ok(Self(indices))
+  }
+
+  /// Returns the indices of the path, before hardening.
+  pub fn indices(&self) -> &[u32] {
+    &self.0
+  }
+
+  /// Returns the hardened indices of the path, i.e. with [`KeyDerivationPath::HARDENED_BIT`] set.
+  pub fn hardened_indices(&self) -> impl Iterator<Item = u32> + '_ {
+    self.0.iter().map(|index| index | Self::HARDENED_BIT)
+  }
+
+  /// Returns a new path extending this path with the given `index`, e.g. to derive one key per verification method.
+  pub fn child(&self, index: u32) -> KeyStorageResult<Self> {
+    Self::new(self.0.iter().copied().chain(std::iter::once(index)))
+  }
+
+  /// Returns the key id under which a key derived at this path is stored.
+  pub fn to_key_id(&self) -> KeyId {
+    KeyId::new(format!("{}{self}", Self::KEY_ID_PREFIX))
+  }
+
+  /// Recovers the path a key was derived from from its `key_id`, or `None` if the key was not derived.
+  ///
+  /// Key ids scoped to a [`Namespace`] by a [`ScopedJwkStorage`](crate::key_storage::ScopedJwkStorage) are supported.
+  pub fn from_key_id(key_id: &KeyId) -> Option<Self> {
+    let (scope, path) = key_id.as_str().split_once(Self::KEY_ID_PREFIX)?;
+    if !scope.is_empty()
+      && scope
+        .strip_suffix(Namespace::SEPARATOR)
+        .map_or(true, |namespace| Namespace::new(namespace).is_err())
+    {
+      return None;
+    }
+    path.parse().ok()
+  }
+}
+
+impl Display for KeyDerivationPath {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("m")?;
+    for index in self.0.iter() {
+      write!(f, "/{index}'")?;
+    }
+    Ok(())
+  }
+}
+
+impl FromStr for KeyDerivationPath {
+  type Err = KeyStorageError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut segments = s.split('/');
+    if segments.next() != Some("m") {
+      return Err(invalid_path(format!("`{s}` does not start with `m`")));
+    }
+
+    let indices = segments
+      .map(|segment| {
+        let index: &str = segment
+          .strip_suffix('\'')
+          .or_else(|| segment.strip_suffix('h'))
+          .ok_or_else(|| invalid_path(format!("segment `{segment}` of `{s}` is not hardened")))?;
+        index
+          .parse::<u32>()
+          .map_err(|err| invalid_path(format!("segment `{segment}` of `{s}` is not a valid index")).with_source(err))
+      })
+      .collect::<KeyStorageResult<Vec<u32>>>()?;
+
+    Self::new(indices)
+  }
+}
+
+impl TryFrom<String> for KeyDerivationPath {
+  type Error = KeyStorageError;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl From<KeyDerivationPath> for String {
+  fn from(value: KeyDerivationPath) -> Self {
+    value.to_string()
+  }
+}
+
+fn invalid_path(message: impl Into<std::borrow::Cow<'static, str>>) -> KeyStorageError {
+  KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+    .with_custom_message(format!("invalid derivation path: {}", message.into()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_and_display() {
+    let path: KeyDerivationPath = "m/44'/4218h/0'/0'".parse().unwrap();
+    assert_eq!(path.indices(), &[44, 4218, 0, 0]);
+    assert_eq!(path.to_string(), "m/44'/4218'/0'/0'");
+    assert_eq!(path.child(7).unwrap().to_string(), "m/44'/4218'/0'/0'/7'");
+
+    for invalid in ["", "m", "44'/0'", "m/44", "m/x'", "m/2147483648'"] {
+      assert!(invalid.parse::<KeyDerivationPath>().is_err(), "{invalid}");
+    }
+  }
+
+  #[test]
+  fn key_id_round_trip() {
+    let path: KeyDerivationPath = "m/44'/4218'/0'".parse().unwrap();
+    assert_eq!(KeyDerivationPath::from_key_id(&path.to_key_id()), Some(path.clone()));
+
+    let scoped = KeyId::new(format!("tenant{}{}", Namespace::SEPARATOR, path.to_key_id()));
+    assert_eq!(KeyDerivationPath::from_key_id(&scoped), Some(path));
+
+    assert_eq!(KeyDerivationPath::from_key_id(&KeyId::new("random")), None);
+    assert_eq!(KeyDerivationPath::from_key_id(&KeyId::new("a:b:slip10:m/0'")), None);
+  }
+}
@@ -11,6 +11,7 @@ use crypto::signatures::ed25519::SecretKey;
 use identity_verification::jose::jwk::EdCurve;
 use identity_verification::jose::jwk::Jwk;
 use identity_verification::jose::jwk::JwkType;
+use identity_verification::jose::jwk::SecretJwk;
 use identity_verification::jose::jws::JwsAlgorithm;
 use identity_verification::jwk::BlsCurve;
 use rand::distributions::DistString;
@@ -27,14 +28,21 @@ use super::KeyStorageErrorKind;
 use super::KeyStorageResult;
 use super::KeyType;
 use crate::key_storage::JwkStorage;
+use crate::key_storage::JwkStorageExportExt;
 
-/// The map from key ids to JWKs.
-type JwkKeyStore = HashMap<KeyId, Jwk>;
+/// The map from key ids to private JWKs.
+type JwkKeyStore = HashMap<KeyId, SecretJwk>;
 
 /// An insecure, in-memory [`JwkStorage`] implementation that serves as an example and may be used in tests.
 #[derive(Debug)]
 pub struct JwkMemStore {
   jwk_store: Shared<JwkKeyStore>,
+  #[cfg(feature = "key-attestation")]
+  attestation_levels: Shared<HashMap<KeyId, super::KeyAttestationLevel>>,
+  #[cfg(feature = "key-derivation")]
+  derivation_seed: Shared<Option<key_derivation_impl::DerivationSeed>>,
+  #[cfg(feature = "threshold")]
+  threshold_shares: Shared<HashMap<KeyId, threshold_impl::ThresholdShare>>,
 }
 
 impl JwkMemStore {
@@ -42,6 +50,12 @@ impl JwkMemStore {
   pub fn new() -> Self {
     Self {
       jwk_store: Shared::new(HashMap::new()),
+      #[cfg(feature = "key-attestation")]
+      attestation_levels: Shared::new(HashMap::new()),
+      #[cfg(feature = "key-derivation")]
+      derivation_seed: Shared::new(None),
+      #[cfg(feature = "threshold")]
+      threshold_shares: Shared::new(HashMap::new()),
     }
   }
 
@@ -83,7 +97,7 @@ impl JwkStorage for JwkMemStore {
     let public_jwk: Jwk = jwk.to_public().expect("should only panic if kty == oct");
 
     let mut jwk_store: RwLockWriteGuard<'_, JwkKeyStore> = self.jwk_store.write().await;
-    jwk_store.insert(kid.clone(), jwk);
+    jwk_store.insert(kid.clone(), SecretJwk::new(jwk).expect("generated keys are private"));
 
     Ok(JwkGenOutput::new(kid, public_jwk))
   }
@@ -91,12 +105,11 @@ impl JwkStorage for JwkMemStore {
   async fn insert(&self, jwk: Jwk) -> KeyStorageResult<KeyId> {
     let key_type = MemStoreKeyType::try_from(&jwk)?;
 
-    if !jwk.is_private() {
-      return Err(
-        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
-          .with_custom_message("expected a Jwk with all private key components set"),
-      );
-    }
+    let jwk: SecretJwk = SecretJwk::new(jwk).map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("expected a Jwk with all private key components set")
+        .with_source(err)
+    })?;
 
     match jwk.alg() {
       Some(alg) => {
@@ -165,7 +178,7 @@ impl JwkStorage for JwkMemStore {
     };
 
     // Obtain the corresponding private key and sign `data`.
-    let jwk: &Jwk = jwk_store
+    let jwk: &SecretJwk = jwk_store
       .get(key_id)
       .ok_or_else(|| KeyStorageError::new(KeyStorageErrorKind::KeyNotFound))?;
     let secret_key = expand_secret_jwk(jwk)?;
@@ -175,6 +188,14 @@ impl JwkStorage for JwkMemStore {
   async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
     let mut jwk_store: RwLockWriteGuard<'_, JwkKeyStore> = self.jwk_store.write().await;
 
+    #[cfg(feature = "key-attestation")]
+    self.attestation_levels.write().await.remove(key_id);
+
+    #[cfg(feature = "threshold")]
+    if self.threshold_shares.write().await.remove(key_id).is_some() {
+      return Ok(());
+    }
+
     jwk_store
       .remove(key_id)
       .map(|_| ())
@@ -182,11 +203,35 @@ impl JwkStorage for JwkMemStore {
   }
 
   async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    #[cfg(feature = "threshold")]
+    if self
+      .threshold_shares
+      .read()
+      .await
+      .get(key_id)
+      .is_some_and(threshold_impl::ThresholdShare::is_complete)
+    {
+      return Ok(true);
+    }
+
     let jwk_store: RwLockReadGuard<'_, JwkKeyStore> = self.jwk_store.read().await;
     Ok(jwk_store.contains_key(key_id))
   }
 }
 
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl JwkStorageExportExt for JwkMemStore {
+  async fn export(&self, key_id: &KeyId) -> KeyStorageResult<SecretJwk> {
+    let jwk_store: RwLockReadGuard<'_, JwkKeyStore> = self.jwk_store.read().await;
+
+    jwk_store
+      .get(key_id)
+      .cloned()
+      .ok_or_else(|| KeyStorageError::new(KeyStorageErrorKind::KeyNotFound))
+  }
+}
+
 #[derive(Debug, Copy, Clone)]
 enum MemStoreKeyType {
   Ed25519,
@@ -301,6 +346,316 @@ fn check_key_alg_compatibility(key_type: MemStoreKeyType, alg: &JwsAlgorithm) ->
   }
 }
 
+#[cfg(feature = "key-attestation")]
+mod key_attestation_impl {
+  use async_trait::async_trait;
+
+  use super::JwkMemStore;
+  use crate::key_storage::JwkStorage;
+  use crate::key_storage::JwkStorageAttestationExt;
+  use crate::key_storage::KeyAttestationLevel;
+  use crate::key_storage::KeyId;
+  use crate::key_storage::KeyStorageError;
+  use crate::key_storage::KeyStorageErrorKind;
+  use crate::key_storage::KeyStorageResult;
+
+  /// JwkStorageAttestationExt implementation for JwkMemStore
+  #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+  #[cfg_attr(feature = "send-sync-storage", async_trait)]
+  impl JwkStorageAttestationExt for JwkMemStore {
+    async fn set_attestation_level(&self, key_id: &KeyId, level: KeyAttestationLevel) -> KeyStorageResult<()> {
+      if !self.exists(key_id).await? {
+        return Err(KeyStorageError::new(KeyStorageErrorKind::KeyNotFound));
+      }
+      self.attestation_levels.write().await.insert(key_id.clone(), level);
+      Ok(())
+    }
+
+    async fn attestation_level(&self, key_id: &KeyId) -> KeyStorageResult<Option<KeyAttestationLevel>> {
+      if !self.exists(key_id).await? {
+        return Err(KeyStorageError::new(KeyStorageErrorKind::KeyNotFound));
+      }
+      Ok(self.attestation_levels.read().await.get(key_id).copied())
+    }
+  }
+}
+
+#[cfg(feature = "key-derivation")]
+mod key_derivation_impl {
+  use core::fmt::Debug;
+  use core::fmt::Formatter;
+
+  use async_trait::async_trait;
+  use crypto::keys::bip39;
+  use crypto::keys::slip10;
+  use crypto::keys::slip10::Segment;
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_verification::jose::jwk::Jwk;
+  use identity_verification::jose::jws::JwsAlgorithm;
+
+  use super::check_key_alg_compatibility;
+  use super::encode_jwk;
+  use super::JwkMemStore;
+  use super::MemStoreKeyType;
+  use crate::key_storage::JwkGenOutput;
+  use crate::key_storage::JwkStorageDerivationExt;
+  use crate::key_storage::KeyDerivationPath;
+  use crate::key_storage::KeyId;
+  use crate::key_storage::KeyStorageError;
+  use crate::key_storage::KeyStorageErrorKind;
+  use crate::key_storage::KeyStorageResult;
+  use crate::key_storage::KeyType;
+
+  /// The seed keys are derived from. Never exposed, not even through `Debug`.
+  pub(super) struct DerivationSeed(slip10::Seed);
+
+  impl Debug for DerivationSeed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+      f.write_str("DerivationSeed(..)")
+    }
+  }
+
+  impl JwkMemStore {
+    /// Sets the seed keys are derived from through [`JwkStorageDerivationExt::derive`] to the seed of the BIP39
+    /// `mnemonic` and `passphrase`, replacing a previously stored seed.
+    ///
+    /// Fails if `mnemonic` is not a valid mnemonic of the english wordlist.
+    pub async fn store_mnemonic(&self, mnemonic: &str, passphrase: &str) -> KeyStorageResult<()> {
+      let mnemonic: bip39::Mnemonic = bip39::Mnemonic::from(mnemonic.to_owned());
+      bip39::wordlist::verify(&mnemonic, &bip39::wordlist::ENGLISH).map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message(format!("invalid BIP39 mnemonic: {err:?}"))
+      })?;
+      let seed: bip39::Seed = bip39::mnemonic_to_seed(&mnemonic, &bip39::Passphrase::from(passphrase.to_owned()));
+
+      *self.derivation_seed.write().await = Some(DerivationSeed(slip10::Seed::from_bytes(seed.as_ref())));
+      Ok(())
+    }
+  }
+
+  /// JwkStorageDerivationExt implementation for JwkMemStore
+  #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+  #[cfg_attr(feature = "send-sync-storage", async_trait)]
+  impl JwkStorageDerivationExt for JwkMemStore {
+    async fn derive(
+      &self,
+      key_type: KeyType,
+      alg: JwsAlgorithm,
+      path: &KeyDerivationPath,
+    ) -> KeyStorageResult<JwkGenOutput> {
+      let key_type: MemStoreKeyType = MemStoreKeyType::try_from(&key_type)?;
+      if !matches!(key_type, MemStoreKeyType::Ed25519) {
+        return Err(
+          KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+            .with_custom_message(format!("{key_type} keys cannot be derived")),
+        );
+      }
+      check_key_alg_compatibility(key_type, &alg)?;
+
+      let private_key: SecretKey = {
+        let seed = self.derivation_seed.read().await;
+        let DerivationSeed(seed) = seed.as_ref().ok_or_else(|| {
+          KeyStorageError::new(KeyStorageErrorKind::KeyNotFound).with_custom_message("no mnemonic has been stored")
+        })?;
+        seed
+          .derive::<SecretKey, _>(path.indices().iter().map(|index| index.harden()))
+          .secret_key()
+      };
+      let public_key = private_key.public_key();
+
+      let kid: KeyId = path.to_key_id();
+
+      let mut jwk: Jwk = encode_jwk(&private_key, &public_key);
+      jwk.set_alg(alg.name());
+      jwk.set_kid(jwk.thumbprint_sha256_b64());
+      let public_jwk: Jwk = jwk.to_public().expect("should only panic if kty == oct");
+
+      self
+        .jwk_store
+        .write()
+        .await
+        .insert(kid.clone(), SecretJwk::new(jwk).expect("derived keys are private"));
+
+      Ok(JwkGenOutput::new(kid, public_jwk))
+    }
+  }
+}
+
+#[cfg(feature = "threshold")]
+mod threshold_impl {
+  use core::fmt::Debug;
+  use core::fmt::Formatter;
+  use std::collections::BTreeMap;
+
+  use async_trait::async_trait;
+  use frost_ed25519::keys::dkg;
+  use frost_ed25519::keys::KeyPackage;
+  use frost_ed25519::keys::PublicKeyPackage;
+  use frost_ed25519::round1::SigningCommitments;
+  use frost_ed25519::round1::SigningNonces;
+  use frost_ed25519::round2::SignatureShare;
+  use frost_ed25519::Identifier;
+  use frost_ed25519::SigningPackage;
+
+  use super::JwkMemStore;
+  use crate::key_storage::JwkStorageThresholdExt;
+  use crate::key_storage::KeyId;
+  use crate::key_storage::KeyStorageError;
+  use crate::key_storage::KeyStorageErrorKind;
+  use crate::key_storage::KeyStorageResult;
+
+  /// The share of a threshold key held by a participant, or the state of its generation. Never exposed, not even
+  /// through `Debug`.
+  pub(super) enum ThresholdShare {
+    Round1(dkg::round1::SecretPackage),
+    Round2(dkg::round2::SecretPackage),
+    Complete {
+      key_package: KeyPackage,
+      public_key_package: PublicKeyPackage,
+      nonces: Option<SigningNonces>,
+    },
+  }
+
+  impl ThresholdShare {
+    pub(super) fn is_complete(&self) -> bool {
+      matches!(self, Self::Complete { .. })
+    }
+  }
+
+  impl Debug for ThresholdShare {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+      f.write_str("ThresholdShare(..)")
+    }
+  }
+
+  fn frost_error(err: frost_ed25519::Error) -> KeyStorageError {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_source(err)
+  }
+
+  fn unexpected_state() -> KeyStorageError {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_custom_message("the threshold key is not in the expected generation round")
+  }
+
+  /// JwkStorageThresholdExt implementation for JwkMemStore
+  #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+  #[cfg_attr(feature = "send-sync-storage", async_trait)]
+  impl JwkStorageThresholdExt for JwkMemStore {
+    async fn dkg_part1(
+      &self,
+      key_id: &KeyId,
+      identifier: Identifier,
+      max_signers: u16,
+      min_signers: u16,
+    ) -> KeyStorageResult<dkg::round1::Package> {
+      let key_id_taken: bool = self.jwk_store.read().await.contains_key(key_id);
+      let mut shares = self.threshold_shares.write().await;
+      if key_id_taken || shares.contains_key(key_id) {
+        return Err(
+          KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+            .with_custom_message(format!("key id {key_id} is already in use")),
+        );
+      }
+
+      let (secret_package, package) =
+        dkg::part1(identifier, max_signers, min_signers, rand::thread_rng()).map_err(frost_error)?;
+      shares.insert(key_id.clone(), ThresholdShare::Round1(secret_package));
+
+      Ok(package)
+    }
+
+    async fn dkg_part2(
+      &self,
+      key_id: &KeyId,
+      round1_packages: &BTreeMap<Identifier, dkg::round1::Package>,
+    ) -> KeyStorageResult<BTreeMap<Identifier, dkg::round2::Package>> {
+      let mut shares = self.threshold_shares.write().await;
+      let secret_package: dkg::round1::SecretPackage = match shares.remove(key_id) {
+        Some(ThresholdShare::Round1(secret_package)) => secret_package,
+        Some(share) => {
+          shares.insert(key_id.clone(), share);
+          return Err(unexpected_state());
+        }
+        None => return Err(KeyStorageError::new(KeyStorageErrorKind::KeyNotFound)),
+      };
+
+      let (secret_package, packages) = dkg::part2(secret_package, round1_packages).map_err(frost_error)?;
+      shares.insert(key_id.clone(), ThresholdShare::Round2(secret_package));
+
+      Ok(packages)
+    }
+
+    async fn dkg_part3(
+      &self,
+      key_id: &KeyId,
+      round1_packages: &BTreeMap<Identifier, dkg::round1::Package>,
+      round2_packages: &BTreeMap<Identifier, dkg::round2::Package>,
+    ) -> KeyStorageResult<PublicKeyPackage> {
+      let mut shares = self.threshold_shares.write().await;
+      let (key_package, public_key_package) = match shares.get(key_id) {
+        Some(ThresholdShare::Round2(secret_package)) => {
+          dkg::part3(secret_package, round1_packages, round2_packages).map_err(frost_error)?
+        }
+        Some(_) => return Err(unexpected_state()),
+        None => return Err(KeyStorageError::new(KeyStorageErrorKind::KeyNotFound)),
+      };
+      shares.insert(
+        key_id.clone(),
+        ThresholdShare::Complete {
+          key_package,
+          public_key_package: public_key_package.clone(),
+          nonces: None,
+        },
+      );
+
+      Ok(public_key_package)
+    }
+
+    async fn threshold_commit(&self, key_id: &KeyId) -> KeyStorageResult<(Identifier, SigningCommitments)> {
+      let mut shares = self.threshold_shares.write().await;
+      let Some(ThresholdShare::Complete {
+        key_package, nonces, ..
+      }) = shares.get_mut(key_id)
+      else {
+        return Err(KeyStorageError::new(KeyStorageErrorKind::KeyNotFound));
+      };
+
+      let (signing_nonces, commitments) =
+        frost_ed25519::round1::commit(key_package.signing_share(), &mut rand::thread_rng());
+      *nonces = Some(signing_nonces);
+
+      Ok((*key_package.identifier(), commitments))
+    }
+
+    async fn threshold_sign(
+      &self,
+      key_id: &KeyId,
+      signing_package: &SigningPackage,
+    ) -> KeyStorageResult<SignatureShare> {
+      let mut shares = self.threshold_shares.write().await;
+      let Some(ThresholdShare::Complete {
+        key_package, nonces, ..
+      }) = shares.get_mut(key_id)
+      else {
+        return Err(KeyStorageError::new(KeyStorageErrorKind::KeyNotFound));
+      };
+      // Taking the nonces guarantees they are never reused.
+      let signing_nonces: SigningNonces = nonces.take().ok_or_else(|| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message("no nonces have been committed to")
+      })?;
+
+      frost_ed25519::round2::sign(signing_package, &signing_nonces, key_package).map_err(frost_error)
+    }
+
+    async fn threshold_public_key_package(&self, key_id: &KeyId) -> KeyStorageResult<PublicKeyPackage> {
+      match self.threshold_shares.read().await.get(key_id) {
+        Some(ThresholdShare::Complete { public_key_package, .. }) => Ok(public_key_package.clone()),
+        _ => Err(KeyStorageError::new(KeyStorageErrorKind::KeyNotFound)),
+      }
+    }
+  }
+}
+
 #[cfg(feature = "jpt-bbs-plus")]
 mod bbs_plus_impl {
   use std::str::FromStr as _;
@@ -322,6 +677,7 @@ mod bbs_plus_impl {
   use async_trait::async_trait;
   use identity_verification::jwk::BlsCurve;
   use identity_verification::jwk::Jwk;
+  use identity_verification::jwk::SecretJwk;
   use jsonprooftoken::jpa::algs::ProofAlgorithm;
 
   use super::random_key_id;
@@ -343,7 +699,7 @@ mod bbs_plus_impl {
 
       let kid: KeyId = random_key_id();
       let mut jwk_store = self.jwk_store.write().await;
-      jwk_store.insert(kid.clone(), jwk);
+      jwk_store.insert(kid.clone(), SecretJwk::new(jwk).expect("generated keys are private"));
 
       Ok(JwkGenOutput::new(kid, public_jwk))
     }
@@ -356,28 +712,11 @@ mod bbs_plus_impl {
       public_key: &Jwk,
     ) -> KeyStorageResult<Vec<u8>> {
       let jwk_store = self.jwk_store.read().await;
-
-      // Extract the required alg from the given public key
-      let alg = public_key
-        .alg()
-        .and_then(|alg_str| ProofAlgorithm::from_str(alg_str).ok())
-        .ok_or(KeyStorageErrorKind::UnsupportedProofAlgorithm)?;
-
-      // Check the provided JWK represents a BLS12381G2 key.
-      if !public_key
-        .try_ec_params()
-        .map(|ec| ec.crv == BlsCurve::BLS12381G2.to_string())
-        .unwrap_or(false)
-      {
-        return Err(
-          KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
-            .with_custom_message(format!("expected a key from the {} curve", BlsCurve::BLS12381G2)),
-        );
-      }
+      let alg: ProofAlgorithm = bbs_proof_algorithm(public_key)?;
 
       // Obtain the corresponding private key.
-      let jwk: &Jwk = jwk_store.get(key_id).ok_or(KeyStorageErrorKind::KeyNotFound)?;
-      let (sk, pk) = expand_bls_jwk(jwk)?;
+      let jwk: &SecretJwk = jwk_store.get(key_id).ok_or(KeyStorageErrorKind::KeyNotFound)?;
+      let (sk, pk) = expand_bls_jwk(jwk.expose_secret())?;
 
       sign_bbs(alg, data, &sk.expect("jwk is private"), &pk, header)
     }
@@ -390,35 +729,86 @@ mod bbs_plus_impl {
       ctx: ProofUpdateCtx,
     ) -> KeyStorageResult<Vec<u8>> {
       let jwk_store = self.jwk_store.read().await;
-
-      // Extract the required alg from the given public key
-      let alg = public_key
-        .alg()
-        .ok_or(KeyStorageErrorKind::UnsupportedProofAlgorithm)
-        .and_then(|alg_str| {
-          ProofAlgorithm::from_str(alg_str).map_err(|_| KeyStorageErrorKind::UnsupportedProofAlgorithm)
-        })?;
-
-      // Check the provided JWK represents a BLS12381G2 key.
-      if !public_key
-        .try_ec_params()
-        .map(|ec| ec.crv == BlsCurve::BLS12381G2.to_string())
-        .unwrap_or(false)
-      {
-        return Err(
-          KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
-            .with_custom_message(format!("expected a key from the {} curve", BlsCurve::BLS12381G2)),
-        );
-      }
+      let alg: ProofAlgorithm = bbs_proof_algorithm(public_key)?;
 
       // Obtain the corresponding private key.
       let jwk = jwk_store.get(key_id).ok_or(KeyStorageErrorKind::KeyNotFound)?;
-      let sk = expand_bls_jwk(jwk)?.0.expect("jwk is private");
+      let sk = expand_bls_jwk(jwk.expose_secret())?.0.expect("jwk is private");
 
       // Update the signature.
       update_bbs_signature(alg, signature, &sk, &ctx)
     }
   }
+
+  /// Extracts the proof algorithm of the BBS+ `public_key`, ensuring it is a key from the BLS12381G2 curve.
+  pub(super) fn bbs_proof_algorithm(public_key: &Jwk) -> KeyStorageResult<ProofAlgorithm> {
+    // Extract the required alg from the given public key
+    let alg = public_key
+      .alg()
+      .and_then(|alg_str| ProofAlgorithm::from_str(alg_str).ok())
+      .ok_or(KeyStorageErrorKind::UnsupportedProofAlgorithm)?;
+
+    // Check the provided JWK represents a BLS12381G2 key.
+    if !public_key
+      .try_ec_params()
+      .map(|ec| ec.crv == BlsCurve::BLS12381G2.to_string())
+      .unwrap_or(false)
+    {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+          .with_custom_message(format!("expected a key from the {} curve", BlsCurve::BLS12381G2)),
+      );
+    }
+
+    Ok(alg)
+  }
+}
+
+#[cfg(feature = "bbs-plus-blind")]
+mod bbs_plus_blind_impl {
+  use crate::key_storage::bls::blind_sign_bbs;
+  use crate::key_storage::bls::expand_bls_jwk;
+  use crate::JwkMemStore;
+  use crate::JwkStorageBbsPlusBlindExt;
+  use crate::KeyId;
+  use crate::KeyStorageErrorKind;
+  use crate::KeyStorageResult;
+  use async_trait::async_trait;
+  use identity_verification::jwk::Jwk;
+  use identity_verification::jwk::SecretJwk;
+  use jsonprooftoken::jpa::algs::ProofAlgorithm;
+
+  use super::bbs_plus_impl::bbs_proof_algorithm;
+
+  /// JwkStorageBbsPlusBlindExt implementation for JwkMemStore
+  #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+  #[cfg_attr(feature = "send-sync-storage", async_trait)]
+  impl JwkStorageBbsPlusBlindExt for JwkMemStore {
+    async fn blind_sign_bbs(
+      &self,
+      key_id: &KeyId,
+      commitment_with_proof: &[u8],
+      data: &[Vec<u8>],
+      header: &[u8],
+      public_key: &Jwk,
+    ) -> KeyStorageResult<Vec<u8>> {
+      let jwk_store = self.jwk_store.read().await;
+      let alg: ProofAlgorithm = bbs_proof_algorithm(public_key)?;
+
+      // Obtain the corresponding private key.
+      let jwk: &SecretJwk = jwk_store.get(key_id).ok_or(KeyStorageErrorKind::KeyNotFound)?;
+      let (sk, pk) = expand_bls_jwk(jwk.expose_secret())?;
+
+      blind_sign_bbs(
+        alg,
+        commitment_with_proof,
+        data,
+        &sk.expect("jwk is private"),
+        &pk,
+        header,
+      )
+    }
+  }
 }
 pub(crate) mod shared {
   use core::fmt::Debug;
@@ -0,0 +1,94 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+
+use crypto::keys::bip39;
+use zeroize::Zeroizing;
+
+use super::KeyStorageError;
+use super::KeyStorageErrorKind;
+use super::KeyStorageResult;
+
+/// A [BIP39](https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki) mnemonic of the english wordlist, from
+/// which keys can be derived through a [`JwkStorageDerivationExt`](crate::key_storage::JwkStorageDerivationExt).
+///
+/// The phrase is zeroized when dropped and never exposed through `Debug`. The same mnemonic and passphrase can be
+/// imported into wallets following BIP39, such as the secret managers of the iota-sdk, to recover the keys derived
+/// from it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic(Zeroizing<String>);
+
+impl Mnemonic {
+  /// The number of bytes of entropy of generated mnemonics, encoded as 24 words.
+  pub const ENTROPY_LENGTH: usize = 32;
+
+  /// Generates a new mnemonic of 24 words from a secure source of randomness.
+  pub fn generate() -> KeyStorageResult<Self> {
+    let mut entropy: Zeroizing<[u8; Self::ENTROPY_LENGTH]> = Zeroizing::new([0; Self::ENTROPY_LENGTH]);
+    crypto::utils::rand::fill(entropy.as_mut()).map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message(format!("failed to generate entropy: {err:?}"))
+    })?;
+    let mnemonic: bip39::Mnemonic =
+      bip39::wordlist::encode(entropy.as_ref(), &bip39::wordlist::ENGLISH).map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message(format!("failed to encode mnemonic: {err:?}"))
+      })?;
+    let phrase: &str = &mnemonic;
+    Ok(Self(Zeroizing::new(phrase.to_owned())))
+  }
+
+  /// Imports an existing mnemonic, e.g. one restored from a paper backup.
+  ///
+  /// Fails if `phrase` is not a valid mnemonic of the english wordlist.
+  pub fn parse(phrase: impl Into<String>) -> KeyStorageResult<Self> {
+    let phrase: Zeroizing<String> = Zeroizing::new(phrase.into());
+    let mnemonic: bip39::Mnemonic = bip39::Mnemonic::from(String::clone(&phrase));
+    bip39::wordlist::verify(&mnemonic, &bip39::wordlist::ENGLISH).map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message(format!("invalid BIP39 mnemonic: {err:?}"))
+    })?;
+    Ok(Self(phrase))
+  }
+
+  /// Returns the words of the mnemonic, separated by spaces.
+  ///
+  /// Handle with care: anyone knowing the phrase can derive all keys of the wallet.
+  pub fn as_str(&self) -> &str {
+    self.0.as_str()
+  }
+
+  /// Returns the number of words of the mnemonic.
+  pub fn word_count(&self) -> usize {
+    self.0.split_whitespace().count()
+  }
+}
+
+impl Debug for Mnemonic {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str("Mnemonic(..)")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn generate_and_parse() {
+    let mnemonic: Mnemonic = Mnemonic::generate().unwrap();
+    assert_eq!(mnemonic.word_count(), 24);
+    assert_ne!(mnemonic, Mnemonic::generate().unwrap());
+    assert_eq!(Mnemonic::parse(mnemonic.as_str()).unwrap(), mnemonic);
+    assert_eq!(format!("{mnemonic:?}"), "Mnemonic(..)");
+
+    // INVALID: wrong checksum.
+    assert!(Mnemonic::parse(
+      "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon"
+    )
+    .is_err());
+    assert!(Mnemonic::parse("not a mnemonic").is_err());
+  }
+}
@@ -13,13 +13,31 @@ pub mod bls;
 mod ed25519;
 mod jwk_gen_output;
 mod jwk_storage;
+#[cfg(feature = "key-attestation")]
+mod jwk_storage_attestation_ext;
+#[cfg(feature = "bbs-plus-blind")]
+mod jwk_storage_bbs_plus_blind_ext;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jwk_storage_bbs_plus_ext;
+#[cfg(feature = "key-derivation")]
+mod jwk_storage_derivation_ext;
+mod jwk_storage_export_ext;
+#[cfg(feature = "threshold")]
+mod jwk_storage_threshold_ext;
+#[cfg(feature = "key-attestation")]
+pub mod key_attestation;
+#[cfg(feature = "key-derivation")]
+mod key_derivation_path;
 mod key_id;
 mod key_storage_error;
 mod key_type;
 #[cfg(feature = "memstore")]
 mod memstore;
+#[cfg(feature = "key-derivation")]
+mod mnemonic;
+mod scoped_jwk_storage;
+#[cfg(feature = "threshold")]
+mod threshold_signer;
 
 #[cfg(test)]
 pub(crate) mod tests;
@@ -28,13 +46,34 @@ pub(crate) mod tests;
 pub mod public_modules {
   pub use super::jwk_gen_output::*;
   pub use super::jwk_storage::*;
+  #[cfg(feature = "key-attestation")]
+  pub use super::jwk_storage_attestation_ext::*;
+  #[cfg(feature = "bbs-plus-blind")]
+  pub use super::jwk_storage_bbs_plus_blind_ext::*;
   #[cfg(feature = "jpt-bbs-plus")]
   pub use super::jwk_storage_bbs_plus_ext::*;
+  #[cfg(feature = "key-derivation")]
+  pub use super::jwk_storage_derivation_ext::*;
+  pub use super::jwk_storage_export_ext::*;
+  #[cfg(feature = "threshold")]
+  pub use super::jwk_storage_threshold_ext::*;
+  #[cfg(feature = "key-attestation")]
+  pub use super::key_attestation::*;
+  #[cfg(feature = "key-derivation")]
+  pub use super::key_derivation_path::*;
   pub use super::key_id::*;
   pub use super::key_storage_error::*;
   pub use super::key_type::*;
   #[cfg(feature = "memstore")]
   pub use super::memstore::*;
+  #[cfg(feature = "key-derivation")]
+  pub use super::mnemonic::*;
+  pub use super::scoped_jwk_storage::*;
+  #[cfg(feature = "threshold")]
+  pub use super::threshold_signer::*;
+  /// Re-export of the FROST implementation used for threshold signatures.
+  #[cfg(feature = "threshold")]
+  pub use frost_ed25519 as frost;
 }
 
 pub use public_modules::*;
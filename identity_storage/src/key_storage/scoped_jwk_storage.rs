@@ -0,0 +1,154 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::SecretJwk;
+use identity_verification::jose::jws::JwsAlgorithm;
+
+use super::JwkGenOutput;
+use super::JwkStorage;
+use super::JwkStorageExportExt;
+use super::KeyId;
+use super::KeyStorageError;
+use super::KeyStorageErrorKind;
+use super::KeyStorageResult;
+use super::KeyType;
+use crate::key_id_storage::Namespace;
+
+/// A [`JwkStorage`] handle restricted to the keys of a single [`Namespace`].
+///
+/// Key ids returned by this handle are prefixed with the namespace, i.e. they have the form
+/// `<namespace>:<key id of the wrapped storage>`. Keys of other namespaces cannot be addressed through this handle.
+///
+/// Typically obtained through [`Storage::scoped`](crate::storage::Storage::scoped).
+#[derive(Debug)]
+pub struct ScopedJwkStorage<'storage, K> {
+  storage: &'storage K,
+  namespace: Namespace,
+}
+
+impl<'storage, K> ScopedJwkStorage<'storage, K> {
+  /// Creates a new handle to the keys in `namespace` of `storage`.
+  pub fn new(storage: &'storage K, namespace: Namespace) -> Self {
+    Self { storage, namespace }
+  }
+
+  /// Returns the namespace this handle is restricted to.
+  pub fn namespace(&self) -> &Namespace {
+    &self.namespace
+  }
+
+  fn scope(&self, key_id: &KeyId) -> KeyId {
+    KeyId::new(format!("{}{}{key_id}", self.namespace, Namespace::SEPARATOR))
+  }
+
+  /// Returns the key id in the wrapped storage, or `None` if `key_id` belongs to another namespace.
+  fn unscope(&self, key_id: &KeyId) -> Option<KeyId> {
+    key_id
+      .as_str()
+      .strip_prefix(self.namespace.as_str())
+      .and_then(|rest| rest.strip_prefix(Namespace::SEPARATOR))
+      .map(KeyId::new)
+  }
+
+  fn unscope_or_not_found(&self, key_id: &KeyId) -> KeyStorageResult<KeyId> {
+    self.unscope(key_id).ok_or_else(|| {
+      KeyStorageError::new(KeyStorageErrorKind::KeyNotFound)
+        .with_custom_message(format!("key id is not part of namespace `{}`", self.namespace))
+    })
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K: JwkStorage> JwkStorage for ScopedJwkStorage<'_, K> {
+  async fn generate(&self, key_type: KeyType, alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    let output: JwkGenOutput = self.storage.generate(key_type, alg).await?;
+    Ok(JwkGenOutput::new(self.scope(&output.key_id), output.jwk))
+  }
+
+  async fn insert(&self, jwk: Jwk) -> KeyStorageResult<KeyId> {
+    let key_id: KeyId = self.storage.insert(jwk).await?;
+    Ok(self.scope(&key_id))
+  }
+
+  async fn sign(&self, key_id: &KeyId, data: &[u8], public_key: &Jwk) -> KeyStorageResult<Vec<u8>> {
+    let key_id: KeyId = self.unscope_or_not_found(key_id)?;
+    self.storage.sign(&key_id, data, public_key).await
+  }
+
+  async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
+    let key_id: KeyId = self.unscope_or_not_found(key_id)?;
+    self.storage.delete(&key_id).await
+  }
+
+  async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    match self.unscope(key_id) {
+      Some(key_id) => self.storage.exists(&key_id).await,
+      None => Ok(false),
+    }
+  }
+
+  async fn insert_many(&self, jwks: Vec<Jwk>) -> KeyStorageResult<Vec<KeyId>> {
+    let key_ids: Vec<KeyId> = self.storage.insert_many(jwks).await?;
+    Ok(key_ids.iter().map(|key_id| self.scope(key_id)).collect())
+  }
+
+  async fn sign_many(&self, requests: &[(&KeyId, &[u8], &Jwk)]) -> KeyStorageResult<Vec<Vec<u8>>> {
+    let key_ids: Vec<KeyId> = requests
+      .iter()
+      .map(|(key_id, _, _)| self.unscope_or_not_found(key_id))
+      .collect::<KeyStorageResult<_>>()?;
+    let requests: Vec<(&KeyId, &[u8], &Jwk)> = key_ids
+      .iter()
+      .zip(requests)
+      .map(|(key_id, (_, data, public_key))| (key_id, *data, *public_key))
+      .collect();
+    self.storage.sign_many(&requests).await
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K: JwkStorageExportExt> JwkStorageExportExt for ScopedJwkStorage<'_, K> {
+  async fn export(&self, key_id: &KeyId) -> KeyStorageResult<SecretJwk> {
+    let key_id: KeyId = self.unscope_or_not_found(key_id)?;
+    self.storage.export(&key_id).await
+  }
+}
+
+#[cfg(feature = "key-attestation")]
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K: super::JwkStorageAttestationExt> super::JwkStorageAttestationExt for ScopedJwkStorage<'_, K> {
+  async fn key_attestation(&self, key_id: &KeyId) -> KeyStorageResult<Option<super::KeyAttestation>> {
+    let key_id: KeyId = self.unscope_or_not_found(key_id)?;
+    self.storage.key_attestation(&key_id).await
+  }
+
+  async fn set_attestation_level(&self, key_id: &KeyId, level: super::KeyAttestationLevel) -> KeyStorageResult<()> {
+    let key_id: KeyId = self.unscope_or_not_found(key_id)?;
+    self.storage.set_attestation_level(&key_id, level).await
+  }
+
+  async fn attestation_level(&self, key_id: &KeyId) -> KeyStorageResult<Option<super::KeyAttestationLevel>> {
+    let key_id: KeyId = self.unscope_or_not_found(key_id)?;
+    self.storage.attestation_level(&key_id).await
+  }
+}
+
+#[cfg(feature = "key-derivation")]
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K: super::JwkStorageDerivationExt> super::JwkStorageDerivationExt for ScopedJwkStorage<'_, K> {
+  async fn derive(
+    &self,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+    path: &super::KeyDerivationPath,
+  ) -> KeyStorageResult<JwkGenOutput> {
+    let output: JwkGenOutput = self.storage.derive(key_type, alg, path).await?;
+    Ok(JwkGenOutput::new(self.scope(&output.key_id), output.jwk))
+  }
+}
@@ -6,6 +6,7 @@ use super::utils::test_incompatible_key_alg;
 use super::utils::test_incompatible_key_type;
 use super::utils::test_insertion;
 use super::utils::test_key_exists;
+use super::utils::test_sign_many;
 use crate::key_storage::JwkMemStore;
 
 #[tokio::test]
@@ -37,3 +38,51 @@ async fn key_exists() {
   let store: JwkMemStore = JwkMemStore::new();
   test_key_exists(store).await;
 }
+
+#[tokio::test]
+async fn sign_many() {
+  let store: JwkMemStore = JwkMemStore::new();
+  test_sign_many(store).await;
+}
+
+#[cfg(feature = "bbs-plus-blind")]
+#[tokio::test]
+async fn blind_sign_bbs() {
+  use crate::key_storage::bls::commit_bbs;
+  use crate::key_storage::bls::expand_bls_jwk;
+  use crate::key_storage::bls::verify_blind_bbs_signature;
+  use crate::key_storage::bls::BbsCommitment;
+  use crate::JwkGenOutput;
+  use crate::JwkStorageBbsPlusBlindExt;
+  use crate::JwkStorageBbsPlusExt;
+  use jsonprooftoken::jpa::algs::ProofAlgorithm;
+
+  let store: JwkMemStore = JwkMemStore::new();
+  let alg: ProofAlgorithm = ProofAlgorithm::BLS12381_SHA256;
+  let JwkGenOutput { key_id, jwk } = store.generate_bbs(JwkMemStore::BLS12381G2_KEY_TYPE, alg).await.unwrap();
+  let (_, public_key) = expand_bls_jwk(&jwk).unwrap();
+
+  // The holder commits to a secret that is never revealed to the issuer.
+  let holder_secret: Vec<Vec<u8>> = vec![b"holder secret".to_vec()];
+  let commitment: BbsCommitment = commit_bbs(alg, &holder_secret).unwrap();
+
+  let header: &[u8] = b"header";
+  let data: Vec<Vec<u8>> = vec![b"name".to_vec(), b"degree".to_vec()];
+  let signature: Vec<u8> = store
+    .blind_sign_bbs(&key_id, &commitment.commitment_with_proof, &data, header, &jwk)
+    .await
+    .unwrap();
+
+  verify_blind_bbs_signature(alg, &signature, &public_key, header, &data, &holder_secret, &commitment).unwrap();
+  let other_secret: Vec<Vec<u8>> = vec![b"other secret".to_vec()];
+  assert!(verify_blind_bbs_signature(alg, &signature, &public_key, header, &data, &other_secret, &commitment).is_err());
+
+  // A tampered commitment is rejected by the issuer.
+  let mut tampered: Vec<u8> = commitment.commitment_with_proof.clone();
+  let last: &mut u8 = tampered.last_mut().unwrap();
+  *last ^= 1;
+  assert!(store
+    .blind_sign_bbs(&key_id, &tampered, &data, header, &jwk)
+    .await
+    .is_err());
+}
@@ -73,6 +73,38 @@ pub(crate) async fn test_generate_and_sign(store: impl JwkStorage) {
   store.delete(&key_id).await.unwrap();
 }
 
+pub(crate) async fn test_sign_many(store: impl JwkStorage) {
+  let messages: [&[u8]; 3] = [b"first", b"second", b"third"];
+
+  let mut outputs = Vec::new();
+  for _ in 0..2 {
+    outputs.push(
+      store
+        .generate(KeyType::new("Ed25519"), JwsAlgorithm::EdDSA)
+        .await
+        .unwrap(),
+    );
+  }
+  let requests: Vec<(&KeyId, &[u8], &Jwk)> = messages
+    .iter()
+    .zip(outputs.iter().cycle())
+    .map(|(msg, output)| (&output.key_id, *msg, &output.jwk))
+    .collect();
+
+  let signatures: Vec<Vec<u8>> = store.sign_many(&requests).await.unwrap();
+  assert_eq!(signatures.len(), messages.len());
+  for ((key_id, msg, jwk), signature) in requests.into_iter().zip(signatures) {
+    assert_eq!(signature, store.sign(key_id, msg, jwk).await.unwrap());
+  }
+
+  let (private_key, public_key) = generate_ed25519();
+  let mut jwk: Jwk = crate::key_storage::ed25519::encode_jwk(&private_key, &public_key);
+  jwk.set_alg(JwsAlgorithm::EdDSA.name());
+  let key_ids: Vec<KeyId> = store.insert_many(vec![jwk.clone(), jwk]).await.unwrap();
+  assert_eq!(key_ids.len(), 2);
+  assert_ne!(key_ids[0], key_ids[1]);
+}
+
 pub(crate) async fn test_key_exists(store: impl JwkStorage) {
   assert!(!store.exists(&KeyId::new("non-existent-id")).await.unwrap());
 }
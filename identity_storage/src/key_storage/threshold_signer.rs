@@ -0,0 +1,309 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use frost_ed25519::keys::dkg;
+use frost_ed25519::keys::PublicKeyPackage;
+use frost_ed25519::round1::SigningCommitments;
+use frost_ed25519::round2::SignatureShare;
+use frost_ed25519::Identifier;
+use frost_ed25519::Signature;
+use frost_ed25519::SigningPackage;
+use identity_verification::jose::jwk::EdCurve;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkParamsOkp;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jwu;
+use rand::distributions::DistString;
+
+use super::JwkGenOutput;
+use super::JwkStorage;
+use super::JwkStorageThresholdExt;
+use super::KeyId;
+use super::KeyStorageError;
+use super::KeyStorageErrorKind;
+use super::KeyStorageResult;
+use super::KeyType;
+
+/// A [`JwkStorage`] producing Ed25519 signatures jointly with several participants, any `min_signers` of which are
+/// required to sign, using [FROST](https://datatracker.ietf.org/doc/html/rfc9591).
+///
+/// Keys are generated through a distributed key generation, so the full private key never exists on any participant
+/// or on the coordinating [`ThresholdSigner`]. Signatures are regular `EdDSA` signatures verifiable with the shared
+/// public key. Since the signer implements [`JwkStorage`], it can be used as the key storage of a
+/// [`Storage`](crate::Storage), e.g. to create JWS through
+/// [`JwkDocumentExt::create_jws`](crate::JwkDocumentExt::create_jws).
+///
+/// The signer merely routes packages between the participants. If participants run on other machines, the
+/// [`JwkStorageThresholdExt`] implementation used to reach them must protect the confidentiality of the second round
+/// packages of the key generation, which carry secret shares, e.g. by encrypting them to their recipient.
+///
+/// Existing private keys cannot be [inserted](JwkStorage::insert) into a [`ThresholdSigner`].
+#[derive(Debug)]
+pub struct ThresholdSigner<K> {
+  participants: Vec<K>,
+  min_signers: u16,
+}
+
+impl<K> ThresholdSigner<K> {
+  const ED25519_KEY_TYPE_STR: &'static str = "Ed25519";
+  /// The Ed25519 key type.
+  pub const ED25519_KEY_TYPE: KeyType = KeyType::from_static_str(Self::ED25519_KEY_TYPE_STR);
+
+  /// Creates a new [`ThresholdSigner`] for keys shared by the given `participants`, `min_signers` of which are
+  /// required to produce a signature.
+  ///
+  /// Fails if `min_signers` is smaller than 2 or larger than the number of participants.
+  pub fn new(participants: Vec<K>, min_signers: u16) -> KeyStorageResult<Self> {
+    let max_signers: usize = participants.len();
+    if min_signers < 2 || usize::from(min_signers) > max_signers || max_signers > usize::from(u16::MAX) {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message(format!(
+          "invalid threshold of {min_signers} out of {max_signers} participants"
+        )),
+      );
+    }
+
+    Ok(Self {
+      participants,
+      min_signers,
+    })
+  }
+
+  /// Returns the participants holding the key shares.
+  pub fn participants(&self) -> &[K] {
+    &self.participants
+  }
+
+  /// Returns the number of participants required to produce a signature.
+  pub fn min_signers(&self) -> u16 {
+    self.min_signers
+  }
+
+  /// Returns the participants together with the identifiers assigned to them during key generation.
+  fn identified_participants(&self) -> impl Iterator<Item = (Identifier, &K)> {
+    self.participants.iter().enumerate().map(|(index, participant)| {
+      // `new` ensures there are at most `u16::MAX` participants, so identifiers are in range and non-zero.
+      let identifier: Identifier = Identifier::try_from(index as u16 + 1).expect("identifier is non-zero");
+      (identifier, participant)
+    })
+  }
+}
+
+impl<K: JwkStorageThresholdExt> ThresholdSigner<K> {
+  /// Runs the distributed key generation between all participants, storing the shares under `key_id`.
+  async fn run_dkg(&self, key_id: &KeyId) -> KeyStorageResult<PublicKeyPackage> {
+    let max_signers: u16 = self.participants.len() as u16;
+
+    let mut round1_packages: BTreeMap<Identifier, dkg::round1::Package> = BTreeMap::new();
+    for (identifier, participant) in self.identified_participants() {
+      let package: dkg::round1::Package = participant
+        .dkg_part1(key_id, identifier, max_signers, self.min_signers)
+        .await?;
+      round1_packages.insert(identifier, package);
+    }
+
+    // Second round packages indexed by their recipient and then by their sender.
+    let mut round2_packages: BTreeMap<Identifier, BTreeMap<Identifier, dkg::round2::Package>> = BTreeMap::new();
+    for (identifier, participant) in self.identified_participants() {
+      let packages: BTreeMap<Identifier, dkg::round2::Package> = participant
+        .dkg_part2(key_id, &others(&round1_packages, identifier))
+        .await?;
+      for (recipient, package) in packages {
+        round2_packages
+          .entry(recipient)
+          .or_default()
+          .insert(identifier, package);
+      }
+    }
+
+    let mut public_key_package: Option<PublicKeyPackage> = None;
+    for (identifier, participant) in self.identified_participants() {
+      let package: PublicKeyPackage = participant
+        .dkg_part3(
+          key_id,
+          &others(&round1_packages, identifier),
+          &round2_packages.remove(&identifier).unwrap_or_default(),
+        )
+        .await?;
+      match &public_key_package {
+        Some(expected) if expected != &package => {
+          return Err(
+            KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+              .with_custom_message("participants derived different public keys"),
+          );
+        }
+        Some(_) => (),
+        None => public_key_package = Some(package),
+      }
+    }
+
+    Ok(public_key_package.expect("there are at least two participants"))
+  }
+
+  /// Removes the shares and key generation state stored under `key_id` from all participants, returning whether any
+  /// participant held one.
+  async fn delete_shares(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    let mut deleted: bool = false;
+    for participant in self.participants.iter() {
+      match participant.delete(key_id).await {
+        Ok(()) => deleted = true,
+        Err(err) if matches!(err.kind(), KeyStorageErrorKind::KeyNotFound) => (),
+        Err(err) => return Err(err),
+      }
+    }
+    Ok(deleted)
+  }
+
+  /// Returns the first `min_signers` participants holding a share of the key identified by `key_id`.
+  async fn signers(&self, key_id: &KeyId) -> KeyStorageResult<Vec<&K>> {
+    let mut signers: Vec<&K> = Vec::with_capacity(self.min_signers.into());
+    for participant in self.participants.iter() {
+      if signers.len() == usize::from(self.min_signers) {
+        break;
+      }
+      if participant.exists(key_id).await? {
+        signers.push(participant);
+      }
+    }
+    Ok(signers)
+  }
+}
+
+/// Returns the packages of all participants except `identifier`.
+fn others<P: Clone>(packages: &BTreeMap<Identifier, P>, identifier: Identifier) -> BTreeMap<Identifier, P> {
+  packages
+    .iter()
+    .filter(|(other, _)| **other != identifier)
+    .map(|(other, package)| (*other, package.clone()))
+    .collect()
+}
+
+fn frost_error(message: &'static str) -> impl FnOnce(frost_ed25519::Error) -> KeyStorageError {
+  move |err| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_custom_message(message)
+      .with_source(err)
+  }
+}
+
+fn encode_public_jwk(public_key_package: &PublicKeyPackage) -> KeyStorageResult<Jwk> {
+  let public_key: Vec<u8> = public_key_package
+    .verifying_key()
+    .serialize()
+    .map_err(frost_error("unable to serialize the shared public key"))?;
+
+  let mut params: JwkParamsOkp = JwkParamsOkp::new();
+  params.x = jwu::encode_b64(public_key);
+  params.crv = EdCurve::Ed25519.name().to_string();
+  let mut jwk: Jwk = Jwk::from_params(params);
+  jwk.set_alg(JwsAlgorithm::EdDSA.name());
+  jwk.set_kid(jwk.thumbprint_sha256_b64());
+
+  Ok(jwk)
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K: JwkStorageThresholdExt> JwkStorage for ThresholdSigner<K> {
+  async fn generate(&self, key_type: KeyType, alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    if key_type != Self::ED25519_KEY_TYPE {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+          .with_custom_message(format!("{key_type} is not supported")),
+      );
+    }
+    if alg != JwsAlgorithm::EdDSA {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::KeyAlgorithmMismatch)
+          .with_custom_message(format!("cannot use key type `{key_type}` with algorithm `{alg}`")),
+      );
+    }
+
+    let key_id: KeyId = KeyId::new(rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 32));
+
+    let public_key_package: PublicKeyPackage = match self.run_dkg(&key_id).await {
+      Ok(package) => package,
+      Err(err) => {
+        // Do not leave partial key generation state behind.
+        let _ = self.delete_shares(&key_id).await;
+        return Err(err);
+      }
+    };
+
+    Ok(JwkGenOutput::new(key_id, encode_public_jwk(&public_key_package)?))
+  }
+
+  async fn insert(&self, _jwk: Jwk) -> KeyStorageResult<KeyId> {
+    Err(
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("private keys cannot be inserted into a threshold signer"),
+    )
+  }
+
+  async fn sign(&self, key_id: &KeyId, data: &[u8], public_key: &Jwk) -> KeyStorageResult<Vec<u8>> {
+    if public_key.alg() != Some(JwsAlgorithm::EdDSA.name()) {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::UnsupportedSignatureAlgorithm)
+          .with_custom_message(format!("expected a Jwk with alg {}", JwsAlgorithm::EdDSA)),
+      );
+    }
+
+    let signers: Vec<&K> = self.signers(key_id).await?;
+    if signers.len() < usize::from(self.min_signers) {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::KeyNotFound).with_custom_message(format!(
+          "only {} of the {} required participants hold a share of the key",
+          signers.len(),
+          self.min_signers
+        )),
+      );
+    }
+
+    let public_key_package: PublicKeyPackage = signers[0].threshold_public_key_package(key_id).await?;
+    if encode_public_jwk(&public_key_package)?
+      .try_okp_params()
+      .map(|params| &params.x)
+      != public_key.try_okp_params().map(|params| &params.x)
+    {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::KeyAlgorithmMismatch)
+          .with_custom_message("the given public key does not match the shared key"),
+      );
+    }
+
+    let mut commitments: BTreeMap<Identifier, SigningCommitments> = BTreeMap::new();
+    let mut committed_signers: Vec<(Identifier, &K)> = Vec::with_capacity(signers.len());
+    for signer in signers {
+      let (identifier, commitment) = signer.threshold_commit(key_id).await?;
+      commitments.insert(identifier, commitment);
+      committed_signers.push((identifier, signer));
+    }
+    let signing_package: SigningPackage = SigningPackage::new(commitments, data);
+
+    let mut signature_shares: BTreeMap<Identifier, SignatureShare> = BTreeMap::new();
+    for (identifier, signer) in committed_signers {
+      signature_shares.insert(identifier, signer.threshold_sign(key_id, &signing_package).await?);
+    }
+
+    let signature: Signature = frost_ed25519::aggregate(&signing_package, &signature_shares, &public_key_package)
+      .map_err(frost_error("unable to aggregate the signature shares"))?;
+    signature
+      .serialize()
+      .map_err(frost_error("unable to serialize the signature"))
+  }
+
+  async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
+    if self.delete_shares(key_id).await? {
+      Ok(())
+    } else {
+      Err(KeyStorageError::new(KeyStorageErrorKind::KeyNotFound))
+    }
+  }
+
+  async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    Ok(self.signers(key_id).await?.len() == usize::from(self.min_signers))
+  }
+}
@@ -17,7 +17,11 @@
 pub mod key_id_storage;
 pub mod key_storage;
 pub mod storage;
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
 
 pub use key_id_storage::*;
 pub use key_storage::public_modules::*;
 pub use storage::*;
+#[cfg(feature = "telemetry")]
+pub use telemetry::*;
@@ -0,0 +1,345 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::hash::Hasher;
+
+use async_trait::async_trait;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_credential::credential::Jwt;
+use identity_did::CoreDID;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jwu;
+use seahash::SeaHasher;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::presentation_response::credential_claims;
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError as Error;
+use super::JwsSignatureOptions;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_storage::JwkStorage;
+
+/// The `typ` of the protected header of signed consent receipts.
+pub const CONSENT_RECEIPT_JWS_TYPE: &str = "consent-receipt+jwt";
+
+/// A credential shared by a holder, as recorded in a [`ConsentReceipt`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedCredential {
+  /// The types of the credential.
+  pub types: Vec<String>,
+  /// The issuer of the credential, if it could be read.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub issuer: Option<String>,
+  /// The names of the claims about the subject that were shared, including selectively disclosed claims.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub claims: Vec<String>,
+}
+
+impl SharedCredential {
+  /// Describes the credential in `jwt`, which may also be an SD-JWT with its selected disclosures, without
+  /// verifying its signature.
+  ///
+  /// Returns `None` if the credential cannot be decoded.
+  pub fn from_jwt(jwt: &Jwt) -> Option<Self> {
+    let claims: Value = credential_claims(jwt)?;
+    let vc: &Value = claims.get("vc")?;
+
+    let types: Vec<String> = match vc.get("type") {
+      Some(Value::String(type_)) => vec![type_.clone()],
+      Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).map(ToOwned::to_owned).collect(),
+      _ => Vec::new(),
+    };
+    let issuer: Option<String> = claims
+      .get("iss")
+      .or_else(|| vc.get("issuer"))
+      .and_then(|issuer| issuer.as_str().or_else(|| issuer.get("id")?.as_str()))
+      .map(ToOwned::to_owned);
+
+    let mut shared_claims: Vec<String> = vc
+      .get("credentialSubject")
+      .and_then(Value::as_object)
+      .into_iter()
+      .flat_map(|subject| subject.keys())
+      .filter(|name| !matches!(name.as_str(), "id" | "_sd" | "_sd_alg"))
+      .cloned()
+      .collect();
+    // The disclosures of an SD-JWT are encoded as `[salt, claim name, claim value]`.
+    shared_claims.extend(jwt.as_str().split('~').skip(1).filter_map(|disclosure| {
+      let decoded: Vec<u8> = jwu::decode_b64(disclosure).ok()?;
+      match serde_json::from_slice::<Value>(&decoded).ok()? {
+        Value::Array(elements) if elements.len() == 3 => elements[1].as_str().map(ToOwned::to_owned),
+        _ => None,
+      }
+    }));
+    shared_claims.sort();
+    shared_claims.dedup();
+
+    Some(Self {
+      types,
+      issuer,
+      claims: shared_claims,
+    })
+  }
+}
+
+/// A record of a holder's consent to share credentials with a recipient for a stated purpose.
+///
+/// Receipts are created when presenting credentials with
+/// [`PresentationResponseBuilder::build_with_consent_receipt`](crate::PresentationResponseBuilder::build_with_consent_receipt),
+/// signed by the holder and kept in a [`ConsentReceiptStorage`], so that a wallet can account for what it shared.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsentReceipt {
+  id: String,
+  holder: CoreDID,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  recipient: Option<Url>,
+  purpose: String,
+  shared: Vec<SharedCredential>,
+  timestamp: Timestamp,
+}
+
+impl ConsentReceipt {
+  /// Creates a new [`ConsentReceipt`] recording that `holder` shared `credentials` with `recipient` for `purpose`.
+  ///
+  /// The identifier of the receipt is derived from its contents.
+  pub fn new(
+    holder: CoreDID,
+    recipient: Option<Url>,
+    purpose: impl Into<String>,
+    credentials: &[Jwt],
+    timestamp: Timestamp,
+  ) -> Self {
+    let purpose: String = purpose.into();
+    let shared: Vec<SharedCredential> = credentials
+      .iter()
+      .map(|credential| SharedCredential::from_jwt(credential).unwrap_or_default())
+      .collect();
+
+    let mut hasher: SeaHasher = SeaHasher::new();
+    hasher.write(holder.as_str().as_bytes());
+    hasher.write(
+      recipient
+        .as_ref()
+        .map(|url| url.as_str())
+        .unwrap_or_default()
+        .as_bytes(),
+    );
+    hasher.write(purpose.as_bytes());
+    credentials
+      .iter()
+      .for_each(|credential| hasher.write(credential.as_str().as_bytes()));
+    hasher.write(timestamp.to_rfc3339().as_bytes());
+    let id: String = format!("urn:consent-receipt:{:016x}", hasher.finish());
+
+    Self {
+      id,
+      holder,
+      recipient,
+      purpose,
+      shared,
+      timestamp,
+    }
+  }
+
+  /// Returns the identifier of the receipt.
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// Returns the DID of the holder who shared the credentials.
+  pub fn holder(&self) -> &CoreDID {
+    &self.holder
+  }
+
+  /// Returns the recipient the credentials were shared with, if known.
+  pub fn recipient(&self) -> Option<&Url> {
+    self.recipient.as_ref()
+  }
+
+  /// Returns the purpose the holder consented to.
+  pub fn purpose(&self) -> &str {
+    &self.purpose
+  }
+
+  /// Returns the credentials that were shared.
+  pub fn shared(&self) -> &[SharedCredential] {
+    &self.shared
+  }
+
+  /// Returns the time the credentials were shared.
+  pub fn timestamp(&self) -> Timestamp {
+    self.timestamp
+  }
+
+  /// Signs the receipt with the verification method of `document` identified by `fragment`.
+  pub async fn sign<D, K, I>(
+    self,
+    document: &D,
+    storage: &Storage<K, I>,
+    fragment: &str,
+  ) -> StorageResult<SignedConsentReceipt>
+  where
+    D: JwkDocumentExt,
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let payload: Vec<u8> = serde_json::to_vec(&self)
+      .map_err(|err| Error::ConsentReceiptError("could not serialize receipt", Some(Box::new(err))))?;
+    let options: JwsSignatureOptions = JwsSignatureOptions::new().typ(CONSENT_RECEIPT_JWS_TYPE);
+    let jws = document.create_jws(storage, fragment, &payload, &options).await?;
+
+    Ok(SignedConsentReceipt {
+      receipt: self,
+      jws: jws.as_str().to_owned(),
+    })
+  }
+}
+
+/// A [`ConsentReceipt`] together with the JWS of the holder over it.
+///
+/// Signed receipts can be exported and imported as JSON through [`ToJson`](identity_core::convert::ToJson) and
+/// [`FromJson`](identity_core::convert::FromJson), e.g. to hand them over in response to a data subject access
+/// request.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedConsentReceipt {
+  receipt: ConsentReceipt,
+  jws: String,
+}
+
+impl SignedConsentReceipt {
+  /// Returns the receipt.
+  pub fn receipt(&self) -> &ConsentReceipt {
+    &self.receipt
+  }
+
+  /// Returns the JWS of the holder over the receipt.
+  pub fn jws(&self) -> &str {
+    &self.jws
+  }
+
+  /// Verifies that the JWS was created by the holder described by `document` and covers the receipt.
+  pub fn verify<T: JwsVerifier>(&self, document: &CoreDocument, verifier: &T) -> StorageResult<()> {
+    if document.id() != self.receipt.holder() {
+      return Err(Error::ConsentReceiptError("the document is not the holder's", None));
+    }
+    let decoded = document
+      .verify_jws(&self.jws, None, verifier, &JwsVerificationOptions::default())
+      .map_err(|err| Error::ConsentReceiptError("invalid signature", Some(Box::new(err))))?;
+    let signed: ConsentReceipt = serde_json::from_slice(&decoded.claims)
+      .map_err(|err| Error::ConsentReceiptError("could not deserialize receipt", Some(Box::new(err))))?;
+    if signed != self.receipt {
+      return Err(Error::ConsentReceiptError(
+        "the signature does not cover the receipt",
+        None,
+      ));
+    }
+    Ok(())
+  }
+}
+
+/// Storage for the [`SignedConsentReceipt`]s of a wallet.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait ConsentReceiptStorage: storage_sub_trait::StorageSendSyncMaybe {
+  /// Stores `receipt`.
+  ///
+  /// If a receipt with the same identifier already exists an error must be returned.
+  async fn insert_receipt(&self, receipt: SignedConsentReceipt) -> StorageResult<()>;
+
+  /// Returns the receipt with the given identifier, if any.
+  async fn get_receipt(&self, id: &str) -> StorageResult<Option<SignedConsentReceipt>>;
+
+  /// Returns all stored receipts, oldest first.
+  async fn list_receipts(&self) -> StorageResult<Vec<SignedConsentReceipt>>;
+}
+
+#[cfg(not(feature = "send-sync-storage"))]
+mod storage_sub_trait {
+  pub trait StorageSendSyncMaybe {}
+  impl<S: super::ConsentReceiptStorage> StorageSendSyncMaybe for S {}
+}
+
+#[cfg(feature = "send-sync-storage")]
+mod storage_sub_trait {
+  pub trait StorageSendSyncMaybe: Send + Sync {}
+  impl<S: Send + Sync + super::ConsentReceiptStorage> StorageSendSyncMaybe for S {}
+}
+
+#[cfg(feature = "memstore")]
+pub use self::memstore::ConsentReceiptMemstore;
+
+#[cfg(feature = "memstore")]
+mod memstore {
+  use async_trait::async_trait;
+
+  use super::ConsentReceiptStorage;
+  use super::SignedConsentReceipt;
+  use crate::key_storage::shared::Shared;
+  use crate::storage::JwkStorageDocumentError as Error;
+  use crate::storage::StorageResult;
+
+  /// An insecure, in-memory [`ConsentReceiptStorage`] implementation that serves as an example and may be used in
+  /// tests.
+  #[derive(Debug, Default)]
+  pub struct ConsentReceiptMemstore {
+    receipts: Shared<Vec<SignedConsentReceipt>>,
+  }
+
+  impl ConsentReceiptMemstore {
+    /// Creates a new, empty `ConsentReceiptMemstore` instance.
+    pub fn new() -> Self {
+      Self::default()
+    }
+
+    /// Returns the number of receipts contained in the [`ConsentReceiptMemstore`].
+    pub async fn count(&self) -> usize {
+      self.receipts.read().await.len()
+    }
+  }
+
+  #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+  #[cfg_attr(feature = "send-sync-storage", async_trait)]
+  impl ConsentReceiptStorage for ConsentReceiptMemstore {
+    async fn insert_receipt(&self, receipt: SignedConsentReceipt) -> StorageResult<()> {
+      let mut receipts = self.receipts.write().await;
+      if receipts
+        .iter()
+        .any(|stored| stored.receipt().id() == receipt.receipt().id())
+      {
+        return Err(Error::ConsentReceiptError(
+          "a receipt with this id already exists",
+          None,
+        ));
+      }
+      receipts.push(receipt);
+      Ok(())
+    }
+
+    async fn get_receipt(&self, id: &str) -> StorageResult<Option<SignedConsentReceipt>> {
+      Ok(
+        self
+          .receipts
+          .read()
+          .await
+          .iter()
+          .find(|stored| stored.receipt().id() == id)
+          .cloned(),
+      )
+    }
+
+    async fn list_receipts(&self) -> StorageResult<Vec<SignedConsentReceipt>> {
+      Ok(self.receipts.read().await.clone())
+    }
+  }
+}
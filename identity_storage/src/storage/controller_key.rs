@@ -0,0 +1,113 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::blake2b::Blake2b256;
+use crypto::hashes::Digest;
+use identity_iota_core::block::address::Address;
+use identity_iota_core::block::address::Ed25519Address;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkParamsOkp;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jwu;
+
+use super::JwkStorageDocumentError as Error;
+use super::Storage;
+use super::StorageResult;
+use crate::key_storage::JwkGenOutput;
+use crate::key_storage::JwkStorageDerivationExt;
+use crate::key_storage::KeyDerivationPath;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyStorageResult;
+use crate::key_storage::KeyType;
+
+/// An Ed25519 key derived from the seed of a [`JwkStorageDerivationExt`], whose address can control the Alias Outputs
+/// of IOTA DIDs, e.g. as their state controller or governor.
+///
+/// Controller keys are derived at [BIP44](https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki) paths, see
+/// [`ControllerKey::derivation_path`], so a wallet importing the same mnemonic and passphrase, such as the secret
+/// managers of the iota-sdk, derives the same address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControllerKey {
+  key_id: KeyId,
+  path: KeyDerivationPath,
+  public_key: Jwk,
+  address: Ed25519Address,
+}
+
+impl ControllerKey {
+  /// The [SLIP-44](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) coin type of IOTA.
+  pub const IOTA_COIN_TYPE: u32 = 4218;
+  /// The [SLIP-44](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) coin type of Shimmer.
+  pub const SHIMMER_COIN_TYPE: u32 = 4219;
+
+  /// Returns the path `m/44'/{coin_type}'/{account}'/0'/{address_index}'` of a controller key, as used by IOTA
+  /// wallets.
+  pub fn derivation_path(coin_type: u32, account: u32, address_index: u32) -> KeyStorageResult<KeyDerivationPath> {
+    KeyDerivationPath::new([44, coin_type, account, 0, address_index])
+  }
+
+  /// Returns the identifier of the key in the key storage.
+  ///
+  /// The key id is [`KeyDerivationPath::to_key_id`] of the path the key was derived at.
+  pub fn key_id(&self) -> &KeyId {
+    &self.key_id
+  }
+
+  /// Returns the path the key was derived at.
+  pub fn path(&self) -> &KeyDerivationPath {
+    &self.path
+  }
+
+  /// Returns the public key as a JWK.
+  pub fn public_key(&self) -> &Jwk {
+    &self.public_key
+  }
+
+  /// Returns the address controlled by the key.
+  pub fn address(&self) -> Address {
+    Address::Ed25519(self.address)
+  }
+
+  /// Returns the Ed25519 address controlled by the key, i.e. the BLAKE2b-256 hash of its public key.
+  pub fn ed25519_address(&self) -> &Ed25519Address {
+    &self.address
+  }
+}
+
+impl<K, I> Storage<K, I>
+where
+  K: JwkStorageDerivationExt,
+{
+  /// The key type of controller keys.
+  const CONTROLLER_KEY_TYPE: KeyType = KeyType::from_static_str("Ed25519");
+
+  /// Derives the Ed25519 controller key at `path` from the seed of the key storage, e.g. one stored from a
+  /// [`Mnemonic`](crate::key_storage::Mnemonic), and returns it together with the address it controls.
+  ///
+  /// Deriving at the same path again returns the same key, so the key can be recovered from the mnemonic alone. The
+  /// key is stored under [`KeyDerivationPath::to_key_id`] and can be removed through the key storage like any other
+  /// key.
+  pub async fn derive_controller_key(&self, path: &KeyDerivationPath) -> StorageResult<ControllerKey> {
+    let JwkGenOutput { key_id, jwk } = self
+      .key_storage()
+      .derive(Self::CONTROLLER_KEY_TYPE, JwsAlgorithm::EdDSA, path)
+      .await
+      .map_err(Error::KeyStorageError)?;
+
+    let params: &JwkParamsOkp = jwk
+      .try_okp_params()
+      .map_err(|_| Error::KeyDerivationError("expected an Ed25519 public key"))?;
+    let public_key: [u8; 32] = jwu::decode_b64(params.x.as_str())
+      .ok()
+      .and_then(|bytes| bytes.try_into().ok())
+      .ok_or(Error::KeyDerivationError("expected an Ed25519 public key"))?;
+    let address: Ed25519Address = Ed25519Address::new(Blake2b256::digest(public_key).into());
+
+    Ok(ControllerKey {
+      key_id,
+      path: path.clone(),
+      public_key: jwk,
+      address,
+    })
+  }
+}
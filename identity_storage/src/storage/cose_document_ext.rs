@@ -0,0 +1,117 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_credential::credential::Credential;
+use identity_credential::iot::CoseSign1;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::MethodData;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+use serde::Serialize;
+
+use super::JwkStorageDocumentError as Error;
+use crate::JwkStorage;
+use crate::KeyIdStorage;
+use crate::MethodDigest;
+use crate::Storage;
+use crate::StorageResult;
+
+/// Extension of [`CoreDocument`] and `IotaDocument` issuing credentials of the compact IoT profile, i.e. encoded as
+/// CBOR and secured with [`CoseSign1`], signed with the keys of their verification methods held in a [`Storage`].
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait CoseDocumentExt {
+  /// Encodes `credential` as CBOR and secures it with a [`CoseSign1`] signed with the key of the assertion method
+  /// identified by `fragment`.
+  ///
+  /// The `kid` of the COSE_Sign1 is the `id` of the method, whose JWK must have an `alg` of `EdDSA` or `ES256`.
+  async fn create_cose_credential<K, I, T>(
+    &self,
+    credential: &Credential<T>,
+    storage: &Storage<K, I>,
+    fragment: &str,
+  ) -> StorageResult<Vec<u8>>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: Serialize + Sync;
+}
+
+// ====================================================================================================================
+// CoreDocument
+// ====================================================================================================================
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl CoseDocumentExt for CoreDocument {
+  async fn create_cose_credential<K, I, T>(
+    &self,
+    credential: &Credential<T>,
+    storage: &Storage<K, I>,
+    fragment: &str,
+  ) -> StorageResult<Vec<u8>>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: Serialize + Sync,
+  {
+    // Obtain the method corresponding to the given fragment.
+    let method: &VerificationMethod = self
+      .resolve_method(fragment, Some(MethodScope::assertion_method()))
+      .ok_or(Error::MethodNotFound)?;
+    let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
+      return Err(Error::NotPublicKeyJwk);
+    };
+    let alg: JwsAlgorithm = jwk
+      .alg()
+      .and_then(|alg| alg.parse().ok())
+      .ok_or(Error::InvalidJwsAlgorithm)?;
+
+    let payload: Vec<u8> = credential.to_cbor().map_err(Error::CoseError)?;
+    let unsigned: CoseSign1 = CoseSign1::new(alg, Some(method.id().to_string()), payload).map_err(Error::CoseError)?;
+    let signing_input: Vec<u8> = unsigned.signing_input().map_err(Error::CoseError)?;
+
+    // Get the key identifier corresponding to the given method from the KeyId storage.
+    let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+    let key_id = <I as KeyIdStorage>::get_key_id(storage.key_id_storage(), &method_digest)
+      .await
+      .map_err(Error::KeyIdStorageError)?;
+    let signature: Vec<u8> = <K as JwkStorage>::sign(storage.key_storage(), &key_id, &signing_input, jwk)
+      .await
+      .map_err(Error::KeyStorageError)?;
+
+    unsigned.with_signature(signature).to_bytes().map_err(Error::CoseError)
+  }
+}
+
+// ====================================================================================================================
+// IotaDocument
+// ====================================================================================================================
+#[cfg(feature = "iota-document")]
+mod iota_document {
+  use super::*;
+  use identity_iota_core::IotaDocument;
+
+  #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+  #[cfg_attr(feature = "send-sync-storage", async_trait)]
+  impl CoseDocumentExt for IotaDocument {
+    async fn create_cose_credential<K, I, T>(
+      &self,
+      credential: &Credential<T>,
+      storage: &Storage<K, I>,
+      fragment: &str,
+    ) -> StorageResult<Vec<u8>>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+      T: Serialize + Sync,
+    {
+      self
+        .core_document()
+        .create_cose_credential(credential, storage, fragment)
+        .await
+    }
+  }
+}
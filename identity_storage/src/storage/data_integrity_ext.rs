@@ -0,0 +1,215 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_credential::data_integrity::Cryptosuite;
+use identity_credential::data_integrity::DataIntegrityProcessor;
+use identity_credential::data_integrity::DataIntegrityProof;
+use identity_credential::data_integrity::DataIntegrityProofOptions;
+use identity_credential::data_integrity::UnsignedSdBaseProof;
+use identity_credential::jsonld::DocumentLoader;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::MethodData;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+use serde_json::Value;
+
+use super::JwkStorageDocumentError as Error;
+use crate::JwkStorage;
+use crate::KeyIdStorage;
+use crate::MethodDigest;
+use crate::Storage;
+use crate::StorageResult;
+
+/// Extension of [`CoreDocument`] and `IotaDocument` securing credentials and presentations with Data Integrity
+/// proofs, signed with the keys of their verification methods held in a [`Storage`].
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait DataIntegrityDocumentExt {
+  /// Secures `document`, e.g. a serialized credential, with a Data Integrity proof signed with the key of the
+  /// verification method identified by `fragment`.
+  ///
+  /// The cryptosuite is determined by the `alg` of the method's JWK: `eddsa-rdfc-2022` for `EdDSA` and
+  /// `ecdsa-rdfc-2019` for `ES256`. The method must be in the verification relationship of the proof's purpose.
+  async fn create_data_integrity_proof<K, I, L>(
+    &self,
+    document: &Value,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    processor: &DataIntegrityProcessor<L>,
+    options: &DataIntegrityProofOptions,
+  ) -> StorageResult<Value>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    L: DocumentLoader + Sync;
+
+  /// Secures `document` with an `ecdsa-sd-2023` base proof signed with the key of the verification method
+  /// identified by `fragment`, from which holders derive proofs disclosing parts of the document.
+  ///
+  /// `mandatory_pointers` are JSON pointers to the values holders must always disclose. The method's JWK must have
+  /// an `alg` of `ES256` and the method must be in the verification relationship of the proof's purpose.
+  async fn create_sd_base_proof<K, I, L>(
+    &self,
+    document: &Value,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    processor: &DataIntegrityProcessor<L>,
+    mandatory_pointers: &[String],
+    options: &DataIntegrityProofOptions,
+  ) -> StorageResult<Value>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    L: DocumentLoader + Sync;
+}
+
+// ====================================================================================================================
+// CoreDocument
+// ====================================================================================================================
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl DataIntegrityDocumentExt for CoreDocument {
+  async fn create_data_integrity_proof<K, I, L>(
+    &self,
+    document: &Value,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    processor: &DataIntegrityProcessor<L>,
+    options: &DataIntegrityProofOptions,
+  ) -> StorageResult<Value>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    L: DocumentLoader + Sync,
+  {
+    // Obtain the method corresponding to the given fragment and purpose.
+    let method: &VerificationMethod = self
+      .resolve_method(fragment, Some(MethodScope::from(options.purpose)))
+      .ok_or(Error::MethodNotFound)?;
+    let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
+      return Err(Error::NotPublicKeyJwk);
+    };
+    let cryptosuite: Cryptosuite = jwk
+      .alg()
+      .and_then(|alg| alg.parse::<JwsAlgorithm>().ok())
+      .and_then(Cryptosuite::from_algorithm)
+      .ok_or(Error::InvalidJwsAlgorithm)?;
+
+    let proof: DataIntegrityProof = DataIntegrityProof::new(cryptosuite, method.id().to_string(), options);
+    let signing_input: Vec<u8> = processor
+      .signing_input(document, &proof)
+      .map_err(Error::DataIntegrityError)?;
+
+    // Get the key identifier corresponding to the given method from the KeyId storage.
+    let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+    let key_id = <I as KeyIdStorage>::get_key_id(storage.key_id_storage(), &method_digest)
+      .await
+      .map_err(Error::KeyIdStorageError)?;
+    let signature: Vec<u8> = <K as JwkStorage>::sign(storage.key_storage(), &key_id, &signing_input, jwk)
+      .await
+      .map_err(Error::KeyStorageError)?;
+
+    processor
+      .secure(document, proof, &signature)
+      .map_err(Error::DataIntegrityError)
+  }
+
+  async fn create_sd_base_proof<K, I, L>(
+    &self,
+    document: &Value,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    processor: &DataIntegrityProcessor<L>,
+    mandatory_pointers: &[String],
+    options: &DataIntegrityProofOptions,
+  ) -> StorageResult<Value>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    L: DocumentLoader + Sync,
+  {
+    // Obtain the method corresponding to the given fragment and purpose.
+    let method: &VerificationMethod = self
+      .resolve_method(fragment, Some(MethodScope::from(options.purpose)))
+      .ok_or(Error::MethodNotFound)?;
+    let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
+      return Err(Error::NotPublicKeyJwk);
+    };
+    if jwk.alg().and_then(|alg| alg.parse::<JwsAlgorithm>().ok()) != Some(JwsAlgorithm::ES256) {
+      return Err(Error::InvalidJwsAlgorithm);
+    }
+
+    let proof: DataIntegrityProof = DataIntegrityProof::new(Cryptosuite::EcdsaSd2023, method.id().to_string(), options);
+    let base: UnsignedSdBaseProof = processor
+      .prepare_sd_base_proof(document, proof, mandatory_pointers)
+      .map_err(Error::DataIntegrityError)?;
+
+    // Get the key identifier corresponding to the given method from the KeyId storage.
+    let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+    let key_id = <I as KeyIdStorage>::get_key_id(storage.key_id_storage(), &method_digest)
+      .await
+      .map_err(Error::KeyIdStorageError)?;
+    let signature: Vec<u8> = <K as JwkStorage>::sign(storage.key_storage(), &key_id, base.signing_input(), jwk)
+      .await
+      .map_err(Error::KeyStorageError)?;
+
+    processor
+      .secure_sd_base_proof(document, base, &signature)
+      .map_err(Error::DataIntegrityError)
+  }
+}
+
+// ====================================================================================================================
+// IotaDocument
+// ====================================================================================================================
+#[cfg(feature = "iota-document")]
+mod iota_document {
+  use super::*;
+  use identity_iota_core::IotaDocument;
+
+  #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+  #[cfg_attr(feature = "send-sync-storage", async_trait)]
+  impl DataIntegrityDocumentExt for IotaDocument {
+    async fn create_data_integrity_proof<K, I, L>(
+      &self,
+      document: &Value,
+      storage: &Storage<K, I>,
+      fragment: &str,
+      processor: &DataIntegrityProcessor<L>,
+      options: &DataIntegrityProofOptions,
+    ) -> StorageResult<Value>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+      L: DocumentLoader + Sync,
+    {
+      self
+        .core_document()
+        .create_data_integrity_proof(document, storage, fragment, processor, options)
+        .await
+    }
+
+    async fn create_sd_base_proof<K, I, L>(
+      &self,
+      document: &Value,
+      storage: &Storage<K, I>,
+      fragment: &str,
+      processor: &DataIntegrityProcessor<L>,
+      mandatory_pointers: &[String],
+      options: &DataIntegrityProofOptions,
+    ) -> StorageResult<Value>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+      L: DocumentLoader + Sync,
+    {
+      self
+        .core_document()
+        .create_sd_base_proof(document, storage, fragment, processor, mandatory_pointers, options)
+        .await
+    }
+  }
+}
@@ -0,0 +1,197 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::OrderedSet;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_credential::credential::Credential;
+use identity_credential::credential::Jwt;
+use identity_credential::credential::LinkedDomainService;
+use identity_credential::domain_linkage::DomainLinkageConfiguration;
+use identity_credential::domain_linkage::DomainLinkageCredentialBuilder;
+use identity_credential::domain_linkage::DomainLinkagePublisher;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_document::service::Service;
+
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError as Error;
+use super::JwsSignatureOptions;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_storage::JwkStorage;
+
+/// A DID Configuration resource created by a [`DomainLinkageGenerator`] for a single origin.
+#[derive(Clone, Debug)]
+pub struct GeneratedDomainLinkage {
+  /// The origin the configuration links to the DID.
+  pub origin: Url,
+  /// The DID Configuration resource to be served at "`origin`/.well-known/did-configuration.json".
+  pub configuration: DomainLinkageConfiguration,
+}
+
+/// Generates the DID Configuration resources linking a DID to a set of domains and, optionally, publishes them
+/// and keeps the `LinkedDomains` service of the DID document in sync.
+///
+/// See: <https://identity.foundation/.well-known/resources/did-configuration/>
+#[derive(Clone, Debug)]
+pub struct DomainLinkageGenerator {
+  domains: OrderedSet<Url>,
+  expiration_date: Timestamp,
+  issuance_date: Option<Timestamp>,
+  signature_options: JwsSignatureOptions,
+}
+
+impl DomainLinkageGenerator {
+  /// Creates a new `DomainLinkageGenerator` for the given `domains`.
+  ///
+  /// The Domain Linkage Credentials created by the generator expire at `expiration_date`.
+  pub fn new(domains: impl Into<OrderedSet<Url>>, expiration_date: Timestamp) -> Self {
+    Self {
+      domains: domains.into(),
+      expiration_date,
+      issuance_date: None,
+      signature_options: JwsSignatureOptions::default(),
+    }
+  }
+
+  /// Sets the issuance date of the Domain Linkage Credentials. If unset the current time will be used.
+  #[must_use]
+  pub fn issuance_date(mut self, value: Timestamp) -> Self {
+    self.issuance_date = Some(value);
+    self
+  }
+
+  /// Sets the options used when signing the Domain Linkage Credentials.
+  #[must_use]
+  pub fn signature_options(mut self, value: JwsSignatureOptions) -> Self {
+    self.signature_options = value;
+    self
+  }
+
+  /// Returns the domains the generator links to.
+  pub fn domains(&self) -> &[Url] {
+    self.domains.as_slice()
+  }
+
+  /// Creates a DID Configuration resource for every domain, each containing a single Domain Linkage Credential
+  /// signed with the verification method identified by `fragment`.
+  pub async fn generate<D, K, I>(
+    &self,
+    document: &D,
+    storage: &Storage<K, I>,
+    fragment: &str,
+  ) -> StorageResult<Vec<GeneratedDomainLinkage>>
+  where
+    D: JwkDocumentExt + AsRef<CoreDocument>,
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let mut linkages: Vec<GeneratedDomainLinkage> = Vec::with_capacity(self.domains.len());
+    for origin in self.domains.iter() {
+      let mut builder: DomainLinkageCredentialBuilder = DomainLinkageCredentialBuilder::new()
+        .issuer(document.as_ref().id().clone())
+        .origin(origin.clone())
+        .expiration_date(self.expiration_date);
+      if let Some(issuance_date) = self.issuance_date {
+        builder = builder.issuance_date(issuance_date);
+      }
+      let credential: Credential<Object> = builder.build().map_err(Error::DomainLinkageError)?;
+
+      let jwt: Jwt = document
+        .create_credential_jwt(&credential, storage, fragment, &self.signature_options, None)
+        .await?;
+
+      linkages.push(GeneratedDomainLinkage {
+        origin: origin.clone(),
+        configuration: DomainLinkageConfiguration::new(vec![jwt]),
+      });
+    }
+
+    Ok(linkages)
+  }
+
+  /// Publishes the given `linkages` using `publisher`.
+  ///
+  /// Stops at the first origin that cannot be published.
+  pub async fn publish<P>(&self, linkages: &[GeneratedDomainLinkage], publisher: &P) -> StorageResult<()>
+  where
+    P: DomainLinkagePublisher,
+  {
+    for GeneratedDomainLinkage { origin, configuration } in linkages {
+      publisher
+        .publish(origin, configuration)
+        .await
+        .map_err(Error::DomainLinkageError)?;
+    }
+    Ok(())
+  }
+
+  /// Creates the DID Configuration resources with [`DomainLinkageGenerator::generate`] and publishes them using
+  /// `publisher`.
+  pub async fn generate_and_publish<D, K, I, P>(
+    &self,
+    document: &D,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    publisher: &P,
+  ) -> StorageResult<Vec<GeneratedDomainLinkage>>
+  where
+    D: JwkDocumentExt + AsRef<CoreDocument>,
+    K: JwkStorage,
+    I: KeyIdStorage,
+    P: DomainLinkagePublisher,
+  {
+    let linkages: Vec<GeneratedDomainLinkage> = self.generate(document, storage, fragment).await?;
+    self.publish(&linkages, publisher).await?;
+    Ok(linkages)
+  }
+
+  /// Inserts a `LinkedDomains` service with the id `#service_fragment` listing exactly the domains of this generator,
+  /// replacing an existing service with the same id.
+  ///
+  /// The document must still be published for the change to take effect.
+  pub fn sync_linked_domain_service<D>(&self, document: &mut D, service_fragment: &str) -> StorageResult<DIDUrl>
+  where
+    D: LinkedDomainsDocument,
+  {
+    let service_id: DIDUrl = document
+      .as_ref()
+      .id()
+      .clone()
+      .join(format!("#{service_fragment}"))
+      .map_err(|err| Error::DomainLinkageError(identity_credential::Error::DomainLinkageError(Box::new(err))))?;
+    let service: LinkedDomainService =
+      LinkedDomainService::new(service_id.clone(), self.domains.clone(), Object::new())
+        .map_err(Error::DomainLinkageError)?;
+
+    document.replace_service(service.into())?;
+    Ok(service_id)
+  }
+}
+
+/// A DID document whose services can be updated by [`DomainLinkageGenerator::sync_linked_domain_service`].
+pub trait LinkedDomainsDocument: AsRef<CoreDocument> {
+  /// Inserts `service`, removing a previous service with the same id.
+  fn replace_service(&mut self, service: Service) -> StorageResult<()>;
+}
+
+macro_rules! linked_domains_document_for_document_type {
+  ($t:ty) => {
+    impl LinkedDomainsDocument for $t {
+      fn replace_service(&mut self, service: Service) -> StorageResult<()> {
+        self.remove_service(service.id());
+        self
+          .insert_service(service)
+          .map_err(|err| Error::DomainLinkageError(identity_credential::Error::DomainLinkageError(Box::new(err))))
+      }
+    }
+  };
+}
+
+linked_domains_document_for_document_type!(CoreDocument);
+#[cfg(feature = "iota-document")]
+linked_domains_document_for_document_type!(identity_iota_core::IotaDocument);
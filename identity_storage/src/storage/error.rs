@@ -49,6 +49,67 @@ pub enum JwkStorageDocumentError {
   /// Caused by a failure during (de)serialization of JWS claims.
   #[error("could not produce JWS payload from the given claims: serialization failed")]
   ClaimsSerializationError(#[source] identity_credential::Error),
+  /// Caused by a failure to create or restore a key backup.
+  #[error("key backup failed: {0}")]
+  KeyBackupError(
+    &'static str,
+    #[source] Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  ),
+  /// Caused by an attestation statement that could not be verified.
+  #[cfg(feature = "key-attestation")]
+  #[error("key attestation failed")]
+  KeyAttestationError(#[source] crate::key_storage::KeyAttestationError),
+  /// Caused by a key that could not be derived or recovered.
+  #[cfg(feature = "key-derivation")]
+  #[error("key derivation failed: {0}")]
+  KeyDerivationError(&'static str),
+  /// Caused by a public key that cannot be used with a WebAuthn authenticator.
+  #[cfg(feature = "webauthn")]
+  #[error("invalid WebAuthn public key: {0}")]
+  InvalidWebAuthnKey(&'static str),
+  /// Caused by a failure to create a Data Integrity proof.
+  #[cfg(feature = "data-integrity")]
+  #[error("data integrity proof creation failed")]
+  DataIntegrityError(#[source] identity_credential::data_integrity::Error),
+  /// Caused by a failure to encode or secure a credential of the compact IoT profile.
+  #[cfg(feature = "iot")]
+  #[error("COSE credential creation failed")]
+  CoseError(#[source] identity_credential::iot::Error),
+  /// Caused by a failure to create, link or publish a DID Configuration resource.
+  #[error("domain linkage failed: {0}")]
+  DomainLinkageError(#[source] identity_credential::Error),
+  /// Caused by a failure to create a presentation responding to a
+  /// [`PresentationRequest`](identity_credential::presentation::PresentationRequest).
+  #[error("presentation response failed: {0}")]
+  PresentationResponseError(
+    &'static str,
+    #[source] Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  ),
+  /// Caused by a failure to sign, verify or store a [`ConsentReceipt`](crate::storage::ConsentReceipt).
+  #[error("consent receipt failed: {0}")]
+  ConsentReceiptError(
+    &'static str,
+    #[source] Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  ),
+  /// Caused by a failure to seal or unlock a [`SealedCredential`](crate::storage::SealedCredential).
+  #[cfg(feature = "credential-encryption")]
+  #[error("credential encryption failed: {0}")]
+  CredentialEncryptionError(
+    &'static str,
+    #[source] Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  ),
+  /// Caused by a failure to issue a credential of a batch with [`Issuer::issue_batch`](crate::storage::Issuer).
+  #[error("credential issuance failed: {0}")]
+  IssuanceError(
+    &'static str,
+    #[source] Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  ),
+  /// Caused by an [`IdentityStateSnapshot`](crate::storage::IdentityStateSnapshot) that cannot be imported.
+  #[error("snapshot import failed: {0}")]
+  SnapshotImportError(
+    &'static str,
+    #[source] Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  ),
   /// Caused by a failure to undo a failed storage operation.
   #[error("storage operation failed after altering state. Unable to undo operation(s): {message}")]
   UndoOperationFailed {
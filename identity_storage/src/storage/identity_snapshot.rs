@@ -0,0 +1,281 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_did::DIDUrl;
+use identity_document::service::Service;
+use identity_iota_core::IotaDocument;
+use identity_verification::VerificationMethod;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::JwkStorageDocumentError as Error;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::KeyIdStorageErrorKind;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::KeyId;
+
+/// Binding of a verification method to the key held for it by the key storage.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyBinding {
+  method_id: DIDUrl,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  key_id: Option<KeyId>,
+}
+
+impl KeyBinding {
+  /// Returns the id of the verification method.
+  pub fn method_id(&self) -> &DIDUrl {
+    &self.method_id
+  }
+
+  /// Returns the identifier of the key bound to the method, or `None` if the storage holds no key for it.
+  pub fn key_id(&self) -> Option<&KeyId> {
+    self.key_id.as_ref()
+  }
+}
+
+/// A local snapshot of the state of an identity, allowing it to be inspected offline.
+///
+/// Contains the document as known locally, the bindings of its methods to keys in the key storage and any updates
+/// that have been prepared but not yet published. It contains no key material and can be exported and imported as
+/// JSON through [`ToJson`](identity_core::convert::ToJson), e.g. to attach it to a support ticket, and compared to
+/// the published document with [`IdentityStateSnapshot::diff`].
+///
+/// Exported snapshots carry the version of their format. Use [`IdentityStateSnapshot::import`] to read snapshots
+/// exported by earlier releases, which migrates them to the current format.
+///
+/// Created with [`Storage::snapshot_identity`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityStateSnapshot {
+  #[serde(default)]
+  version: u32,
+  created: Timestamp,
+  document: IotaDocument,
+  key_bindings: Vec<KeyBinding>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pending_updates: Vec<IotaDocument>,
+}
+
+impl IdentityStateSnapshot {
+  /// The version of the snapshot format written by this release.
+  ///
+  /// Snapshots without a version were exported before the format was versioned and are treated as version `0`.
+  pub const CURRENT_VERSION: u32 = 1;
+
+  /// Imports a snapshot exported as JSON by this or an earlier release, migrating it to the current format.
+  ///
+  /// # Errors
+  /// Fails if `json` is not a snapshot or if it was exported by a later release using an unknown version of the
+  /// format.
+  pub fn import(json: &str) -> StorageResult<Self> {
+    let value: Value =
+      serde_json::from_str(json).map_err(|err| Error::SnapshotImportError("invalid JSON", Some(err.into())))?;
+    Self::migrate(value)
+  }
+
+  /// Migrates the JSON representation of a snapshot of any known version to the current format.
+  ///
+  /// Migrations are applied one version at a time, so every supported version remains importable.
+  pub fn migrate(value: Value) -> StorageResult<Self> {
+    let Value::Object(mut snapshot) = value else {
+      return Err(Error::SnapshotImportError("expected a JSON object", None));
+    };
+    let version: u64 = match snapshot.get("version") {
+      None => 0,
+      Some(version) => version
+        .as_u64()
+        .ok_or(Error::SnapshotImportError("invalid version", None))?,
+    };
+    if version > Self::CURRENT_VERSION as u64 {
+      return Err(Error::SnapshotImportError("unsupported version", None));
+    }
+
+    if version < 1 {
+      migrate_v0_to_v1(&mut snapshot);
+    }
+
+    serde_json::from_value(Value::Object(snapshot))
+      .map_err(|err| Error::SnapshotImportError("invalid snapshot", Some(err.into())))
+  }
+
+  /// Returns the version of the format the snapshot was read from or is written with.
+  pub fn version(&self) -> u32 {
+    self.version
+  }
+
+  /// Returns the time the snapshot was created.
+  pub fn created(&self) -> Timestamp {
+    self.created
+  }
+
+  /// Returns the document as known locally at the time of the snapshot.
+  pub fn document(&self) -> &IotaDocument {
+    &self.document
+  }
+
+  /// Returns the key bindings of the embedded verification methods of the document.
+  pub fn key_bindings(&self) -> &[KeyBinding] {
+    &self.key_bindings
+  }
+
+  /// Returns the updates of the document that have been prepared but not yet published, oldest first.
+  pub fn pending_updates(&self) -> &[IotaDocument] {
+    &self.pending_updates
+  }
+
+  /// Records an update of the document that has been prepared but not yet published.
+  pub fn push_pending_update(&mut self, document: IotaDocument) {
+    self.pending_updates.push(document);
+  }
+
+  /// Compares the document of the snapshot to `published`, typically the document resolved from the ledger.
+  ///
+  /// Items are reported as added if they are only present in `published`, and as removed if they are only present in
+  /// the snapshot.
+  pub fn diff(&self, published: &IotaDocument) -> IdentityStateDiff {
+    let local_methods: Vec<&VerificationMethod> = self.document.methods(None);
+    let published_methods: Vec<&VerificationMethod> = published.methods(None);
+    let (added_methods, removed_methods, changed_methods) =
+      diff_by_id(&local_methods, &published_methods, |method| method.id());
+
+    let local_services: Vec<&Service> = self.document.service().iter().collect();
+    let published_services: Vec<&Service> = published.service().iter().collect();
+    let (added_services, removed_services, changed_services) =
+      diff_by_id(&local_services, &published_services, |service| service.id());
+
+    let unbound_methods: Vec<DIDUrl> = published_methods
+      .iter()
+      .map(|method| method.id())
+      .filter(|id| {
+        !self
+          .key_bindings
+          .iter()
+          .any(|binding| binding.method_id() == *id && binding.key_id.is_some())
+      })
+      .cloned()
+      .collect();
+
+    let local_metadata = &self.document.metadata;
+    let published_metadata = &published.metadata;
+    let metadata_changed: bool = local_metadata.created != published_metadata.created
+      || local_metadata.updated != published_metadata.updated
+      || local_metadata.deactivated != published_metadata.deactivated;
+
+    IdentityStateDiff {
+      document_changed: self.document.core_document() != published.core_document(),
+      metadata_changed,
+      added_methods,
+      removed_methods,
+      changed_methods,
+      added_services,
+      removed_services,
+      changed_services,
+      unbound_methods,
+    }
+  }
+}
+
+/// The differences between an [`IdentityStateSnapshot`] and a published document, as computed by
+/// [`IdentityStateSnapshot::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityStateDiff {
+  /// Whether the documents differ in any way, including properties and verification relationships.
+  pub document_changed: bool,
+  /// Whether the `created`, `updated` or `deactivated` metadata differ.
+  pub metadata_changed: bool,
+  /// Embedded methods that are only present in the published document.
+  pub added_methods: Vec<DIDUrl>,
+  /// Embedded methods that are only present in the snapshot.
+  pub removed_methods: Vec<DIDUrl>,
+  /// Embedded methods present in both documents with different contents.
+  pub changed_methods: Vec<DIDUrl>,
+  /// Services that are only present in the published document.
+  pub added_services: Vec<DIDUrl>,
+  /// Services that are only present in the snapshot.
+  pub removed_services: Vec<DIDUrl>,
+  /// Services present in both documents with different contents.
+  pub changed_services: Vec<DIDUrl>,
+  /// Embedded methods of the published document for which the snapshot records no key.
+  pub unbound_methods: Vec<DIDUrl>,
+}
+
+impl IdentityStateDiff {
+  /// Returns `true` if the snapshot matches the published document and holds keys for all of its methods.
+  pub fn is_empty(&self) -> bool {
+    !self.document_changed && !self.metadata_changed && self.unbound_methods.is_empty()
+  }
+}
+
+/// Version `1` only introduced the `version` field, the other fields are unchanged.
+fn migrate_v0_to_v1(snapshot: &mut Map<String, Value>) {
+  snapshot.insert("version".to_owned(), Value::from(1));
+}
+
+/// Returns the ids of the items that were added, removed and changed from `local` to `published`.
+fn diff_by_id<T: PartialEq>(
+  local: &[&T],
+  published: &[&T],
+  id: impl Fn(&T) -> &DIDUrl,
+) -> (Vec<DIDUrl>, Vec<DIDUrl>, Vec<DIDUrl>) {
+  let find = |items: &[&T], item_id: &DIDUrl| items.iter().find(|item| id(item) == item_id).copied();
+
+  let mut added: Vec<DIDUrl> = Vec::new();
+  let mut changed: Vec<DIDUrl> = Vec::new();
+  for item in published {
+    match find(local, id(item)) {
+      None => added.push(id(item).clone()),
+      Some(local_item) if local_item != *item => changed.push(id(item).clone()),
+      Some(_) => {}
+    }
+  }
+  let removed: Vec<DIDUrl> = local
+    .iter()
+    .filter(|item| find(published, id(item)).is_none())
+    .map(|item| id(item).clone())
+    .collect();
+
+  (added, removed, changed)
+}
+
+impl<K, I> Storage<K, I>
+where
+  I: KeyIdStorage,
+{
+  /// Create an [`IdentityStateSnapshot`] of `document`, recording the keys this storage holds for its embedded
+  /// verification methods.
+  ///
+  /// Methods without a key in this storage, e.g. those not using `publicKeyJwk`, are recorded without a key id.
+  pub async fn snapshot_identity(&self, document: &IotaDocument) -> StorageResult<IdentityStateSnapshot> {
+    let mut key_bindings: Vec<KeyBinding> = Vec::new();
+    for method in document.methods(None) {
+      let key_id: Option<KeyId> = match MethodDigest::new(method) {
+        Ok(method_digest) => match self.key_id_storage().get_key_id(&method_digest).await {
+          Ok(key_id) => Some(key_id),
+          Err(err) if matches!(err.kind(), KeyIdStorageErrorKind::KeyIdNotFound) => None,
+          Err(err) => return Err(Error::KeyIdStorageError(err)),
+        },
+        Err(_) => None,
+      };
+      key_bindings.push(KeyBinding {
+        method_id: method.id().clone(),
+        key_id,
+      });
+    }
+
+    Ok(IdentityStateSnapshot {
+      version: IdentityStateSnapshot::CURRENT_VERSION,
+      created: Timestamp::now_utc(),
+      document: document.clone(),
+      key_bindings,
+      pending_updates: Vec::new(),
+    })
+  }
+}
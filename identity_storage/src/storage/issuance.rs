@@ -0,0 +1,185 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::ops::Range;
+
+use futures::stream;
+use futures::StreamExt;
+use identity_core::common::OneOrMany;
+use identity_core::common::Url;
+use identity_credential::credential::Credential;
+use identity_credential::credential::Issuer as CredentialIssuer;
+use identity_credential::credential::Jwt;
+use identity_credential::credential::RevocationBitmapStatus;
+use identity_credential::credential::Status;
+use identity_credential::credential::Subject;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError as Error;
+use super::JwsSignatureOptions;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_storage::JwkStorage;
+
+/// The number of credentials an [`Issuer`] signs concurrently by default.
+const DEFAULT_CONCURRENCY: usize = 16;
+
+/// Allocates the `credentialStatus` of the credentials issued by [`Issuer::issue_batch`].
+pub trait StatusAllocator {
+  /// Returns the status of the next issued credential, or `None` if no status is left to allocate.
+  fn allocate(&mut self) -> Option<Status>;
+}
+
+/// A [`StatusAllocator`] handing out consecutive indices of a `RevocationBitmap2022` service.
+#[derive(Clone, Debug)]
+pub struct RevocationBitmapAllocator {
+  service: DIDUrl,
+  indices: Range<u32>,
+}
+
+impl RevocationBitmapAllocator {
+  /// Creates an allocator for the `indices` of the revocation bitmap identified by `service`.
+  pub fn new(service: DIDUrl, indices: Range<u32>) -> Self {
+    Self { service, indices }
+  }
+
+  /// Returns the indices that have not been allocated yet, e.g. to resume allocation in the next batch.
+  pub fn remaining(&self) -> Range<u32> {
+    self.indices.clone()
+  }
+}
+
+impl StatusAllocator for RevocationBitmapAllocator {
+  fn allocate(&mut self) -> Option<Status> {
+    self
+      .indices
+      .next()
+      .map(|index| RevocationBitmapStatus::new(self.service.clone(), index).into())
+  }
+}
+
+/// The outcome of issuing the credential of a single subject with [`Issuer::issue_batch`].
+#[derive(Debug)]
+pub struct IssuedCredential {
+  /// The position of the subject in the batch.
+  pub index: usize,
+  /// The status allocated to the credential, if any.
+  ///
+  /// A status is allocated before signing, so it is set even if signing failed. It is not handed out again.
+  pub status: Option<Status>,
+  /// The signed credential, or the error that prevented issuing it.
+  pub jwt: StorageResult<Jwt>,
+}
+
+/// Issues batches of JWT credentials signed by the method of a single issuer document.
+///
+/// The issuer document is resolved once by the caller and shared by all credentials of a batch, which are signed
+/// concurrently.
+pub struct Issuer<'a, D, K, I> {
+  document: &'a D,
+  storage: &'a Storage<K, I>,
+  fragment: String,
+  options: JwsSignatureOptions,
+  concurrency: usize,
+}
+
+impl<'a, D, K, I> Issuer<'a, D, K, I>
+where
+  D: JwkDocumentExt + AsRef<CoreDocument>,
+  K: JwkStorage,
+  I: KeyIdStorage,
+{
+  /// Creates an [`Issuer`] signing with the method identified by `fragment` in `document`, whose key is held in
+  /// `storage`.
+  pub fn new(document: &'a D, storage: &'a Storage<K, I>, fragment: impl Into<String>) -> Self {
+    Self {
+      document,
+      storage,
+      fragment: fragment.into(),
+      options: JwsSignatureOptions::default(),
+      concurrency: DEFAULT_CONCURRENCY,
+    }
+  }
+
+  /// Sets the options of the JWS signatures.
+  pub fn options(mut self, options: JwsSignatureOptions) -> Self {
+    self.options = options;
+    self
+  }
+
+  /// Sets the maximum number of credentials signed concurrently, which is at least one.
+  pub fn concurrency(mut self, concurrency: usize) -> Self {
+    self.concurrency = concurrency.max(1);
+    self
+  }
+
+  /// Issues a credential for each of the `subjects`, which replace the `credentialSubject` of `template`.
+  ///
+  /// The `issuer` of every credential is set to the DID of the issuer document. If `status` is provided, a status is
+  /// allocated to each credential in the order of `subjects`, replacing any `credentialStatus` of the template. The
+  /// outcomes are returned in the same order. A failure to allocate a status for or sign a credential is reported
+  /// for that credential only.
+  pub async fn issue_batch(
+    &self,
+    template: &Credential,
+    subjects: &[Subject],
+    mut status: Option<&mut dyn StatusAllocator>,
+  ) -> Vec<IssuedCredential> {
+    let issuer: Url = Url::from(self.document.as_ref().id().to_url());
+    let mut template: Credential = template.clone();
+    match &mut template.issuer {
+      CredentialIssuer::Url(url) => *url = issuer,
+      CredentialIssuer::Obj(data) => data.id = issuer,
+    }
+
+    // Statuses are allocated sequentially, so that the indices follow the order of the subjects.
+    let prepared: Vec<(usize, Option<Status>, StorageResult<Credential>)> = subjects
+      .iter()
+      .enumerate()
+      .map(|(index, subject)| {
+        let allocated: Option<Status> = match status.as_deref_mut() {
+          Some(allocator) => match allocator.allocate() {
+            Some(allocated) => Some(allocated),
+            None => {
+              return (
+                index,
+                None,
+                Err(Error::IssuanceError("no status left to allocate", None)),
+              )
+            }
+          },
+          None => None,
+        };
+
+        let mut credential: Credential = template.clone();
+        credential.credential_subject = OneOrMany::One(subject.clone());
+        if allocated.is_some() {
+          credential.credential_status = allocated.clone();
+        }
+
+        (index, allocated, Ok(credential))
+      })
+      .collect();
+
+    stream::iter(prepared)
+      .map(|(index, status, credential)| async move {
+        let jwt: StorageResult<Jwt> = match credential {
+          Ok(credential) => {
+            self
+              .document
+              .create_credential_jwt(&credential, self.storage, &self.fragment, &self.options, None)
+              .await
+          }
+          Err(err) => Err(err),
+        };
+        IssuedCredential { index, status, jwt }
+      })
+      .buffered(self.concurrency)
+      .collect()
+      .await
+  }
+}
@@ -3,7 +3,10 @@
 
 use super::JwkStorageDocumentError as Error;
 use super::JwsSignatureOptions;
+use super::MethodRotation;
+use super::MethodRotationPolicy;
 use super::Storage;
+use super::EXPIRES_PROPERTY;
 
 use crate::key_id_storage::KeyIdStorage;
 use crate::key_id_storage::KeyIdStorageResult;
@@ -22,13 +25,16 @@ use identity_credential::credential::Jwt;
 use identity_credential::presentation::JwtPresentationOptions;
 use identity_credential::presentation::Presentation;
 use identity_did::DIDUrl;
+use identity_did::DID;
 use identity_document::document::CoreDocument;
 use identity_verification::jose::jws::CompactJwsEncoder;
 use identity_verification::jose::jws::CompactJwsEncodingOptions;
 use identity_verification::jose::jws::JwsAlgorithm;
 use identity_verification::jose::jws::JwsHeader;
+use identity_verification::jwk::Jwk;
 use identity_verification::jws::CharSet;
 use identity_verification::MethodData;
+use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
 use identity_verification::VerificationMethod;
 use serde::de::DeserializeOwned;
@@ -79,6 +85,31 @@ pub trait JwkDocumentExt: private::Sealed {
     K: JwkStorage,
     I: KeyIdStorage;
 
+  /// Rotate the key material of the method identified by `id` as a single operation.
+  ///
+  /// New key material is generated in the given `storage` and a new verification method is inserted under the same
+  /// scope as the previous one, with the `KeyIdStorage` entry linking it to the new key. Verification relationships
+  /// referencing the previous method are updated to reference the new method instead, so a retained method is only
+  /// part of the document's `verificationMethod` set or of the relationship it is embedded in. The previous method is
+  /// then handled according to `policy`.
+  ///
+  /// If any step fails, the changes made to the document and storage are reverted where possible.
+  ///
+  /// The returned [`MethodRotation`] summarizes the changes. The document still needs to be published.
+  #[allow(clippy::too_many_arguments)]
+  async fn rotate_method<K, I>(
+    &mut self,
+    storage: &Storage<K, I>,
+    id: &DIDUrl,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+    fragment: Option<&str>,
+    policy: MethodRotationPolicy,
+  ) -> StorageResult<MethodRotation>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage;
+
   /// Sign the arbitrary `payload` according to `options` with the storage backed private key corresponding to the
   /// public key material in the verification method identified by the given `fragment.
   ///
@@ -95,6 +126,14 @@ pub trait JwkDocumentExt: private::Sealed {
     K: JwkStorage,
     I: KeyIdStorage;
 
+  /// Returns the signing input that [`JwkDocumentExt::create_jws`] signs for the given `fragment`, `payload` and
+  /// `options`, i.e. the encoded protected header and payload separated by a `.`.
+  ///
+  /// No key is involved, so the exact bytes signed by different layers, e.g. the WASM bindings, can be compared.
+  /// The signing input of [`JwkDocumentExt::create_credential_jwt`] is obtained by passing the credential serialized
+  /// with [`Credential::serialize_jwt`] as `payload`.
+  fn jws_signing_input(&self, fragment: &str, payload: &[u8], options: &JwsSignatureOptions) -> StorageResult<Vec<u8>>;
+
   /// Produces a JWT where the payload is produced from the given `credential`
   /// in accordance with [VC Data Model v1.1](https://www.w3.org/TR/vc-data-model/#json-web-token).
   ///
@@ -300,6 +339,106 @@ macro_rules! purge_method_for_document_type {
   };
 }
 
+macro_rules! rotate_method_for_document_type {
+  ($t:ty, $generate:ident, $purge:ident, $name:ident) => {
+    async fn $name<K, I>(
+      document: &mut $t,
+      storage: &Storage<K, I>,
+      id: &DIDUrl,
+      key_type: KeyType,
+      alg: JwsAlgorithm,
+      fragment: Option<&str>,
+      policy: MethodRotationPolicy,
+    ) -> StorageResult<MethodRotation>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+    {
+      // Determine the scope of the previous method and the relationships referencing it.
+      let mut relationships: Vec<MethodRelationship> = [
+        MethodRelationship::Authentication,
+        MethodRelationship::AssertionMethod,
+        MethodRelationship::KeyAgreement,
+        MethodRelationship::CapabilityDelegation,
+        MethodRelationship::CapabilityInvocation,
+      ]
+      .into_iter()
+      .filter(|relationship| {
+        document
+          .resolve_method(id, Some(MethodScope::VerificationRelationship(*relationship)))
+          .is_some()
+      })
+      .collect();
+      let scope: MethodScope = if document
+        .resolve_method(id, Some(MethodScope::VerificationMethod))
+        .is_some()
+      {
+        MethodScope::VerificationMethod
+      } else {
+        // An embedded method can only be part of a single relationship.
+        match relationships.as_slice() {
+          [relationship] => {
+            let scope: MethodScope = MethodScope::VerificationRelationship(*relationship);
+            relationships.clear();
+            scope
+          }
+          _ => return Err(Error::MethodNotFound),
+        }
+      };
+
+      let new_fragment: String = $generate(document, storage, key_type, alg, fragment, scope).await?;
+      let new_method: DIDUrl = document.id().to_url().join(format!("#{new_fragment}")).map_err(|err| {
+        Error::VerificationMethodConstructionError(identity_verification::Error::DIDUrlConstructionError(err))
+      })?;
+
+      let rotation_result: StorageResult<()> = async {
+        for relationship in relationships.iter() {
+          document
+            .attach_method_relationship(&new_method, *relationship)
+            .map_err(|_| Error::MethodNotFound)?;
+        }
+        match policy {
+          MethodRotationPolicy::Purge => $purge(document, storage, id).await,
+          MethodRotationPolicy::Retain { expires } => {
+            document
+              .resolve_method_mut(id, None)
+              .ok_or(Error::MethodNotFound)?
+              .properties_mut()
+              .insert(EXPIRES_PROPERTY.to_owned(), expires.to_rfc3339().into());
+            for relationship in relationships.iter() {
+              document
+                .detach_method_relationship(id, *relationship)
+                .map_err(|_| Error::MethodNotFound)?;
+            }
+            Ok(())
+          }
+        }
+      }
+      .await;
+
+      // Revert the generation of the new method if the previous method could not be handled.
+      if let Err(error) = rotation_result {
+        return match $purge(document, storage, &new_method).await {
+          Ok(()) => Err(error),
+          Err(undo_error) => Err(Error::UndoOperationFailed {
+            message: format!("unable to remove rotated method {new_method}"),
+            source: Box::new(error),
+            undo_error: Some(Box::new(undo_error)),
+          }),
+        };
+      }
+
+      Ok(MethodRotation {
+        previous_method: id.clone(),
+        new_method,
+        scope,
+        relationships,
+        policy,
+      })
+    }
+  };
+}
+
 // ====================================================================================================================
 // CoreDocument
 // ====================================================================================================================
@@ -312,6 +451,12 @@ generate_method_for_document_type!(
   generate_method_core_document
 );
 purge_method_for_document_type!(CoreDocument, purge_method_core_document);
+rotate_method_for_document_type!(
+  CoreDocument,
+  generate_method_core_document,
+  purge_method_core_document,
+  rotate_method_core_document
+);
 
 #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync-storage", async_trait)]
@@ -339,6 +484,22 @@ impl JwkDocumentExt for CoreDocument {
     purge_method_core_document(self, storage, id).await
   }
 
+  async fn rotate_method<K, I>(
+    &mut self,
+    storage: &Storage<K, I>,
+    id: &DIDUrl,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+    fragment: Option<&str>,
+    policy: MethodRotationPolicy,
+  ) -> StorageResult<MethodRotation>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    rotate_method_core_document(self, storage, id, key_type, alg, fragment, policy).await
+  }
+
   async fn create_jws<K, I>(
     &self,
     storage: &Storage<K, I>,
@@ -350,67 +511,12 @@ impl JwkDocumentExt for CoreDocument {
     K: JwkStorage,
     I: KeyIdStorage,
   {
-    // Obtain the method corresponding to the given fragment.
-    let method: &VerificationMethod = self.resolve_method(fragment, None).ok_or(Error::MethodNotFound)?;
-    let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
-      return Err(Error::NotPublicKeyJwk);
-    };
-
-    // Extract JwsAlgorithm.
-    let alg: JwsAlgorithm = jwk
-      .alg()
-      .unwrap_or("")
-      .parse()
-      .map_err(|_| Error::InvalidJwsAlgorithm)?;
-
-    // Create JWS header in accordance with options.
-    let header: JwsHeader = {
-      let mut header = JwsHeader::new();
-
-      header.set_alg(alg);
-      if let Some(custom) = &options.custom_header_parameters {
-        header.set_custom(custom.clone())
-      }
-
-      if let Some(ref kid) = options.kid {
-        header.set_kid(kid.clone());
-      } else {
-        header.set_kid(method.id().to_string());
-      }
-
-      if options.attach_jwk {
-        header.set_jwk(jwk.clone())
-      };
-
-      if let Some(b64) = options.b64 {
-        // Follow recommendation in https://datatracker.ietf.org/doc/html/rfc7797#section-7.
-        if !b64 {
-          header.set_b64(b64);
-          header.set_crit(["b64"]);
-        }
-      };
-
-      if let Some(typ) = &options.typ {
-        header.set_typ(typ.clone())
-      } else {
-        // https://www.w3.org/TR/vc-data-model/#jwt-encoding
-        header.set_typ("JWT")
-      }
-
-      if let Some(cty) = &options.cty {
-        header.set_cty(cty.clone())
-      };
-
-      if let Some(url) = &options.url {
-        header.set_url(url.clone())
-      };
-
-      if let Some(nonce) = &options.nonce {
-        header.set_nonce(nonce.clone())
-      };
-
-      header
-    };
+    let PreparedJws {
+      method,
+      jwk,
+      header,
+      encoding_options,
+    } = prepare_jws(self, fragment, options)?;
 
     // Get the key identifier corresponding to the given method from the KeyId storage.
     let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
@@ -418,17 +524,6 @@ impl JwkDocumentExt for CoreDocument {
       .await
       .map_err(Error::KeyIdStorageError)?;
 
-    // Extract Compact JWS encoding options.
-    let encoding_options: CompactJwsEncodingOptions = if !options.detached_payload {
-      // We use this as a default and don't provide the extra UrlSafe check for now.
-      // Applications that require such checks can easily do so after JWS creation.
-      CompactJwsEncodingOptions::NonDetached {
-        charset_requirements: CharSet::Default,
-      }
-    } else {
-      CompactJwsEncodingOptions::Detached
-    };
-
     let jws_encoder: CompactJwsEncoder<'_> = CompactJwsEncoder::new_with_options(payload, &header, encoding_options)
       .map_err(|err| Error::EncodingError(err.into()))?;
     let signature = <K as JwkStorage>::sign(storage.key_storage(), &key_id, jws_encoder.signing_input(), jwk)
@@ -437,6 +532,17 @@ impl JwkDocumentExt for CoreDocument {
     Ok(Jws::new(jws_encoder.into_jws(&signature)))
   }
 
+  fn jws_signing_input(&self, fragment: &str, payload: &[u8], options: &JwsSignatureOptions) -> StorageResult<Vec<u8>> {
+    let PreparedJws {
+      header,
+      encoding_options,
+      ..
+    } = prepare_jws(self, fragment, options)?;
+    CompactJwsEncoder::new_with_options(payload, &header, encoding_options)
+      .map(|jws_encoder| jws_encoder.signing_input().to_vec())
+      .map_err(|err| Error::EncodingError(err.into()))
+  }
+
   async fn create_credential_jwt<K, I, T>(
     &self,
     credential: &Credential<T>,
@@ -508,6 +614,108 @@ impl JwkDocumentExt for CoreDocument {
   }
 }
 
+/// The method, protected header and encoding options of a JWS created by [`JwkDocumentExt::create_jws`].
+struct PreparedJws<'doc> {
+  method: &'doc VerificationMethod,
+  jwk: &'doc Jwk,
+  header: JwsHeader,
+  encoding_options: CompactJwsEncodingOptions,
+}
+
+/// Resolves the method identified by `fragment` and constructs the protected header and encoding options of a JWS
+/// according to `options`.
+fn prepare_jws<'doc>(
+  document: &'doc CoreDocument,
+  fragment: &str,
+  options: &JwsSignatureOptions,
+) -> StorageResult<PreparedJws<'doc>> {
+  // Obtain the method corresponding to the given fragment.
+  let method: &VerificationMethod = document.resolve_method(fragment, None).ok_or(Error::MethodNotFound)?;
+  let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
+    return Err(Error::NotPublicKeyJwk);
+  };
+
+  // Extract JwsAlgorithm.
+  let alg: JwsAlgorithm = jwk
+    .alg()
+    .unwrap_or("")
+    .parse()
+    .map_err(|_| Error::InvalidJwsAlgorithm)?;
+
+  // Create JWS header in accordance with options.
+  let header: JwsHeader = {
+    let mut header = JwsHeader::new();
+
+    header.set_alg(alg);
+    if let Some(custom) = &options.custom_header_parameters {
+      header.set_custom(custom.clone())
+    }
+
+    if let Some(ref kid) = options.kid {
+      header.set_kid(kid.clone());
+    } else {
+      header.set_kid(method.id().to_string());
+    }
+
+    if options.attach_jwk {
+      header.set_jwk(jwk.clone())
+    };
+
+    let mut crit: Vec<String> = options.crit.clone().unwrap_or_default();
+    if let Some(b64) = options.b64 {
+      // Follow recommendation in https://datatracker.ietf.org/doc/html/rfc7797#section-7.
+      if !b64 {
+        header.set_b64(b64);
+        if !crit.iter().any(|param| param == "b64") {
+          crit.push("b64".to_owned());
+        }
+      }
+    };
+    if !crit.is_empty() {
+      header.set_crit(crit);
+    }
+
+    if let Some(typ) = &options.typ {
+      header.set_typ(typ.clone())
+    } else {
+      // https://www.w3.org/TR/vc-data-model/#jwt-encoding
+      header.set_typ("JWT")
+    }
+
+    if let Some(cty) = &options.cty {
+      header.set_cty(cty.clone())
+    };
+
+    if let Some(url) = &options.url {
+      header.set_url(url.clone())
+    };
+
+    if let Some(nonce) = &options.nonce {
+      header.set_nonce(nonce.clone())
+    };
+
+    header
+  };
+
+  // Extract Compact JWS encoding options.
+  let encoding_options: CompactJwsEncodingOptions = if !options.detached_payload {
+    // We use this as a default and don't provide the extra UrlSafe check for now.
+    // Applications that require such checks can easily do so after JWS creation.
+    CompactJwsEncodingOptions::NonDetached {
+      charset_requirements: CharSet::Default,
+    }
+  } else {
+    CompactJwsEncodingOptions::Detached
+  };
+
+  Ok(PreparedJws {
+    method,
+    jwk,
+    header,
+    encoding_options,
+  })
+}
+
 /// Attempt to revert key generation. If this succeeds the original `source_error` is returned,
 /// otherwise [`JwkStorageDocumentError::UndoOperationFailed`] is returned with the `source_error` attached as
 /// `source`.
@@ -545,6 +753,12 @@ mod iota_document {
     generate_method_iota_document
   );
   purge_method_for_document_type!(IotaDocument, purge_method_iota_document);
+  rotate_method_for_document_type!(
+    IotaDocument,
+    generate_method_iota_document,
+    purge_method_iota_document,
+    rotate_method_iota_document
+  );
 
   #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
   #[cfg_attr(feature = "send-sync-storage", async_trait)]
@@ -572,6 +786,22 @@ mod iota_document {
       purge_method_iota_document(self, storage, id).await
     }
 
+    async fn rotate_method<K, I>(
+      &mut self,
+      storage: &Storage<K, I>,
+      id: &DIDUrl,
+      key_type: KeyType,
+      alg: JwsAlgorithm,
+      fragment: Option<&str>,
+      policy: MethodRotationPolicy,
+    ) -> StorageResult<MethodRotation>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+    {
+      rotate_method_iota_document(self, storage, id, key_type, alg, fragment, policy).await
+    }
+
     async fn create_jws<K, I>(
       &self,
       storage: &Storage<K, I>,
@@ -589,6 +819,15 @@ mod iota_document {
         .await
     }
 
+    fn jws_signing_input(
+      &self,
+      fragment: &str,
+      payload: &[u8],
+      options: &JwsSignatureOptions,
+    ) -> StorageResult<Vec<u8>> {
+      self.core_document().jws_signing_input(fragment, payload, options)
+    }
+
     async fn create_credential_jwt<K, I, T>(
       &self,
       credential: &Credential<T>,
@@ -0,0 +1,272 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodData;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError as Error;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::JwkStorageAttestationExt;
+use crate::key_storage::KeyAttestation;
+use crate::key_storage::KeyAttestationLevel;
+use crate::key_storage::KeyAttestationVerifier;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyType;
+
+/// A verification method generated with [`KeyAttestationDocumentExt::generate_attested_method`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttestedMethod {
+  /// The fragment of the generated method.
+  pub fragment: String,
+  /// The attestation level established for the key of the method, or `None` if the storage provided no attestation
+  /// statement for the key.
+  pub attestation_level: Option<KeyAttestationLevel>,
+}
+
+/// Extension trait attesting the keys of the verification methods of a DID document.
+///
+/// The attestation level established by verifying an attestation statement is recorded as key metadata in a
+/// [`JwkStorageAttestationExt`] storage.
+///
+/// This trait is deliberately sealed and cannot be implemented by external crates.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait KeyAttestationDocumentExt: private::Sealed {
+  /// Generate new key material in the given `storage` and insert a new verification method with the corresponding
+  /// public key material into the DID document, as in [`JwkDocumentExt::generate_method`].
+  ///
+  /// If the storage provides an attestation statement for the generated key, it is verified with `verifier` and the
+  /// resulting level is recorded and returned. If the verification fails, the method is purged and an error is
+  /// returned.
+  #[allow(clippy::too_many_arguments)]
+  async fn generate_attested_method<K, I, V>(
+    &mut self,
+    storage: &Storage<K, I>,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+    fragment: Option<&str>,
+    scope: MethodScope,
+    verifier: &V,
+  ) -> StorageResult<AttestedMethod>
+  where
+    K: JwkStorageAttestationExt,
+    I: KeyIdStorage,
+    V: KeyAttestationVerifier + Sync;
+
+  /// Verify the `attestation` statement for the key of the method identified by `fragment` with `verifier`, e.g. when
+  /// onboarding a key generated on a device, and record the resulting level in `storage`.
+  async fn attest_method<K, I, V>(
+    &self,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    attestation: &KeyAttestation,
+    verifier: &V,
+  ) -> StorageResult<KeyAttestationLevel>
+  where
+    K: JwkStorageAttestationExt,
+    I: KeyIdStorage,
+    V: KeyAttestationVerifier + Sync;
+
+  /// Returns the attestation level recorded for the key of the method identified by `fragment`, or `None` if the key
+  /// was never attested.
+  async fn attestation_level<K, I>(
+    &self,
+    storage: &Storage<K, I>,
+    fragment: &str,
+  ) -> StorageResult<Option<KeyAttestationLevel>>
+  where
+    K: JwkStorageAttestationExt,
+    I: KeyIdStorage;
+}
+
+mod private {
+  pub trait Sealed {}
+  impl Sealed for identity_document::document::CoreDocument {}
+  #[cfg(feature = "iota-document")]
+  impl Sealed for identity_iota_core::IotaDocument {}
+}
+
+// ====================================================================================================================
+// Implementation
+// ====================================================================================================================
+
+/// Resolves the method identified by `fragment` and the id of its key in `storage`.
+async fn resolve_method_key<'document, K, I>(
+  document: &'document CoreDocument,
+  storage: &Storage<K, I>,
+  fragment: &str,
+) -> StorageResult<(&'document VerificationMethod, KeyId)>
+where
+  I: KeyIdStorage,
+{
+  let method: &VerificationMethod = document.resolve_method(fragment, None).ok_or(Error::MethodNotFound)?;
+  let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+  let key_id: KeyId = storage
+    .key_id_storage()
+    .get_key_id(&method_digest)
+    .await
+    .map_err(Error::KeyIdStorageError)?;
+
+  Ok((method, key_id))
+}
+
+async fn attest_method<K, I, V>(
+  document: &CoreDocument,
+  storage: &Storage<K, I>,
+  fragment: &str,
+  attestation: &KeyAttestation,
+  verifier: &V,
+) -> StorageResult<KeyAttestationLevel>
+where
+  K: JwkStorageAttestationExt,
+  I: KeyIdStorage,
+  V: KeyAttestationVerifier,
+{
+  let (method, key_id) = resolve_method_key(document, storage, fragment).await?;
+  let MethodData::PublicKeyJwk(ref public_key) = method.data() else {
+    return Err(Error::NotPublicKeyJwk);
+  };
+
+  let level: KeyAttestationLevel = verifier
+    .verify(attestation, public_key)
+    .map_err(Error::KeyAttestationError)?;
+  storage
+    .key_storage()
+    .set_attestation_level(&key_id, level)
+    .await
+    .map_err(Error::KeyStorageError)?;
+
+  Ok(level)
+}
+
+/// Attests the key of a freshly generated method if `storage` provides an attestation statement for it.
+async fn attest_generated_method<K, I, V>(
+  document: &CoreDocument,
+  storage: &Storage<K, I>,
+  fragment: &str,
+  verifier: &V,
+) -> StorageResult<Option<KeyAttestationLevel>>
+where
+  K: JwkStorageAttestationExt,
+  I: KeyIdStorage,
+  V: KeyAttestationVerifier,
+{
+  let (_, key_id) = resolve_method_key(document, storage, fragment).await?;
+  let Some(attestation) = storage
+    .key_storage()
+    .key_attestation(&key_id)
+    .await
+    .map_err(Error::KeyStorageError)?
+  else {
+    return Ok(None);
+  };
+
+  attest_method(document, storage, fragment, &attestation, verifier)
+    .await
+    .map(Some)
+}
+
+async fn attestation_level<K, I>(
+  document: &CoreDocument,
+  storage: &Storage<K, I>,
+  fragment: &str,
+) -> StorageResult<Option<KeyAttestationLevel>>
+where
+  K: JwkStorageAttestationExt,
+  I: KeyIdStorage,
+{
+  let (_, key_id) = resolve_method_key(document, storage, fragment).await?;
+  storage
+    .key_storage()
+    .attestation_level(&key_id)
+    .await
+    .map_err(Error::KeyStorageError)
+}
+
+macro_rules! key_attestation_document_ext_for_document_type {
+  ($t:ty) => {
+    #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync-storage", async_trait)]
+    impl KeyAttestationDocumentExt for $t {
+      async fn generate_attested_method<K, I, V>(
+        &mut self,
+        storage: &Storage<K, I>,
+        key_type: KeyType,
+        alg: JwsAlgorithm,
+        fragment: Option<&str>,
+        scope: MethodScope,
+        verifier: &V,
+      ) -> StorageResult<AttestedMethod>
+      where
+        K: JwkStorageAttestationExt,
+        I: KeyIdStorage,
+        V: KeyAttestationVerifier + Sync,
+      {
+        let fragment: String = self.generate_method(storage, key_type, alg, fragment, scope).await?;
+
+        match attest_generated_method(AsRef::<CoreDocument>::as_ref(self), storage, &fragment, verifier).await {
+          Ok(attestation_level) => Ok(AttestedMethod {
+            fragment,
+            attestation_level,
+          }),
+          Err(error) => {
+            // The method was just generated, so it is guaranteed to be resolvable.
+            let method_id: DIDUrl = AsRef::<CoreDocument>::as_ref(self)
+              .resolve_method(fragment.as_str(), None)
+              .map(|method| method.id().clone())
+              .ok_or(Error::MethodNotFound)?;
+            let _ = self.purge_method(storage, &method_id).await;
+            Err(error)
+          }
+        }
+      }
+
+      async fn attest_method<K, I, V>(
+        &self,
+        storage: &Storage<K, I>,
+        fragment: &str,
+        attestation: &KeyAttestation,
+        verifier: &V,
+      ) -> StorageResult<KeyAttestationLevel>
+      where
+        K: JwkStorageAttestationExt,
+        I: KeyIdStorage,
+        V: KeyAttestationVerifier + Sync,
+      {
+        attest_method(
+          AsRef::<CoreDocument>::as_ref(self),
+          storage,
+          fragment,
+          attestation,
+          verifier,
+        )
+        .await
+      }
+
+      async fn attestation_level<K, I>(
+        &self,
+        storage: &Storage<K, I>,
+        fragment: &str,
+      ) -> StorageResult<Option<KeyAttestationLevel>>
+      where
+        K: JwkStorageAttestationExt,
+        I: KeyIdStorage,
+      {
+        attestation_level(AsRef::<CoreDocument>::as_ref(self), storage, fragment).await
+      }
+    }
+  };
+}
+
+key_attestation_document_ext_for_document_type!(CoreDocument);
+#[cfg(feature = "iota-document")]
+key_attestation_document_ext_for_document_type!(identity_iota_core::IotaDocument);
@@ -0,0 +1,298 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::age;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_verification::jwk::SecretJwk;
+use identity_verification::MethodData;
+use identity_verification::VerificationMethod;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::Serializer;
+use zeroize::Zeroizing;
+
+use super::JwkStorageDocumentError as Error;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::JwkStorageExportExt;
+use crate::key_storage::KeyId;
+
+/// Magic bytes used to mark encrypted key backups.
+const KEY_BACKUP_MARKER: &[u8] = b"IKB";
+
+/// Version of the key backup envelope.
+const KEY_BACKUP_VERSION_V1: u8 = 1;
+
+/// Options for [`Storage::export_keys`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBackupOptions {
+  /// The scrypt work factor used to derive the encryption key from the password.
+  ///
+  /// Defaults to [`KeyBackupOptions::DEFAULT_WORK_FACTOR`] and must not exceed
+  /// [`KeyBackupOptions::MAX_WORK_FACTOR`].
+  pub work_factor: u8,
+  /// Custom metadata stored alongside the keys, e.g. the DIDs the keys belong to.
+  pub metadata: Object,
+}
+
+impl KeyBackupOptions {
+  /// The default work factor, as recommended for password based encryption with `age`.
+  pub const DEFAULT_WORK_FACTOR: u8 = age::RECOMMENDED_MINIMUM_ENCRYPT_WORK_FACTOR;
+
+  /// The maximum work factor, above which [`Storage::import_keys`] refuses to decrypt a backup.
+  pub const MAX_WORK_FACTOR: u8 = age::RECOMMENDED_MAXIMUM_DECRYPT_WORK_FACTOR;
+
+  /// Replace the value of the `work_factor` field.
+  ///
+  /// [`Storage::export_keys`] fails if it exceeds [`KeyBackupOptions::MAX_WORK_FACTOR`], as the backup could not be
+  /// imported again.
+  pub fn work_factor(mut self, work_factor: u8) -> Self {
+    self.work_factor = work_factor;
+    self
+  }
+
+  /// Replace the value of the `metadata` field.
+  pub fn metadata(mut self, metadata: Object) -> Self {
+    self.metadata = metadata;
+    self
+  }
+}
+
+impl Default for KeyBackupOptions {
+  fn default() -> Self {
+    Self {
+      work_factor: Self::DEFAULT_WORK_FACTOR,
+      metadata: Object::new(),
+    }
+  }
+}
+
+/// A single key in a [`KeyBackup`] together with the verification method it is bound to.
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KeyBackupEntry {
+  method: VerificationMethod,
+  #[serde(serialize_with = "serialize_secret_jwk")]
+  private_key_jwk: SecretJwk,
+}
+
+/// Serializes the private key of a [`KeyBackupEntry`], which is only ever written to the zeroized plaintext of an
+/// [`EncryptedKeyBackup`].
+fn serialize_secret_jwk<S: Serializer>(jwk: &SecretJwk, serializer: S) -> Result<S::Ok, S::Error> {
+  jwk.expose_secret().serialize(serializer)
+}
+
+/// The plaintext contents of an [`EncryptedKeyBackup`].
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KeyBackup {
+  created: Timestamp,
+  entries: Vec<KeyBackupEntry>,
+  #[serde(default, skip_serializing_if = "Object::is_empty")]
+  metadata: Object,
+}
+
+/// A password-encrypted, versioned backup of key material and the verification methods it is bound to.
+///
+/// Created with [`Storage::export_keys`] and restored with [`Storage::import_keys`], which allows keys to be
+/// migrated between different [`JwkStorage`] and [`KeyIdStorage`] implementations.
+///
+/// The layout is `[marker, version, ciphertext]`, where the ciphertext is an `age` encrypted JSON document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedKeyBackup(Vec<u8>);
+
+impl EncryptedKeyBackup {
+  /// Parses an encrypted key backup from its byte representation, checking the marker and version.
+  ///
+  /// This does not decrypt the backup.
+  pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> StorageResult<Self> {
+    let bytes: Vec<u8> = bytes.into();
+    if bytes.get(0..KEY_BACKUP_MARKER.len()) != Some(KEY_BACKUP_MARKER) {
+      return Err(Error::KeyBackupError("missing `IKB` marker", None));
+    }
+    if bytes.get(KEY_BACKUP_MARKER.len()) != Some(&KEY_BACKUP_VERSION_V1) {
+      return Err(Error::KeyBackupError("unsupported version", None));
+    }
+    Ok(Self(bytes))
+  }
+
+  /// Returns the version of the backup envelope.
+  pub fn version(&self) -> u8 {
+    self.0[KEY_BACKUP_MARKER.len()]
+  }
+
+  /// Returns the byte representation of the backup.
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.0
+  }
+
+  /// Consumes the backup, returning its byte representation.
+  pub fn into_bytes(self) -> Vec<u8> {
+    self.0
+  }
+
+  fn encrypt(backup: &KeyBackup, password: &[u8], work_factor: u8) -> StorageResult<Self> {
+    if work_factor > KeyBackupOptions::MAX_WORK_FACTOR {
+      return Err(Error::KeyBackupError(
+        "work factor exceeds the maximum supported for decryption",
+        None,
+      ));
+    }
+    let work_factor: age::WorkFactor = age::WorkFactor::try_from(work_factor)
+      .map_err(|err| Error::KeyBackupError("invalid work factor", Some(crypto::Error::from(err).into())))?;
+    let plaintext: Zeroizing<Vec<u8>> = backup
+      .to_json_vec()
+      .map(Zeroizing::new)
+      .map_err(|err| Error::KeyBackupError("failed to serialize backup", Some(err.into())))?;
+    let ciphertext: Vec<u8> = age::encrypt_vec(password, work_factor, &plaintext)
+      .map_err(|err| Error::KeyBackupError("failed to encrypt backup", Some(crypto::Error::from(err).into())))?;
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(KEY_BACKUP_MARKER.len() + 1 + ciphertext.len());
+    bytes.extend_from_slice(KEY_BACKUP_MARKER);
+    bytes.push(KEY_BACKUP_VERSION_V1);
+    bytes.extend_from_slice(&ciphertext);
+    Ok(Self(bytes))
+  }
+
+  fn decrypt(&self, password: &[u8]) -> StorageResult<KeyBackup> {
+    let ciphertext: &[u8] = &self.0[KEY_BACKUP_MARKER.len() + 1..];
+    let plaintext: Zeroizing<Vec<u8>> = age::decrypt_vec(password, KeyBackupOptions::MAX_WORK_FACTOR, ciphertext)
+      .map(Zeroizing::new)
+      .map_err(|err| Error::KeyBackupError("failed to decrypt backup", Some(crypto::Error::from(err).into())))?;
+    KeyBackup::from_json_slice(&plaintext)
+      .map_err(|err| Error::KeyBackupError("failed to deserialize backup", Some(err.into())))
+  }
+}
+
+impl<K, I> Storage<K, I>
+where
+  K: JwkStorageExportExt,
+  I: KeyIdStorage,
+{
+  /// Export the key material of the given verification `methods` into a backup encrypted with `password`.
+  ///
+  /// Every method must be a `publicKeyJwk` method whose key is held by this storage.
+  pub async fn export_keys<'method>(
+    &self,
+    methods: impl IntoIterator<Item = &'method VerificationMethod>,
+    password: &[u8],
+    options: &KeyBackupOptions,
+  ) -> StorageResult<EncryptedKeyBackup> {
+    let mut entries: Vec<KeyBackupEntry> = Vec::new();
+    for method in methods {
+      if !matches!(method.data(), MethodData::PublicKeyJwk(_)) {
+        return Err(Error::NotPublicKeyJwk);
+      }
+      let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+      let key_id: KeyId = self
+        .key_id_storage()
+        .get_key_id(&method_digest)
+        .await
+        .map_err(Error::KeyIdStorageError)?;
+      let private_key: SecretJwk = self
+        .key_storage()
+        .export(&key_id)
+        .await
+        .map_err(Error::KeyStorageError)?;
+
+      entries.push(KeyBackupEntry {
+        method: method.clone(),
+        private_key_jwk: private_key,
+      });
+    }
+
+    let backup: KeyBackup = KeyBackup {
+      created: Timestamp::now_utc(),
+      entries,
+      metadata: options.metadata.clone(),
+    };
+    EncryptedKeyBackup::encrypt(&backup, password, options.work_factor)
+  }
+}
+
+impl<K, I> Storage<K, I>
+where
+  K: JwkStorage,
+  I: KeyIdStorage,
+{
+  /// Decrypt `backup` with `password` and insert its keys and key id bindings into this storage.
+  ///
+  /// Returns the custom metadata of the backup. Either all keys are imported or, if an error occurs, the keys
+  /// imported so far are removed again.
+  pub async fn import_keys(&self, backup: &EncryptedKeyBackup, password: &[u8]) -> StorageResult<Object> {
+    let KeyBackup { entries, metadata, .. } = backup.decrypt(password)?;
+
+    let mut imported: Vec<(MethodDigest, KeyId)> = Vec::with_capacity(entries.len());
+    for KeyBackupEntry {
+      method,
+      private_key_jwk,
+    } in entries
+    {
+      match self.import_key(&method, &private_key_jwk).await {
+        Ok(binding) => imported.push(binding),
+        Err(error) => return Err(self.try_undo_key_import(imported, error).await),
+      }
+    }
+
+    Ok(metadata)
+  }
+
+  async fn import_key(
+    &self,
+    method: &VerificationMethod,
+    private_key_jwk: &SecretJwk,
+  ) -> StorageResult<(MethodDigest, KeyId)> {
+    let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+    let key_id: KeyId = self
+      .key_storage()
+      .insert(private_key_jwk.expose_secret().clone())
+      .await
+      .map_err(Error::KeyStorageError)?;
+
+    if let Err(error) = self
+      .key_id_storage()
+      .insert_key_id(method_digest.clone(), key_id.clone())
+      .await
+    {
+      let error: Error = Error::KeyIdStorageError(error);
+      return match self.key_storage().delete(&key_id).await {
+        Ok(()) => Err(error),
+        Err(undo_error) => Err(Error::UndoOperationFailed {
+          message: format!("unable to delete imported key with key id: {key_id}"),
+          source: Box::new(error),
+          undo_error: Some(Box::new(Error::KeyStorageError(undo_error))),
+        }),
+      };
+    }
+
+    Ok((method_digest, key_id))
+  }
+
+  async fn try_undo_key_import(&self, imported: Vec<(MethodDigest, KeyId)>, error: Error) -> Error {
+    for (method_digest, key_id) in imported {
+      let key_id_deletion = self.key_id_storage().delete_key_id(&method_digest).await;
+      let key_deletion = self.key_storage().delete(&key_id).await;
+      if let Err(undo_error) = key_id_deletion {
+        return Error::UndoOperationFailed {
+          message: format!("unable to remove imported key id: {key_id}"),
+          source: Box::new(error),
+          undo_error: Some(Box::new(Error::KeyIdStorageError(undo_error))),
+        };
+      }
+      if let Err(undo_error) = key_deletion {
+        return Error::UndoOperationFailed {
+          message: format!("unable to delete imported key with key id: {key_id}"),
+          source: Box::new(error),
+          undo_error: Some(Box::new(Error::KeyStorageError(undo_error))),
+        };
+      }
+    }
+    error
+  }
+}
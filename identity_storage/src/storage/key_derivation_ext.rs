@@ -0,0 +1,264 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodData;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+
+use super::try_undo_key_generation;
+use super::JwkStorageDocumentError as Error;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::JwkGenOutput;
+use crate::key_storage::JwkStorageDerivationExt;
+use crate::key_storage::KeyDerivationPath;
+use crate::key_storage::KeyStorageResult;
+use crate::key_storage::KeyType;
+
+/// Extension trait for verification methods whose keys are derived deterministically from the seed of a
+/// [`JwkStorageDerivationExt`], e.g. one recovered from a BIP39 mnemonic.
+///
+/// The [`KeyDerivationPath`] of a derived key is recorded in the [`KeyIdStorage`] as part of its key id. Should the
+/// key storage be lost, the keys of the methods can be recovered with
+/// [`KeyDerivationDocumentExt::recover_derived_method`] from the seed phrase alone.
+///
+/// This trait is deliberately sealed and cannot be implemented by external crates.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait KeyDerivationDocumentExt: private::Sealed {
+  /// Derive the key at `path` in the given `storage` and insert a new verification method with the corresponding
+  /// public key material into the DID document, as in
+  /// [`JwkDocumentExt::generate_method`](crate::storage::JwkDocumentExt::generate_method).
+  ///
+  /// Fails if the key of another method of the document was already derived at `path`, since purging either method
+  /// would delete the key of both. Use distinct paths, e.g. through [`KeyDerivationPath::child`].
+  #[allow(clippy::too_many_arguments)]
+  async fn generate_derived_method<K, I>(
+    &mut self,
+    storage: &Storage<K, I>,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+    path: &KeyDerivationPath,
+    fragment: Option<&str>,
+    scope: MethodScope,
+  ) -> StorageResult<String>
+  where
+    K: JwkStorageDerivationExt,
+    I: KeyIdStorage;
+
+  /// Re-derive the key of the method identified by `fragment` at `path` in the given `storage`, e.g. after restoring
+  /// the seed from its mnemonic, and record the mapping from the method to the key in the [`KeyIdStorage`].
+  ///
+  /// Fails if the re-derived key does not match the public key material of the method.
+  async fn recover_derived_method<K, I>(
+    &self,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    key_type: KeyType,
+    path: &KeyDerivationPath,
+  ) -> StorageResult<()>
+  where
+    K: JwkStorageDerivationExt,
+    I: KeyIdStorage;
+
+  /// Returns the path the key of the method identified by `fragment` was derived at, or `None` if the key was not
+  /// derived.
+  async fn derivation_path<K, I>(
+    &self,
+    storage: &Storage<K, I>,
+    fragment: &str,
+  ) -> StorageResult<Option<KeyDerivationPath>>
+  where
+    K: JwkStorageDerivationExt,
+    I: KeyIdStorage;
+}
+
+mod private {
+  pub trait Sealed {}
+  impl Sealed for identity_document::document::CoreDocument {}
+  #[cfg(feature = "iota-document")]
+  impl Sealed for identity_iota_core::IotaDocument {}
+}
+
+// ====================================================================================================================
+// Implementation
+// ====================================================================================================================
+
+/// Adapts [`JwkStorageDerivationExt::derive`] to the signature expected by `generate_method_for_document_type`.
+async fn derive_key<K>(
+  key_storage: &K,
+  key_type: KeyType,
+  (alg, path): (JwsAlgorithm, &KeyDerivationPath),
+) -> KeyStorageResult<JwkGenOutput>
+where
+  K: JwkStorageDerivationExt,
+{
+  key_storage.derive(key_type, alg, path).await
+}
+
+generate_method_for_document_type!(
+  CoreDocument,
+  (JwsAlgorithm, &KeyDerivationPath),
+  JwkStorageDerivationExt,
+  derive_key,
+  generate_derived_method_core_document
+);
+
+#[cfg(feature = "iota-document")]
+generate_method_for_document_type!(
+  identity_iota_core::IotaDocument,
+  (JwsAlgorithm, &KeyDerivationPath),
+  JwkStorageDerivationExt,
+  derive_key,
+  generate_derived_method_iota_document
+);
+
+/// Returns the derivation path recorded in the [`KeyIdStorage`] for `method`, if any.
+async fn method_derivation_path<K, I>(
+  storage: &Storage<K, I>,
+  method: &VerificationMethod,
+) -> StorageResult<Option<KeyDerivationPath>>
+where
+  I: KeyIdStorage,
+{
+  let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+  let key_id = storage
+    .key_id_storage()
+    .get_key_id(&method_digest)
+    .await
+    .map_err(Error::KeyIdStorageError)?;
+
+  Ok(KeyDerivationPath::from_key_id(&key_id))
+}
+
+/// Ensures no method of `document` has a key derived at `path`.
+async fn ensure_path_unused<K, I>(
+  document: &CoreDocument,
+  storage: &Storage<K, I>,
+  path: &KeyDerivationPath,
+) -> StorageResult<()>
+where
+  I: KeyIdStorage,
+{
+  for method in document.methods(None) {
+    // Methods without a key in storage cannot collide.
+    if let Ok(Some(method_path)) = method_derivation_path(storage, method).await {
+      if &method_path == path {
+        return Err(Error::KeyDerivationError(
+          "the key of another method was already derived at this path",
+        ));
+      }
+    }
+  }
+  Ok(())
+}
+
+async fn recover_derived_method<K, I>(
+  document: &CoreDocument,
+  storage: &Storage<K, I>,
+  fragment: &str,
+  key_type: KeyType,
+  path: &KeyDerivationPath,
+) -> StorageResult<()>
+where
+  K: JwkStorageDerivationExt,
+  I: KeyIdStorage,
+{
+  let method: &VerificationMethod = document.resolve_method(fragment, None).ok_or(Error::MethodNotFound)?;
+  let MethodData::PublicKeyJwk(ref public_key) = method.data() else {
+    return Err(Error::NotPublicKeyJwk);
+  };
+  let alg: JwsAlgorithm = public_key
+    .alg()
+    .unwrap_or("")
+    .parse()
+    .map_err(|_| Error::InvalidJwsAlgorithm)?;
+
+  let JwkGenOutput { key_id, jwk } = storage
+    .key_storage()
+    .derive(key_type, alg, path)
+    .await
+    .map_err(Error::KeyStorageError)?;
+
+  if jwk.thumbprint_sha256_b64() != public_key.thumbprint_sha256_b64() {
+    let error = Error::KeyDerivationError("the derived key does not match the public key of the method");
+    return Err(try_undo_key_generation(storage, &key_id, error).await);
+  }
+
+  let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+  // The mapping is still intact if only the key storage was lost.
+  if let Ok(existing_key_id) = storage.key_id_storage().get_key_id(&method_digest).await {
+    if existing_key_id == key_id {
+      return Ok(());
+    }
+  }
+  storage
+    .key_id_storage()
+    .insert_key_id(method_digest, key_id)
+    .await
+    .map_err(Error::KeyIdStorageError)
+}
+
+macro_rules! key_derivation_document_ext_for_document_type {
+  ($t:ty, $generate:ident) => {
+    #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync-storage", async_trait)]
+    impl KeyDerivationDocumentExt for $t {
+      async fn generate_derived_method<K, I>(
+        &mut self,
+        storage: &Storage<K, I>,
+        key_type: KeyType,
+        alg: JwsAlgorithm,
+        path: &KeyDerivationPath,
+        fragment: Option<&str>,
+        scope: MethodScope,
+      ) -> StorageResult<String>
+      where
+        K: JwkStorageDerivationExt,
+        I: KeyIdStorage,
+      {
+        ensure_path_unused(AsRef::<CoreDocument>::as_ref(self), storage, path).await?;
+        $generate(self, storage, key_type, (alg, path), fragment, scope).await
+      }
+
+      async fn recover_derived_method<K, I>(
+        &self,
+        storage: &Storage<K, I>,
+        fragment: &str,
+        key_type: KeyType,
+        path: &KeyDerivationPath,
+      ) -> StorageResult<()>
+      where
+        K: JwkStorageDerivationExt,
+        I: KeyIdStorage,
+      {
+        recover_derived_method(AsRef::<CoreDocument>::as_ref(self), storage, fragment, key_type, path).await
+      }
+
+      async fn derivation_path<K, I>(
+        &self,
+        storage: &Storage<K, I>,
+        fragment: &str,
+      ) -> StorageResult<Option<KeyDerivationPath>>
+      where
+        K: JwkStorageDerivationExt,
+        I: KeyIdStorage,
+      {
+        let method: &VerificationMethod = AsRef::<CoreDocument>::as_ref(self)
+          .resolve_method(fragment, None)
+          .ok_or(Error::MethodNotFound)?;
+        method_derivation_path(storage, method).await
+      }
+    }
+  };
+}
+
+key_derivation_document_ext_for_document_type!(CoreDocument, generate_derived_method_core_document);
+#[cfg(feature = "iota-document")]
+key_derivation_document_ext_for_document_type!(identity_iota_core::IotaDocument, generate_derived_method_iota_document);
@@ -0,0 +1,64 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_did::DIDUrl;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodScope;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Name of the custom verification method property marking a retained, rotated method.
+///
+/// The property is advisory only: it is not checked when verifying signatures. Verifiers that want to reject
+/// signatures of expired methods must check it themselves, and controllers should remove the method, e.g. with
+/// [`JwkDocumentExt::purge_method`](crate::storage::JwkDocumentExt::purge_method), once it has expired.
+pub const EXPIRES_PROPERTY: &str = "expires";
+
+/// Determines what happens to the previous verification method when rotating a key with
+/// [`JwkDocumentExt::rotate_method`](crate::storage::JwkDocumentExt::rotate_method).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MethodRotationPolicy {
+  /// Remove the previous method from the document and delete its key material from storage.
+  ///
+  /// This is the default.
+  #[default]
+  Purge,
+  /// Keep the previous method and its key material, marking the method with an
+  /// [`expires`](EXPIRES_PROPERTY) property.
+  ///
+  /// This allows verifiers to keep validating signatures created before the rotation. The expiry is advisory only,
+  /// see [`EXPIRES_PROPERTY`].
+  Retain {
+    /// The time after which the previous method should no longer be considered valid.
+    expires: Timestamp,
+  },
+}
+
+/// Summary of a key rotation performed with
+/// [`JwkDocumentExt::rotate_method`](crate::storage::JwkDocumentExt::rotate_method).
+///
+/// The document has been updated in place and must still be published for the rotation to take effect.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodRotation {
+  /// The identifier of the rotated method.
+  pub previous_method: DIDUrl,
+  /// The identifier of the newly generated method.
+  pub new_method: DIDUrl,
+  /// The scope under which the new method was inserted, identical to that of the previous method.
+  pub scope: MethodScope,
+  /// The verification relationships referencing the previous method that now reference the new one.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub relationships: Vec<MethodRelationship>,
+  /// The policy that was applied to the previous method.
+  pub policy: MethodRotationPolicy,
+}
+
+impl MethodRotation {
+  /// Returns `true` if the previous method is still contained in the document.
+  pub fn previous_method_retained(&self) -> bool {
+    matches!(self.policy, MethodRotationPolicy::Retain { .. })
+  }
+}
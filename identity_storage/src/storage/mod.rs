@@ -3,26 +3,78 @@
 
 //! This module provides a type wrapping a key and key id storage.
 
+mod consent_receipt;
+#[cfg(feature = "controller-key")]
+mod controller_key;
+#[cfg(feature = "iot")]
+mod cose_document_ext;
+#[cfg(feature = "data-integrity")]
+mod data_integrity_ext;
+#[cfg(feature = "domain-linkage")]
+mod domain_linkage_generator;
 mod error;
+#[cfg(feature = "iota-document")]
+mod identity_snapshot;
+mod issuance;
 #[macro_use]
 mod jwk_document_ext;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jwp_document_ext;
+#[cfg(feature = "key-attestation")]
+mod key_attestation_ext;
+#[cfg(feature = "key-backup")]
+mod key_backup;
+#[cfg(feature = "key-derivation")]
+mod key_derivation_ext;
+mod method_rotation;
+mod presentation_response;
+mod scoped_storage;
+#[cfg(feature = "credential-encryption")]
+mod sealed_credential;
 mod signature_options;
+mod signature_presets;
 #[cfg(feature = "jpt-bbs-plus")]
 mod timeframe_revocation_ext;
+#[cfg(feature = "webauthn")]
+mod webauthn_ext;
 
 #[cfg(all(test, feature = "memstore"))]
 pub(crate) mod tests;
 
+pub use consent_receipt::*;
+#[cfg(feature = "controller-key")]
+pub use controller_key::*;
+#[cfg(feature = "iot")]
+pub use cose_document_ext::*;
+#[cfg(feature = "data-integrity")]
+pub use data_integrity_ext::*;
+#[cfg(feature = "domain-linkage")]
+pub use domain_linkage_generator::*;
 pub use error::*;
+#[cfg(feature = "iota-document")]
+pub use identity_snapshot::*;
+pub use issuance::*;
 
 pub use jwk_document_ext::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use jwp_document_ext::*;
+#[cfg(feature = "key-attestation")]
+pub use key_attestation_ext::*;
+#[cfg(feature = "key-backup")]
+pub use key_backup::*;
+#[cfg(feature = "key-derivation")]
+pub use key_derivation_ext::*;
+pub use method_rotation::*;
+pub use presentation_response::*;
+pub use scoped_storage::*;
+#[cfg(feature = "credential-encryption")]
+pub use sealed_credential::*;
 pub use signature_options::*;
+pub use signature_presets::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use timeframe_revocation_ext::*;
+#[cfg(feature = "webauthn")]
+pub use webauthn_ext::*;
 
 /// A type wrapping a key and key id storage, typically used with [`JwkStorage`](crate::key_storage::JwkStorage) and
 /// [`KeyIdStorage`](crate::key_id_storage::KeyIdStorage) that should always be used together when calling methods from
@@ -50,4 +102,9 @@ impl<K, I> Storage<K, I> {
   pub fn key_id_storage(&self) -> &I {
     &self.key_id_storage
   }
+
+  /// Consumes the [`Storage`], returning the wrapped key and key id storage.
+  pub fn into_parts(self) -> (K, I) {
+    (self.key_storage, self.key_id_storage)
+  }
 }
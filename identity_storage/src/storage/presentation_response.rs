@@ -0,0 +1,228 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_credential::credential::Jwt;
+use identity_credential::presentation::JwtPresentationOptions;
+use identity_credential::presentation::Presentation;
+use identity_credential::presentation::PresentationBuilder;
+use identity_credential::presentation::PresentationRequest;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::Decoder;
+use serde_json::Value;
+
+use super::ConsentReceipt;
+use super::ConsentReceiptStorage;
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError as Error;
+use super::JwsSignatureOptions;
+use super::SignedConsentReceipt;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_storage::JwkStorage;
+
+/// Creates the signed presentation JWT responding to a [`PresentationRequest`].
+///
+/// The `nonce` of the request is set in the protected header of the JWT, its audience and expiration date in the
+/// claims, such that the presentation is bound to the verifier's challenge.
+///
+/// # Example
+///
+/// ```ignore
+/// let request: PresentationRequest = PresentationRequest::new(nonce).audience(verifier);
+/// let presentation_jwt: Jwt = PresentationResponseBuilder::new(request)
+///   .select_credentials(wallet_credentials)
+///   .build(&holder_document, &storage, &fragment)
+///   .await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct PresentationResponseBuilder {
+  request: PresentationRequest,
+  credentials: Vec<Jwt>,
+  issuance_date: Option<Timestamp>,
+  signature_options: JwsSignatureOptions,
+}
+
+impl PresentationResponseBuilder {
+  /// Creates a new [`PresentationResponseBuilder`] responding to `request`.
+  pub fn new(request: PresentationRequest) -> Self {
+    Self {
+      request,
+      credentials: Vec::new(),
+      issuance_date: None,
+      signature_options: JwsSignatureOptions::default(),
+    }
+  }
+
+  /// Returns the request the presentation responds to.
+  pub fn request(&self) -> &PresentationRequest {
+    &self.request
+  }
+
+  /// Returns the credentials included in the presentation so far.
+  pub fn credentials(&self) -> &[Jwt] {
+    &self.credentials
+  }
+
+  /// Includes `credential` in the presentation, regardless of the credential types requested.
+  #[must_use]
+  pub fn credential(mut self, credential: Jwt) -> Self {
+    self.credentials.push(credential);
+    self
+  }
+
+  /// Includes the credentials of `candidates` whose types are requested, see [`PresentationRequest::requests`].
+  ///
+  /// The types are read from the credentials without validating them. Candidates that cannot be decoded are skipped.
+  #[must_use]
+  pub fn select_credentials(mut self, candidates: impl IntoIterator<Item = Jwt>) -> Self {
+    let request: &PresentationRequest = &self.request;
+    let selected = candidates.into_iter().filter(|candidate| {
+      credential_types(candidate).is_some_and(|types| request.requests(types.iter().map(String::as_str)))
+    });
+    self.credentials.extend(selected);
+    self
+  }
+
+  /// Includes the SD-JWT `credential` in the presentation, keeping only the disclosures of the claims named in
+  /// `disclosed` and dropping any key binding JWT, as the signature of the presentation binds it to the request.
+  #[cfg(feature = "sd-jwt")]
+  pub fn sd_jwt_credential(
+    mut self,
+    credential: &identity_credential::sd_jwt_payload::SdJwt,
+    disclosed: &[&str],
+  ) -> StorageResult<Self> {
+    use identity_credential::sd_jwt_payload::Disclosure;
+    use identity_credential::sd_jwt_payload::SdJwt;
+
+    let mut disclosures: Vec<String> = Vec::new();
+    for encoded in &credential.disclosures {
+      let disclosure: Disclosure = Disclosure::parse(encoded.clone())
+        .map_err(|err| Error::PresentationResponseError("invalid SD-JWT disclosure", Some(Box::new(err))))?;
+      if disclosure
+        .claim_name
+        .as_deref()
+        .is_some_and(|name| disclosed.contains(&name))
+      {
+        disclosures.push(encoded.clone());
+      }
+    }
+
+    let presented: SdJwt = SdJwt::new(credential.jwt.clone(), disclosures, None);
+    self.credentials.push(Jwt::new(presented.presentation()));
+    Ok(self)
+  }
+
+  /// Sets the issuance date of the presentation. If unset the current time will be used.
+  #[must_use]
+  pub fn issuance_date(mut self, value: Timestamp) -> Self {
+    self.issuance_date = Some(value);
+    self
+  }
+
+  /// Sets the options used when signing the presentation. Their `nonce` is replaced with the one of the request.
+  #[must_use]
+  pub fn signature_options(mut self, value: JwsSignatureOptions) -> Self {
+    self.signature_options = value;
+    self
+  }
+
+  /// Creates the presentation like [`Self::build`] together with a [`ConsentReceipt`] recording that the credentials
+  /// were shared with the audience of the request for `purpose`.
+  ///
+  /// The receipt is signed with the same verification method as the presentation and stored in `receipts`.
+  pub async fn build_with_consent_receipt<D, K, I, R>(
+    &self,
+    document: &D,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    purpose: &str,
+    receipts: &R,
+  ) -> StorageResult<(Jwt, SignedConsentReceipt)>
+  where
+    D: JwkDocumentExt + AsRef<CoreDocument>,
+    K: JwkStorage,
+    I: KeyIdStorage,
+    R: ConsentReceiptStorage,
+  {
+    let presentation_jwt: Jwt = self.build(document, storage, fragment).await?;
+
+    let receipt: SignedConsentReceipt = ConsentReceipt::new(
+      document.as_ref().id().clone(),
+      self.request.audience.clone(),
+      purpose,
+      &self.credentials,
+      self.issuance_date.unwrap_or_else(Timestamp::now_utc),
+    )
+    .sign(document, storage, fragment)
+    .await?;
+    receipts.insert_receipt(receipt.clone()).await?;
+
+    Ok((presentation_jwt, receipt))
+  }
+
+  /// Creates the presentation of the selected credentials held by `document`, signed with the verification method
+  /// identified by `fragment`.
+  pub async fn build<D, K, I>(&self, document: &D, storage: &Storage<K, I>, fragment: &str) -> StorageResult<Jwt>
+  where
+    D: JwkDocumentExt + AsRef<CoreDocument>,
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    if self.credentials.is_empty() {
+      return Err(Error::PresentationResponseError("no credentials selected", None));
+    }
+
+    let holder: Url = document.as_ref().id().to_url().into();
+    let presentation: Presentation<Jwt> = self
+      .credentials
+      .iter()
+      .cloned()
+      .fold(
+        PresentationBuilder::new(holder, Object::new()),
+        PresentationBuilder::credential,
+      )
+      .build()
+      .map_err(|err| Error::PresentationResponseError("invalid presentation", Some(Box::new(err))))?;
+
+    let mut presentation_options: JwtPresentationOptions = JwtPresentationOptions::default();
+    presentation_options.issuance_date = self.issuance_date.or(presentation_options.issuance_date);
+    presentation_options.expiration_date = self.request.expiration_date;
+    presentation_options.audience = self.request.audience.clone();
+
+    let signature_options: JwsSignatureOptions = self.signature_options.clone().nonce(self.request.nonce.clone());
+
+    document
+      .create_presentation_jwt(
+        &presentation,
+        storage,
+        fragment,
+        &signature_options,
+        &presentation_options,
+      )
+      .await
+  }
+}
+
+/// Reads the types of the credential in `jwt`, which may also be the issuer-signed JWT of an SD-JWT, without
+/// verifying its signature.
+fn credential_types(jwt: &Jwt) -> Option<Vec<String>> {
+  match credential_claims(jwt)?.get("vc")?.get("type")? {
+    Value::String(type_) => Some(vec![type_.clone()]),
+    Value::Array(types) => Some(types.iter().filter_map(Value::as_str).map(ToOwned::to_owned).collect()),
+    _ => None,
+  }
+}
+
+/// Reads the claims of the credential in `jwt`, which may also be the issuer-signed JWT of an SD-JWT, without
+/// verifying its signature.
+pub(super) fn credential_claims(jwt: &Jwt) -> Option<Value> {
+  let token: &str = jwt.as_str().split('~').next()?;
+  let decoded = Decoder::new()
+    .decode_compact_serialization(token.as_bytes(), None)
+    .ok()?;
+  serde_json::from_slice(decoded.claims()).ok()
+}
@@ -0,0 +1,72 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::JwkStorageDocumentError as Error;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorageNamespaceExt;
+use crate::key_id_storage::MethodDigest;
+use crate::key_id_storage::Namespace;
+use crate::key_id_storage::ScopedKeyIdStorage;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::key_storage::ScopedJwkStorage;
+
+/// A [`Storage`] restricted to the keys and key ids of a single [`Namespace`].
+pub type ScopedStorage<'storage, K, I> = Storage<ScopedJwkStorage<'storage, K>, ScopedKeyIdStorage<'storage, I>>;
+
+impl<K, I> Storage<K, I>
+where
+  K: JwkStorage,
+  I: KeyIdStorageNamespaceExt,
+{
+  /// Returns a [`Storage`] whose keys and key ids are isolated in `namespace`, e.g. to serve one of many tenants
+  /// from shared storage backends.
+  ///
+  /// The returned storage can be used with all methods of [`JwkDocumentExt`](crate::storage::JwkDocumentExt).
+  pub fn scoped(&self, namespace: Namespace) -> ScopedStorage<'_, K, I> {
+    Storage::new(
+      ScopedJwkStorage::new(self.key_storage(), namespace.clone()),
+      ScopedKeyIdStorage::new(self.key_id_storage(), namespace),
+    )
+  }
+
+  /// Returns the method digests and key ids stored in `namespace`.
+  ///
+  /// The key ids are those returned by the [`ScopedJwkStorage`] of `namespace`.
+  pub async fn key_ids_in(&self, namespace: &Namespace) -> StorageResult<Vec<(MethodDigest, KeyId)>> {
+    self
+      .key_id_storage()
+      .key_ids_in(namespace)
+      .await
+      .map_err(Error::KeyIdStorageError)
+  }
+
+  /// Deletes all keys and key ids stored in `namespace`, returning the number of deleted entries.
+  ///
+  /// Keys that no longer exist in the key storage are skipped.
+  ///
+  /// # Warning
+  ///
+  /// This operation cannot be undone. The keys are purged permanently.
+  pub async fn delete_namespace(&self, namespace: &Namespace) -> StorageResult<usize> {
+    let scoped: ScopedStorage<'_, K, I> = self.scoped(namespace.clone());
+    let entries: Vec<(MethodDigest, KeyId)> = self.key_ids_in(namespace).await?;
+
+    for (method_digest, key_id) in entries.iter() {
+      match scoped.key_storage().delete(key_id).await {
+        Ok(()) => (),
+        Err(error) if matches!(error.kind(), KeyStorageErrorKind::KeyNotFound) => (),
+        Err(error) => return Err(Error::KeyStorageError(error)),
+      }
+      self
+        .key_id_storage()
+        .delete_key_id_in(namespace, method_digest)
+        .await
+        .map_err(Error::KeyIdStorageError)?;
+    }
+
+    Ok(entries.len())
+  }
+}
@@ -0,0 +1,239 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+
+use crypto::ciphers::chacha::XChaCha20Poly1305;
+use crypto::ciphers::traits::Aead;
+use identity_credential::credential::Credential;
+use identity_verification::jwu;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use zeroize::Zeroizing;
+
+use super::JwkStorageDocumentError as Error;
+use super::StorageResult;
+
+const KEY_LENGTH: usize = XChaCha20Poly1305::KEY_LENGTH;
+
+/// A symmetric key encrypting the sensitive fields of [`SealedCredential`]s.
+///
+/// The key is zeroized on drop. Keeping it apart from the sealed credentials, e.g. in the secure storage of the
+/// platform, protects their sensitive fields even if the database holding the credentials leaks.
+pub struct CredentialEncryptionKey(Zeroizing<[u8; KEY_LENGTH]>);
+
+impl CredentialEncryptionKey {
+  /// The length of the key in bytes.
+  pub const LENGTH: usize = KEY_LENGTH;
+
+  /// Generates a new random key.
+  pub fn generate() -> StorageResult<Self> {
+    let mut key: Zeroizing<[u8; Self::LENGTH]> = Zeroizing::new([0; Self::LENGTH]);
+    crypto::utils::rand::fill(&mut key[..])
+      .map_err(|err| Error::CredentialEncryptionError("failed to generate key", Some(err.into())))?;
+    Ok(Self(key))
+  }
+
+  /// Creates a key from its byte representation.
+  pub fn from_bytes(bytes: [u8; Self::LENGTH]) -> Self {
+    Self(Zeroizing::new(bytes))
+  }
+
+  /// Returns the byte representation of the key.
+  pub fn as_bytes(&self) -> &[u8; Self::LENGTH] {
+    &self.0
+  }
+}
+
+impl Debug for CredentialEncryptionKey {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str("CredentialEncryptionKey(..)")
+  }
+}
+
+/// A subject field of a [`SealedCredential`] encrypted with XChaCha20-Poly1305.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SealedField {
+  /// The index of the subject the field belongs to.
+  subject: usize,
+  /// The name of the field.
+  name: String,
+  /// The base64url encoded nonce.
+  nonce: String,
+  /// The base64url encoded ciphertext followed by the authentication tag.
+  ciphertext: String,
+}
+
+impl SealedField {
+  fn seal(subject: usize, name: &str, value: &Value, key: &CredentialEncryptionKey) -> StorageResult<Self> {
+    let plaintext: Zeroizing<Vec<u8>> = serde_json::to_vec(value)
+      .map(Zeroizing::new)
+      .map_err(|err| Error::CredentialEncryptionError("failed to serialize field", Some(err.into())))?;
+
+    let mut nonce: [u8; XChaCha20Poly1305::NONCE_LENGTH] = [0; XChaCha20Poly1305::NONCE_LENGTH];
+    crypto::utils::rand::fill(&mut nonce)
+      .map_err(|err| Error::CredentialEncryptionError("failed to generate nonce", Some(err.into())))?;
+
+    let mut ciphertext: Vec<u8> = vec![0; plaintext.len() + XChaCha20Poly1305::TAG_LENGTH];
+    let (encrypted, tag) = ciphertext.split_at_mut(plaintext.len());
+    XChaCha20Poly1305::try_encrypt(
+      key.as_bytes(),
+      &nonce,
+      Self::associated_data(subject, name).as_bytes(),
+      &plaintext,
+      encrypted,
+      tag,
+    )
+    .map_err(|err| Error::CredentialEncryptionError("failed to encrypt field", Some(err.into())))?;
+
+    Ok(Self {
+      subject,
+      name: name.to_owned(),
+      nonce: jwu::encode_b64(nonce),
+      ciphertext: jwu::encode_b64(ciphertext),
+    })
+  }
+
+  fn unseal(&self, key: &CredentialEncryptionKey) -> StorageResult<Value> {
+    let nonce: Vec<u8> = jwu::decode_b64(&self.nonce)
+      .map_err(|err| Error::CredentialEncryptionError("invalid nonce encoding", Some(err.into())))?;
+    let ciphertext: Vec<u8> = jwu::decode_b64(&self.ciphertext)
+      .map_err(|err| Error::CredentialEncryptionError("invalid ciphertext encoding", Some(err.into())))?;
+    let Some(plaintext_length) = ciphertext.len().checked_sub(XChaCha20Poly1305::TAG_LENGTH) else {
+      return Err(Error::CredentialEncryptionError("ciphertext is too short", None));
+    };
+
+    let (encrypted, tag) = ciphertext.split_at(plaintext_length);
+    let mut plaintext: Zeroizing<Vec<u8>> = Zeroizing::new(vec![0; plaintext_length]);
+    XChaCha20Poly1305::try_decrypt(
+      key.as_bytes(),
+      &nonce,
+      Self::associated_data(self.subject, &self.name).as_bytes(),
+      &mut plaintext,
+      encrypted,
+      tag,
+    )
+    .map_err(|err| Error::CredentialEncryptionError("failed to decrypt field", Some(err.into())))?;
+
+    serde_json::from_slice(&plaintext)
+      .map_err(|err| Error::CredentialEncryptionError("failed to deserialize field", Some(err.into())))
+  }
+
+  /// Binds the ciphertext to the position of the field, so that sealed fields cannot be swapped.
+  fn associated_data(subject: usize, name: &str) -> String {
+    format!("{subject}/{name}")
+  }
+}
+
+/// A credential whose sensitive subject fields are encrypted at rest.
+///
+/// The sensitive fields are removed from the subjects of the credential and encrypted individually with a
+/// [`CredentialEncryptionKey`]. All other fields remain readable, e.g. to list the credentials of a wallet, through
+/// [`SealedCredential::redacted`]. The full credential is only restored by an explicit call to
+/// [`SealedCredential::unlock`].
+///
+/// Only decoded credentials can be sealed: the fields of a credential issued as a JWT are covered by the issuer's
+/// signature, so such a credential has to be stored in its entirety.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SealedCredential {
+  credential: Value,
+  sealed_fields: Vec<SealedField>,
+}
+
+impl SealedCredential {
+  /// Seals `credential`, encrypting the `sensitive_fields` of its subjects with `key`.
+  ///
+  /// Fields that are not present in a subject are ignored. The `id` of a subject cannot be sealed.
+  pub fn seal<T>(
+    credential: &Credential<T>,
+    sensitive_fields: &[&str],
+    key: &CredentialEncryptionKey,
+  ) -> StorageResult<Self>
+  where
+    T: Serialize,
+  {
+    if sensitive_fields.contains(&"id") {
+      return Err(Error::CredentialEncryptionError(
+        "the subject id cannot be sealed",
+        None,
+      ));
+    }
+
+    let mut credential: Value = serde_json::to_value(credential)
+      .map_err(|err| Error::CredentialEncryptionError("failed to serialize credential", Some(err.into())))?;
+    let mut sealed_fields: Vec<SealedField> = Vec::new();
+
+    for (index, subject) in subjects_mut(&mut credential).into_iter().enumerate() {
+      for name in sensitive_fields {
+        if let Some(value) = subject.remove(*name) {
+          sealed_fields.push(SealedField::seal(index, name, &value, key)?);
+        }
+      }
+    }
+
+    Ok(Self {
+      credential,
+      sealed_fields,
+    })
+  }
+
+  /// Returns the names of the sealed fields of each subject, identified by its index.
+  pub fn sealed_fields(&self) -> impl Iterator<Item = (usize, &str)> + '_ {
+    self
+      .sealed_fields
+      .iter()
+      .map(|field| (field.subject, field.name.as_str()))
+  }
+
+  /// Returns the credential without its sealed fields.
+  pub fn redacted<T>(&self) -> StorageResult<Credential<T>>
+  where
+    T: DeserializeOwned,
+  {
+    Self::deserialize_credential(self.credential.clone())
+  }
+
+  /// Decrypts the sealed fields with `key` and returns the full credential.
+  ///
+  /// # Errors
+  /// Fails if `key` is not the key the credential was sealed with, or if a sealed field has been tampered with.
+  pub fn unlock<T>(&self, key: &CredentialEncryptionKey) -> StorageResult<Credential<T>>
+  where
+    T: DeserializeOwned,
+  {
+    let mut credential: Value = self.credential.clone();
+    let mut subjects: Vec<&mut Map<String, Value>> = subjects_mut(&mut credential);
+    for field in self.sealed_fields.iter() {
+      let value: Value = field.unseal(key)?;
+      subjects
+        .get_mut(field.subject)
+        .ok_or_else(|| Error::CredentialEncryptionError("sealed field of an unknown subject", None))?
+        .insert(field.name.clone(), value);
+    }
+
+    Self::deserialize_credential(credential)
+  }
+
+  fn deserialize_credential<T>(credential: Value) -> StorageResult<Credential<T>>
+  where
+    T: DeserializeOwned,
+  {
+    serde_json::from_value(credential)
+      .map_err(|err| Error::CredentialEncryptionError("failed to deserialize credential", Some(err.into())))
+  }
+}
+
+/// Returns the subjects of a serialized credential, whose `credentialSubject` is either a single object or an array.
+fn subjects_mut(credential: &mut Value) -> Vec<&mut Map<String, Value>> {
+  match credential.get_mut("credentialSubject") {
+    Some(Value::Array(subjects)) => subjects.iter_mut().filter_map(Value::as_object_mut).collect(),
+    Some(Value::Object(subject)) => vec![subject],
+    _ => Vec::new(),
+  }
+}
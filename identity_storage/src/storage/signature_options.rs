@@ -57,6 +57,12 @@ pub struct JwsSignatureOptions {
   /// [More Info](https://www.rfc-editor.org/rfc/rfc7515#appendix-F).
   pub detached_payload: bool,
 
+  /// Header parameters to be listed as critical in the protected header, in addition to `b64` if it is disabled.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc7515#section-4.1.11)
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub crit: Option<Vec<String>>,
+
   /// Additional header parameters.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub custom_header_parameters: Option<Object>,
@@ -116,9 +122,38 @@ impl JwsSignatureOptions {
     self
   }
 
+  /// Replace the value of the `crit` field.
+  pub fn crit(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.crit = Some(value.into_iter().map(Into::into).collect());
+    self
+  }
+
   /// Adds additional header parameters.
   pub fn custom_header_parameters(mut self, value: Object) -> Self {
     self.custom_header_parameters = Some(value);
     self
   }
 }
+
+/// Presets of [`JwsSignatureOptions`] for common credential profiles.
+///
+/// All presets use the id of the signing method as `kid`.
+impl JwsSignatureOptions {
+  /// Options for credentials and presentations encoded as JWTs, as defined by the
+  /// [VC Data Model v1.1](https://www.w3.org/TR/vc-data-model/#json-web-token).
+  pub fn vc_jwt_1_1() -> Self {
+    Self::new().typ("JWT")
+  }
+
+  /// Options for the issuer-signed JWT of an SD-JWT VC, as defined by
+  /// [SD-JWT VC draft 04](https://www.ietf.org/archive/id/draft-ietf-oauth-sd-jwt-vc-04.html#section-3.2.1).
+  pub fn sd_jwt_vc_draft_04() -> Self {
+    Self::new().typ("vc+sd-jwt")
+  }
+
+  /// Options for the Key Binding JWT of an SD-JWT, as defined by
+  /// [SD-JWT](https://www.ietf.org/archive/id/draft-ietf-oauth-selective-disclosure-jwt-07.html#section-5.3).
+  pub fn kb_jwt() -> Self {
+    Self::new().typ("kb+jwt")
+  }
+}
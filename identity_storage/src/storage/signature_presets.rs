@@ -0,0 +1,68 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use super::JwsSignatureOptions;
+
+/// A registry of named [`JwsSignatureOptions`], allowing applications to configure the options for each credential
+/// profile once and reference them by name when issuing.
+///
+/// [`JwsSignaturePresets::new`] registers the presets of [`JwsSignatureOptions`] under the names of the associated
+/// constants, e.g. [`JwsSignaturePresets::VC_JWT_1_1`]. Custom presets can be added with
+/// [`JwsSignaturePresets::register`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JwsSignaturePresets {
+  presets: HashMap<String, JwsSignatureOptions>,
+}
+
+impl JwsSignaturePresets {
+  /// The name of the [`JwsSignatureOptions::vc_jwt_1_1`] preset.
+  pub const VC_JWT_1_1: &'static str = "VC_JWT_1_1";
+  /// The name of the [`JwsSignatureOptions::sd_jwt_vc_draft_04`] preset.
+  pub const SD_JWT_VC_DRAFT_04: &'static str = "SD_JWT_VC_DRAFT_04";
+  /// The name of the [`JwsSignatureOptions::kb_jwt`] preset.
+  pub const KB_JWT: &'static str = "KB_JWT";
+
+  /// Creates a new [`JwsSignaturePresets`] containing the built-in presets.
+  pub fn new() -> Self {
+    let presets: HashMap<String, JwsSignatureOptions> = [
+      (Self::VC_JWT_1_1, JwsSignatureOptions::vc_jwt_1_1()),
+      (Self::SD_JWT_VC_DRAFT_04, JwsSignatureOptions::sd_jwt_vc_draft_04()),
+      (Self::KB_JWT, JwsSignatureOptions::kb_jwt()),
+    ]
+    .into_iter()
+    .map(|(name, options)| (name.to_owned(), options))
+    .collect();
+
+    Self { presets }
+  }
+
+  /// Registers `options` under `name`, returning the options previously registered under that name, if any.
+  ///
+  /// Built-in presets can be replaced this way.
+  pub fn register(&mut self, name: impl Into<String>, options: JwsSignatureOptions) -> Option<JwsSignatureOptions> {
+    self.presets.insert(name.into(), options)
+  }
+
+  /// Returns the options registered under `name`.
+  pub fn get(&self, name: &str) -> Option<&JwsSignatureOptions> {
+    self.presets.get(name)
+  }
+
+  /// Removes the options registered under `name`, returning them if present.
+  pub fn remove(&mut self, name: &str) -> Option<JwsSignatureOptions> {
+    self.presets.remove(name)
+  }
+
+  /// Returns an iterator over the names of the registered presets, in arbitrary order.
+  pub fn names(&self) -> impl Iterator<Item = &str> {
+    self.presets.keys().map(String::as_str)
+  }
+}
+
+impl Default for JwsSignaturePresets {
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
+use identity_core::common::OneOrSet;
+use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_credential::credential::Credential;
 use identity_credential::credential::Jws;
 use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_did::CoreDID;
 use identity_did::DIDUrl;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
@@ -21,7 +24,11 @@ use identity_verification::MethodScope;
 
 use crate::key_id_storage::KeyIdMemstore;
 use crate::key_storage::JwkMemStore;
+use crate::storage::JwkStorageDocumentError;
 use crate::storage::JwsSignatureOptions;
+use crate::storage::MethodRotation;
+use crate::storage::MethodRotationPolicy;
+use crate::storage::EXPIRES_PROPERTY;
 
 use crate::storage::JwkDocumentExt;
 use crate::Storage;
@@ -188,6 +195,124 @@ async fn create_jws_with_nonce() {
     .is_err());
 }
 
+#[tokio::test]
+async fn verify_jws_require_controlled_method() {
+  let (mut document, storage, fragment) = setup_with_method().await;
+  let payload: &[u8] = b"test";
+  let verification_options: JwsVerificationOptions = JwsVerificationOptions::new().require_controlled_method(true);
+
+  let jws: Jws = document
+    .create_jws(&storage, &fragment, payload, &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(jws.as_str(), None, &EdDSAJwsVerifier::default(), &verification_options)
+    .is_ok());
+
+  // Hand control of the signing method to another DID.
+  let other: CoreDID = CoreDID::parse("did:bar:other").unwrap();
+  *document
+    .resolve_method_mut(fragment.as_str(), None)
+    .unwrap()
+    .controller_mut() = other.clone();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new()
+    )
+    .is_ok());
+  assert!(matches!(
+    document.verify_jws(jws.as_str(), None, &EdDSAJwsVerifier::default(), &verification_options),
+    Err(identity_document::error::Error::InvalidMethodController(_))
+  ));
+
+  // The method is accepted once its controller is a controller of the document.
+  *document.controller_mut() = Some(OneOrSet::new_one(other));
+  assert!(document
+    .verify_jws(jws.as_str(), None, &EdDSAJwsVerifier::default(), &verification_options)
+    .is_ok());
+}
+
+#[tokio::test]
+async fn verify_jws_blockchain_account() {
+  use identity_did::DIDPkh;
+  use identity_verification::jose::jws::CompactJwsEncoder;
+  use identity_verification::jose::jws::JwsHeader;
+  use identity_verification::jws::AsyncJwsVerifier;
+  use identity_verification::jws::JwsVerifier;
+  use identity_verification::jws::SignatureVerificationError;
+  use identity_verification::jws::SignatureVerificationErrorKind;
+  use identity_verification::jws::VerificationInput;
+
+  const ACCOUNT_ID: &str = "eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a";
+  const SIGNATURE: &[u8] = b"signature";
+
+  /// Accepts `SIGNATURE` for `ACCOUNT_ID`.
+  struct AccountVerifier;
+
+  impl JwsVerifier for AccountVerifier {
+    fn verify(&self, _: VerificationInput, _: &Jwk) -> Result<(), SignatureVerificationError> {
+      Err(SignatureVerificationErrorKind::UnsupportedKeyType.into())
+    }
+
+    fn verify_blockchain_account(
+      &self,
+      input: VerificationInput,
+      account_id: &str,
+    ) -> Result<(), SignatureVerificationError> {
+      (account_id == ACCOUNT_ID && input.decoded_signature.as_ref() == SIGNATURE)
+        .then_some(())
+        .ok_or_else(|| SignatureVerificationErrorKind::InvalidSignature.into())
+    }
+  }
+
+  /// An asynchronous verifier without support for blockchain accounts.
+  struct RemoteVerifier;
+
+  #[async_trait::async_trait]
+  impl AsyncJwsVerifier for RemoteVerifier {
+    async fn verify(&self, _: VerificationInput, _: &Jwk) -> Result<(), SignatureVerificationError> {
+      Ok(())
+    }
+  }
+
+  let document: CoreDocument =
+    CoreDocument::expand_did_pkh(DIDPkh::parse(&format!("did:pkh:{ACCOUNT_ID}")).unwrap()).unwrap();
+  let jws = |signature: &[u8]| -> String {
+    let mut header: JwsHeader = JwsHeader::new();
+    header.set_alg(JwsAlgorithm::ES256K);
+    header.set_kid(format!("did:pkh:{ACCOUNT_ID}#blockchainAccountId"));
+    CompactJwsEncoder::new(b"test", &header).unwrap().into_jws(signature)
+  };
+  let options: JwsVerificationOptions = JwsVerificationOptions::new();
+
+  let valid: String = jws(SIGNATURE);
+  assert!(document.verify_jws(&valid, None, &AccountVerifier, &options).is_ok());
+  assert!(document
+    .verify_jws_async(&valid, None, &AccountVerifier, &options)
+    .await
+    .is_ok());
+
+  // INVALID: the signature does not belong to the account.
+  let invalid: String = jws(b"other");
+  assert!(document.verify_jws(&invalid, None, &AccountVerifier, &options).is_err());
+  assert!(document
+    .verify_jws_async(&invalid, None, &AccountVerifier, &options)
+    .await
+    .is_err());
+
+  // INVALID: the verifier does not support blockchain accounts.
+  assert!(document
+    .verify_jws(&valid, None, &EdDSAJwsVerifier::default(), &options)
+    .is_err());
+  assert!(document
+    .verify_jws_async(&valid, None, &RemoteVerifier, &options)
+    .await
+    .is_err());
+}
+
 #[tokio::test]
 async fn create_jws_with_header_copy_options() {
   let (document, storage, fragment) = setup_with_method().await;
@@ -275,6 +400,40 @@ async fn create_jws_detached() {
     .is_ok());
 }
 
+#[tokio::test]
+async fn jws_signing_input_matches_create_jws() {
+  let (document, storage, fragment) = setup_with_method().await;
+  let payload: &[u8] = b"test";
+
+  let signature_options: JwsSignatureOptions = JwsSignatureOptions::new().nonce("nonce").attach_jwk_to_header(true);
+  let jws: Jws = document
+    .create_jws(&storage, &fragment, payload, &signature_options)
+    .await
+    .unwrap();
+  let signing_input: Vec<u8> = document
+    .jws_signing_input(&fragment, payload, &signature_options)
+    .unwrap();
+  let (encoded_header_and_payload, _) = jws.as_str().rsplit_once('.').unwrap();
+  assert_eq!(signing_input, encoded_header_and_payload.as_bytes());
+
+  // Without `b64` encoding, the signing input contains the raw payload.
+  let signature_options: JwsSignatureOptions = JwsSignatureOptions::new().b64(false).detached_payload(true);
+  let jws: Jws = document
+    .create_jws(&storage, &fragment, payload, &signature_options)
+    .await
+    .unwrap();
+  let signing_input: Vec<u8> = document
+    .jws_signing_input(&fragment, payload, &signature_options)
+    .unwrap();
+  let (encoded_header, _) = jws.as_str().split_once('.').unwrap();
+  assert_eq!(signing_input, format!("{encoded_header}.test").as_bytes());
+
+  assert!(matches!(
+    document.jws_signing_input("unknown", payload, &signature_options),
+    Err(JwkStorageDocumentError::MethodNotFound)
+  ));
+}
+
 #[tokio::test]
 async fn create_jws_with_custom_kid() {
   let (document, storage, fragment) = setup_with_method().await;
@@ -380,6 +539,181 @@ async fn purging() {
   assert_eq!(storage.key_storage().count().await, 0);
 }
 
+#[tokio::test]
+async fn rotation_purges_previous_method() {
+  let (mut document, storage, fragment) = setup_with_method().await;
+  let previous_method: DIDUrl = document.resolve_method(&fragment, None).unwrap().id().to_owned();
+  document
+    .attach_method_relationship(&previous_method, MethodRelationship::AssertionMethod)
+    .unwrap();
+
+  let rotation: MethodRotation = document
+    .rotate_method(
+      &storage,
+      &previous_method,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("#key-2"),
+      MethodRotationPolicy::Purge,
+    )
+    .await
+    .unwrap();
+
+  assert_eq!(rotation.previous_method, previous_method);
+  assert_eq!(rotation.scope, MethodScope::VerificationMethod);
+  assert_eq!(rotation.relationships, vec![MethodRelationship::AssertionMethod]);
+  assert!(!rotation.previous_method_retained());
+
+  // The previous method and its key material are gone.
+  assert!(document.resolve_method(&previous_method, None).is_none());
+  assert_eq!(storage.key_id_storage().count().await, 1);
+  assert_eq!(storage.key_storage().count().await, 1);
+
+  // The new method took over the relationships of the previous one and is backed by storage.
+  assert!(document
+    .resolve_method(&rotation.new_method, Some(MethodScope::assertion_method()))
+    .is_some());
+  let jws: Jws = document
+    .create_jws(&storage, "key-2", b"test", &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new()
+    )
+    .is_ok());
+}
+
+#[tokio::test]
+async fn rotation_retains_previous_method() {
+  let (mut document, storage) = setup();
+  let fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::authentication(),
+    )
+    .await
+    .unwrap();
+  let previous_method: DIDUrl = document.resolve_method(&fragment, None).unwrap().id().to_owned();
+  let expires: Timestamp = Timestamp::parse("2030-01-01T00:00:00Z").unwrap();
+
+  let rotation: MethodRotation = document
+    .rotate_method(
+      &storage,
+      &previous_method,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodRotationPolicy::Retain { expires },
+    )
+    .await
+    .unwrap();
+
+  assert_eq!(rotation.scope, MethodScope::authentication());
+  assert!(rotation.relationships.is_empty());
+  assert!(rotation.previous_method_retained());
+
+  // Both methods are embedded in the same relationship and backed by storage.
+  let retained = document
+    .resolve_method(&previous_method, Some(MethodScope::authentication()))
+    .unwrap();
+  assert_eq!(
+    retained.properties().get(EXPIRES_PROPERTY).unwrap(),
+    &expires.to_rfc3339()
+  );
+  assert!(document
+    .resolve_method(&rotation.new_method, Some(MethodScope::authentication()))
+    .is_some());
+  assert_eq!(storage.key_id_storage().count().await, 2);
+  assert_eq!(storage.key_storage().count().await, 2);
+
+  // Signing with the retained method still works.
+  assert!(document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .is_ok());
+}
+
+#[tokio::test]
+async fn rotation_retains_previous_method_without_relationships() {
+  let (mut document, storage, fragment) = setup_with_method().await;
+  let previous_method: DIDUrl = document.resolve_method(&fragment, None).unwrap().id().to_owned();
+  document
+    .attach_method_relationship(&previous_method, MethodRelationship::AssertionMethod)
+    .unwrap();
+  let jws: Jws = document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+  let expires: Timestamp = Timestamp::parse("2020-01-01T00:00:00Z").unwrap();
+
+  let rotation: MethodRotation = document
+    .rotate_method(
+      &storage,
+      &previous_method,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("#key-2"),
+      MethodRotationPolicy::Retain { expires },
+    )
+    .await
+    .unwrap();
+  assert_eq!(rotation.relationships, vec![MethodRelationship::AssertionMethod]);
+
+  // The relationship references the new method instead of the retained one.
+  assert!(document
+    .resolve_method(&rotation.new_method, Some(MethodScope::assertion_method()))
+    .is_some());
+  assert!(document
+    .resolve_method(&previous_method, Some(MethodScope::assertion_method()))
+    .is_none());
+  assert!(document
+    .resolve_method(&previous_method, Some(MethodScope::VerificationMethod))
+    .is_some());
+
+  // The expiry is advisory, so signatures of the retained method still verify after it.
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new()
+    )
+    .is_ok());
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new().method_scope(MethodScope::assertion_method())
+    )
+    .is_err());
+}
+
+#[tokio::test]
+async fn rotation_of_unknown_method_fails() {
+  let (mut document, storage) = setup();
+  let method_id: DIDUrl = document.id().to_url().join("#missing").unwrap();
+  let result = document
+    .rotate_method(
+      &storage,
+      &method_id,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodRotationPolicy::Purge,
+    )
+    .await;
+  assert!(matches!(result, Err(JwkStorageDocumentError::MethodNotFound)));
+  assert_eq!(storage.key_storage().count().await, 0);
+}
+
 #[cfg(feature = "iota-document")]
 mod iota_document_tests {
   // Write a single test for the IotaDocument case just to check that it works
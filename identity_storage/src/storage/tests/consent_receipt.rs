@@ -0,0 +1,99 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_credential::credential::Jwt;
+use identity_credential::presentation::PresentationRequest;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+use crate::storage::tests::test_utils::generate_credential;
+use crate::storage::tests::test_utils::setup_coredocument;
+use crate::storage::tests::test_utils::CredentialSetup;
+use crate::ConsentReceipt;
+use crate::ConsentReceiptMemstore;
+use crate::ConsentReceiptStorage;
+use crate::JwkDocumentExt;
+use crate::JwsSignatureOptions;
+use crate::PresentationResponseBuilder;
+use crate::SignedConsentReceipt;
+
+#[tokio::test]
+async fn test_presentation_consent_receipt() {
+  let setup = setup_coredocument(None, None).await;
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let credential_jwt: Jwt = setup
+    .issuer_doc
+    .create_credential_jwt(
+      &credential.credential,
+      &setup.issuer_storage,
+      &setup.issuer_method_fragment,
+      &JwsSignatureOptions::default(),
+      None,
+    )
+    .await
+    .unwrap();
+
+  let audience: Url = Url::parse("did:test:verifier").unwrap();
+  let receipts: ConsentReceiptMemstore = ConsentReceiptMemstore::new();
+  let (_, signed): (Jwt, SignedConsentReceipt) =
+    PresentationResponseBuilder::new(PresentationRequest::new("0xd0ff7c8a").audience(audience.clone()))
+      .credential(credential_jwt)
+      .build_with_consent_receipt(
+        &setup.subject_doc,
+        &setup.subject_storage,
+        &setup.subject_method_fragment,
+        "age verification",
+        &receipts,
+      )
+      .await
+      .unwrap();
+
+  let receipt = signed.receipt();
+  assert_eq!(receipt.holder(), setup.subject_doc.id());
+  assert_eq!(receipt.recipient(), Some(&audience));
+  assert_eq!(receipt.purpose(), "age verification");
+  assert_eq!(receipt.shared().len(), 1);
+  assert_eq!(
+    receipt.shared()[0].types,
+    ["VerifiableCredential", "UniversityDegreeCredential"]
+  );
+  assert_eq!(
+    receipt.shared()[0].issuer.as_deref(),
+    Some(setup.issuer_doc.id().as_str())
+  );
+  assert_eq!(receipt.shared()[0].claims, ["GPA", "degree", "name"]);
+
+  // The receipt is stored, exportable and verifiable against the holder's document.
+  assert_eq!(receipts.count().await, 1);
+  assert_eq!(receipts.get_receipt(receipt.id()).await.unwrap(), Some(signed.clone()));
+  let exported: SignedConsentReceipt = SignedConsentReceipt::from_json(&signed.to_json().unwrap()).unwrap();
+  exported
+    .verify(&setup.subject_doc, &EdDSAJwsVerifier::default())
+    .unwrap();
+  assert!(signed.verify(&setup.issuer_doc, &EdDSAJwsVerifier::default()).is_err());
+
+  // A receipt cannot be stored twice.
+  assert!(receipts.insert_receipt(signed).await.is_err());
+}
+
+#[tokio::test]
+async fn test_tampered_consent_receipt() {
+  let setup = setup_coredocument(None, None).await;
+  let holder: &CoreDocument = &setup.subject_doc;
+  let signed: SignedConsentReceipt =
+    ConsentReceipt::new(holder.id().clone(), None, "marketing", &[], Timestamp::now_utc())
+      .sign(holder, &setup.subject_storage, &setup.subject_method_fragment)
+      .await
+      .unwrap();
+  signed.verify(holder, &EdDSAJwsVerifier::default()).unwrap();
+
+  let mut json: serde_json::Value = serde_json::to_value(&signed).unwrap();
+  json["receipt"]["purpose"] = "analytics".into();
+  let tampered: SignedConsentReceipt = serde_json::from_value(json).unwrap();
+  assert!(tampered.verify(holder, &EdDSAJwsVerifier::default()).is_err());
+}
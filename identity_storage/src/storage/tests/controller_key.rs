@@ -0,0 +1,71 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::blake2b::Blake2b256;
+use crypto::hashes::Digest;
+use identity_iota_core::block::address::Address;
+use identity_iota_core::block::address::Ed25519Address;
+use identity_verification::jwu;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyDerivationPath;
+use crate::key_storage::Mnemonic;
+use crate::storage::ControllerKey;
+use crate::storage::JwkStorageDocumentError;
+use crate::Storage;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+async fn storage_with_mnemonic(mnemonic: &Mnemonic, passphrase: &str) -> MemStorage {
+  let storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  storage
+    .key_storage()
+    .store_mnemonic(mnemonic.as_str(), passphrase)
+    .await
+    .unwrap();
+  storage
+}
+
+#[tokio::test]
+async fn derive_controller_key() {
+  let mnemonic: Mnemonic = Mnemonic::generate().unwrap();
+  let storage: MemStorage = storage_with_mnemonic(&mnemonic, "passphrase").await;
+  let path: KeyDerivationPath = ControllerKey::derivation_path(ControllerKey::SHIMMER_COIN_TYPE, 0, 0).unwrap();
+  assert_eq!(path.to_string(), "m/44'/4219'/0'/0'/0'");
+
+  let controller: ControllerKey = storage.derive_controller_key(&path).await.unwrap();
+  assert_eq!(controller.key_id(), &path.to_key_id());
+  assert!(storage.key_storage().exists(controller.key_id()).await.unwrap());
+
+  // The address is the BLAKE2b-256 hash of the public key.
+  let public_key: Vec<u8> = jwu::decode_b64(&controller.public_key().try_okp_params().unwrap().x).unwrap();
+  let hash: [u8; 32] = Blake2b256::digest(public_key).into();
+  assert_eq!(controller.address(), Address::Ed25519(Ed25519Address::new(hash)));
+
+  // The key is recovered from the mnemonic and passphrase alone.
+  let restored: MemStorage = storage_with_mnemonic(&Mnemonic::parse(mnemonic.as_str()).unwrap(), "passphrase").await;
+  assert_eq!(restored.derive_controller_key(&path).await.unwrap(), controller);
+
+  // Other passphrases and paths yield other addresses.
+  let other: MemStorage = storage_with_mnemonic(&mnemonic, "").await;
+  assert_ne!(
+    other.derive_controller_key(&path).await.unwrap().address(),
+    controller.address()
+  );
+  let next: KeyDerivationPath = ControllerKey::derivation_path(ControllerKey::SHIMMER_COIN_TYPE, 0, 1).unwrap();
+  assert_ne!(
+    storage.derive_controller_key(&next).await.unwrap().address(),
+    controller.address()
+  );
+}
+
+#[tokio::test]
+async fn derive_controller_key_without_mnemonic() {
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let path: KeyDerivationPath = ControllerKey::derivation_path(ControllerKey::IOTA_COIN_TYPE, 0, 0).unwrap();
+
+  let error = storage.derive_controller_key(&path).await.unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::KeyStorageError(_)));
+}
@@ -16,6 +16,7 @@ use identity_verification::MethodScope;
 use crate::key_id_storage::KeyIdMemstore;
 use crate::key_storage::JwkMemStore;
 use crate::storage::JwsSignatureOptions;
+use crate::storage::JwsSignaturePresets;
 
 use crate::storage::JwkDocumentExt;
 use crate::Storage;
@@ -227,6 +228,48 @@ async fn signing_credential_with_custom_kid() {
   assert_eq!(decoded.header.kid().unwrap(), my_kid);
 }
 
+#[tokio::test]
+async fn signing_credential_with_preset() {
+  let (document, storage, kid, credential) = setup().await;
+
+  let mut presets = JwsSignaturePresets::new();
+  let mut org_parameters = Object::new();
+  org_parameters.insert("org".to_owned(), serde_json::Value::String("acme".to_owned()));
+  presets.register(
+    "ACME",
+    JwsSignatureOptions::vc_jwt_1_1()
+      .custom_header_parameters(org_parameters)
+      .crit(["org"]),
+  );
+
+  let validator =
+    identity_credential::validator::JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  for (name, expected_crit) in [
+    (JwsSignaturePresets::VC_JWT_1_1, None),
+    ("ACME", Some(["org".to_owned()])),
+  ] {
+    let jws = document
+      .create_credential_jwt(&credential, &storage, kid.as_ref(), presets.get(name).unwrap(), None)
+      .await
+      .unwrap();
+    let decoded = validator
+      .validate::<_, Object>(
+        &jws,
+        &document,
+        &JwtCredentialValidationOptions::default(),
+        identity_credential::validator::FailFast::FirstError,
+      )
+      .unwrap();
+
+    assert_eq!(decoded.header.typ(), Some("JWT"));
+    assert_eq!(
+      decoded.header.crit(),
+      expected_crit.as_ref().map(|crit| crit.as_slice())
+    );
+  }
+  assert!(presets.get("UNKNOWN").is_none());
+}
+
 #[tokio::test]
 async fn custom_claims() {
   let (document, storage, kid, credential) = setup().await;
@@ -11,6 +11,8 @@ use identity_credential::credential::Status;
 use identity_credential::revocation::RevocationBitmap;
 use identity_credential::revocation::RevocationDocumentExt;
 use identity_credential::validator::FailFast;
+use identity_credential::validator::IssuerDocumentPolicy;
+use identity_credential::validator::IssuerDocumentVersion;
 use identity_credential::validator::JwtCredentialValidationOptions;
 use identity_credential::validator::JwtCredentialValidator;
 use identity_credential::validator::JwtCredentialValidatorUtils;
@@ -445,3 +447,67 @@ async fn full_validation_fail_fast() {
   full_validation_fail_fast_impl(test_utils::setup_coredocument(None, None).await).await;
   full_validation_fail_fast_impl(test_utils::setup_iotadocument(None, None).await).await;
 }
+
+#[tokio::test]
+async fn validation_against_issuer_history() {
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    ..
+  } = test_utils::setup_coredocument(None, None).await;
+
+  let CredentialSetup {
+    credential,
+    issuance_date,
+    expiration_date,
+  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None);
+  let jwt: Jwt = issuer_doc
+    .create_credential_jwt(
+      &credential,
+      &storage,
+      method_fragment.as_ref(),
+      &JwsSignatureOptions::default(),
+      None,
+    )
+    .await
+    .unwrap();
+
+  // Rotate the signing key out of the issuer's document after issuance.
+  let mut latest: CoreDocument = issuer_doc.clone();
+  latest
+    .remove_method(&issuer_doc.id().to_url().join(format!("#{method_fragment}")).unwrap())
+    .unwrap();
+  let history = [IssuerDocumentVersion::new(
+    issuer_doc,
+    issuance_date.checked_sub(Duration::days(1)).unwrap(),
+  )];
+
+  let options = JwtCredentialValidationOptions::default()
+    .latest_issuance_date(issuance_date)
+    .earliest_expiry_date(expiration_date);
+  let validate = |options: &JwtCredentialValidationOptions| {
+    JWT_CREDENTIAL_VALIDATOR_ED25519.validate_with_issuer_history::<_, Object>(
+      &jwt,
+      &latest,
+      &history,
+      options,
+      FailFast::FirstError,
+    )
+  };
+
+  assert!(validate(&options).is_err());
+  assert!(validate(&options.clone().issuer_document_policy(IssuerDocumentPolicy::AtIssuance)).is_ok());
+  assert!(validate(&options.clone().issuer_document_policy(IssuerDocumentPolicy::Either)).is_ok());
+
+  // No version of the document was current before the first one.
+  let options = options
+    .issuer_document_policy(IssuerDocumentPolicy::AtIssuance)
+    .issuer_document_as_of(issuance_date.checked_sub(Duration::days(2)).unwrap());
+  let validation_errors = validate(&options).unwrap_err().validation_errors;
+  assert!(matches!(
+    validation_errors.as_slice(),
+    [JwtValidationError::MissingIssuerDocumentVersion]
+  ));
+}
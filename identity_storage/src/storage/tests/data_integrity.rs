@@ -0,0 +1,144 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_credential::data_integrity::DataIntegrityProcessor;
+use identity_credential::data_integrity::DataIntegrityProof;
+use identity_credential::data_integrity::DataIntegrityProofOptions;
+use identity_credential::data_integrity::DataIntegrityVerificationOptions;
+use identity_credential::jsonld::ContextCache;
+use identity_document::document::CoreDocument;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodScope;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::storage::DataIntegrityDocumentExt;
+use crate::storage::JwkStorageDocumentError;
+use crate::JwkDocumentExt;
+use crate::Storage;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+const DOCUMENT_JSON: &str = r#"
+{
+    "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr"
+}"#;
+
+/// A minimal stand-in for a context defining the credential and Data Integrity terms.
+fn contexts() -> ContextCache {
+  let mut cache: ContextCache = ContextCache::new();
+  cache.insert(
+    "https://example.com/credentials/v1",
+    json!({
+      "@context": {
+        "id": "@id",
+        "type": "@type",
+        "@vocab": "https://example.com/vocab#",
+        "xsd": "http://www.w3.org/2001/XMLSchema#",
+        "sec": "https://w3id.org/security#",
+        "issuer": { "@type": "@id" },
+        "verificationMethod": { "@id": "sec:verificationMethod", "@type": "@id" },
+        "proofPurpose": { "@id": "sec:proofPurpose", "@type": "@vocab" },
+        "created": { "@id": "http://purl.org/dc/terms/created", "@type": "xsd:dateTime" }
+      }
+    }),
+  );
+  cache
+}
+
+#[tokio::test]
+async fn create_data_integrity_proof() {
+  let mut issuer: CoreDocument = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let fragment: String = issuer
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::assertion_method(),
+    )
+    .await
+    .unwrap();
+
+  let credential: Value = json!({
+    "@context": "https://example.com/credentials/v1",
+    "type": "Credential",
+    "issuer": issuer.id().to_string(),
+    "name": "Alice"
+  });
+  let processor: DataIntegrityProcessor<ContextCache> = DataIntegrityProcessor::new(contexts());
+
+  let secured: Value = issuer
+    .create_data_integrity_proof(
+      &credential,
+      &storage,
+      &fragment,
+      &processor,
+      &DataIntegrityProofOptions::default(),
+    )
+    .await
+    .unwrap();
+  let proofs: Vec<DataIntegrityProof> = processor
+    .verify(
+      &secured,
+      &issuer,
+      &EdDSAJwsVerifier::default(),
+      &DataIntegrityVerificationOptions::default(),
+    )
+    .unwrap();
+  assert_eq!(proofs[0].cryptosuite, "eddsa-rdfc-2022");
+
+  // The method is not an authentication method.
+  let result = issuer
+    .create_data_integrity_proof(
+      &credential,
+      &storage,
+      &fragment,
+      &processor,
+      &DataIntegrityProofOptions::default().purpose(MethodRelationship::Authentication),
+    )
+    .await;
+  assert!(matches!(result, Err(JwkStorageDocumentError::MethodNotFound)));
+}
+
+#[tokio::test]
+async fn create_sd_base_proof_requires_es256_method() {
+  let mut issuer: CoreDocument = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let fragment: String = issuer
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::assertion_method(),
+    )
+    .await
+    .unwrap();
+
+  let credential: Value = json!({
+    "@context": "https://example.com/credentials/v1",
+    "type": "Credential",
+    "issuer": issuer.id().to_string(),
+    "name": "Alice"
+  });
+  let processor: DataIntegrityProcessor<ContextCache> = DataIntegrityProcessor::new(contexts());
+
+  let result = issuer
+    .create_sd_base_proof(
+      &credential,
+      &storage,
+      &fragment,
+      &processor,
+      &["/issuer".to_owned()],
+      &DataIntegrityProofOptions::default(),
+    )
+    .await;
+  assert!(matches!(result, Err(JwkStorageDocumentError::InvalidJwsAlgorithm)));
+}
@@ -0,0 +1,110 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_credential::credential::LinkedDomainService;
+use identity_credential::domain_linkage::DomainLinkageConfiguration;
+use identity_credential::domain_linkage::DomainLinkagePublisher;
+use identity_credential::domain_linkage::JwtDomainLinkageValidator;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_did::DIDUrl;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+use super::test_utils::setup_coredocument;
+use super::test_utils::Setup;
+use crate::storage::DomainLinkageGenerator;
+use crate::storage::GeneratedDomainLinkage;
+
+#[derive(Default)]
+struct MemPublisher {
+  published: Mutex<Vec<Url>>,
+}
+
+#[async_trait]
+impl DomainLinkagePublisher for MemPublisher {
+  async fn publish(
+    &self,
+    origin: &Url,
+    _configuration: &DomainLinkageConfiguration,
+  ) -> identity_credential::error::Result<()> {
+    self.published.lock().unwrap().push(origin.clone());
+    Ok(())
+  }
+}
+
+fn generator() -> DomainLinkageGenerator {
+  let domains: Vec<Url> = vec![
+    Url::parse("https://foo.example.com").unwrap(),
+    Url::parse("https://bar.example.com").unwrap(),
+  ];
+  DomainLinkageGenerator::new(domains, Timestamp::now_utc().checked_add(Duration::days(1)).unwrap())
+}
+
+#[tokio::test]
+async fn generate_and_publish_for_multiple_domains() {
+  let Setup {
+    issuer_doc,
+    issuer_storage,
+    issuer_method_fragment,
+    ..
+  } = setup_coredocument(None, None).await;
+  let generator: DomainLinkageGenerator = generator();
+  let publisher: MemPublisher = MemPublisher::default();
+
+  let linkages: Vec<GeneratedDomainLinkage> = generator
+    .generate_and_publish(&issuer_doc, &issuer_storage, &issuer_method_fragment, &publisher)
+    .await
+    .unwrap();
+  assert_eq!(linkages.len(), 2);
+  assert_eq!(publisher.published.lock().unwrap().as_slice(), generator.domains());
+
+  let validator = JwtDomainLinkageValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  for GeneratedDomainLinkage { origin, configuration } in &linkages {
+    validator
+      .validate_linkage(
+        &issuer_doc,
+        configuration,
+        origin,
+        &JwtCredentialValidationOptions::default(),
+      )
+      .unwrap();
+  }
+
+  // INVALID: a configuration does not link to the other domains.
+  assert!(validator
+    .validate_linkage(
+      &issuer_doc,
+      &linkages[0].configuration,
+      &linkages[1].origin,
+      &JwtCredentialValidationOptions::default(),
+    )
+    .is_err());
+}
+
+#[tokio::test]
+async fn sync_linked_domain_service_replaces_service() {
+  let Setup { mut issuer_doc, .. } = setup_coredocument(None, None).await;
+
+  let service_id: DIDUrl = generator()
+    .sync_linked_domain_service(&mut issuer_doc, "linked-domains")
+    .unwrap();
+  let service = LinkedDomainService::try_from(issuer_doc.resolve_service(&service_id).unwrap().clone()).unwrap();
+  assert_eq!(service.domains().len(), 2);
+
+  // Syncing with fewer domains replaces the existing service.
+  let generator = DomainLinkageGenerator::new(
+    vec![Url::parse("https://foo.example.com").unwrap()],
+    Timestamp::now_utc(),
+  );
+  generator
+    .sync_linked_domain_service(&mut issuer_doc, "linked-domains")
+    .unwrap();
+  assert_eq!(issuer_doc.service().len(), 1);
+  let service = LinkedDomainService::try_from(issuer_doc.resolve_service(&service_id).unwrap().clone()).unwrap();
+  assert_eq!(service.domains(), generator.domains());
+}
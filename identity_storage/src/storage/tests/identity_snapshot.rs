@@ -0,0 +1,159 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_document::service::Service;
+use identity_iota_core::IotaDocument;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodScope;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::storage::IdentityStateDiff;
+use crate::storage::IdentityStateSnapshot;
+use crate::storage::JwkDocumentExt;
+use crate::JwkStorageDocumentError;
+use crate::Storage;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+const SNAPSHOT_V0: &str = include_str!("../../../tests/fixtures/golden/identity-snapshot-v0.json");
+const SNAPSHOT_V1: &str = include_str!("../../../tests/fixtures/golden/identity-snapshot-v1.json");
+
+const DOCUMENT_JSON: &str = r#"
+{
+  "doc": {
+    "id": "did:iota:rms:0x7591a0bc872e3a4ab66228d65773961a7a95d2299ec8464331c80fcd86b35f38"
+  },
+  "meta": {
+    "created": "2023-01-25T15:48:09Z",
+    "updated": "2023-01-25T15:48:09Z"
+  }
+}
+"#;
+
+async fn setup() -> (IotaDocument, MemStorage) {
+  let mut document: IotaDocument = IotaDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  for fragment in ["key-1", "key-2"] {
+    document
+      .generate_method(
+        &storage,
+        JwkMemStore::ED25519_KEY_TYPE,
+        JwsAlgorithm::EdDSA,
+        Some(fragment),
+        MethodScope::VerificationMethod,
+      )
+      .await
+      .unwrap();
+  }
+  (document, storage)
+}
+
+fn method_id(document: &IotaDocument, fragment: &str) -> DIDUrl {
+  document.id().to_url().join(format!("#{fragment}")).unwrap()
+}
+
+#[tokio::test]
+async fn snapshot_export_import_roundtrip() {
+  let (document, storage) = setup().await;
+
+  let mut snapshot: IdentityStateSnapshot = storage.snapshot_identity(&document).await.unwrap();
+  assert_eq!(snapshot.document(), &document);
+  assert_eq!(snapshot.key_bindings().len(), 2);
+  assert!(snapshot.key_bindings().iter().all(|binding| binding.key_id().is_some()));
+
+  let mut update: IotaDocument = document.clone();
+  update.metadata.updated = Some(Timestamp::parse("2023-01-26T00:00:00Z").unwrap());
+  snapshot.push_pending_update(update.clone());
+
+  let imported: IdentityStateSnapshot = IdentityStateSnapshot::from_json(&snapshot.to_json().unwrap()).unwrap();
+  assert_eq!(imported, snapshot);
+  assert_eq!(imported.pending_updates(), &[update]);
+}
+
+#[tokio::test]
+async fn snapshot_diff() {
+  let (document, storage) = setup().await;
+  let snapshot: IdentityStateSnapshot = storage.snapshot_identity(&document).await.unwrap();
+  assert!(snapshot.diff(&document).is_empty());
+
+  // The published document diverged from the local state.
+  let mut published: IotaDocument = document.clone();
+  published.remove_method(&method_id(&document, "key-1")).unwrap();
+  published
+    .generate_method(
+      &MemStorage::new(JwkMemStore::new(), KeyIdMemstore::new()),
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("key-3"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  published
+    .insert_service(
+      Service::builder(Object::new())
+        .id(method_id(&document, "linked-domain"))
+        .type_("LinkedDomains")
+        .service_endpoint(Url::parse("https://example.com").unwrap())
+        .build()
+        .unwrap(),
+    )
+    .unwrap();
+  published.metadata.updated = Some(Timestamp::parse("2023-01-26T00:00:00Z").unwrap());
+
+  let diff: IdentityStateDiff = snapshot.diff(&published);
+  assert_eq!(
+    diff,
+    IdentityStateDiff {
+      document_changed: true,
+      metadata_changed: true,
+      added_methods: vec![method_id(&document, "key-3")],
+      removed_methods: vec![method_id(&document, "key-1")],
+      changed_methods: vec![],
+      added_services: vec![method_id(&document, "linked-domain")],
+      removed_services: vec![],
+      changed_services: vec![],
+      unbound_methods: vec![method_id(&document, "key-3")],
+    }
+  );
+  assert!(!diff.is_empty());
+}
+
+#[test]
+fn snapshot_golden_v1() {
+  let snapshot: IdentityStateSnapshot = IdentityStateSnapshot::import(SNAPSHOT_V1).unwrap();
+  assert_eq!(snapshot.version(), IdentityStateSnapshot::CURRENT_VERSION);
+  assert_eq!(snapshot.key_bindings().len(), 1);
+  assert_eq!(
+    serde_json::to_value(&snapshot).unwrap(),
+    serde_json::from_str::<serde_json::Value>(SNAPSHOT_V1).unwrap()
+  );
+}
+
+#[test]
+fn snapshot_migration_from_v0() {
+  let migrated: IdentityStateSnapshot = IdentityStateSnapshot::import(SNAPSHOT_V0).unwrap();
+  assert_eq!(migrated.version(), 1);
+  assert_eq!(migrated, IdentityStateSnapshot::import(SNAPSHOT_V1).unwrap());
+}
+
+#[test]
+fn snapshot_import_rejects_unknown_version() {
+  let mut snapshot: serde_json::Value = serde_json::from_str(SNAPSHOT_V1).unwrap();
+  snapshot["version"] = (IdentityStateSnapshot::CURRENT_VERSION + 1).into();
+  assert!(matches!(
+    IdentityStateSnapshot::import(&snapshot.to_string()),
+    Err(JwkStorageDocumentError::SnapshotImportError(
+      "unsupported version",
+      None
+    ))
+  ));
+}
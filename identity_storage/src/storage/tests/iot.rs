@@ -0,0 +1,76 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
+use identity_credential::credential::Subject;
+use identity_credential::iot::LiteCoseCredentialVerifier;
+use identity_document::document::CoreDocument;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodScope;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::storage::CoseDocumentExt;
+use crate::storage::JwkStorageDocumentError;
+use crate::JwkDocumentExt;
+use crate::Storage;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+const DOCUMENT_JSON: &str = r#"
+{
+    "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr"
+}"#;
+
+#[tokio::test]
+async fn create_cose_credential() {
+  let mut issuer: CoreDocument = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let fragment: String = issuer
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::assertion_method(),
+    )
+    .await
+    .unwrap();
+
+  let credential: Credential = CredentialBuilder::default()
+    .issuer(Url::parse(issuer.id().as_str()).unwrap())
+    .subject(Subject::with_id(Url::parse("did:example:device").unwrap()))
+    .issuance_date(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+    .build()
+    .unwrap();
+
+  let secured: Vec<u8> = issuer
+    .create_cose_credential(&credential, &storage, &fragment)
+    .await
+    .unwrap();
+  let verifier = LiteCoseCredentialVerifier::new(EdDSAJwsVerifier::default(), [issuer.clone()]);
+  let verified: Credential<Object> = verifier
+    .verify(&secured, Timestamp::parse("2024-06-01T00:00:00Z").unwrap())
+    .unwrap();
+  assert_eq!(verified, credential);
+
+  // The method must be an assertion method.
+  let fragment: String = issuer
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::authentication(),
+    )
+    .await
+    .unwrap();
+  let result = issuer.create_cose_credential(&credential, &storage, &fragment).await;
+  assert!(matches!(result, Err(JwkStorageDocumentError::MethodNotFound)));
+}
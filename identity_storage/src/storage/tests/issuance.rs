@@ -0,0 +1,110 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::convert::FromJson;
+use identity_credential::credential::Credential;
+use identity_credential::credential::RevocationBitmapStatus;
+use identity_credential::credential::Subject;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtCredentialValidator;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_iota_core::IotaDocument;
+use serde_json::json;
+
+use super::test_utils::setup_iotadocument;
+use super::test_utils::Setup;
+use crate::storage::IssuedCredential;
+use crate::storage::Issuer;
+use crate::storage::JwkStorageDocumentError;
+use crate::storage::RevocationBitmapAllocator;
+
+const TEMPLATE: &str = r#"{
+  "@context": "https://www.w3.org/2018/credentials/v1",
+  "type": ["VerifiableCredential", "MembershipCredential"],
+  "issuer": "did:example:placeholder",
+  "issuanceDate": "2024-01-01T00:00:00Z",
+  "credentialSubject": {
+    "id": "did:example:placeholder"
+  }
+}"#;
+
+fn subject(holder: &str, number: u64) -> Subject {
+  serde_json::from_value(json!({ "id": holder, "memberNumber": number })).unwrap()
+}
+
+#[tokio::test]
+async fn issue_batch_reports_errors_per_item() {
+  let Setup {
+    issuer_doc,
+    issuer_storage,
+    issuer_method_fragment,
+    ..
+  } = setup_iotadocument(None, None).await;
+  let template: Credential = Credential::from_json(TEMPLATE).unwrap();
+  let service: DIDUrl = issuer_doc.id().to_url().join("#revocation").unwrap();
+  let mut allocator = RevocationBitmapAllocator::new(service, 10..12);
+
+  let subjects: Vec<Subject> = vec![
+    subject("did:example:alice", 1),
+    subject("did:example:bob", 2),
+    subject("did:example:carol", 3),
+  ];
+  let issued: Vec<IssuedCredential> = Issuer::new(&issuer_doc, &issuer_storage, issuer_method_fragment)
+    .concurrency(2)
+    .issue_batch(&template, &subjects, Some(&mut allocator))
+    .await;
+
+  assert_eq!(issued.iter().map(|item| item.index).collect::<Vec<_>>(), [0, 1, 2]);
+  // The allocator is exhausted by the third subject.
+  assert!(matches!(
+    issued[2].jwt,
+    Err(JwkStorageDocumentError::IssuanceError(_, None))
+  ));
+  assert!(issued[2].status.is_none());
+  assert!(allocator.remaining().is_empty());
+
+  let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  for (item, index) in [(&issued[0], 10), (&issued[1], 11)] {
+    let status = RevocationBitmapStatus::try_from(item.status.clone().unwrap()).unwrap();
+    assert_eq!(status.index().unwrap(), index);
+
+    let decoded = validator
+      .validate::<_, Object>(
+        item.jwt.as_ref().unwrap(),
+        &issuer_doc,
+        &JwtCredentialValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .unwrap();
+    assert_eq!(decoded.credential.issuer.url().as_str(), issuer_doc.id().as_str());
+    assert_eq!(decoded.credential.credential_status.as_ref(), item.status.as_ref());
+    assert_eq!(
+      decoded.credential.credential_subject,
+      OneOrMany::One(subjects[item.index].clone())
+    );
+  }
+}
+
+#[tokio::test]
+async fn issue_batch_without_status() {
+  let Setup {
+    issuer_doc,
+    issuer_storage,
+    issuer_method_fragment,
+    ..
+  } = setup_iotadocument(None, None).await;
+  let template: Credential = Credential::from_json(TEMPLATE).unwrap();
+  let subjects: Vec<Subject> = (0..5).map(|number| subject("did:example:holder", number)).collect();
+
+  let issued: Vec<IssuedCredential> =
+    Issuer::<IotaDocument, _, _>::new(&issuer_doc, &issuer_storage, issuer_method_fragment)
+      .issue_batch(&template, &subjects, None)
+      .await;
+  assert_eq!(issued.len(), 5);
+  assert!(issued.iter().all(|item| item.jwt.is_ok() && item.status.is_none()));
+}
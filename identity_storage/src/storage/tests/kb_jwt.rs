@@ -23,6 +23,8 @@ use identity_credential::sd_jwt_payload::Sha256Hasher;
 use identity_credential::validator::FailFast;
 use identity_credential::validator::JwtCredentialValidationOptions;
 use identity_credential::validator::KeyBindingJWTValidationOptions;
+use identity_credential::validator::KeyBindingJWTValidator;
+use identity_credential::validator::KeyBindingJwtBuilder;
 use identity_credential::validator::KeyBindingJwtError;
 use identity_credential::validator::SdJwtCredentialValidator;
 use identity_eddsa_verifier::EdDSAJwsVerifier;
@@ -80,16 +82,14 @@ async fn setup_test() -> (Setup<IotaDocument, IotaDocument>, Credential, SdJwt)
     .map(|disclosure| disclosure.to_string())
     .collect();
 
-  let binding_claims = KeyBindingJwtClaims::new(
-    &Sha256Hasher::new(),
-    jwt.as_str().to_string(),
-    disclosures.clone(),
-    NONCE.to_string(),
-    VERIFIER_ID.to_string(),
-    Timestamp::now_utc().to_unix(),
-  )
-  .to_json()
-  .unwrap();
+  let sd_jwt = SdJwt::new(jwt.into(), disclosures, None);
+  let binding_claims = KeyBindingJwtBuilder::new()
+    .nonce(NONCE)
+    .aud(VERIFIER_ID)
+    .finish(&sd_jwt, &Sha256Hasher::new())
+    .unwrap()
+    .to_json()
+    .unwrap();
 
   // Setting the `typ` in the header is required.
   let options = JwsSignatureOptions::new().typ(KeyBindingJwtClaims::KB_JWT_HEADER_TYP);
@@ -105,7 +105,7 @@ async fn setup_test() -> (Setup<IotaDocument, IotaDocument>, Credential, SdJwt)
     )
     .await
     .unwrap();
-  let sd_jwt_obj = SdJwt::new(jwt.into(), disclosures.clone(), Some(kb_jwt.into()));
+  let sd_jwt_obj = SdJwt::new(sd_jwt.jwt, sd_jwt.disclosures, Some(kb_jwt.into()));
   (setup, credential, sd_jwt_obj)
 }
 
@@ -174,35 +174,13 @@ async fn kb_too_late() {
 #[tokio::test]
 async fn kb_in_the_future() {
   let (setup, _credential, sd_jwt) = setup_test().await;
-  let binding_claims = KeyBindingJwtClaims::new(
-    &Sha256Hasher::new(),
-    sd_jwt.jwt.as_str().to_string(),
-    sd_jwt.disclosures.clone(),
-    NONCE.to_string(),
-    VERIFIER_ID.to_string(),
-    Timestamp::now_utc()
-      .checked_add(Duration::seconds(30))
-      .unwrap()
-      .to_unix(),
-  )
-  .to_json()
-  .unwrap();
-
-  // Setting the `typ` in the header is required.
-  let options = JwsSignatureOptions::new().typ(KeyBindingJwtClaims::KB_JWT_HEADER_TYP);
-
-  // Create the KB-JWT.
-  let kb_jwt: Jws = setup
-    .subject_doc
-    .create_jws(
-      &setup.subject_storage,
-      &setup.subject_method_fragment,
-      binding_claims.as_bytes(),
-      &options,
-    )
-    .await
+  let binding_claims = KeyBindingJwtBuilder::new()
+    .nonce(NONCE)
+    .aud(VERIFIER_ID)
+    .issued_at(Timestamp::now_utc().checked_add(Duration::seconds(30)).unwrap())
+    .finish(&sd_jwt, &Sha256Hasher::new())
     .unwrap();
-  let sd_jwt = SdJwt::new(sd_jwt.jwt, sd_jwt.disclosures.clone(), Some(kb_jwt.into()));
+  let sd_jwt = bind(&setup, &sd_jwt, &binding_claims).await;
 
   let decoder = SdObjectDecoder::new_with_sha256();
   let validator = SdJwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default(), decoder);
@@ -240,3 +218,103 @@ async fn kb_nonce() {
   let kb_validation = validator.validate_key_binding_jwt(&sd_jwt, &setup.subject_doc, &options);
   assert!(matches!(kb_validation.err().unwrap(), KeyBindingJwtError::InvalidNonce));
 }
+
+/// Signs `claims` as the KB-JWT of `sd_jwt` with the subject's key.
+async fn bind(setup: &Setup<IotaDocument, IotaDocument>, sd_jwt: &SdJwt, claims: &KeyBindingJwtClaims) -> SdJwt {
+  let options = JwsSignatureOptions::new().typ(KeyBindingJwtClaims::KB_JWT_HEADER_TYP);
+  let kb_jwt: Jws = setup
+    .subject_doc
+    .create_jws(
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      claims.to_json().unwrap().as_bytes(),
+      &options,
+    )
+    .await
+    .unwrap();
+  SdJwt::new(sd_jwt.jwt.clone(), sd_jwt.disclosures.clone(), Some(kb_jwt.into()))
+}
+
+#[tokio::test]
+async fn kb_builder_without_disclosures() {
+  let (setup, _credential, sd_jwt) = setup_test().await;
+  let sd_jwt = SdJwt::new(sd_jwt.jwt, Vec::new(), None);
+  let claims = KeyBindingJwtBuilder::new()
+    .nonce(NONCE)
+    .aud(VERIFIER_ID)
+    .finish(&sd_jwt, &Sha256Hasher::new())
+    .unwrap();
+  let sd_jwt = bind(&setup, &sd_jwt, &claims).await;
+
+  let validator =
+    KeyBindingJWTValidator::with_signature_verifier(EdDSAJwsVerifier::default(), SdObjectDecoder::new_with_sha256());
+  let options = KeyBindingJWTValidationOptions::new().nonce(NONCE).aud(VERIFIER_ID);
+  let validated = validator.validate(&sd_jwt, &setup.subject_doc, &options).unwrap();
+  assert_eq!(validated.sd_hash, claims.sd_hash);
+}
+
+#[tokio::test]
+async fn kb_leeway() {
+  let (setup, _credential, sd_jwt) = setup_test().await;
+  let claims = KeyBindingJwtBuilder::new()
+    .nonce(NONCE)
+    .aud(VERIFIER_ID)
+    .issued_at(Timestamp::now_utc().checked_add(Duration::seconds(30)).unwrap())
+    .finish(&sd_jwt, &Sha256Hasher::new())
+    .unwrap();
+  let sd_jwt = bind(&setup, &sd_jwt, &claims).await;
+
+  let validator =
+    KeyBindingJWTValidator::with_signature_verifier(EdDSAJwsVerifier::default(), SdObjectDecoder::new_with_sha256());
+  let options = KeyBindingJWTValidationOptions::new().nonce(NONCE).aud(VERIFIER_ID);
+  assert!(matches!(
+    validator.validate(&sd_jwt, &setup.subject_doc, &options).unwrap_err(),
+    KeyBindingJwtError::IssuanceDate(_)
+  ));
+  assert!(validator
+    .validate(&sd_jwt, &setup.subject_doc, &options.leeway(60))
+    .is_ok());
+}
+
+#[tokio::test]
+async fn kb_required_claims() {
+  let (setup, _credential, sd_jwt) = setup_test().await;
+  let validator =
+    KeyBindingJWTValidator::with_signature_verifier(EdDSAJwsVerifier::default(), SdObjectDecoder::new_with_sha256());
+  let options = KeyBindingJWTValidationOptions::new().required_claim("transaction_data_hashes");
+  assert!(matches!(
+    validator.validate(&sd_jwt, &setup.subject_doc, &options).unwrap_err(),
+    KeyBindingJwtError::MissingRequiredClaim(claim) if claim == "transaction_data_hashes"
+  ));
+
+  let mut claims = KeyBindingJwtBuilder::new()
+    .nonce(NONCE)
+    .aud(VERIFIER_ID)
+    .finish(&sd_jwt, &Sha256Hasher::new())
+    .unwrap();
+  claims
+    .properties
+    .insert("transaction_data_hashes".to_owned(), json!(["hash"]));
+  let sd_jwt = bind(&setup, &sd_jwt, &claims).await;
+  assert!(validator.validate(&sd_jwt, &setup.subject_doc, &options).is_ok());
+}
+
+#[tokio::test]
+async fn kb_invalid_signature() {
+  let (setup, _credential, sd_jwt) = setup_test().await;
+  let kb_jwt: &str = sd_jwt.key_binding_jwt.as_deref().unwrap();
+  let (signing_input, _) = kb_jwt.rsplit_once('.').unwrap();
+  let sd_jwt = SdJwt::new(
+    sd_jwt.jwt.clone(),
+    sd_jwt.disclosures.clone(),
+    Some(format!("{signing_input}.{}", "A".repeat(86))),
+  );
+
+  let validator =
+    KeyBindingJWTValidator::with_signature_verifier(EdDSAJwsVerifier::default(), SdObjectDecoder::new_with_sha256());
+  let options = KeyBindingJWTValidationOptions::new().nonce(NONCE).aud(VERIFIER_ID);
+  assert!(matches!(
+    validator.validate(&sd_jwt, &setup.subject_doc, &options).unwrap_err(),
+    KeyBindingJwtError::JwtValidationError(_)
+  ));
+}
@@ -0,0 +1,215 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_document::document::CoreDocument;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jwk::Jwk;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::AndroidKeyAttestationVerifier;
+use crate::key_storage::CertificateChainAttestationVerifier;
+use crate::key_storage::CertificateChainValidator;
+use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyAttestation;
+use crate::key_storage::KeyAttestationErrorKind;
+use crate::key_storage::KeyAttestationLevel;
+use crate::key_storage::KeyId;
+use crate::storage::JwkStorageDocumentError;
+use crate::storage::KeyAttestationDocumentExt;
+use crate::JwkDocumentExt;
+use crate::Storage;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+const DOCUMENT_JSON: &str = r#"
+{
+    "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr"
+}"#;
+
+// Ed25519 certificate chain: root -> intermediate -> attested key, shared with the X.509 tests of
+// `identity_credential`.
+// The attested key's certificate contains an Android key description with attestation security level
+// `TrustedEnvironment`, KeyMint security level `StrongBox` and attestation challenge "challenge".
+const ROOT_CERTIFICATE: &[u8] = include_bytes!("../../../../identity_credential/tests/fixtures/x509/root.der");
+const INTERMEDIATE_CERTIFICATE: &[u8] =
+  include_bytes!("../../../../identity_credential/tests/fixtures/x509/intermediate.der");
+const LEAF_CERTIFICATE: &[u8] = include_bytes!("../../../../identity_credential/tests/fixtures/x509/leaf.der");
+const OTHER_ROOT_CERTIFICATE: &[u8] =
+  include_bytes!("../../../../identity_credential/tests/fixtures/x509/other-root.der");
+const LEAF_KEY: &str = include_str!("../../../../identity_credential/tests/fixtures/x509/leaf-key.json");
+
+const FRAGMENT: &str = "attested-key";
+
+fn android_attestation() -> KeyAttestation {
+  KeyAttestation::new(
+    KeyAttestation::ANDROID_KEY,
+    vec![LEAF_CERTIFICATE.to_vec(), INTERMEDIATE_CERTIFICATE.to_vec()],
+  )
+}
+
+fn validator(root: &[u8]) -> CertificateChainValidator<EdDSAJwsVerifier> {
+  CertificateChainValidator::new(EdDSAJwsVerifier::default())
+    .with_root(root)
+    .unwrap()
+}
+
+/// Inserts the key certified by `LEAF_CERTIFICATE` into `storage` and adds a method for it to `document`.
+async fn insert_attested_key(document: &mut CoreDocument, storage: &MemStorage) -> KeyId {
+  let mut jwk: Jwk = Jwk::from_json(LEAF_KEY).unwrap();
+  jwk.set_alg(JwsAlgorithm::EdDSA.name());
+
+  let public_key: Jwk = jwk.to_public().unwrap();
+  let key_id: KeyId = storage.key_storage().insert(jwk).await.unwrap();
+  let method: VerificationMethod =
+    VerificationMethod::new_from_jwk(document.id().clone(), public_key, Some(FRAGMENT)).unwrap();
+  storage
+    .key_id_storage()
+    .insert_key_id(MethodDigest::new(&method).unwrap(), key_id.clone())
+    .await
+    .unwrap();
+  document.insert_method(method, MethodScope::VerificationMethod).unwrap();
+
+  key_id
+}
+
+fn assert_attestation_error(
+  result: Result<KeyAttestationLevel, JwkStorageDocumentError>,
+  kind: KeyAttestationErrorKind,
+) {
+  match result {
+    Err(JwkStorageDocumentError::KeyAttestationError(err)) => {
+      assert_eq!(std::mem::discriminant(err.kind()), std::mem::discriminant(&kind))
+    }
+    other => panic!("expected key attestation error `{kind}`, got {other:?}"),
+  }
+}
+
+#[tokio::test]
+async fn attest_method_records_level() {
+  let mut document: CoreDocument = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  insert_attested_key(&mut document, &storage).await;
+  let verifier = AndroidKeyAttestationVerifier::new(validator(ROOT_CERTIFICATE)).with_challenge(b"challenge".to_vec());
+
+  assert_eq!(document.attestation_level(&storage, FRAGMENT).await.unwrap(), None);
+
+  let level: KeyAttestationLevel = document
+    .attest_method(&storage, FRAGMENT, &android_attestation(), &verifier)
+    .await
+    .unwrap();
+  // The lower of the attestation and KeyMint security levels.
+  assert_eq!(level, KeyAttestationLevel::TrustedEnvironment);
+  assert_eq!(
+    document.attestation_level(&storage, FRAGMENT).await.unwrap(),
+    Some(KeyAttestationLevel::TrustedEnvironment)
+  );
+}
+
+#[tokio::test]
+async fn attest_method_rejects_invalid_statements() {
+  let mut document: CoreDocument = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  insert_attested_key(&mut document, &storage).await;
+
+  // Wrong challenge.
+  let verifier = AndroidKeyAttestationVerifier::new(validator(ROOT_CERTIFICATE)).with_challenge(b"other".to_vec());
+  let result = document
+    .attest_method(&storage, FRAGMENT, &android_attestation(), &verifier)
+    .await;
+  assert_attestation_error(result, KeyAttestationErrorKind::ChallengeMismatch);
+
+  // Chain issued by a root that is not trusted.
+  let verifier = AndroidKeyAttestationVerifier::new(validator(OTHER_ROOT_CERTIFICATE));
+  let result = document
+    .attest_method(&storage, FRAGMENT, &android_attestation(), &verifier)
+    .await;
+  assert_attestation_error(result, KeyAttestationErrorKind::UntrustedChain);
+
+  // Chain missing the intermediate certificate.
+  let verifier = AndroidKeyAttestationVerifier::new(validator(ROOT_CERTIFICATE));
+  let attestation = KeyAttestation::new(KeyAttestation::ANDROID_KEY, vec![LEAF_CERTIFICATE.to_vec()]);
+  let result = document
+    .attest_method(&storage, FRAGMENT, &attestation, &verifier)
+    .await;
+  assert_attestation_error(result, KeyAttestationErrorKind::UntrustedChain);
+
+  // Statement of another format.
+  let attestation = KeyAttestation::new("tpm", android_attestation().certificate_chain().to_vec());
+  let result = document
+    .attest_method(&storage, FRAGMENT, &attestation, &verifier)
+    .await;
+  assert_attestation_error(result, KeyAttestationErrorKind::UnsupportedFormat);
+
+  // Nothing was recorded.
+  assert_eq!(document.attestation_level(&storage, FRAGMENT).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn attest_method_rejects_statement_of_other_key() {
+  let mut document: CoreDocument = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  let verifier = AndroidKeyAttestationVerifier::new(validator(ROOT_CERTIFICATE));
+
+  let result = document
+    .attest_method(&storage, &fragment, &android_attestation(), &verifier)
+    .await;
+  assert_attestation_error(result, KeyAttestationErrorKind::PublicKeyMismatch);
+}
+
+#[tokio::test]
+async fn certificate_chain_attestation_verifier_establishes_configured_level() {
+  let mut document: CoreDocument = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  insert_attested_key(&mut document, &storage).await;
+  let verifier = CertificateChainAttestationVerifier::new(
+    "vendor-ca",
+    KeyAttestationLevel::SecureHardware,
+    validator(ROOT_CERTIFICATE),
+  );
+
+  let attestation = KeyAttestation::new("vendor-ca", android_attestation().certificate_chain().to_vec());
+  let level: KeyAttestationLevel = document
+    .attest_method(&storage, FRAGMENT, &attestation, &verifier)
+    .await
+    .unwrap();
+  assert_eq!(level, KeyAttestationLevel::SecureHardware);
+}
+
+#[tokio::test]
+async fn generate_attested_method_without_statement() {
+  let mut document: CoreDocument = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let verifier = AndroidKeyAttestationVerifier::new(validator(ROOT_CERTIFICATE));
+
+  // The memstore does not provide attestation statements for its keys.
+  let method = document
+    .generate_attested_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+      &verifier,
+    )
+    .await
+    .unwrap();
+  assert_eq!(method.attestation_level, None);
+  assert!(document.resolve_method(method.fragment.as_str(), None).is_some());
+}
@@ -0,0 +1,166 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::convert::FromJson;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::storage::EncryptedKeyBackup;
+use crate::storage::JwkDocumentExt;
+use crate::storage::JwkStorageDocumentError;
+use crate::storage::JwsSignatureOptions;
+use crate::storage::KeyBackupOptions;
+use crate::Storage;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+const DOCUMENT_JSON: &str = r#"
+{
+    "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr"
+}"#;
+
+const PASSWORD: &[u8] = b"backup-password";
+
+// Keep the tests fast, the default work factor is intended for production use.
+fn options() -> KeyBackupOptions {
+  KeyBackupOptions::default().work_factor(4)
+}
+
+async fn setup() -> (CoreDocument, MemStorage) {
+  let mut document = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  for fragment in ["key-1", "key-2"] {
+    document
+      .generate_method(
+        &storage,
+        JwkMemStore::ED25519_KEY_TYPE,
+        JwsAlgorithm::EdDSA,
+        Some(fragment),
+        MethodScope::VerificationMethod,
+      )
+      .await
+      .unwrap();
+  }
+  (document, storage)
+}
+
+#[tokio::test]
+async fn export_import_roundtrip() {
+  let (document, storage) = setup().await;
+  let mut metadata = Object::new();
+  metadata.insert("did".to_owned(), document.id().to_string().into());
+
+  let backup: EncryptedKeyBackup = storage
+    .export_keys(document.methods(None), PASSWORD, &options().metadata(metadata.clone()))
+    .await
+    .unwrap();
+  assert_eq!(backup.version(), 1);
+
+  // Restore the keys into an empty storage, e.g. after migrating to another machine.
+  let backup: EncryptedKeyBackup = EncryptedKeyBackup::from_bytes(backup.into_bytes()).unwrap();
+  let restored: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  assert_eq!(restored.import_keys(&backup, PASSWORD).await.unwrap(), metadata);
+
+  for method in document.methods(None) {
+    let jws = document
+      .create_jws(
+        &restored,
+        method.id().fragment().unwrap(),
+        b"test",
+        &JwsSignatureOptions::default(),
+      )
+      .await
+      .unwrap();
+    assert!(document
+      .verify_jws(
+        jws.as_str(),
+        None,
+        &EdDSAJwsVerifier::default(),
+        &JwsVerificationOptions::default()
+      )
+      .is_ok());
+  }
+
+  // INVALID: importing the same keys twice conflicts with the existing key ids.
+  let error = restored.import_keys(&backup, PASSWORD).await.unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::KeyIdStorageError(_)));
+  // The partial import was reverted.
+  assert_eq!(restored.key_storage().count().await, 2);
+}
+
+#[tokio::test]
+async fn import_with_wrong_password_fails() {
+  let (document, storage) = setup().await;
+  let backup: EncryptedKeyBackup = storage
+    .export_keys(document.methods(None), PASSWORD, &options())
+    .await
+    .unwrap();
+
+  let restored: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let error = restored.import_keys(&backup, b"wrong-password").await.unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::KeyBackupError(_, _)));
+  assert_eq!(restored.key_storage().count().await, 0);
+}
+
+#[tokio::test]
+async fn export_of_unknown_method_fails() {
+  let (document, storage) = setup().await;
+  let (other_document, _) = setup().await;
+  let methods: Vec<&VerificationMethod> = document
+    .methods(None)
+    .into_iter()
+    .chain(other_document.methods(None))
+    .collect();
+
+  let error = storage.export_keys(methods, PASSWORD, &options()).await.unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::KeyIdStorageError(_)));
+}
+
+#[tokio::test]
+async fn export_checks_work_factor() {
+  let (document, storage) = setup().await;
+
+  // INVALID: a backup with a higher work factor could not be decrypted.
+  let options: KeyBackupOptions = options().work_factor(KeyBackupOptions::MAX_WORK_FACTOR + 1);
+  let error = storage
+    .export_keys(document.methods(None), PASSWORD, &options)
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::KeyBackupError(_, None)));
+}
+
+// Deriving the key at the maximum work factor requires 8 GiB of memory.
+#[ignore]
+#[tokio::test]
+async fn export_import_roundtrip_with_max_work_factor() {
+  let (document, storage) = setup().await;
+  let options: KeyBackupOptions = options().work_factor(KeyBackupOptions::MAX_WORK_FACTOR);
+  let backup: EncryptedKeyBackup = storage
+    .export_keys(document.methods(None), PASSWORD, &options)
+    .await
+    .unwrap();
+
+  let restored: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  restored.import_keys(&backup, PASSWORD).await.unwrap();
+  assert_eq!(restored.key_storage().count().await, 2);
+}
+
+#[test]
+fn from_bytes_checks_envelope() {
+  assert!(matches!(
+    EncryptedKeyBackup::from_bytes(b"DID\x01".to_vec()),
+    Err(JwkStorageDocumentError::KeyBackupError(_, _))
+  ));
+  assert!(matches!(
+    EncryptedKeyBackup::from_bytes(b"IKB\x02".to_vec()),
+    Err(JwkStorageDocumentError::KeyBackupError(_, _))
+  ));
+  assert!(EncryptedKeyBackup::from_bytes(b"IKB\x01".to_vec()).is_ok());
+}
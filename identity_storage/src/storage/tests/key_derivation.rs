@@ -0,0 +1,182 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodScope;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorageDerivationExt;
+use crate::key_storage::KeyDerivationPath;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::storage::JwkDocumentExt;
+use crate::storage::JwkStorageDocumentError;
+use crate::storage::JwsSignatureOptions;
+use crate::storage::KeyDerivationDocumentExt;
+use crate::Storage;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+const DOCUMENT_JSON: &str = r#"
+{
+    "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr"
+}"#;
+
+// The BIP39 test vector mnemonic.
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+fn path() -> KeyDerivationPath {
+  "m/44'/4218'/0'/0'/0'".parse().unwrap()
+}
+
+async fn storage_with_mnemonic() -> MemStorage {
+  let storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  storage.key_storage().store_mnemonic(MNEMONIC, "").await.unwrap();
+  storage
+}
+
+async fn setup() -> (CoreDocument, MemStorage) {
+  let mut document = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let storage = storage_with_mnemonic().await;
+  document
+    .generate_derived_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      &path(),
+      Some("key-0"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  (document, storage)
+}
+
+#[tokio::test]
+async fn derive_is_deterministic() {
+  let storage = storage_with_mnemonic().await;
+  let output = storage
+    .key_storage()
+    .derive(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, &path())
+    .await
+    .unwrap();
+
+  assert_eq!(output.key_id, path().to_key_id());
+  // Computed independently from the SLIP-10 specification.
+  assert_eq!(
+    output.jwk.try_okp_params().unwrap().x,
+    "kxxUtniDfPlqSe4dESICf6ut8K7pfZ-QlBh9uL45b2M"
+  );
+
+  let other = storage
+    .key_storage()
+    .derive(
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      &path().child(1).unwrap(),
+    )
+    .await
+    .unwrap();
+  assert_ne!(output.jwk, other.jwk);
+}
+
+#[tokio::test]
+async fn derive_without_mnemonic_fails() {
+  let error = JwkMemStore::new()
+    .derive(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, &path())
+    .await
+    .unwrap_err();
+  assert!(matches!(error.kind(), KeyStorageErrorKind::KeyNotFound));
+
+  assert!(JwkMemStore::new().store_mnemonic("not a mnemonic", "").await.is_err());
+}
+
+#[tokio::test]
+async fn recover_from_mnemonic() {
+  let (document, storage) = setup().await;
+  assert_eq!(document.derivation_path(&storage, "key-0").await.unwrap(), Some(path()));
+
+  // Recover the key into an empty storage, e.g. after losing the original one.
+  let restored: MemStorage = storage_with_mnemonic().await;
+  document
+    .recover_derived_method(&restored, "key-0", JwkMemStore::ED25519_KEY_TYPE, &path())
+    .await
+    .unwrap();
+  assert_eq!(
+    document.derivation_path(&restored, "key-0").await.unwrap(),
+    Some(path())
+  );
+
+  let jws = document
+    .create_jws(&restored, "key-0", b"test", &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default()
+    )
+    .is_ok());
+
+  // Recovering into a storage that still holds the mapping is a no-op.
+  document
+    .recover_derived_method(&storage, "key-0", JwkMemStore::ED25519_KEY_TYPE, &path())
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn recover_with_wrong_path_fails() {
+  let (document, _) = setup().await;
+  let restored: MemStorage = storage_with_mnemonic().await;
+
+  let error = document
+    .recover_derived_method(
+      &restored,
+      "key-0",
+      JwkMemStore::ED25519_KEY_TYPE,
+      &path().child(1).unwrap(),
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::KeyDerivationError(_)));
+  // The mismatching key was removed again.
+  assert_eq!(restored.key_storage().count().await, 0);
+}
+
+#[tokio::test]
+async fn derivation_paths_are_not_reused() {
+  let (mut document, storage) = setup().await;
+
+  let error = document
+    .generate_derived_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      &path(),
+      Some("key-1"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::KeyDerivationError(_)));
+
+  // Keys that were not derived have no derivation path.
+  let fragment = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  assert_eq!(document.derivation_path(&storage, &fragment).await.unwrap(), None);
+}
@@ -2,8 +2,34 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod api;
+mod consent_receipt;
+#[cfg(feature = "controller-key")]
+mod controller_key;
 mod credential_jws;
 mod credential_validation;
+#[cfg(feature = "data-integrity")]
+mod data_integrity;
+#[cfg(feature = "domain-linkage")]
+mod domain_linkage;
+#[cfg(feature = "iota-document")]
+mod identity_snapshot;
+#[cfg(feature = "iot")]
+mod iot;
+#[cfg(feature = "iota-document")]
+mod issuance;
 mod kb_jwt;
+#[cfg(feature = "key-attestation")]
+mod key_attestation;
+#[cfg(feature = "key-backup")]
+mod key_backup;
+#[cfg(feature = "key-derivation")]
+mod key_derivation;
 mod presentation_validation;
+mod scoped_storage;
+#[cfg(feature = "credential-encryption")]
+mod sealed_credential;
 pub(crate) mod test_utils;
+#[cfg(feature = "threshold")]
+mod threshold;
+#[cfg(feature = "webauthn")]
+mod webauthn;
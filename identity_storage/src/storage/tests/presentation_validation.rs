@@ -11,6 +11,7 @@ use identity_credential::credential::Jwt;
 use identity_credential::presentation::JwtPresentationOptions;
 use identity_credential::presentation::Presentation;
 use identity_credential::presentation::PresentationBuilder;
+use identity_credential::presentation::PresentationRequest;
 use identity_credential::validator::DecodedJwtPresentation;
 use identity_credential::validator::JwtPresentationValidationOptions;
 use identity_credential::validator::JwtPresentationValidator;
@@ -31,6 +32,7 @@ use crate::storage::tests::test_utils::setup_iotadocument;
 use crate::storage::tests::test_utils::Setup;
 use crate::JwkDocumentExt;
 use crate::JwsSignatureOptions;
+use crate::PresentationResponseBuilder;
 
 use super::test_utils::CredentialSetup;
 
@@ -370,6 +372,69 @@ where
   assert!(validation_ok);
 }
 
+#[tokio::test]
+async fn leeway() {
+  leeway_impl(setup_coredocument(None, None).await).await;
+  leeway_impl(setup_iotadocument(None, None).await).await;
+}
+
+async fn leeway_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument> + Clone,
+{
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let jws = sign_credential(&setup, &credential.credential).await;
+
+  // Presentation that expired 30 seconds ago.
+  let issuance_date: Timestamp = Timestamp::now_utc().checked_sub(Duration::minutes(5)).unwrap();
+  let expiration_date: Timestamp = Timestamp::now_utc().checked_sub(Duration::seconds(30)).unwrap();
+  let presentation: Presentation<Jwt> =
+    PresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(jws)
+      .issuance_date(issuance_date)
+      .expiration_date(expiration_date)
+      .build()
+      .unwrap();
+
+  let presentation_jwt = setup
+    .subject_doc
+    .create_presentation_jwt(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  let validation_error: JwtValidationError = JWT_PRESENTATION_VALIDATOR_ED25519
+    .validate::<_, Jwt, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwtPresentationValidationOptions::default(),
+    )
+    .err()
+    .unwrap()
+    .presentation_validation_errors
+    .into_iter()
+    .next()
+    .unwrap();
+  assert!(matches!(validation_error, JwtValidationError::ExpirationDate));
+
+  // Allow for one minute of clock skew.
+  let decoded_presentation: DecodedJwtPresentation<Jwt> = JWT_PRESENTATION_VALIDATOR_ED25519
+    .validate::<_, Jwt, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwtPresentationValidationOptions::default().leeway(60),
+    )
+    .unwrap();
+  assert_eq!(decoded_presentation.expiration_date, Some(expiration_date));
+  assert_eq!(decoded_presentation.presentation.issuance_date, Some(issuance_date));
+  assert_eq!(decoded_presentation.presentation.expiration_date, Some(expiration_date));
+}
+
 #[tokio::test]
 async fn presentation_jws_error() {
   presentation_jws_error_impl(setup_coredocument(None, None).await).await;
@@ -423,6 +488,66 @@ where
   ));
 }
 
+#[tokio::test]
+async fn test_presentation_response() {
+  test_presentation_response_impl(setup_coredocument(None, None).await).await;
+  test_presentation_response_impl(setup_iotadocument(None, None).await).await;
+}
+async fn test_presentation_response_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let jws = sign_credential(&setup, &credential.credential).await;
+
+  let audience: Url = Url::parse("did:test:verifier").unwrap();
+  let request: PresentationRequest = PresentationRequest::new("0xd0ff7c8a")
+    .audience(audience.clone())
+    .credential_type("UniversityDegreeCredential");
+
+  // Credentials of other types and undecodable credentials are not selected.
+  let builder: PresentationResponseBuilder =
+    PresentationResponseBuilder::new(PresentationRequest::new("0xd0ff7c8a").credential_type("OtherCredential"))
+      .select_credentials([jws.clone(), Jwt::new("invalid".to_owned())]);
+  assert!(builder.credentials().is_empty());
+  assert!(builder
+    .build(
+      &setup.subject_doc,
+      &setup.subject_storage,
+      &setup.subject_method_fragment
+    )
+    .await
+    .is_err());
+
+  let presentation_jwt: Jwt = PresentationResponseBuilder::new(request.clone())
+    .select_credentials([jws.clone()])
+    .build(
+      &setup.subject_doc,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+    )
+    .await
+    .unwrap();
+
+  let decoded_presentation: DecodedJwtPresentation<Jwt> = JWT_PRESENTATION_VALIDATOR_ED25519
+    .validate::<_, Jwt, Object>(&presentation_jwt, &setup.subject_doc, &request.validation_options())
+    .unwrap();
+  assert_eq!(decoded_presentation.aud, Some(audience));
+  assert_eq!(
+    decoded_presentation.presentation.verifiable_credential.as_slice(),
+    [jws]
+  );
+
+  // Responses to other requests are rejected.
+  assert!(JWT_PRESENTATION_VALIDATOR_ED25519
+    .validate::<_, Jwt, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &PresentationRequest::new("replayed").validation_options(),
+    )
+    .is_err());
+}
+
 async fn sign_credential<T>(setup: &Setup<T, T>, credential: &Credential) -> Jwt
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
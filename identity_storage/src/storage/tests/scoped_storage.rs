@@ -0,0 +1,104 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_document::document::CoreDocument;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodScope;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_id_storage::Namespace;
+use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::storage::JwkDocumentExt;
+use crate::storage::JwsSignatureOptions;
+use crate::Storage;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+const DOCUMENT_JSON: &str = r#"
+{
+    "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr"
+}"#;
+
+async fn generate_method(storage: &MemStorage, namespace: &Namespace, document: &mut CoreDocument) -> String {
+  document
+    .generate_method(
+      &storage.scoped(namespace.clone()),
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn namespaces_are_isolated() {
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let tenant_a: Namespace = Namespace::new("tenant-a").unwrap();
+  let tenant_b: Namespace = Namespace::new("tenant-b").unwrap();
+  let mut document = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+
+  let fragment_a: String = generate_method(&storage, &tenant_a, &mut document).await;
+  let fragment_b: String = generate_method(&storage, &tenant_b, &mut document).await;
+  assert_eq!(storage.key_storage().count().await, 2);
+  assert_eq!(storage.key_id_storage().count().await, 0);
+  assert_eq!(storage.key_id_storage().count_in(&tenant_a).await, 1);
+
+  // Each tenant can only sign with its own keys.
+  let options = JwsSignatureOptions::default();
+  document
+    .create_jws(&storage.scoped(tenant_a.clone()), &fragment_a, b"test", &options)
+    .await
+    .unwrap();
+  assert!(document
+    .create_jws(&storage.scoped(tenant_a.clone()), &fragment_b, b"test", &options)
+    .await
+    .is_err());
+  assert!(document
+    .create_jws(&storage, &fragment_a, b"test", &options)
+    .await
+    .is_err());
+
+  // Key ids of one tenant cannot be used through the handle of another.
+  let (_, key_id_b) = storage.key_ids_in(&tenant_b).await.unwrap().pop().unwrap();
+  let scoped_a = storage.scoped(tenant_a.clone());
+  assert!(!scoped_a.key_storage().exists(&key_id_b).await.unwrap());
+  let error = scoped_a.key_storage().delete(&key_id_b).await.unwrap_err();
+  assert!(matches!(error.kind(), KeyStorageErrorKind::KeyNotFound));
+  assert!(storage
+    .scoped(tenant_b.clone())
+    .key_storage()
+    .exists(&key_id_b)
+    .await
+    .unwrap());
+}
+
+#[tokio::test]
+async fn delete_namespace() {
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let tenant_a: Namespace = Namespace::new("tenant-a").unwrap();
+  let tenant_b: Namespace = Namespace::new("tenant-b").unwrap();
+  let mut document = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+
+  for _ in 0..3 {
+    generate_method(&storage, &tenant_a, &mut document).await;
+  }
+  generate_method(&storage, &tenant_b, &mut document).await;
+  assert_eq!(storage.key_ids_in(&tenant_a).await.unwrap().len(), 3);
+
+  assert_eq!(storage.delete_namespace(&tenant_a).await.unwrap(), 3);
+  assert!(storage.key_ids_in(&tenant_a).await.unwrap().is_empty());
+  assert_eq!(storage.key_storage().count().await, 1);
+  assert_eq!(storage.key_ids_in(&tenant_b).await.unwrap().len(), 1);
+}
+
+#[test]
+fn namespace_validation() {
+  assert!(Namespace::new("").is_err());
+  assert!(Namespace::new("tenant:a").is_err());
+  assert_eq!(Namespace::new("tenant-a").unwrap().as_str(), "tenant-a");
+}
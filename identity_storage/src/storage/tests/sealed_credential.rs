@@ -0,0 +1,84 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_credential::credential::Credential;
+use identity_credential::credential::Subject;
+use serde_json::json;
+
+use crate::CredentialEncryptionKey;
+use crate::JwkStorageDocumentError;
+use crate::SealedCredential;
+
+fn credential() -> Credential {
+  let subject: Subject = Subject::from_json_value(json!({
+    "id": "did:example:holder",
+    "name": "Alice",
+    "dateOfBirth": "1990-01-01",
+    "nationalId": "123-45-6789"
+  }))
+  .unwrap();
+  Credential::builder(Object::new())
+    .issuer(Url::parse("did:example:issuer").unwrap())
+    .subject(subject)
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn test_seal_and_unlock_credential() {
+  let credential: Credential = credential();
+  let key: CredentialEncryptionKey = CredentialEncryptionKey::generate().unwrap();
+  let sealed: SealedCredential = SealedCredential::seal(&credential, &["dateOfBirth", "nationalId"], &key).unwrap();
+
+  assert_eq!(
+    sealed.sealed_fields().collect::<Vec<_>>(),
+    [(0, "dateOfBirth"), (0, "nationalId")]
+  );
+  // The sealed fields are neither part of the redacted credential nor of the stored representation.
+  let redacted: Credential = sealed.redacted().unwrap();
+  let properties: &Object = &redacted.credential_subject.get(0).unwrap().properties;
+  assert_eq!(properties.get("name"), Some(&json!("Alice")));
+  assert!(!properties.contains_key("dateOfBirth") && !properties.contains_key("nationalId"));
+  let stored: String = sealed.to_json().unwrap();
+  assert!(!stored.contains("1990-01-01") && !stored.contains("123-45-6789"));
+
+  let restored: SealedCredential = SealedCredential::from_json(&stored).unwrap();
+  assert_eq!(restored.unlock::<Object>(&key).unwrap(), credential);
+}
+
+#[test]
+fn test_unlock_with_wrong_key_fails() {
+  let key: CredentialEncryptionKey = CredentialEncryptionKey::generate().unwrap();
+  let sealed: SealedCredential = SealedCredential::seal(&credential(), &["nationalId"], &key).unwrap();
+
+  let other_key: CredentialEncryptionKey = CredentialEncryptionKey::from_bytes([7; CredentialEncryptionKey::LENGTH]);
+  assert!(matches!(
+    sealed.unlock::<Object>(&other_key),
+    Err(JwkStorageDocumentError::CredentialEncryptionError(..))
+  ));
+}
+
+#[test]
+fn test_swapped_sealed_fields_are_rejected() {
+  let key: CredentialEncryptionKey = CredentialEncryptionKey::generate().unwrap();
+  let sealed: SealedCredential = SealedCredential::seal(&credential(), &["dateOfBirth", "nationalId"], &key).unwrap();
+
+  let mut json: serde_json::Value = serde_json::to_value(&sealed).unwrap();
+  let fields = json["sealedFields"].as_array_mut().unwrap();
+  let name = fields[0]["name"].take();
+  fields[0]["name"] = fields[1]["name"].take();
+  fields[1]["name"] = name;
+  let tampered: SealedCredential = serde_json::from_value(json).unwrap();
+
+  assert!(tampered.unlock::<Object>(&key).is_err());
+}
+
+#[test]
+fn test_subject_id_cannot_be_sealed() {
+  let key: CredentialEncryptionKey = CredentialEncryptionKey::generate().unwrap();
+  assert!(SealedCredential::seal(&credential(), &["id"], &key).is_err());
+}
@@ -0,0 +1,133 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jwk::Jwk;
+use identity_verification::MethodScope;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::key_storage::ThresholdSigner;
+use crate::storage::JwkDocumentExt;
+use crate::storage::JwkStorageDocumentError;
+use crate::storage::JwsSignatureOptions;
+use crate::Storage;
+
+type ThresholdStorage = Storage<ThresholdSigner<JwkMemStore>, KeyIdMemstore>;
+
+const DOCUMENT_JSON: &str = r#"
+{
+    "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr"
+}"#;
+
+async fn setup() -> (CoreDocument, ThresholdStorage, String) {
+  let mut document = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+  let signer = ThresholdSigner::new(vec![JwkMemStore::new(), JwkMemStore::new(), JwkMemStore::new()], 2).unwrap();
+  let storage = Storage::new(signer, KeyIdMemstore::new());
+  let fragment = document
+    .generate_method(
+      &storage,
+      ThresholdSigner::<JwkMemStore>::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  (document, storage, fragment)
+}
+
+fn public_key(document: &CoreDocument, fragment: &str) -> Jwk {
+  document
+    .resolve_method(fragment, None)
+    .unwrap()
+    .data()
+    .try_public_key_jwk()
+    .unwrap()
+    .clone()
+}
+
+#[test]
+fn threshold_signer_rejects_invalid_thresholds() {
+  for (participants, min_signers) in [(3, 1), (3, 4), (1, 2)] {
+    let participants: Vec<JwkMemStore> = (0..participants).map(|_| JwkMemStore::new()).collect();
+    assert!(ThresholdSigner::new(participants, min_signers).is_err());
+  }
+}
+
+#[tokio::test]
+async fn threshold_create_jws() {
+  let (document, storage, fragment) = setup().await;
+
+  let jws = document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default()
+    )
+    .is_ok());
+
+  // Every participant holds a share, none holds the full key.
+  let jwk: Jwk = public_key(&document, &fragment);
+  for participant in storage.key_storage().participants() {
+    assert_eq!(participant.count().await, 0);
+    assert!(participant.sign(&KeyId::new("unknown"), b"test", &jwk).await.is_err());
+  }
+}
+
+#[tokio::test]
+async fn threshold_sign_with_missing_participant() {
+  let (document, storage, fragment) = setup().await;
+  let participants: &[JwkMemStore] = storage.key_storage().participants();
+  let method_digest: MethodDigest = MethodDigest::new(document.resolve_method(&fragment, None).unwrap()).unwrap();
+  let method_key_id: KeyId = storage.key_id_storage().get_key_id(&method_digest).await.unwrap();
+
+  // Two of three shares suffice.
+  participants[0].delete(&method_key_id).await.unwrap();
+  assert!(storage.key_storage().exists(&method_key_id).await.unwrap());
+  let jws = document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default()
+    )
+    .is_ok());
+
+  // A single share does not.
+  participants[1].delete(&method_key_id).await.unwrap();
+  assert!(!storage.key_storage().exists(&method_key_id).await.unwrap());
+  let error = document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::default())
+    .await
+    .unwrap_err();
+  assert!(matches!(
+    error,
+    JwkStorageDocumentError::KeyStorageError(ref err) if matches!(err.kind(), KeyStorageErrorKind::KeyNotFound)
+  ));
+}
+
+#[tokio::test]
+async fn threshold_signer_rejects_inserting_keys() {
+  let (document, storage, fragment) = setup().await;
+  let jwk: Jwk = public_key(&document, &fragment);
+  assert!(storage.key_storage().insert(jwk).await.is_err());
+}
@@ -0,0 +1,107 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_document::document::CoreDocument;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jwk::Jwk;
+use identity_verification::MethodData;
+use identity_verification::MethodScope;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::storage::JwkStorageDocumentError;
+use crate::storage::WebAuthnDocumentExt;
+use crate::Storage;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+const DOCUMENT_JSON: &str = r#"
+{
+    "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr"
+}"#;
+
+// P-256 key taken from https://datatracker.ietf.org/doc/html/rfc7515#appendix-A.3.
+const P256_PUBLIC_KEY: &str = r#"
+{
+  "kty": "EC",
+  "crv": "P-256",
+  "x": "f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU",
+  "y": "x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0"
+}"#;
+
+const CREDENTIAL_ID: &[u8] = b"webauthn-credential-id";
+
+fn setup() -> (CoreDocument, MemStorage) {
+  (
+    CoreDocument::from_json(DOCUMENT_JSON).unwrap(),
+    Storage::new(JwkMemStore::new(), KeyIdMemstore::new()),
+  )
+}
+
+#[tokio::test]
+async fn insert_webauthn_method() {
+  let (mut document, storage) = setup();
+  let public_key: Jwk = Jwk::from_json(P256_PUBLIC_KEY).unwrap();
+
+  let fragment = document
+    .insert_webauthn_method(
+      &storage,
+      CREDENTIAL_ID,
+      public_key.clone(),
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  assert_eq!(fragment, public_key.thumbprint_sha256_b64());
+
+  let method = document.resolve_method(&fragment, None).unwrap();
+  let MethodData::PublicKeyJwk(jwk) = method.data() else {
+    panic!("expected a publicKeyJwk method");
+  };
+  assert_eq!(jwk.alg(), Some(JwsAlgorithm::ES256.name()));
+
+  assert_eq!(
+    document.webauthn_credential_id(&storage, &fragment).await.unwrap(),
+    CREDENTIAL_ID
+  );
+
+  // The fragment is taken.
+  let error = document
+    .insert_webauthn_method(
+      &storage,
+      CREDENTIAL_ID,
+      public_key,
+      Some(&fragment),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::FragmentAlreadyExists));
+}
+
+#[tokio::test]
+async fn insert_webauthn_method_rejects_invalid_keys() {
+  let (mut document, storage) = setup();
+
+  let mut private_key: Jwk = Jwk::from_json(P256_PUBLIC_KEY).unwrap();
+  private_key.try_ec_params_mut().unwrap().d = Some("jpsQnnGQmL-YBIffH1136cspYG6-0iY7X1fCE9-E9LI".to_owned());
+  let ed25519_key: Jwk =
+    Jwk::from_json(r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#).unwrap();
+  let mut es256k_key: Jwk = Jwk::from_json(P256_PUBLIC_KEY).unwrap();
+  es256k_key.set_alg(JwsAlgorithm::ES256K.name());
+
+  for (key, invalid_key_error) in [(private_key, true), (ed25519_key, true), (es256k_key, false)] {
+    let error = document
+      .insert_webauthn_method(&storage, CREDENTIAL_ID, key, None, MethodScope::VerificationMethod)
+      .await
+      .unwrap_err();
+    if invalid_key_error {
+      assert!(matches!(error, JwkStorageDocumentError::InvalidWebAuthnKey(_)));
+    } else {
+      assert!(matches!(error, JwkStorageDocumentError::InvalidJwsAlgorithm));
+    }
+  }
+  assert_eq!(document.methods(None).len(), 0);
+}
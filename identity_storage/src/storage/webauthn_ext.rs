@@ -0,0 +1,184 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_verification::jose::jwk::EcCurve;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkParamsEc;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jwu;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+
+use super::JwkStorageDocumentError as Error;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyId;
+
+/// Extension trait for verification methods whose keys are held by a WebAuthn authenticator, such as a platform
+/// authenticator storing passkeys.
+///
+/// WebAuthn credentials are P-256 keys used with [`JwsAlgorithm::ES256`]. The [`KeyId`] of such a key is the base64url
+/// encoded credential id, which is what a [`JwkStorage`] signing through the WebAuthn API needs to request an
+/// assertion from the authenticator. New credentials can be created through
+/// [`JwkDocumentExt::generate_method`](crate::storage::JwkDocumentExt::generate_method) with a storage that supports
+/// it, while this trait allows adding methods for credentials that were registered beforehand.
+///
+/// Note that WebAuthn authenticators do not sign the JWS signing input directly. Signatures must be verified with a
+/// verifier aware of WebAuthn assertions, such as the `WebAuthnJwsVerifier` of the `identity_ecdsa_verifier` crate.
+///
+/// This trait is deliberately sealed and cannot be implemented by external crates.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait WebAuthnDocumentExt: private::Sealed {
+  /// Insert a new verification method with the P-256 `public_key` of the WebAuthn credential identified by
+  /// `credential_id` into the DID document and record the mapping from the method to the credential in the
+  /// [`KeyIdStorage`].
+  ///
+  /// If no `fragment` is given, the `kid` of the key is used, defaulting to its JWK thumbprint.
+  /// The `alg` of the key is set to [`JwsAlgorithm::ES256`] if absent.
+  ///
+  /// The fragment of the inserted method is returned.
+  async fn insert_webauthn_method<K, I>(
+    &mut self,
+    storage: &Storage<K, I>,
+    credential_id: &[u8],
+    public_key: Jwk,
+    fragment: Option<&str>,
+    scope: MethodScope,
+  ) -> StorageResult<String>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage;
+
+  /// Returns the id of the WebAuthn credential holding the key of the method identified by `fragment`.
+  async fn webauthn_credential_id<K, I>(&self, storage: &Storage<K, I>, fragment: &str) -> StorageResult<Vec<u8>>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage;
+}
+
+mod private {
+  pub trait Sealed {}
+  impl Sealed for identity_document::document::CoreDocument {}
+  #[cfg(feature = "iota-document")]
+  impl Sealed for identity_iota_core::IotaDocument {}
+}
+
+// ====================================================================================================================
+// Implementation
+// ====================================================================================================================
+
+/// Checks that `public_key` is a public P-256 key usable with [`JwsAlgorithm::ES256`], setting `alg` and `kid` if
+/// absent.
+fn prepare_public_key(mut public_key: Jwk) -> StorageResult<Jwk> {
+  let params: &JwkParamsEc = public_key
+    .try_ec_params()
+    .map_err(|_| Error::InvalidWebAuthnKey("expected an EC key"))?;
+  if !matches!(params.try_ec_curve(), Ok(EcCurve::P256)) {
+    return Err(Error::InvalidWebAuthnKey("expected a key on curve P-256"));
+  }
+  if !public_key.is_public() {
+    return Err(Error::InvalidWebAuthnKey("expected a public key"));
+  }
+
+  match public_key.alg() {
+    None => public_key.set_alg(JwsAlgorithm::ES256.name()),
+    Some(alg) if alg == JwsAlgorithm::ES256.name() => (),
+    Some(_) => return Err(Error::InvalidJwsAlgorithm),
+  }
+  if public_key.kid().is_none() {
+    public_key.set_kid(public_key.thumbprint_sha256_b64());
+  }
+
+  Ok(public_key)
+}
+
+async fn webauthn_credential_id<K, I>(
+  document: &CoreDocument,
+  storage: &Storage<K, I>,
+  fragment: &str,
+) -> StorageResult<Vec<u8>>
+where
+  I: KeyIdStorage,
+{
+  let method: &VerificationMethod = document.resolve_method(fragment, None).ok_or(Error::MethodNotFound)?;
+  let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+  let key_id: KeyId = storage
+    .key_id_storage()
+    .get_key_id(&method_digest)
+    .await
+    .map_err(Error::KeyIdStorageError)?;
+
+  jwu::decode_b64(key_id.as_str()).map_err(|err| Error::EncodingError(err.into()))
+}
+
+macro_rules! webauthn_document_ext_for_document_type {
+  ($t:ty) => {
+    #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync-storage", async_trait)]
+    impl WebAuthnDocumentExt for $t {
+      async fn insert_webauthn_method<K, I>(
+        &mut self,
+        storage: &Storage<K, I>,
+        credential_id: &[u8],
+        public_key: Jwk,
+        fragment: Option<&str>,
+        scope: MethodScope,
+      ) -> StorageResult<String>
+      where
+        K: JwkStorage,
+        I: KeyIdStorage,
+      {
+        let public_key: Jwk = prepare_public_key(public_key)?;
+        let key_id: KeyId = KeyId::new(jwu::encode_b64(credential_id));
+
+        let method: VerificationMethod = VerificationMethod::new_from_jwk(self.id().clone(), public_key, fragment)
+          .map_err(Error::VerificationMethodConstructionError)?;
+        let method_digest: MethodDigest = MethodDigest::new(&method).map_err(Error::MethodDigestConstructionError)?;
+        let method_id: DIDUrl = method.id().clone();
+
+        // The fragment is always set on a method, so this error will never occur.
+        let fragment: String = method_id
+          .fragment()
+          .ok_or(identity_verification::Error::MissingIdFragment)
+          .map_err(Error::VerificationMethodConstructionError)?
+          .to_owned();
+
+        self
+          .insert_method(method, scope)
+          .map_err(|_| Error::FragmentAlreadyExists)?;
+
+        // The credential remains with the authenticator, so only the method needs to be removed again on failure.
+        if let Err(error) = storage
+          .key_id_storage()
+          .insert_key_id(method_digest, key_id)
+          .await
+          .map_err(Error::KeyIdStorageError)
+        {
+          let _ = self.remove_method(&method_id);
+          return Err(error);
+        }
+
+        Ok(fragment)
+      }
+
+      async fn webauthn_credential_id<K, I>(&self, storage: &Storage<K, I>, fragment: &str) -> StorageResult<Vec<u8>>
+      where
+        K: JwkStorage,
+        I: KeyIdStorage,
+      {
+        webauthn_credential_id(AsRef::<CoreDocument>::as_ref(self), storage, fragment).await
+      }
+    }
+  };
+}
+
+webauthn_document_ext_for_document_type!(CoreDocument);
+#[cfg(feature = "iota-document")]
+webauthn_document_ext_for_document_type!(identity_iota_core::IotaDocument);
@@ -0,0 +1,144 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::SecretJwk;
+use identity_verification::jose::jws::JwsAlgorithm;
+
+use super::observe;
+use super::StorageOperation;
+use super::StorageOperationOutcome;
+use super::StorageTelemetrySink;
+use crate::key_storage::JwkGenOutput;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::JwkStorageExportExt;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyStorageError;
+use crate::key_storage::KeyStorageResult;
+use crate::key_storage::KeyType;
+
+/// A [`JwkStorage`] wrapper reporting the latency and outcome of every operation to a [`StorageTelemetrySink`].
+pub struct InstrumentedJwkStorage<K> {
+  storage: K,
+  sink: Arc<dyn StorageTelemetrySink>,
+}
+
+impl<K> InstrumentedJwkStorage<K> {
+  /// Wraps `storage`, reporting its operations to `sink`.
+  pub fn new(storage: K, sink: Arc<dyn StorageTelemetrySink>) -> Self {
+    Self { storage, sink }
+  }
+
+  /// Obtain a reference to the wrapped storage.
+  pub fn inner(&self) -> &K {
+    &self.storage
+  }
+
+  /// Consumes the wrapper, returning the wrapped storage.
+  pub fn into_inner(self) -> K {
+    self.storage
+  }
+}
+
+impl<K: std::fmt::Debug> std::fmt::Debug for InstrumentedJwkStorage<K> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("InstrumentedJwkStorage")
+      .field("storage", &self.storage)
+      .finish_non_exhaustive()
+  }
+}
+
+fn failure(error: &KeyStorageError) -> StorageOperationOutcome {
+  StorageOperationOutcome::KeyStorageFailure(error.kind().clone())
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K: JwkStorage> JwkStorage for InstrumentedJwkStorage<K> {
+  async fn generate(&self, key_type: KeyType, alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::Generate,
+      self.storage.generate(key_type, alg),
+      failure,
+    )
+    .await
+  }
+
+  async fn insert(&self, jwk: Jwk) -> KeyStorageResult<KeyId> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::Insert,
+      self.storage.insert(jwk),
+      failure,
+    )
+    .await
+  }
+
+  async fn sign(&self, key_id: &KeyId, data: &[u8], public_key: &Jwk) -> KeyStorageResult<Vec<u8>> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::Sign,
+      self.storage.sign(key_id, data, public_key),
+      failure,
+    )
+    .await
+  }
+
+  async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::Delete,
+      self.storage.delete(key_id),
+      failure,
+    )
+    .await
+  }
+
+  async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::Exists,
+      self.storage.exists(key_id),
+      failure,
+    )
+    .await
+  }
+
+  async fn insert_many(&self, jwks: Vec<Jwk>) -> KeyStorageResult<Vec<KeyId>> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::InsertMany,
+      self.storage.insert_many(jwks),
+      failure,
+    )
+    .await
+  }
+
+  async fn sign_many(&self, requests: &[(&KeyId, &[u8], &Jwk)]) -> KeyStorageResult<Vec<Vec<u8>>> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::SignMany,
+      self.storage.sign_many(requests),
+      failure,
+    )
+    .await
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K: JwkStorageExportExt> JwkStorageExportExt for InstrumentedJwkStorage<K> {
+  async fn export(&self, key_id: &KeyId) -> KeyStorageResult<SecretJwk> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::Export,
+      self.storage.export(key_id),
+      failure,
+    )
+    .await
+  }
+}
@@ -0,0 +1,85 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::observe;
+use super::StorageOperation;
+use super::StorageOperationOutcome;
+use super::StorageTelemetrySink;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::KeyIdStorageError;
+use crate::key_id_storage::KeyIdStorageResult;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::KeyId;
+
+/// A [`KeyIdStorage`] wrapper reporting the latency and outcome of every operation to a [`StorageTelemetrySink`].
+pub struct InstrumentedKeyIdStorage<I> {
+  storage: I,
+  sink: Arc<dyn StorageTelemetrySink>,
+}
+
+impl<I> InstrumentedKeyIdStorage<I> {
+  /// Wraps `storage`, reporting its operations to `sink`.
+  pub fn new(storage: I, sink: Arc<dyn StorageTelemetrySink>) -> Self {
+    Self { storage, sink }
+  }
+
+  /// Obtain a reference to the wrapped storage.
+  pub fn inner(&self) -> &I {
+    &self.storage
+  }
+
+  /// Consumes the wrapper, returning the wrapped storage.
+  pub fn into_inner(self) -> I {
+    self.storage
+  }
+}
+
+impl<I: std::fmt::Debug> std::fmt::Debug for InstrumentedKeyIdStorage<I> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("InstrumentedKeyIdStorage")
+      .field("storage", &self.storage)
+      .finish_non_exhaustive()
+  }
+}
+
+fn failure(error: &KeyIdStorageError) -> StorageOperationOutcome {
+  StorageOperationOutcome::KeyIdStorageFailure(error.kind().clone())
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<I: KeyIdStorage> KeyIdStorage for InstrumentedKeyIdStorage<I> {
+  async fn insert_key_id(&self, method_digest: MethodDigest, key_id: KeyId) -> KeyIdStorageResult<()> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::InsertKeyId,
+      self.storage.insert_key_id(method_digest, key_id),
+      failure,
+    )
+    .await
+  }
+
+  async fn get_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<KeyId> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::GetKeyId,
+      self.storage.get_key_id(method_digest),
+      failure,
+    )
+    .await
+  }
+
+  async fn delete_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<()> {
+    observe(
+      self.sink.as_ref(),
+      StorageOperation::DeleteKeyId,
+      self.storage.delete_key_id(method_digest),
+      failure,
+    )
+    .await
+  }
+}
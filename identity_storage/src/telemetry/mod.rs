@@ -0,0 +1,64 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Telemetry for storage backends.
+//!
+//! This module provides wrappers around [`JwkStorage`](crate::key_storage::JwkStorage) and
+//! [`KeyIdStorage`](crate::key_id_storage::KeyIdStorage) implementations that report the latency and outcome of
+//! every operation to a user-provided [`StorageTelemetrySink`], e.g. to monitor the health of a KMS or HSM.
+
+mod instrumented_jwk_storage;
+mod instrumented_key_id_storage;
+mod telemetry_sink;
+
+#[cfg(all(test, feature = "memstore"))]
+mod tests;
+
+pub use instrumented_jwk_storage::*;
+pub use instrumented_key_id_storage::*;
+pub use telemetry_sink::*;
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::storage::Storage;
+
+/// Awaits `operation` and reports its latency and outcome to `sink`.
+async fn observe<T, E, F>(
+  sink: &dyn StorageTelemetrySink,
+  operation: StorageOperation,
+  future: F,
+  failure: impl FnOnce(&E) -> StorageOperationOutcome,
+) -> Result<T, E>
+where
+  F: Future<Output = Result<T, E>>,
+{
+  let start: Instant = Instant::now();
+  let result: Result<T, E> = future.await;
+  let outcome: StorageOperationOutcome = match &result {
+    Ok(_) => StorageOperationOutcome::Success,
+    Err(error) => failure(error),
+  };
+  sink.record(&StorageOperationEvent {
+    operation,
+    latency: start.elapsed(),
+    outcome,
+  });
+  result
+}
+
+impl<K, I> Storage<K, I> {
+  /// Wraps the key and key id storage so that every operation is reported to `sink`.
+  pub fn with_telemetry<S>(self, sink: S) -> Storage<InstrumentedJwkStorage<K>, InstrumentedKeyIdStorage<I>>
+  where
+    S: StorageTelemetrySink + 'static,
+  {
+    let sink: Arc<dyn StorageTelemetrySink> = Arc::new(sink);
+    let (key_storage, key_id_storage): (K, I) = self.into_parts();
+    Storage::new(
+      InstrumentedJwkStorage::new(key_storage, sink.clone()),
+      InstrumentedKeyIdStorage::new(key_id_storage, sink),
+    )
+  }
+}
@@ -0,0 +1,110 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::key_id_storage::KeyIdStorageErrorKind;
+use crate::key_storage::KeyStorageErrorKind;
+
+/// A storage operation reported to a [`StorageTelemetrySink`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StorageOperation {
+  /// [`JwkStorage::generate`](crate::key_storage::JwkStorage::generate).
+  Generate,
+  /// [`JwkStorage::insert`](crate::key_storage::JwkStorage::insert).
+  Insert,
+  /// [`JwkStorage::sign`](crate::key_storage::JwkStorage::sign).
+  Sign,
+  /// [`JwkStorage::delete`](crate::key_storage::JwkStorage::delete).
+  Delete,
+  /// [`JwkStorage::insert_many`](crate::key_storage::JwkStorage::insert_many).
+  InsertMany,
+  /// [`JwkStorage::sign_many`](crate::key_storage::JwkStorage::sign_many).
+  SignMany,
+  /// [`JwkStorage::exists`](crate::key_storage::JwkStorage::exists).
+  Exists,
+  /// [`JwkStorageExportExt::export`](crate::key_storage::JwkStorageExportExt::export).
+  Export,
+  /// [`KeyIdStorage::insert_key_id`](crate::key_id_storage::KeyIdStorage::insert_key_id).
+  InsertKeyId,
+  /// [`KeyIdStorage::get_key_id`](crate::key_id_storage::KeyIdStorage::get_key_id).
+  GetKeyId,
+  /// [`KeyIdStorage::delete_key_id`](crate::key_id_storage::KeyIdStorage::delete_key_id).
+  DeleteKeyId,
+}
+
+impl StorageOperation {
+  /// Returns the string representation of the operation, suitable as a metric label.
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Generate => "generate",
+      Self::Insert => "insert",
+      Self::Sign => "sign",
+      Self::Delete => "delete",
+      Self::InsertMany => "insert_many",
+      Self::SignMany => "sign_many",
+      Self::Exists => "exists",
+      Self::Export => "export",
+      Self::InsertKeyId => "insert_key_id",
+      Self::GetKeyId => "get_key_id",
+      Self::DeleteKeyId => "delete_key_id",
+    }
+  }
+}
+
+impl Display for StorageOperation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// The outcome of a [`StorageOperation`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum StorageOperationOutcome {
+  /// The operation succeeded.
+  Success,
+  /// The key storage operation failed.
+  KeyStorageFailure(KeyStorageErrorKind),
+  /// The key id storage operation failed.
+  KeyIdStorageFailure(KeyIdStorageErrorKind),
+}
+
+impl StorageOperationOutcome {
+  /// Returns `true` if the operation succeeded.
+  pub fn is_success(&self) -> bool {
+    matches!(self, Self::Success)
+  }
+}
+
+/// A report of a single storage operation.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct StorageOperationEvent {
+  /// The operation that was performed.
+  pub operation: StorageOperation,
+  /// The time it took the wrapped storage to complete the operation.
+  pub latency: Duration,
+  /// Whether the operation succeeded.
+  pub outcome: StorageOperationOutcome,
+}
+
+/// A destination for [`StorageOperationEvent`]s, e.g. a metrics registry or a logger.
+///
+/// `record` is called on the task performing the storage operation, so implementations should not block.
+/// Closures taking a `&StorageOperationEvent` implement this trait.
+pub trait StorageTelemetrySink: Send + Sync {
+  /// Records the given `event`.
+  fn record(&self, event: &StorageOperationEvent);
+}
+
+impl<F> StorageTelemetrySink for F
+where
+  F: Fn(&StorageOperationEvent) + Send + Sync,
+{
+  fn record(&self, event: &StorageOperationEvent) {
+    self(event)
+  }
+}
@@ -0,0 +1,77 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use identity_core::convert::FromJson;
+use identity_document::document::CoreDocument;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodScope;
+
+use super::StorageOperation;
+use super::StorageOperationEvent;
+use super::StorageOperationOutcome;
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::storage::JwkDocumentExt;
+use crate::storage::JwsSignatureOptions;
+use crate::Storage;
+
+const DOCUMENT_JSON: &str = r#"
+{
+    "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr"
+}"#;
+
+#[tokio::test]
+async fn operations_are_reported() {
+  let events: Arc<Mutex<Vec<StorageOperationEvent>>> = Arc::default();
+  let recorded = events.clone();
+  let storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new())
+    .with_telemetry(move |event: &StorageOperationEvent| recorded.lock().unwrap().push(event.clone()));
+  let mut document = CoreDocument::from_json(DOCUMENT_JSON).unwrap();
+
+  let fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+
+  let operations: Vec<StorageOperation> = events.lock().unwrap().iter().map(|event| event.operation).collect();
+  assert_eq!(
+    operations,
+    [
+      StorageOperation::Generate,
+      StorageOperation::InsertKeyId,
+      StorageOperation::GetKeyId,
+      StorageOperation::Sign
+    ]
+  );
+  assert!(events.lock().unwrap().iter().all(|event| event.outcome.is_success()));
+
+  // Failures are reported with the error kind of the wrapped storage.
+  let public_jwk = document.methods(None)[0].data().try_public_key_jwk().unwrap().clone();
+  assert!(storage
+    .key_storage()
+    .sign(&KeyId::new("unknown"), b"test", &public_jwk)
+    .await
+    .is_err());
+  let event: StorageOperationEvent = events.lock().unwrap().pop().unwrap();
+  assert_eq!(event.operation, StorageOperation::Sign);
+  assert!(matches!(
+    event.outcome,
+    StorageOperationOutcome::KeyStorageFailure(KeyStorageErrorKind::KeyNotFound)
+  ));
+}
@@ -1,12 +1,22 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod persistence_policy;
 mod stronghold_jwk_storage;
 #[cfg(any(feature = "bbs-plus", test))]
 mod stronghold_jwk_storage_bbs_plus_ext;
+#[cfg(any(feature = "key-derivation", test))]
+mod stronghold_jwk_storage_derivation_ext;
+mod stronghold_jwk_storage_export_ext;
 mod stronghold_key_id;
 
+pub use persistence_policy::*;
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
 
 #[cfg(feature = "bbs-plus")]
 use identity_storage::key_storage::bls::encode_bls_jwk;
@@ -32,6 +42,10 @@ use iota_stronghold::Stronghold;
 #[cfg(feature = "bbs-plus")]
 use jsonprooftoken::jpa::algs::ProofAlgorithm;
 use tokio::sync::MutexGuard;
+use tokio::sync::RwLock;
+use tokio::sync::RwLockReadGuard;
+use tokio::sync::RwLockWriteGuard;
+use tokio::time::MissedTickBehavior;
 #[cfg(feature = "bbs-plus")]
 use zeroize::Zeroizing;
 #[cfg(feature = "bbs-plus")]
@@ -39,35 +53,120 @@ use zkryptium::bbsplus::keys::BBSplusSecretKey;
 
 use crate::stronghold_key_type::StrongholdKeyType;
 use crate::utils::get_client;
+use crate::utils::persist_changes;
 use crate::utils::IDENTITY_VAULT_PATH;
 
 /// Wrapper around a [`StrongholdSecretManager`] that implements the [`KeyIdStorage`](crate::KeyIdStorage)
 /// and [`JwkStorage`](crate::JwkStorage) interfaces.
+///
+/// Read-only operations (e.g. signing or looking up key ids) on clones of the same storage can run concurrently,
+/// while write operations are executed exclusively. When changes are written to the snapshot file is determined by
+/// the storage's [`PersistencePolicy`].
 #[derive(Clone, Debug)]
-pub struct StrongholdStorage(Arc<SecretManager>);
+pub struct StrongholdStorage(Arc<StrongholdStorageInner>);
+
+#[derive(Debug)]
+struct StrongholdStorageInner {
+  secret_manager: SecretManager,
+  persistence_policy: PersistencePolicy,
+  access: RwLock<()>,
+  has_pending_changes: AtomicBool,
+}
 
 impl StrongholdStorage {
-  /// Creates a new [`StrongholdStorage`].
+  /// Creates a new [`StrongholdStorage`] persisting every write operation, see [`PersistencePolicy::OnWrite`].
   pub fn new(stronghold_secret_manager: StrongholdSecretManager) -> Self {
-    Self(Arc::new(SecretManager::Stronghold(stronghold_secret_manager)))
+    Self::with_persistence_policy(stronghold_secret_manager, PersistencePolicy::default())
+  }
+
+  /// Creates a new [`StrongholdStorage`] writing changes to the snapshot file according to `persistence_policy`.
+  ///
+  /// # Panics
+  /// Panics if `persistence_policy` is [`PersistencePolicy::Interval`] and this function is not called from within
+  /// a Tokio runtime, or if the interval's period is zero.
+  pub fn with_persistence_policy(
+    stronghold_secret_manager: StrongholdSecretManager,
+    persistence_policy: PersistencePolicy,
+  ) -> Self {
+    let storage = Self(Arc::new(StrongholdStorageInner {
+      secret_manager: SecretManager::Stronghold(stronghold_secret_manager),
+      persistence_policy,
+      access: RwLock::new(()),
+      has_pending_changes: AtomicBool::new(false),
+    }));
+
+    if let PersistencePolicy::Interval(period) = persistence_policy {
+      spawn_periodic_checkpoint(Arc::downgrade(&storage.0), period);
+    }
+
+    storage
   }
 
   /// Shared reference to the inner [`SecretManager`].
   pub fn as_secret_manager(&self) -> &SecretManager {
-    self.0.as_ref()
+    &self.0.secret_manager
+  }
+
+  /// Returns the [`PersistencePolicy`] of this storage.
+  pub fn persistence_policy(&self) -> PersistencePolicy {
+    self.0.persistence_policy
+  }
+
+  /// Returns `true` if this storage contains changes that have not yet been written to the snapshot file.
+  pub fn has_pending_changes(&self) -> bool {
+    self.0.has_pending_changes.load(Ordering::Acquire)
+  }
+
+  /// Writes all pending changes to the snapshot file.
+  ///
+  /// Waits for in-flight write operations to complete. Does nothing if there are no pending changes.
+  pub async fn checkpoint(&self) -> KeyStorageResult<()> {
+    let _access = self.write_access().await;
+    if !self.0.has_pending_changes.swap(false, Ordering::AcqRel) {
+      return Ok(());
+    }
+
+    let stronghold = self.get_stronghold().await;
+    persist_changes(self.as_secret_manager(), stronghold)
+      .await
+      .map_err(|err| {
+        self.0.has_pending_changes.store(true, Ordering::Release);
+        err
+      })
   }
 
   /// Acquire lock of the inner [`Stronghold`].
   pub(crate) async fn get_stronghold(&self) -> MutexGuard<'_, Stronghold> {
-    match *self.0 {
+    match self.0.secret_manager {
       SecretManager::Stronghold(ref stronghold) => stronghold.inner().await,
       _ => unreachable!("secret manager can be only constructed from stronghold"),
     }
   }
 
+  /// Acquire shared access for a read-only operation.
+  pub(crate) async fn read_access(&self) -> RwLockReadGuard<'_, ()> {
+    self.0.access.read().await
+  }
+
+  /// Acquire exclusive access for an operation modifying the stronghold.
+  pub(crate) async fn write_access(&self) -> RwLockWriteGuard<'_, ()> {
+    self.0.access.write().await
+  }
+
+  /// Persists the changes made while holding `stronghold` according to the storage's [`PersistencePolicy`].
+  pub(crate) async fn commit_changes(&self, stronghold: MutexGuard<'_, Stronghold>) -> KeyStorageResult<()> {
+    match self.0.persistence_policy {
+      PersistencePolicy::OnWrite => persist_changes(self.as_secret_manager(), stronghold).await,
+      _ => {
+        self.0.has_pending_changes.store(true, Ordering::Release);
+        Ok(())
+      }
+    }
+  }
+
   async fn get_ed25519_public_key(&self, key_id: &KeyId) -> KeyStorageResult<Jwk> {
-    let stronghold = self.get_stronghold().await;
-    let client = get_client(&stronghold)?;
+    let _access = self.read_access().await;
+    let client = get_client(&*self.get_stronghold().await)?;
 
     let location = Location::generic(
       IDENTITY_VAULT_PATH.as_bytes().to_vec(),
@@ -97,8 +196,8 @@ impl StrongholdStorage {
 
   #[cfg(feature = "bbs-plus")]
   async fn get_bls12381g2_public_key(&self, key_id: &KeyId) -> KeyStorageResult<Jwk> {
-    let stronghold = self.get_stronghold().await;
-    let client = get_client(&stronghold)?;
+    let _access = self.read_access().await;
+    let client = get_client(&*self.get_stronghold().await)?;
 
     let location = Location::generic(
       IDENTITY_VAULT_PATH.as_bytes().to_vec(),
@@ -132,8 +231,8 @@ impl StrongholdStorage {
   /// Retrieve the public key corresponding to `key_id`.
   #[deprecated(since = "1.3.0", note = "use `get_public_key_with_type` instead")]
   pub async fn get_public_key(&self, key_id: &KeyId) -> KeyStorageResult<Jwk> {
-    let stronghold = self.get_stronghold().await;
-    let client = get_client(&stronghold)?;
+    let _access = self.read_access().await;
+    let client = get_client(&*self.get_stronghold().await)?;
 
     let location = Location::generic(
       IDENTITY_VAULT_PATH.as_bytes().to_vec(),
@@ -161,3 +260,37 @@ impl StrongholdStorage {
     Ok(jwk)
   }
 }
+
+impl Drop for StrongholdStorageInner {
+  fn drop(&mut self) {
+    if *self.has_pending_changes.get_mut() {
+      let SecretManager::Stronghold(ref stronghold_manager) = self.secret_manager else {
+        return;
+      };
+      // Errors cannot be reported from here, see `PersistencePolicy::OnDrop`.
+      let _ = futures::executor::block_on(async {
+        let stronghold = stronghold_manager.inner().await;
+        persist_changes(&self.secret_manager, stronghold).await
+      });
+    }
+  }
+}
+
+/// Periodically persists the pending changes of `storage` until it is dropped.
+fn spawn_periodic_checkpoint(storage: Weak<StrongholdStorageInner>, period: Duration) {
+  let mut interval = tokio::time::interval(period);
+  interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+  tokio::spawn(async move {
+    // The first tick completes immediately.
+    interval.tick().await;
+    loop {
+      interval.tick().await;
+      let Some(inner) = storage.upgrade() else {
+        break;
+      };
+      // A failed checkpoint keeps the changes pending, they are retried on the next tick.
+      let _ = StrongholdStorage(inner).checkpoint().await;
+    }
+  });
+}
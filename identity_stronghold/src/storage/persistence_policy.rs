@@ -0,0 +1,27 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+/// Determines when changes made through a [`StrongholdStorage`](crate::StrongholdStorage) are written to its
+/// snapshot file.
+///
+/// Regardless of the policy, pending changes can be written at any time with
+/// [`StrongholdStorage::checkpoint`](crate::StrongholdStorage::checkpoint).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PersistencePolicy {
+  /// Every write operation (key generation, insertion, deletion, ...) is persisted before it returns.
+  #[default]
+  OnWrite,
+  /// Pending changes are persisted by a background task every given period, as well as when the last handle to
+  /// the storage is dropped.
+  ///
+  /// The period must be non-zero.
+  Interval(Duration),
+  /// Pending changes are only persisted when the last handle to the storage is dropped.
+  ///
+  /// Since errors cannot be reported from a destructor, calling
+  /// [`StrongholdStorage::checkpoint`](crate::StrongholdStorage::checkpoint) before shutting down is recommended.
+  OnDrop,
+}
@@ -14,6 +14,7 @@ use identity_storage::KeyType;
 use identity_verification::jwk::EdCurve;
 use identity_verification::jwk::Jwk;
 use identity_verification::jwk::JwkParamsOkp;
+use identity_verification::jwk::SecretJwk;
 use identity_verification::jws::JwsAlgorithm;
 use identity_verification::jwu;
 use iota_stronghold::procedures::Ed25519Sign;
@@ -32,6 +33,7 @@ use crate::StrongholdStorage;
 #[cfg_attr(feature = "send-sync-storage", async_trait)]
 impl JwkStorage for StrongholdStorage {
   async fn generate(&self, key_type: KeyType, alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    let _access = self.write_access().await;
     let stronghold = self.get_stronghold().await;
 
     let client = get_client(&stronghold)?;
@@ -81,7 +83,7 @@ impl JwkStorage for StrongholdStorage {
           .with_source(err)
       })?;
     let public_key: Vec<u8> = procedure_result.into();
-    persist_changes(self.as_secret_manager(), stronghold).await?;
+    self.commit_changes(stronghold).await?;
 
     let mut params = JwkParamsOkp::new();
     params.x = jwu::encode_b64(public_key);
@@ -95,12 +97,11 @@ impl JwkStorage for StrongholdStorage {
 
   async fn insert(&self, jwk: Jwk) -> KeyStorageResult<KeyId> {
     let key_type = StrongholdKeyType::try_from(&jwk)?;
-    if !jwk.is_private() {
-      return Err(
-        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
-          .with_custom_message("expected a Jwk with all private key components set"),
-      );
-    }
+    let jwk: SecretJwk = SecretJwk::new(jwk).map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("expected a Jwk with all private key components set")
+        .with_source(err)
+    })?;
 
     match jwk.alg() {
       Some(alg) => {
@@ -122,6 +123,7 @@ impl JwkStorage for StrongholdStorage {
       IDENTITY_VAULT_PATH.as_bytes().to_vec(),
       key_id.to_string().as_bytes().to_vec(),
     );
+    let _access = self.write_access().await;
     let stronghold = self.get_stronghold().await;
     let client = get_client(&stronghold)?;
     client
@@ -133,7 +135,7 @@ impl JwkStorage for StrongholdStorage {
           .with_source(err)
       })?;
 
-    persist_changes(self.as_secret_manager(), stronghold).await?;
+    self.commit_changes(stronghold).await?;
 
     Ok(key_id)
   }
@@ -181,8 +183,8 @@ impl JwkStorage for StrongholdStorage {
       msg: data.to_vec(),
     };
 
-    let stronghold = self.get_stronghold().await;
-    let client = get_client(&stronghold)?;
+    let _access = self.read_access().await;
+    let client = get_client(&*self.get_stronghold().await)?;
 
     let signature: [u8; 64] = client.execute_procedure(procedure).map_err(|err| {
       KeyStorageError::new(KeyStorageErrorKind::Unspecified)
@@ -194,6 +196,7 @@ impl JwkStorage for StrongholdStorage {
   }
 
   async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
+    let _access = self.write_access().await;
     let stronghold = self.get_stronghold().await;
     let client = get_client(&stronghold)?;
     let deleted = client
@@ -209,14 +212,14 @@ impl JwkStorage for StrongholdStorage {
       return Err(KeyStorageError::new(KeyStorageErrorKind::KeyNotFound));
     }
 
-    persist_changes(self.as_secret_manager(), stronghold).await?;
+    self.commit_changes(stronghold).await?;
 
     Ok(())
   }
 
   async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
-    let stronghold = self.get_stronghold().await;
-    let client = get_client(&stronghold)?;
+    let _access = self.read_access().await;
+    let client = get_client(&*self.get_stronghold().await)?;
     let location = Location::generic(
       IDENTITY_VAULT_PATH.as_bytes().to_vec(),
       key_id.to_string().as_bytes().to_vec(),
@@ -49,6 +49,7 @@ impl JwkStorageBbsPlusExt for StrongholdStorage {
       kid = random_key_id();
     }
 
+    let _access = self.write_access().await;
     let stronghold = self.get_stronghold().await;
     let client = get_client(&stronghold)?;
     let target_key_location = Location::generic(
@@ -71,7 +72,7 @@ impl JwkStorageBbsPlusExt for StrongholdStorage {
           .with_source(e)
       })?;
 
-    persist_changes(self.as_secret_manager(), stronghold).await?;
+    self.commit_changes(stronghold).await?;
 
     Ok(JwkGenOutput::new(kid, jwk))
   }
@@ -105,8 +106,8 @@ impl JwkStorageBbsPlusExt for StrongholdStorage {
       record_path: key_id.to_string().as_bytes().to_vec(),
     };
 
-    let stronghold = self.get_stronghold().await;
-    let client = get_client(&stronghold)?;
+    let _access = self.read_access().await;
+    let client = get_client(&*self.get_stronghold().await)?;
     client
       .get_guards([sk_location], |[sk]| {
         let sk = BBSplusSecretKey::from_bytes(&sk.borrow()).map_err(|e| FatalProcedureError::from(e.to_string()))?;
@@ -154,8 +155,8 @@ impl JwkStorageBbsPlusExt for StrongholdStorage {
       vault_path: IDENTITY_VAULT_PATH.as_bytes().to_vec(),
       record_path: key_id.to_string().as_bytes().to_vec(),
     };
-    let stronghold = self.get_stronghold().await;
-    let client = get_client(&stronghold)?;
+    let _access = self.read_access().await;
+    let client = get_client(&*self.get_stronghold().await)?;
 
     client
       .get_guards([sk_location], |[sk]| {
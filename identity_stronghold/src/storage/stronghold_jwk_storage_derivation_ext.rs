@@ -0,0 +1,151 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use crypto::keys::bip39::wordlist;
+use crypto::keys::bip39::Mnemonic;
+use crypto::keys::bip39::Passphrase;
+use identity_storage::key_storage::JwkStorageDerivationExt;
+use identity_storage::key_storage::KeyDerivationPath;
+use identity_storage::JwkGenOutput;
+use identity_storage::KeyId;
+use identity_storage::KeyStorageError;
+use identity_storage::KeyStorageErrorKind;
+use identity_storage::KeyStorageResult;
+use identity_storage::KeyType;
+use identity_verification::jwk::EdCurve;
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkParamsOkp;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jwu;
+use iota_stronghold::procedures::BIP39Recover;
+use iota_stronghold::procedures::Curve;
+use iota_stronghold::procedures::KeyType as ProceduresKeyType;
+use iota_stronghold::procedures::Slip10Derive;
+use iota_stronghold::procedures::Slip10DeriveInput;
+use iota_stronghold::procedures::StrongholdProcedure;
+use iota_stronghold::Location;
+
+use crate::stronghold_key_type::StrongholdKeyType;
+use crate::utils::check_key_alg_compatibility;
+use crate::utils::get_client;
+use crate::utils::IDENTITY_VAULT_PATH;
+use crate::StrongholdStorage;
+
+/// The record of the seed keys are derived from.
+const SEED_RECORD_PATH: &[u8] = b"iota_identity_derivation_seed";
+
+fn seed_location() -> Location {
+  Location::generic(IDENTITY_VAULT_PATH.as_bytes().to_vec(), SEED_RECORD_PATH.to_vec())
+}
+
+impl StrongholdStorage {
+  /// Sets the seed keys are derived from through [`JwkStorageDerivationExt::derive`] to the seed of the BIP39
+  /// `mnemonic` and `passphrase`, replacing a previously stored seed.
+  ///
+  /// The mnemonic is only used to recover the seed inside of Stronghold and is not stored itself.
+  /// Fails if `mnemonic` is not a valid mnemonic of the english wordlist.
+  pub async fn store_mnemonic(&self, mnemonic: String, passphrase: String) -> KeyStorageResult<()> {
+    let mnemonic: Mnemonic = Mnemonic::from(mnemonic);
+    wordlist::verify(&mnemonic, &wordlist::ENGLISH).map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message(format!("invalid BIP39 mnemonic: {err:?}"))
+    })?;
+
+    let _access = self.write_access().await;
+    let stronghold = self.get_stronghold().await;
+    let client = get_client(&stronghold)?;
+
+    let recover_procedure = BIP39Recover {
+      mnemonic,
+      passphrase: Passphrase::from(passphrase),
+      output: seed_location(),
+    };
+    client
+      .execute_procedure(StrongholdProcedure::BIP39Recover(recover_procedure))
+      .map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("stronghold BIP39 recover procedure failed")
+          .with_source(err)
+      })?;
+
+    self.commit_changes(stronghold).await
+  }
+}
+
+/// Only Ed25519 keys can be derived. The seed must have been stored with [`StrongholdStorage::store_mnemonic`].
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl JwkStorageDerivationExt for StrongholdStorage {
+  async fn derive(
+    &self,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+    path: &KeyDerivationPath,
+  ) -> KeyStorageResult<JwkGenOutput> {
+    let key_type = StrongholdKeyType::try_from(&key_type)?;
+    if !matches!(key_type, StrongholdKeyType::Ed25519) {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+          .with_custom_message(format!("`{key_type}` keys cannot be derived")),
+      );
+    }
+    check_key_alg_compatibility(key_type, &alg)?;
+
+    let _access = self.write_access().await;
+    let stronghold = self.get_stronghold().await;
+    let client = get_client(&stronghold)?;
+
+    let seed_exists = client
+      .record_exists(&seed_location())
+      .map_err(|err| KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_source(err))?;
+    if !seed_exists {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::KeyNotFound).with_custom_message("no mnemonic has been stored"),
+      );
+    }
+
+    let key_id: KeyId = path.to_key_id();
+    let location = Location::generic(
+      IDENTITY_VAULT_PATH.as_bytes().to_vec(),
+      key_id.to_string().as_bytes().to_vec(),
+    );
+
+    let derive_procedure = Slip10Derive {
+      curve: Curve::Ed25519,
+      chain: path.hardened_indices().collect(),
+      input: Slip10DeriveInput::Seed(seed_location()),
+      output: location.clone(),
+    };
+    client
+      .execute_procedure(StrongholdProcedure::Slip10Derive(derive_procedure))
+      .map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("stronghold slip10 derive procedure failed")
+          .with_source(err)
+      })?;
+
+    let public_key_procedure = iota_stronghold::procedures::PublicKey {
+      ty: ProceduresKeyType::Ed25519,
+      private_key: location,
+    };
+    let public_key: Vec<u8> = client
+      .execute_procedure(StrongholdProcedure::PublicKey(public_key_procedure))
+      .map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("stronghold public key procedure failed")
+          .with_source(err)
+      })?
+      .into();
+    self.commit_changes(stronghold).await?;
+
+    let mut params = JwkParamsOkp::new();
+    params.x = jwu::encode_b64(public_key);
+    params.crv = EdCurve::Ed25519.name().to_string();
+    let mut jwk: Jwk = Jwk::from_params(params);
+    jwk.set_alg(alg.name());
+    jwk.set_kid(jwk.thumbprint_sha256_b64());
+
+    Ok(JwkGenOutput::new(key_id, jwk))
+  }
+}
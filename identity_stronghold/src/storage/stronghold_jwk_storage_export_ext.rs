@@ -0,0 +1,54 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use crypto::signatures::ed25519::SecretKey;
+use identity_storage::key_storage::JwkStorageExportExt;
+use identity_storage::KeyId;
+use identity_storage::KeyStorageError;
+use identity_storage::KeyStorageErrorKind;
+use identity_storage::KeyStorageResult;
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::SecretJwk;
+use identity_verification::jws::JwsAlgorithm;
+use iota_stronghold::procedures::FatalProcedureError;
+use iota_stronghold::procedures::Runner as _;
+use iota_stronghold::Location;
+use zeroize::Zeroizing;
+
+use crate::ed25519::encode_jwk;
+use crate::utils::get_client;
+use crate::utils::IDENTITY_VAULT_PATH;
+use crate::StrongholdStorage;
+
+/// Only Ed25519 keys generated or inserted through [`JwkStorage`](identity_storage::JwkStorage) can be exported.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl JwkStorageExportExt for StrongholdStorage {
+  async fn export(&self, key_id: &KeyId) -> KeyStorageResult<SecretJwk> {
+    let _access = self.read_access().await;
+    let client = get_client(&*self.get_stronghold().await)?;
+
+    let location = Location::generic(
+      IDENTITY_VAULT_PATH.as_bytes().to_vec(),
+      key_id.to_string().as_bytes().to_vec(),
+    );
+
+    client
+      .get_guards([location], |[sk]| {
+        let sk_bytes: Zeroizing<[u8; SecretKey::LENGTH]> = sk
+          .borrow()
+          .as_ref()
+          .try_into()
+          .map(Zeroizing::new)
+          .map_err(|_| FatalProcedureError::from(format!("expected key of length {}", SecretKey::LENGTH)))?;
+        let sk: SecretKey = SecretKey::from_bytes(&sk_bytes);
+        let mut jwk: Jwk = encode_jwk(&sk, &sk.public_key());
+        jwk.set_alg(JwsAlgorithm::EdDSA.name());
+        jwk.set_kid(jwk.thumbprint_sha256_b64());
+
+        Ok(SecretJwk::new(jwk).expect("encoded keys are private"))
+      })
+      .map_err(|err| KeyStorageError::new(KeyStorageErrorKind::KeyNotFound).with_source(err))
+  }
+}
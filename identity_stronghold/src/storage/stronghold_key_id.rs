@@ -13,12 +13,12 @@ use identity_storage::KeyIdStorageErrorKind;
 use iota_stronghold::Client;
 use iota_stronghold::ClientError;
 use iota_stronghold::Stronghold;
-use tokio::sync::MutexGuard;
 
 #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync-storage", async_trait)]
 impl KeyIdStorage for StrongholdStorage {
   async fn insert_key_id(&self, method_digest: MethodDigest, key_id: KeyId) -> KeyIdStorageResult<()> {
+    let _access = self.write_access().await;
     let stronghold = self.get_stronghold().await;
     let client = get_client(&stronghold)?;
     let store = client.store();
@@ -35,13 +35,16 @@ impl KeyIdStorage for StrongholdStorage {
       .store()
       .insert(method_digest_pack, key_id.into(), None)
       .map_err(|err| KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified).with_source(err))?;
-    persist_changes(self, stronghold).await?;
+    self
+      .commit_changes(stronghold)
+      .await
+      .map_err(|err| KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified).with_source(err))?;
     Ok(())
   }
 
   async fn get_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<KeyId> {
-    let stronghold = self.get_stronghold().await;
-    let store = get_client(&stronghold)?.store();
+    let _access = self.read_access().await;
+    let store = get_client(&*self.get_stronghold().await)?.store();
     let method_digest_pack: Vec<u8> = method_digest.pack();
     let key_id_bytes: Vec<u8> = store
       .get(method_digest_pack.as_ref())
@@ -56,6 +59,7 @@ impl KeyIdStorage for StrongholdStorage {
   }
 
   async fn delete_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<()> {
+    let _access = self.write_access().await;
     let stronghold = self.get_stronghold().await;
     let store = get_client(&stronghold)?.store();
     let key: Vec<u8> = method_digest.pack();
@@ -65,7 +69,10 @@ impl KeyIdStorage for StrongholdStorage {
       .map_err(|err| KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified).with_source(err))?
       .ok_or(KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound))?;
 
-    persist_changes(self, stronghold).await?;
+    self
+      .commit_changes(stronghold)
+      .await
+      .map_err(|err| KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified).with_source(err))?;
     Ok(())
   }
 }
@@ -88,36 +95,3 @@ fn load_or_create_client(stronghold: &Stronghold) -> KeyIdStorageResult<Client>
     Err(err) => Err(KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified).with_source(err)),
   }
 }
-
-async fn persist_changes(
-  secret_manager: &StrongholdStorage,
-  stronghold: MutexGuard<'_, Stronghold>,
-) -> KeyIdStorageResult<()> {
-  stronghold.write_client(IDENTITY_CLIENT_PATH).map_err(|err| {
-    KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified)
-      .with_custom_message("stronghold write client error")
-      .with_source(err)
-  })?;
-  // Must be dropped since `write_stronghold_snapshot` requires the stronghold instance.
-  drop(stronghold);
-  match secret_manager.as_secret_manager() {
-    iota_sdk::client::secret::SecretManager::Stronghold(stronghold_manager) => {
-      stronghold_manager
-        .write_stronghold_snapshot(None)
-        .await
-        .map_err(|err| {
-          KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified)
-            .with_custom_message("writing to stronghold snapshot failed")
-            .with_source(err)
-        })?;
-    }
-    _ => {
-      return Err(
-        KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified)
-          .with_custom_message("secret manager is not of type stronghold"),
-      )
-    }
-  };
-
-  Ok(())
-}
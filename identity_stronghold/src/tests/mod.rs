@@ -3,5 +3,7 @@
 
 mod test_bbs_ext;
 mod test_jwk_storage;
+mod test_key_derivation;
 mod test_key_id_storage;
+mod test_persistence_policy;
 pub(crate) mod utils;
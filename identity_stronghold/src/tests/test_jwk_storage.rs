@@ -68,6 +68,13 @@ async fn key_exists() {
   jwk_storage_tests::test_key_exists(stronghold_storage).await;
 }
 
+#[tokio::test]
+async fn export() {
+  let stronghold_secret_manager = create_stronghold_secret_manager();
+  let stronghold_storage = StrongholdStorage::new(stronghold_secret_manager);
+  jwk_storage_tests::test_export(stronghold_storage).await;
+}
+
 // Tests the cases that require persisting to disk, generate, insert and delete.
 #[tokio::test]
 async fn write_to_disk() {
@@ -129,6 +136,7 @@ mod jwk_storage_tests {
   use crypto::signatures::ed25519::SecretKey;
   use crypto::signatures::ed25519::Signature;
   use identity_storage::key_storage::JwkStorage;
+  use identity_storage::key_storage::JwkStorageExportExt;
   use identity_storage::key_storage::KeyId;
   use identity_storage::key_storage::KeyStorageErrorKind;
   use identity_storage::key_storage::KeyType;
@@ -138,6 +146,7 @@ mod jwk_storage_tests {
   use identity_verification::jose::jwu;
   use identity_verification::jwk::EcCurve;
   use identity_verification::jwk::JwkParamsEc;
+  use identity_verification::jwk::SecretJwk;
   use identity_verification::jws::JwsAlgorithm;
 
   use crate::ed25519;
@@ -199,6 +208,23 @@ mod jwk_storage_tests {
     store.delete(&key_id).await.unwrap();
   }
 
+  pub(crate) async fn test_export(store: impl JwkStorageExportExt) {
+    let generate = store
+      .generate(KeyType::new("Ed25519"), JwsAlgorithm::EdDSA)
+      .await
+      .unwrap();
+    let exported: SecretJwk = store.export(&generate.key_id).await.unwrap();
+    assert!(exported.expose_secret().is_private());
+    assert_eq!(exported.to_public().unwrap(), generate.jwk);
+
+    // The exported secret key corresponds to the generated public key.
+    let expanded = ed25519::expand_secret_jwk(&exported).unwrap();
+    assert_eq!(expanded.public_key(), expand_public_jwk(&generate.jwk));
+
+    let err = store.export(&KeyId::new("non-existent-id")).await.unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::KeyNotFound));
+  }
+
   pub(crate) async fn test_key_exists(store: impl JwkStorage) {
     assert!(!store.exists(&KeyId::new("non-existent-id")).await.unwrap());
   }
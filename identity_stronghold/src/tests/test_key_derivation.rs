@@ -0,0 +1,74 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_storage::key_storage::JwkStorage;
+use identity_storage::key_storage::JwkStorageDerivationExt;
+use identity_storage::key_storage::KeyDerivationPath;
+use identity_storage::key_storage::KeyType;
+use identity_storage::KeyStorageErrorKind;
+use identity_verification::jws::JwsAlgorithm;
+
+use crate::tests::utils::create_stronghold_secret_manager;
+use crate::StrongholdStorage;
+
+// The BIP39 test vector mnemonic.
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+fn path() -> KeyDerivationPath {
+  "m/44'/4218'/0'/0'/0'".parse().unwrap()
+}
+
+#[tokio::test]
+async fn derive_from_mnemonic() {
+  let storage = StrongholdStorage::new(create_stronghold_secret_manager());
+
+  let error = storage
+    .derive(KeyType::new("Ed25519"), JwsAlgorithm::EdDSA, &path())
+    .await
+    .unwrap_err();
+  assert!(matches!(error.kind(), KeyStorageErrorKind::KeyNotFound));
+
+  storage
+    .store_mnemonic(MNEMONIC.to_owned(), String::new())
+    .await
+    .unwrap();
+  let output = storage
+    .derive(KeyType::new("Ed25519"), JwsAlgorithm::EdDSA, &path())
+    .await
+    .unwrap();
+
+  assert_eq!(output.key_id, path().to_key_id());
+  assert!(storage.exists(&output.key_id).await.unwrap());
+  // Matches the key derived by `JwkMemStore` from the same mnemonic.
+  assert_eq!(
+    output.jwk.try_okp_params().unwrap().x,
+    "kxxUtniDfPlqSe4dESICf6ut8K7pfZ-QlBh9uL45b2M"
+  );
+
+  // The key can be recovered in another storage from the mnemonic alone.
+  let restored = StrongholdStorage::new(create_stronghold_secret_manager());
+  restored
+    .store_mnemonic(MNEMONIC.to_owned(), String::new())
+    .await
+    .unwrap();
+  let recovered = restored
+    .derive(KeyType::new("Ed25519"), JwsAlgorithm::EdDSA, &path())
+    .await
+    .unwrap();
+  assert_eq!(recovered.jwk, output.jwk);
+
+  let signature = restored.sign(&recovered.key_id, b"test", &recovered.jwk).await.unwrap();
+  assert_eq!(
+    signature,
+    storage.sign(&output.key_id, b"test", &output.jwk).await.unwrap()
+  );
+}
+
+#[tokio::test]
+async fn invalid_mnemonic_is_rejected() {
+  let storage = StrongholdStorage::new(create_stronghold_secret_manager());
+  assert!(storage
+    .store_mnemonic("not a mnemonic".to_owned(), String::new())
+    .await
+    .is_err());
+}
@@ -0,0 +1,105 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use identity_storage::key_storage::JwkStorage;
+use identity_storage::key_storage::KeyType;
+use identity_storage::KeyId;
+use identity_verification::jws::JwsAlgorithm;
+use iota_sdk::client::secret::stronghold::StrongholdSecretManager;
+use iota_sdk::client::Password;
+
+use super::utils::create_temp_file;
+use crate::PersistencePolicy;
+use crate::StrongholdStorage;
+
+const PASS: &str = "secure_password";
+
+fn open_storage(file: &Path, persistence_policy: PersistencePolicy) -> StrongholdStorage {
+  iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+  let secret_manager = StrongholdSecretManager::builder()
+    .password(Password::from(PASS.to_owned()))
+    .build(file)
+    .unwrap();
+  StrongholdStorage::with_persistence_policy(secret_manager, persistence_policy)
+}
+
+async fn generate_key(storage: &StrongholdStorage) -> KeyId {
+  storage
+    .generate(KeyType::new("Ed25519"), JwsAlgorithm::EdDSA)
+    .await
+    .unwrap()
+    .key_id
+}
+
+#[tokio::test]
+async fn on_write_persists_immediately() {
+  let file: PathBuf = create_temp_file();
+  let storage = open_storage(&file, PersistencePolicy::OnWrite);
+  let key_id = generate_key(&storage).await;
+  assert!(!storage.has_pending_changes());
+
+  let reopened = open_storage(&file, PersistencePolicy::OnWrite);
+  assert!(reopened.exists(&key_id).await.unwrap());
+}
+
+#[tokio::test]
+async fn checkpoint_persists_pending_changes() {
+  let file: PathBuf = create_temp_file();
+  let storage = open_storage(&file, PersistencePolicy::OnDrop);
+  let key_id = generate_key(&storage).await;
+  assert!(storage.has_pending_changes());
+
+  storage.checkpoint().await.unwrap();
+  assert!(!storage.has_pending_changes());
+
+  let reopened = open_storage(&file, PersistencePolicy::OnWrite);
+  assert!(reopened.exists(&key_id).await.unwrap());
+
+  // Nothing to persist.
+  storage.checkpoint().await.unwrap();
+}
+
+#[tokio::test]
+async fn on_drop_persists_when_last_handle_is_dropped() {
+  let file: PathBuf = create_temp_file();
+  let storage = open_storage(&file, PersistencePolicy::OnDrop);
+  let clone = storage.clone();
+  let key_id = generate_key(&storage).await;
+
+  drop(storage);
+  drop(clone);
+
+  let reopened = open_storage(&file, PersistencePolicy::OnWrite);
+  assert!(reopened.exists(&key_id).await.unwrap());
+}
+
+#[tokio::test]
+async fn interval_persists_periodically() {
+  let file: PathBuf = create_temp_file();
+  let storage = open_storage(&file, PersistencePolicy::Interval(Duration::from_millis(50)));
+  let key_id = generate_key(&storage).await;
+
+  for _ in 0..100 {
+    if !storage.has_pending_changes() {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(20)).await;
+  }
+  assert!(!storage.has_pending_changes());
+
+  let reopened = open_storage(&file, PersistencePolicy::OnWrite);
+  assert!(reopened.exists(&key_id).await.unwrap());
+}
+
+#[tokio::test]
+async fn concurrent_reads() {
+  let storage = open_storage(&create_temp_file(), PersistencePolicy::OnDrop);
+  let key_id = generate_key(&storage).await;
+
+  let results = futures::future::join_all((0..10).map(|_| storage.exists(&key_id))).await;
+  assert!(results.into_iter().all(|exists| exists.unwrap()));
+}
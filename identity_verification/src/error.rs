@@ -29,6 +29,9 @@ pub enum Error {
   /// Caused by key material in a [`MethodData`](crate::MethodData) that is expected to be multibase encoded.
   #[error("invalid multibase key data")]
   InvalidKeyDataMultibase,
+  /// Caused by key material that is not a valid or supported [`Multikey`](crate::Multikey).
+  #[error("invalid multikey: {0}")]
+  InvalidMultikey(&'static str),
   /// Caused by attempting to decode [`MethodData`](crate::MethodData) that is not in the expected encoding.
   #[error("the method data could not be transformed to the desired type")]
   InvalidMethodDataTransformation(&'static str),
@@ -39,4 +42,13 @@ pub enum Error {
   /// Caused by key material that is not a JSON Web Key.
   #[error("verification material format is not publicKeyJwk")]
   NotPublicKeyJwk,
+  /// Caused by key material that does not match the [`MethodTypeDefinition`](crate::MethodTypeDefinition)
+  /// registered for the type of a [`VerificationMethod`](crate::VerificationMethod).
+  #[error("invalid key material for method type `{method_type}`: {reason}")]
+  InvalidMethodData {
+    /// The type of the verification method.
+    method_type: crate::MethodType,
+    /// The reason the key material is invalid.
+    reason: String,
+  },
 }
@@ -16,6 +16,12 @@ pub mod jws {
   pub use identity_jose::jws::*;
 }
 
+pub mod jwt {
+  //! Reexport of [identity_jose::jwt].
+
+  pub use identity_jose::jwt::*;
+}
+
 pub mod jwu {
   //! Reexport of [identity_jose::jwu].
 
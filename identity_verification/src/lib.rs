@@ -25,5 +25,6 @@ pub use error::Error;
 pub use error::Result;
 pub use jose::jwk;
 pub use jose::jws;
+pub use jose::jwt;
 pub use jose::jwu;
 pub use verification_method::*;
@@ -11,9 +11,13 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde::Serializer;
 use serde_json::Value;
+use std::borrow::Cow;
 
 use crate::error::Error;
 use crate::error::Result;
+use crate::verification_method::Multikey;
+
+const BLOCKCHAIN_ACCOUNT_ID: &str = "blockchainAccountId";
 
 /// Supported verification method data formats.
 #[allow(clippy::large_enum_variant)]
@@ -45,11 +49,25 @@ impl MethodData {
     Self::PublicKeyMultibase(BaseEncoding::encode_multibase(&data, None))
   }
 
+  /// Creates a new `MethodData` variant with the [`Multikey`] encoding of a public key.
+  pub fn new_multikey(multikey: &Multikey) -> Self {
+    Self::PublicKeyMultibase(multikey.to_multibase())
+  }
+
   /// Creates a new `MethodData` variant from custom data.
   pub fn new_custom(data: impl Into<CustomMethodData>) -> Self {
     Self::Custom(data.into())
   }
 
+  /// Creates a new `MethodData` variant with the `blockchainAccountId` of an account, given as a
+  /// [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-10.md) account id.
+  pub fn new_blockchain_account_id(account_id: impl Into<String>) -> Self {
+    Self::Custom(CustomMethodData {
+      name: BLOCKCHAIN_ACCOUNT_ID.to_owned(),
+      data: Value::String(account_id.into()),
+    })
+  }
+
   /// Returns a `Vec<u8>` containing the decoded bytes of the `MethodData`.
   ///
   /// This is generally a public key identified by a `MethodType` value.
@@ -83,6 +101,40 @@ impl MethodData {
     self.public_key_jwk().ok_or(Error::NotPublicKeyJwk)
   }
 
+  /// Decodes the [`Multikey`] in the `publicKeyMultibase` entry.
+  ///
+  /// # Errors
+  /// Fails if `MethodData` is not [`MethodData::PublicKeyMultibase`] or does not hold a supported [`Multikey`].
+  pub fn try_multikey(&self) -> Result<Multikey> {
+    match self {
+      Self::PublicKeyMultibase(multibase) => Multikey::from_multibase(multibase),
+      _ => Err(Error::InvalidMethodDataTransformation(
+        "method data is not publicKeyMultibase",
+      )),
+    }
+  }
+
+  /// Returns the public key as a [`Jwk`], converting it from a [`Multikey`] in the `publicKeyMultibase` entry if
+  /// necessary.
+  ///
+  /// # Errors
+  /// Fails if `MethodData` is neither a [`Jwk`] nor a [`Multikey`] that can be converted to one.
+  pub fn try_to_public_key_jwk(&self) -> Result<Cow<'_, Jwk>> {
+    match self {
+      Self::PublicKeyJwk(jwk) => Ok(Cow::Borrowed(jwk)),
+      Self::PublicKeyMultibase(_) => self.try_multikey()?.to_jwk().map(Cow::Owned),
+      _ => Err(Error::NotPublicKeyJwk),
+    }
+  }
+
+  /// Returns the CAIP-10 account id in the `blockchainAccountId` entry, if any.
+  pub fn blockchain_account_id(&self) -> Option<&str> {
+    match self {
+      Self::Custom(CustomMethodData { name, data }) if name == BLOCKCHAIN_ACCOUNT_ID => data.as_str(),
+      _ => None,
+    }
+  }
+
   /// Returns the custom method data, if any.
   pub fn custom(&self) -> Option<&CustomMethodData> {
     if let Self::Custom(method_data) = self {
@@ -104,6 +156,49 @@ impl Debug for MethodData {
   }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for MethodData {
+  fn schema_name() -> String {
+    "MethodData".to_owned()
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    use schemars::schema::InstanceType;
+    use schemars::schema::ObjectValidation;
+    use schemars::schema::Schema;
+    use schemars::schema::SchemaObject;
+    use schemars::schema::SubschemaValidation;
+
+    // An object with the verification material as its only property.
+    let material = |property: &str, schema: Schema| -> Schema {
+      SchemaObject {
+        instance_type: Some(InstanceType::Object.into()),
+        object: Some(Box::new(ObjectValidation {
+          properties: [(property.to_owned(), schema)].into_iter().collect(),
+          required: [property.to_owned()].into_iter().collect(),
+          ..Default::default()
+        })),
+        ..Default::default()
+      }
+      .into()
+    };
+
+    SchemaObject {
+      subschemas: Some(Box::new(SubschemaValidation {
+        one_of: Some(vec![
+          material("publicKeyMultibase", gen.subschema_for::<String>()),
+          material("publicKeyBase58", gen.subschema_for::<String>()),
+          material("publicKeyJwk", gen.subschema_for::<Jwk>()),
+          gen.subschema_for::<CustomMethodData>(),
+        ]),
+        ..Default::default()
+      })),
+      ..Default::default()
+    }
+    .into()
+  }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 /// Custom verification method.
 pub struct CustomMethodData {
@@ -133,6 +228,27 @@ impl<'de> Deserialize<'de> for CustomMethodData {
   }
 }
 
+// An object with the custom verification material as its only property.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CustomMethodData {
+  fn schema_name() -> String {
+    "CustomMethodData".to_owned()
+  }
+
+  fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+      instance_type: Some(schemars::schema::InstanceType::Object.into()),
+      object: Some(Box::new(schemars::schema::ObjectValidation {
+        min_properties: Some(1),
+        max_properties: Some(1),
+        ..Default::default()
+      })),
+      ..Default::default()
+    }
+    .into()
+  }
+}
+
 struct CustomMethodDataVisitor;
 
 impl<'de> Visitor<'de> for CustomMethodDataVisitor {
@@ -173,6 +289,22 @@ mod tests {
     .to_string();
     assert_eq!(serde_json::to_string(&custom).unwrap(), target_str);
   }
+  #[test]
+  fn blockchain_account_id() {
+    let account_id: &str = "eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a";
+    let data: MethodData = MethodData::new_blockchain_account_id(account_id);
+    assert_eq!(data.blockchain_account_id(), Some(account_id));
+    assert_eq!(
+      serde_json::to_value(&data).unwrap(),
+      json!({ "blockchainAccountId": account_id })
+    );
+    assert_eq!(
+      serde_json::from_value::<MethodData>(json!({ "blockchainAccountId": account_id })).unwrap(),
+      data
+    );
+    assert!(MethodData::new_base58([1, 2, 3]).blockchain_account_id().is_none());
+  }
+
   #[test]
   fn deserialize_custom_method_data() {
     let inner_data = json!({
@@ -21,6 +21,8 @@ use crate::verification_method::MethodBuilder;
 use crate::verification_method::MethodData;
 use crate::verification_method::MethodRef;
 use crate::verification_method::MethodType;
+use crate::verification_method::MethodTypeRegistry;
+use crate::verification_method::Multikey;
 use crate::CustomMethodData;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
@@ -30,7 +32,8 @@ use identity_did::DID;
 ///
 /// [Specification](https://www.w3.org/TR/did-core/#verification-method-properties)
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(from = "_VerificationMethod")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "_VerificationMethod")]
 pub struct VerificationMethod {
   pub(crate) id: DIDUrl,
   pub(crate) controller: CoreDID,
@@ -79,11 +82,15 @@ impl VerificationMethod {
       }
     };
 
+    let type_: MethodType = builder.type_.ok_or(Error::InvalidMethod("missing type"))?;
+    let data: MethodData = builder.data.ok_or(Error::InvalidMethod("missing data"))?;
+    MethodTypeRegistry::validate(&type_, &data)?;
+
     Ok(VerificationMethod {
       id,
       controller: builder.controller.ok_or(Error::InvalidMethod("missing controller"))?,
-      type_: builder.type_.ok_or(Error::InvalidMethod("missing type"))?,
-      data: builder.data.ok_or(Error::InvalidMethod("missing data"))?,
+      type_,
+      data,
       properties: builder.properties,
     })
   }
@@ -225,6 +232,25 @@ impl VerificationMethod {
       .data(MethodData::PublicKeyJwk(key))
       .build()
   }
+
+  /// Creates a new [`VerificationMethod`] of type [`MethodType::MULTIKEY`] from the given `did` and [`Multikey`].
+  /// If `fragment` is not given the multibase encoding of the key is used, as is customary for `did:key`.
+  pub fn new_from_multikey<D: DID>(did: D, key: &Multikey, fragment: Option<&str>) -> Result<Self> {
+    let multibase: String = key.to_multibase();
+    let fragment: String = match fragment.unwrap_or(&multibase) {
+      fragment if fragment.starts_with('#') => fragment.to_owned(),
+      fragment => format!("#{fragment}"),
+    };
+
+    let id: DIDUrl = did.to_url().join(fragment).map_err(Error::DIDUrlConstructionError)?;
+
+    MethodBuilder::default()
+      .id(id)
+      .controller(did.into())
+      .type_(MethodType::MULTIKEY)
+      .data(MethodData::PublicKeyMultibase(multibase))
+      .build()
+  }
 }
 
 impl Display for VerificationMethod {
@@ -260,6 +286,7 @@ impl TryFrom<DIDJwk> for VerificationMethod {
 // the input when deserializing flattened enums (MethodData in this case) causing duplication of data (in this case
 // it ends up in the properties object). This workaround simply removes the duplication.
 #[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 struct _VerificationMethod {
   #[serde(deserialize_with = "deserialize_id_with_fragment")]
   pub(crate) id: DIDUrl,
@@ -272,8 +299,10 @@ struct _VerificationMethod {
   pub(crate) properties: Object,
 }
 
-impl From<_VerificationMethod> for VerificationMethod {
-  fn from(value: _VerificationMethod) -> Self {
+impl TryFrom<_VerificationMethod> for VerificationMethod {
+  type Error = Error;
+
+  fn try_from(value: _VerificationMethod) -> Result<Self, Self::Error> {
     let _VerificationMethod {
       id,
       controller,
@@ -288,13 +317,14 @@ impl From<_VerificationMethod> for VerificationMethod {
       MethodData::Custom(CustomMethodData { name, .. }) => name.as_str(),
     };
     properties.remove(key);
+    MethodTypeRegistry::validate(&type_, &data)?;
 
-    VerificationMethod {
+    Ok(VerificationMethod {
       id,
       controller,
       type_,
       data,
       properties,
-    }
+    })
   }
 }
@@ -12,6 +12,7 @@ use identity_did::DIDUrl;
 
 /// A reference to a verification method, either a `DID` or embedded `Method`.
 #[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum MethodRef {
   /// A [`VerificationMethod`] embedded in a verification relationship.
@@ -5,6 +5,7 @@
 ///
 /// See also: <https://www.w3.org/TR/did-core/#verification-relationships>.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, strum::IntoStaticStr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum MethodRelationship {
   /// The authentication verification relationship.
   Authentication,
@@ -17,3 +18,14 @@ pub enum MethodRelationship {
   /// The capability invocation verification relationship.
   CapabilityInvocation,
 }
+
+impl MethodRelationship {
+  /// All verification relationships, in the order they appear in a DID document.
+  pub const ALL: [Self; 5] = [
+    Self::Authentication,
+    Self::AssertionMethod,
+    Self::KeyAgreement,
+    Self::CapabilityDelegation,
+    Self::CapabilityInvocation,
+  ];
+}
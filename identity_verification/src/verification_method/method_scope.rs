@@ -16,6 +16,7 @@ use crate::verification_method::MethodRelationship;
 /// Can either refer to a generic method embedded in the verification method field,
 /// or to a verification relationship.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum MethodScope {
   /// The scope of generic verification methods.
   VerificationMethod,
@@ -56,6 +57,21 @@ impl MethodScope {
   pub const fn key_agreement() -> Self {
     Self::VerificationRelationship(MethodRelationship::KeyAgreement)
   }
+
+  /// Returns an iterator over all scopes, starting with [`MethodScope::VerificationMethod`] followed by the scopes of
+  /// [`MethodRelationship::ALL`].
+  pub fn all() -> impl Iterator<Item = Self> {
+    core::iter::once(Self::VerificationMethod)
+      .chain(MethodRelationship::ALL.into_iter().map(Self::VerificationRelationship))
+  }
+
+  /// Returns the [`MethodRelationship`] of this scope, or `None` for [`MethodScope::VerificationMethod`].
+  pub fn relationship(&self) -> Option<MethodRelationship> {
+    match self {
+      Self::VerificationMethod => None,
+      Self::VerificationRelationship(relationship) => Some(*relationship),
+    }
+  }
 }
 
 impl Default for MethodScope {
@@ -13,9 +13,12 @@ const ED25519_VERIFICATION_KEY_2018_STR: &str = "Ed25519VerificationKey2018";
 const X25519_KEY_AGREEMENT_KEY_2019_STR: &str = "X25519KeyAgreementKey2019";
 const JSON_WEB_KEY_METHOD_TYPE: &str = "JsonWebKey";
 const JSON_WEB_KEY_2020_STR: &str = "JsonWebKey2020";
+const ECDSA_SECP256K1_RECOVERY_METHOD_2020_STR: &str = "EcdsaSecp256k1RecoveryMethod2020";
+const MULTIKEY_STR: &str = "Multikey";
 
 /// verification method types.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MethodType(Cow<'static, str>);
 
 impl MethodType {
@@ -30,6 +33,12 @@ impl MethodType {
   /// A verification method for use with JWT verification as prescribed by the [`Jwk`](::identity_jose::jwk::Jwk)
   /// in the [`publicKeyJwk`](crate::MethodData::PublicKeyJwk) entry.
   pub const JSON_WEB_KEY_2020: Self = Self(Cow::Borrowed(JSON_WEB_KEY_2020_STR));
+  /// A verification method for secp256k1 signatures from which the public key of the signer can be recovered, e.g.
+  /// of the blockchain account in the [`blockchainAccountId`](crate::MethodData::blockchain_account_id) entry.
+  pub const ECDSA_SECP256K1_RECOVERY_METHOD_2020: Self = Self(Cow::Borrowed(ECDSA_SECP256K1_RECOVERY_METHOD_2020_STR));
+  /// A verification method holding a [`Multikey`](crate::Multikey) in the
+  /// [`publicKeyMultibase`](crate::MethodData::PublicKeyMultibase) entry.
+  pub const MULTIKEY: Self = Self(Cow::Borrowed(MULTIKEY_STR));
   /// Construct a custom method type.
   pub fn custom(type_: impl AsRef<str>) -> Self {
     Self(Cow::Owned(type_.as_ref().to_owned()))
@@ -67,6 +76,8 @@ impl FromStr for MethodType {
         Self::JSON_WEB_KEY,
       ),
       JSON_WEB_KEY_2020_STR => Ok(Self::JSON_WEB_KEY_2020),
+      ECDSA_SECP256K1_RECOVERY_METHOD_2020_STR => Ok(Self::ECDSA_SECP256K1_RECOVERY_METHOD_2020),
+      MULTIKEY_STR => Ok(Self::MULTIKEY),
       _ => Ok(Self(Cow::Owned(string.to_owned()))),
     }
   }
@@ -84,6 +95,8 @@ mod tests {
       MethodType::ED25519_VERIFICATION_KEY_2018,
       MethodType::X25519_KEY_AGREEMENT_KEY_2019,
       MethodType::JSON_WEB_KEY_2020,
+      MethodType::ECDSA_SECP256K1_RECOVERY_METHOD_2020,
+      MethodType::MULTIKEY,
     ] {
       let ser: Value = serde_json::to_value(method_type.clone()).unwrap();
       assert_eq!(ser.as_str().unwrap(), method_type.as_str());
@@ -0,0 +1,251 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::verification_method::MethodData;
+use crate::verification_method::MethodType;
+use crate::CustomMethodData;
+
+/// Callback validating the key material of a verification method of a registered [`MethodType`].
+type ValidatorCallback = Arc<dyn Fn(&MethodData) -> Result<(), String> + Send + Sync>;
+
+static REGISTRY: Lazy<RwLock<HashMap<MethodType, MethodTypeDefinition>>> = Lazy::new(Default::default);
+
+/// The format in which the key material of a verification method is expressed.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MethodDataFormat {
+  /// Key material in the `publicKeyMultibase` property.
+  PublicKeyMultibase,
+  /// Key material in the `publicKeyBase58` property.
+  PublicKeyBase58,
+  /// Key material in the `publicKeyJwk` property.
+  PublicKeyJwk,
+  /// Key material in a custom property with the given name.
+  Custom(String),
+}
+
+impl MethodDataFormat {
+  /// Returns the format of `data`.
+  pub fn of(data: &MethodData) -> Self {
+    match data {
+      MethodData::PublicKeyMultibase(_) => Self::PublicKeyMultibase,
+      MethodData::PublicKeyBase58(_) => Self::PublicKeyBase58,
+      MethodData::PublicKeyJwk(_) => Self::PublicKeyJwk,
+      MethodData::Custom(CustomMethodData { name, .. }) => Self::Custom(name.clone()),
+    }
+  }
+}
+
+/// Describes the key material expected for verification methods of a custom [`MethodType`].
+///
+/// Once registered with [`MethodTypeRegistry::register`], verification methods of the type are validated against it
+/// when they are built with a [`MethodBuilder`](crate::MethodBuilder) or deserialized, e.g. as part of a DID
+/// document. Verification methods of unregistered types are not validated.
+#[derive(Clone)]
+pub struct MethodTypeDefinition {
+  type_: MethodType,
+  formats: Vec<MethodDataFormat>,
+  validator: Option<ValidatorCallback>,
+}
+
+impl MethodTypeDefinition {
+  /// Creates a new [`MethodTypeDefinition`] for `type_`, accepting key material in any format.
+  pub fn new(type_: MethodType) -> Self {
+    Self {
+      type_,
+      formats: Vec::new(),
+      validator: None,
+    }
+  }
+
+  /// Adds a format the key material is expected in. Any format is accepted if none is added.
+  #[must_use]
+  pub fn format(mut self, format: MethodDataFormat) -> Self {
+    self.formats.push(format);
+    self
+  }
+
+  /// Sets a callback further validating the key material, returning the reason it is invalid on failure.
+  ///
+  /// The callback is only invoked on key material in one of the expected formats.
+  #[must_use]
+  pub fn validator<F>(mut self, validator: F) -> Self
+  where
+    F: Fn(&MethodData) -> Result<(), String> + Send + Sync + 'static,
+  {
+    self.validator = Some(Arc::new(validator));
+    self
+  }
+
+  /// Returns the [`MethodType`] this definition describes.
+  pub fn type_(&self) -> &MethodType {
+    &self.type_
+  }
+
+  /// Returns the formats the key material is expected in.
+  pub fn formats(&self) -> &[MethodDataFormat] {
+    &self.formats
+  }
+
+  /// Validates `data` against this definition.
+  pub fn validate(&self, data: &MethodData) -> Result<()> {
+    if !self.formats.is_empty() && !self.formats.contains(&MethodDataFormat::of(data)) {
+      return Err(Error::InvalidMethodData {
+        method_type: self.type_.clone(),
+        reason: "unexpected key material format".to_owned(),
+      });
+    }
+
+    if let Some(validator) = &self.validator {
+      validator(data).map_err(|reason| Error::InvalidMethodData {
+        method_type: self.type_.clone(),
+        reason,
+      })?;
+    }
+
+    Ok(())
+  }
+}
+
+impl core::fmt::Debug for MethodTypeDefinition {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("MethodTypeDefinition")
+      .field("type_", &self.type_)
+      .field("formats", &self.formats)
+      .field("validator", &self.validator.is_some())
+      .finish()
+  }
+}
+
+/// The process-wide registry of custom [`MethodType`]s.
+///
+/// # Example
+///
+/// ```
+/// # use identity_verification::MethodData;
+/// # use identity_verification::MethodDataFormat;
+/// # use identity_verification::MethodType;
+/// # use identity_verification::MethodTypeDefinition;
+/// # use identity_verification::MethodTypeRegistry;
+/// let type_: MethodType = MethodType::custom("AcmeVerificationKey2024");
+/// MethodTypeRegistry::register(
+///   MethodTypeDefinition::new(type_.clone())
+///     .format(MethodDataFormat::PublicKeyMultibase)
+///     .validator(|data| match data.try_decode() {
+///       Ok(key) if key.len() == 32 => Ok(()),
+///       _ => Err("expected a 32 byte key".to_owned()),
+///     }),
+/// );
+///
+/// assert!(MethodTypeRegistry::validate(&type_, &MethodData::new_multibase([0; 32])).is_ok());
+/// assert!(MethodTypeRegistry::validate(&type_, &MethodData::new_base58([0; 32])).is_err());
+/// # MethodTypeRegistry::unregister(&type_);
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct MethodTypeRegistry;
+
+impl MethodTypeRegistry {
+  /// Registers `definition`, replacing and returning the definition previously registered for the same type.
+  pub fn register(definition: MethodTypeDefinition) -> Option<MethodTypeDefinition> {
+    REGISTRY
+      .write()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+      .insert(definition.type_.clone(), definition)
+  }
+
+  /// Removes and returns the definition registered for `type_`.
+  pub fn unregister(type_: &MethodType) -> Option<MethodTypeDefinition> {
+    REGISTRY
+      .write()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+      .remove(type_)
+  }
+
+  /// Returns the definition registered for `type_`.
+  pub fn get(type_: &MethodType) -> Option<MethodTypeDefinition> {
+    REGISTRY
+      .read()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+      .get(type_)
+      .cloned()
+  }
+
+  /// Returns whether a definition is registered for `type_`.
+  pub fn is_registered(type_: &MethodType) -> bool {
+    REGISTRY
+      .read()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+      .contains_key(type_)
+  }
+
+  /// Validates `data` against the definition registered for `type_`, succeeding if none is registered.
+  pub fn validate(type_: &MethodType, data: &MethodData) -> Result<()> {
+    match Self::get(type_) {
+      Some(definition) => definition.validate(data),
+      None => Ok(()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::VerificationMethod;
+
+  #[test]
+  fn test_registered_type_is_validated() {
+    let type_: MethodType = MethodType::custom("TestRegistryKey2024");
+    assert!(MethodTypeRegistry::validate(&type_, &MethodData::new_base58([0; 3])).is_ok());
+
+    MethodTypeRegistry::register(
+      MethodTypeDefinition::new(type_.clone())
+        .format(MethodDataFormat::PublicKeyBase58)
+        .validator(|data| match data.try_decode() {
+          Ok(key) if key.len() == 4 => Ok(()),
+          _ => Err("expected a 4 byte key".to_owned()),
+        }),
+    );
+    assert!(MethodTypeRegistry::is_registered(&type_));
+    assert!(MethodTypeRegistry::validate(&type_, &MethodData::new_base58([0; 4])).is_ok());
+    assert!(matches!(
+      MethodTypeRegistry::validate(&type_, &MethodData::new_base58([0; 3])),
+      Err(Error::InvalidMethodData { reason, .. }) if reason == "expected a 4 byte key"
+    ));
+    assert!(matches!(
+      MethodTypeRegistry::validate(&type_, &MethodData::new_multibase([0; 4])),
+      Err(Error::InvalidMethodData { .. })
+    ));
+
+    assert!(MethodTypeRegistry::unregister(&type_).is_some());
+    assert!(MethodTypeRegistry::validate(&type_, &MethodData::new_multibase([0; 4])).is_ok());
+  }
+
+  #[test]
+  fn test_registered_type_is_validated_on_deserialization() {
+    let type_: MethodType = MethodType::custom("TestRegistryKeyJwk2024");
+    MethodTypeRegistry::register(MethodTypeDefinition::new(type_.clone()).format(MethodDataFormat::PublicKeyJwk));
+
+    let method = |data: (&str, serde_json::Value)| {
+      serde_json::json!({
+        "id": "did:example:123#key-1",
+        "controller": "did:example:123",
+        "type": "TestRegistryKeyJwk2024",
+        data.0: data.1,
+      })
+    };
+    let jwk = serde_json::json!({ "kty": "OKP", "crv": "Ed25519", "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo" });
+    assert!(serde_json::from_value::<VerificationMethod>(method(("publicKeyJwk", jwk))).is_ok());
+    assert!(serde_json::from_value::<VerificationMethod>(method(("publicKeyBase58", "3M5RCDj".into()))).is_err());
+
+    MethodTypeRegistry::unregister(&type_);
+  }
+}
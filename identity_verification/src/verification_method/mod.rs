@@ -13,6 +13,8 @@ mod method_ref;
 mod method_relationship;
 mod method_scope;
 mod method_type;
+mod method_type_registry;
+mod multikey;
 
 pub use self::builder::MethodBuilder;
 pub use self::material::CustomMethodData;
@@ -22,3 +24,8 @@ pub use self::method_ref::MethodRef;
 pub use self::method_relationship::MethodRelationship;
 pub use self::method_scope::MethodScope;
 pub use self::method_type::MethodType;
+pub use self::method_type_registry::MethodDataFormat;
+pub use self::method_type_registry::MethodTypeDefinition;
+pub use self::method_type_registry::MethodTypeRegistry;
+pub use self::multikey::Multikey;
+pub use self::multikey::MultikeyCodec;
@@ -0,0 +1,331 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use identity_core::convert::BaseEncoding;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jose::jwk::EcCurve;
+use crate::jose::jwk::EcxCurve;
+use crate::jose::jwk::EdCurve;
+use crate::jose::jwk::Jwk;
+use crate::jose::jwk::JwkParams;
+use crate::jose::jwk::JwkParamsEc;
+use crate::jose::jwk::JwkParamsOkp;
+use crate::jose::jwu;
+
+/// The types of public keys that can be expressed as a [`Multikey`], identified by their
+/// [multicodec](https://github.com/multiformats/multicodec/blob/master/table.csv) code.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MultikeyCodec {
+  /// An Ed25519 public key (`ed25519-pub`).
+  Ed25519,
+  /// An X25519 public key (`x25519-pub`).
+  X25519,
+  /// A compressed P-256 public key (`p256-pub`).
+  P256,
+  /// A compressed secp256k1 public key (`secp256k1-pub`).
+  Secp256k1,
+  /// A compressed BLS12-381 public key in the G1 group (`bls12_381-g1-pub`).
+  Bls12381G1,
+  /// A compressed BLS12-381 public key in the G2 group (`bls12_381-g2-pub`).
+  Bls12381G2,
+}
+
+impl MultikeyCodec {
+  const ALL: [Self; 6] = [
+    Self::Ed25519,
+    Self::X25519,
+    Self::P256,
+    Self::Secp256k1,
+    Self::Bls12381G1,
+    Self::Bls12381G2,
+  ];
+
+  /// Returns the multicodec code of the key type.
+  pub const fn code(self) -> u64 {
+    match self {
+      Self::Ed25519 => 0xed,
+      Self::X25519 => 0xec,
+      Self::P256 => 0x1200,
+      Self::Secp256k1 => 0xe7,
+      Self::Bls12381G1 => 0xea,
+      Self::Bls12381G2 => 0xeb,
+    }
+  }
+
+  /// Returns the length in bytes of public keys of this type.
+  pub const fn key_length(self) -> usize {
+    match self {
+      Self::Ed25519 | Self::X25519 => 32,
+      Self::P256 | Self::Secp256k1 => 33,
+      Self::Bls12381G1 => 48,
+      Self::Bls12381G2 => 96,
+    }
+  }
+
+  /// The unsigned varint encoding of the code, prepended to the key bytes.
+  const fn prefix(self) -> &'static [u8] {
+    match self {
+      Self::Ed25519 => &[0xed, 0x01],
+      Self::X25519 => &[0xec, 0x01],
+      Self::P256 => &[0x80, 0x24],
+      Self::Secp256k1 => &[0xe7, 0x01],
+      Self::Bls12381G1 => &[0xea, 0x01],
+      Self::Bls12381G2 => &[0xeb, 0x01],
+    }
+  }
+}
+
+impl Display for MultikeyCodec {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(match self {
+      Self::Ed25519 => "ed25519-pub",
+      Self::X25519 => "x25519-pub",
+      Self::P256 => "p256-pub",
+      Self::Secp256k1 => "secp256k1-pub",
+      Self::Bls12381G1 => "bls12_381-g1-pub",
+      Self::Bls12381G2 => "bls12_381-g2-pub",
+    })
+  }
+}
+
+/// A public key in the [Multikey](https://www.w3.org/TR/controller-document/#multikey) format, as expressed in the
+/// `publicKeyMultibase` property of verification methods of type
+/// [`Multikey`](crate::MethodType::MULTIKEY).
+///
+/// Elliptic curve keys are held in their compressed form.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Multikey {
+  codec: MultikeyCodec,
+  key: Vec<u8>,
+}
+
+impl Multikey {
+  /// Creates a new [`Multikey`] from the raw bytes of a public key of type `codec`.
+  ///
+  /// # Errors
+  /// Fails if `key` is not of the length expected for `codec`, or is not a compressed point for elliptic curve keys.
+  pub fn new(codec: MultikeyCodec, key: impl Into<Vec<u8>>) -> Result<Self> {
+    let key: Vec<u8> = key.into();
+    if key.len() != codec.key_length() {
+      return Err(Error::InvalidMultikey("invalid key length"));
+    }
+    if matches!(codec, MultikeyCodec::P256 | MultikeyCodec::Secp256k1) && !matches!(key[0], 0x02 | 0x03) {
+      return Err(Error::InvalidMultikey("expected a compressed elliptic curve point"));
+    }
+    Ok(Self { codec, key })
+  }
+
+  /// Decodes a [`Multikey`] from the value of a `publicKeyMultibase` property.
+  ///
+  /// # Errors
+  /// Fails if `multibase` is not base58btc encoded or does not start with the multicodec prefix of a supported key
+  /// type.
+  pub fn from_multibase(multibase: &str) -> Result<Self> {
+    if !multibase.starts_with('z') {
+      return Err(Error::InvalidMultikey("expected base58btc encoding"));
+    }
+    let bytes: Vec<u8> = BaseEncoding::decode_multibase(multibase).map_err(|_| Error::InvalidKeyDataMultibase)?;
+    let codec: MultikeyCodec = MultikeyCodec::ALL
+      .into_iter()
+      .find(|codec| bytes.starts_with(codec.prefix()))
+      .ok_or(Error::InvalidMultikey("unsupported multicodec"))?;
+    Self::new(codec, &bytes[codec.prefix().len()..])
+  }
+
+  /// Encodes this key as the value of a `publicKeyMultibase` property.
+  pub fn to_multibase(&self) -> String {
+    let bytes: Vec<u8> = [self.codec.prefix(), self.key.as_slice()].concat();
+    BaseEncoding::encode_multibase(&bytes, None)
+  }
+
+  /// Returns the type of this key.
+  pub fn codec(&self) -> MultikeyCodec {
+    self.codec
+  }
+
+  /// Returns the raw bytes of this key.
+  pub fn public_key(&self) -> &[u8] {
+    &self.key
+  }
+
+  /// Converts a public [`Jwk`] into a [`Multikey`].
+  ///
+  /// # Errors
+  /// Fails if `jwk` contains private key material, or is not an Ed25519, X25519, P-256 or secp256k1 key.
+  pub fn from_jwk(jwk: &Jwk) -> Result<Self> {
+    if !jwk.is_public() {
+      return Err(Error::PrivateKeyMaterialExposed);
+    }
+    let decode = |value: &str| jwu::decode_b64(value).map_err(|_| Error::InvalidMultikey("invalid base64url value"));
+
+    match jwk.params() {
+      JwkParams::Okp(params) => {
+        let codec: MultikeyCodec = match (params.try_ed_curve(), params.try_ecx_curve()) {
+          (Ok(EdCurve::Ed25519), _) => MultikeyCodec::Ed25519,
+          (_, Ok(EcxCurve::X25519)) => MultikeyCodec::X25519,
+          _ => return Err(Error::InvalidMultikey("unsupported OKP curve")),
+        };
+        Self::new(codec, decode(&params.x)?)
+      }
+      JwkParams::Ec(params) => {
+        let codec: MultikeyCodec = match params.try_ec_curve() {
+          Ok(EcCurve::P256) => MultikeyCodec::P256,
+          Ok(EcCurve::Secp256K1) => MultikeyCodec::Secp256k1,
+          _ => return Err(Error::InvalidMultikey("unsupported EC curve")),
+        };
+        let x: Vec<u8> = decode(&params.x)?;
+        let y: Vec<u8> = decode(&params.y)?;
+        // The compressed point is the x coordinate, tagged with the parity of the y coordinate.
+        let tag: u8 = match y.last() {
+          Some(last) if y.len() == x.len() => 0x02 | (last & 1),
+          _ => return Err(Error::InvalidMultikey("invalid y coordinate")),
+        };
+        Self::new(codec, [[tag].as_slice(), x.as_slice()].concat())
+      }
+      JwkParams::Rsa(_) | JwkParams::Oct(_) => Err(Error::InvalidMultikey("unsupported key type")),
+    }
+  }
+
+  /// Converts this key into a public [`Jwk`].
+  ///
+  /// # Errors
+  /// Fails for BLS12-381 keys, whose JWK representation holds uncompressed points, and for elliptic curve keys
+  /// that are not valid points on their curve.
+  pub fn to_jwk(&self) -> Result<Jwk> {
+    match self.codec {
+      MultikeyCodec::Ed25519 => Ok(Self::okp_jwk(EdCurve::Ed25519.name(), &self.key)),
+      MultikeyCodec::X25519 => Ok(Self::okp_jwk(EcxCurve::X25519.name(), &self.key)),
+      MultikeyCodec::P256 => {
+        let public_key =
+          p256::PublicKey::from_sec1_bytes(&self.key).map_err(|_| Error::InvalidMultikey("invalid P-256 point"))?;
+        Ok(Self::ec_jwk(
+          EcCurve::P256,
+          public_key.to_encoded_point(false).as_bytes(),
+        ))
+      }
+      MultikeyCodec::Secp256k1 => {
+        let public_key =
+          k256::PublicKey::from_sec1_bytes(&self.key).map_err(|_| Error::InvalidMultikey("invalid secp256k1 point"))?;
+        Ok(Self::ec_jwk(
+          EcCurve::Secp256K1,
+          public_key.to_encoded_point(false).as_bytes(),
+        ))
+      }
+      MultikeyCodec::Bls12381G1 | MultikeyCodec::Bls12381G2 => {
+        Err(Error::InvalidMultikey("BLS12-381 keys cannot be converted to a JWK"))
+      }
+    }
+  }
+
+  fn okp_jwk(curve: &str, x: &[u8]) -> Jwk {
+    let mut params: JwkParamsOkp = JwkParamsOkp::new();
+    params.crv = curve.to_owned();
+    params.x = jwu::encode_b64(x);
+    Jwk::from_params(params)
+  }
+
+  /// Builds an EC [`Jwk`] from an uncompressed SEC1 point, i.e. `0x04 || x || y`.
+  fn ec_jwk(curve: EcCurve, uncompressed: &[u8]) -> Jwk {
+    let (x, y) = uncompressed[1..].split_at((uncompressed.len() - 1) / 2);
+    let mut params: JwkParamsEc = JwkParamsEc::new();
+    params.crv = curve.name().to_owned();
+    params.x = jwu::encode_b64(x);
+    params.y = jwu::encode_b64(y);
+    Jwk::from_params(params)
+  }
+}
+
+impl TryFrom<&Jwk> for Multikey {
+  type Error = Error;
+
+  fn try_from(jwk: &Jwk) -> Result<Self> {
+    Self::from_jwk(jwk)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  // Test vectors from https://www.w3.org/TR/controller-document/#Multikey.
+  const ED25519_MULTIBASE: &str = "z6MkmM42vxfqZQsv4ehtTjFFxQ4sQKS2w6WR7emozFAn5cxu";
+  const P256_MULTIBASE: &str = "zDnaerDaTF5BXEavCrfRZEk316dpbLsfPDZ3WJ5hRTPFU2169";
+
+  #[test]
+  fn test_multibase_roundtrip() {
+    for multibase in [ED25519_MULTIBASE, P256_MULTIBASE] {
+      let multikey: Multikey = Multikey::from_multibase(multibase).unwrap();
+      assert_eq!(multikey.to_multibase(), multibase);
+    }
+    assert_eq!(
+      Multikey::from_multibase(ED25519_MULTIBASE).unwrap().codec(),
+      MultikeyCodec::Ed25519
+    );
+    assert_eq!(
+      Multikey::from_multibase(P256_MULTIBASE).unwrap().codec(),
+      MultikeyCodec::P256
+    );
+
+    let bls: Multikey = Multikey::new(MultikeyCodec::Bls12381G2, [7; 96]).unwrap();
+    assert_eq!(Multikey::from_multibase(&bls.to_multibase()).unwrap(), bls);
+  }
+
+  #[test]
+  fn test_invalid_multibase() {
+    // Not base58btc.
+    assert!(Multikey::from_multibase("f0102").is_err());
+    // Unknown multicodec.
+    assert!(Multikey::from_multibase(&BaseEncoding::encode_multibase(&[0x00; 34], None)).is_err());
+    // Invalid length.
+    assert!(matches!(
+      Multikey::new(MultikeyCodec::Ed25519, [0; 31]),
+      Err(Error::InvalidMultikey(_))
+    ));
+  }
+
+  #[test]
+  fn test_jwk_roundtrip() {
+    for multibase in [ED25519_MULTIBASE, P256_MULTIBASE] {
+      let multikey: Multikey = Multikey::from_multibase(multibase).unwrap();
+      let jwk: Jwk = multikey.to_jwk().unwrap();
+      assert_eq!(Multikey::from_jwk(&jwk).unwrap(), multikey);
+    }
+
+    // The compressed generator point of secp256k1.
+    let generator: [u8; 33] = [
+      0x02, 0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87, 0x0b, 0x07, 0x02, 0x9b,
+      0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16, 0xf8, 0x17, 0x98,
+    ];
+    let multikey: Multikey = Multikey::new(MultikeyCodec::Secp256k1, generator).unwrap();
+    let jwk: Jwk = multikey.to_jwk().unwrap();
+    assert_eq!(jwk.try_ec_curve().unwrap(), EcCurve::Secp256K1);
+    assert_eq!(Multikey::from_jwk(&jwk).unwrap(), multikey);
+
+    let bls: Multikey = Multikey::new(MultikeyCodec::Bls12381G1, [7; 48]).unwrap();
+    assert!(bls.to_jwk().is_err());
+  }
+
+  #[test]
+  fn test_private_jwk_rejected() {
+    let jwk: Jwk = serde_json::from_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+      "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"
+    }))
+    .unwrap();
+    assert!(matches!(
+      Multikey::from_jwk(&jwk),
+      Err(Error::PrivateKeyMaterialExposed)
+    ));
+  }
+}